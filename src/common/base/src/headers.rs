@@ -20,7 +20,10 @@ pub const HEADER_USER: &str = "X-DATABEND-USER";
 pub const HEADER_FUNCTION: &str = "X-DATABEND-FUNCTION";
 
 pub const HEADER_DEDUPLICATE_LABEL: &str = "X-DATABEND-DEDUPLICATE-LABEL";
+pub const HEADER_IDEMPOTENCY_KEY: &str = "X-DATABEND-IDEMPOTENCY-KEY";
 pub const HEADER_NODE_ID: &str = "X-DATABEND-NODE-ID";
+pub const HEADER_QUERY_TAG: &str = "X-DATABEND-QUERY-TAG";
+pub const HEADER_APPLICATION_NAME: &str = "X-DATABEND-APPLICATION-NAME";
 
 pub const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
 pub const HEADER_QUERY_PAGE_ROWS: &str = "X-DATABEND-QUERY-PAGE-ROWS";
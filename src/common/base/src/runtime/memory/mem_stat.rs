@@ -179,6 +179,21 @@ impl MemStat {
         self.used.load(Ordering::Relaxed)
     }
 
+    /// Check if the used memory reaches `ratio` percent of the limit, which means the
+    /// operators should spill or pause to release memory before the limit is exceeded.
+    ///
+    /// Always false if there is no limit or `ratio` is 0.
+    #[inline]
+    pub fn is_memory_pressure(&self, ratio: u64) -> bool {
+        let limit = self.limit.load(Ordering::Relaxed);
+        if limit <= 0 || ratio == 0 {
+            return false;
+        }
+
+        let used = self.used.load(Ordering::Relaxed);
+        used as i128 * 100 >= limit as i128 * ratio.min(100) as i128
+    }
+
     #[inline]
     #[allow(unused)]
     pub fn get_peak_memory_usage(&self) -> i64 {
@@ -259,6 +274,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_memory_pressure() -> Result<()> {
+        let mem_stat = MemStat::create("TEST".to_string());
+        mem_stat
+            .record_memory::<false>(MINIMUM_MEMORY_LIMIT, MINIMUM_MEMORY_LIMIT)
+            .unwrap();
+
+        // No limit
+        assert!(!mem_stat.is_memory_pressure(90));
+
+        mem_stat.set_limit(MINIMUM_MEMORY_LIMIT * 2);
+        assert!(!mem_stat.is_memory_pressure(90));
+        assert!(mem_stat.is_memory_pressure(50));
+        assert!(!mem_stat.is_memory_pressure(0));
+
+        mem_stat
+            .record_memory::<false>(MINIMUM_MEMORY_LIMIT, MINIMUM_MEMORY_LIMIT)
+            .unwrap();
+        assert!(mem_stat.is_memory_pressure(90));
+        assert!(mem_stat.is_memory_pressure(100));
+
+        Ok(())
+    }
+
     #[test]
     fn test_single_level_mem_stat_with_check_limit() -> Result<()> {
         let mem_stat = MemStat::create("TEST".to_string());
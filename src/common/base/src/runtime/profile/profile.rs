@@ -99,6 +99,17 @@ impl Profile {
         }
     }
 
+    /// The id of the processor running on the current thread.
+    pub fn current_pid() -> Option<usize> {
+        ThreadTracker::with(|x| {
+            x.borrow()
+                .payload
+                .profile
+                .as_ref()
+                .map(|profile| profile.pid)
+        })
+    }
+
     pub fn record_usize_profile(name: ProfileStatisticsName, value: usize) {
         ThreadTracker::with(|x| match x.borrow().payload.profile.as_ref() {
             None => {}
@@ -45,6 +45,11 @@ pub enum ProfileStatisticsName {
     SpillReadTime,
     RuntimeFilterPruneParts,
     MemoryUsage,
+    StorageReadErrors,
+    StorageReadRetries,
+    StorageHedgedReads,
+    CommitSegments,
+    CommitTime,
 }
 
 #[derive(Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize, Debug)]
@@ -242,6 +247,41 @@ pub fn get_statistics_desc() -> Arc<BTreeMap<ProfileStatisticsName, ProfileDesc>
                 index: ProfileStatisticsName::MemoryUsage as usize,
                 unit: StatisticsUnit::Bytes,
                 plain_statistics: false,
+            }),
+            (ProfileStatisticsName::StorageReadErrors, ProfileDesc {
+                display_name: "storage read errors",
+                desc: "The number of failed reads from storage, including the retried ones",
+                index: ProfileStatisticsName::StorageReadErrors as usize,
+                unit: StatisticsUnit::Count,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::StorageReadRetries, ProfileDesc {
+                display_name: "storage read retries",
+                desc: "The number of retried reads from storage after transient errors",
+                index: ProfileStatisticsName::StorageReadRetries as usize,
+                unit: StatisticsUnit::Count,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::StorageHedgedReads, ProfileDesc {
+                display_name: "storage hedged reads",
+                desc: "The number of duplicate reads issued to storage because the first read exceeded the latency threshold",
+                index: ProfileStatisticsName::StorageHedgedReads as usize,
+                unit: StatisticsUnit::Count,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::CommitSegments, ProfileDesc {
+                display_name: "commit segments",
+                desc: "The number of new segments committed to the table",
+                index: ProfileStatisticsName::CommitSegments as usize,
+                unit: StatisticsUnit::Count,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::CommitTime, ProfileDesc {
+                display_name: "commit time",
+                desc: "The time spent to commit the snapshot of the new segments in millisecond",
+                index: ProfileStatisticsName::CommitTime as usize,
+                unit: StatisticsUnit::MillisSeconds,
+                plain_statistics: false,
             })
         ]))
     }).clone()
@@ -159,6 +159,12 @@ build_exceptions! {
     WrongSequenceCount(1125),
     UnknownSequence(1126),
     UnknownQuery(1127),
+    // The statement execution exceeds the statement timeout.
+    StatementTimeout(1128),
+    // The estimated resources of the query exceed the limits.
+    QueryEstimateExceedsLimit(1129),
+    // The error injected by the setting `fault_injection` for testing.
+    FaultInjected(1130),
 
     // Data Related Errors
 
@@ -182,6 +188,9 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// TableColumnBackfillPending is used when the blocks are rewritten while the columns
+    /// added with the non-constant defaults are not backfilled yet.
+    TableColumnBackfillPending(1304),
 
     // License related errors starts here
 
@@ -238,6 +247,10 @@ build_exceptions! {
     DuplicatedUpsertFiles(2014),
     TableAlreadyLocked(2015),
     TableLockExpired(2016),
+    TableLockWaitTimeout(2017),
+    TableReadOnly(2018),
+    MaintenanceMode(2019),
+    ObjectHasDependents(2020),
 
     // User api error codes.
     UnknownUser(2201),
@@ -294,6 +307,7 @@ build_exceptions! {
     // Cluster error codes.
     ClusterUnknownNode(2401),
     ClusterNodeAlreadyExists(2402),
+    ClusterNodeSuspended(2403),
 
     // Stage error codes.
     UnknownStage(2501),
@@ -313,6 +327,10 @@ build_exceptions! {
     IllegalConnection(2511),
     ConnectionAlreadyExists(2512),
 
+    // Tag error codes.
+    UnknownTag(2513),
+    TagAlreadyExists(2514),
+
     // User defined function error codes.
     IllegalUDFFormat(2601),
     UnknownUDF(2602),
@@ -321,6 +339,7 @@ build_exceptions! {
     UDFSchemaMismatch(2605),
     UnsupportedDataType(2606),
     UDFDataError(2607),
+    UDFServerUnavailable(2608),
 
     // Database error codes.
     UnknownDatabaseEngine(2701),
@@ -393,6 +412,7 @@ build_exceptions! {
     StorageInsecure(3903),
     DeprecatedIndexFormat(3904),
     InvalidOperation(3905),
+    BlockCorrupted(3906),
     StorageOther(4000),
     UnresolvableConflict(4001),
 
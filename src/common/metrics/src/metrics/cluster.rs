@@ -25,6 +25,8 @@ static CLUSTER_CLUSTER_ERROR_COUNT: LazyLock<FamilyCounter<Vec<(&'static str, St
     LazyLock::new(|| register_counter_family("cluster_error_count"));
 static CLUSTER_DISCOVERED_NODE_GAUGE: LazyLock<FamilyGauge<Vec<(&'static str, String)>>> =
     LazyLock::new(|| register_gauge_family("cluster_discovered_node"));
+static CLUSTER_SCALING_RECOMMENDATION_GAUGE: LazyLock<FamilyGauge<Vec<(&'static str, String)>>> =
+    LazyLock::new(|| register_gauge_family("cluster_scaling_recommendation"));
 
 pub fn metric_incr_cluster_heartbeat_count(
     local_id: &str,
@@ -80,3 +82,22 @@ pub fn metrics_gauge_discovered_nodes(
         .get_or_create(labels)
         .set(val as i64);
 }
+
+/// The latest recommendation of the scaling advisor of the node, 1 to scale up, -1 to scale
+/// down and 0 to keep.
+pub fn metrics_gauge_scaling_recommendation(
+    local_id: &str,
+    cluster_id: &str,
+    tenant_id: &str,
+    val: i64,
+) {
+    let labels = &vec![
+        ("local_id", local_id.to_string()),
+        ("cluster_id", cluster_id.to_string()),
+        ("tenant_id", tenant_id.to_string()),
+    ];
+
+    CLUSTER_SCALING_RECOMMENDATION_GAUGE
+        .get_or_create(labels)
+        .set(val);
+}
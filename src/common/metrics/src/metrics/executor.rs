@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::OnceLock;
+
+use databend_common_base::runtime::metrics::register_counter_family;
+use databend_common_base::runtime::metrics::register_gauge_family;
+use databend_common_base::runtime::metrics::FamilyCounter;
+use databend_common_base::runtime::metrics::FamilyGauge;
+use databend_common_base::runtime::metrics::Gauge;
+
+use crate::VecLabels;
+
+pub const PROCESSOR_STATE_READY: &str = "ready";
+pub const PROCESSOR_STATE_BLOCKED: &str = "blocked";
+
+pub const EXCHANGE_DIRECTION_SEND: &str = "send";
+pub const EXCHANGE_DIRECTION_RECEIVE: &str = "receive";
+
+/// The labels of the warehouse the node belongs to, which are attached to the metrics of
+/// the engine internals, so that they can be aggregated per warehouse.
+static WAREHOUSE_LABELS: OnceLock<VecLabels> = OnceLock::new();
+
+static PIPELINE_PROCESSORS: LazyLock<FamilyGauge<VecLabels>> =
+    LazyLock::new(|| register_gauge_family("pipeline_processors"));
+static PIPELINE_SCHEDULER_QUEUE_DEPTH: LazyLock<FamilyGauge<VecLabels>> =
+    LazyLock::new(|| register_gauge_family("pipeline_scheduler_queue_depth"));
+static EXCHANGE_INFLIGHT_BYTES: LazyLock<FamilyGauge<VecLabels>> =
+    LazyLock::new(|| register_gauge_family("exchange_inflight_bytes"));
+static SPILL_WRITE_BYTES: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family("spill_write_bytes"));
+
+// The gauges updated by the executor are looked up once, they are updated on every
+// schedule of a processor.
+static READY_PROCESSORS: LazyLock<Arc<Gauge>> = LazyLock::new(|| {
+    PIPELINE_PROCESSORS.get_or_create(&warehouse_labels(vec![(
+        "state",
+        PROCESSOR_STATE_READY.to_string(),
+    )]))
+});
+static BLOCKED_PROCESSORS: LazyLock<Arc<Gauge>> = LazyLock::new(|| {
+    PIPELINE_PROCESSORS.get_or_create(&warehouse_labels(vec![(
+        "state",
+        PROCESSOR_STATE_BLOCKED.to_string(),
+    )]))
+});
+static SCHEDULER_QUEUE_DEPTH: LazyLock<Arc<Gauge>> =
+    LazyLock::new(|| PIPELINE_SCHEDULER_QUEUE_DEPTH.get_or_create(&warehouse_labels(vec![])));
+static EXCHANGE_SEND_LABELS: LazyLock<VecLabels> =
+    LazyLock::new(|| warehouse_labels(vec![("direction", EXCHANGE_DIRECTION_SEND.to_string())]));
+static EXCHANGE_RECEIVE_LABELS: LazyLock<VecLabels> =
+    LazyLock::new(|| warehouse_labels(vec![("direction", EXCHANGE_DIRECTION_RECEIVE.to_string())]));
+
+/// Sets the warehouse labels once on startup, before any of the metrics are recorded.
+pub fn set_warehouse_labels(tenant_id: &str, cluster_id: &str) {
+    let _ = WAREHOUSE_LABELS.set(vec![
+        ("tenant_id", tenant_id.to_string()),
+        ("cluster_id", cluster_id.to_string()),
+    ]);
+}
+
+fn warehouse_labels(labels: VecLabels) -> VecLabels {
+    let mut warehouse_labels = WAREHOUSE_LABELS.get().cloned().unwrap_or_default();
+    warehouse_labels.extend(labels);
+    warehouse_labels
+}
+
+fn exchange_labels(direction: &str) -> &'static VecLabels {
+    match direction {
+        EXCHANGE_DIRECTION_SEND => &EXCHANGE_SEND_LABELS,
+        _ => &EXCHANGE_RECEIVE_LABELS,
+    }
+}
+
+/// The handles of the `pipeline_processors` gauges, which are held by each processor of
+/// the executor to count it in its current state.
+///
+/// Processors are ready when they are scheduled or running, and blocked when they are
+/// waiting for their ports.
+#[derive(Clone)]
+pub struct PipelineProcessorsGauges {
+    ready: Arc<Gauge>,
+    blocked: Arc<Gauge>,
+}
+
+impl PipelineProcessorsGauges {
+    pub fn create() -> Self {
+        PipelineProcessorsGauges {
+            ready: READY_PROCESSORS.clone(),
+            blocked: BLOCKED_PROCESSORS.clone(),
+        }
+    }
+
+    fn gauge(&self, state: &str) -> &Gauge {
+        match state {
+            PROCESSOR_STATE_READY => &self.ready,
+            _ => &self.blocked,
+        }
+    }
+
+    pub fn inc(&self, state: &str, v: i64) {
+        self.gauge(state).inc_by(v);
+    }
+
+    pub fn dec(&self, state: &str, v: i64) {
+        self.gauge(state).dec_by(v);
+    }
+}
+
+pub fn metrics_inc_pipeline_scheduler_queue_depth(v: i64) {
+    SCHEDULER_QUEUE_DEPTH.inc_by(v);
+}
+
+pub fn metrics_dec_pipeline_scheduler_queue_depth(v: i64) {
+    SCHEDULER_QUEUE_DEPTH.dec_by(v);
+}
+
+/// The bytes of the exchange data queued to be sent to, or received from, the other
+/// nodes but not consumed yet.
+pub fn metrics_inc_exchange_inflight_bytes(direction: &str, v: i64) {
+    EXCHANGE_INFLIGHT_BYTES
+        .get_or_create(exchange_labels(direction))
+        .inc_by(v);
+}
+
+pub fn metrics_dec_exchange_inflight_bytes(direction: &str, v: i64) {
+    EXCHANGE_INFLIGHT_BYTES
+        .get_or_create(exchange_labels(direction))
+        .dec_by(v);
+}
+
+pub fn metrics_inc_spill_write_bytes(operator: &str, v: u64) {
+    let labels = warehouse_labels(vec![("operator", operator.to_string())]);
+    SPILL_WRITE_BYTES.get_or_create(&labels).inc_by(v);
+}
@@ -14,6 +14,7 @@
 
 pub mod cache;
 pub mod cluster;
+pub mod executor;
 pub mod http;
 pub mod interpreter;
 pub mod lock;
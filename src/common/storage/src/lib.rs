@@ -37,6 +37,8 @@ pub use config::StorageConfig;
 
 mod operator;
 pub use operator::init_operator;
+pub use operator::multipart_chunk_size;
+pub use operator::remove_all;
 pub use operator::DataOperator;
 
 pub mod metrics;
@@ -65,6 +67,15 @@ pub use stage::StageFileStatus;
 pub use stage::StageFilesInfo;
 pub use stage::STDIN_FD;
 
+mod stage_operator_pool;
+pub use stage_operator_pool::StageOperatorPool;
+
+mod tiered;
+pub use tiered::cold_tier_location;
+pub use tiered::is_cold_tier_location;
+pub use tiered::tiered_operator;
+pub use tiered::COLD_TIER_PREFIX;
+
 mod copy;
 mod merge;
 mod metrics_layer;
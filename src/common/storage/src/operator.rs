@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Reverse;
 use std::env;
 use std::io::Error;
 use std::io::ErrorKind;
@@ -26,6 +27,7 @@ use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_exception::ErrorCode;
 use databend_common_meta_app::storage::StorageAzblobConfig;
+use databend_common_meta_app::storage::StorageAzdlsConfig;
 use databend_common_meta_app::storage::StorageCosConfig;
 use databend_common_meta_app::storage::StorageFsConfig;
 use databend_common_meta_app::storage::StorageGcsConfig;
@@ -41,6 +43,7 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::storage::StorageS3Config;
 use databend_common_meta_app::storage::StorageWebhdfsConfig;
 use databend_enterprise_storage_encryption::get_storage_encryption_handler;
+use futures::stream;
 use log::warn;
 use opendal::layers::AsyncBacktraceLayer;
 use opendal::layers::ConcurrentLimitLayer;
@@ -53,6 +56,7 @@ use opendal::raw::HttpClient;
 use opendal::services;
 use opendal::Builder;
 use opendal::Operator;
+use opendal::Scheme;
 use reqwest_hickory_resolver::HickoryResolver;
 
 use crate::metrics_layer::METRICS_LAYER;
@@ -67,6 +71,7 @@ static GLOBAL_HICKORY_RESOLVER: LazyLock<Arc<HickoryResolver>> =
 pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
     let op = match &cfg {
         StorageParams::Azblob(cfg) => build_operator(init_azblob_operator(cfg)?)?,
+        StorageParams::Azdls(cfg) => build_operator(init_azdls_operator(cfg)?)?,
         StorageParams::Fs(cfg) => build_operator(init_fs_operator(cfg)?)?,
         StorageParams::Gcs(cfg) => build_operator(init_gcs_operator(cfg)?)?,
         #[cfg(feature = "storage-hdfs")]
@@ -95,6 +100,55 @@ pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
     Ok(op)
 }
 
+/// The part size of the multipart writes of the large files, e.g. the unloaded and spilled
+/// files, which is `None` if the storage can't write a file in parts, the file should be
+/// written at once then.
+///
+/// GCS writes the parts by its resumable upload, whose parts except the last must be a
+/// multiple of 256 KiB, so the size is aligned up.
+pub fn multipart_chunk_size(op: &Operator, chunk_size: usize) -> Option<usize> {
+    let info = op.info();
+    if !info.full_capability().write_can_multi {
+        return None;
+    }
+    match info.scheme() {
+        Scheme::Gcs => Some(chunk_size.next_multiple_of(GCS_UPLOAD_CHUNK_ALIGNMENT)),
+        _ => Some(chunk_size),
+    }
+}
+
+const GCS_UPLOAD_CHUNK_ALIGNMENT: usize = 256 * 1024;
+
+/// Removes all the files and the directories under the path.
+///
+/// The directories of azdls, whose namespace is hierarchical, can only be deleted when they
+/// are empty, so they are removed after the files, the deepest first.
+pub async fn remove_all(op: &Operator, path: &str) -> Result<()> {
+    if op.info().scheme() != Scheme::Azdls {
+        return op.remove_all(path).await.map_err(Error::from);
+    }
+
+    let (dirs, files): (Vec<_>, Vec<_>) = op
+        .list_with(path)
+        .recursive(true)
+        .await?
+        .into_iter()
+        .map(|entry| entry.path().to_string())
+        .filter(|entry| entry != path)
+        .partition(|entry| entry.ends_with('/'));
+    op.remove_via(stream::iter(files)).await?;
+
+    let mut dirs = dirs;
+    dirs.sort_by_key(|dir| Reverse(dir.matches('/').count()));
+    for dir in dirs {
+        op.delete(&dir).await?;
+    }
+    if path != "/" {
+        op.delete(path).await?;
+    }
+    Ok(())
+}
+
 pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
     let ob = Operator::new(builder)?;
 
@@ -172,6 +226,28 @@ pub fn init_azblob_operator(cfg: &StorageAzblobConfig) -> Result<impl Builder> {
     Ok(builder)
 }
 
+/// init_azdls_operator will init an opendal azdls operator.
+pub fn init_azdls_operator(cfg: &StorageAzdlsConfig) -> Result<impl Builder> {
+    let mut builder = services::Azdls::default();
+
+    // Endpoint
+    builder.endpoint(&cfg.endpoint_url);
+
+    // Filesystem
+    builder.filesystem(&cfg.filesystem);
+
+    // Root
+    builder.root(&cfg.root);
+
+    // Credential
+    builder.account_name(&cfg.account_name);
+    builder.account_key(&cfg.account_key);
+
+    builder.http_client(new_storage_http_client()?);
+
+    Ok(builder)
+}
+
 /// init_fs_operator will init a opendal fs operator.
 fn init_fs_operator(cfg: &StorageFsConfig) -> Result<impl Builder> {
     let mut builder = services::Fs::default();
@@ -22,6 +22,8 @@ use databend_common_expression::converts::arrow::EXTENSION_KEY;
 use databend_common_expression::FieldIndex;
 use opendal::Operator;
 use parquet::arrow::parquet_to_arrow_schema;
+use parquet::basic::ConvertedType;
+use parquet::basic::LogicalType;
 use parquet::file::footer::decode_footer;
 use parquet::file::footer::decode_metadata;
 use parquet::file::metadata::FileMetaData;
@@ -172,6 +174,11 @@ pub fn build_parquet_schema_tree(
             res
         }
         parquet::schema::types::Type::GroupType { fields, .. } => {
+            // The list is transparent in the tree, so the inner fields of the nested tuple
+            // can be reached by the same path as the tuple.
+            if let Some(element) = parquet_list_element(ty) {
+                return build_parquet_schema_tree(element, leave_id);
+            }
             let mut children = Vec::with_capacity(fields.len());
             for field in fields.iter() {
                 children.push(build_parquet_schema_tree(field, leave_id));
@@ -181,6 +188,36 @@ pub fn build_parquet_schema_tree(
     }
 }
 
+/// Returns the element type of a parquet list, following the backward-compatibility rules of
+/// the `LIST` logical type.
+pub fn parquet_list_element(
+    ty: &parquet::schema::types::Type,
+) -> Option<&parquet::schema::types::Type> {
+    let parquet::schema::types::Type::GroupType { basic_info, fields } = ty else {
+        return None;
+    };
+    if basic_info.converted_type() != ConvertedType::LIST
+        && !matches!(basic_info.logical_type(), Some(LogicalType::List))
+    {
+        return None;
+    }
+    let [repeated] = fields.as_slice() else {
+        return None;
+    };
+    match repeated.as_ref() {
+        parquet::schema::types::Type::GroupType {
+            basic_info: repeated_info,
+            fields,
+        } if fields.len() == 1
+            && repeated_info.name() != "array"
+            && repeated_info.name() != format!("{}_tuple", basic_info.name()) =>
+        {
+            Some(fields[0].as_ref())
+        }
+        repeated => Some(repeated),
+    }
+}
+
 /// Traverse the schema tree by `path` to collect the leaves' ids.
 pub fn traverse_parquet_schema_tree(
     node: &ParquetSchemaTreeNode,
@@ -266,4 +303,52 @@ mod tests {
         ]);
         assert_eq!(tree, expected_tree);
     }
+
+    #[test]
+    fn test_build_parquet_schema_tree_with_list() {
+        // Test schema (4 physical columns):
+        // a: Array(Tuple (
+        //    b: Int32,         (leave id: 0, path: [0, 0])
+        //    c: Array(Tuple (
+        //        d: Int32,     (leave id: 1, path: [0, 1, 0])
+        //        e: String,    (leave id: 2, path: [0, 1, 1])
+        //    )),
+        // ))
+        // f: Array(String),    (leave id: 3, path: [1])
+        let schema = TableSchema::new(vec![
+            TableField::new(
+                "a",
+                TableDataType::Array(Box::new(TableDataType::Tuple {
+                    fields_name: vec!["b".to_string(), "c".to_string()],
+                    fields_type: vec![
+                        TableDataType::Number(NumberDataType::Int32),
+                        TableDataType::Array(Box::new(TableDataType::Tuple {
+                            fields_name: vec!["d".to_string(), "e".to_string()],
+                            fields_type: vec![
+                                TableDataType::Number(NumberDataType::Int32),
+                                TableDataType::String,
+                            ],
+                        })),
+                    ],
+                })),
+            ),
+            TableField::new("f", TableDataType::Array(Box::new(TableDataType::String))),
+        ]);
+        let arrow_schema = (&schema).into();
+        let schema_desc = arrow_to_parquet_schema(&arrow_schema).unwrap();
+        let mut leave_id = 0;
+        let tree = build_parquet_schema_tree(schema_desc.root_schema(), &mut leave_id);
+        assert_eq!(leave_id, 4);
+        let expected_tree = ParquetSchemaTreeNode::Inner(vec![
+            ParquetSchemaTreeNode::Inner(vec![
+                ParquetSchemaTreeNode::Leaf(0),
+                ParquetSchemaTreeNode::Inner(vec![
+                    ParquetSchemaTreeNode::Leaf(1),
+                    ParquetSchemaTreeNode::Leaf(2),
+                ]),
+            ]),
+            ParquetSchemaTreeNode::Leaf(3),
+        ]);
+        assert_eq!(tree, expected_tree);
+    }
 }
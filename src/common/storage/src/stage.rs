@@ -34,8 +34,8 @@ use opendal::Metakey;
 use opendal::Operator;
 use regex::Regex;
 
-use crate::init_operator;
 use crate::DataOperator;
+use crate::StageOperatorPool;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum StageFileStatus {
@@ -73,16 +73,17 @@ impl StageFileInfo {
     }
 }
 
+/// Returns the operator of the stage from the [`StageOperatorPool`].
 pub fn init_stage_operator(stage_info: &StageInfo) -> Result<Operator> {
     if stage_info.stage_type == StageType::External {
-        Ok(init_operator(&stage_info.stage_params.storage)?)
+        StageOperatorPool::instance().get(&stage_info.stage_params.storage)
     } else {
         let stage_prefix = stage_info.stage_prefix();
         let param = DataOperator::instance()
             .params()
             .map_root(|path| format!("{path}/{stage_prefix}"));
 
-        Ok(init_operator(&param)?)
+        StageOperatorPool::instance().get(&param)
     }
 }
 /// select * from @s1/<path> (FILES => <files> PATTERN => <pattern>)
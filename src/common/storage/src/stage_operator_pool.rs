@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use dashmap::DashMap;
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::Result;
+use databend_common_meta_app::storage::StorageParams;
+use log::info;
+use opendal::Operator;
+
+use crate::init_operator;
+
+/// The operators of the stages pooled across the queries, keyed by the storage params of
+/// the stages, so that the queries on a stage share the connections and the credentials
+/// loaded by the operator.
+///
+/// A pooled operator is rebuilt once it is older than the refresh interval, which loads the
+/// temporary credentials (e.g. STS tokens of assumed roles or web identities) again before
+/// they expire. The long running readers, e.g. of COPY, take the operator from the pool for
+/// each file, so that a query running for hours picks up the refreshed credentials.
+pub struct StageOperatorPool {
+    refresh_interval: Duration,
+    operators: DashMap<String, PooledOperator>,
+}
+
+struct PooledOperator {
+    operator: Operator,
+    built_at: Instant,
+}
+
+impl StageOperatorPool {
+    /// The operators are not pooled if `refresh_interval` is zero.
+    pub fn init(refresh_interval: Duration) -> Result<()> {
+        GlobalInstance::set(Arc::new(StageOperatorPool {
+            refresh_interval,
+            operators: DashMap::new(),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<StageOperatorPool> {
+        GlobalInstance::get()
+    }
+
+    /// Returns the pooled operator of the params, the operator is built if it is not pooled
+    /// yet or is due to refresh.
+    pub fn get(&self, params: &StorageParams) -> Result<Operator> {
+        if self.refresh_interval.is_zero() {
+            return Ok(init_operator(params)?);
+        }
+
+        let key = serde_json::to_string(params)?;
+        if let Some(pooled) = self.operators.get(&key) {
+            if pooled.built_at.elapsed() < self.refresh_interval {
+                return Ok(pooled.operator.clone());
+            }
+        }
+
+        let operator = init_operator(params)?;
+        // Drop the operators of the stages that are not used anymore, e.g. dropped or altered.
+        self.operators
+            .retain(|_, pooled| pooled.built_at.elapsed() < self.refresh_interval * 2);
+        self.operators.insert(key, PooledOperator {
+            operator: operator.clone(),
+            built_at: Instant::now(),
+        });
+        info!(
+            "Stage operator of {} is built, {} operators are pooled",
+            params,
+            self.operators.len()
+        );
+        Ok(operator)
+    }
+
+    pub fn len(&self) -> usize {
+        self.operators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operators.is_empty()
+    }
+}
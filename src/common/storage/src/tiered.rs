@@ -0,0 +1,158 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use opendal::raw::Access;
+use opendal::raw::Accessor;
+use opendal::raw::AccessorInfo;
+use opendal::raw::OpCreateDir;
+use opendal::raw::OpDelete;
+use opendal::raw::OpList;
+use opendal::raw::OpPresign;
+use opendal::raw::OpRead;
+use opendal::raw::OpStat;
+use opendal::raw::OpWrite;
+use opendal::raw::RpCreateDir;
+use opendal::raw::RpDelete;
+use opendal::raw::RpList;
+use opendal::raw::RpPresign;
+use opendal::raw::RpRead;
+use opendal::raw::RpStat;
+use opendal::raw::RpWrite;
+use opendal::Operator;
+use opendal::Result;
+
+/// The prefix of the paths in the cold tier of a tiered operator, e.g. the block
+/// `1/2/_b/x.parquet` moved to the cold tier is located at `_cold/1/2/_b/x.parquet`, and is
+/// stored at `1/2/_b/x.parquet` of the cold storage.
+pub const COLD_TIER_PREFIX: &str = "_cold/";
+
+/// Returns the location of the path in the cold tier.
+pub fn cold_tier_location(path: &str) -> String {
+    format!("{}{}", COLD_TIER_PREFIX, path)
+}
+
+pub fn is_cold_tier_location(path: &str) -> bool {
+    path.starts_with(COLD_TIER_PREFIX)
+}
+
+/// Builds an operator over a hot and a cold storage, the paths with [`COLD_TIER_PREFIX`]
+/// are routed to the cold storage, and the others to the hot storage, so that the readers
+/// of the tiered data need not to know the tier of a path.
+pub fn tiered_operator(hot: Operator, cold: Operator) -> Operator {
+    Operator::from_inner(std::sync::Arc::new(TieredAccessor {
+        hot: hot.into_inner(),
+        cold: cold.into_inner(),
+    }))
+}
+
+#[derive(Debug)]
+struct TieredAccessor {
+    hot: Accessor,
+    cold: Accessor,
+}
+
+impl TieredAccessor {
+    fn route<'a>(&'a self, path: &'a str) -> (&'a Accessor, &'a str) {
+        match path.strip_prefix(COLD_TIER_PREFIX) {
+            Some(cold_path) => (&self.cold, cold_path),
+            None => (&self.hot, path),
+        }
+    }
+}
+
+impl Access for TieredAccessor {
+    type Reader = <Accessor as Access>::Reader;
+    type BlockingReader = <Accessor as Access>::BlockingReader;
+    type Writer = <Accessor as Access>::Writer;
+    type BlockingWriter = <Accessor as Access>::BlockingWriter;
+    type Lister = <Accessor as Access>::Lister;
+    type BlockingLister = <Accessor as Access>::BlockingLister;
+
+    fn info(&self) -> AccessorInfo {
+        let mut info = self.hot.info();
+        let cold = self.cold.info().full_capability();
+        let mut capability = info.full_capability();
+        capability.blocking &= cold.blocking;
+        capability.presign &= cold.presign;
+        // The paths of a batch may be in both tiers, which are removed one by one instead.
+        capability.batch = false;
+        info.set_native_capability(capability);
+        info
+    }
+
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let (accessor, path) = self.route(path);
+        accessor.create_dir(path, args).await
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let (accessor, path) = self.route(path);
+        accessor.read(path, args).await
+    }
+
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let (accessor, path) = self.route(path);
+        accessor.write(path, args).await
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let (accessor, path) = self.route(path);
+        accessor.stat(path, args).await
+    }
+
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let (accessor, path) = self.route(path);
+        accessor.delete(path, args).await
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let (accessor, path) = self.route(path);
+        accessor.list(path, args).await
+    }
+
+    async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        let (accessor, path) = self.route(path);
+        accessor.presign(path, args).await
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let (accessor, path) = self.route(path);
+        accessor.blocking_create_dir(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let (accessor, path) = self.route(path);
+        accessor.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        let (accessor, path) = self.route(path);
+        accessor.blocking_write(path, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let (accessor, path) = self.route(path);
+        accessor.blocking_stat(path, args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let (accessor, path) = self.route(path);
+        accessor.blocking_delete(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        let (accessor, path) = self.route(path);
+        accessor.blocking_list(path, args)
+    }
+}
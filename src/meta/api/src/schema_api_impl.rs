@@ -150,6 +150,8 @@ use databend_common_meta_app::schema::SetLVTReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SwapTableReply;
+use databend_common_meta_app::schema::SwapTableReq;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::TableCopiedFileNameIdent;
 use databend_common_meta_app::schema::TableId;
@@ -169,6 +171,7 @@ use databend_common_meta_app::schema::UndropDatabaseReq;
 use databend_common_meta_app::schema::UndropTableByIdReq;
 use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
+use databend_common_meta_app::schema::UpdateDatabaseOptionsReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
@@ -748,6 +751,54 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall]
+    #[minitrace::trace]
+    async fn update_database_options(
+        &self,
+        req: UpdateDatabaseOptionsReq,
+    ) -> Result<(), KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (_, db_id, db_meta_seq, mut db_meta) =
+                get_db_or_err(self, &req.name_ident, "update_database_options").await?;
+            for (key, value) in req.options.iter() {
+                match value {
+                    Some(value) => {
+                        db_meta.options.insert(key.clone(), value.clone());
+                    }
+                    None => {
+                        db_meta.options.remove(key);
+                    }
+                }
+            }
+            db_meta.updated_on = Utc::now();
+
+            let id_key = DatabaseId { db_id };
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(&id_key, Eq, db_meta_seq)],
+                if_then: vec![txn_op_put(&id_key, serialize_struct(&db_meta)?)],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name :? =(&req.name_ident),
+                database_id :? =(&db_id),
+                succ = succ;
+                "update_database_options"
+            );
+
+            if succ {
+                return Ok(());
+            }
+        }
+    }
+
     #[logcall::logcall]
     #[minitrace::trace]
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>, KVAppError> {
@@ -2187,6 +2238,185 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall]
+    #[minitrace::trace]
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let tenant_dbname_tbname = &req.name_ident;
+        let tenant_dbname = tenant_dbname_tbname.db_name_ident();
+        let tenant_dbname_target_tbname = TableNameIdent {
+            tenant: tenant_dbname_tbname.tenant.clone(),
+            db_name: tenant_dbname_tbname.db_name.clone(),
+            table_name: req.target_table_name.clone(),
+        };
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            // Get db by name to ensure presence
+
+            let (_, db_id, db_meta_seq, db_meta) =
+                get_db_or_err(self, &tenant_dbname, "swap_table").await?;
+
+            // cannot operate on shared database
+            if let Some(from_share) = db_meta.from_share {
+                return Err(KVAppError::AppError(AppError::ShareHasNoGrantedPrivilege(
+                    ShareHasNoGrantedPrivilege::new(from_share.tenant_name(), from_share.name()),
+                )));
+            }
+
+            // Get both tables by db_id, table_name to assert presence.
+
+            let dbid_tbname = DBIdTableName {
+                db_id,
+                table_name: tenant_dbname_tbname.table_name.clone(),
+            };
+            let (tb_id_seq, table_id) = get_u64_value(self, &dbid_tbname).await?;
+            if req.if_exists && tb_id_seq == 0 {
+                return Ok(SwapTableReply {});
+            }
+            assert_table_exist(tb_id_seq, tenant_dbname_tbname, "swap_table: origin table")?;
+
+            let target_dbid_tbname = DBIdTableName {
+                db_id,
+                table_name: req.target_table_name.clone(),
+            };
+            let (target_tb_id_seq, target_table_id) =
+                get_u64_value(self, &target_dbid_tbname).await?;
+            assert_table_exist(
+                target_tb_id_seq,
+                &tenant_dbname_target_tbname,
+                "swap_table: target table",
+            )?;
+
+            // The last table id of the table id list of each name is the current table id,
+            // swap them to keep the history of each name.
+            let dbid_tbname_idlist = TableIdHistoryIdent {
+                database_id: db_id,
+                table_name: tenant_dbname_tbname.table_name.clone(),
+            };
+            let (tb_id_list_seq, tb_id_list_opt): (_, Option<TableIdList>) =
+                get_pb_value(self, &dbid_tbname_idlist).await?;
+            let target_dbid_tbname_idlist = TableIdHistoryIdent {
+                database_id: db_id,
+                table_name: req.target_table_name.clone(),
+            };
+            let (target_tb_id_list_seq, target_tb_id_list_opt): (_, Option<TableIdList>) =
+                get_pb_value(self, &target_dbid_tbname_idlist).await?;
+
+            let mut tb_id_list = tb_id_list_opt.unwrap_or_else(|| {
+                // may the table is created before add db_id_list, so we just add the id into the list.
+                let mut list = TableIdList::new();
+                list.append(table_id);
+                list
+            });
+            let mut target_tb_id_list = target_tb_id_list_opt.unwrap_or_else(|| {
+                let mut list = TableIdList::new();
+                list.append(target_table_id);
+                list
+            });
+            for (list, id, name_ident) in [
+                (&tb_id_list, table_id, tenant_dbname_tbname),
+                (
+                    &target_tb_id_list,
+                    target_table_id,
+                    &tenant_dbname_target_tbname,
+                ),
+            ] {
+                if list.last() != Some(&id) {
+                    error!(
+                        "swap_table {:?} but last table id conflict, id list last: {:?}, current: {}",
+                        name_ident,
+                        list.last(),
+                        id
+                    );
+                    return Err(KVAppError::AppError(AppError::UnknownTable(
+                        UnknownTable::new(
+                            &name_ident.table_name,
+                            format!("{}: {}", "swap table", name_ident),
+                        ),
+                    )));
+                }
+            }
+            tb_id_list.pop();
+            tb_id_list.append(target_table_id);
+            target_tb_id_list.pop();
+            target_tb_id_list.append(table_id);
+
+            // get table id name
+            let table_id_to_name_key = TableIdToName { table_id };
+            let (table_id_to_name_seq, _): (_, Option<DBIdTableName>) =
+                get_pb_value(self, &table_id_to_name_key).await?;
+            let target_table_id_to_name_key = TableIdToName {
+                table_id: target_table_id,
+            };
+            let (target_table_id_to_name_seq, _): (_, Option<DBIdTableName>) =
+                get_pb_value(self, &target_table_id_to_name_key).await?;
+
+            let condition = vec![
+                // db has not to change, i.e., no new table is created.
+                txn_cond_seq(&DatabaseId { db_id }, Eq, db_meta_seq),
+                // table_name->table_id does not change.
+                // Updating the table meta is ok.
+                txn_cond_seq(&dbid_tbname, Eq, tb_id_seq),
+                txn_cond_seq(&target_dbid_tbname, Eq, target_tb_id_seq),
+                // no other table id with the same name is append.
+                txn_cond_seq(&dbid_tbname_idlist, Eq, tb_id_list_seq),
+                txn_cond_seq(&target_dbid_tbname_idlist, Eq, target_tb_id_list_seq),
+                txn_cond_seq(&table_id_to_name_key, Eq, table_id_to_name_seq),
+                txn_cond_seq(
+                    &target_table_id_to_name_key,
+                    Eq,
+                    target_table_id_to_name_seq,
+                ),
+            ];
+
+            let then_ops = vec![
+                txn_op_put(&dbid_tbname, serialize_u64(target_table_id)?), /* (db_id, tb_name) -> target_tb_id */
+                txn_op_put(&target_dbid_tbname, serialize_u64(table_id)?), /* (db_id, target_tb_name) -> tb_id */
+                // Changing a table in a db has to update the seq of db_meta,
+                // to block the batch-delete-tables when deleting a db.
+                txn_op_put(&DatabaseId { db_id }, serialize_struct(&db_meta)?), /* (db_id) -> db_meta */
+                txn_op_put(&dbid_tbname_idlist, serialize_struct(&tb_id_list)?), /* _fd_table_id_list/db_id/tb_name -> tb_id_list */
+                txn_op_put(
+                    &target_dbid_tbname_idlist,
+                    serialize_struct(&target_tb_id_list)?,
+                ), /* _fd_table_id_list/db_id/target_tb_name -> tb_id_list */
+                txn_op_put(
+                    &table_id_to_name_key,
+                    serialize_struct(&target_dbid_tbname)?,
+                ), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
+                txn_op_put(
+                    &target_table_id_to_name_key,
+                    serialize_struct(&dbid_tbname)?,
+                ), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
+            ];
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then: then_ops,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name :? =(tenant_dbname_tbname),
+                target :? =(&target_dbid_tbname),
+                table_id :? =(&table_id),
+                target_table_id :? =(&target_table_id),
+                succ = succ;
+                "swap_table"
+            );
+
+            if succ {
+                return Ok(SwapTableReply {});
+            }
+        }
+    }
+
     #[logcall::logcall]
     #[minitrace::trace]
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, KVAppError> {
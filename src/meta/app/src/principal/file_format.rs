@@ -59,6 +59,7 @@ pub enum FileFormatParams {
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
     Orc(OrcFileFormatParams),
+    Plugin(PluginFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -71,6 +72,7 @@ impl FileFormatParams {
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
             FileFormatParams::Orc(_) => StageFileFormatType::Orc,
+            FileFormatParams::Plugin(_) => StageFileFormatType::Plugin,
         }
     }
 
@@ -104,6 +106,7 @@ impl FileFormatParams {
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
             FileFormatParams::Orc(_) => StageFileCompression::None,
+            FileFormatParams::Plugin(v) => v.compression,
         }
     }
 
@@ -120,7 +123,21 @@ impl FileFormatParams {
     }
 
     pub fn try_from_reader(mut reader: FileFormatOptionsReader, old: bool) -> Result<Self> {
-        let typ = reader.take_type()?;
+        let type_name = reader.take_type_name()?;
+        let typ = match StageFileFormatType::from_str(&type_name) {
+            Ok(typ) => typ,
+            // The other formats may be provided by plugins, whose options are opaque here,
+            // and are validated by the plugins when the file format is resolved.
+            Err(_) if !old => {
+                let compression = reader.take_compression()?;
+                return Ok(FileFormatParams::Plugin(PluginFileFormatParams {
+                    format_name: type_name.to_uppercase(),
+                    compression,
+                    options: std::mem::take(&mut reader.options),
+                }));
+            }
+            Err(msg) => return Err(ErrorCode::IllegalFileFormat(msg)),
+        };
         let params = match typ {
             StageFileFormatType::Xml => {
                 let default = XmlFileFormatParams::default();
@@ -330,11 +347,9 @@ impl FileFormatOptionsReader {
         self.options.remove(key).unwrap_or(default)
     }
 
-    fn take_type(&mut self) -> Result<StageFileFormatType> {
+    fn take_type_name(&mut self) -> Result<String> {
         match (self.options.remove("type"), self.options.remove("format")) {
-            (Some(t), None) | (None, Some(t)) => {
-                StageFileFormatType::from_str(&t).map_err(ErrorCode::IllegalFileFormat)
-            }
+            (Some(t), None) | (None, Some(t)) => Ok(t),
             (Some(_), Some(_)) => Err(ErrorCode::IllegalFileFormat(
                 "Invalid FILE_FORMAT options: both TYPE and FORMAT option are present. \
                 Please only use the TYPE to specify the file format type. The FORMAT option is deprecated.",
@@ -684,6 +699,15 @@ impl ParquetFileFormatParams {
     }
 }
 
+/// The params of a format provided by a plugin registered under `format_name`, which
+/// parses and validates the options on its own.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginFileFormatParams {
+    pub format_name: String,
+    pub compression: StageFileCompression,
+    pub options: BTreeMap<String, String>,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OrcFileFormatParams {
     pub missing_field_as: NullAs,
@@ -768,6 +792,17 @@ impl Display for FileFormatParams {
                     params.missing_field_as
                 )
             }
+            FileFormatParams::Plugin(params) => {
+                write!(
+                    f,
+                    "TYPE = {} COMPRESSION = {:?}",
+                    params.format_name, params.compression
+                )?;
+                for (k, v) in &params.options {
+                    write!(f, " {} = '{}'", k.to_uppercase(), escape_string(v))?;
+                }
+                Ok(())
+            }
         }
     }
 }
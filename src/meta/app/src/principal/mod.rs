@@ -23,6 +23,7 @@ mod principal_identity;
 pub mod role_ident;
 mod role_info;
 mod stage_file_path;
+mod tag;
 pub mod udf_ident;
 mod user_auth;
 mod user_defined_file_format;
@@ -41,6 +42,7 @@ pub mod connection_ident;
 pub mod network_policy_ident;
 pub mod password_policy_ident;
 pub mod stage_file_ident;
+pub mod tag_ident;
 pub mod tenant_ownership_object_ident;
 pub mod tenant_user_ident;
 pub mod user_defined_file_format_ident;
@@ -62,6 +64,8 @@ pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
 pub use stage_file_ident::StageFileIdent;
 pub use stage_file_path::StageFilePath;
+pub use tag::Tag;
+pub use tag_ident::TagIdent;
 pub use tenant_ownership_object_ident::TenantOwnershipObjectIdent;
 pub use tenant_user_ident::TenantUserIdent;
 pub use udf_ident::UdfIdent;
@@ -32,6 +32,11 @@ pub struct RoleInfo {
     pub grants: UserGrantSet,
     pub created_on: DateTime<Utc>,
     pub update_on: DateTime<Utc>,
+
+    /// Refuse the queries estimated to scan more bytes than this, for the sessions using the role.
+    pub max_estimated_scan_bytes: Option<u64>,
+    /// Refuse the queries estimated to use more memory than this, for the sessions using the role.
+    pub max_estimated_memory_bytes: Option<u64>,
 }
 
 /// Error when ser/de RoleInfo
@@ -49,6 +54,8 @@ impl RoleInfo {
             grants: UserGrantSet::empty(),
             created_on: now,
             update_on: now,
+            max_estimated_scan_bytes: None,
+            max_estimated_memory_bytes: None,
         }
     }
 
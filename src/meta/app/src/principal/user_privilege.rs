@@ -240,6 +240,11 @@ impl UserPrivilegeSet {
         }
     }
 
+    /// Warehouses are not created by SQL, so they have no ownership.
+    pub fn available_privileges_on_warehouse() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Usage }).into()
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
@@ -174,6 +174,8 @@ pub enum StageFileFormatType {
     Orc,
     Parquet,
     Xml,
+    /// A format provided by a plugin, see [`crate::principal::PluginFileFormatParams`].
+    Plugin,
     None,
 }
 
@@ -497,6 +499,8 @@ pub struct CopyOptions {
     pub max_file_size: usize,
     pub single: bool,
     pub detailed_output: bool,
+    /// The names of the unloaded files, see `FILE_NAME_TEMPLATE` of `COPY INTO <location>`.
+    pub file_name_template: Option<String>,
 }
 
 impl CopyOptions {
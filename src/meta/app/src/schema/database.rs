@@ -258,6 +258,25 @@ pub struct RenameDatabaseReply {
     pub share_spec: Option<(Vec<ShareSpec>, ShareObject)>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateDatabaseOptionsReq {
+    pub name_ident: DatabaseNameIdent,
+    /// The options to set, the ones with `None` are removed.
+    pub options: BTreeMap<String, Option<String>>,
+}
+
+impl Display for UpdateDatabaseOptionsReq {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "update_database_options:{}/{}={:?}",
+            self.name_ident.tenant_name(),
+            self.name_ident.database_name(),
+            self.options
+        )
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropDatabaseReq {
     pub if_exists: bool,
@@ -58,6 +58,7 @@ pub use database::ShareDBParams;
 pub use database::ShareDbId;
 pub use database::UndropDatabaseReply;
 pub use database::UndropDatabaseReq;
+pub use database::UpdateDatabaseOptionsReq;
 pub use database_id_history_ident::DatabaseIdHistoryIdent;
 pub use index::*;
 pub use index_name_ident::IndexNameIdent;
@@ -107,6 +108,10 @@ pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
 pub use table::SetTableColumnMaskPolicyReply;
 pub use table::SetTableColumnMaskPolicyReq;
+pub use table::SwapTableReply;
+pub use table::SwapTableReq;
+pub use table::TableConstraint;
+pub use table::TableConstraintType;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileNameIdent;
 pub use table::TableId;
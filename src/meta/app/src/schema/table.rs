@@ -260,6 +260,11 @@ pub struct TableMeta {
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub indexes: BTreeMap<String, TableIndex>,
+    pub constraints: BTreeMap<String, TableConstraint>,
+    // tag name -> tag value
+    pub tags: BTreeMap<String, String>,
+    // column name -> (tag name -> tag value)
+    pub column_tags: BTreeMap<String, BTreeMap<String, String>>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -277,6 +282,56 @@ pub struct TableIndex {
     pub options: BTreeMap<String, String>,
 }
 
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TableConstraintType {
+    PrimaryKey = 0,
+    Unique = 1,
+    ForeignKey = 2,
+}
+
+impl Display for TableConstraintType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableConstraintType::PrimaryKey => write!(f, "PRIMARY KEY"),
+            TableConstraintType::Unique => write!(f, "UNIQUE"),
+            TableConstraintType::ForeignKey => write!(f, "FOREIGN KEY"),
+        }
+    }
+}
+
+/// An informational constraint of a table, it is never enforced on write.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableConstraint {
+    pub name: String,
+    pub constraint_type: TableConstraintType,
+    pub column_ids: Vec<u32>,
+    // the referenced database, table and columns of a foreign key.
+    pub ref_database: Option<String>,
+    pub ref_table: Option<String>,
+    pub ref_columns: Vec<String>,
+    // if true, the optimizer can assume the constraint holds.
+    pub rely: bool,
+}
+
+impl TableConstraint {
+    /// Returns true if the constraint guarantees the uniqueness of its columns.
+    pub fn is_unique_key(&self) -> bool {
+        matches!(
+            self.constraint_type,
+            TableConstraintType::PrimaryKey | TableConstraintType::Unique
+        )
+    }
+}
+
 impl TableMeta {
     pub fn add_column(
         &mut self,
@@ -389,6 +444,9 @@ impl Default for TableMeta {
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
             indexes: BTreeMap::new(),
+            constraints: BTreeMap::new(),
+            tags: BTreeMap::new(),
+            column_tags: BTreeMap::new(),
         }
     }
 }
@@ -693,6 +751,42 @@ pub struct RenameTableReply {
     pub share_table_info: Option<(Vec<ShareSpec>, ShareObject)>,
 }
 
+/// Swap the names of two tables in the same database, the table ids are kept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapTableReq {
+    pub if_exists: bool,
+    pub name_ident: TableNameIdent,
+    pub target_table_name: String,
+}
+
+impl SwapTableReq {
+    pub fn tenant(&self) -> &Tenant {
+        &self.name_ident.tenant
+    }
+    pub fn db_name(&self) -> &str {
+        &self.name_ident.db_name
+    }
+    pub fn table_name(&self) -> &str {
+        &self.name_ident.table_name
+    }
+}
+
+impl Display for SwapTableReq {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "swap_table:{}/{}-{}<=>{}",
+            self.tenant().tenant_name(),
+            self.db_name(),
+            self.table_name(),
+            self.target_table_name
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapTableReply {}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableOptionReq {
     pub table_id: u64,
@@ -27,6 +27,7 @@ use serde::Serialize;
 #[serde(tag = "type")]
 pub enum StorageParams {
     Azblob(StorageAzblobConfig),
+    Azdls(StorageAzdlsConfig),
     Fs(StorageFsConfig),
     Ftp(StorageFtpConfig),
     Gcs(StorageGcsConfig),
@@ -61,6 +62,7 @@ impl StorageParams {
     pub fn is_secure(&self) -> bool {
         match self {
             StorageParams::Azblob(v) => v.endpoint_url.starts_with("https://"),
+            StorageParams::Azdls(v) => v.endpoint_url.starts_with("https://"),
             StorageParams::Fs(_) => false,
             StorageParams::Ftp(v) => v.endpoint.starts_with("ftps://"),
             StorageParams::Hdfs(_) => false,
@@ -83,6 +85,7 @@ impl StorageParams {
     pub fn map_root(mut self, f: impl Fn(&str) -> String) -> Self {
         match &mut self {
             StorageParams::Azblob(v) => v.root = f(&v.root),
+            StorageParams::Azdls(v) => v.root = f(&v.root),
             StorageParams::Fs(v) => v.root = f(&v.root),
             StorageParams::Ftp(v) => v.root = f(&v.root),
             StorageParams::Hdfs(v) => v.root = f(&v.root),
@@ -172,6 +175,11 @@ impl Display for StorageParams {
                 "azblob | container={},root={},endpoint={}",
                 v.container, v.root, v.endpoint_url
             ),
+            StorageParams::Azdls(v) => write!(
+                f,
+                "azdls | filesystem={},root={},endpoint={}",
+                v.filesystem, v.root, v.endpoint_url
+            ),
             StorageParams::Fs(v) => write!(f, "fs | root={}", v.root),
             StorageParams::Ftp(v) => {
                 write!(f, "ftp | root={},endpoint={}", v.root, v.endpoint)
@@ -253,6 +261,30 @@ impl Debug for StorageAzblobConfig {
     }
 }
 
+/// Config for storage backend azdls, i.e. Azure Data Lake Storage Gen2, which is an
+/// azure blob storage account with the hierarchical namespace enabled.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageAzdlsConfig {
+    /// The dfs endpoint of the account, e.g. `https://<account>.dfs.core.windows.net`
+    pub endpoint_url: String,
+    pub filesystem: String,
+    pub account_name: String,
+    pub account_key: String,
+    pub root: String,
+}
+
+impl Debug for StorageAzdlsConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("StorageAzdlsConfig")
+            .field("endpoint_url", &self.endpoint_url)
+            .field("filesystem", &self.filesystem)
+            .field("root", &self.root)
+            .field("account_name", &self.account_name)
+            .field("account_key", &mask_string(&self.account_key, 3))
+            .finish()
+    }
+}
+
 /// Config for storage backend fs.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StorageFsConfig {
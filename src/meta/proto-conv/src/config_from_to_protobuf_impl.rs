@@ -66,6 +66,9 @@ impl FromToProto for mt::storage::StorageParams {
                     mt::storage::StorageHuggingfaceConfig::from_pb(s)?,
                 ))
             }
+            Some(pb::storage_config::Storage::Azdls(s)) => Ok(mt::storage::StorageParams::Azdls(
+                mt::storage::StorageAzdlsConfig::from_pb(s)?,
+            )),
             None => Err(Incompatible {
                 reason: "StageStorage.storage cannot be None".to_string(),
             }),
@@ -101,6 +104,9 @@ impl FromToProto for mt::storage::StorageParams {
             mt::storage::StorageParams::Huggingface(v) => Ok(pb::StorageConfig {
                 storage: Some(pb::storage_config::Storage::Huggingface(v.to_pb()?)),
             }),
+            mt::storage::StorageParams::Azdls(v) => Ok(pb::StorageConfig {
+                storage: Some(pb::storage_config::Storage::Azdls(v.to_pb()?)),
+            }),
             others => Err(Incompatible {
                 reason: format!("stage type: {} not supported", others),
             }),
@@ -400,3 +406,35 @@ impl FromToProto for mt::storage::StorageHuggingfaceConfig {
         })
     }
 }
+
+impl FromToProto for mt::storage::StorageAzdlsConfig {
+    type PB = pb::AzdlsStorageConfig;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.version
+    }
+
+    fn from_pb(p: pb::AzdlsStorageConfig) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.version, p.min_reader_ver)?;
+
+        Ok(mt::storage::StorageAzdlsConfig {
+            endpoint_url: p.endpoint_url,
+            filesystem: p.filesystem,
+            account_name: p.account_name,
+            account_key: p.account_key,
+            root: p.root,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::AzdlsStorageConfig, Incompatible> {
+        Ok(pb::AzdlsStorageConfig {
+            version: VER,
+            min_reader_ver: MIN_READER_VER,
+            endpoint_url: self.endpoint_url.clone(),
+            filesystem: self.filesystem.clone(),
+            account_name: self.account_name.clone(),
+            account_key: self.account_key.clone(),
+            root: self.root.clone(),
+        })
+    }
+}
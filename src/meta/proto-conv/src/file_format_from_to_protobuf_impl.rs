@@ -60,6 +60,9 @@ impl FromToProtoEnum for mt::principal::StageFileFormatType {
             mt::principal::StageFileFormatType::None => Err(Incompatible {
                 reason: "StageFileFormatType::None cannot be converted to protobuf".to_string(),
             }),
+            mt::principal::StageFileFormatType::Plugin => Err(Incompatible {
+                reason: "StageFileFormatType::Plugin cannot be converted to protobuf".to_string(),
+            }),
         }
     }
 }
@@ -249,6 +252,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Plugin(p)) => {
+                Ok(mt::principal::FileFormatParams::Plugin(
+                    mt::principal::PluginFileFormatParams::from_pb(p)?,
+                ))
+            }
             None => Err(Incompatible {
                 reason: "FileFormatParams.format cannot be None".to_string(),
             }),
@@ -292,10 +300,49 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::OrcFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Plugin(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Plugin(
+                    mt::principal::PluginFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
 
+impl FromToProto for mt::principal::PluginFileFormatParams {
+    type PB = pb::PluginFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::PluginFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        let compression = mt::principal::StageFileCompression::from_pb_enum(
+            FromPrimitive::from_i32(p.compression).ok_or_else(|| Incompatible {
+                reason: format!("invalid StageFileCompression: {}", p.compression),
+            })?,
+        )?;
+        Ok(Self {
+            format_name: p.format_name,
+            compression,
+            options: p.options,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::PluginFileFormatParams, Incompatible> {
+        let compression =
+            mt::principal::StageFileCompression::to_pb_enum(&self.compression)? as i32;
+        Ok(pb::PluginFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            format_name: self.format_name.clone(),
+            compression,
+            options: self.options.clone(),
+        })
+    }
+}
+
 impl FromToProto for mt::principal::OrcFileFormatParams {
     type PB = pb::OrcFileFormatParams;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -53,6 +53,8 @@ impl FromToProto for mt::principal::RoleInfo {
                 Some(c) => DateTime::<Utc>::from_pb(c)?,
                 None => DateTime::<Utc>::default(),
             },
+            max_estimated_scan_bytes: p.max_estimated_scan_bytes,
+            max_estimated_memory_bytes: p.max_estimated_memory_bytes,
         })
     }
 
@@ -64,6 +66,8 @@ impl FromToProto for mt::principal::RoleInfo {
             grants: Some(mt::principal::UserGrantSet::to_pb(&self.grants)?),
             created_on: Some(self.created_on.to_pb()?),
             update_on: Some(self.update_on.to_pb()?),
+            max_estimated_scan_bytes: self.max_estimated_scan_bytes,
+            max_estimated_memory_bytes: self.max_estimated_memory_bytes,
         })
     }
 }
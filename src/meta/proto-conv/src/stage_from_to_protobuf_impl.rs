@@ -137,6 +137,7 @@ impl FromToProto for mt::principal::CopyOptions {
             disable_variant_check: p.disable_variant_check,
             return_failed_only: p.return_failed_only,
             detailed_output: false,
+            file_name_template: None,
         })
     }
 
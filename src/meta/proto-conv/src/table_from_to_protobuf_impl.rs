@@ -27,6 +27,7 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_protos::pb;
+use num::FromPrimitive;
 
 use crate::reader_check_msg;
 use crate::FromToProto;
@@ -192,6 +193,11 @@ impl FromToProto for mt::TableMeta {
             indexes.insert(name, mt::TableIndex::from_pb(index)?);
         }
 
+        let mut constraints = BTreeMap::new();
+        for (name, constraint) in p.constraints {
+            constraints.insert(name, mt::TableConstraint::from_pb(constraint)?);
+        }
+
         let v = Self {
             schema: Arc::new(ex::TableSchema::from_pb(schema)?),
             engine: p.engine,
@@ -225,6 +231,13 @@ impl FromToProto for mt::TableMeta {
                 Some(p.column_mask_policy)
             },
             indexes,
+            constraints,
+            tags: p.tags,
+            column_tags: p
+                .column_tags
+                .into_iter()
+                .map(|(column, tags)| (column, tags.values))
+                .collect(),
         };
         Ok(v)
     }
@@ -234,6 +247,10 @@ impl FromToProto for mt::TableMeta {
         for (name, index) in &self.indexes {
             indexes.insert(name.clone(), index.to_pb()?);
         }
+        let mut constraints = BTreeMap::new();
+        for (name, constraint) in &self.constraints {
+            constraints.insert(name.clone(), constraint.to_pb()?);
+        }
         let p = pb::TableMeta {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -265,6 +282,17 @@ impl FromToProto for mt::TableMeta {
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
             indexes,
+            constraints,
+            tags: self.tags.clone(),
+            column_tags: self
+                .column_tags
+                .iter()
+                .map(|(column, tags)| {
+                    (column.clone(), pb::TagValues {
+                        values: tags.clone(),
+                    })
+                })
+                .collect(),
         };
         Ok(p)
     }
@@ -358,3 +386,43 @@ impl FromToProto for mt::TableIndex {
         Ok(p)
     }
 }
+
+impl FromToProto for mt::TableConstraint {
+    type PB = pb::TableConstraint;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableConstraint) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            name: p.name,
+            constraint_type: FromPrimitive::from_i32(p.constraint_type).ok_or_else(|| {
+                Incompatible {
+                    reason: format!("invalid TableConstraintType: {}", p.constraint_type),
+                }
+            })?,
+            column_ids: p.column_ids,
+            ref_database: p.ref_database,
+            ref_table: p.ref_table,
+            ref_columns: p.ref_columns,
+            rely: p.rely,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableConstraint, Incompatible> {
+        let p = pb::TableConstraint {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            constraint_type: self.constraint_type as i32,
+            column_ids: self.column_ids.clone(),
+            ref_database: self.ref_database.clone(),
+            ref_table: self.ref_table.clone(),
+            ref_columns: self.ref_columns.clone(),
+            rely: self.rely,
+        };
+        Ok(p)
+    }
+}
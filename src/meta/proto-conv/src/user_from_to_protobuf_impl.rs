@@ -193,6 +193,9 @@ impl FromToProto for mt::principal::GrantObject {
             pb::grant_object::Object::Stage(pb::grant_object::GrantStageObject { stage }) => {
                 Ok(mt::principal::GrantObject::Stage(stage))
             }
+            pb::grant_object::Object::Warehouse(pb::grant_object::GrantWarehouseObject {
+                warehouse,
+            }) => Ok(mt::principal::GrantObject::Warehouse(warehouse)),
         }
     }
 
@@ -235,6 +238,11 @@ impl FromToProto for mt::principal::GrantObject {
                     stage: stage.clone(),
                 },
             )),
+            mt::principal::GrantObject::Warehouse(warehouse) => Some(
+                pb::grant_object::Object::Warehouse(pb::grant_object::GrantWarehouseObject {
+                    warehouse: warehouse.clone(),
+                }),
+            ),
         };
         Ok(pb::GrantObject {
             ver: VER,
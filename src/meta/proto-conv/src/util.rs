@@ -132,6 +132,12 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (100, "2024-06-21: Add: tenant.proto/TenantQuota"),
     (101, "2024-07-06: Add: add from_share_db_id field into DatabaseMeta"),
     (102, "2024-07-11: Add: UserOption add must_change_password, AuthInfo.Password add need_change"),
+    (103, "2024-07-15: Add: table.proto/TableConstraint and TableMeta add constraints"),
+    (104, "2024-07-20: Add: tag.proto/Tag, table.proto/TableMeta add tags and column_tags"),
+    (105, "2024-07-24: Add: file_format.proto/PluginFileFormatParams"),
+    (106, "2024-07-26: Add: config.proto/AzdlsStorageConfig"),
+    (107, "2024-07-29: Add: user.proto/GrantWarehouseObject"),
+    (108, "2024-08-01: Add: role.proto/RoleInfo add max_estimated_scan_bytes and max_estimated_memory_bytes"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
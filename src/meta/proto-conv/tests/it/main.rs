@@ -106,3 +106,9 @@ mod v099_parquet_format_params;
 mod v100_tenant_quota;
 mod v101_database_meta;
 mod v102_user_must_change_password;
+mod v103_table_constraints;
+mod v104_tag;
+mod v105_plugin_file_format_params;
+mod v106_azdls_config;
+mod v107_grant_warehouse_object;
+mod v108_role_estimate_limits;
@@ -245,6 +245,9 @@ fn new_table_meta() -> mt::TableMeta {
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
         indexes: btreemap! {},
+        constraints: btreemap! {},
+        tags: btreemap! {},
+        column_tags: btreemap! {},
     }
 }
 
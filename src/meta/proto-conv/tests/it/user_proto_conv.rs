@@ -105,6 +105,7 @@ pub(crate) fn test_fs_stage_info() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
 
@@ -149,6 +150,7 @@ pub(crate) fn test_s3_stage_info() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -189,6 +191,7 @@ pub(crate) fn test_s3_stage_info_v16() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -229,6 +232,7 @@ pub(crate) fn test_s3_stage_info_v14() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -265,6 +269,7 @@ pub(crate) fn test_gcs_stage_info() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -305,6 +310,7 @@ pub(crate) fn test_oss_stage_info() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -340,6 +346,7 @@ pub(crate) fn test_webhdfs_stage_info() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -376,6 +383,7 @@ pub(crate) fn test_obs_stage_info() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -412,6 +420,7 @@ pub(crate) fn test_cos_stage_info() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -852,6 +861,7 @@ pub(crate) fn test_internal_stage_info_v17() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -884,6 +894,7 @@ pub(crate) fn test_stage_info_v18() -> mt::principal::StageInfo {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -112,6 +112,7 @@ fn test_user_stage_webhdfs_v30() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -158,6 +159,7 @@ fn test_user_stage_fs_v22() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -203,6 +205,7 @@ fn test_user_stage_fs_v21() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -247,6 +250,7 @@ fn test_user_stage_fs_v20() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -292,6 +296,7 @@ fn test_user_stage_fs_v16() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -349,6 +354,7 @@ fn test_user_stage_s3_v16() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -400,6 +406,7 @@ fn test_user_stage_gcs_v16() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -456,6 +463,7 @@ fn test_user_stage_oss_v16() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -512,6 +520,7 @@ fn test_user_stage_oss_v13() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -568,6 +577,7 @@ fn test_user_stage_s3_v11() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -622,6 +632,7 @@ fn test_user_stage_s3_v8() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -667,6 +678,7 @@ fn test_user_stage_fs_v6() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -722,6 +734,7 @@ fn test_user_stage_s3_v6() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -773,6 +786,7 @@ fn test_user_stage_gcs_v6() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -817,6 +831,7 @@ fn test_user_stage_fs_v4() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -872,6 +887,7 @@ fn test_user_stage_s3_v4() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -922,6 +938,7 @@ fn test_user_stage_gcs_v4() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -974,6 +991,7 @@ fn test_user_stage_s3_v1() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -1021,6 +1039,7 @@ fn test_internal_stage_v17() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -1067,6 +1086,7 @@ fn test_user_stage_v19() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
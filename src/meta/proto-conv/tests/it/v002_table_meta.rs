@@ -143,6 +143,9 @@ fn test_decode_v2_table_meta() -> anyhow::Result<()> {
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
         indexes: btreemap! {},
+        constraints: btreemap! {},
+        tags: btreemap! {},
+        column_tags: btreemap! {},
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -147,6 +147,9 @@ fn test_decode_v12_table_meta() -> anyhow::Result<()> {
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
         indexes: btreemap! {},
+        constraints: btreemap! {},
+        tags: btreemap! {},
+        column_tags: btreemap! {},
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -71,6 +71,7 @@ fn test_decode_v30_user_stage() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
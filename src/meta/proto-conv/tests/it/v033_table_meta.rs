@@ -149,6 +149,9 @@ fn test_decode_v33_table_meta() -> anyhow::Result<()> {
         shared_by: btreeset! {1},
         column_mask_policy: None,
         indexes: btreemap! {},
+        constraints: btreemap! {},
+        tags: btreemap! {},
+        column_tags: btreemap! {},
     };
 
     common::test_pb_from_to(func_name!(), want())?;
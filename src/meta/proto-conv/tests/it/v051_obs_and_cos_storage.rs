@@ -72,6 +72,7 @@ fn test_decode_v51_obs_stage() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -122,6 +123,7 @@ fn test_decode_v51_cos_stage() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         ..Default::default()
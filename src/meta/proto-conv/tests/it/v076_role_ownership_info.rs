@@ -92,6 +92,8 @@ fn test_decode_v76_role() -> anyhow::Result<()> {
         ),
         created_on: DateTime::<Utc>::default(),
         update_on: DateTime::<Utc>::default(),
+        max_estimated_scan_bytes: None,
+        max_estimated_memory_bytes: None,
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), role_info_v76.as_slice(), 76, want())?;
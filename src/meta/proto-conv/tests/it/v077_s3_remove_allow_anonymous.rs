@@ -71,6 +71,7 @@ fn test_v077_s3_remove_allow_anonymous() -> anyhow::Result<()> {
             disable_variant_check: true,
             return_failed_only: false,
             detailed_output: false,
+            file_name_template: None,
         },
         comment: "test".to_string(),
         number_of_files: 100,
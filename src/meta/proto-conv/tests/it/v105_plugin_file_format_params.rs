@@ -0,0 +1,51 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::principal::PluginFileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+use maplit::btreemap;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v105_plugin_file_format_params() -> anyhow::Result<()> {
+    let plugin_file_format_params_v105 = vec![
+        10, 5, 77, 89, 82, 69, 67, 16, 1, 26, 8, 10, 3, 115, 101, 112, 18, 1, 124, 160, 6, 105,
+        168, 6, 24,
+    ];
+
+    let want = || PluginFileFormatParams {
+        format_name: "MYREC".to_string(),
+        compression: StageFileCompression::Gzip,
+        options: btreemap! {"sep".to_string() => "|".to_string()},
+    };
+    common::test_load_old(
+        func_name!(),
+        plugin_file_format_params_v105.as_slice(),
+        105,
+        want(),
+    )?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
@@ -0,0 +1,53 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::storage::StorageAzdlsConfig;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v106_azdls_config() -> anyhow::Result<()> {
+    let storage_azdls_config_v106 = vec![
+        10, 37, 104, 116, 116, 112, 115, 58, 47, 47, 100, 97, 116, 97, 98, 101, 110, 100, 46, 100,
+        102, 115, 46, 99, 111, 114, 101, 46, 119, 105, 110, 100, 111, 119, 115, 46, 110, 101, 116,
+        18, 4, 100, 97, 116, 97, 26, 8, 100, 97, 116, 97, 98, 101, 110, 100, 34, 3, 107, 101, 121,
+        42, 7, 47, 115, 116, 97, 103, 101, 47, 160, 6, 106, 168, 6, 24,
+    ];
+
+    let want = || StorageAzdlsConfig {
+        endpoint_url: "https://databend.dfs.core.windows.net".to_string(),
+        filesystem: "data".to_string(),
+        account_name: "databend".to_string(),
+        account_key: "key".to_string(),
+        root: "/stage/".to_string(),
+    };
+    common::test_load_old(
+        func_name!(),
+        storage_azdls_config_v106.as_slice(),
+        106,
+        want(),
+    )?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
@@ -1242,6 +1242,8 @@ pub enum BinaryOperator {
     Xor,
     Like,
     NotLike,
+    ILike,
+    NotILike,
     Regexp,
     RLike,
     NotRegexp,
@@ -1352,6 +1354,12 @@ impl Display for BinaryOperator {
             BinaryOperator::NotLike => {
                 write!(f, "NOT LIKE")
             }
+            BinaryOperator::ILike => {
+                write!(f, "ILIKE")
+            }
+            BinaryOperator::NotILike => {
+                write!(f, "NOT ILIKE")
+            }
             BinaryOperator::Regexp => {
                 write!(f, "REGEXP")
             }
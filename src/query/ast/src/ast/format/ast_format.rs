@@ -732,6 +732,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 ExplainKind::Memo(_) => "Memo",
                 ExplainKind::Join => "Join",
                 ExplainKind::AnalyzePlan => "Analyze",
+                ExplainKind::Verify => "Verify",
             },
             if options.is_empty() {
                 "".to_string()
@@ -745,6 +746,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                                 ExplainOption::Verbose => "Verbose",
                                 ExplainOption::Logical => "Logical",
                                 ExplainOption::Optimized => "Optimized",
+                                ExplainOption::FormatJson => "FormatJson",
                             }
                         })
                         .join(", ")
@@ -1036,6 +1038,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_kill_where(&mut self, stmt: &'ast KillWhereStmt) {
+        let mut children = Vec::with_capacity(1);
+        self.visit_expr(&stmt.selection);
+        children.push(self.children.pop().unwrap());
+
+        let name = format!("KillWhere {}", stmt.kill_target);
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_set_variable(
         &mut self,
         is_global: bool,
@@ -1286,6 +1299,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterDatabaseAction::SetReadOnly { read_only } => {
+                let action_name = format!("Action SetReadOnly {}", read_only);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterDatabase".to_string();
@@ -1294,6 +1312,30 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_export_database(&mut self, stmt: &'ast ExportDatabaseStmt) {
+        self.visit_database_ref(&stmt.catalog, &stmt.database);
+        let database_child = self.children.pop().unwrap();
+        let location_format_ctx = AstFormatContext::new(format!("Location @{}", stmt.location));
+        let location_child = FormatTreeNode::new(location_format_ctx);
+
+        let name = "ExportDatabase".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![database_child, location_child]);
+        self.children.push(node);
+    }
+
+    fn visit_import_database(&mut self, stmt: &'ast ImportDatabaseStmt) {
+        self.visit_database_ref(&stmt.catalog, &stmt.database);
+        let database_child = self.children.pop().unwrap();
+        let location_format_ctx = AstFormatContext::new(format!("Location @{}", stmt.location));
+        let location_child = FormatTreeNode::new(location_format_ctx);
+
+        let name = "ImportDatabase".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![database_child, location_child]);
+        self.children.push(node);
+    }
+
     fn visit_use_database(&mut self, database: &'ast Identifier) {
         self.visit_identifier(database);
         let child = self.children.pop().unwrap();
@@ -1455,7 +1497,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
-            CreateTableSource::Columns(columns, inverted_indexes) => {
+            CreateTableSource::Columns(columns, inverted_indexes, constraints) => {
                 let mut children = Vec::with_capacity(columns.len());
                 for column in columns.iter() {
                     self.visit_column_definition(column);
@@ -1467,6 +1509,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                         children.push(self.children.pop().unwrap());
                     }
                 }
+                if let Some(constraints) = constraints {
+                    for constraint in constraints {
+                        self.visit_constraint_definition(constraint);
+                        children.push(self.children.pop().unwrap());
+                    }
+                }
                 let name = "ColumnsDefinition".to_string();
                 let format_ctx = AstFormatContext::with_children(name, children.len());
                 let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1518,6 +1566,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_constraint_definition(&mut self, constraint_definition: &'ast ConstraintDefinition) {
+        let mut column_nodes = Vec::with_capacity(constraint_definition.columns.len());
+        for column in &constraint_definition.columns {
+            let column_name = format!("Column {}", column);
+            let column_format_ctx = AstFormatContext::new(column_name);
+            let column_node = FormatTreeNode::new(column_format_ctx);
+            column_nodes.push(column_node);
+        }
+        let name = format!("ConstraintDefinition {}", constraint_definition);
+        let format_ctx = AstFormatContext::with_children(name, column_nodes.len());
+        let node = FormatTreeNode::with_children(format_ctx, column_nodes);
+        self.children.push(node);
+    }
+
     fn visit_drop_table(&mut self, stmt: &'ast DropTableStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let child = self.children.pop().unwrap();
@@ -1597,6 +1659,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                         format!("Action ModifyColumn column {}", column),
                         "Action ConvertStoredComputedColumn".to_string(),
                     ),
+                    ModifyColumnAction::SetTags(column, tags) => (
+                        format!("Action ModifyColumn column {}", column),
+                        format!(
+                            "Action SetTags {}",
+                            tags.iter()
+                                .map(|(name, value)| format!("{name} to {value}"))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    ),
+                    ModifyColumnAction::UnsetTags(column, tags) => (
+                        format!("Action ModifyColumn column {}", column),
+                        format!("Action UnsetTags {}", tags.join(", ")),
+                    ),
                 };
                 let child_format_ctx = AstFormatContext::new(child_name);
                 let child = FormatTreeNode::new(child_format_ctx);
@@ -1626,13 +1702,21 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 FormatTreeNode::new(action_format_ctx)
             }
             AlterTableAction::ReclusterTable {
-                selection, limit, ..
+                selection,
+                last_segments,
+                limit,
+                ..
             } => {
                 let mut children = Vec::new();
                 if let Some(selection) = selection {
                     self.visit_expr(selection);
                     children.push(self.children.pop().unwrap());
                 }
+                if let Some(last_segments) = last_segments {
+                    let name = format!("LastSegments {}", last_segments);
+                    let last_segments_format_ctx = AstFormatContext::new(name);
+                    children.push(FormatTreeNode::new(last_segments_format_ctx));
+                }
                 if let Some(limit) = limit {
                     let name = format!("Limit {}", limit);
                     let limit_format_ctx = AstFormatContext::new(name);
@@ -1658,6 +1742,39 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::SetTags { tags } => {
+                let action_name = format!(
+                    "Action SetTags {}",
+                    tags.iter()
+                        .map(|(name, value)| format!("{name} to {value}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::UnsetTags { tags } => {
+                let action_name = format!("Action UnsetTags {}", tags.join(", "));
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::SetStoragePolicy {
+                cold_after_days,
+                cold_location,
+            } => {
+                let action_name = format!(
+                    "Action SetStoragePolicy cold after {} days to {}",
+                    cold_after_days,
+                    cold_location.mask()
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::UnsetStoragePolicy => {
+                let action_format_ctx =
+                    AstFormatContext::new("Action UnsetStoragePolicy".to_string());
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1748,6 +1865,42 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_verify_table(&mut self, stmt: &'ast VerifyTableStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let child = self.children.pop().unwrap();
+
+        let name = "VerifyTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_table_statistics(&mut self, stmt: &'ast RefreshTableStatisticsStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshTableStatistics".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_restore_table(&mut self, stmt: &'ast RestoreTableStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let mut children = vec![self.children.pop().unwrap()];
+        let location_format_ctx = AstFormatContext::new(format!("Location @{}", stmt.location));
+        children.push(FormatTreeNode::new(location_format_ctx));
+        if let Some(point) = &stmt.point {
+            self.visit_time_travel_point(point);
+            children.push(self.children.pop().unwrap());
+        }
+
+        let name = "RestoreTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_exists_table(&mut self, stmt: &'ast ExistsTableStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let child = self.children.pop().unwrap();
@@ -2266,6 +2419,21 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_alter_role(&mut self, stmt: &'ast AlterRoleStmt) {
+        let role_name = format!("Role {}", stmt.role_name);
+        let role_format_ctx = AstFormatContext::new(role_name);
+        let mut children = vec![FormatTreeNode::new(role_format_ctx)];
+        for (name, value) in stmt.options.iter() {
+            let option_format_ctx = AstFormatContext::new(format!("{name} = {value}"));
+            children.push(FormatTreeNode::new(option_format_ctx));
+        }
+
+        let name = "AlterRole".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_grant(&mut self, grant: &'ast GrantStmt) {
         let source_child = match &grant.source {
             AccountMgrSource::Role { role } => {
@@ -3020,6 +3188,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_show_options(show_options, "ShowPasswordPolicies".to_string());
     }
 
+    fn visit_create_tag(&mut self, stmt: &'ast CreateTagStmt) {
+        let ctx = AstFormatContext::new(format!("TagName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateTag".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_tag(&mut self, stmt: &'ast AlterTagStmt) {
+        let ctx = AstFormatContext::new(format!("TagName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterTag".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_tag(&mut self, stmt: &'ast DropTagStmt) {
+        let ctx = AstFormatContext::new(format!("TagName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropTag".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -3437,6 +3635,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, children);
                 self.children.push(node)
             }
+            TableReference::JsonTable {
+                span: _,
+                json_table,
+                alias,
+            } => {
+                self.visit_expr(&json_table.input);
+                let mut children = vec![self.children.pop().unwrap()];
+                children.push(FormatTreeNode::new(AstFormatContext::new(format!(
+                    "Path {}",
+                    json_table.path
+                ))));
+                for column in json_table.columns.iter() {
+                    children.push(FormatTreeNode::new(AstFormatContext::new(format!(
+                        "Column {}",
+                        column
+                    ))));
+                }
+                let name = "JsonTable".to_string();
+                let format_ctx = if let Some(alias) = alias {
+                    AstFormatContext::with_children_alias(
+                        name,
+                        children.len(),
+                        Some(format!("{}", alias)),
+                    )
+                } else {
+                    AstFormatContext::with_children(name, children.len())
+                };
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node)
+            }
         }
     }
 
@@ -114,7 +114,7 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
 
 fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
     match source {
-        CreateTableSource::Columns(columns, inverted_indexes) => RcDoc::space()
+        CreateTableSource::Columns(columns, inverted_indexes, constraints) => RcDoc::space()
             .append(parenthesized(
                 interweave_comma(
                     columns
@@ -134,6 +134,18 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 )
             } else {
                 RcDoc::nil()
+            })
+            .append(if let Some(constraints) = constraints {
+                parenthesized(
+                    interweave_comma(
+                        constraints
+                            .into_iter()
+                            .map(|constraint| RcDoc::text(constraint.to_string())),
+                    )
+                    .group(),
+                )
+            } else {
+                RcDoc::nil()
             }),
         CreateTableSource::Like {
             catalog,
@@ -216,6 +228,7 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::ReclusterTable {
             is_final,
             selection,
+            last_segments,
             limit,
         } => RcDoc::line()
             .append(RcDoc::text("RECLUSTER"))
@@ -233,6 +246,11 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             } else {
                 RcDoc::nil()
             })
+            .append(if let Some(last_segments) = last_segments {
+                RcDoc::text(format!(" LAST {last_segments} SEGMENTS"))
+            } else {
+                RcDoc::nil()
+            })
             .append(if let Some(limit) = limit {
                 RcDoc::text(format!(" LIMIT {limit}"))
             } else {
@@ -272,6 +290,9 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        action @ (AlterTableAction::SetTags { .. } | AlterTableAction::UnsetTags { .. }) => {
+            RcDoc::line().append(RcDoc::text(action.to_string()))
+        }
     }
 }
 
@@ -452,6 +452,15 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             }),
+        TableReference::JsonTable {
+            span: _,
+            json_table,
+            alias,
+        } => RcDoc::text(json_table.to_string()).append(if let Some(a) = alias {
+            RcDoc::text(format!(" AS {a}"))
+        } else {
+            RcDoc::nil()
+        }),
     }
 }
 
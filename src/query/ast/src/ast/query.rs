@@ -19,6 +19,7 @@ use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
 use super::Lambda;
+use crate::ast::quote::QuotedString;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_dot_separated_list;
 use crate::ast::Expr;
@@ -26,6 +27,7 @@ use crate::ast::FileLocation;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::SelectStageOptions;
+use crate::ast::TypeName;
 use crate::ast::WindowDefinition;
 use crate::Span;
 
@@ -652,6 +654,12 @@ pub enum TableReference {
         options: SelectStageOptions,
         alias: Option<TableAlias>,
     },
+    // `JSON_TABLE(expr, 'path' COLUMNS (...))[ AS alias ]`
+    JsonTable {
+        span: Span,
+        json_table: Box<JsonTable>,
+        alias: Option<TableAlias>,
+    },
 }
 
 impl TableReference {
@@ -679,6 +687,8 @@ impl TableReference {
     pub fn is_lateral_subquery(&self) -> bool {
         match self {
             TableReference::Subquery { lateral, .. } => *lateral,
+            // JSON_TABLE can always refer to the preceding tables.
+            TableReference::JsonTable { .. } => true,
             _ => false,
         }
     }
@@ -824,11 +834,108 @@ impl Display for TableReference {
                     write!(f, " AS {alias}")?;
                 }
             }
+            TableReference::JsonTable {
+                span: _,
+                json_table,
+                alias,
+            } => {
+                write!(f, "{json_table}")?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+            }
         }
         Ok(())
     }
 }
 
+/// `JSON_TABLE(expr, 'path' COLUMNS (...))`, each match of the path in `expr` is a row.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct JsonTable {
+    pub input: Box<Expr>,
+    pub path: String,
+    pub columns: Vec<JsonTableColumn>,
+}
+
+impl Display for JsonTable {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "JSON_TABLE({}, {} COLUMNS (",
+            self.input,
+            QuotedString(&self.path, '\'')
+        )?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, "))")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum JsonTableColumn {
+    /// `name FOR ORDINALITY`
+    Ordinality { name: Identifier },
+    /// `name type PATH 'path' [behavior ON EMPTY] [behavior ON ERROR]`
+    Path {
+        name: Identifier,
+        data_type: TypeName,
+        path: String,
+        on_empty: Option<JsonTableBehavior>,
+        on_error: Option<JsonTableBehavior>,
+    },
+    /// `NESTED [PATH] 'path' COLUMNS (...)`
+    Nested {
+        path: String,
+        columns: Vec<JsonTableColumn>,
+    },
+}
+
+impl Display for JsonTableColumn {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            JsonTableColumn::Ordinality { name } => write!(f, "{name} FOR ORDINALITY"),
+            JsonTableColumn::Path {
+                name,
+                data_type,
+                path,
+                on_empty,
+                on_error,
+            } => {
+                write!(f, "{name} {data_type} PATH {}", QuotedString(path, '\''))?;
+                if let Some(on_empty) = on_empty {
+                    write!(f, " {on_empty} ON EMPTY")?;
+                }
+                if let Some(on_error) = on_error {
+                    write!(f, " {on_error} ON ERROR")?;
+                }
+                Ok(())
+            }
+            JsonTableColumn::Nested { path, columns } => {
+                write!(f, "NESTED PATH {} COLUMNS (", QuotedString(path, '\''))?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// What a JSON_TABLE column returns if the path matches nothing or the value can't be cast.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum JsonTableBehavior {
+    Null,
+    Error,
+    Default(Expr),
+}
+
+impl Display for JsonTableBehavior {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            JsonTableBehavior::Null => write!(f, "NULL"),
+            JsonTableBehavior::Error => write!(f, "ERROR"),
+            JsonTableBehavior::Default(expr) => write!(f, "DEFAULT {expr}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct TableAlias {
     pub name: Identifier,
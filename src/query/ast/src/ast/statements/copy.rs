@@ -149,11 +149,12 @@ pub struct CopyIntoLocationStmt {
     pub with: Option<With>,
     pub hints: Option<Hint>,
     pub src: CopyIntoLocationSource,
-    pub dst: FileLocation,
+    pub dst: CopyIntoLocationTarget,
     pub file_format: FileFormatOptions,
     pub single: bool,
     pub max_file_size: usize,
     pub detailed_output: bool,
+    pub file_name_template: Option<String>,
 }
 
 impl Display for CopyIntoLocationStmt {
@@ -174,11 +175,57 @@ impl Display for CopyIntoLocationStmt {
         write!(f, " SINGLE = {}", self.single)?;
         write!(f, " MAX_FILE_SIZE = {}", self.max_file_size)?;
         write!(f, " DETAILED_OUTPUT = {}", self.detailed_output)?;
+        if let Some(template) = &self.file_name_template {
+            write!(f, " FILE_NAME_TEMPLATE = {}", QuotedString(template, '\''))?;
+        }
 
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum CopyIntoLocationTarget {
+    Location(FileLocation),
+    /// `COPY INTO ('<url>' [, '<url>' ...])`, the files are uploaded to the urls in order,
+    /// which are usually presigned by the downstream systems.
+    Urls(#[drive(skip)] Vec<String>),
+}
+
+impl CopyIntoLocationTarget {
+    /// Hide the signatures in the queries of the urls.
+    pub fn mask(&self) -> Self {
+        match self {
+            CopyIntoLocationTarget::Location(FileLocation::Uri(location)) => {
+                CopyIntoLocationTarget::Location(FileLocation::Uri(location.mask()))
+            }
+            CopyIntoLocationTarget::Location(location) => {
+                CopyIntoLocationTarget::Location(location.clone())
+            }
+            CopyIntoLocationTarget::Urls(urls) => CopyIntoLocationTarget::Urls(
+                urls.iter()
+                    .map(|url| match url.split_once('?') {
+                        Some((url, _)) => format!("{url}?******"),
+                        None => url.clone(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl Display for CopyIntoLocationTarget {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            CopyIntoLocationTarget::Location(location) => write!(f, "{location}"),
+            CopyIntoLocationTarget::Urls(urls) => {
+                write!(f, "(")?;
+                write_comma_separated_string_list(f, urls)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 impl CopyIntoLocationStmt {
     pub fn apply_option(&mut self, opt: CopyIntoLocationOption) {
         match opt {
@@ -186,6 +233,7 @@ impl CopyIntoLocationStmt {
             CopyIntoLocationOption::Single(v) => self.single = v,
             CopyIntoLocationOption::MaxFileSize(v) => self.max_file_size = v,
             CopyIntoLocationOption::DetailedOutput(v) => self.detailed_output = v,
+            CopyIntoLocationOption::FileNameTemplate(v) => self.file_name_template = Some(v),
         }
     }
 }
@@ -457,6 +505,7 @@ pub enum CopyIntoLocationOption {
     MaxFileSize(usize),
     Single(bool),
     DetailedOutput(bool),
+    FileNameTemplate(String),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Drive, DriveMut)]
@@ -136,6 +136,38 @@ impl Display for UndropDatabaseStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct ExportDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    pub location: String,
+}
+
+impl Display for ExportDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "EXPORT DATABASE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " TO @{}", self.location)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct ImportDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    pub location: String,
+}
+
+impl Display for ImportDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "IMPORT DATABASE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " FROM @{}", self.location)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct AlterDatabaseStmt {
     pub if_exists: bool,
@@ -155,6 +187,9 @@ impl Display for AlterDatabaseStmt {
             AlterDatabaseAction::RenameDatabase { new_db } => {
                 write!(f, " RENAME TO {new_db}")?;
             }
+            AlterDatabaseAction::SetReadOnly { read_only } => {
+                write!(f, " SET READ_ONLY = {read_only}")?;
+            }
         }
 
         Ok(())
@@ -164,6 +199,7 @@ impl Display for AlterDatabaseStmt {
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub enum AlterDatabaseAction {
     RenameDatabase { new_db: Identifier },
+    SetReadOnly { read_only: bool },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
@@ -37,6 +37,9 @@ pub enum ExplainKind {
 
     // Explain analyze plan
     AnalyzePlan,
+
+    // Estimate the resources of the query and check them against the limits
+    Verify,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
@@ -44,4 +47,6 @@ pub enum ExplainOption {
     Verbose,
     Logical,
     Optimized,
+    // `FORMAT = JSON`, output a machine-readable plan
+    FormatJson,
 }
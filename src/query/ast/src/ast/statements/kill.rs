@@ -17,6 +17,9 @@ use std::fmt::Formatter;
 
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
+
+use crate::ast::Expr;
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub enum KillTarget {
     Query,
@@ -31,3 +34,16 @@ impl Display for KillTarget {
         }
     }
 }
+
+/// Kill all the queries (or connections) in `system.processes` matching the selection.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct KillWhereStmt {
+    pub kill_target: KillTarget,
+    pub selection: Expr,
+}
+
+impl Display for KillWhereStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "KILL {} WHERE {}", self.kill_target, self.selection)
+    }
+}
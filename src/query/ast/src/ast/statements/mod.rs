@@ -48,6 +48,7 @@ mod statement;
 mod stream;
 mod system_action;
 mod table;
+mod tag;
 mod task;
 mod udf;
 mod unset;
@@ -92,6 +93,7 @@ pub use statement::*;
 pub use stream::*;
 pub use system_action::*;
 pub use table::*;
+pub use tag::*;
 pub use task::*;
 pub use udf::*;
 pub use unset::*;
@@ -28,6 +28,7 @@ use crate::ast::quote::QuotedString;
 use crate::ast::statements::connection::CreateConnectionStmt;
 use crate::ast::statements::pipe::CreatePipeStmt;
 use crate::ast::statements::task::CreateTaskStmt;
+use crate::ast::write_comma_separated_list;
 use crate::ast::CreateOption;
 use crate::ast::Expr;
 use crate::ast::Identifier;
@@ -46,6 +47,10 @@ pub enum Statement {
     ExplainAnalyze {
         query: Box<Statement>,
     },
+    StatementWithSettings {
+        settings: Vec<HintItem>,
+        stmt: Box<Statement>,
+    },
 
     CopyIntoTable(CopyIntoTableStmt),
     CopyIntoLocation(CopyIntoLocationStmt),
@@ -77,11 +82,13 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
     ShowLocks(ShowLocksStmt),
+    ShowWarnings,
 
     KillStmt {
         kill_target: KillTarget,
         object_id: String,
     },
+    KillWhere(KillWhereStmt),
 
     SetVariable {
         is_global: bool,
@@ -121,6 +128,8 @@ pub enum Statement {
     DropDatabase(DropDatabaseStmt),
     UndropDatabase(UndropDatabaseStmt),
     AlterDatabase(AlterDatabaseStmt),
+    ExportDatabase(ExportDatabaseStmt),
+    ImportDatabase(ImportDatabaseStmt),
     UseDatabase {
         database: Identifier,
     },
@@ -142,7 +151,11 @@ pub enum Statement {
     VacuumTable(VacuumTableStmt),
     VacuumDropTable(VacuumDropTableStmt),
     VacuumTemporaryFiles(VacuumTemporaryFiles),
+    VacuumSystemHistory(VacuumSystemHistory),
     AnalyzeTable(AnalyzeTableStmt),
+    VerifyTable(VerifyTableStmt),
+    RefreshTableStatistics(RefreshTableStatisticsStmt),
+    RestoreTable(RestoreTableStmt),
     ExistsTable(ExistsTableStmt),
 
     // Dictionaries
@@ -201,6 +214,7 @@ pub enum Statement {
         if_exists: bool,
         role_name: String,
     },
+    AlterRole(AlterRoleStmt),
     Grant(GrantStmt),
     ShowGrants {
         principal: Option<PrincipalIdentity>,
@@ -289,6 +303,11 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
 
+    // tag
+    CreateTag(CreateTagStmt),
+    AlterTag(AlterTagStmt),
+    DropTag(DropTagStmt),
+
     // tasks
     CreateTask(CreateTaskStmt),
     AlterTask(AlterTaskStmt),
@@ -349,9 +368,7 @@ impl Statement {
             Statement::CopyIntoLocation(copy) => {
                 let mut copy_clone = copy.clone();
 
-                if let FileLocation::Uri(location) = &mut copy_clone.dst {
-                    location.connection = location.connection.mask()
-                }
+                copy_clone.dst = copy_clone.dst.mask();
                 format!("{}", Statement::CopyIntoLocation(copy_clone))
             }
             Statement::CreateStage(stage) => {
@@ -391,6 +408,7 @@ impl Display for Statement {
                                     ExplainOption::Verbose => "VERBOSE",
                                     ExplainOption::Logical => "LOGICAL",
                                     ExplainOption::Optimized => "OPTIMIZED",
+                                    ExplainOption::FormatJson => "FORMAT = JSON",
                                 }
                             })
                             .join(", ")
@@ -408,12 +426,23 @@ impl Display for Statement {
                     ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
                     ExplainKind::Join => write!(f, " JOIN")?,
                     ExplainKind::Memo(_) => write!(f, " MEMO")?,
+                    ExplainKind::Verify => write!(f, " VERIFY")?,
                 }
                 write!(f, " {query}")?;
             }
             Statement::ExplainAnalyze { query } => {
                 write!(f, "EXPLAIN ANALYZE {query}")?;
             }
+            Statement::StatementWithSettings { settings, stmt } => {
+                write!(f, "SETTINGS (")?;
+                write_comma_separated_list(
+                    f,
+                    settings
+                        .iter()
+                        .map(|setting| format!("{} = {}", setting.name, setting.expr)),
+                )?;
+                write!(f, ") {stmt}")?;
+            }
             Statement::Query(stmt) => write!(f, "{stmt}")?,
             Statement::Insert(stmt) => write!(f, "{stmt}")?,
             Statement::InsertMultiTable(insert_multi_table) => write!(f, "{insert_multi_table}")?,
@@ -472,6 +501,7 @@ impl Display for Statement {
                 }
             }
             Statement::ShowLocks(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowWarnings => write!(f, "SHOW WARNINGS")?,
             Statement::KillStmt {
                 kill_target,
                 object_id,
@@ -483,6 +513,7 @@ impl Display for Statement {
                 }
                 write!(f, " '{object_id}'")?;
             }
+            Statement::KillWhere(stmt) => write!(f, "{stmt}")?,
             Statement::SetVariable {
                 is_global,
                 variable,
@@ -522,6 +553,8 @@ impl Display for Statement {
             Statement::DropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UndropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::AlterDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::ExportDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::ImportDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UseDatabase { database } => write!(f, "USE {database}")?,
             Statement::ShowTables(stmt) => write!(f, "{stmt}")?,
             Statement::ShowColumns(stmt) => write!(f, "{stmt}")?,
@@ -540,7 +573,11 @@ impl Display for Statement {
             Statement::VacuumTable(stmt) => write!(f, "{stmt}")?,
             Statement::VacuumDropTable(stmt) => write!(f, "{stmt}")?,
             Statement::VacuumTemporaryFiles(stmt) => write!(f, "{stmt}")?,
+            Statement::VacuumSystemHistory(stmt) => write!(f, "{stmt}")?,
             Statement::AnalyzeTable(stmt) => write!(f, "{stmt}")?,
+            Statement::VerifyTable(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshTableStatistics(stmt) => write!(f, "{stmt}")?,
+            Statement::RestoreTable(stmt) => write!(f, "{stmt}")?,
             Statement::ExistsTable(stmt) => write!(f, "{stmt}")?,
             Statement::CreateDictionary(stmt) => write!(f, "{stmt}")?,
             Statement::DropDictionary(stmt) => write!(f, "{stmt}")?,
@@ -602,6 +639,7 @@ impl Display for Statement {
                 }
                 write!(f, " '{role}'")?;
             }
+            Statement::AlterRole(stmt) => write!(f, "{stmt}")?,
             Statement::Grant(stmt) => write!(f, "{stmt}")?,
             Statement::ShowGrants {
                 principal,
@@ -711,6 +749,9 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::CreateTag(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterTag(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTag(stmt) => write!(f, "{stmt}")?,
             Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
             Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
@@ -18,6 +18,8 @@ use std::fmt::Formatter;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
+use crate::ast::quote::QuotedString;
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct SystemStmt {
     pub action: SystemAction,
@@ -32,6 +34,20 @@ impl Display for SystemStmt {
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub enum SystemAction {
     Backtrace(bool),
+    CancelAllQueries {
+        warehouse: String,
+    },
+    /// Suspends the node, the local node if no node is given.
+    SuspendNode {
+        node_id: Option<String>,
+    },
+    ResumeNode {
+        node_id: Option<String>,
+    },
+    /// Re-executes a query from its replay bundle.
+    ReplayQuery {
+        query_id: String,
+    },
 }
 
 impl Display for SystemAction {
@@ -41,6 +57,26 @@ impl Display for SystemAction {
                 true => write!(f, "ENABLE EXCEPTION_BACKTRACE"),
                 false => write!(f, "DISABLE EXCEPTION_BACKTRACE"),
             },
+            SystemAction::CancelAllQueries { warehouse } => {
+                write!(f, "CANCEL ALL QUERIES ON WAREHOUSE {warehouse}")
+            }
+            SystemAction::SuspendNode { node_id } => {
+                write!(f, "SUSPEND NODE")?;
+                if let Some(node_id) = node_id {
+                    write!(f, " {}", QuotedString(node_id, '\''))?;
+                }
+                Ok(())
+            }
+            SystemAction::ResumeNode { node_id } => {
+                write!(f, "RESUME NODE")?;
+                if let Some(node_id) = node_id {
+                    write!(f, " {}", QuotedString(node_id, '\''))?;
+                }
+                Ok(())
+            }
+            SystemAction::ReplayQuery { query_id } => {
+                write!(f, "REPLAY QUERY {}", QuotedString(query_id, '\''))
+            }
         }
     }
 }
@@ -223,7 +223,11 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>, Option<Vec<InvertedIndexDefinition>>),
+    Columns(
+        Vec<ColumnDefinition>,
+        Option<Vec<InvertedIndexDefinition>>,
+        Option<Vec<ConstraintDefinition>>,
+    ),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
@@ -234,13 +238,17 @@ pub enum CreateTableSource {
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns, inverted_indexes) => {
+            CreateTableSource::Columns(columns, inverted_indexes, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
                 if let Some(inverted_indexes) = inverted_indexes {
                     write!(f, ", ")?;
                     write_comma_separated_list(f, inverted_indexes)?;
                 }
+                if let Some(constraints) = constraints {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -281,6 +289,7 @@ pub struct DropTableStmt {
     pub database: Option<Identifier>,
     pub table: Identifier,
     pub all: bool,
+    pub drop_behavior: Option<DropBehavior>,
 }
 
 impl Display for DropTableStmt {
@@ -299,11 +308,30 @@ impl Display for DropTableStmt {
         if self.all {
             write!(f, " ALL")?;
         }
+        if let Some(drop_behavior) = &self.drop_behavior {
+            write!(f, " {drop_behavior}")?;
+        }
 
         Ok(())
     }
 }
 
+/// Whether dropping an object is refused when other objects depend on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum DropBehavior {
+    Restrict,
+    Cascade,
+}
+
+impl Display for DropBehavior {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            DropBehavior::Restrict => write!(f, "RESTRICT"),
+            DropBehavior::Cascade => write!(f, "CASCADE"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct UndropTableStmt {
     pub catalog: Option<Identifier>,
@@ -347,6 +375,9 @@ pub enum AlterTableAction {
     RenameTable {
         new_table: Identifier,
     },
+    SwapWith {
+        target_table: Identifier,
+    },
     AddColumn {
         column: ColumnDefinition,
         option: AddColumnOption,
@@ -371,6 +402,8 @@ pub enum AlterTableAction {
     ReclusterTable {
         is_final: bool,
         selection: Option<Expr>,
+        /// Only the newest N segments of the table are reclustered.
+        last_segments: Option<u64>,
         limit: Option<u64>,
     },
     FlashbackTo {
@@ -379,6 +412,18 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    SetTags {
+        tags: BTreeMap<String, String>,
+    },
+    UnsetTags {
+        tags: Vec<String>,
+    },
+    /// The blocks of the segments older than `cold_after_days` are moved to `cold_location`.
+    SetStoragePolicy {
+        cold_after_days: u64,
+        cold_location: UriLocation,
+    },
+    UnsetStoragePolicy,
 }
 
 impl Display for AlterTableAction {
@@ -392,6 +437,9 @@ impl Display for AlterTableAction {
             AlterTableAction::RenameTable { new_table } => {
                 write!(f, "RENAME TO {new_table}")?;
             }
+            AlterTableAction::SwapWith { target_table } => {
+                write!(f, "SWAP WITH {target_table}")?;
+            }
             AlterTableAction::ModifyTableComment { new_comment } => {
                 write!(f, "COMMENT='{new_comment}'")?;
             }
@@ -421,6 +469,7 @@ impl Display for AlterTableAction {
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
+                last_segments,
                 limit,
             } => {
                 write!(f, "RECLUSTER")?;
@@ -430,6 +479,9 @@ impl Display for AlterTableAction {
                 if let Some(conditions) = selection {
                     write!(f, " WHERE {conditions}")?;
                 }
+                if let Some(last_segments) = last_segments {
+                    write!(f, " LAST {last_segments} SEGMENTS")?;
+                }
                 if let Some(limit) = limit {
                     write!(f, " LIMIT {limit}")?;
                 }
@@ -437,6 +489,26 @@ impl Display for AlterTableAction {
             AlterTableAction::FlashbackTo { point } => {
                 write!(f, "FLASHBACK TO {}", point)?;
             }
+            AlterTableAction::SetTags { tags } => {
+                write!(f, "SET TAG ")?;
+                write_comma_separated_string_map(f, tags)?;
+            }
+            AlterTableAction::UnsetTags { tags } => {
+                write!(f, "UNSET TAG ")?;
+                write_comma_separated_list(f, tags)?;
+            }
+            AlterTableAction::SetStoragePolicy {
+                cold_after_days,
+                cold_location,
+            } => {
+                write!(
+                    f,
+                    "SET STORAGE_POLICY COLD_AFTER_DAYS = {cold_after_days} COLD_LOCATION = {cold_location}"
+                )?;
+            }
+            AlterTableAction::UnsetStoragePolicy => {
+                write!(f, "UNSET STORAGE_POLICY")?;
+            }
         };
         Ok(())
     }
@@ -589,6 +661,30 @@ impl Display for crate::ast::VacuumTemporaryFiles {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct VacuumSystemHistory {
+    #[drive(skip)]
+    pub retain: Option<Duration>,
+}
+
+impl Display for VacuumSystemHistory {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "VACUUM SYSTEM HISTORY")?;
+        if let Some(retain) = &self.retain {
+            let days = Duration::from_secs(60 * 60 * 24);
+            if retain >= &days {
+                let days = retain.as_secs() / (60 * 60 * 24);
+                write!(f, " RETAIN {days} DAYS")?;
+            } else {
+                let seconds = retain.as_secs();
+                write!(f, " RETAIN {seconds} SECONDS")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct OptimizeTableStmt {
     pub catalog: Option<Identifier>,
@@ -639,6 +735,78 @@ impl Display for AnalyzeTableStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct VerifyTableStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+}
+
+impl Display for VerifyTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "SYSTEM VERIFY TABLE ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct RestoreTableStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub location: String,
+    pub point: Option<TimeTravelPoint>,
+}
+
+impl Display for RestoreTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "RESTORE TABLE ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " FROM @{}", self.location)?;
+        if let Some(point) = &self.point {
+            write!(f, " AT {point}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct RefreshTableStatisticsStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+}
+
+impl Display for RefreshTableStatisticsStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "SYSTEM REFRESH TABLE STATISTICS ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct ExistsTableStmt {
     pub catalog: Option<Identifier>,
@@ -668,6 +836,7 @@ pub enum Engine {
     Random,
     Iceberg,
     Delta,
+    Hudi,
 }
 
 impl Display for Engine {
@@ -680,6 +849,7 @@ impl Display for Engine {
             Engine::Random => write!(f, "RANDOM"),
             Engine::Iceberg => write!(f, "ICEBERG"),
             Engine::Delta => write!(f, "DELTA"),
+            Engine::Hudi => write!(f, "HUDI"),
         }
     }
 }
@@ -735,6 +905,7 @@ pub enum OptimizeTableAction {
     All,
     Purge { before: Option<TimeTravelPoint> },
     Compact { target: CompactTarget },
+    Recluster,
 }
 
 impl Display for OptimizeTableAction {
@@ -759,6 +930,7 @@ impl Display for OptimizeTableAction {
                 }
                 Ok(())
             }
+            OptimizeTableAction::Recluster => write!(f, "RECLUSTER"),
         }
     }
 }
@@ -842,10 +1014,70 @@ impl Display for InvertedIndexDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum ConstraintType {
+    PrimaryKey,
+    Unique,
+    ForeignKey {
+        database: Option<Identifier>,
+        table: Identifier,
+        columns: Vec<Identifier>,
+    },
+}
+
+/// Table constraints are informational, they are never enforced on write.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ConstraintDefinition {
+    pub name: Option<Identifier>,
+    pub constraint_type: ConstraintType,
+    pub columns: Vec<Identifier>,
+    pub enforced: bool,
+    // if true, the optimizer can rely on the constraint to rewrite queries.
+    pub rely: bool,
+}
+
+impl Display for ConstraintDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "CONSTRAINT {name} ")?;
+        }
+        match &self.constraint_type {
+            ConstraintType::PrimaryKey => write!(f, "PRIMARY KEY")?,
+            ConstraintType::Unique => write!(f, "UNIQUE")?,
+            ConstraintType::ForeignKey { .. } => write!(f, "FOREIGN KEY")?,
+        }
+        write!(f, " (")?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, ")")?;
+        if let ConstraintType::ForeignKey {
+            database,
+            table,
+            columns,
+        } = &self.constraint_type
+        {
+            write!(f, " REFERENCES ")?;
+            write_dot_separated_list(f, database.iter().chain(Some(table)))?;
+            write!(f, " (")?;
+            write_comma_separated_list(f, columns)?;
+            write!(f, ")")?;
+        }
+        if self.enforced {
+            write!(f, " ENFORCED")?;
+        } else {
+            write!(f, " NOT ENFORCED")?;
+        }
+        if self.rely {
+            write!(f, " RELY")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateDefinition {
     Column(ColumnDefinition),
     InvertedIndex(InvertedIndexDefinition),
+    Constraint(ConstraintDefinition),
 }
 
 impl Display for CreateDefinition {
@@ -857,6 +1089,9 @@ impl Display for CreateDefinition {
             CreateDefinition::InvertedIndex(inverted_index_def) => {
                 write!(f, "{}", inverted_index_def)?;
             }
+            CreateDefinition::Constraint(constraint_def) => {
+                write!(f, "{}", constraint_def)?;
+            }
         }
         Ok(())
     }
@@ -872,6 +1107,10 @@ pub enum ModifyColumnAction {
     SetDataType(Vec<ColumnDefinition>),
     // column name id
     ConvertStoredComputedColumn(Identifier),
+    // (column name id, tag name -> tag value)
+    SetTags(Identifier, BTreeMap<String, String>),
+    // (column name id, tag names)
+    UnsetTags(Identifier, Vec<String>),
 }
 
 impl Display for ModifyColumnAction {
@@ -889,6 +1128,14 @@ impl Display for ModifyColumnAction {
             ModifyColumnAction::ConvertStoredComputedColumn(column) => {
                 write!(f, "{} DROP STORED", column)?
             }
+            ModifyColumnAction::SetTags(column, tags) => {
+                write!(f, "{} SET TAG ", column)?;
+                write_comma_separated_string_map(f, tags)?;
+            }
+            ModifyColumnAction::UnsetTags(column, tags) => {
+                write!(f, "{} UNSET TAG ", column)?;
+                write_comma_separated_list(f, tags)?;
+            }
         }
 
         Ok(())
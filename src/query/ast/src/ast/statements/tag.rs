@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+use crate::ast::quote::QuotedString;
+use crate::ast::CreateOption;
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreateTagStmt {
+    pub create_option: CreateOption,
+    pub name: String,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateTagStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "TAG ")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = {}", QuotedString(comment, '\''))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct AlterTagStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub action: AlterTagAction,
+}
+
+impl Display for AlterTagStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER TAG ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} {}", self.name, self.action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum AlterTagAction {
+    SetMaskingPolicy(String),
+    UnsetMaskingPolicy,
+}
+
+impl Display for AlterTagAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterTagAction::SetMaskingPolicy(policy) => write!(f, "SET MASKING POLICY {policy}"),
+            AlterTagAction::UnsetMaskingPolicy => write!(f, "UNSET MASKING POLICY"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DropTagStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropTagStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP TAG ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
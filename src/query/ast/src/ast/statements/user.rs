@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -103,6 +104,25 @@ impl Display for AlterUserStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct AlterRoleStmt {
+    pub role_name: String,
+    #[drive(skip)]
+    pub options: BTreeMap<String, u64>,
+}
+
+impl Display for AlterRoleStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER ROLE '{}' SET ", self.role_name)?;
+        write_comma_separated_list(
+            f,
+            self.options
+                .iter()
+                .map(|(name, value)| format!("{name} = {value}")),
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct GrantStmt {
     pub source: AccountMgrSource,
@@ -147,6 +167,7 @@ pub enum GrantObjectName {
     Table(Option<String>, String),
     UDF(String),
     Stage(String),
+    Warehouse(String),
 }
 
 impl Display for GrantObjectName {
@@ -164,6 +185,7 @@ impl Display for GrantObjectName {
             }
             GrantObjectName::UDF(udf) => write!(f, " UDF {udf}"),
             GrantObjectName::Stage(stage) => write!(f, " STAGE {stage}"),
+            GrantObjectName::Warehouse(warehouse) => write!(f, " WAREHOUSE {warehouse}"),
         }
     }
 }
@@ -220,6 +242,7 @@ pub enum AccountMgrLevel {
     Table(Option<String>, String),
     UDF(String),
     Stage(String),
+    Warehouse(String),
 }
 
 impl Display for AccountMgrLevel {
@@ -242,6 +265,7 @@ impl Display for AccountMgrLevel {
             }
             AccountMgrLevel::UDF(udf) => write!(f, " UDF {udf}"),
             AccountMgrLevel::Stage(stage) => write!(f, " STAGE {stage}"),
+            AccountMgrLevel::Warehouse(warehouse) => write!(f, " WAREHOUSE {warehouse}"),
         }
     }
 }
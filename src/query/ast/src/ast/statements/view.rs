@@ -21,6 +21,7 @@ use derive_visitor::DriveMut;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_dot_separated_list;
 use crate::ast::CreateOption;
+use crate::ast::DropBehavior;
 use crate::ast::Identifier;
 use crate::ast::Query;
 use crate::ast::ShowLimit;
@@ -95,6 +96,7 @@ pub struct DropViewStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub view: Identifier,
+    pub drop_behavior: Option<DropBehavior>,
 }
 
 impl Display for DropViewStmt {
@@ -109,7 +111,12 @@ impl Display for DropViewStmt {
                 .iter()
                 .chain(&self.database)
                 .chain(Some(&self.view)),
-        )
+        )?;
+        if let Some(drop_behavior) = &self.drop_behavior {
+            write!(f, " {drop_behavior}")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -415,6 +415,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_kill(&mut self, _kill_target: &'ast KillTarget, _object_id: &'ast str) {}
 
+    fn visit_kill_where(&mut self, _stmt: &'ast KillWhereStmt) {}
+
     fn visit_set_variable(
         &mut self,
         _is_global: bool,
@@ -507,6 +509,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_alter_database(&mut self, _stmt: &'ast AlterDatabaseStmt) {}
 
+    fn visit_export_database(&mut self, _stmt: &'ast ExportDatabaseStmt) {}
+
+    fn visit_import_database(&mut self, _stmt: &'ast ImportDatabaseStmt) {}
+
     fn visit_use_database(&mut self, _database: &'ast Identifier) {}
 
     fn visit_show_tables(&mut self, _stmt: &'ast ShowTablesStmt) {}
@@ -537,6 +543,8 @@ pub trait Visitor<'ast>: Sized {
     ) {
     }
 
+    fn visit_constraint_definition(&mut self, _constraint_definition: &'ast ConstraintDefinition) {}
+
     fn visit_drop_table(&mut self, _stmt: &'ast DropTableStmt) {}
 
     fn visit_undrop_table(&mut self, _stmt: &'ast UndropTableStmt) {}
@@ -555,8 +563,16 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_vacuum_temporary_files(&mut self, _stmt: &'ast VacuumTemporaryFiles) {}
 
+    fn visit_vacuum_system_history(&mut self, _stmt: &'ast VacuumSystemHistory) {}
+
     fn visit_analyze_table(&mut self, _stmt: &'ast AnalyzeTableStmt) {}
 
+    fn visit_verify_table(&mut self, _stmt: &'ast VerifyTableStmt) {}
+
+    fn visit_refresh_table_statistics(&mut self, _stmt: &'ast RefreshTableStatisticsStmt) {}
+
+    fn visit_restore_table(&mut self, _stmt: &'ast RestoreTableStmt) {}
+
     fn visit_exists_table(&mut self, _stmt: &'ast ExistsTableStmt) {}
 
     fn visit_create_dictionary(&mut self, _stmt: &'ast CreateDictionaryStmt) {}
@@ -621,6 +637,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &'ast str) {}
 
+    fn visit_alter_role(&mut self, _stmt: &'ast AlterRoleStmt) {}
+
     fn visit_grant(&mut self, _grant: &'ast GrantStmt) {}
 
     fn visit_show_grant(
@@ -716,6 +734,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_password_policies(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
+    fn visit_create_tag(&mut self, _stmt: &'ast CreateTagStmt) {}
+
+    fn visit_alter_tag(&mut self, _stmt: &'ast AlterTagStmt) {}
+
+    fn visit_drop_tag(&mut self, _stmt: &'ast DropTagStmt) {}
+
     fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt) {}
 
     fn visit_drop_task(&mut self, _stmt: &'ast DropTaskStmt) {}
@@ -421,6 +421,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_kill(&mut self, _kill_target: &mut KillTarget, _object_id: &mut String) {}
 
+    fn visit_kill_where(&mut self, _stmt: &mut KillWhereStmt) {}
+
     fn visit_set_variable(
         &mut self,
         _is_global: bool,
@@ -515,6 +517,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_alter_database(&mut self, _stmt: &mut AlterDatabaseStmt) {}
 
+    fn visit_export_database(&mut self, _stmt: &mut ExportDatabaseStmt) {}
+
+    fn visit_import_database(&mut self, _stmt: &mut ImportDatabaseStmt) {}
+
     fn visit_use_database(&mut self, _database: &mut Identifier) {}
 
     fn visit_show_tables(&mut self, _stmt: &mut ShowTablesStmt) {}
@@ -545,6 +551,8 @@ pub trait VisitorMut: Sized {
     ) {
     }
 
+    fn visit_constraint_definition(&mut self, _constraint_definition: &mut ConstraintDefinition) {}
+
     fn visit_drop_table(&mut self, _stmt: &mut DropTableStmt) {}
 
     fn visit_undrop_table(&mut self, _stmt: &mut UndropTableStmt) {}
@@ -563,8 +571,16 @@ pub trait VisitorMut: Sized {
 
     fn visit_vacuum_temporary_files(&mut self, _stmt: &mut VacuumTemporaryFiles) {}
 
+    fn visit_vacuum_system_history(&mut self, _stmt: &mut VacuumSystemHistory) {}
+
     fn visit_analyze_table(&mut self, _stmt: &mut AnalyzeTableStmt) {}
 
+    fn visit_verify_table(&mut self, _stmt: &mut VerifyTableStmt) {}
+
+    fn visit_refresh_table_statistics(&mut self, _stmt: &mut RefreshTableStatisticsStmt) {}
+
+    fn visit_restore_table(&mut self, _stmt: &mut RestoreTableStmt) {}
+
     fn visit_exists_table(&mut self, _stmt: &mut ExistsTableStmt) {}
 
     fn visit_create_dictionary(&mut self, _stmt: &mut CreateDictionaryStmt) {}
@@ -629,6 +645,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &mut String) {}
 
+    fn visit_alter_role(&mut self, _stmt: &mut AlterRoleStmt) {}
+
     fn visit_grant(&mut self, _grant: &mut GrantStmt) {}
 
     fn visit_show_grant(
@@ -724,6 +742,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_password_policies(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
+    fn visit_create_tag(&mut self, _stmt: &mut CreateTagStmt) {}
+
+    fn visit_alter_tag(&mut self, _stmt: &mut AlterTagStmt) {}
+
+    fn visit_drop_tag(&mut self, _stmt: &mut DropTagStmt) {}
+
     fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt) {}
 
     fn visit_drop_task(&mut self, _stmt: &mut DropTaskStmt) {}
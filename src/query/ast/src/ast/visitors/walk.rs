@@ -303,6 +303,37 @@ pub fn walk_table_reference<'a, V: Visitor<'a>>(visitor: &mut V, table_ref: &'a
             visitor.visit_join(join);
         }
         TableReference::Location { .. } => {}
+        TableReference::JsonTable {
+            json_table, alias, ..
+        } => {
+            visitor.visit_expr(&json_table.input);
+            walk_json_table_columns(visitor, &json_table.columns);
+            if let Some(alias) = alias {
+                visitor.visit_identifier(&alias.name);
+            }
+        }
+    }
+}
+
+fn walk_json_table_columns<'a, V: Visitor<'a>>(visitor: &mut V, columns: &'a [JsonTableColumn]) {
+    for column in columns {
+        match column {
+            JsonTableColumn::Ordinality { name } => visitor.visit_identifier(name),
+            JsonTableColumn::Path {
+                name,
+                on_empty,
+                on_error,
+                ..
+            } => {
+                visitor.visit_identifier(name);
+                for behavior in [on_empty, on_error].into_iter().flatten() {
+                    if let JsonTableBehavior::Default(expr) = behavior {
+                        visitor.visit_expr(expr);
+                    }
+                }
+            }
+            JsonTableColumn::Nested { columns, .. } => walk_json_table_columns(visitor, columns),
+        }
     }
 }
 
@@ -402,6 +433,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             query,
         } => visitor.visit_explain(kind, options, query),
         Statement::ExplainAnalyze { query } => visitor.visit_statement(query),
+        Statement::StatementWithSettings { stmt, .. } => visitor.visit_statement(stmt),
         Statement::Query(query) => visitor.visit_query(query),
         Statement::Insert(insert) => visitor.visit_insert(insert),
         Statement::Replace(replace) => visitor.visit_replace(replace),
@@ -425,10 +457,12 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         }
         Statement::ShowIndexes { show_options } => visitor.visit_show_indexes(show_options),
         Statement::ShowLocks(stmt) => visitor.visit_show_locks(stmt),
+        Statement::ShowWarnings => {}
         Statement::KillStmt {
             kill_target,
             object_id,
         } => visitor.visit_kill(kill_target, object_id),
+        Statement::KillWhere(stmt) => visitor.visit_kill_where(stmt),
         Statement::SetVariable {
             is_global,
             variable,
@@ -450,6 +484,8 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::DropDatabase(stmt) => visitor.visit_drop_database(stmt),
         Statement::UndropDatabase(stmt) => visitor.visit_undrop_database(stmt),
         Statement::AlterDatabase(stmt) => visitor.visit_alter_database(stmt),
+        Statement::ExportDatabase(stmt) => visitor.visit_export_database(stmt),
+        Statement::ImportDatabase(stmt) => visitor.visit_import_database(stmt),
         Statement::UseDatabase { database } => visitor.visit_use_database(database),
         Statement::ShowTables(stmt) => visitor.visit_show_tables(stmt),
         Statement::ShowColumns(stmt) => visitor.visit_show_columns(stmt),
@@ -467,7 +503,11 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::VacuumTable(stmt) => visitor.visit_vacuum_table(stmt),
         Statement::VacuumDropTable(stmt) => visitor.visit_vacuum_drop_table(stmt),
         Statement::VacuumTemporaryFiles(stmt) => visitor.visit_vacuum_temporary_files(stmt),
+        Statement::VacuumSystemHistory(stmt) => visitor.visit_vacuum_system_history(stmt),
         Statement::AnalyzeTable(stmt) => visitor.visit_analyze_table(stmt),
+        Statement::VerifyTable(stmt) => visitor.visit_verify_table(stmt),
+        Statement::RefreshTableStatistics(stmt) => visitor.visit_refresh_table_statistics(stmt),
+        Statement::RestoreTable(stmt) => visitor.visit_restore_table(stmt),
         Statement::ExistsTable(stmt) => visitor.visit_exists_table(stmt),
         Statement::CreateDictionary(stmt) => visitor.visit_create_dictionary(stmt),
         Statement::DropDictionary(stmt) => visitor.visit_drop_dictionary(stmt),
@@ -508,6 +548,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             if_exists,
             role_name,
         } => visitor.visit_drop_role(*if_exists, role_name),
+        Statement::AlterRole(stmt) => visitor.visit_alter_role(stmt),
         Statement::Grant(stmt) => visitor.visit_grant(stmt),
         Statement::ShowGrants {
             principal,
@@ -573,6 +614,9 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::ShowPasswordPolicies { show_options } => {
             visitor.visit_show_password_policies(show_options)
         }
+        Statement::CreateTag(stmt) => visitor.visit_create_tag(stmt),
+        Statement::AlterTag(stmt) => visitor.visit_alter_tag(stmt),
+        Statement::DropTag(stmt) => visitor.visit_drop_tag(stmt),
         Statement::CreateTask(stmt) => visitor.visit_create_task(stmt),
         Statement::ExecuteTask(stmt) => visitor.visit_execute_task(stmt),
         Statement::DropTask(stmt) => visitor.visit_drop_task(stmt),
@@ -327,6 +327,39 @@ pub fn walk_table_reference_mut<V: VisitorMut>(visitor: &mut V, table_ref: &mut
             visitor.visit_join(join);
         }
         TableReference::Location { .. } => {}
+        TableReference::JsonTable {
+            json_table, alias, ..
+        } => {
+            visitor.visit_expr(&mut json_table.input);
+            walk_json_table_columns_mut(visitor, &mut json_table.columns);
+            if let Some(alias) = alias {
+                visitor.visit_identifier(&mut alias.name);
+            }
+        }
+    }
+}
+
+fn walk_json_table_columns_mut<V: VisitorMut>(visitor: &mut V, columns: &mut [JsonTableColumn]) {
+    for column in columns {
+        match column {
+            JsonTableColumn::Ordinality { name } => visitor.visit_identifier(name),
+            JsonTableColumn::Path {
+                name,
+                on_empty,
+                on_error,
+                ..
+            } => {
+                visitor.visit_identifier(name);
+                for behavior in [on_empty, on_error].into_iter().flatten() {
+                    if let JsonTableBehavior::Default(expr) = behavior {
+                        visitor.visit_expr(expr);
+                    }
+                }
+            }
+            JsonTableColumn::Nested { columns, .. } => {
+                walk_json_table_columns_mut(visitor, columns)
+            }
+        }
     }
 }
 
@@ -397,6 +430,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             query,
         } => visitor.visit_explain(kind, options, &mut *query),
         Statement::ExplainAnalyze { query } => visitor.visit_statement(&mut *query),
+        Statement::StatementWithSettings { stmt, .. } => visitor.visit_statement(&mut *stmt),
         Statement::Query(query) => visitor.visit_query(&mut *query),
         Statement::Insert(insert) => visitor.visit_insert(insert),
         Statement::Replace(replace) => visitor.visit_replace(replace),
@@ -417,6 +451,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         }
         Statement::ShowIndexes { show_options } => visitor.visit_show_indexes(show_options),
         Statement::ShowLocks(stmt) => visitor.visit_show_locks(stmt),
+        Statement::ShowWarnings => {}
         Statement::ShowTableFunctions { show_options } => {
             visitor.visit_show_table_functions(show_options)
         }
@@ -424,6 +459,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             kill_target,
             object_id,
         } => visitor.visit_kill(kill_target, object_id),
+        Statement::KillWhere(stmt) => visitor.visit_kill_where(stmt),
         Statement::SetVariable {
             is_global,
             variable,
@@ -445,6 +481,8 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::DropDatabase(stmt) => visitor.visit_drop_database(stmt),
         Statement::UndropDatabase(stmt) => visitor.visit_undrop_database(stmt),
         Statement::AlterDatabase(stmt) => visitor.visit_alter_database(stmt),
+        Statement::ExportDatabase(stmt) => visitor.visit_export_database(stmt),
+        Statement::ImportDatabase(stmt) => visitor.visit_import_database(stmt),
         Statement::UseDatabase { database } => visitor.visit_use_database(database),
         Statement::ShowTables(stmt) => visitor.visit_show_tables(stmt),
         Statement::ShowColumns(stmt) => visitor.visit_show_columns(stmt),
@@ -462,7 +500,11 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::VacuumTable(stmt) => visitor.visit_vacuum_table(stmt),
         Statement::VacuumDropTable(stmt) => visitor.visit_vacuum_drop_table(stmt),
         Statement::VacuumTemporaryFiles(stmt) => visitor.visit_vacuum_temporary_files(stmt),
+        Statement::VacuumSystemHistory(stmt) => visitor.visit_vacuum_system_history(stmt),
         Statement::AnalyzeTable(stmt) => visitor.visit_analyze_table(stmt),
+        Statement::VerifyTable(stmt) => visitor.visit_verify_table(stmt),
+        Statement::RefreshTableStatistics(stmt) => visitor.visit_refresh_table_statistics(stmt),
+        Statement::RestoreTable(stmt) => visitor.visit_restore_table(stmt),
         Statement::ExistsTable(stmt) => visitor.visit_exists_table(stmt),
         Statement::CreateDictionary(stmt) => visitor.visit_create_dictionary(stmt),
         Statement::DropDictionary(stmt) => visitor.visit_drop_dictionary(stmt),
@@ -503,6 +545,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             if_exists,
             role_name,
         } => visitor.visit_drop_role(*if_exists, role_name),
+        Statement::AlterRole(stmt) => visitor.visit_alter_role(stmt),
         Statement::Grant(stmt) => visitor.visit_grant(stmt),
         Statement::ShowGrants {
             principal,
@@ -569,6 +612,9 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             visitor.visit_show_password_policies(show_options)
         }
 
+        Statement::CreateTag(stmt) => visitor.visit_create_tag(stmt),
+        Statement::AlterTag(stmt) => visitor.visit_alter_tag(stmt),
+        Statement::DropTag(stmt) => visitor.visit_drop_tag(stmt),
         Statement::CreateTask(stmt) => visitor.visit_create_task(stmt),
         Statement::ExecuteTask(stmt) => visitor.visit_execute_task(stmt),
         Statement::DropTask(stmt) => visitor.visit_drop_task(stmt),
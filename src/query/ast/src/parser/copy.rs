@@ -19,6 +19,7 @@ use super::query::with;
 use crate::ast::CopyIntoLocationOption;
 use crate::ast::CopyIntoLocationSource;
 use crate::ast::CopyIntoLocationStmt;
+use crate::ast::CopyIntoLocationTarget;
 use crate::ast::CopyIntoTableOption;
 use crate::ast::CopyIntoTableSource;
 use crate::ast::CopyIntoTableStmt;
@@ -92,12 +93,19 @@ fn copy_into_location(i: Input) -> IResult<Statement> {
             CopyIntoLocationSource::Query(Box::new(query))
         }),
     ));
+    let copy_into_location_target = alt((
+        map(file_location, CopyIntoLocationTarget::Location),
+        map(
+            rule! { "(" ~ #comma_separated_list1(literal_string) ~ ")" },
+            |(_, urls, _)| CopyIntoLocationTarget::Urls(urls),
+        ),
+    ));
 
     map(
         rule! {
             #with? ~ COPY
             ~ #hint?
-            ~ INTO ~ #file_location
+            ~ INTO ~ #copy_into_location_target
             ~ ^FROM ~ ^#copy_into_location_source
             ~ #copy_into_location_option*
         },
@@ -111,6 +119,7 @@ fn copy_into_location(i: Input) -> IResult<Statement> {
                 single: Default::default(),
                 max_file_size: Default::default(),
                 detailed_output: false,
+                file_name_template: None,
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -122,7 +131,7 @@ fn copy_into_location(i: Input) -> IResult<Statement> {
 pub fn copy_into(i: Input) -> IResult<Statement> {
     rule!(
          #copy_into_location:"`COPY
-                INTO { internalStage | externalStage | externalLocation }
+                INTO { internalStage | externalStage | externalLocation | ( '<url>' [ , '<url>' ] [ , ... ] ) }
                 FROM { [<database_name>.]<table_name> | ( <query> ) }
                 [ FILE_FORMAT = ( { TYPE = { CSV | JSON | PARQUET | TSV } [ formatTypeOptions ] } ) ]
                 [ copyOptions ]`"
@@ -201,6 +210,10 @@ fn copy_into_location_option(i: Input) -> IResult<CopyIntoLocationOption> {
             rule! { DETAILED_OUTPUT ~ "=" ~ #literal_bool },
             |(_, _, detailed_output)| CopyIntoLocationOption::DetailedOutput(detailed_output),
         ),
+        map(
+            rule! { FILE_NAME_TEMPLATE ~ "=" ~ #literal_string },
+            |(_, _, template)| CopyIntoLocationOption::FileNameTemplate(template),
+        ),
         map(rule! { #file_format_clause }, |options| {
             CopyIntoLocationOption::FileFormat(options)
         }),
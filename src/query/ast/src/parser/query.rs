@@ -15,6 +15,7 @@
 use nom::branch::alt;
 use nom::combinator::consumed;
 use nom::combinator::map;
+use nom::combinator::not;
 use nom::combinator::value;
 use nom::error::context;
 use pratt::Affix;
@@ -569,16 +570,19 @@ pub fn temporal_clause(i: Input) -> IResult<TemporalClause> {
 }
 
 pub fn alias_name(i: Input) -> IResult<Identifier> {
+    // `SETTINGS (name = value, ...)` after a query is the settings clause of the statement,
+    // while `SETTINGS (a, ...)` is still an alias with the column aliases.
     let short_alias = map(
         rule! {
-            #ident
+            #not(rule! { SETTINGS ~ "(" ~ #ident ~ "=" })
+            ~ #ident
             ~ #error_hint(
                 rule! { AS },
                 "an alias without `AS` keyword has already been defined before this one, \
                     please remove one of them"
             )
         },
-        |(ident, _)| ident,
+        |(_, ident, _)| ident,
     );
     let as_alias = map(
         rule! {
@@ -666,9 +670,16 @@ pub fn table_function_param(i: Input) -> IResult<TableFunctionParam> {
         TableFunctionParam::Named { name, value }
     });
     let normal = map(rule! { #expr }, TableFunctionParam::Normal);
+    // func(@stage/path) is the same as func('@stage/path')
+    let stage = map(rule! { #consumed(at_string) }, |(span, location)| {
+        TableFunctionParam::Normal(Expr::Literal {
+            span: transform_span(span.tokens),
+            value: Literal::String(format!("@{location}")),
+        })
+    });
 
     rule!(
-        #named | #normal
+        #named | #stage | #normal
     )(i)
 }
 
@@ -712,6 +723,10 @@ pub enum TableReferenceElement {
         options: Vec<SelectStageOption>,
         alias: Option<TableAlias>,
     },
+    JsonTable {
+        json_table: Box<JsonTable>,
+        alias: Option<TableAlias>,
+    },
 }
 
 pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceElement>> {
@@ -786,6 +801,21 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
             alias,
         },
     );
+    let json_table = map(
+        rule! {
+            JSON_TABLE ~ "(" ~ #expr ~ "," ~ #literal_string
+            ~ COLUMNS ~ "(" ~ #comma_separated_list1(json_table_column) ~ ")" ~ ")"
+            ~ #table_alias?
+        },
+        |(_, _, input, _, path, _, _, columns, _, _, alias)| TableReferenceElement::JsonTable {
+            json_table: Box::new(JsonTable {
+                input: Box::new(input),
+                path,
+                columns,
+            }),
+            alias,
+        },
+    );
     let subquery = map(
         rule! {
             LATERAL? ~ "(" ~ #query ~ ")" ~ #table_alias?
@@ -821,6 +851,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
 
     let (rest, (span, elem)) = consumed(rule! {
         #aliased_stage
+        | #json_table
         | #table_function
         | #aliased_table
         | #subquery
@@ -832,6 +863,51 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     Ok((rest, WithSpan { span, elem }))
 }
 
+pub fn json_table_column(i: Input) -> IResult<JsonTableColumn> {
+    let ordinality = map(
+        rule! {
+            #ident ~ FOR ~ ORDINALITY
+        },
+        |(name, _, _)| JsonTableColumn::Ordinality { name },
+    );
+    let path = map(
+        rule! {
+            #ident ~ #type_name ~ PATH ~ #literal_string
+            ~ ( #json_table_behavior ~ ON ~ EMPTY )?
+            ~ ( #json_table_behavior ~ ON ~ ERROR )?
+        },
+        |(name, data_type, _, path, on_empty, on_error)| JsonTableColumn::Path {
+            name,
+            data_type,
+            path,
+            on_empty: on_empty.map(|(behavior, _, _)| behavior),
+            on_error: on_error.map(|(behavior, _, _)| behavior),
+        },
+    );
+    let nested = map(
+        rule! {
+            NESTED ~ PATH? ~ #literal_string ~ COLUMNS ~ "(" ~ #comma_separated_list1(json_table_column) ~ ")"
+        },
+        |(_, _, path, _, _, columns, _)| JsonTableColumn::Nested { path, columns },
+    );
+
+    rule!(
+        #nested
+        | #ordinality
+        | #path
+    )(i)
+}
+
+fn json_table_behavior(i: Input) -> IResult<JsonTableBehavior> {
+    alt((
+        value(JsonTableBehavior::Null, rule! { NULL }),
+        value(JsonTableBehavior::Error, rule! { ERROR }),
+        map(rule! { DEFAULT ~ #expr }, |(_, expr)| {
+            JsonTableBehavior::Default(expr)
+        }),
+    ))(i)
+}
+
 struct TableReferenceParser;
 
 impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
@@ -925,6 +1001,11 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     alias,
                 }
             }
+            TableReferenceElement::JsonTable { json_table, alias } => TableReference::JsonTable {
+                span: transform_span(input.span.tokens),
+                json_table,
+                alias,
+            },
             _ => unreachable!(),
         };
         Ok(table_ref)
@@ -60,9 +60,13 @@ pub enum CreateDatabaseOption {
 pub fn statement_body(i: Input) -> IResult<Statement> {
     let explain = map_res(
         rule! {
-            EXPLAIN ~ ( "(" ~ #comma_separated_list1(explain_option) ~ ")" )? ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO )? ~ #statement
+            EXPLAIN ~ ( "(" ~ #comma_separated_list1(explain_option) ~ ")" )? ~ ( FORMAT ~ "=" ~ ^JSON )? ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO | VERIFY )? ~ #statement
         },
-        |(_, options, opt_kind, statement)| {
+        |(_, options, opt_format, opt_kind, statement)| {
+            let mut options = options.map_or(vec![], |(_, opts, _)| opts);
+            if opt_format.is_some() && !options.contains(&ExplainOption::FormatJson) {
+                options.push(ExplainOption::FormatJson);
+            }
             Ok(Statement::Explain {
                 kind: match opt_kind.map(|token| token.kind) {
                     Some(TokenKind::AST) => {
@@ -86,10 +90,11 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     Some(TokenKind::RAW) => ExplainKind::Raw,
                     Some(TokenKind::OPTIMIZED) => ExplainKind::Optimized,
                     Some(TokenKind::MEMO) => ExplainKind::Memo("".to_string()),
+                    Some(TokenKind::VERIFY) => ExplainKind::Verify,
                     None => ExplainKind::Plan,
                     _ => unreachable!(),
                 },
-                options: options.as_ref().map_or(vec![], |(_, opts, _)| opts.clone()),
+                options,
                 query: Box::new(statement.stmt),
             })
         },
@@ -102,6 +107,15 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             query: Box::new(statement.stmt),
         },
     );
+    let statement_with_settings = map(
+        rule! {
+            SETTINGS ~ ^"(" ~ ^#comma_separated_list1(query_setting) ~ ^")" ~ #statement_body
+        },
+        |(_, _, settings, _, stmt)| Statement::StatementWithSettings {
+            settings,
+            stmt: Box::new(stmt),
+        },
+    );
 
     let create_task = map(
         rule! {
@@ -278,6 +292,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, show_options)| Statement::ShowSettings { show_options },
     );
     let show_stages = value(Statement::ShowStages, rule! { SHOW ~ STAGES });
+    let show_warnings = value(Statement::ShowWarnings, rule! { SHOW ~ WARNINGS });
     let show_process_list = map(
         rule! {
             SHOW ~ PROCESSLIST ~ #show_options?
@@ -343,6 +358,19 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    // kill query where user = 'etl' and elapsed > 600;
+    let kill_where_stmt = map(
+        rule! {
+            KILL ~ #kill_target ~ WHERE ~ ^#expr
+        },
+        |(_, kill_target, _, selection)| {
+            Statement::KillWhere(KillWhereStmt {
+                kill_target,
+                selection,
+            })
+        },
+    );
+
     let set_priority = map(
         rule! {
             SET ~ PRIORITY ~  #priority  ~ #parameter_to_string
@@ -531,6 +559,32 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let export_database = map(
+        rule! {
+            EXPORT ~ DATABASE ~ #dot_separated_idents_1_to_2 ~ TO ~ #at_string
+        },
+        |(_, _, (catalog, database), _, location)| {
+            Statement::ExportDatabase(ExportDatabaseStmt {
+                catalog,
+                database,
+                location,
+            })
+        },
+    );
+
+    let import_database = map(
+        rule! {
+            IMPORT ~ DATABASE ~ #dot_separated_idents_1_to_2 ~ FROM ~ #at_string
+        },
+        |(_, _, (catalog, database), _, location)| {
+            Statement::ImportDatabase(ImportDatabaseStmt {
+                catalog,
+                database,
+                location,
+            })
+        },
+    );
+
     let alter_database = map(
         rule! {
             ALTER ~ DATABASE ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_2 ~ #alter_database_action
@@ -713,15 +767,16 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let drop_table = map(
         rule! {
-            DROP ~ TABLE ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3 ~ ALL?
+            DROP ~ TABLE ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3 ~ ALL? ~ #drop_behavior?
         },
-        |(_, _, opt_if_exists, (catalog, database, table), opt_all)| {
+        |(_, _, opt_if_exists, (catalog, database, table), opt_all, drop_behavior)| {
             Statement::DropTable(DropTableStmt {
                 if_exists: opt_if_exists.is_some(),
                 catalog,
                 database,
                 table,
                 all: opt_all.is_some(),
+                drop_behavior,
             })
         },
     );
@@ -786,14 +841,14 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let optimize_table = map(
         rule! {
-            OPTIMIZE ~ TABLE ~ #dot_separated_idents_1_to_3 ~ #optimize_table_action ~ ( LIMIT ~ #literal_u64 )?
+            OPTIMIZE ~ TABLE ~ #dot_separated_idents_1_to_3 ~ #optimize_table_action? ~ ( LIMIT ~ #literal_u64 )?
         },
-        |(_, _, (catalog, database, table), action, opt_limit)| {
+        |(_, _, (catalog, database, table), opt_action, opt_limit)| {
             Statement::OptimizeTable(OptimizeTableStmt {
                 catalog,
                 database,
                 table,
-                action,
+                action: opt_action.unwrap_or(OptimizeTableAction::All),
                 limit: opt_limit.map(|(_, limit)| limit),
             })
         },
@@ -809,6 +864,16 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             })
         },
     );
+    let vacuum_system_history = map(
+        rule! {
+            VACUUM ~ SYSTEM ~ HISTORY ~ (RETAIN ~ #literal_duration)?
+        },
+        |(_, _, _, retain)| {
+            Statement::VacuumSystemHistory(VacuumSystemHistory {
+                retain: retain.map(|(_, retain)| retain),
+            })
+        },
+    );
     let vacuum_table = map(
         rule! {
             VACUUM ~ TABLE ~ #dot_separated_idents_1_to_3 ~ #vacuum_table_option
@@ -850,6 +915,44 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             })
         },
     );
+    let verify_table = map(
+        rule! {
+            SYSTEM ~ VERIFY ~ TABLE ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, table))| {
+            Statement::VerifyTable(VerifyTableStmt {
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+    let refresh_table_statistics = map(
+        rule! {
+            SYSTEM ~ REFRESH ~ TABLE ~ STATISTICS ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, _, (catalog, database, table))| {
+            Statement::RefreshTableStatistics(RefreshTableStatisticsStmt {
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+    let restore_table = map(
+        rule! {
+            RESTORE ~ TABLE ~ #dot_separated_idents_1_to_3 ~ FROM ~ #at_string ~ ( AT ~ ^#at_snapshot_or_ts )?
+        },
+        |(_, _, (catalog, database, table), _, location, opt_point)| {
+            Statement::RestoreTable(RestoreTableStmt {
+                catalog,
+                database,
+                table,
+                location,
+                point: opt_point.map(|(_, point)| point),
+            })
+        },
+    );
     let exists_table = map(
         rule! {
             EXISTS ~ TABLE ~ #dot_separated_idents_1_to_3
@@ -968,14 +1071,15 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let drop_view = map(
         rule! {
-            DROP ~ VIEW ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+            DROP ~ VIEW ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3 ~ #drop_behavior?
         },
-        |(_, _, opt_if_exists, (catalog, database, view))| {
+        |(_, _, opt_if_exists, (catalog, database, view), drop_behavior)| {
             Statement::DropView(DropViewStmt {
                 if_exists: opt_if_exists.is_some(),
                 catalog,
                 database,
                 view,
+                drop_behavior,
             })
         },
     );
@@ -1326,6 +1430,17 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             role_name,
         },
     );
+    let alter_role = map(
+        rule! {
+            ALTER ~ ROLE ~ #role_name ~ SET ~ ^#comma_separated_list1(role_option)
+        },
+        |(_, _, role_name, _, options)| {
+            Statement::AlterRole(AlterRoleStmt {
+                role_name,
+                options: BTreeMap::from_iter(options),
+            })
+        },
+    );
     let grant = map(
         rule! {
             GRANT ~ #grant_source ~ TO ~ #grant_option
@@ -1987,6 +2102,45 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let create_tag = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ TAG ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+             ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, opt_or_replace, _, opt_if_not_exists, name, opt_comment)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateTag(CreateTagStmt {
+                create_option,
+                name: name.to_string(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            }))
+        },
+    );
+    let alter_tag = map(
+        rule! {
+            ALTER ~ TAG ~ ( IF ~ ^EXISTS )? ~ ^#ident ~ #alter_tag_action
+        },
+        |(_, _, opt_if_exists, name, action)| {
+            Statement::AlterTag(AlterTagStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                action,
+            })
+        },
+    );
+    let drop_tag = map(
+        rule! {
+            DROP ~ TAG ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropTag(DropTagStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -2148,6 +2302,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #map(query, |query| Statement::Query(Box::new(query)))
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
             | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
+            | #statement_with_settings : "`SETTINGS (<setting> = <value>, ...) <statement>`"
             | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
             | #show_engines : "`SHOW ENGINES`"
@@ -2156,10 +2311,15 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_functions : "`SHOW FUNCTIONS [<show_limit>]`"
             | #show_indexes : "`SHOW INDEXES`"
             | #show_locks : "`SHOW LOCKS [IN ACCOUNT] [WHERE ...]`"
+            | #show_warnings : "`SHOW WARNINGS`"
+            | #kill_where_stmt : "`KILL (QUERY | CONNECTION) WHERE <expr>`"
             | #kill_stmt : "`KILL (QUERY | CONNECTION) <object_id>`"
             | #vacuum_temp_files : "VACUUM TEMPORARY FILES [RETAIN number SECONDS|DAYS] [LIMIT number]"
+            | #vacuum_system_history : "VACUUM SYSTEM HISTORY [RETAIN number SECONDS|DAYS]"
             | #set_priority: "`SET PRIORITY (HIGH | MEDIUM | LOW) <object_id>`"
-            | #system_action: "`SYSTEM (ENABLE | DISABLE) EXCEPTION_BACKTRACE`"
+            | #verify_table : "`SYSTEM VERIFY TABLE [<database>.]<table>`"
+            | #refresh_table_statistics : "`SYSTEM REFRESH TABLE STATISTICS [<database>.]<table>`"
+            | #system_action: "`SYSTEM (ENABLE | DISABLE) EXCEPTION_BACKTRACE | SYSTEM CANCEL ALL QUERIES ON WAREHOUSE <warehouse> | SYSTEM (SUSPEND | RESUME) NODE ['<node_id>'] | SYSTEM REPLAY QUERY '<query_id>'`"
         ),
         // database
         rule!(
@@ -2170,6 +2330,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_database : "`DROP DATABASE [IF EXISTS] <database>`"
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
+            | #export_database : "`EXPORT DATABASE <database> TO @<stage>/<path>`"
+            | #import_database : "`IMPORT DATABASE <database> FROM @<stage>/<path>`"
+            | #restore_table : "`RESTORE TABLE [<database>.]<table> FROM @<stage>/<path> [AT (TIMESTAMP => <expr> | SNAPSHOT => '<snapshot_id>' | OFFSET => <expr>)]`"
         ),
         // network policy / password policy
         rule!(
@@ -2184,6 +2347,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #describe_password_policy: "`DESC PASSWORD POLICY name`"
             | #show_password_policies: "`SHOW PASSWORD POLICIES [<show_options>]`"
         ),
+        // tag
+        rule!(
+            #create_tag: "`CREATE [OR REPLACE] TAG [IF NOT EXISTS] name [COMMENT = '<string_literal>']`"
+            | #alter_tag: "`ALTER TAG [IF EXISTS] name (SET MASKING POLICY <policy> | UNSET MASKING POLICY)`"
+            | #drop_tag: "`DROP TAG [IF EXISTS] name`"
+        ),
         rule!(
             #conditional_multi_table_insert() : "`INSERT [OVERWRITE] {FIRST|ALL} { WHEN <condition> THEN intoClause [ ... ] } [ ... ] [ ELSE intoClause ] <subquery>`"
             | #unconditional_multi_table_insert() : "`INSERT [OVERWRITE] ALL intoClause [ ... ] <subquery>`"
@@ -2211,12 +2380,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_drop_tables_status : "`SHOW DROP TABLES [FROM <database>]`"
             | #attach_table : "`ATTACH TABLE [<database>.]<table> <uri>`"
             | #create_table : "`CREATE [OR REPLACE] TABLE [IF NOT EXISTS] [<database>.]<table> [<source>] [<table_options>]`"
-            | #drop_table : "`DROP TABLE [IF EXISTS] [<database>.]<table>`"
+            | #drop_table : "`DROP TABLE [IF EXISTS] [<database>.]<table> [ALL] [RESTRICT | CASCADE]`"
             | #undrop_table : "`UNDROP TABLE [<database>.]<table>`"
             | #alter_table : "`ALTER TABLE [<database>.]<table> <action>`"
             | #rename_table : "`RENAME TABLE [<database>.]<table> TO <new_table>`"
             | #truncate_table : "`TRUNCATE TABLE [<database>.]<table>`"
-            | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
+            | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> [ALL | PURGE | COMPACT [SEGMENT] | RECLUSTER]`"
             | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
             | #vacuum_drop_table : "`VACUUM DROP TABLE [FROM [<catalog>.]<database>] [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
             | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
@@ -2233,7 +2402,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         // view,index
         rule!(
             #create_view : "`CREATE [OR REPLACE] VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
-            | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
+            | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view> [RESTRICT | CASCADE]`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #show_views : "`SHOW [FULL] VIEWS [FROM <database>] [<show_limit>]`"
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
@@ -2259,6 +2428,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
+            | #alter_role : "`ALTER ROLE <role_name> SET <role_option> = <value>, ...`"
             | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
@@ -2365,10 +2535,18 @@ AS
 pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     map(
         rule! {
-            #statement_body ~ ( FORMAT ~ ^#ident )? ~ ";"? ~ &EOI
+            #statement_body
+            ~ ( SETTINGS ~ ^"(" ~ ^#comma_separated_list1(query_setting) ~ ^")" )?
+            ~ ( FORMAT ~ ^#ident )? ~ ";"? ~ &EOI
         },
-        |(stmt, opt_format, _, _)| StatementWithFormat {
-            stmt,
+        |(stmt, opt_settings, opt_format, _, _)| StatementWithFormat {
+            stmt: match opt_settings {
+                Some((_, _, settings, _)) => Statement::StatementWithSettings {
+                    settings,
+                    stmt: Box::new(stmt),
+                },
+                None => stmt,
+            },
             format: opt_format.map(|(_, format)| format.name),
         },
     )(i)
@@ -2680,6 +2858,15 @@ pub fn set_var_hints(i: Input) -> IResult<HintItem> {
     )(i)
 }
 
+pub fn query_setting(i: Input) -> IResult<HintItem> {
+    map(
+        rule! {
+            #ident ~ ^"=" ~ ^#subexpr(0)
+        },
+        |(name, _, expr)| HintItem { name, expr },
+    )(i)
+}
+
 pub fn hint(i: Input) -> IResult<Hint> {
     let hint = map(
         rule! {
@@ -2837,8 +3024,57 @@ pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
     )(i)
 }
 
+pub fn constraint_def(i: Input) -> IResult<ConstraintDefinition> {
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ KEY ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _)| (ConstraintType::PrimaryKey, columns),
+    );
+    let unique = map(
+        rule! {
+            UNIQUE ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, columns, _)| (ConstraintType::Unique, columns),
+    );
+    let foreign_key = map(
+        rule! {
+            FOREIGN ~ KEY ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ^REFERENCES ~ ^#dot_separated_idents_1_to_2
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _, _, (database, table), _, ref_columns, _)| {
+            let constraint_type = ConstraintType::ForeignKey {
+                database,
+                table,
+                columns: ref_columns,
+            };
+            (constraint_type, columns)
+        },
+    );
+    let enforced = map(rule! { NOT? ~ ENFORCED }, |(opt_not, _)| opt_not.is_none());
+    let rely = alt((value(true, rule! { RELY }), value(false, rule! { NORELY })));
+
+    map(
+        rule! {
+            ( CONSTRAINT ~ #ident )?
+            ~ ( #primary_key | #unique | #foreign_key )
+            ~ #enforced?
+            ~ #rely?
+        },
+        |(opt_name, (constraint_type, columns), opt_enforced, opt_rely)| ConstraintDefinition {
+            name: opt_name.map(|(_, name)| name),
+            constraint_type,
+            columns,
+            enforced: opt_enforced.unwrap_or(false),
+            rely: opt_rely.unwrap_or(false),
+        },
+    )(i)
+}
+
 pub fn create_def(i: Input) -> IResult<CreateDefinition> {
     alt((
+        map(rule! { #constraint_def }, CreateDefinition::Constraint),
         map(rule! { #column_def }, CreateDefinition::Column),
         map(
             rule! { #inverted_index_def },
@@ -2908,6 +3144,26 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         },
     );
 
+    let warehouse_privs = map(
+        rule! {
+            USAGE ~ ON ~ WAREHOUSE ~ #ident
+        },
+        |(_, _, _, warehouse)| AccountMgrSource::Privs {
+            privileges: vec![UserPrivilegeType::Usage],
+            level: AccountMgrLevel::Warehouse(warehouse.to_string()),
+        },
+    );
+
+    let warehouse_all_privs = map(
+        rule! {
+            ALL ~ PRIVILEGES? ~ ON ~ WAREHOUSE ~ #ident
+        },
+        |(_, _, _, _, warehouse)| AccountMgrSource::Privs {
+            privileges: vec![UserPrivilegeType::Usage],
+            level: AccountMgrLevel::Warehouse(warehouse.to_string()),
+        },
+    );
+
     let stage_privs = map(
         rule! {
             #comma_separated_list1(stage_priv_type) ~ ON ~ STAGE ~ #ident
@@ -2921,9 +3177,11 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
     rule!(
         #role : "ROLE <role_name>"
         | #udf_privs: "USAGE ON UDF <udf_name>"
+        | #warehouse_privs: "USAGE ON WAREHOUSE <warehouse_name>"
         | #privs : "<privileges> ON <privileges_level>"
         | #stage_privs : "<stage_privileges> ON STAGE <stage_name>"
         | #udf_all_privs: "ALL [ PRIVILEGES ] ON UDF <udf_name>"
+        | #warehouse_all_privs: "ALL [ PRIVILEGES ] ON WAREHOUSE <warehouse_name>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
 }
@@ -3023,11 +3281,16 @@ pub fn on_object_name(i: Input) -> IResult<GrantObjectName> {
         GrantObjectName::UDF(udf_name.to_string())
     });
 
+    let warehouse = map(rule! { WAREHOUSE ~ #ident}, |(_, warehouse_name)| {
+        GrantObjectName::Warehouse(warehouse_name.to_string())
+    });
+
     rule!(
         #database : "DATABASE <database>"
         | #table : "TABLE <database>.<table>"
         | #stage : "STAGE <stage_name>"
         | #udf : "UDF <udf_name>"
+        | #warehouse : "WAREHOUSE <warehouse_name>"
     )(i)
 }
 
@@ -3197,6 +3460,7 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         |(_, create_defs, _)| {
             let mut columns = Vec::with_capacity(create_defs.len());
             let mut inverted_indexes = Vec::new();
+            let mut constraints = Vec::new();
             for create_def in create_defs {
                 match create_def {
                     CreateDefinition::Column(column) => {
@@ -3205,6 +3469,9 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
                     CreateDefinition::InvertedIndex(inverted_index) => {
                         inverted_indexes.push(inverted_index);
                     }
+                    CreateDefinition::Constraint(constraint) => {
+                        constraints.push(constraint);
+                    }
                 }
             }
             let opt_inverted_indexes = if !inverted_indexes.is_empty() {
@@ -3212,7 +3479,12 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             } else {
                 None
             };
-            CreateTableSource::Columns(columns, opt_inverted_indexes)
+            let opt_constraints = if !constraints.is_empty() {
+                Some(constraints)
+            } else {
+                None
+            };
+            CreateTableSource::Columns(columns, opt_inverted_indexes, opt_constraints)
         },
     );
     let like = map(
@@ -3240,8 +3512,16 @@ pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
         |(_, _, new_db)| AlterDatabaseAction::RenameDatabase { new_db },
     );
 
+    let set_read_only = map(
+        rule! {
+            SET ~ READ_ONLY ~ "=" ~ #literal_bool
+        },
+        |(_, _, _, read_only)| AlterDatabaseAction::SetReadOnly { read_only },
+    );
+
     rule!(
         #rename_database
+        | #set_read_only
     )(i)
 }
 
@@ -3328,6 +3608,22 @@ pub fn modify_column_action(i: Input) -> IResult<ModifyColumnAction> {
         |(column, _, _, _)| ModifyColumnAction::UnsetMaskingPolicy(column),
     );
 
+    let set_tags = map(
+        rule! {
+            #ident ~ SET ~ TAG ~ ^#comma_separated_list1(tag_value)
+        },
+        |(column, _, _, tags)| ModifyColumnAction::SetTags(column, tags.into_iter().collect()),
+    );
+
+    let unset_tags = map(
+        rule! {
+            #ident ~ UNSET ~ TAG ~ ^#comma_separated_list1(ident)
+        },
+        |(column, _, _, tags)| {
+            ModifyColumnAction::UnsetTags(column, tags.iter().map(|t| t.to_string()).collect())
+        },
+    );
+
     let convert_stored_computed_column = map(
         rule! {
             #ident ~ DROP ~ STORED
@@ -3351,11 +3647,46 @@ pub fn modify_column_action(i: Input) -> IResult<ModifyColumnAction> {
     rule!(
         #set_mask_policy
         | #unset_mask_policy
+        | #set_tags
+        | #unset_tags
         | #convert_stored_computed_column
         | #modify_column_type
     )(i)
 }
 
+pub fn tag_value(i: Input) -> IResult<(String, String)> {
+    map(
+        rule! {
+            #ident ~ "=" ~ #literal_string
+        },
+        |(name, _, value)| (name.to_string(), value),
+    )(i)
+}
+
+pub fn alter_tag_action(i: Input) -> IResult<AlterTagAction> {
+    let set_masking_policy = map(
+        rule! {
+            SET ~ MASKING ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, _, policy)| AlterTagAction::SetMaskingPolicy(policy.to_string()),
+    );
+    let unset_masking_policy = value(AlterTagAction::UnsetMaskingPolicy, rule! {
+        UNSET ~ MASKING ~ ^POLICY
+    });
+
+    rule!(
+        #set_masking_policy
+        | #unset_masking_policy
+    )(i)
+}
+
+pub fn drop_behavior(i: Input) -> IResult<DropBehavior> {
+    alt((
+        value(DropBehavior::Restrict, rule! { RESTRICT }),
+        value(DropBehavior::Cascade, rule! { CASCADE }),
+    ))(i)
+}
+
 pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     let rename_table = map(
         rule! {
@@ -3363,6 +3694,12 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
         |(_, _, new_table)| AlterTableAction::RenameTable { new_table },
     );
+    let swap_with = map(
+        rule! {
+            SWAP ~ WITH ~ #ident
+        },
+        |(_, _, target_table)| AlterTableAction::SwapWith { target_table },
+    );
     let rename_column = map(
         rule! {
             RENAME ~ COLUMN? ~ #ident ~ TO ~ #ident
@@ -3417,12 +3754,15 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
 
     let recluster_table = map(
         rule! {
-            RECLUSTER ~ FINAL? ~ ( WHERE ~ ^#expr )? ~ ( LIMIT ~ #literal_u64 )?
+            RECLUSTER ~ FINAL? ~ ( WHERE ~ ^#expr )? ~ ( LAST ~ ^#literal_u64 ~ ^SEGMENTS )? ~ ( LIMIT ~ #literal_u64 )?
         },
-        |(_, opt_is_final, opt_selection, opt_limit)| AlterTableAction::ReclusterTable {
-            is_final: opt_is_final.is_some(),
-            selection: opt_selection.map(|(_, selection)| selection),
-            limit: opt_limit.map(|(_, limit)| limit),
+        |(_, opt_is_final, opt_selection, opt_last_segments, opt_limit)| {
+            AlterTableAction::ReclusterTable {
+                is_final: opt_is_final.is_some(),
+                selection: opt_selection.map(|(_, selection)| selection),
+                last_segments: opt_last_segments.map(|(_, last_segments, _)| last_segments),
+                limit: opt_limit.map(|(_, limit)| limit),
+            }
         },
     );
 
@@ -3440,10 +3780,55 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let set_table_read_only = map(
+        rule! {
+            SET ~ READ_ONLY ~ "=" ~ #literal_bool
+        },
+        |(_, _, _, read_only)| AlterTableAction::SetOptions {
+            set_options: BTreeMap::from([("read_only".to_string(), read_only.to_string())]),
+        },
+    );
+
+    let set_table_tags = map(
+        rule! {
+            SET ~ TAG ~ ^#comma_separated_list1(tag_value)
+        },
+        |(_, _, tags)| AlterTableAction::SetTags {
+            tags: tags.into_iter().collect(),
+        },
+    );
+
+    let unset_table_tags = map(
+        rule! {
+            UNSET ~ TAG ~ ^#comma_separated_list1(ident)
+        },
+        |(_, _, tags)| AlterTableAction::UnsetTags {
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        },
+    );
+
+    let set_storage_policy = map(
+        rule! {
+            SET ~ STORAGE_POLICY
+            ~ ^COLD_AFTER_DAYS ~ ^"=" ~ ^#literal_u64
+            ~ ^COLD_LOCATION ~ ^"=" ~ ^#uri_location
+        },
+        |(_, _, _, _, cold_after_days, _, _, cold_location)| AlterTableAction::SetStoragePolicy {
+            cold_after_days,
+            cold_location,
+        },
+    );
+
+    let unset_storage_policy = value(
+        AlterTableAction::UnsetStoragePolicy,
+        rule! { UNSET ~ STORAGE_POLICY },
+    );
+
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
         | #rename_table
+        | #swap_with
         | #rename_column
         | #modify_table_comment
         | #add_column
@@ -3452,6 +3837,11 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #set_table_read_only
+        | #set_table_tags
+        | #unset_table_tags
+        | #set_storage_policy
+        | #unset_storage_policy
     )(i)
 }
 
@@ -3574,6 +3964,7 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
                 target: opt_segment.map_or(CompactTarget::Block, |_| CompactTarget::Segment),
             }
         }),
+        value(OptimizeTableAction::Recluster, rule! { RECLUSTER }),
     ))(i)
 }
 
@@ -3832,9 +4223,39 @@ pub fn action(i: Input) -> IResult<SystemAction> {
         },
         |(switch, _)| SystemAction::Backtrace(switch),
     );
+    let cancel_all_queries = map(
+        rule! {
+             CANCEL ~ ALL ~ QUERIES ~ ON ~ WAREHOUSE ~ #ident
+        },
+        |(_, _, _, _, _, warehouse)| SystemAction::CancelAllQueries {
+            warehouse: warehouse.to_string(),
+        },
+    );
+    let suspend_node = map(
+        rule! {
+             SUSPEND ~ NODE ~ #literal_string?
+        },
+        |(_, _, node_id)| SystemAction::SuspendNode { node_id },
+    );
+    let resume_node = map(
+        rule! {
+             RESUME ~ NODE ~ #literal_string?
+        },
+        |(_, _, node_id)| SystemAction::ResumeNode { node_id },
+    );
+    let replay_query = map(
+        rule! {
+             REPLAY ~ QUERY ~ #literal_string
+        },
+        |(_, _, query_id)| SystemAction::ReplayQuery { query_id },
+    );
     // add other system action type here
     rule!(
         #backtrace
+        | #cancel_all_queries
+        | #suspend_node
+        | #resume_node
+        | #replay_query
     )(i)
 }
 
@@ -3944,6 +4365,7 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Random, rule! { RANDOM }),
         value(Engine::Iceberg, rule! { ICEBERG }),
         value(Engine::Delta, rule! { DELTA }),
+        value(Engine::Hudi, rule! { HUDI }),
     ));
 
     map(
@@ -3989,6 +4411,15 @@ pub fn catalog_type(i: Input) -> IResult<CatalogType> {
     ))(i)
 }
 
+pub fn role_option(i: Input) -> IResult<(String, u64)> {
+    map(
+        rule! {
+            #ident ~ ^"=" ~ ^#literal_u64
+        },
+        |(name, _, value)| (name.name.to_lowercase(), value),
+    )(i)
+}
+
 pub fn user_option(i: Input) -> IResult<UserOptionItem> {
     let tenant_setting = value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING });
     let no_tenant_setting = value(
@@ -4343,7 +4774,13 @@ pub fn alter_password_action(i: Input) -> IResult<AlterPasswordAction> {
 }
 
 pub fn explain_option(i: Input) -> IResult<ExplainOption> {
-    map(
+    let format_json = map(
+        rule! {
+            FORMAT ~ "=" ~ ^JSON
+        },
+        |_| ExplainOption::FormatJson,
+    );
+    let other = map(
         rule! {
             VERBOSE | LOGICAL | OPTIMIZED
         },
@@ -4353,6 +4790,11 @@ pub fn explain_option(i: Input) -> IResult<ExplainOption> {
             OPTIMIZED => ExplainOption::Optimized,
             _ => unreachable!(),
         },
+    );
+
+    rule!(
+        #format_json
+        | #other
     )(i)
 }
 
@@ -404,6 +404,10 @@ pub enum TokenKind {
     BZ2,
     #[token("CALL", ignore(ascii_case))]
     CALL,
+    #[token("CANCEL", ignore(ascii_case))]
+    CANCEL,
+    #[token("CASCADE", ignore(ascii_case))]
+    CASCADE,
     #[token("CASE", ignore(ascii_case))]
     CASE,
     #[token("CAST", ignore(ascii_case))]
@@ -428,6 +432,8 @@ pub enum TokenKind {
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
     CONNECTIONS,
+    #[token("CONSTRAINT", ignore(ascii_case))]
+    CONSTRAINT,
     #[token("CONSUME", ignore(ascii_case))]
     CONSUME,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
@@ -436,6 +442,10 @@ pub enum TokenKind {
     CONTINUE,
     #[token("CHAR", ignore(ascii_case))]
     CHAR,
+    #[token("COLD_AFTER_DAYS", ignore(ascii_case))]
+    COLD_AFTER_DAYS,
+    #[token("COLD_LOCATION", ignore(ascii_case))]
+    COLD_LOCATION,
     #[token("COLUMN", ignore(ascii_case))]
     COLUMN,
     #[token("COLUMNS", ignore(ascii_case))]
@@ -544,6 +554,8 @@ pub enum TokenKind {
     EXCLUDE,
     #[token("ELSE", ignore(ascii_case))]
     ELSE,
+    #[token("EMPTY", ignore(ascii_case))]
+    EMPTY,
     #[token("EMPTY_FIELD_AS", ignore(ascii_case))]
     EMPTY_FIELD_AS,
     #[token("ENABLE", ignore(ascii_case))]
@@ -554,12 +566,16 @@ pub enum TokenKind {
     END,
     #[token("ENDPOINT", ignore(ascii_case))]
     ENDPOINT,
+    #[token("ENFORCED", ignore(ascii_case))]
+    ENFORCED,
     #[token("ENGINE", ignore(ascii_case))]
     ENGINE,
     #[token("ENGINES", ignore(ascii_case))]
     ENGINES,
     #[token("EPOCH", ignore(ascii_case))]
     EPOCH,
+    #[token("ERROR", ignore(ascii_case))]
+    ERROR,
     #[token("ERROR_ON_COLUMN_COUNT_MISMATCH", ignore(ascii_case))]
     ERROR_ON_COLUMN_COUNT_MISMATCH,
     #[token("ESCAPE", ignore(ascii_case))]
@@ -572,6 +588,8 @@ pub enum TokenKind {
     EXPLAIN,
     #[token("EXPIRE", ignore(ascii_case))]
     EXPIRE,
+    #[token("EXPORT", ignore(ascii_case))]
+    EXPORT,
     #[token("EXTRACT", ignore(ascii_case))]
     EXTRACT,
     #[token("ELSEIF", ignore(ascii_case))]
@@ -590,6 +608,8 @@ pub enum TokenKind {
     NULL_IF,
     #[token("FILE_FORMAT", ignore(ascii_case))]
     FILE_FORMAT,
+    #[token("FILE_NAME_TEMPLATE", ignore(ascii_case))]
+    FILE_NAME_TEMPLATE,
     #[token("FILE", ignore(ascii_case))]
     FILE,
     #[token("FILES", ignore(ascii_case))]
@@ -606,6 +626,8 @@ pub enum TokenKind {
     FLOAT64,
     #[token("FOR", ignore(ascii_case))]
     FOR,
+    #[token("FOREIGN", ignore(ascii_case))]
+    FOREIGN,
     #[token("FORCE", ignore(ascii_case))]
     FORCE,
     #[token("FORMAT", ignore(ascii_case))]
@@ -658,6 +680,8 @@ pub enum TokenKind {
     HOUR,
     #[token("HOURS", ignore(ascii_case))]
     HOURS,
+    #[token("HUDI", ignore(ascii_case))]
+    HUDI,
     #[token("ICEBERG", ignore(ascii_case))]
     ICEBERG,
     #[token("INTERSECT", ignore(ascii_case))]
@@ -668,6 +692,8 @@ pub enum TokenKind {
     IDENTIFIER,
     #[token("IF", ignore(ascii_case))]
     IF,
+    #[token("ILIKE", ignore(ascii_case))]
+    ILIKE,
     #[token("IN", ignore(ascii_case))]
     IN,
     #[token("INCREMENTAL", ignore(ascii_case))]
@@ -702,6 +728,8 @@ pub enum TokenKind {
     INVERTED,
     #[token("IMMEDIATE", ignore(ascii_case))]
     IMMEDIATE,
+    #[token("IMPORT", ignore(ascii_case))]
+    IMPORT,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -712,6 +740,8 @@ pub enum TokenKind {
     JOIN,
     #[token("JSON", ignore(ascii_case))]
     JSON,
+    #[token("JSON_TABLE", ignore(ascii_case))]
+    JSON_TABLE,
     #[token("JULIAN", ignore(ascii_case))]
     JULIAN,
     #[token("JWT", ignore(ascii_case))]
@@ -789,6 +819,8 @@ pub enum TokenKind {
     NON_DISPLAY,
     #[token("NATURAL", ignore(ascii_case))]
     NATURAL,
+    #[token("NESTED", ignore(ascii_case))]
+    NESTED,
     #[token("NETWORK", ignore(ascii_case))]
     NETWORK,
     #[token("DISABLED", ignore(ascii_case))]
@@ -797,8 +829,12 @@ pub enum TokenKind {
     NDJSON,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
+    #[token("NODE", ignore(ascii_case))]
+    NODE,
     #[token("NONE", ignore(ascii_case))]
     NONE,
+    #[token("NORELY", ignore(ascii_case))]
+    NORELY,
     #[token("NOT", ignore(ascii_case))]
     NOT,
     #[token("NOTENANTSETTING", ignore(ascii_case))]
@@ -831,6 +867,8 @@ pub enum TokenKind {
     ORC,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("ORDINALITY", ignore(ascii_case))]
+    ORDINALITY,
     #[token("OUTPUT_HEADER", ignore(ascii_case))]
     OUTPUT_HEADER,
     #[token("OUTER", ignore(ascii_case))]
@@ -869,6 +907,8 @@ pub enum TokenKind {
     PASSWORD_LOCKOUT_TIME_MINS,
     #[token("PASSWORD_HISTORY", ignore(ascii_case))]
     PASSWORD_HISTORY,
+    #[token("PATH", ignore(ascii_case))]
+    PATH,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
     #[token("PIPELINE", ignore(ascii_case))]
@@ -891,6 +931,8 @@ pub enum TokenKind {
     PUT,
     #[token("QUARTER", ignore(ascii_case))]
     QUARTER,
+    #[token("QUERIES", ignore(ascii_case))]
+    QUERIES,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
     #[token("QUOTE", ignore(ascii_case))]
@@ -907,14 +949,20 @@ pub enum TokenKind {
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
     REFERENCE_USAGE,
+    #[token("REFERENCES", ignore(ascii_case))]
+    REFERENCES,
     #[token("REFRESH", ignore(ascii_case))]
     REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
     REGEXP,
+    #[token("RELY", ignore(ascii_case))]
+    RELY,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("RESTRICT", ignore(ascii_case))]
+    RESTRICT,
     #[token("RETURN_FAILED_ONLY", ignore(ascii_case))]
     RETURN_FAILED_ONLY,
     #[token("REVERSE", ignore(ascii_case))]
@@ -959,6 +1007,8 @@ pub enum TokenKind {
     REVOKE,
     #[token("RECURSIVE", ignore(ascii_case))]
     RECURSIVE,
+    #[token("RESTORE", ignore(ascii_case))]
+    RESTORE,
     #[token("RETURN", ignore(ascii_case))]
     RETURN,
     #[token("RETURNS", ignore(ascii_case))]
@@ -995,6 +1045,8 @@ pub enum TokenKind {
     UNPIVOT,
     #[token("SEGMENT", ignore(ascii_case))]
     SEGMENT,
+    #[token("SEGMENTS", ignore(ascii_case))]
+    SEGMENTS,
     #[token("SET", ignore(ascii_case))]
     SET,
     #[token("UNSET", ignore(ascii_case))]
@@ -1007,6 +1059,8 @@ pub enum TokenKind {
     STAGES,
     #[token("STATISTIC", ignore(ascii_case))]
     STATISTIC,
+    #[token("STATISTICS", ignore(ascii_case))]
+    STATISTICS,
     #[token("SUMMARY", ignore(ascii_case))]
     SUMMARY,
     #[token("SHA256_PASSWORD", ignore(ascii_case))]
@@ -1069,16 +1123,22 @@ pub enum TokenKind {
     SEMI,
     #[token("SOUNDS", ignore(ascii_case))]
     SOUNDS,
+    #[token("SWAP", ignore(ascii_case))]
+    SWAP,
     #[token("SYNC", ignore(ascii_case))]
     SYNC,
     #[token("SYSTEM", ignore(ascii_case))]
     SYSTEM,
+    #[token("STORAGE_POLICY", ignore(ascii_case))]
+    STORAGE_POLICY,
     #[token("STORAGE_TYPE", ignore(ascii_case))]
     STORAGE_TYPE,
     #[token("TABLE", ignore(ascii_case))]
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TARGET_LAG", ignore(ascii_case))]
     TARGET_LAG,
     #[token("TEXT", ignore(ascii_case))]
@@ -1131,6 +1191,8 @@ pub enum TokenKind {
     TYPE,
     #[token("UNBOUNDED", ignore(ascii_case))]
     UNBOUNDED,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UNION", ignore(ascii_case))]
     UNION,
     #[token("UINT16", ignore(ascii_case))]
@@ -1173,6 +1235,8 @@ pub enum TokenKind {
     VARIANT,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
+    #[token("VERIFY", ignore(ascii_case))]
+    VERIFY,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
     #[token("VIEWS", ignore(ascii_case))]
@@ -1247,6 +1311,8 @@ pub enum TokenKind {
     TOP,
     #[token("WAREHOUSE", ignore(ascii_case))]
     WAREHOUSE,
+    #[token("WARNINGS", ignore(ascii_case))]
+    WARNINGS,
     #[token("SCHEDULE", ignore(ascii_case))]
     SCHEDULE,
     #[token("SUSPEND_TASK_AFTER_NUM_FAILURES", ignore(ascii_case))]
@@ -1259,6 +1325,8 @@ pub enum TokenKind {
     SUSPEND,
     #[token("RESUME", ignore(ascii_case))]
     RESUME,
+    #[token("REPLAY", ignore(ascii_case))]
+    REPLAY,
     #[token("PIPE", ignore(ascii_case))]
     PIPE,
     #[token("NOTIFICATION", ignore(ascii_case))]
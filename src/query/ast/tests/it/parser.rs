@@ -145,6 +145,7 @@ fn test_statement() {
         r#"truncate table "a".b;"#,
         r#"drop table a;"#,
         r#"drop table if exists a."b";"#,
+        r#"drop table a restrict;"#,
         r#"use "a";"#,
         r#"create catalog ctl type=hive connection=(url='<hive-meta-store>' thrift_protocol='binary');"#,
         r#"create database if not exists a;"#,
@@ -159,6 +160,7 @@ fn test_statement() {
         r#"create view v as select number % 3 as a from numbers(1000);"#,
         r#"alter view v as select number % 3 as a from numbers(1000);"#,
         r#"drop view v;"#,
+        r#"drop view v cascade;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"create or replace view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
@@ -256,12 +258,15 @@ fn test_statement() {
         r#"drop role if exists 'test'"#,
         r#"OPTIMIZE TABLE t COMPACT SEGMENT LIMIT 10;"#,
         r#"OPTIMIZE TABLE t COMPACT LIMIT 10;"#,
+        r#"OPTIMIZE TABLE t RECLUSTER LIMIT 10;"#,
+        r#"OPTIMIZE TABLE t;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5') LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (TIMESTAMP => '2023-06-26 09:49:02.038483'::TIMESTAMP) LIMIT 10;"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t COMMENT='t1-commnet';"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0 LIMIT 10;"#,
+        r#"ALTER TABLE t RECLUSTER LAST 10 SEGMENTS;"#,
         r#"ALTER TABLE t ADD c int null;"#,
         r#"ALTER TABLE t ADD COLUMN c int null;"#,
         r#"ALTER TABLE t ADD COLUMN a float default 1.1 COMMENT 'hello' FIRST;"#,
@@ -437,6 +442,11 @@ fn test_statement() {
                     skip_header = 1
                 );
         "#,
+        r#"
+            COPY INTO ('https://bucket.example.com/a.csv?sig=1', 'https://bucket.example.com/b.csv?sig=2')
+                FROM mytable
+                SINGLE = true;
+        "#,
         r#"
             COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
@@ -737,6 +747,7 @@ fn test_statement() {
         r#"DESC CONNECTION my_conn;"#,
         r#"SHOW CONNECTIONS;"#,
         r#"SHOW LOCKS IN ACCOUNT"#,
+        r#"SHOW WARNINGS"#,
         // pipes
         r#"CREATE PIPE IF NOT EXISTS MyPipe1 AUTO_INGEST = TRUE COMMENT = 'This is test pipe 1' AS COPY INTO MyTable1 FROM '@~/MyStage1' FILE_FORMAT = (TYPE = 'CSV')"#,
         r#"CREATE PIPE pipe1 AS COPY INTO db1.MyTable1 FROM @~/mybucket/data.csv"#,
@@ -75,6 +75,8 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SwapTableReply;
+use databend_common_meta_app::schema::SwapTableReq;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -84,6 +86,7 @@ use databend_common_meta_app::schema::UndropDatabaseReq;
 use databend_common_meta_app::schema::UndropTableByIdReq;
 use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
+use databend_common_meta_app::schema::UpdateDatabaseOptionsReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
@@ -194,6 +197,12 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
 
     async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply>;
 
+    async fn update_database_options(&self, _req: UpdateDatabaseOptionsReq) -> Result<()> {
+        Err(ErrorCode::Unimplemented(
+            "'update_database_options' not implemented",
+        ))
+    }
+
     /// Table.
 
     // Build a `Arc<dyn Table>` from `TableInfo`.
@@ -266,6 +275,10 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
 
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply>;
 
+    async fn swap_table(&self, _req: SwapTableReq) -> Result<SwapTableReply> {
+        Err(ErrorCode::Unimplemented("'swap_table' not implemented"))
+    }
+
     // Check a db.table is exists or not.
     #[async_backtrace::framed]
     async fn exists_table(&self, tenant: &Tenant, db_name: &str, table_name: &str) -> Result<bool> {
@@ -74,6 +74,8 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SwapTableReply;
+use databend_common_meta_app::schema::SwapTableReq;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -83,6 +85,7 @@ use databend_common_meta_app::schema::UndropDatabaseReq;
 use databend_common_meta_app::schema::UndropTableByIdReq;
 use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
+use databend_common_meta_app::schema::UpdateDatabaseOptionsReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
@@ -218,6 +221,10 @@ impl Catalog for SessionCatalog {
         self.inner.rename_database(req).await
     }
 
+    async fn update_database_options(&self, req: UpdateDatabaseOptionsReq) -> Result<()> {
+        self.inner.update_database_options(req).await
+    }
+
     /// Table.
 
     // Build a `Arc<dyn Table>` from `TableInfo`.
@@ -345,6 +352,10 @@ impl Catalog for SessionCatalog {
         self.inner.rename_table(req).await
     }
 
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply> {
+        self.inner.swap_table(req).await
+    }
+
     async fn upsert_table_option(
         &self,
         tenant: &Tenant,
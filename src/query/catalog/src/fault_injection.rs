@@ -0,0 +1,192 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::time::Duration;
+
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_settings::Settings;
+use rand::thread_rng;
+use rand::Rng;
+
+const MAX_COMMIT_DELAY_MILLIS: u64 = 10_000;
+
+/// The named points of the query execution where the faults can be injected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FaultPoint {
+    /// Planning a statement, before its tables are resolved.
+    Plan,
+    /// Sending the data blocks to the other nodes of the cluster.
+    ExchangeSend,
+    /// Reading the columns of a block from the storage.
+    BlockRead,
+    /// Committing the new snapshot of a table, or of all the tables of a multi-table insert.
+    Commit,
+}
+
+impl FaultPoint {
+    fn from_name(name: &str) -> Option<FaultPoint> {
+        match name {
+            "plan" => Some(FaultPoint::Plan),
+            "exchange_send" => Some(FaultPoint::ExchangeSend),
+            "block_read" => Some(FaultPoint::BlockRead),
+            "commit" => Some(FaultPoint::Commit),
+            _ => None,
+        }
+    }
+}
+
+impl Display for FaultPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FaultPoint::Plan => write!(f, "plan"),
+            FaultPoint::ExchangeSend => write!(f, "exchange_send"),
+            FaultPoint::BlockRead => write!(f, "block_read"),
+            FaultPoint::Commit => write!(f, "commit"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum FaultAction {
+    Delay(Duration),
+    Error,
+    OutOfMemory,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Fault {
+    point: FaultPoint,
+    action: FaultAction,
+    probability: f64,
+}
+
+/// Faults injected at the named points, configured by the setting `fault_injection` as a
+/// comma separated list of `<point>:<action>[@<probability>]`, for example:
+///
+/// `exchange_send:error@0.1,block_read:delay=200,commit:oom`
+///
+/// The actions are `error`, `oom` and `delay=<milliseconds>`, the probability is 1 by default.
+///
+/// The faults are only injected if the binary is built with the `fault-injection` feature and
+/// the node config `enable_fault_injection` is on, otherwise the queries fail if the setting
+/// is not empty. The delay of `commit` is limited to `MAX_COMMIT_DELAY_MILLIS`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FaultInjection {
+    faults: Vec<Fault>,
+}
+
+impl FaultInjection {
+    pub fn try_create(settings: &Settings) -> Result<FaultInjection> {
+        FaultInjection::parse(&settings.get_fault_injection()?)
+    }
+
+    pub fn parse(text: &str) -> Result<FaultInjection> {
+        let mut faults = vec![];
+        for item in text
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            let invalid = || {
+                ErrorCode::InvalidArgument(format!(
+                    "Invalid fault injection '{item}', expect '<point>:<action>[@<probability>]'"
+                ))
+            };
+
+            let (fault, probability) = match item.split_once('@') {
+                None => (item, 1.0),
+                Some((fault, probability)) => {
+                    let probability = probability.trim().parse::<f64>().map_err(|_| invalid())?;
+                    if !(0.0..=1.0).contains(&probability) {
+                        return Err(invalid());
+                    }
+                    (fault, probability)
+                }
+            };
+            let (point, action) = fault.split_once(':').ok_or_else(invalid)?;
+            let point = FaultPoint::from_name(point.trim()).ok_or_else(invalid)?;
+            let action = match action.trim() {
+                "error" => FaultAction::Error,
+                "oom" => FaultAction::OutOfMemory,
+                action => {
+                    let millis = action
+                        .strip_prefix("delay=")
+                        .and_then(|millis| millis.trim().parse::<u64>().ok())
+                        .ok_or_else(invalid)?;
+                    // The commit holds the table until it's done, so don't let it stall too long.
+                    if point == FaultPoint::Commit && millis > MAX_COMMIT_DELAY_MILLIS {
+                        return Err(ErrorCode::InvalidArgument(format!(
+                            "Invalid fault injection '{item}', the delay of commit can't exceed {MAX_COMMIT_DELAY_MILLIS} milliseconds"
+                        )));
+                    }
+                    FaultAction::Delay(Duration::from_millis(millis))
+                }
+            };
+            faults.push(Fault {
+                point,
+                action,
+                probability,
+            });
+        }
+
+        if !faults.is_empty() && !cfg!(feature = "fault-injection") {
+            return Err(ErrorCode::Unimplemented(
+                "Fault injection is not supported, the binary is built without the feature 'fault-injection'",
+            ));
+        }
+        if !faults.is_empty() && !GlobalConfig::instance().query.enable_fault_injection {
+            return Err(ErrorCode::PermissionDenied(
+                "Fault injection is not allowed, you can enable it by setting 'enable_fault_injection = true' in query node config",
+            ));
+        }
+        Ok(FaultInjection { faults })
+    }
+
+    /// Inject the faults configured at the point, the delays are applied before the errors.
+    #[async_backtrace::framed]
+    pub async fn inject(&self, point: FaultPoint) -> Result<()> {
+        for fault in self.faults.iter().filter(|fault| fault.point == point) {
+            if fault.probability < 1.0 && !thread_rng().gen_bool(fault.probability) {
+                continue;
+            }
+            match &fault.action {
+                FaultAction::Delay(duration) => {
+                    databend_common_base::base::tokio::time::sleep(*duration).await;
+                }
+                FaultAction::Error => {
+                    return Err(ErrorCode::FaultInjected(format!(
+                        "Fault injected at {point}"
+                    )));
+                }
+                // Same as the error of a panic caught when the memory usage exceeds the limit.
+                FaultAction::OutOfMemory => {
+                    return Err(ErrorCode::PanicError(format!(
+                        "memory usage exceeds limit, injected at {point}"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Inject the faults configured by the settings at the point.
+#[async_backtrace::framed]
+pub async fn inject_fault(settings: &Settings, point: FaultPoint) -> Result<()> {
+    FaultInjection::try_create(settings)?.inject(point).await
+}
@@ -20,6 +20,9 @@ pub struct ParquetReadOptions {
     /// Prune pages before reading. Require Page level statistics.
     /// Filter rows don't need to read.
     prune_pages: bool,
+    /// Prune row groups by the bloom filters of the columns in the equality predicates.
+    /// Require bloom filters written in the file.
+    prune_bloom_filters: bool,
     /// If use prewhere filter.
     do_prewhere: bool,
     /// If push down bitmap generated by prewhere reader to remain reader.
@@ -49,6 +52,12 @@ impl ParquetReadOptions {
         self
     }
 
+    #[inline]
+    pub fn with_prune_bloom_filters(mut self, v: bool) -> Self {
+        self.prune_bloom_filters = v;
+        self
+    }
+
     #[inline]
     pub fn with_push_down_bitmap(mut self, v: bool) -> Self {
         self.push_down_bitmap = v;
@@ -79,6 +88,11 @@ impl ParquetReadOptions {
         self.prune_pages
     }
 
+    #[inline]
+    pub fn prune_bloom_filters(&self) -> bool {
+        self.prune_bloom_filters
+    }
+
     #[inline]
     pub fn push_down_bitmap(&self) -> bool {
         self.push_down_bitmap
@@ -101,6 +115,7 @@ impl Default for ParquetReadOptions {
             do_prewhere: true,
             prune_row_groups: true,
             prune_pages: true,
+            prune_bloom_filters: true,
             push_down_bitmap: false,
             // refresh_meta_cache: false,
         }
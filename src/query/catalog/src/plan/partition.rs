@@ -13,10 +13,14 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
@@ -55,6 +59,12 @@ pub trait PartInfo: Send + Sync {
     fn part_type(&self) -> PartInfoType {
         PartInfoType::BlockLevel
     }
+
+    /// The id of the cluster key the rows of the partition are sorted by.
+    /// Default is none, the order of the rows is unknown.
+    fn cluster_key_id(&self) -> Option<u32> {
+        None
+    }
 }
 
 impl Debug for Box<dyn PartInfo> {
@@ -94,8 +104,14 @@ pub enum PartitionsShuffleKind {
     Rand,
     // Bind the Partition to executor by broadcast
     Broadcast,
+    // Bind the Partition to executor by consistent hashing of partition.hash(), so the
+    // partition mostly stays on the executor which has cached it when the cluster changes.
+    ConsistentHash,
 }
 
+// The number of virtual nodes of each executor on the hash ring.
+const CONSISTENT_HASH_VIRTUAL_NODES: usize = 128;
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
 pub struct Partitions {
     pub kind: PartitionsShuffleKind,
@@ -149,6 +165,9 @@ impl Partitions {
 
                 return Ok(executor_part);
             }
+            PartitionsShuffleKind::ConsistentHash => {
+                return Ok(self.consistent_hash_reshuffle(executors_sorted));
+            }
         };
 
         // parts_per_executor = num_parts / num_executors
@@ -179,6 +198,39 @@ impl Partitions {
         Ok(executor_part)
     }
 
+    fn consistent_hash_reshuffle(&self, executors: Vec<String>) -> HashMap<String, Partitions> {
+        let mut ring = Vec::with_capacity(executors.len() * CONSISTENT_HASH_VIRTUAL_NODES);
+        for (idx, executor) in executors.iter().enumerate() {
+            for replica in 0..CONSISTENT_HASH_VIRTUAL_NODES {
+                let mut s = DefaultHasher::new();
+                executor.hash(&mut s);
+                replica.hash(&mut s);
+                ring.push((s.finish(), idx));
+            }
+        }
+        ring.sort();
+
+        let mut executor_parts = vec![vec![]; executors.len()];
+        if !ring.is_empty() {
+            for part in self.partitions.iter() {
+                // The first virtual node clockwise from the partition hash.
+                let pos = ring.partition_point(|(hash, _)| *hash < part.hash()) % ring.len();
+                executor_parts[ring[pos].1].push(part.clone());
+            }
+        }
+
+        executors
+            .into_iter()
+            .zip(executor_parts)
+            .map(|(executor, parts)| {
+                (
+                    executor,
+                    Partitions::create(PartitionsShuffleKind::Seq, parts),
+                )
+            })
+            .collect()
+    }
+
     pub fn compute_sha256(&self) -> Result<String> {
         let buf = serde_json::to_vec(&self.partitions)?;
         let sha = sha2::Sha256::digest(buf);
@@ -214,6 +266,9 @@ pub struct StealablePartitions {
     // In some cases, we need to disable steal.
     // Such as topk queries, this is suitable that topk will respect all the pagecache and reduce false sharing between threads.
     pub disable_steal: bool,
+    // The milliseconds the readers of the scan have paused under memory pressure in total,
+    // shared by the readers so that the pause is budgeted per scan rather than per read.
+    pub memory_pressure_paused_ms: Arc<AtomicU64>,
 }
 
 impl StealablePartitions {
@@ -222,6 +277,7 @@ impl StealablePartitions {
             partitions: Arc::new(RwLock::new(partitions)),
             ctx,
             disable_steal: false,
+            memory_pressure_paused_ms: Arc::new(AtomicU64::new(0)),
         }
     }
 
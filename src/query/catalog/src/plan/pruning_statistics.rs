@@ -29,6 +29,10 @@ pub struct PruningStatistics {
     /// Block inverted index filter pruning stats.
     pub blocks_inverted_index_pruning_before: usize,
     pub blocks_inverted_index_pruning_after: usize,
+
+    /// Block limit pruning stats.
+    pub blocks_limit_pruning_before: usize,
+    pub blocks_limit_pruning_after: usize,
 }
 
 impl PruningStatistics {
@@ -41,5 +45,7 @@ impl PruningStatistics {
         self.blocks_bloom_pruning_after += other.blocks_bloom_pruning_after;
         self.blocks_inverted_index_pruning_before += other.blocks_inverted_index_pruning_before;
         self.blocks_inverted_index_pruning_after += other.blocks_inverted_index_pruning_after;
+        self.blocks_limit_pruning_before += other.blocks_limit_pruning_before;
+        self.blocks_limit_pruning_after += other.blocks_limit_pruning_after;
     }
 }
@@ -112,6 +112,9 @@ pub struct PushDownInfo {
     pub prewhere: Option<PrewhereInfo>,
     /// Optional limit to skip read.
     pub limit: Option<usize>,
+    /// Optional limit of the rows matching the filters, it can't be used to skip read,
+    /// but the blocks most likely to match the filters can be read first.
+    pub filter_limit: Option<usize>,
     /// Optional order_by expression plan, asc, null_first.
     pub order_by: Vec<(RemoteExpr<String>, bool, bool)>,
     /// Optional virtual columns
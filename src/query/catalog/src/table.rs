@@ -138,6 +138,12 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// Whether the table engine can read the inner fields of tuples nested in arrays,
+    /// like `a:b` of `a Array(Tuple(b Int32, c String))`, without reading the whole column.
+    fn support_nested_projection(&self) -> bool {
+        false
+    }
+
     fn support_index(&self) -> bool {
         false
     }
@@ -400,6 +406,10 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// Load the metadata needed by the scans of the table into the node-local cache in
+    /// background, e.g. the snapshot and segments of fuse table.
+    fn prefetch_metadata(&self, _ctx: Arc<dyn TableContext>) {}
+
     fn result_can_be_cached(&self) -> bool {
         false
     }
@@ -42,6 +42,7 @@ use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_pipeline_core::processors::PlanProfile;
@@ -69,7 +70,9 @@ use crate::lock::LockTableOption;
 use crate::merge_into_join::MergeIntoJoin;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
+use crate::plan::PartStatistics;
 use crate::plan::Partitions;
+use crate::plan::PushDownInfo;
 use crate::query_kind::QueryKind;
 use crate::runtime_filter_info::RuntimeFilterInfo;
 use crate::statistics::data_cache_statistics::DataCacheMetrics;
@@ -114,6 +117,80 @@ impl Display for ProcessInfoState {
     }
 }
 
+/// The fragment of a distributed query which is executed on the current node.
+#[derive(Debug, Clone)]
+pub struct QueryFragmentInfo {
+    pub query_id: String,
+    pub fragment_id: usize,
+    pub state: QueryFragmentState,
+    /// The ids and names of the physical plans executed by the fragment.
+    pub plans: Vec<(u32, String)>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum QueryFragmentState {
+    /// The pipeline of the fragment is built, waiting for the query to start.
+    Initialized,
+    Running,
+    /// The pipeline of the fragment is done on the node, the fragment is kept for a while
+    /// after the query is finished on the node.
+    Finished,
+}
+
+impl Display for QueryFragmentState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            QueryFragmentState::Initialized => write!(f, "Initialized"),
+            QueryFragmentState::Running => write!(f, "Running"),
+            QueryFragmentState::Finished => write!(f, "Finished"),
+        }
+    }
+}
+
+/// The progress of a long maintenance statement, i.e. recluster, compact, purge and vacuum,
+/// which is returned with each polling response of the HTTP handler, so that the clients
+/// can render a progress bar. The readable status is still set by `set_status_info`.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default, Eq, PartialEq)]
+pub struct MaintenanceProgress {
+    /// `recluster`, `compact`, `purge` or `vacuum`.
+    pub operation: String,
+    /// The phase of the operation, e.g. `read_segments`.
+    pub phase: String,
+    pub tasks_done: u64,
+    /// 0 if the number of the tasks of the phase is not known yet.
+    pub tasks_total: u64,
+    /// The bytes written or purged by the phase, 0 if they are not tracked.
+    pub bytes: u64,
+}
+
+impl MaintenanceProgress {
+    pub fn new(operation: &str, phase: &str, tasks_done: usize, tasks_total: usize) -> Self {
+        MaintenanceProgress {
+            operation: operation.to_string(),
+            phase: phase.to_string(),
+            tasks_done: tasks_done as u64,
+            tasks_total: tasks_total as u64,
+            bytes: 0,
+        }
+    }
+
+    pub fn with_bytes(mut self, bytes: u64) -> Self {
+        self.bytes = bytes;
+        self
+    }
+}
+
+/// A repeated subquery to be materialized for the following queries, which read the
+/// materialized result instead as long as the version is unchanged.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SubqueryMaterialization {
+    pub fingerprint: String,
+    /// The ids and the versions of the tables read by the subquery.
+    pub version: String,
+    /// The subquery to run to materialize it.
+    pub sql: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct StageAttachment {
     pub location: String,
@@ -153,6 +230,8 @@ pub trait TableContext: Send + Sync {
     fn get_result_progress_value(&self) -> ProgressValues;
     fn get_status_info(&self) -> String;
     fn set_status_info(&self, info: &str);
+    fn get_maintenance_progress(&self) -> Option<MaintenanceProgress>;
+    fn set_maintenance_progress(&self, progress: MaintenanceProgress);
     fn get_data_cache_metrics(&self) -> &DataCacheMetrics;
     fn get_partition(&self) -> Option<PartInfoPtr>;
     fn get_partitions(&self, num: usize) -> Vec<PartInfoPtr>;
@@ -160,6 +239,15 @@ pub trait TableContext: Send + Sync {
         unimplemented!()
     }
     fn set_partitions(&self, partitions: Partitions) -> Result<()>;
+    /// Prunes the lazy parts of the segments of the table on the nodes of the cluster, each
+    /// node prunes its own share of the segments and returns the parts of the surviving blocks.
+    async fn prune_lazy_parts_in_cluster(
+        &self,
+        table_info: &TableInfo,
+        push_downs: Option<PushDownInfo>,
+        snapshot_loc: Option<String>,
+        lazy_parts: Partitions,
+    ) -> Result<(PartStatistics, Partitions)>;
     fn add_partitions_sha(&self, sha: String);
     fn get_partitions_shas(&self) -> Vec<String>;
     fn get_cacheable(&self) -> bool;
@@ -170,6 +258,8 @@ pub trait TableContext: Send + Sync {
     fn set_enable_sort_spill(&self, enable: bool);
     fn set_compaction_num_block_hint(&self, hint: u64);
     fn get_compaction_num_block_hint(&self) -> u64;
+    fn set_compaction_num_segment_hint(&self, hint: u64);
+    fn get_compaction_num_segment_hint(&self) -> u64;
 
     fn attach_query_str(&self, kind: QueryKind, query: String);
     fn attach_query_hash(&self, text_hash: String, parameterized_hash: String);
@@ -182,6 +272,7 @@ pub trait TableContext: Send + Sync {
     async fn get_catalog(&self, catalog_name: &str) -> Result<Arc<dyn Catalog>>;
     fn get_default_catalog(&self) -> Result<Arc<dyn Catalog>>;
     fn get_id(&self) -> String;
+    fn get_created_time(&self) -> SystemTime;
     fn get_current_catalog(&self) -> String;
     fn check_aborting(&self) -> Result<()>;
     fn get_abort_checker(self: Arc<Self>) -> AbortChecker
@@ -202,6 +293,27 @@ pub trait TableContext: Send + Sync {
     }
     fn get_error(&self) -> Option<ErrorCode>;
     fn push_warning(&self, warning: String);
+    /// Counts a run of an expensive subquery in the scope of `repeated_subquery_tracking_scope`,
+    /// returns the times it has run.
+    fn record_subquery_run(&self, fingerprint: &str) -> Result<u64>;
+    fn get_subquery_materialization(
+        &self,
+        fingerprint: &str,
+        version: &str,
+    ) -> Result<Option<Arc<Vec<DataBlock>>>>;
+    /// The subqueries are materialized once the statement is bound.
+    fn add_pending_subquery_materialization(&self, materialization: SubqueryMaterialization);
+    /// Materializes the pending subqueries, returns true if any of them is materialized, then
+    /// the statement is bound again to read the materialized results.
+    async fn materialize_pending_subqueries(&self) -> Result<bool>;
+    /// Finishes the backfills of the columns added to the table, before the statement
+    /// rewrites the blocks of the table.
+    async fn finish_column_backfills(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<()>;
     fn get_current_database(&self) -> String;
     fn get_current_user(&self) -> Result<UserInfo>;
     fn get_current_role(&self) -> Option<RoleInfo>;
@@ -231,6 +343,7 @@ pub trait TableContext: Send + Sync {
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
     fn get_queued_queries(&self) -> Vec<ProcessInfo>;
     fn get_queries_profile(&self) -> HashMap<String, Vec<PlanProfile>>;
+    fn get_queries_fragments(&self) -> Vec<QueryFragmentInfo>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn get_last_query_id(&self, index: i32) -> String;
     fn get_query_id_history(&self) -> HashSet<String>;
@@ -249,6 +362,10 @@ pub trait TableContext: Send + Sync {
 
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams>;
 
+    /// Checks the format of the params is provided by a registered plugin if it's not built
+    /// in, and the plugin accepts the options.
+    fn check_file_format_params(&self, params: &FileFormatParams) -> Result<()>;
+
     async fn get_connection(&self, name: &str) -> Result<UserDefinedConnection>;
 
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
@@ -218,6 +218,46 @@ fn test_partition_reshuffle() {
     }
 }
 
+#[test]
+fn test_partition_consistent_hash_reshuffle() {
+    let executors_3 = vec![
+        "node-1".to_string(),
+        "node-2".to_string(),
+        "node-3".to_string(),
+    ];
+    let executors_4 = vec![
+        "node-1".to_string(),
+        "node-2".to_string(),
+        "node-3".to_string(),
+        "node-4".to_string(),
+    ];
+
+    let partitions = gen_parts(PartitionsShuffleKind::ConsistentHash, 1000);
+    let shuffle_3 = partitions.reshuffle(executors_3.clone()).unwrap();
+    let shuffle_4 = partitions.reshuffle(executors_4.clone()).unwrap();
+
+    // Every partition is assigned to exactly one executor.
+    let total = shuffle_3.values().map(|parts| parts.len()).sum::<usize>();
+    assert_eq!(total, 1000);
+    for executor in executors_3.iter() {
+        assert!(!shuffle_3.get(executor).unwrap().is_empty());
+    }
+
+    // The assignment is stable.
+    assert_eq!(
+        shuffle_3,
+        partitions.reshuffle(executors_3.clone()).unwrap()
+    );
+
+    // Adding an executor only moves partitions to the new executor.
+    for executor in executors_3.iter() {
+        let parts_4 = &shuffle_4.get(executor).unwrap().partitions;
+        for part in parts_4.iter() {
+            assert!(shuffle_3.get(executor).unwrap().partitions.contains(part));
+        }
+    }
+}
+
 #[test]
 fn test_split() {
     for seg in 0..1024 * 10 {
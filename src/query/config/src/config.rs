@@ -29,6 +29,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::UserSettingValue;
 use databend_common_meta_app::storage::StorageAzblobConfig as InnerStorageAzblobConfig;
+use databend_common_meta_app::storage::StorageAzdlsConfig as InnerStorageAzdlsConfig;
 use databend_common_meta_app::storage::StorageCosConfig as InnerStorageCosConfig;
 use databend_common_meta_app::storage::StorageFsConfig as InnerStorageFsConfig;
 use databend_common_meta_app::storage::StorageGcsConfig as InnerStorageGcsConfig;
@@ -285,6 +286,10 @@ pub struct StorageConfig {
     #[clap(flatten)]
     pub azblob: AzblobStorageConfig,
 
+    // azure data lake storage gen2 config.
+    #[clap(flatten)]
+    pub azdls: AzdlsStorageConfig,
+
     // hdfs storage backend config
     #[clap(flatten)]
     pub hdfs: HdfsConfig,
@@ -327,6 +332,7 @@ impl From<InnerStorageConfig> for StorageConfig {
             s3: Default::default(),
             oss: Default::default(),
             azblob: Default::default(),
+            azdls: Default::default(),
             hdfs: Default::default(),
             obs: Default::default(),
             webhdfs: Default::default(),
@@ -342,6 +348,10 @@ impl From<InnerStorageConfig> for StorageConfig {
                 cfg.typ = "azblob".to_string();
                 cfg.azblob = v.into();
             }
+            StorageParams::Azdls(v) => {
+                cfg.typ = "azdls".to_string();
+                cfg.azdls = v.into();
+            }
             StorageParams::Fs(v) => {
                 cfg.typ = "fs".to_string();
                 cfg.fs = v.into();
@@ -406,6 +416,7 @@ impl TryInto<InnerStorageConfig> for StorageConfig {
             params: {
                 match self.typ.as_str() {
                     "azblob" => StorageParams::Azblob(self.azblob.try_into()?),
+                    "azdls" => StorageParams::Azdls(self.azdls.try_into()?),
                     "fs" => StorageParams::Fs(self.fs.try_into()?),
                     "gcs" => StorageParams::Gcs(self.gcs.try_into()?),
                     #[cfg(feature = "storage-hdfs")]
@@ -877,6 +888,91 @@ impl TryInto<InnerStorageAzblobConfig> for AzblobStorageConfig {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct AzdlsStorageConfig {
+    /// Account for Azdls
+    #[clap(
+        long = "storage-azdls-account-name",
+        value_name = "VALUE",
+        default_value_t
+    )]
+    pub account_name: String,
+
+    /// Master key for Azdls
+    #[clap(
+        long = "storage-azdls-account-key",
+        value_name = "VALUE",
+        default_value_t
+    )]
+    pub account_key: String,
+
+    /// Filesystem for Azdls
+    #[clap(
+        long = "storage-azdls-filesystem",
+        value_name = "VALUE",
+        default_value_t
+    )]
+    pub filesystem: String,
+
+    /// Endpoint URL for Azdls, e.g. `https://<account>.dfs.core.windows.net`
+    #[clap(
+        long = "storage-azdls-endpoint-url",
+        value_name = "VALUE",
+        default_value_t
+    )]
+    #[serde(rename = "endpoint_url")]
+    pub azdls_endpoint_url: String,
+
+    #[clap(long = "storage-azdls-root", value_name = "VALUE", default_value_t)]
+    #[serde(rename = "root")]
+    pub azdls_root: String,
+}
+
+impl Default for AzdlsStorageConfig {
+    fn default() -> Self {
+        InnerStorageAzdlsConfig::default().into()
+    }
+}
+
+impl fmt::Debug for AzdlsStorageConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AzdlsStorageConfig")
+            .field("endpoint_url", &self.azdls_endpoint_url)
+            .field("filesystem", &self.filesystem)
+            .field("root", &self.azdls_root)
+            .field("account_name", &mask_string(&self.account_name, 3))
+            .field("account_key", &mask_string(&self.account_key, 3))
+            .finish()
+    }
+}
+
+impl From<InnerStorageAzdlsConfig> for AzdlsStorageConfig {
+    fn from(inner: InnerStorageAzdlsConfig) -> Self {
+        Self {
+            account_name: inner.account_name,
+            account_key: inner.account_key,
+            filesystem: inner.filesystem,
+            azdls_endpoint_url: inner.endpoint_url,
+            azdls_root: inner.root,
+        }
+    }
+}
+
+impl TryInto<InnerStorageAzdlsConfig> for AzdlsStorageConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerStorageAzdlsConfig> {
+        Ok(InnerStorageAzdlsConfig {
+            endpoint_url: self.azdls_endpoint_url,
+            filesystem: self.filesystem,
+            account_name: self.account_name,
+            account_key: self.account_key,
+            root: self.azdls_root,
+        })
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args, Debug)]
 #[serde(default)]
 pub struct HdfsConfig {
@@ -1506,9 +1602,54 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "5000")]
     pub shutdown_wait_timeout_ms: u64,
 
+    /// Kill user sessions that have been idle for longer than this, 0 means never.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub idle_session_timeout_secs: u64,
+
+    /// How often to look for idle sessions and queries whose client has gone away, 0 disables it.
+    #[clap(long, value_name = "VALUE", default_value = "10")]
+    pub session_reaper_interval_secs: u64,
+
+    /// How often to move the old segments of the tables with a storage policy to the cold
+    /// storage, 0 disables it.
+    #[clap(long, value_name = "VALUE", default_value = "3600")]
+    pub tiering_interval_secs: u64,
+
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// Purge the system history (query log, profiles, usage, ...) older than this many days, 0 means never.
+    #[clap(long, value_name = "VALUE", default_value = "7")]
+    pub system_history_retention_days: u64,
+
+    /// Purge the oldest system history when its memory size exceeds this many bytes, 0 means no limit.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub system_history_max_bytes: u64,
+
+    /// Rebuild the pooled operator of a stage after this many seconds, which loads its temporary credentials again before they expire, 0 disables the pool.
+    #[clap(long, value_name = "VALUE", default_value = "900")]
+    pub stage_operator_refresh_secs: u64,
+
+    /// The worker threads reserved for the batch queries (COPY, INSERT, ...), which are shared by the running batch queries to keep them from starving the interactive queries, 0 means the batch queries are not limited.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub batch_executor_threads: u64,
+
+    /// The memory size of the hash join build sides cached for the following queries, shared by all the sessions of the node, the least recently used ones are evicted first.
+    #[clap(long, value_name = "VALUE", default_value = "1073741824")]
+    pub hash_join_build_cache_bytes: u64,
+
+    /// The memory size of the subqueries materialized with `repeated_subquery_tracking_scope = 'warehouse'`, shared by all the sessions of the node, the least recently used ones are evicted first.
+    #[clap(long, value_name = "VALUE", default_value = "67108864")]
+    pub subquery_materialization_cache_bytes: u64,
+
+    /// How often the scaling advisor samples the load of the node and posts its recommendation to the webhook.
+    #[clap(long, value_name = "VALUE", default_value = "30")]
+    pub scaling_advisor_interval_secs: u64,
+
+    /// The url the scaling advisor posts its scale up or down recommendations to, empty means no webhook.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub scaling_advisor_webhook_url: String,
+
     #[clap(long, value_name = "VALUE")]
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
@@ -1659,10 +1800,19 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "false")]
     pub enable_udf_server: bool,
 
+    /// Allows the sessions to inject faults with the setting `fault_injection`, only for testing.
+    #[clap(long, value_name = "VALUE", default_value = "false")]
+    pub enable_fault_injection: bool,
+
     /// A list of allowed udf server addresses.
     #[clap(long, value_name = "VALUE")]
     pub udf_server_allow_list: Vec<String>,
 
+    /// A list of the addresses the files can be unloaded into with `COPY INTO ('<url>', ...)`,
+    /// the subdomains of the hosts are allowed too. No url is allowed if it's empty.
+    #[clap(long, value_name = "VALUE")]
+    pub unload_url_allow_list: Vec<String>,
+
     #[clap(long)]
     pub cloud_control_grpc_server_address: Option<String>,
 
@@ -1727,7 +1877,18 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             rpc_client_timeout_secs: self.rpc_client_timeout_secs,
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: self.shutdown_wait_timeout_ms,
+            idle_session_timeout_secs: self.idle_session_timeout_secs,
+            session_reaper_interval_secs: self.session_reaper_interval_secs,
+            tiering_interval_secs: self.tiering_interval_secs,
             max_query_log_size: self.max_query_log_size,
+            system_history_retention_days: self.system_history_retention_days,
+            system_history_max_bytes: self.system_history_max_bytes,
+            stage_operator_refresh_secs: self.stage_operator_refresh_secs,
+            batch_executor_threads: self.batch_executor_threads,
+            hash_join_build_cache_bytes: self.hash_join_build_cache_bytes,
+            subquery_materialization_cache_bytes: self.subquery_materialization_cache_bytes,
+            scaling_advisor_interval_secs: self.scaling_advisor_interval_secs,
+            scaling_advisor_webhook_url: self.scaling_advisor_webhook_url,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
@@ -1754,7 +1915,9 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             openai_api_embedding_model: self.openai_api_embedding_model,
             openai_api_version: self.openai_api_version,
             enable_udf_server: self.enable_udf_server,
+            enable_fault_injection: self.enable_fault_injection,
             udf_server_allow_list: self.udf_server_allow_list,
+            unload_url_allow_list: self.unload_url_allow_list,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: self.cloud_control_grpc_timeout,
             max_cached_queries_profiles: self.max_cached_queries_profiles,
@@ -1814,7 +1977,18 @@ impl From<InnerQueryConfig> for QueryConfig {
             rpc_client_timeout_secs: inner.rpc_client_timeout_secs,
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: inner.shutdown_wait_timeout_ms,
+            idle_session_timeout_secs: inner.idle_session_timeout_secs,
+            session_reaper_interval_secs: inner.session_reaper_interval_secs,
+            tiering_interval_secs: inner.tiering_interval_secs,
             max_query_log_size: inner.max_query_log_size,
+            system_history_retention_days: inner.system_history_retention_days,
+            system_history_max_bytes: inner.system_history_max_bytes,
+            stage_operator_refresh_secs: inner.stage_operator_refresh_secs,
+            batch_executor_threads: inner.batch_executor_threads,
+            hash_join_build_cache_bytes: inner.hash_join_build_cache_bytes,
+            subquery_materialization_cache_bytes: inner.subquery_materialization_cache_bytes,
+            scaling_advisor_interval_secs: inner.scaling_advisor_interval_secs,
+            scaling_advisor_webhook_url: inner.scaling_advisor_webhook_url,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
@@ -1853,7 +2027,9 @@ impl From<InnerQueryConfig> for QueryConfig {
             openai_api_completion_model: inner.openai_api_completion_model,
             openai_api_embedding_model: inner.openai_api_embedding_model,
             enable_udf_server: inner.enable_udf_server,
+            enable_fault_injection: inner.enable_fault_injection,
             udf_server_allow_list: inner.udf_server_allow_list,
+            unload_url_allow_list: inner.unload_url_allow_list,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: inner.cloud_control_grpc_timeout,
             max_cached_queries_profiles: inner.max_cached_queries_profiles,
@@ -198,7 +198,29 @@ pub struct QueryConfig {
     pub table_engine_memory_enabled: bool,
     /// Graceful shutdown timeout
     pub shutdown_wait_timeout_ms: u64,
+    /// Idle user sessions are killed after this many seconds, 0 means never
+    pub idle_session_timeout_secs: u64,
+    /// Interval of the background reaper of idle sessions and orphaned queries
+    pub session_reaper_interval_secs: u64,
+    /// Interval of the background move of the old segments to the cold storage
+    pub tiering_interval_secs: u64,
     pub max_query_log_size: usize,
+    /// System history older than this many days is purged, 0 means never
+    pub system_history_retention_days: u64,
+    /// The oldest system history is purged when its memory size exceeds this, 0 means no limit
+    pub system_history_max_bytes: u64,
+    /// The pooled operator of a stage is rebuilt after this many seconds, 0 disables the pool
+    pub stage_operator_refresh_secs: u64,
+    /// The worker threads reserved for the batch queries, 0 means not limited
+    pub batch_executor_threads: u64,
+    /// The memory size of the hash join build sides cached by the node
+    pub hash_join_build_cache_bytes: u64,
+    /// The memory size of the subqueries materialized for the whole node
+    pub subquery_materialization_cache_bytes: u64,
+    /// Interval of the scaling advisor posting to the webhook
+    pub scaling_advisor_interval_secs: u64,
+    /// The webhook of the scaling advisor, empty means none
+    pub scaling_advisor_webhook_url: String,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -231,7 +253,9 @@ pub struct QueryConfig {
     pub openai_api_completion_model: String,
 
     pub enable_udf_server: bool,
+    pub enable_fault_injection: bool,
     pub udf_server_allow_list: Vec<String>,
+    pub unload_url_allow_list: Vec<String>,
 
     pub cloud_control_grpc_server_address: Option<String>,
     pub cloud_control_grpc_timeout: u64,
@@ -280,7 +304,18 @@ impl Default for QueryConfig {
             rpc_client_timeout_secs: 0,
             table_engine_memory_enabled: true,
             shutdown_wait_timeout_ms: 5000,
+            idle_session_timeout_secs: 0,
+            session_reaper_interval_secs: 10,
+            tiering_interval_secs: 3600,
             max_query_log_size: 10_000,
+            system_history_retention_days: 7,
+            system_history_max_bytes: 0,
+            stage_operator_refresh_secs: 900,
+            batch_executor_threads: 0,
+            hash_join_build_cache_bytes: 1024 * 1024 * 1024,
+            subquery_materialization_cache_bytes: 64 * 1024 * 1024,
+            scaling_advisor_interval_secs: 30,
+            scaling_advisor_webhook_url: "".to_string(),
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
@@ -304,7 +339,9 @@ impl Default for QueryConfig {
             openai_api_completion_model: "gpt-3.5-turbo".to_string(),
             openai_api_embedding_model: "text-embedding-ada-002".to_string(),
             enable_udf_server: false,
+            enable_fault_injection: false,
             udf_server_allow_list: Vec::new(),
+            unload_url_allow_list: Vec::new(),
             cloud_control_grpc_server_address: None,
             cloud_control_grpc_timeout: 0,
             data_retention_time_in_days_max: 90,
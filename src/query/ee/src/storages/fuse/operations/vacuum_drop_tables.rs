@@ -19,6 +19,7 @@ use databend_common_base::runtime::execute_futures_in_parallel;
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_storage::StageOperatorPool;
 use databend_common_storages_fuse::FuseTable;
 use databend_enterprise_vacuum_handler::vacuum_handler::VacuumDropFileInfo;
 use futures_util::TryStreamExt;
@@ -35,6 +36,12 @@ pub async fn do_vacuum_drop_table(
     let mut list_files = vec![];
     for (table_info, operator) in tables {
         let dir = format!("{}/", FuseTable::parse_storage_prefix(&table_info)?);
+        // The blocks moved to the cold storage are removed with the table too, they are
+        // under the same dir of the cold storage.
+        let mut operators = vec![operator];
+        if let Some(policy) = FuseTable::parse_storage_policy(&table_info)? {
+            operators.push(StageOperatorPool::instance().get(&policy.cold_storage)?);
+        }
 
         info!(
             "vacuum drop table {:?} dir {:?}, is_external_table:{:?}",
@@ -45,33 +52,38 @@ pub async fn do_vacuum_drop_table(
 
         let start = Instant::now();
 
-        match dry_run_limit {
-            None => {
-                operator.remove_all(&dir).await?;
-            }
-            Some(dry_run_limit) => {
-                let mut ds = operator
-                    .lister_with(&dir)
-                    .recursive(true)
-                    .metakey(Metakey::Mode)
-                    .metakey(Metakey::ContentLength)
-                    .await?;
-
-                while let Some(de) = ds.try_next().await? {
-                    let meta = de.metadata();
-                    if EntryMode::FILE == meta.mode() {
-                        list_files.push((
-                            table_info.name.clone(),
-                            de.name().to_string(),
-                            meta.content_length(),
-                        ));
-                        if list_files.len() >= dry_run_limit {
-                            break;
+        for operator in &operators {
+            match dry_run_limit {
+                None => {
+                    operator.remove_all(&dir).await?;
+                }
+                Some(dry_run_limit) => {
+                    if list_files.len() >= dry_run_limit {
+                        break;
+                    }
+                    let mut ds = operator
+                        .lister_with(&dir)
+                        .recursive(true)
+                        .metakey(Metakey::Mode)
+                        .metakey(Metakey::ContentLength)
+                        .await?;
+
+                    while let Some(de) = ds.try_next().await? {
+                        let meta = de.metadata();
+                        if EntryMode::FILE == meta.mode() {
+                            list_files.push((
+                                table_info.name.clone(),
+                                de.name().to_string(),
+                                meta.content_length(),
+                            ));
+                            if list_files.len() >= dry_run_limit {
+                                break;
+                            }
                         }
                     }
                 }
-            }
-        };
+            };
+        }
 
         info!(
             "vacuum drop table {:?} dir {:?}, cost:{:?}",
@@ -21,9 +21,12 @@ use chrono::Duration;
 use chrono::Utc;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::MaintenanceProgress;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_storage::cold_tier_location;
+use databend_common_storage::COLD_TIER_PREFIX;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::io::SnapshotLiteExtended;
 use databend_common_storages_fuse::io::SnapshotsIO;
@@ -143,24 +146,32 @@ async fn get_orphan_files_to_be_purged(
     referenced_files: HashSet<String>,
     retention_time: DateTime<Utc>,
 ) -> Result<Vec<String>> {
-    let files_to_be_purged = match referenced_files.iter().next().cloned() {
-        Some(location) => {
-            let prefix = SnapshotsIO::get_s3_prefix_from_file(&location);
-            if let Some(prefix) = prefix {
-                fuse_table
-                    .list_files(prefix, |location, modified| {
-                        modified <= retention_time && !referenced_files.contains(&location)
-                    })
-                    .await?
-            } else {
-                vec![]
-            }
-        }
-        None => {
-            vec![]
-        }
+    let prefix = match referenced_files.iter().next() {
+        // The files moved to the cold tier keep their paths under the prefix of the cold tier.
+        Some(location) => SnapshotsIO::get_s3_prefix_from_file(
+            location.strip_prefix(COLD_TIER_PREFIX).unwrap_or(location),
+        ),
+        None => None,
+    };
+    let Some(prefix) = prefix else {
+        return Ok(vec![]);
     };
 
+    let mut prefixes = vec![prefix.clone()];
+    if fuse_table.storage_policy()?.is_some() {
+        prefixes.push(cold_tier_location(&prefix));
+    }
+
+    let mut files_to_be_purged = vec![];
+    for prefix in prefixes {
+        files_to_be_purged.extend(
+            fuse_table
+                .list_files(prefix, |location, modified| {
+                    modified <= retention_time && !referenced_files.contains(&location)
+                })
+                .await?,
+        );
+    }
     Ok(files_to_be_purged)
 }
 
@@ -200,6 +211,12 @@ pub async fn do_gc_orphan_files(
 
     // 2.2 Delete all the orphan segment files to be purged
     let purged_file_num = segment_locations_to_be_purged.len();
+    ctx.set_maintenance_progress(MaintenanceProgress::new(
+        "vacuum",
+        "purge_segments",
+        0,
+        purged_file_num,
+    ));
     fuse_table
         .try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
             ctx.clone(),
@@ -212,6 +229,12 @@ pub async fn do_gc_orphan_files(
         start.elapsed()
     );
     ctx.set_status_info(&status);
+    ctx.set_maintenance_progress(MaintenanceProgress::new(
+        "vacuum",
+        "purge_segments",
+        purged_file_num,
+        purged_file_num,
+    ));
 
     // 3. Purge orphan block files.
     // 3.1 Get orphan block files to be purged
@@ -226,6 +249,12 @@ pub async fn do_gc_orphan_files(
 
     // 3.2 Delete all the orphan block files to be purged
     let purged_file_num = block_locations_to_be_purged.len();
+    ctx.set_maintenance_progress(MaintenanceProgress::new(
+        "vacuum",
+        "purge_blocks",
+        0,
+        purged_file_num,
+    ));
     fuse_table
         .try_purge_location_files(
             ctx.clone(),
@@ -238,6 +267,12 @@ pub async fn do_gc_orphan_files(
         start.elapsed()
     );
     ctx.set_status_info(&status);
+    ctx.set_maintenance_progress(MaintenanceProgress::new(
+        "vacuum",
+        "purge_blocks",
+        purged_file_num,
+        purged_file_num,
+    ));
 
     // 4. Purge orphan block index files.
     // 4.1 Get orphan block index files to be purged
@@ -253,6 +288,12 @@ pub async fn do_gc_orphan_files(
 
     // 4.2 Delete all the orphan block index files to be purged
     let purged_file_num = index_locations_to_be_purged.len();
+    ctx.set_maintenance_progress(MaintenanceProgress::new(
+        "vacuum",
+        "purge_block_indexes",
+        0,
+        purged_file_num,
+    ));
     fuse_table
         .try_purge_location_files(
             ctx.clone(),
@@ -265,6 +306,12 @@ pub async fn do_gc_orphan_files(
         start.elapsed()
     );
     ctx.set_status_info(&status);
+    ctx.set_maintenance_progress(MaintenanceProgress::new(
+        "vacuum",
+        "purge_block_indexes",
+        purged_file_num,
+        purged_file_num,
+    ));
 
     Ok(())
 }
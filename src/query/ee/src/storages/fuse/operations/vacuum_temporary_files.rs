@@ -69,6 +69,8 @@ pub async fn do_vacuum_temporary_files(
             let meta = de.metadata();
 
             match meta.mode() {
+                // The storages with hierarchical namespace, e.g. azdls, list the directory itself.
+                EntryMode::DIR if de.path() == temporary_dir => continue,
                 EntryMode::DIR => {
                     let life_mills =
                         match operator.is_exist(&format!("{}finished", de.path())).await? {
@@ -166,9 +168,10 @@ async fn vacuum_finished_query(
 ) -> Result<()> {
     let operator = DataOperator::instance().operator();
 
+    let query_dir = de.path();
     let mut all_files_removed = true;
     let mut ds = operator
-        .lister_with(de.path())
+        .lister_with(query_dir)
         .metakey(Metakey::Mode | Metakey::LastModified)
         .await?;
 
@@ -180,6 +183,10 @@ async fn vacuum_finished_query(
         let mut remove_temp_files_path = Vec::with_capacity(1001);
 
         while let Some(de) = ds.try_next().await? {
+            // The storages with hierarchical namespace list the directory itself.
+            if de.path() == query_dir {
+                continue;
+            }
             let meta = de.metadata();
             if meta.is_file() {
                 if de.name() == "finished" {
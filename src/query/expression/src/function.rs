@@ -18,12 +18,14 @@ use std::ops::BitAnd;
 use std::ops::BitOr;
 use std::ops::Not;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use chrono::DateTime;
 use chrono::Utc;
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_ast::Span;
+use databend_common_base::runtime::profile::Profile;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_io::GeometryDataType;
@@ -116,6 +118,59 @@ pub struct FunctionContext {
     pub parse_datetime_ignore_remainder: bool,
     pub enable_dst_hour_fix: bool,
     pub enable_strict_datetime_parser: bool,
+    pub strict_mode: bool,
+    pub random_seed: Option<RandomSeed>,
+}
+
+/// The seed of `rand()` set by `random_seed`.
+///
+/// Each value is derived from the seed, the processor evaluating the block, the number of
+/// the block in that processor and the offset of the row in the block. The pipelines of the
+/// cluster fragments mix the fragment and the executor into the seed, so the values of the
+/// nodes don't repeat each other.
+#[derive(Clone, Debug)]
+pub struct RandomSeed {
+    seed: u64,
+    next_block_ids: Arc<Mutex<HashMap<usize, u64>>>,
+}
+
+impl RandomSeed {
+    pub fn new(seed: u64) -> Self {
+        RandomSeed {
+            seed,
+            next_block_ids: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn with_fragment(&self, fragment_id: usize, executor_id: &str) -> Self {
+        let executor = executor_id
+            .bytes()
+            .fold(0, |hash, byte| splitmix64(hash ^ byte as u64));
+        RandomSeed::new(splitmix64(
+            self.seed ^ splitmix64(fragment_id as u64) ^ splitmix64(executor),
+        ))
+    }
+
+    /// Takes the id of the next block evaluated by the current processor.
+    pub fn next_block_id(&self) -> u64 {
+        let pid = Profile::current_pid().unwrap_or_default();
+        let mut next_block_ids = self.next_block_ids.lock().unwrap();
+        let next_block_id = next_block_ids.entry(pid).or_default();
+        let block_id = splitmix64(pid as u64) ^ *next_block_id;
+        *next_block_id += 1;
+        block_id
+    }
+
+    pub fn row_seed(&self, block_id: u64, row: usize) -> u64 {
+        splitmix64(splitmix64(self.seed ^ splitmix64(block_id)) ^ row as u64)
+    }
+}
+
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
 }
 
 impl Default for FunctionContext {
@@ -138,6 +193,8 @@ impl Default for FunctionContext {
             parse_datetime_ignore_remainder: false,
             enable_dst_hour_fix: false,
             enable_strict_datetime_parser: true,
+            strict_mode: false,
+            random_seed: None,
         }
     }
 }
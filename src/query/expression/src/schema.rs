@@ -838,6 +838,21 @@ impl TableSchema {
                 .collect::<Vec<_>>();
             return Self::traverse_paths(&fields, &path[1..]);
         }
+        // The inner fields of Tuple nested in Array can be read as arrays of the field values,
+        // for tables that support nested projection, see `Table::support_nested_projection`.
+        if let Some((fields_name, fields_type)) = field.data_type.array_tuple_fields() {
+            let mut next_column_id = field.column_id;
+            let fields = fields_name
+                .iter()
+                .zip(fields_type)
+                .map(|(name, ty)| {
+                    let inner_name = format!("{}:{}", field.name(), display_tuple_field_name(name));
+                    let field = TableField::new(&inner_name, ty);
+                    field.build_column_id(&mut next_column_id)
+                })
+                .collect::<Vec<_>>();
+            return Self::traverse_paths(&fields, &path[1..]);
+        }
         let valid_fields: Vec<String> = fields.iter().map(|f| f.name.clone()).collect();
         Err(ErrorCode::BadArguments(format!(
             "Unable to get field paths. Valid fields: {:?}",
@@ -1311,6 +1326,39 @@ impl TableDataType {
             _ => 1,
         }
     }
+
+    /// Returns the field names and the projected types of a tuple nested in an array,
+    /// e.g. `Array(Tuple(a Int32, b String))` has the inner fields `a: Array(Int32)`
+    /// and `b: Array(String)`.
+    pub fn array_tuple_fields(&self) -> Option<(Vec<String>, Vec<TableDataType>)> {
+        let TableDataType::Array(box inner_ty) = self.remove_nullable() else {
+            return None;
+        };
+        let TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } = inner_ty.remove_nullable()
+        else {
+            return None;
+        };
+        let fields_type = fields_type
+            .into_iter()
+            .map(|ty| {
+                let ty = if inner_ty.is_nullable() {
+                    ty.wrap_nullable()
+                } else {
+                    ty
+                };
+                let ty = TableDataType::Array(Box::new(ty));
+                if self.is_nullable() {
+                    ty.wrap_nullable()
+                } else {
+                    ty
+                }
+            })
+            .collect();
+        Some((fields_name, fields_type))
+    }
 }
 
 // for merge into not matched clauses, when there are multi inserts, they maybe
@@ -22,6 +22,7 @@ pub mod display;
 pub mod filter_helper;
 pub mod serialize;
 pub mod udf_client;
+pub mod udf_server_pool;
 pub mod variant_transform;
 
 use databend_common_arrow::arrow::bitmap::Bitmap;
@@ -19,6 +19,7 @@ use arrow_array::RecordBatch;
 use arrow_flight::decode::FlightRecordBatchStream;
 use arrow_flight::encode::FlightDataEncoderBuilder;
 use arrow_flight::flight_service_client::FlightServiceClient;
+use arrow_flight::Empty;
 use arrow_flight::FlightDescriptor;
 use arrow_select::concat::concat_batches;
 use databend_common_base::headers::HEADER_FUNCTION;
@@ -150,6 +151,21 @@ impl UDFFlightClient {
         request
     }
 
+    /// Checks that the UDF server is serving, a server that does not implement the
+    /// actions is healthy as long as it answers.
+    #[async_backtrace::framed]
+    pub async fn health_check(&mut self) -> Result<()> {
+        let request = self.make_request(Empty {});
+        match self.inner.list_actions(request).await {
+            Ok(_) => Ok(()),
+            Err(status) if status.code() == tonic::Code::Unimplemented => Ok(()),
+            Err(status) => Err(ErrorCode::UDFServerConnectError(format!(
+                "UDF Server health check failed: {}",
+                status
+            ))),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn check_schema(
         &mut self,
@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use dashmap::DashMap;
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use log::warn;
+
+use crate::udf_client::UDFFlightClient;
+
+/// The consecutive failures after which the circuit of a UDF server address is opened.
+const UDF_SERVER_FAILURE_THRESHOLD: u32 = 3;
+/// How long an address with an open circuit is skipped before it is health checked again.
+const UDF_SERVER_CIRCUIT_BREAK: Duration = Duration::from_secs(30);
+
+/// Splits the `ADDRESS` of a UDF server, which may list several comma separated addresses
+/// of the replicas serving the functions, in the order of preference.
+pub fn udf_server_addresses(address: &str) -> Vec<&str> {
+    address
+        .split(',')
+        .map(str::trim)
+        .filter(|addr| !addr.is_empty())
+        .collect()
+}
+
+/// The connections to the UDF servers shared by the queries on the node.
+///
+/// A request fails over to the next address of the function if an address can not be
+/// connected or fails to serve. An address failing repeatedly has its circuit opened and is
+/// skipped until a health check passes, so that one flaky server does not hold up every
+/// query that references it.
+#[derive(Default)]
+pub struct UDFServerPool {
+    servers: DashMap<String, UDFServerState>,
+}
+
+#[derive(Default)]
+struct UDFServerState {
+    client: Option<UDFFlightClient>,
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+/// The errors of the data or the schema are returned by any replica of the function alike,
+/// so they are not failed over.
+fn is_server_failure(err: &ErrorCode) -> bool {
+    !matches!(
+        err.code(),
+        ErrorCode::UDF_SCHEMA_MISMATCH
+            | ErrorCode::UDF_DATA_ERROR
+            | ErrorCode::EMPTY_DATA_FROM_SERVER
+    )
+}
+
+impl UDFServerPool {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(UDFServerPool::default()));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<UDFServerPool> {
+        GlobalInstance::get()
+    }
+
+    /// Runs `f` with a client connected to the first available address of `address`.
+    #[async_backtrace::framed]
+    pub async fn with_client<T, F, Fut>(
+        &self,
+        address: &str,
+        conn_timeout: u64,
+        request_timeout: u64,
+        batch_rows: u64,
+        f: F,
+    ) -> Result<T>
+    where
+        F: Fn(UDFFlightClient) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut last_error = None;
+        for addr in udf_server_addresses(address) {
+            let key = format!("{addr}/{conn_timeout}/{request_timeout}/{batch_rows}");
+            let (client, half_open) = match self.servers.get(&key) {
+                Some(state) => match state.open_until {
+                    Some(open_until) if open_until > Instant::now() => {
+                        last_error.get_or_insert_with(|| {
+                            ErrorCode::UDFServerUnavailable(format!(
+                                "UDF server {addr} is circuit broken after {} consecutive failures",
+                                state.consecutive_failures
+                            ))
+                        });
+                        continue;
+                    }
+                    open_until => (state.client.clone(), open_until.is_some()),
+                },
+                None => (None, false),
+            };
+
+            let result = async {
+                let mut client = match client {
+                    Some(client) => client,
+                    None => {
+                        UDFFlightClient::connect(addr, conn_timeout, request_timeout, batch_rows)
+                            .await?
+                    }
+                };
+                if half_open {
+                    client.health_check().await?;
+                }
+                let res = f(client.clone()).await;
+                Ok::<_, ErrorCode>((client, res))
+            }
+            .await;
+
+            match result {
+                Ok((client, Ok(res))) => {
+                    self.servers.insert(key, UDFServerState {
+                        client: Some(client),
+                        ..Default::default()
+                    });
+                    return Ok(res);
+                }
+                Ok((_, Err(cause))) if !is_server_failure(&cause) => return Err(cause),
+                Ok((_, Err(cause))) | Err(cause) => {
+                    self.record_failure(key, addr, &cause);
+                    last_error = Some(cause);
+                }
+            }
+        }
+
+        let cause = last_error.unwrap_or_else(|| {
+            ErrorCode::UDFServerConnectError(format!("Invalid UDF Server address: {address}"))
+        });
+        Err(ErrorCode::UDFServerUnavailable(format!(
+            "No address of UDF server {} is available, last error: {}",
+            address,
+            cause.message()
+        )))
+    }
+
+    fn record_failure(&self, key: String, addr: &str, cause: &ErrorCode) {
+        let mut state = self.servers.entry(key).or_default();
+        // The connection may be broken, connect again on the next request.
+        state.client = None;
+        state.consecutive_failures += 1;
+        if state.consecutive_failures >= UDF_SERVER_FAILURE_THRESHOLD {
+            state.open_until = Some(Instant::now() + UDF_SERVER_CIRCUIT_BREAK);
+            warn!(
+                "Open the circuit of UDF server {} for {:?} after {} consecutive failures, last error: {}",
+                addr, UDF_SERVER_CIRCUIT_BREAK, state.consecutive_failures, cause
+            );
+        }
+    }
+}
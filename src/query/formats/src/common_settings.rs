@@ -26,6 +26,7 @@ pub struct InputCommonSettings {
     pub binary_format: BinaryFormat,
     pub is_rounding_mode: bool,
     pub enable_dst_hour_fix: bool,
+    pub strict_mode: bool,
 }
 
 #[derive(Clone)]
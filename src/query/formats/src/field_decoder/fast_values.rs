@@ -90,6 +90,7 @@ impl FastFieldDecoderValues {
                 binary_format: Default::default(),
                 is_rounding_mode,
                 enable_dst_hour_fix: format.enable_dst_hour_fix,
+                strict_mode: false,
             },
         }
     }
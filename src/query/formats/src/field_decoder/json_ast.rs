@@ -55,6 +55,7 @@ pub struct FieldJsonAstDecoder {
     pub is_select: bool,
     is_rounding_mode: bool,
     enable_dst_hour_fix: bool,
+    strict_mode: bool,
 }
 
 impl FieldDecoder for FieldJsonAstDecoder {
@@ -71,6 +72,7 @@ impl FieldJsonAstDecoder {
             is_select: options.is_select,
             is_rounding_mode: options.is_rounding_mode,
             enable_dst_hour_fix: options.enable_dst_hour_fix,
+            strict_mode: options.strict_mode,
         }
     }
 
@@ -147,6 +149,7 @@ impl FieldJsonAstDecoder {
                 let new_val: Option<T::Native> = match v.as_i64() {
                     Some(v) => num_traits::cast::cast(v),
                     None => match v.as_f64() {
+                        Some(v) if self.strict_mode && v.fract() != 0.0 => None,
                         Some(v) => {
                             if self.is_rounding_mode {
                                 num_traits::cast::cast(v.round())
@@ -179,6 +182,7 @@ impl FieldJsonAstDecoder {
                 let new_val: Option<T::Native> = match v.as_u64() {
                     Some(v) => num_traits::cast::cast(v),
                     None => match v.as_f64() {
+                        Some(v) if self.strict_mode && v.fract() != 0.0 => None,
                         Some(v) => {
                             if self.is_rounding_mode {
                                 num_traits::cast::cast(v.round())
@@ -258,9 +262,20 @@ impl FieldJsonAstDecoder {
         Ok(())
     }
 
+    // The zero date is read as `1970-01-01` unless the strict mode is on.
+    fn check_zero_date(&self, v: &str) -> Result<()> {
+        if self.strict_mode && v.starts_with("0000-00-00") {
+            return Err(ErrorCode::BadBytes(
+                "the zero date is invalid in strict mode",
+            ));
+        }
+        Ok(())
+    }
+
     fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
+                self.check_zero_date(v)?;
                 let mut reader = Cursor::new(v.as_bytes());
                 let date = reader.read_date_text(&self.timezone, self.enable_dst_hour_fix)?;
                 let days = uniform_date(date);
@@ -283,6 +298,7 @@ impl FieldJsonAstDecoder {
     fn read_timestamp(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
+                self.check_zero_date(v)?;
                 let v = v.clone();
                 let mut reader = Cursor::new(v.as_bytes());
                 let ts =
@@ -81,6 +81,7 @@ impl NestedValues {
                 binary_format: Default::default(),
                 is_rounding_mode: options_ext.is_rounding_mode,
                 enable_dst_hour_fix: options_ext.enable_dst_hour_fix,
+                strict_mode: options_ext.strict_mode,
             },
         }
     }
@@ -85,6 +85,7 @@ impl SeparatedTextDecoder {
                 binary_format: params.binary_format,
                 is_rounding_mode: options_ext.is_rounding_mode,
                 enable_dst_hour_fix: options_ext.enable_dst_hour_fix,
+                strict_mode: options_ext.strict_mode,
             },
             nested_decoder: NestedValues::create(options_ext),
         }
@@ -101,6 +102,7 @@ impl SeparatedTextDecoder {
                 binary_format: Default::default(),
                 is_rounding_mode: options_ext.is_rounding_mode,
                 enable_dst_hour_fix: options_ext.enable_dst_hour_fix,
+                strict_mode: options_ext.strict_mode,
             },
             nested_decoder: NestedValues::create(options_ext),
         }
@@ -206,6 +208,12 @@ impl SeparatedTextDecoder {
             Err(_) => {
                 // cast float value to integer value
                 let val: f64 = read_num_text_exact(&data[..effective])?;
+                if self.common_settings.strict_mode && val.fract() != 0.0 {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "the fractional part of number {} would be lost",
+                        val
+                    )));
+                }
                 let new_val: Option<T::Native> = if self.common_settings.is_rounding_mode {
                     num_traits::cast::cast(val.round())
                 } else {
@@ -249,6 +257,7 @@ impl SeparatedTextDecoder {
     }
 
     fn read_date(&self, column: &mut Vec<i32>, data: &[u8]) -> Result<()> {
+        self.check_zero_date(data)?;
         let mut buffer_readr = Cursor::new(&data);
         let date = buffer_readr.read_date_text(
             &self.common_settings().timezone,
@@ -264,6 +273,7 @@ impl SeparatedTextDecoder {
         let ts = if !data.contains(&b'-') {
             read_num_text_exact(data)?
         } else {
+            self.check_zero_date(data)?;
             let mut buffer_readr = Cursor::new(&data);
             let t = buffer_readr.read_timestamp_text(
                 &self.common_settings().timezone,
@@ -291,6 +301,16 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    // The zero date is read as `1970-01-01` unless the strict mode is on.
+    fn check_zero_date(&self, data: &[u8]) -> Result<()> {
+        if self.common_settings.strict_mode && data.starts_with(b"0000-00-00") {
+            return Err(ErrorCode::BadBytes(
+                "the zero date is invalid in strict mode",
+            ));
+        }
+        Ok(())
+    }
+
     fn read_bitmap(&self, column: &mut BinaryColumnBuilder, data: &[u8]) -> Result<()> {
         let rb = parse_bitmap(data)?;
         rb.serialize_into(&mut column.data).unwrap();
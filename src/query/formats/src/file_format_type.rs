@@ -50,6 +50,7 @@ pub struct FileFormatOptionsExt {
     pub is_rounding_mode: bool,
     pub geometry_format: GeometryDataType,
     pub enable_dst_hour_fix: bool,
+    pub strict_mode: bool,
 }
 
 impl FileFormatOptionsExt {
@@ -64,6 +65,7 @@ impl FileFormatOptionsExt {
             .get_numeric_cast_option()
             .unwrap_or("rounding".to_string());
         let is_rounding_mode = numeric_cast_option.as_str() == "rounding";
+        let strict_mode = settings.get_strict_sql_mode()?;
 
         let options = FileFormatOptionsExt {
             ident_case_sensitive: false,
@@ -77,6 +79,7 @@ impl FileFormatOptionsExt {
             is_rounding_mode,
             geometry_format,
             enable_dst_hour_fix,
+            strict_mode,
         };
         Ok(options)
     }
@@ -100,6 +103,7 @@ impl FileFormatOptionsExt {
             is_rounding_mode: true,
             geometry_format,
             enable_dst_hour_fix,
+            strict_mode: false,
         };
         let suf = &clickhouse_type.suffixes;
         options.headers = suf.headers;
@@ -88,14 +88,73 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_unary_arithmetic(registry);
 }
 
+/// The arithmetic of the numbers checking the overflows, used in the strict sql mode.
+trait StrictArithmetic: Sized {
+    fn strict_add(self, rhs: Self) -> Option<Self>;
+    fn strict_sub(self, rhs: Self) -> Option<Self>;
+    fn strict_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_strict_arithmetic_for_integer {
+    ($($t:ty),*) => {
+        $(
+            impl StrictArithmetic for $t {
+                fn strict_add(self, rhs: Self) -> Option<Self> {
+                    self.checked_add(rhs)
+                }
+
+                fn strict_sub(self, rhs: Self) -> Option<Self> {
+                    self.checked_sub(rhs)
+                }
+
+                fn strict_mul(self, rhs: Self) -> Option<Self> {
+                    self.checked_mul(rhs)
+                }
+            }
+        )*
+    };
+}
+
+// The floats overflow to the infinities.
+macro_rules! impl_strict_arithmetic_for_float {
+    ($($t:ty),*) => {
+        $(
+            impl StrictArithmetic for $t {
+                fn strict_add(self, rhs: Self) -> Option<Self> {
+                    check_float_overflow(self, rhs, self + rhs)
+                }
+
+                fn strict_sub(self, rhs: Self) -> Option<Self> {
+                    check_float_overflow(self, rhs, self - rhs)
+                }
+
+                fn strict_mul(self, rhs: Self) -> Option<Self> {
+                    check_float_overflow(self, rhs, self * rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_strict_arithmetic_for_integer!(u8, u16, u32, u64, i8, i16, i32, i64);
+impl_strict_arithmetic_for_float!(F32, F64);
+
+fn check_float_overflow<T: AsPrimitive<f64>>(lhs: T, rhs: T, val: T) -> Option<T> {
+    if val.as_().is_infinite() && lhs.as_().is_finite() && rhs.as_().is_finite() {
+        None
+    } else {
+        Some(val)
+    }
+}
+
 macro_rules! register_plus {
     ( $lt:ty, $rt:ty, $registry:expr) => {
         type L = $lt;
         type R = $rt;
         type T = <(L, R) as ResultTypeOfBinary>::AddMul;
-        $registry.register_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
+        $registry.register_passthrough_nullable_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
             "plus",
-            |_, lhs, rhs| {
+            |func_ctx, lhs, rhs| {
                 (|| {
                     let lm: T = num_traits::cast::cast(lhs.max)?;
                     let ln: T = num_traits::cast::cast(lhs.min)?;
@@ -107,9 +166,28 @@ macro_rules! register_plus {
                         max: lm.checked_add(rm)?,
                     }))
                 })()
-                .unwrap_or(FunctionDomain::Full)
+                .unwrap_or(if func_ctx.strict_mode {
+                    FunctionDomain::MayThrow
+                } else {
+                    FunctionDomain::Full
+                })
+            },
+            |a, b, ctx| {
+                if !ctx.func_ctx.strict_mode {
+                    return vectorize_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>>(
+                        |a, b, _| (AsPrimitive::<T>::as_(a)) + (AsPrimitive::<T>::as_(b)),
+                    )(a, b, ctx);
+                }
+                vectorize_with_builder_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>>(
+                    |a, b, output, ctx| match AsPrimitive::<T>::as_(a).strict_add(b.as_()) {
+                        Some(val) => output.push(val),
+                        None => {
+                            ctx.set_error(output.len(), "number overflowed");
+                            output.push(T::default());
+                        }
+                    },
+                )(a, b, ctx)
             },
-            |a, b, _| (AsPrimitive::<T>::as_(a)) + (AsPrimitive::<T>::as_(b)),
         );
     };
 }
@@ -119,9 +197,9 @@ macro_rules! register_minus {
         type L = $lt;
         type R = $rt;
         type T = <(L, R) as ResultTypeOfBinary>::Minus;
-        $registry.register_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
+        $registry.register_passthrough_nullable_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
             "minus",
-            |_, lhs, rhs| {
+            |func_ctx, lhs, rhs| {
                 (|| {
                     let lm: T = num_traits::cast::cast(lhs.max)?;
                     let ln: T = num_traits::cast::cast(lhs.min)?;
@@ -133,9 +211,28 @@ macro_rules! register_minus {
                         max: lm.checked_sub(rn)?,
                     }))
                 })()
-                .unwrap_or(FunctionDomain::Full)
+                .unwrap_or(if func_ctx.strict_mode {
+                    FunctionDomain::MayThrow
+                } else {
+                    FunctionDomain::Full
+                })
+            },
+            |a, b, ctx| {
+                if !ctx.func_ctx.strict_mode {
+                    return vectorize_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>>(
+                        |a, b, _| (AsPrimitive::<T>::as_(a)) - (AsPrimitive::<T>::as_(b)),
+                    )(a, b, ctx);
+                }
+                vectorize_with_builder_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>>(
+                    |a, b, output, ctx| match AsPrimitive::<T>::as_(a).strict_sub(b.as_()) {
+                        Some(val) => output.push(val),
+                        None => {
+                            ctx.set_error(output.len(), "number overflowed");
+                            output.push(T::default());
+                        }
+                    },
+                )(a, b, ctx)
             },
-            |a, b, _| (AsPrimitive::<T>::as_(a)) - (AsPrimitive::<T>::as_(b)),
         );
     };
 }
@@ -145,9 +242,9 @@ macro_rules! register_multiply {
         type L = $lt;
         type R = $rt;
         type T = <(L, R) as ResultTypeOfBinary>::AddMul;
-        $registry.register_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
+        $registry.register_passthrough_nullable_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
             "multiply",
-            |_, lhs, rhs| {
+            |func_ctx, lhs, rhs| {
                 (|| {
                     let lm: T = num_traits::cast::cast(lhs.max)?;
                     let ln: T = num_traits::cast::cast(lhs.min)?;
@@ -164,9 +261,28 @@ macro_rules! register_multiply {
                         max: x.max(y).max(m).max(n),
                     }))
                 })()
-                .unwrap_or(FunctionDomain::Full)
+                .unwrap_or(if func_ctx.strict_mode {
+                    FunctionDomain::MayThrow
+                } else {
+                    FunctionDomain::Full
+                })
+            },
+            |a, b, ctx| {
+                if !ctx.func_ctx.strict_mode {
+                    return vectorize_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>>(
+                        |a, b, _| (AsPrimitive::<T>::as_(a)) * (AsPrimitive::<T>::as_(b)),
+                    )(a, b, ctx);
+                }
+                vectorize_with_builder_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>>(
+                    |a, b, output, ctx| match AsPrimitive::<T>::as_(a).strict_mul(b.as_()) {
+                        Some(val) => output.push(val),
+                        None => {
+                            ctx.set_error(output.len(), "number overflowed");
+                            output.push(T::default());
+                        }
+                    },
+                )(a, b, ctx)
             },
-            |a, b, _| (AsPrimitive::<T>::as_(a)) * (AsPrimitive::<T>::as_(b)),
         );
     };
 }
@@ -659,7 +775,7 @@ pub fn register_number_to_number(registry: &mut FunctionRegistry) {
                                     } else {
                                         domain.overflow_cast()
                                     };
-                                    if overflowing {
+                                    if overflowing || func_ctx.strict_mode {
                                         FunctionDomain::MayThrow
                                     } else {
                                         FunctionDomain::Domain(domain)
@@ -667,6 +783,11 @@ pub fn register_number_to_number(registry: &mut FunctionRegistry) {
                                 },
                                 vectorize_with_builder_1_arg::<NumberType<SRC_TYPE>, NumberType<DEST_TYPE>>(
                                     move |val, output, ctx| {
+                                        if ctx.func_ctx.strict_mode && AsPrimitive::<f64>::as_(val).fract() != 0.0 {
+                                            ctx.set_error(output.len(), "the fractional part of number would be lost");
+                                            output.push(DEST_TYPE::default());
+                                            return;
+                                        }
                                         let val = if ctx.func_ctx.rounding_mode {
                                             let val = AsPrimitive::<f64>::as_(val);
                                             num_traits::cast::cast(val.round())
@@ -742,7 +863,7 @@ pub fn register_number_to_number(registry: &mut FunctionRegistry) {
                                         domain.overflow_cast()
                                     };
                                     FunctionDomain::Domain(NullableDomain {
-                                        has_null: overflowing,
+                                        has_null: overflowing || func_ctx.strict_mode,
                                         value: Some(Box::new(
                                             domain,
                                         )),
@@ -750,6 +871,10 @@ pub fn register_number_to_number(registry: &mut FunctionRegistry) {
                                 },
                                 vectorize_with_builder_1_arg::<NumberType<SRC_TYPE>, NullableType<NumberType<DEST_TYPE>>>(
                                     |val, output, ctx| {
+                                        if ctx.func_ctx.strict_mode && AsPrimitive::<f64>::as_(val).fract() != 0.0 {
+                                            output.push_null();
+                                            return;
+                                        }
                                         let val = if ctx.func_ctx.rounding_mode {
                                             let val = AsPrimitive::<f64>::as_(val);
                                             num_traits::cast::cast(val.round())
@@ -161,7 +161,13 @@ fn register_string_to_timestamp(registry: &mut FunctionRegistry) {
         vectorize_with_builder_1_arg::<StringType, TimestampType>(|val, output, ctx| {
             let tz = ctx.func_ctx.tz.tz;
             let enable_dst_hour_fix = ctx.func_ctx.enable_dst_hour_fix;
-            if ctx.func_ctx.enable_strict_datetime_parser {
+            if ctx.func_ctx.strict_mode && is_zero_date_text(val) {
+                ctx.set_error(
+                    output.len(),
+                    "cannot parse to type `TIMESTAMP`. The zero date is invalid in strict mode",
+                );
+                output.push(0);
+            } else if ctx.func_ctx.enable_strict_datetime_parser || ctx.func_ctx.strict_mode {
                 match string_to_timestamp(val, tz, enable_dst_hour_fix) {
                     Ok(ts) => output.push(ts.timestamp_micros()),
                     Err(e) => {
@@ -340,6 +346,12 @@ fn register_string_to_timestamp(registry: &mut FunctionRegistry) {
     );
 }
 
+/// The zero date `0000-00-00` is read as `1970-01-01` unless the strict mode is on.
+#[inline]
+fn is_zero_date_text(val: &str) -> bool {
+    val.trim_start().starts_with("0000-00-00")
+}
+
 fn string_to_format_timestmap(
     timestamp: &str,
     format: &str,
@@ -361,7 +373,7 @@ fn string_to_format_timestmap(
         .any(|&pattern| format.contains(pattern));
     let enable_dst_hour_fix = ctx.func_ctx.enable_dst_hour_fix;
     let tz = ctx.func_ctx.tz.tz;
-    if ctx.func_ctx.parse_datetime_ignore_remainder {
+    if ctx.func_ctx.parse_datetime_ignore_remainder && !ctx.func_ctx.strict_mode {
         let mut parsed = Parsed::new();
         if let Err(e) = parse_and_remainder(&mut parsed, timestamp, StrftimeItems::new(format)) {
             return Err(ErrorCode::BadArguments(format!("{}", e)));
@@ -534,7 +546,13 @@ fn register_string_to_date(registry: &mut FunctionRegistry) {
 
     fn eval_string_to_date(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<DateType> {
         vectorize_with_builder_1_arg::<StringType, DateType>(|val, output, ctx| {
-            if ctx.func_ctx.enable_strict_datetime_parser {
+            if ctx.func_ctx.strict_mode && is_zero_date_text(val) {
+                ctx.set_error(
+                    output.len(),
+                    "cannot parse to type `DATE`. The zero date is invalid in strict mode",
+                );
+                output.push(0);
+            } else if ctx.func_ctx.enable_strict_datetime_parser || ctx.func_ctx.strict_mode {
                 match string_to_date(val, ctx.func_ctx.tz.tz, ctx.func_ctx.enable_dst_hour_fix) {
                     Ok(d) => output.push(d.num_days_from_ce() - EPOCH_DAYS_FROM_CE),
                     Err(e) => {
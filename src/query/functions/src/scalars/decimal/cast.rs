@@ -225,6 +225,9 @@ pub(crate) fn register_decimal_to_int<T: Number>(registry: &mut FunctionRegistry
             },
             eval: FunctionEval::Scalar {
                 calc_domain: Box::new(|ctx, d| {
+                    if ctx.strict_mode {
+                        return FunctionDomain::MayThrow;
+                    }
                     let res_fn = move || match d[0].as_decimal().unwrap() {
                         DecimalDomain::Decimal128(d, size) => Some(SimpleDomain::<T> {
                             min: d.min.to_int(size.scale, ctx.rounding_mode)?,
@@ -574,6 +577,15 @@ fn get_round_val<T: Decimal>(x: T, scale: u32, ctx: &mut EvalContext) -> Option<
     round_val
 }
 
+/// Whether rescaling `x` down by `scale` digits drops non-zero fractional digits, which is an
+/// error in the strict mode.
+#[inline]
+fn is_lossy_rescale<T: Decimal>(x: T, scale: u32, ctx: &EvalContext) -> bool {
+    ctx.func_ctx.strict_mode
+        && scale > 0
+        && x.checked_rem(T::e(scale)).is_some_and(|r| r != T::zero())
+}
+
 fn decimal_256_to_128(
     buffer: &ValueRef<AnyType>,
     from_size: DecimalSize,
@@ -615,6 +627,12 @@ fn decimal_256_to_128(
 
                 match y {
                     Some(y) if (y <= max && y >= min) && (y != 0 || x / source_factor == 0) => {
+                        if is_lossy_rescale(x, scale_diff, ctx) {
+                            ctx.set_error(
+                                builder.len(),
+                                "the fractional digits of decimal would be lost",
+                            );
+                        }
                         builder.push(*y.low());
                     }
                     _ => {
@@ -670,6 +688,12 @@ macro_rules! m_decimal_to_decimal {
 
                     match y {
                         Some(y) if y <= max && y >= min && (y != 0 || x / source_factor == 0) => {
+                            if is_lossy_rescale(x, scale_diff, ctx) {
+                                ctx.set_error(
+                                    builder.len(),
+                                    "the fractional digits of decimal would be lost",
+                                );
+                            }
                             builder.push(y as T);
                         }
                         _ => {
@@ -806,7 +830,15 @@ fn decimal_to_int<T: Number>(
                 |x, builder: &mut Vec<T>, ctx: &mut EvalContext| match x
                     .to_int(from_size.scale, ctx.func_ctx.rounding_mode)
                 {
-                    Some(x) => builder.push(x),
+                    Some(y) => {
+                        if is_lossy_rescale(x, from_size.scale as u32, ctx) {
+                            ctx.set_error(
+                                builder.len(),
+                                "the fractional digits of decimal would be lost",
+                            );
+                        }
+                        builder.push(y)
+                    }
                     None => {
                         ctx.set_error(builder.len(), "decimal cast to int overflow");
                         builder.push(T::default())
@@ -144,10 +144,23 @@ pub fn register(registry: &mut FunctionRegistry) {
             })
         },
         |ctx| {
-            let mut rng = rand::rngs::SmallRng::from_entropy();
-            let rand_nums = (0..ctx.num_rows)
-                .map(|_| rng.gen::<F64>())
-                .collect::<Vec<_>>();
+            let rand_nums = match &ctx.func_ctx.random_seed {
+                Some(random_seed) => {
+                    let block_id = random_seed.next_block_id();
+                    (0..ctx.num_rows)
+                        .map(|row| {
+                            let seed = random_seed.row_seed(block_id, row);
+                            rand::rngs::SmallRng::seed_from_u64(seed).gen::<F64>()
+                        })
+                        .collect::<Vec<_>>()
+                }
+                None => {
+                    let mut rng = rand::rngs::SmallRng::from_entropy();
+                    (0..ctx.num_rows)
+                        .map(|_| rng.gen::<F64>())
+                        .collect::<Vec<_>>()
+                }
+            };
             Value::Column(rand_nums.into())
         },
     );
@@ -486,6 +486,19 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    // Trims any of the characters in the second argument, like `btrim` of PostgreSQL.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, StringType, _, _>(
+        "trim_chars",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_string_to_string_2_arg(
+            |col, _| col.data().len(),
+            |val, trim_chars, _, output| {
+                output.put_str(val.trim_matches(|c| trim_chars.contains(c)));
+                output.commit_row();
+            },
+        ),
+    );
+
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "to_hex",
         |_, _| FunctionDomain::Full,
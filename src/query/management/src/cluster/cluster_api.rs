@@ -31,4 +31,13 @@ pub trait ClusterApi: Sync + Send {
     async fn heartbeat(&self, node: &NodeInfo, seq: MatchSeq) -> Result<u64>;
 
     async fn get_local_addr(&self) -> Result<Option<String>>;
+
+    // Mark the node of the tenant's cluster suspended, which is kept until it is resumed.
+    async fn suspend_node(&self, node_id: &str) -> Result<()>;
+
+    // Remove the suspended mark of the node.
+    async fn resume_node(&self, node_id: &str) -> Result<()>;
+
+    // Get the ids of the suspended nodes of the tenant's cluster.
+    async fn get_suspended_nodes(&self) -> Result<Vec<String>>;
 }
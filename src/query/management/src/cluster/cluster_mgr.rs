@@ -36,6 +36,7 @@ pub struct ClusterMgr {
     metastore: MetaStore,
     lift_time: Duration,
     cluster_prefix: String,
+    suspended_prefix: String,
 }
 
 impl ClusterMgr {
@@ -60,6 +61,12 @@ impl ClusterMgr {
                 escape_for_key(tenant)?,
                 escape_for_key(cluster_id)?
             ),
+            suspended_prefix: format!(
+                "{}/{}/{}/suspended_nodes",
+                CLUSTER_API_KEY_PREFIX,
+                escape_for_key(tenant)?,
+                escape_for_key(cluster_id)?
+            ),
         })
     }
 
@@ -154,4 +161,52 @@ impl ClusterApi for ClusterMgr {
     async fn get_local_addr(&self) -> Result<Option<String>> {
         Ok(self.metastore.get_local_addr().await?)
     }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn suspend_node(&self, node_id: &str) -> Result<()> {
+        // The mark has no lift time, unlike the node info it outlives the restarts.
+        let node_key = format!("{}/{}", self.suspended_prefix, escape_for_key(node_id)?);
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(
+                &node_key,
+                MatchSeq::GE(0),
+                Operation::Update(vec![]),
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn resume_node(&self, node_id: &str) -> Result<()> {
+        let node_key = format!("{}/{}", self.suspended_prefix, escape_for_key(node_id)?);
+        self.metastore
+            .upsert_kv(UpsertKVReq::new(
+                &node_key,
+                MatchSeq::GE(0),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_suspended_nodes(&self) -> Result<Vec<String>> {
+        let values = self
+            .metastore
+            .prefix_list_kv(&format!("{}/", self.suspended_prefix))
+            .await?;
+
+        let mut node_ids = Vec::with_capacity(values.len());
+        for (node_key, _) in values {
+            node_ids.push(unescape_for_key(
+                &node_key[self.suspended_prefix.len() + 1..],
+            )?);
+        }
+        Ok(node_ids)
+    }
 }
@@ -24,6 +24,7 @@ mod role;
 mod serde;
 mod setting;
 mod stage;
+mod tag;
 pub mod udf;
 mod user;
 
@@ -46,5 +47,6 @@ pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use tag::TagMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
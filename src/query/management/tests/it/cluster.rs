@@ -113,6 +113,39 @@ async fn test_unknown_node_drop_node() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_suspend_and_resume_node() -> Result<()> {
+    let (kv_api, cluster_api) = new_cluster_api().await?;
+
+    let node_info = create_test_node_info();
+    cluster_api.add_node(node_info.clone()).await?;
+    assert_eq!(
+        cluster_api.get_suspended_nodes().await?,
+        Vec::<String>::new()
+    );
+
+    cluster_api.suspend_node(&node_info.id).await?;
+    cluster_api.suspend_node(&node_info.id).await?;
+    assert_eq!(cluster_api.get_suspended_nodes().await?, vec![
+        node_info.id.clone()
+    ]);
+    // The marks are not listed as the nodes of the cluster.
+    assert_eq!(cluster_api.get_nodes().await?, vec![node_info.clone()]);
+
+    let value = kv_api
+        .get_kv("__fd_clusters_v2/test%2dtenant%2did/test%2dcluster%2did/suspended_nodes/test_node")
+        .await?;
+    assert!(value.unwrap().meta.is_none());
+
+    cluster_api.resume_node(&node_info.id).await?;
+    cluster_api.resume_node(&node_info.id).await?;
+    assert_eq!(
+        cluster_api.get_suspended_nodes().await?,
+        Vec::<String>::new()
+    );
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_successfully_heartbeat_node() -> Result<()> {
     let now_ms = SeqV::<()>::now_ms();
@@ -43,7 +43,8 @@ use super::transform_sort_merge_base::TransformSortMergeBase;
 use super::AccumulatingTransform;
 use crate::processors::sort::Merger;
 
-/// Merge sort blocks without limit.
+/// Merge sort blocks without limit, or with a limit too large for the heap of
+/// [`super::transform_sort_merge_limit`], in which case the merged rows are truncated to it.
 ///
 /// For merge sort with limit, see [`super::transform_sort_merge_limit`]
 pub struct TransformSortMerge<R: Rows> {
@@ -52,6 +53,7 @@ pub struct TransformSortMerge<R: Rows> {
     enable_loser_tree: bool,
 
     block_size: usize,
+    limit: Option<usize>,
     buffer: Vec<Option<(DataBlock, Column)>>,
 
     aborting: Arc<AtomicBool>,
@@ -68,6 +70,7 @@ impl<R: Rows> TransformSortMerge<R> {
         schema: DataSchemaRef,
         sort_desc: Arc<Vec<SortColumnDescription>>,
         block_size: usize,
+        limit: Option<usize>,
         enable_loser_tree: bool,
     ) -> Self {
         TransformSortMerge {
@@ -75,6 +78,7 @@ impl<R: Rows> TransformSortMerge<R> {
             sort_desc,
             enable_loser_tree,
             block_size,
+            limit,
             buffer: vec![],
             aborting: Arc::new(AtomicBool::new(false)),
             num_bytes: 0,
@@ -150,9 +154,13 @@ impl<R: Rows> TransformSortMerge<R> {
         if self.buffer.len() == 1 {
             // If there is only one block, we don't need to merge.
             let (block, _) = self.buffer.pop().unwrap().unwrap();
-            let num_rows = block.num_rows();
+            let num_rows = match self.limit {
+                Some(limit) => block.num_rows().min(limit),
+                None => block.num_rows(),
+            };
+            let size_hint = num_rows.div_ceil(batch_size);
             if size_hint == 1 {
-                return Ok(vec![block]);
+                return Ok(vec![block.slice(0..num_rows)]);
             }
             let mut result = Vec::with_capacity(size_hint);
             for i in 0..size_hint {
@@ -184,7 +192,7 @@ impl<R: Rows> TransformSortMerge<R> {
             streams,
             self.sort_desc.clone(),
             batch_size,
-            None,
+            self.limit,
         );
 
         while let Some(block) = merger.next_block()? {
@@ -242,7 +250,7 @@ pub fn sort_merge(
         0,
         0,
         sort_spilling_batch_bytes,
-        MergeSortCommonImpl::create(schema, sort_desc, block_size, enable_loser_tree),
+        MergeSortCommonImpl::create(schema, sort_desc, block_size, None, enable_loser_tree),
     )?;
     for block in data_blocks {
         processor.transform(block)?;
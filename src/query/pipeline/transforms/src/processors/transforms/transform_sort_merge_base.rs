@@ -56,6 +56,7 @@ use super::MergeSortTimestamp;
 use super::MergeSortTimestampImpl;
 use super::TransformSortMerge;
 use super::TransformSortMergeLimit;
+use super::MAX_SORT_MERGE_HEAP_LIMIT;
 use crate::processors::sort::utils::ORDER_COL_NAME;
 use crate::processors::sort::SortSpillMetaWithParams;
 
@@ -340,7 +341,11 @@ impl TransformSortMergeBuilder {
             !self.schema.has_field(ORDER_COL_NAME)
         });
 
-        if self.limit.is_some() {
+        // The larger limits are applied by the merge of all the rows instead of a heap.
+        if self
+            .limit
+            .is_some_and(|limit| limit <= MAX_SORT_MERGE_HEAP_LIMIT)
+        {
             self.build_sort_merge_limit()
         } else {
             self.build_sort_merge()
@@ -360,6 +365,7 @@ impl TransformSortMergeBuilder {
             spilling_bytes_threshold_per_core,
             spilling_batch_bytes,
             enable_loser_tree,
+            limit,
             ..
         } = self;
 
@@ -386,6 +392,7 @@ impl TransformSortMergeBuilder {
                                 schema,
                                 sort_desc,
                                 block_size,
+                                limit,
                                 enable_loser_tree
                             ),
                         )?,
@@ -402,7 +409,13 @@ impl TransformSortMergeBuilder {
                         max_memory_usage,
                         spilling_bytes_threshold_per_core,
                         spilling_batch_bytes,
-                        MergeSortDateImpl::create(schema, sort_desc, block_size, enable_loser_tree),
+                        MergeSortDateImpl::create(
+                            schema,
+                            sort_desc,
+                            block_size,
+                            limit,
+                            enable_loser_tree,
+                        ),
                     )?,
                 ),
                 DataType::Timestamp => AccumulatingTransformer::create(
@@ -420,6 +433,7 @@ impl TransformSortMergeBuilder {
                             schema,
                             sort_desc,
                             block_size,
+                            limit,
                             enable_loser_tree,
                         ),
                     )?,
@@ -439,6 +453,7 @@ impl TransformSortMergeBuilder {
                             schema,
                             sort_desc,
                             block_size,
+                            limit,
                             enable_loser_tree,
                         ),
                     )?,
@@ -458,6 +473,7 @@ impl TransformSortMergeBuilder {
                             schema,
                             sort_desc,
                             block_size,
+                            limit,
                             enable_loser_tree,
                         ),
                     )?,
@@ -475,7 +491,13 @@ impl TransformSortMergeBuilder {
                     max_memory_usage,
                     spilling_bytes_threshold_per_core,
                     spilling_batch_bytes,
-                    MergeSortCommonImpl::create(schema, sort_desc, block_size, enable_loser_tree),
+                    MergeSortCommonImpl::create(
+                        schema,
+                        sort_desc,
+                        block_size,
+                        limit,
+                        enable_loser_tree,
+                    ),
                 )?,
             )
         };
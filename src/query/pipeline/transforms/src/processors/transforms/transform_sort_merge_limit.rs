@@ -35,6 +35,10 @@ use super::sort::TimestampRows;
 use super::transform_sort_merge_base::MergeSort;
 use super::transform_sort_merge_base::TransformSortMergeBase;
 
+/// The max limit to keep the Top-N rows in a bounded heap,
+/// larger limits are handled by a full merge sort truncating the merged rows to the limit.
+pub const MAX_SORT_MERGE_HEAP_LIMIT: usize = 10000;
+
 /// This is a specific version of [`super::transform_sort_merge::TransformSortMerge`] which sort blocks with limit.
 pub struct TransformSortMergeLimit<R: Rows> {
     heap: FixedHeap<Reverse<Cursor<R, LocalCursorOrder>>>,
@@ -136,7 +140,11 @@ impl<R: Rows> CursorOrder<R> for LocalCursorOrder {
 
 impl<R: Rows> TransformSortMergeLimit<R> {
     pub fn create(block_size: usize, limit: usize) -> Self {
-        debug_assert!(limit <= 10000, "Too large sort merge limit: {}", limit);
+        debug_assert!(
+            limit <= MAX_SORT_MERGE_HEAP_LIMIT,
+            "Too large sort merge limit: {}",
+            limit
+        );
         TransformSortMergeLimit {
             heap: FixedHeap::new(limit),
             buffer: HashMap::with_capacity(limit),
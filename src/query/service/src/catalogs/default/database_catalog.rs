@@ -82,6 +82,8 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SwapTableReply;
+use databend_common_meta_app::schema::SwapTableReq;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TruncateTableReply;
@@ -91,6 +93,7 @@ use databend_common_meta_app::schema::UndropDatabaseReq;
 use databend_common_meta_app::schema::UndropTableByIdReq;
 use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
+use databend_common_meta_app::schema::UpdateDatabaseOptionsReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
@@ -247,6 +250,19 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.rename_database(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn update_database_options(&self, req: UpdateDatabaseOptionsReq) -> Result<()> {
+        if self
+            .immutable_catalog
+            .exists_database(req.name_ident.tenant(), req.name_ident.database_name())
+            .await?
+        {
+            return self.immutable_catalog.update_database_options(req).await;
+        }
+
+        self.mutable_catalog.update_database_options(req).await
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let res = self.immutable_catalog.get_table_by_info(table_info);
         match res {
@@ -527,6 +543,23 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.rename_table(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn swap_table(&self, req: SwapTableReq) -> Result<SwapTableReply> {
+        info!("Swap table from req:{:?}", req);
+
+        if self
+            .immutable_catalog
+            .exists_database(req.tenant(), req.db_name())
+            .await?
+        {
+            return Err(ErrorCode::Unimplemented(
+                "Cannot swap tables in system databases",
+            ));
+        }
+
+        self.mutable_catalog.swap_table(req).await
+    }
+
     #[async_backtrace::framed]
     async fn create_table_index(&self, req: CreateTableIndexReq) -> Result<CreateTableIndexReply> {
         self.mutable_catalog.create_table_index(req).await
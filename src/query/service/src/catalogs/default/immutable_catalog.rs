@@ -83,6 +83,7 @@ use databend_common_meta_app::schema::UndropDatabaseReq;
 use databend_common_meta_app::schema::UndropTableByIdReq;
 use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
+use databend_common_meta_app::schema::UpdateDatabaseOptionsReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -182,6 +183,13 @@ impl Catalog for ImmutableCatalog {
         Err(ErrorCode::Unimplemented("Cannot rename system database"))
     }
 
+    #[async_backtrace::framed]
+    async fn update_database_options(&self, _req: UpdateDatabaseOptionsReq) -> Result<()> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot change the options of system database",
+        ))
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let table_id = table_info.ident.table_id;
 
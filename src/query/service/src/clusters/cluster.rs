@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
@@ -50,7 +51,9 @@ use futures::future::Either;
 use futures::Future;
 use futures::StreamExt;
 use log::error;
+use log::info;
 use log::warn;
+use parking_lot::RwLock;
 use rand::thread_rng;
 use rand::Rng;
 use serde::Deserialize;
@@ -66,6 +69,10 @@ pub struct ClusterDiscovery {
     cluster_id: String,
     tenant_id: String,
     flight_address: String,
+    /// The nodes suspended by `SYSTEM SUSPEND NODE`, which are left out of the cluster of
+    /// the queries until they are resumed. They are persisted in the meta, this is the
+    /// cache of them refreshed on every discovery.
+    suspended_nodes: RwLock<HashSet<String>>,
 }
 
 // avoid leak FlightClient to common-xxx
@@ -200,6 +207,7 @@ impl ClusterDiscovery {
             cluster_id: cfg.query.cluster_id.clone(),
             tenant_id: cfg.query.tenant_id.tenant_name().to_string(),
             flight_address: cfg.query.flight_api_address.clone(),
+            suspended_nodes: RwLock::new(HashSet::new()),
         }))
     }
 
@@ -223,6 +231,68 @@ impl ClusterDiscovery {
 
     #[async_backtrace::framed]
     pub async fn discover(&self, config: &InnerConfig) -> Result<Arc<Cluster>> {
+        self.discover_nodes(config, false).await
+    }
+
+    /// Discovers the cluster including the suspended nodes, e.g. to resume them.
+    #[async_backtrace::framed]
+    pub async fn discover_with_suspended(&self, config: &InnerConfig) -> Result<Arc<Cluster>> {
+        self.discover_nodes(config, true).await
+    }
+
+    /// Persists the suspended mark of the node in the meta, so that it's kept across the
+    /// restarts of the nodes and seen by the nodes joining the cluster later.
+    #[async_backtrace::framed]
+    pub async fn suspend_node(&self, node_id: &str) -> Result<()> {
+        self.api_provider.suspend_node(node_id).await?;
+        self.set_suspended(node_id, true);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn resume_node(&self, node_id: &str) -> Result<()> {
+        self.api_provider.resume_node(node_id).await?;
+        self.set_suspended(node_id, false);
+        Ok(())
+    }
+
+    /// Updates the cached mark of the node persisted by another node, the cache is
+    /// refreshed from the meta on every discovery anyway.
+    pub fn set_suspended(&self, node_id: &str, suspended: bool) {
+        let mut suspended_nodes = self.suspended_nodes.write();
+        if suspended && suspended_nodes.insert(node_id.to_string()) {
+            info!("Suspend node {} of cluster {}", node_id, self.cluster_id);
+        } else if !suspended && suspended_nodes.remove(node_id) {
+            info!("Resume node {} of cluster {}", node_id, self.cluster_id);
+        }
+    }
+
+    pub fn is_suspended(&self, node_id: &str) -> bool {
+        self.suspended_nodes.read().contains(node_id)
+    }
+
+    pub fn is_local_suspended(&self) -> bool {
+        self.is_suspended(&self.local_id)
+    }
+
+    #[async_backtrace::framed]
+    async fn discover_nodes(
+        &self,
+        config: &InnerConfig,
+        include_suspended: bool,
+    ) -> Result<Arc<Cluster>> {
+        match self.api_provider.get_suspended_nodes().await {
+            Ok(suspended_nodes) => {
+                *self.suspended_nodes.write() = suspended_nodes.into_iter().collect();
+            }
+            Err(cause) => {
+                warn!(
+                    "Cannot get the suspended nodes of cluster {}, use the cached ones. cause: {:?}",
+                    self.cluster_id, cause
+                );
+            }
+        }
+
         match self.api_provider.get_nodes().await {
             Err(cause) => {
                 metric_incr_cluster_error_count(
@@ -238,6 +308,10 @@ impl ClusterDiscovery {
                 let mut res = Vec::with_capacity(cluster_nodes.len());
                 for node in &cluster_nodes {
                     if node.id != self.local_id {
+                        if !include_suspended && self.is_suspended(&node.id) {
+                            continue;
+                        }
+
                         let start_at = Instant::now();
                         if let Err(cause) = create_client(config, &node.flight_address).await {
                             warn!(
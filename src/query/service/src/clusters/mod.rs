@@ -13,7 +13,15 @@
 // limitations under the License.
 
 mod cluster;
+mod scaling_advisor;
 
 pub use cluster::Cluster;
 pub use cluster::ClusterDiscovery;
 pub use cluster::ClusterHelper;
+pub use scaling_advisor::NodeLoad;
+pub use scaling_advisor::ScalingAdvisor;
+pub use scaling_advisor::ScalingAdvisorService;
+pub use scaling_advisor::ScalingDirection;
+pub use scaling_advisor::ScalingRecommendation;
+pub use scaling_advisor::ScalingReport;
+pub use scaling_advisor::ThresholdScalingAdvisor;
@@ -0,0 +1,286 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::metrics::dump_process_stat;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::runtime::GLOBAL_MEM_STAT;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_metrics::cluster::metrics_gauge_scaling_recommendation;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::clusters::ClusterDiscovery;
+use crate::sessions::QueriesQueueManager;
+use crate::sessions::SessionManager;
+
+/// The load of a node sampled by the [`ScalingAdvisorService`].
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct NodeLoad {
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub node_id: String,
+    pub suspended: bool,
+    /// The heavy queries waiting in the admission queue.
+    pub queued_queries: usize,
+    pub running_queries: u64,
+    /// The CPU time of the process since the last sample divided by the wall time and the
+    /// number of cores, 1.0 means all the cores are busy.
+    pub cpu_usage: f64,
+    /// The tracked memory usage divided by `max_server_memory_usage`, 0 if it is unlimited.
+    pub memory_usage: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScalingDirection {
+    ScaleUp,
+    ScaleDown,
+    Keep,
+}
+
+impl ScalingDirection {
+    fn metric_value(&self) -> i64 {
+        match self {
+            ScalingDirection::ScaleUp => 1,
+            ScalingDirection::ScaleDown => -1,
+            ScalingDirection::Keep => 0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ScalingRecommendation {
+    pub direction: ScalingDirection,
+    pub reason: String,
+}
+
+impl ScalingRecommendation {
+    pub fn create(direction: ScalingDirection, reason: impl Into<String>) -> Self {
+        ScalingRecommendation {
+            direction,
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Recommends to scale the warehouse up or down from the load of a node, the recommendations
+/// are served by the admin api and posted to the webhook, so that the external operators
+/// can scale the compute of the warehouse.
+pub trait ScalingAdvisor: Send + Sync {
+    fn advise(&self, load: &NodeLoad) -> ScalingRecommendation;
+}
+
+/// Scales up when queries are queued or the node is under CPU or memory pressure, and scales
+/// down when the node is idle.
+pub struct ThresholdScalingAdvisor;
+
+impl ThresholdScalingAdvisor {
+    const SCALE_UP_CPU_USAGE: f64 = 0.8;
+    const SCALE_UP_MEMORY_USAGE: f64 = 0.8;
+    const SCALE_DOWN_CPU_USAGE: f64 = 0.1;
+}
+
+impl ScalingAdvisor for ThresholdScalingAdvisor {
+    fn advise(&self, load: &NodeLoad) -> ScalingRecommendation {
+        if load.queued_queries > 0 {
+            return ScalingRecommendation::create(
+                ScalingDirection::ScaleUp,
+                format!("{} queries are queued", load.queued_queries),
+            );
+        }
+        if load.cpu_usage >= Self::SCALE_UP_CPU_USAGE {
+            return ScalingRecommendation::create(
+                ScalingDirection::ScaleUp,
+                format!("cpu usage {:.2} is high", load.cpu_usage),
+            );
+        }
+        if load.memory_usage >= Self::SCALE_UP_MEMORY_USAGE {
+            return ScalingRecommendation::create(
+                ScalingDirection::ScaleUp,
+                format!("memory usage {:.2} is high", load.memory_usage),
+            );
+        }
+        if load.running_queries == 0 && load.cpu_usage < Self::SCALE_DOWN_CPU_USAGE {
+            return ScalingRecommendation::create(ScalingDirection::ScaleDown, "node is idle");
+        }
+        ScalingRecommendation::create(ScalingDirection::Keep, "")
+    }
+}
+
+/// The recommendation of the advisor with the load it was made from.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ScalingReport {
+    pub load: NodeLoad,
+    pub recommendation: ScalingRecommendation,
+}
+
+/// Samples the load of the node and asks the [`ScalingAdvisor`] for recommendations, in
+/// background if the webhook is configured, and on demand of the admin api.
+pub struct ScalingAdvisorService {
+    tenant_id: String,
+    cluster_id: String,
+    node_id: String,
+    max_server_memory_usage: u64,
+    webhook_url: Option<String>,
+    advisor: RwLock<Arc<dyn ScalingAdvisor>>,
+    /// The instant and the cpu seconds of the process of the last sample.
+    last_cpu_sample: Mutex<Option<(Instant, u64)>>,
+}
+
+impl ScalingAdvisorService {
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        let webhook_url = &cfg.query.scaling_advisor_webhook_url;
+        let service = Arc::new(ScalingAdvisorService {
+            tenant_id: cfg.query.tenant_id.tenant_name().to_string(),
+            cluster_id: cfg.query.cluster_id.clone(),
+            node_id: cfg.query.node_id.clone(),
+            max_server_memory_usage: cfg.query.max_server_memory_usage,
+            webhook_url: (!webhook_url.is_empty()).then(|| webhook_url.clone()),
+            advisor: RwLock::new(Arc::new(ThresholdScalingAdvisor)),
+            last_cpu_sample: Mutex::new(None),
+        });
+        GlobalInstance::set(service.clone());
+
+        let interval = cfg.query.scaling_advisor_interval_secs;
+        if service.webhook_url.is_none() || interval == 0 {
+            return Ok(());
+        }
+
+        let service = Arc::downgrade(&service);
+        let client = reqwest::Client::new();
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_secs(interval)).await;
+                let Some(service) = service.upgrade() else {
+                    break;
+                };
+
+                let report = service.report();
+                if report.recommendation.direction == ScalingDirection::Keep {
+                    continue;
+                }
+                if let Err(cause) = service.post_report(&client, &report).await {
+                    warn!("scaling_advisor.webhook.error: {:?}", cause);
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<ScalingAdvisorService> {
+        GlobalInstance::get()
+    }
+
+    /// Replaces the advisor, e.g. by enterprise builds on startup.
+    pub fn set_advisor(&self, advisor: Arc<dyn ScalingAdvisor>) {
+        *self.advisor.write() = advisor;
+    }
+
+    /// Samples the load of the node and asks the advisor for a recommendation.
+    pub fn report(&self) -> ScalingReport {
+        let load = self.sample_load();
+        let advisor = self.advisor.read().clone();
+        let recommendation = advisor.advise(&load);
+        metrics_gauge_scaling_recommendation(
+            &self.node_id,
+            &self.cluster_id,
+            &self.tenant_id,
+            recommendation.direction.metric_value(),
+        );
+        ScalingReport {
+            load,
+            recommendation,
+        }
+    }
+
+    fn sample_load(&self) -> NodeLoad {
+        let session_status = SessionManager::instance().get_current_session_status();
+        let memory_usage = match self.max_server_memory_usage {
+            0 => 0.0,
+            limit => GLOBAL_MEM_STAT.get_memory_usage().max(0) as f64 / limit as f64,
+        };
+
+        NodeLoad {
+            tenant_id: self.tenant_id.clone(),
+            cluster_id: self.cluster_id.clone(),
+            node_id: self.node_id.clone(),
+            suspended: ClusterDiscovery::instance().is_local_suspended(),
+            queued_queries: QueriesQueueManager::instance().length(),
+            running_queries: session_status.running_queries_count,
+            cpu_usage: self.sample_cpu_usage(),
+            memory_usage,
+        }
+    }
+
+    fn sample_cpu_usage(&self) -> f64 {
+        let Some(stat) = dump_process_stat() else {
+            return 0.0;
+        };
+
+        let now = Instant::now();
+        let last = self.last_cpu_sample.lock().replace((now, stat.cpu_secs));
+        match last {
+            Some((instant, cpu_secs)) => {
+                let elapsed = now.duration_since(instant).as_secs_f64();
+                if elapsed <= 0.0 {
+                    return 0.0;
+                }
+                let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+                stat.cpu_secs.saturating_sub(cpu_secs) as f64 / elapsed / cores as f64
+            }
+            None => 0.0,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn post_report(&self, client: &reqwest::Client, report: &ScalingReport) -> Result<()> {
+        let Some(url) = &self.webhook_url else {
+            return Ok(());
+        };
+
+        info!(
+            "scaling_advisor: recommend {:?} of warehouse {} because {}",
+            report.recommendation.direction, self.cluster_id, report.recommendation.reason
+        );
+        let response = client
+            .post(url)
+            .json(report)
+            .timeout(Duration::from_secs(10))
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ErrorCode::Internal(format!(
+                "scaling advisor webhook {} responded {} {}",
+                url, status, body
+            )));
+        }
+        Ok(())
+    }
+}
@@ -29,21 +29,26 @@ use databend_common_storages_system::CachesTable;
 use databend_common_storages_system::CatalogsTable;
 use databend_common_storages_system::ClusteringHistoryTable;
 use databend_common_storages_system::ClustersTable;
+use databend_common_storages_system::ColumnBackfillsTable;
+use databend_common_storages_system::ColumnLineageTable;
 use databend_common_storages_system::ColumnsTable;
 use databend_common_storages_system::ConfigsTable;
 use databend_common_storages_system::ContributorsTable;
 use databend_common_storages_system::CreditsTable;
 use databend_common_storages_system::DatabasesTable;
 use databend_common_storages_system::EnginesTable;
+use databend_common_storages_system::FragmentsTable;
 use databend_common_storages_system::FullStreamsTable;
 use databend_common_storages_system::FunctionsTable;
 use databend_common_storages_system::IndexesTable;
 use databend_common_storages_system::LocksTable;
+use databend_common_storages_system::MaintenanceHistoryTable;
 use databend_common_storages_system::MallocStatsTable;
 use databend_common_storages_system::MallocStatsTotalsTable;
 use databend_common_storages_system::MetricsTable;
 use databend_common_storages_system::NotificationHistoryTable;
 use databend_common_storages_system::NotificationsTable;
+use databend_common_storages_system::ObjectDependenciesTable;
 use databend_common_storages_system::OneTable;
 use databend_common_storages_system::PasswordPoliciesTable;
 use databend_common_storages_system::ProcessesTable;
@@ -52,14 +57,21 @@ use databend_common_storages_system::QueryCacheTable;
 use databend_common_storages_system::QueryLogTable;
 use databend_common_storages_system::RolesTable;
 use databend_common_storages_system::SettingsTable;
+use databend_common_storages_system::ShareConsumerUsageTable;
+use databend_common_storages_system::ShareUsageTable;
 use databend_common_storages_system::StagesTable;
+use databend_common_storages_system::TableBlocksTable;
 use databend_common_storages_system::TableFunctionsTable;
+use databend_common_storages_system::TableSegmentsTable;
 use databend_common_storages_system::TablesTableWithHistory;
 use databend_common_storages_system::TablesTableWithoutHistory;
+use databend_common_storages_system::TagReferencesTable;
+use databend_common_storages_system::TagsTable;
 use databend_common_storages_system::TaskHistoryTable;
 use databend_common_storages_system::TasksTable;
 use databend_common_storages_system::TempFilesTable;
 use databend_common_storages_system::TerseStreamsTable;
+use databend_common_storages_system::UsageTable;
 use databend_common_storages_system::UserFunctionsTable;
 use databend_common_storages_system::UsersTable;
 use databend_common_storages_system::ViewsTableWithHistory;
@@ -115,6 +127,23 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(MaintenanceHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            Arc::new(ColumnLineageTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            Arc::new(UsageTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            Arc::new(ShareUsageTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            ShareConsumerUsageTable::create(sys_db_meta.next_table_id()),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -131,9 +160,16 @@ impl SystemDatabase {
             TasksTable::create(sys_db_meta.next_table_id()),
             TaskHistoryTable::create(sys_db_meta.next_table_id()),
             QueriesProfilingTable::create(sys_db_meta.next_table_id()),
+            FragmentsTable::create(sys_db_meta.next_table_id()),
             LocksTable::create(sys_db_meta.next_table_id()),
+            TableSegmentsTable::create(sys_db_meta.next_table_id()),
+            TableBlocksTable::create(sys_db_meta.next_table_id()),
+            ColumnBackfillsTable::create(sys_db_meta.next_table_id()),
             VirtualColumnsTable::create(sys_db_meta.next_table_id()),
             PasswordPoliciesTable::create(sys_db_meta.next_table_id()),
+            TagsTable::create(sys_db_meta.next_table_id()),
+            TagReferencesTable::create(sys_db_meta.next_table_id()),
+            ObjectDependenciesTable::create(sys_db_meta.next_table_id()),
             UserFunctionsTable::create(sys_db_meta.next_table_id()),
             NotificationsTable::create(sys_db_meta.next_table_id()),
             NotificationHistoryTable::create(sys_db_meta.next_table_id()),
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_base::base::GlobalInstance;
 use databend_common_base::runtime::GlobalIORuntime;
@@ -24,12 +25,17 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
+use databend_common_expression::udf_server_pool::UDFServerPool;
 use databend_common_meta_app::schema::CatalogType;
+use databend_common_metrics::executor::set_warehouse_labels;
 use databend_common_sharing::ShareEndpointManager;
+use databend_common_sharing::ShareUsageMeter;
 use databend_common_storage::DataOperator;
 use databend_common_storage::ShareTableConfig;
+use databend_common_storage::StageOperatorPool;
 use databend_common_storages_hive::HiveCreator;
 use databend_common_storages_iceberg::IcebergCreator;
+use databend_common_storages_stage::FileFormatPluginRegistry;
 use databend_common_storages_system::ProfilesLogQueue;
 use databend_common_tracing::GlobalLogger;
 use databend_common_users::builtin::BuiltIn;
@@ -42,12 +48,19 @@ use crate::builtin::BuiltinUDFs;
 use crate::builtin::BuiltinUsers;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::clusters::ScalingAdvisorService;
+use crate::interpreters::SystemHistoryPurger;
+use crate::interpreters::TieringMover;
+use crate::interpreters::UsageMeter;
 use crate::locks::LockManager;
+use crate::pipelines::executor::BatchExecutorThreads;
 #[cfg(feature = "enable_queries_executor")]
 use crate::pipelines::executor::GlobalQueriesExecutor;
+use crate::pipelines::processors::transforms::HashJoinBuildCache;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueriesQueueManager;
+use crate::sessions::RepeatedSubqueries;
 use crate::sessions::SessionManager;
 
 pub struct GlobalServices;
@@ -78,6 +91,10 @@ impl GlobalServices {
         log_labels.insert("cluster_id".to_string(), config.query.cluster_id.clone());
         log_labels.insert("node_id".to_string(), config.query.node_id.clone());
         GlobalLogger::init(&app_name_shuffle, &config.log, log_labels);
+        set_warehouse_labels(
+            config.query.tenant_id.tenant_name(),
+            &config.query.cluster_id,
+        );
 
         // 3. runtime init.
         GlobalIORuntime::init(config.storage.num_cpus as usize)?;
@@ -110,6 +127,8 @@ impl GlobalServices {
         DataExchangeManager::init()?;
         SessionManager::init(config)?;
         LockManager::init()?;
+        HashJoinBuildCache::init(config.query.hash_join_build_cache_bytes as usize)?;
+        RepeatedSubqueries::init()?;
         AuthMgr::init(config)?;
 
         // Init user manager.
@@ -136,6 +155,9 @@ impl GlobalServices {
         ShareEndpointManager::init()?;
 
         DataOperator::init(&config.storage).await?;
+        StageOperatorPool::init(Duration::from_secs(
+            config.query.stage_operator_refresh_secs,
+        ))?;
         ShareTableConfig::init(
             &config.query.share_endpoint_address,
             &config.query.share_endpoint_auth_token_file,
@@ -151,11 +173,19 @@ impl GlobalServices {
             CloudControlApiProvider::init(addr, config.query.cloud_control_grpc_timeout).await?;
         }
 
+        FileFormatPluginRegistry::init()?;
         ProfilesLogQueue::init(config.query.max_cached_queries_profiles);
+        ShareUsageMeter::init()?;
+        UDFServerPool::init()?;
+        UsageMeter::init(config)?;
+        SystemHistoryPurger::init(config)?;
+        TieringMover::init(config)?;
+        ScalingAdvisorService::init(config)?;
+        BatchExecutorThreads::init(config.query.batch_executor_threads as usize)?;
 
         #[cfg(feature = "enable_queries_executor")]
         {
-            GlobalQueriesExecutor::init()?;
+            GlobalQueriesExecutor::init(config.query.batch_executor_threads as usize)?;
         }
 
         Ok(())
@@ -18,6 +18,8 @@ use std::sync::Arc;
 use databend_common_exception::Result;
 
 use crate::interpreters::access::PrivilegeAccess;
+use crate::interpreters::access::ReadOnlyAccess;
+use crate::interpreters::access::WarehouseAccess;
 use crate::interpreters::ManagementModeAccess;
 use crate::sessions::QueryContext;
 use crate::sql::plans::Plan;
@@ -41,6 +43,8 @@ impl Accessor {
             "privilege".to_string(),
             PrivilegeAccess::create(ctx.clone()),
         );
+        accessors.insert("read_only".to_string(), ReadOnlyAccess::create());
+        accessors.insert("warehouse".to_string(), WarehouseAccess::create());
         Accessor { ctx, accessors }
     }
 
@@ -96,6 +96,7 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::ShowRoles(_)
                 | Plan::CreateRole(_)
                 | Plan::DropRole(_)
+                | Plan::AlterRole(_)
 
                 // Privilege.
                 | Plan::GrantPriv(_)
@@ -113,6 +114,10 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreatePasswordPolicy(_)
                 | Plan::AlterPasswordPolicy(_)
                 | Plan::DropPasswordPolicy(_)
+                // Tag.
+                | Plan::CreateTag(_)
+                | Plan::AlterTag(_)
+                | Plan::DropTag(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
@@ -15,8 +15,12 @@
 mod accessor;
 mod management_mode_access;
 mod privilege_access;
+mod read_only_access;
+mod warehouse_access;
 
 pub use accessor::AccessChecker;
 pub use accessor::Accessor;
 pub use management_mode_access::ManagementModeAccess;
 pub use privilege_access::PrivilegeAccess;
+pub use read_only_access::ReadOnlyAccess;
+pub use warehouse_access::WarehouseAccess;
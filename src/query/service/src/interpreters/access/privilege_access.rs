@@ -149,7 +149,7 @@ impl PrivilegeAccess {
             GrantObject::UDF(name) => OwnershipObject::UDF {
                 name: name.to_string(),
             },
-            GrantObject::Global => return Ok(None),
+            GrantObject::Global | GrantObject::Warehouse(_) => return Ok(None),
         };
 
         Ok(Some(object))
@@ -416,7 +416,7 @@ impl PrivilegeAccess {
             | GrantObject::UDF(_)
             | GrantObject::Stage(_)
             | GrantObject::TableById(_, _, _) => true,
-            GrantObject::Global => false,
+            GrantObject::Global | GrantObject::Warehouse(_) => false,
         };
 
         if verify_ownership
@@ -461,6 +461,7 @@ impl PrivilegeAccess {
                     GrantObject::Global
                     | GrantObject::UDF(_)
                     | GrantObject::Stage(_)
+                    | GrantObject::Warehouse(_)
                     | GrantObject::Database(_, _)
                     | GrantObject::Table(_, _, _) => Err(ErrorCode::PermissionDenied(format!(
                         "Permission denied: privilege [{:?}] is required on {} for user {} with roles [{}]. \
@@ -586,7 +587,7 @@ impl PrivilegeAccess {
             InsertInputSource::Stage(plan) => {
                 self.check(ctx, plan).await?;
             }
-            InsertInputSource::Values(_) => {}
+            InsertInputSource::Values(_) | InsertInputSource::Blocks { .. } => {}
         }
         Ok(())
     }
@@ -758,6 +759,18 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Drop, plan.if_exists).await?;
             }
+            Plan::SetDatabaseOptions(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Alter, plan.if_exists).await?;
+            }
+            Plan::ExportDatabase(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Select, false).await?;
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Write).await?;
+            }
+            Plan::ImportDatabase(plan) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::CreateDatabase, true)
+                    .await?;
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Read).await?;
+            }
             Plan::UndropDatabase(_)
             | Plan::DropIndex(_)
             | Plan::DropTableIndex(_) => {
@@ -875,6 +888,14 @@ impl AccessChecker for PrivilegeAccess {
                 }
                 self.validate_db_access(&plan.catalog, &plan.new_database, UserPrivilegeType::Create, false).await?;
             }
+            Plan::SwapTable(plan) => {
+                // You must have ALTER and DROP privileges for both tables.
+                let privileges = vec![UserPrivilegeType::Alter, UserPrivilegeType::Drop];
+                for privilege in privileges {
+                    self.validate_table_access(&plan.catalog, &plan.database, &plan.table, privilege, plan.if_exists).await?;
+                    self.validate_table_access(&plan.catalog, &plan.database, &plan.target_table, privilege, false).await?;
+                }
+            }
             Plan::SetOptions(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false).await?
             }
@@ -890,6 +911,12 @@ impl AccessChecker for PrivilegeAccess {
             Plan::ModifyTableComment(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false).await?
             }
+            Plan::AlterTableTags(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false).await?
+            }
+            Plan::AlterTableStoragePolicy(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false).await?
+            }
             Plan::DropTableColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false).await?
             }
@@ -920,12 +947,34 @@ impl AccessChecker for PrivilegeAccess {
             Plan::VacuumDropTable(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Super, false).await?
             }
-            Plan::VacuumTemporaryFiles(_) => {
+            Plan::VacuumTemporaryFiles(_) | Plan::VacuumSystemHistory(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false).await?
             }
             Plan::AnalyzeTable(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false).await?
             }
+            Plan::VerifyTable(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false).await?
+            }
+            Plan::RefreshTableStatistics(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false).await?
+            }
+            Plan::RestoreTable(plan) => {
+                // The data of an existing table is overwritten, otherwise the table is created.
+                let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+                match catalog.get_table(&self.ctx.get_tenant(), &plan.database, &plan.table).await {
+                    Ok(_) => {
+                        for privilege in [UserPrivilegeType::Insert, UserPrivilegeType::Delete] {
+                            self.validate_table_access(&plan.catalog, &plan.database, &plan.table, privilege, false).await?;
+                        }
+                    }
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => {
+                        self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
+                    }
+                    Err(e) => return Err(e),
+                }
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Read).await?;
+            }
             // Others.
             Plan::Insert(plan) => {
                 let target_table_privileges = if plan.overwrite {
@@ -1118,7 +1167,7 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Grant,false)
                     .await?;
             }
-            Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) | Plan::SetPriority(_) | Plan::System(_) => {
+            Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) | Plan::KillWhere(_) | Plan::SetPriority(_) | Plan::System(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false)
                     .await?;
             }
@@ -1133,6 +1182,12 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Alter, false)
                     .await?;
             }
+            // The estimate limits of a role restrict its users, who must not lift them
+            // with the privileges they are granted.
+            Plan::AlterRole(_) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::Grant, false)
+                    .await?;
+            }
             Plan::CopyIntoTable(plan) => {
                 self.validate_stage_access(&plan.stage_table_info.stage_info, UserPrivilegeType::Read).await?;
                 self.validate_table_access(plan.catalog_info.catalog_name(), &plan.database_name, &plan.table_name, UserPrivilegeType::Insert, false).await?;
@@ -1170,6 +1225,9 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::CreateTag(_)
+            | Plan::AlterTag(_)
+            | Plan::DropTag(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -1220,6 +1278,7 @@ impl AccessChecker for PrivilegeAccess {
             Plan::Commit => {}
             Plan::Abort => {}
             Plan::ExecuteImmediate(_) => {}
+            Plan::ShowWarnings => {}
         }
 
         Ok(())
@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserSettingValue;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::MergeInto;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_READ_ONLY;
+
+use crate::interpreters::access::AccessChecker;
+use crate::sessions::QueryContext;
+use crate::sql::plans::Plan;
+
+pub struct ReadOnlyAccess {}
+impl ReadOnlyAccess {
+    pub fn create() -> Box<dyn AccessChecker> {
+        Box::new(ReadOnlyAccess {})
+    }
+}
+
+#[async_trait::async_trait]
+impl AccessChecker for ReadOnlyAccess {
+    // Reject the DML in maintenance mode or on the read-only tables and databases.
+    #[async_backtrace::framed]
+    async fn check(&self, ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        let targets = dml_target_tables(plan)?;
+        if targets.is_empty() {
+            return Ok(());
+        }
+
+        if is_maintenance_mode(ctx).await? {
+            return Err(ErrorCode::MaintenanceMode(format!(
+                "Maintenance Mode Error: {} is rejected while the cluster is in maintenance mode, only reads and admin commands are allowed",
+                plan.format_indent(false)?
+            )));
+        }
+
+        for (catalog, database, table) in targets {
+            let db = ctx
+                .get_catalog(&catalog)
+                .await?
+                .get_database(&ctx.get_tenant(), &database)
+                .await?;
+            if is_read_only(db.options()) {
+                return Err(ErrorCode::TableReadOnly(format!(
+                    "Database '{}' is read only, DML is not allowed on its tables. Use `ALTER DATABASE ... SET READ_ONLY = false` to make it writable",
+                    database
+                )));
+            }
+
+            let table = ctx.get_table(&catalog, &database, &table).await?;
+            if is_read_only(table.options()) {
+                return Err(ErrorCode::TableReadOnly(format!(
+                    "Table '{}'.'{}' is read only, DML is not allowed. Use `ALTER TABLE ... SET READ_ONLY = false` to make it writable",
+                    database,
+                    table.name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_read_only(options: &BTreeMap<String, String>) -> bool {
+    options
+        .get(OPT_KEY_READ_ONLY)
+        .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+}
+
+// `maintenance_mode` can only be set globally, it's read from the meta instead of the settings
+// loaded by the session, so that it takes effect on all the sessions at once.
+async fn is_maintenance_mode(ctx: &QueryContext) -> Result<bool> {
+    let setting = UserApiProvider::instance()
+        .setting_api(&ctx.get_tenant())
+        .get_setting("maintenance_mode", MatchSeq::GE(0))
+        .await;
+    match setting {
+        Ok(setting) => Ok(matches!(setting.data.value, UserSettingValue::UInt64(v) if v != 0)),
+        Err(e) if e.code() == ErrorCode::UNKNOWN_VARIABLE => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+// The (catalog, database, table) of the tables modified by the DML plan.
+fn dml_target_tables(plan: &Plan) -> Result<Vec<(String, String, String)>> {
+    let target = |catalog: &str, database: &str, table: &str| {
+        (catalog.to_string(), database.to_string(), table.to_string())
+    };
+
+    Ok(match plan {
+        Plan::ExplainAnalyze { plan } => dml_target_tables(plan)?,
+        Plan::Insert(plan) => vec![target(&plan.catalog, &plan.database, &plan.table)],
+        Plan::InsertMultiTable(plan) => plan
+            .whens
+            .iter()
+            .flat_map(|when| when.intos.iter())
+            .chain(plan.opt_else.iter().flat_map(|e| e.intos.iter()))
+            .map(|into| target(&into.catalog, &into.database, &into.table))
+            .collect(),
+        Plan::Replace(plan) => vec![target(&plan.catalog, &plan.database, &plan.table)],
+        Plan::MergeInto { s_expr, .. } => {
+            let plan: MergeInto = s_expr.plan().clone().try_into()?;
+            vec![target(&plan.catalog, &plan.database, &plan.table)]
+        }
+        Plan::Delete(plan) => vec![target(
+            &plan.catalog_name,
+            &plan.database_name,
+            &plan.table_name,
+        )],
+        Plan::Update(plan) => vec![target(&plan.catalog, &plan.database, &plan.table)],
+        Plan::CopyIntoTable(plan) => vec![target(
+            plan.catalog_info.catalog_name(),
+            &plan.database_name,
+            &plan.table_name,
+        )],
+        Plan::TruncateTable(plan) => vec![target(&plan.catalog, &plan.database, &plan.table)],
+        _ => vec![],
+    })
+}
@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_meta_app::principal::UserSettingValue;
+use databend_common_meta_types::MatchSeq;
+use databend_common_settings::ScopeLevel;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::access::AccessChecker;
+use crate::sessions::QueryContext;
+use crate::sql::plans::Plan;
+
+pub struct WarehouseAccess {}
+impl WarehouseAccess {
+    pub fn create() -> Box<dyn AccessChecker> {
+        Box::new(WarehouseAccess {})
+    }
+}
+
+#[async_trait::async_trait]
+impl AccessChecker for WarehouseAccess {
+    // Reject the queries routed to a reserved warehouse, unless the user or the session roles
+    // have USAGE on it. The warehouses are reserved by `SET GLOBAL reserved_warehouses`.
+    #[async_backtrace::framed]
+    async fn check(&self, ctx: &Arc<QueryContext>, plan: &Plan) -> Result<()> {
+        // Switching to a role that can use the warehouse must always be possible.
+        if matches!(plan, Plan::SetRole(_) | Plan::SetSecondaryRoles(_)) {
+            return Ok(());
+        }
+
+        let warehouse = GlobalConfig::instance().query.cluster_id.clone();
+        if !reserved_warehouses(ctx)
+            .await?
+            .iter()
+            .any(|reserved| reserved == &warehouse)
+        {
+            return Ok(());
+        }
+
+        let session = ctx.get_current_session();
+        match session
+            .validate_privilege(
+                &GrantObject::Warehouse(warehouse.clone()),
+                UserPrivilegeType::Usage,
+                false,
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) if err.code() == ErrorCode::PERMISSION_DENIED => {
+                let roles_name = session
+                    .get_all_effective_roles()
+                    .await?
+                    .iter()
+                    .map(|r| r.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: privilege [Usage] is required on WAREHOUSE {} for user {} with roles [{}]",
+                    warehouse,
+                    ctx.get_current_user()?.identity().display(),
+                    roles_name,
+                )))
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+// `reserved_warehouses` can only be set globally, so it's taken from the global settings loaded by
+// the session and only read from the meta again if the session has overridden it, e.g. by the
+// settings of an http query, so that the session can't release the warehouse by itself.
+async fn reserved_warehouses(ctx: &QueryContext) -> Result<Vec<String>> {
+    let loaded = ctx
+        .get_shared_settings()
+        .changes()
+        .get("reserved_warehouses")
+        .map(|change| (change.level.clone(), change.value.clone()));
+    let value = match loaded {
+        None => return Ok(vec![]),
+        Some((ScopeLevel::Global, value)) => value,
+        Some(_) => {
+            let setting = UserApiProvider::instance()
+                .setting_api(&ctx.get_tenant())
+                .get_setting("reserved_warehouses", MatchSeq::GE(0))
+                .await;
+            match setting {
+                Ok(setting) => setting.data.value,
+                Err(e) if e.code() == ErrorCode::UNKNOWN_VARIABLE => return Ok(vec![]),
+                Err(e) => return Err(e),
+            }
+        }
+    };
+    Ok(match value {
+        UserSettingValue::String(v) => v
+            .split(',')
+            .map(|warehouse| warehouse.trim().to_string())
+            .filter(|warehouse| !warehouse.is_empty())
+            .collect(),
+        UserSettingValue::UInt64(_) => vec![],
+    })
+}
@@ -0,0 +1,264 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_sql::Planner;
+use databend_common_sql::TableReferenceCollector;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+use futures::TryStreamExt;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+
+/// The name of the manifest file at the root of a database bundle.
+pub const DATABASE_BUNDLE_MANIFEST: &str = "manifest.json";
+
+/// The dialect the DDL of a bundle is written in, so that a bundle can be imported
+/// regardless of the dialect of the importing session.
+pub const DATABASE_BUNDLE_DIALECT: Dialect = Dialect::PostgreSQL;
+
+/// The manifest of a bundle written by `EXPORT DATABASE` and read by `IMPORT DATABASE`.
+///
+/// The data of each table is a set of parquet files under `<location>/<data_dir>/`, which
+/// are read from the snapshot recorded in the manifest. The data dirs are unique to each
+/// export, so the files left by a failed export at the same location are never imported.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseBundleManifest {
+    pub version: u64,
+    pub database: String,
+    #[serde(default)]
+    pub exported_at: Option<DateTime<Utc>>,
+    pub tables: Vec<DatabaseBundleTable>,
+    pub views: Vec<DatabaseBundleView>,
+}
+
+impl DatabaseBundleManifest {
+    pub const VERSION: u64 = 1;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseBundleTable {
+    pub name: String,
+    pub ddl: String,
+    /// None if the table had no snapshot, i.e. no data, when it was exported.
+    pub snapshot_id: Option<String>,
+    #[serde(default)]
+    pub snapshot_timestamp: Option<DateTime<Utc>>,
+    pub rows: u64,
+    pub data_dir: String,
+}
+
+impl DatabaseBundleTable {
+    /// The point in time the data of the table in the bundle is of.
+    pub fn as_of(&self, manifest: &DatabaseBundleManifest) -> Option<DateTime<Utc>> {
+        self.snapshot_timestamp.or(manifest.exported_at)
+    }
+
+    /// Checks the data dir is `data/<export id>/<table id>` as written by `EXPORT DATABASE`,
+    /// so that a modified manifest can't make the import load the files out of the bundle.
+    pub fn check_data_dir(&self) -> Result<()> {
+        let valid = match self.data_dir.split('/').collect::<Vec<_>>().as_slice() {
+            ["data", export_id, table_id] => {
+                Uuid::try_parse(export_id).is_ok()
+                    && !table_id.is_empty()
+                    && table_id.bytes().all(|b| b.is_ascii_digit())
+            }
+            _ => false,
+        };
+        if !valid {
+            return Err(ErrorCode::BadArguments(format!(
+                "invalid data dir {:?} of table {} in the database bundle, expect data/<export id>/<table id>",
+                self.data_dir, self.name
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseBundleView {
+    pub name: String,
+    pub ddl: String,
+}
+
+/// Sorts the views so that each view comes after the views of the database it selects
+/// from, and by name otherwise, because a view is created after the views it depends on.
+pub fn sort_database_bundle_views(
+    database: &str,
+    views: Vec<DatabaseBundleView>,
+) -> Result<Vec<DatabaseBundleView>> {
+    let names = views
+        .iter()
+        .map(|view| view.name.clone())
+        .collect::<HashSet<_>>();
+
+    let mut pending = Vec::with_capacity(views.len());
+    for view in views {
+        let dependencies = TableReferenceCollector::collect(
+            database.to_string(),
+            &view.ddl,
+            DATABASE_BUNDLE_DIALECT,
+        )?
+        .into_iter()
+        .filter(|(db, name)| db == database && name != &view.name && names.contains(name))
+        .map(|(_, name)| name)
+        .collect::<BTreeSet<_>>();
+        pending.push((view, dependencies));
+    }
+    pending.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+
+    let mut created = HashSet::with_capacity(pending.len());
+    let mut sorted = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let Some(index) = pending
+            .iter()
+            .position(|(_, dependencies)| dependencies.iter().all(|name| created.contains(name)))
+        else {
+            return Err(ErrorCode::BadArguments(format!(
+                "the views {} of database {} depend on each other",
+                pending
+                    .iter()
+                    .map(|(view, _)| view.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                database
+            )));
+        };
+        let (view, _) = pending.remove(index);
+        created.insert(view.name.clone());
+        sorted.push(view);
+    }
+    Ok(sorted)
+}
+
+/// Joins `name` to the path of a bundle resolved from a stage location.
+pub fn database_bundle_path(path: &str, name: &str) -> String {
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}/{name}")
+    }
+}
+
+/// Changes the quote of the quoted identifiers of a statement, which is used to replay the
+/// DDL of a bundle in the dialect of the current session.
+#[derive(VisitorMut)]
+#[visitor(Identifier(enter))]
+struct IdentifierRequoter {
+    quote: char,
+}
+
+impl IdentifierRequoter {
+    fn enter_identifier(&mut self, ident: &mut Identifier) {
+        if ident.quote.is_some() {
+            ident.quote = Some(self.quote);
+        }
+    }
+}
+
+/// Reads the manifest of a bundle, failing if it is of a newer version than supported.
+#[async_backtrace::framed]
+pub async fn read_database_bundle_manifest(
+    op: &Operator,
+    manifest_path: &str,
+) -> Result<DatabaseBundleManifest> {
+    let bytes = op.read(manifest_path).await?.to_vec();
+    let manifest: DatabaseBundleManifest = serde_json::from_slice(&bytes)?;
+    if manifest.version > DatabaseBundleManifest::VERSION {
+        return Err(ErrorCode::BadArguments(format!(
+            "unsupported database bundle version {} of {}, the latest supported is {}",
+            manifest.version,
+            manifest_path,
+            DatabaseBundleManifest::VERSION
+        )));
+    }
+    Ok(manifest)
+}
+
+/// Rewrites the DDL of a bundle to create the object in the given database, in the
+/// dialect of the current session.
+pub fn rewrite_database_bundle_ddl(
+    ddl: &str,
+    catalog: &str,
+    database: &str,
+    sql_dialect: Dialect,
+) -> Result<String> {
+    let tokens = tokenize_sql(ddl)?;
+    let (mut stmt, _) = parse_sql(&tokens, DATABASE_BUNDLE_DIALECT)?;
+
+    let quote = Some(DATABASE_BUNDLE_DIALECT.default_ident_quote());
+    let catalog = Some(Identifier::from_name_with_quoted(None, catalog, quote));
+    let database = Some(Identifier::from_name_with_quoted(None, database, quote));
+    match &mut stmt {
+        Statement::CreateTable(create) => {
+            create.catalog = catalog;
+            create.database = database;
+        }
+        Statement::CreateView(create) => {
+            create.catalog = catalog;
+            create.database = database;
+        }
+        _ => {
+            return Err(ErrorCode::BadArguments(format!(
+                "unsupported statement in database bundle: {}",
+                ddl
+            )));
+        }
+    }
+
+    stmt.drive_mut(&mut IdentifierRequoter {
+        quote: sql_dialect.default_ident_quote(),
+    });
+    Ok(stmt.to_string())
+}
+
+/// Plans and executes a statement in a new query context of the current session.
+#[async_backtrace::framed]
+pub async fn execute_sql_in_new_context(
+    ctx: &Arc<QueryContext>,
+    sql: &str,
+) -> Result<Vec<DataBlock>> {
+    execute_sql_in_session(&ctx.get_current_session(), sql).await
+}
+
+/// Plans and executes a statement in a new query context of the session.
+#[async_backtrace::framed]
+pub async fn execute_sql_in_session(session: &Arc<Session>, sql: &str) -> Result<Vec<DataBlock>> {
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    stream.try_collect::<Vec<_>>().await
+}
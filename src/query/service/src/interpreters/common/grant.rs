@@ -93,7 +93,8 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
-        GrantObject::Global => (),
+        // Warehouses are provisioned outside of the meta service, and may be granted before they start.
+        GrantObject::Global | GrantObject::Warehouse(_) => (),
     }
 
     Ok(())
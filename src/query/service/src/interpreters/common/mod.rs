@@ -12,24 +12,46 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod database_bundle;
 mod grant;
 mod metrics;
 mod notification;
+mod query_estimate;
 mod query_log;
+mod query_replay;
 mod stream;
+mod system_history;
 mod table;
 mod task;
+mod usage_meter;
 mod util;
 
+pub use database_bundle::database_bundle_path;
+pub use database_bundle::execute_sql_in_new_context;
+pub use database_bundle::execute_sql_in_session;
+pub use database_bundle::read_database_bundle_manifest;
+pub use database_bundle::rewrite_database_bundle_ddl;
+pub use database_bundle::sort_database_bundle_views;
+pub use database_bundle::DatabaseBundleManifest;
+pub use database_bundle::DatabaseBundleTable;
+pub use database_bundle::DatabaseBundleView;
+pub use database_bundle::DATABASE_BUNDLE_DIALECT;
+pub use database_bundle::DATABASE_BUNDLE_MANIFEST;
 pub use grant::validate_grant_object_exists;
 pub use notification::get_notification_client_config;
+pub use query_estimate::check_query_estimate;
+pub use query_estimate::get_current_role_with_limits;
 pub use query_log::InterpreterQueryLog;
+pub use query_replay::capture_query_replay;
 pub use stream::dml_build_update_stream_req;
 pub use stream::query_build_update_stream_req;
+pub use system_history::SystemHistoryPurger;
+pub use table::check_no_dependents;
 pub use table::check_referenced_computed_columns;
 pub use task::get_task_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
+pub use usage_meter::UsageMeter;
 pub use util::check_deduplicate_label;
 pub use util::create_push_down_filters;
 
@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::RoleInfo;
+use databend_common_users::RoleCacheManager;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::executor::PhysicalPlan;
+use crate::sql::executor::QueryEstimate;
+
+/// Refuses the query if it's estimated to exceed the limits of the current role,
+/// which are set by `ALTER ROLE <role> SET max_estimated_scan_bytes = <bytes>`.
+pub async fn check_query_estimate(ctx: &QueryContext, plan: &PhysicalPlan) -> Result<()> {
+    match get_current_role_with_limits(ctx).await? {
+        Some(role) => QueryEstimate::estimate(plan).check_limits(&role),
+        None => Ok(()),
+    }
+}
+
+/// Returns the current role if it has any estimate limit. The role is looked up in the
+/// role cache, so the limits altered after the role is set to the session are applied.
+pub async fn get_current_role_with_limits(ctx: &QueryContext) -> Result<Option<RoleInfo>> {
+    let Some(current_role) = ctx.get_current_role() else {
+        return Ok(None);
+    };
+    let role = RoleCacheManager::instance()
+        .find_role(&ctx.get_tenant(), &current_role.name)
+        .await?
+        .unwrap_or(current_role);
+    match role.max_estimated_scan_bytes.is_some() || role.max_estimated_memory_bytes.is_some() {
+        true => Ok(Some(role)),
+        false => Ok(None),
+    }
+}
@@ -20,6 +20,7 @@ use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_settings::ScopeLevel;
 use databend_common_storages_system::LogType;
 use databend_common_storages_system::QueryLogElement;
 use databend_common_storages_system::QueryLogQueue;
@@ -33,6 +34,27 @@ use crate::sessions::TableContext;
 
 pub struct InterpreterQueryLog;
 
+/// The settings of the query that are not default, and where their values come from.
+fn effective_settings(ctx: &QueryContext) -> String {
+    let mut effective_settings = String::new();
+    for item in ctx.get_settings().into_iter() {
+        if item.level == ScopeLevel::Default {
+            continue;
+        }
+
+        if !effective_settings.is_empty() {
+            effective_settings.push_str(", ");
+        }
+        write!(
+            effective_settings,
+            "{}={:?} ({:?})",
+            item.name, item.user_value, item.level
+        )
+        .expect("write to string must succeed");
+    }
+    effective_settings
+}
+
 fn error_fields(log_type: LogType, err: Option<ErrorCode>) -> (LogType, i32, String, String) {
     match err {
         None => (log_type, 0, "".to_string(), "".to_string()),
@@ -135,6 +157,8 @@ impl InterpreterQueryLog {
             None => "".to_string(),
         };
         let user_agent = ctx.get_ua();
+        let query_tag = ctx.get_settings().get_query_tag()?;
+        let application_name = ctx.get_settings().get_application_name()?;
         // Session settings
         let mut session_settings = String::new();
         let current_session = ctx.get_current_session();
@@ -144,6 +168,7 @@ impl InterpreterQueryLog {
         }
 
         session_settings.push_str("scope: SESSION");
+        let effective_settings = effective_settings(ctx);
 
         // Error
         let (log_type, exception_code, exception_text, stack_trace) =
@@ -208,12 +233,15 @@ impl InterpreterQueryLog {
             client_info: "".to_string(),
             client_address,
             user_agent,
+            query_tag,
+            application_name,
 
             exception_code,
             exception_text,
             stack_trace,
             server_version: DATABEND_COMMIT_VERSION.to_string(),
             session_settings,
+            effective_settings,
             extra: "".to_string(),
             has_profiles: false,
             txn_state,
@@ -296,6 +324,8 @@ impl InterpreterQueryLog {
             None => "".to_string(),
         };
         let user_agent = ctx.get_ua();
+        let query_tag = ctx.get_settings().get_query_tag()?;
+        let application_name = ctx.get_settings().get_application_name()?;
 
         // Schema.
         let current_database = ctx.get_current_database();
@@ -310,6 +340,7 @@ impl InterpreterQueryLog {
         }
 
         session_settings.push_str("scope: SESSION");
+        let effective_settings = effective_settings(ctx);
 
         // Error
         let (log_type, exception_code, exception_text, stack_trace) =
@@ -374,6 +405,8 @@ impl InterpreterQueryLog {
             client_info: "".to_string(),
             client_address,
             user_agent,
+            query_tag,
+            application_name,
             current_database,
 
             exception_code,
@@ -381,6 +414,7 @@ impl InterpreterQueryLog {
             stack_trace,
             server_version: DATABEND_COMMIT_VERSION.to_string(),
             session_settings,
+            effective_settings,
             extra: "".to_string(),
             has_profiles,
             txn_state,
@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Instant;
+
+use chrono::Duration;
+use chrono::Utc;
+use databend_common_ast::ast::Statement;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_exception::Result;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanExtras;
+use databend_common_sql::QueryReplayBundle;
+use databend_common_sql::QueryReplayTable;
+use databend_common_sql::QueryReplayView;
+use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The setting that enables the capture, which is not captured itself.
+const CAPTURE_SETTING: &str = "enable_query_replay_capture";
+
+/// The expired bundles of a tenant are purged at most once in the interval on the node.
+const PURGE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// The last time the expired bundles of each tenant were purged on the node.
+static LAST_PURGES: LazyLock<Mutex<HashMap<String, Instant>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Writes the replay bundle of a planned query if `enable_query_replay_capture` is set,
+/// which is replayed by `SYSTEM REPLAY QUERY '<query_id>'`.
+///
+/// Only the SELECT queries are captured, the tables they read are recorded at the
+/// snapshots that were resolved while planning, and the views they read with their
+/// queries. The bundles older than `query_replay_retention_days` are removed in background
+/// once every `PURGE_INTERVAL`.
+#[async_backtrace::framed]
+pub async fn capture_query_replay(
+    ctx: &Arc<QueryContext>,
+    sql: &str,
+    plan: &Plan,
+    extras: &PlanExtras,
+) -> Result<()> {
+    let settings = ctx.get_settings();
+    if !settings.get_enable_query_replay_capture()? {
+        return Ok(());
+    }
+    let is_query = match &extras.statement {
+        Statement::StatementWithSettings { stmt, .. } => {
+            matches!(stmt.as_ref(), Statement::Query(_))
+        }
+        stmt => matches!(stmt, Statement::Query(_)),
+    };
+    if !is_query || !matches!(plan, Plan::Query { .. }) {
+        return Ok(());
+    }
+
+    let captured_settings = settings
+        .changes()
+        .iter()
+        .filter(|change| change.key() != CAPTURE_SETTING)
+        .map(|change| (change.key().clone(), change.value().value.as_string()))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut tables = vec![];
+    let mut views = vec![];
+    for ((catalog, database, name), table) in ctx.get_named_tables_refs() {
+        if table.engine() == VIEW_ENGINE {
+            if let Some(query) = table.options().get(QUERY) {
+                views.push(QueryReplayView {
+                    catalog,
+                    database,
+                    name,
+                    query: query.clone(),
+                });
+            }
+            continue;
+        }
+        let snapshot_id = match FuseTable::try_from_table(table.as_ref()) {
+            Ok(fuse_table) => fuse_table
+                .read_table_snapshot()
+                .await?
+                .map(|snapshot| snapshot.snapshot_id.simple().to_string()),
+            Err(_) => None,
+        };
+        tables.push(QueryReplayTable {
+            catalog,
+            database,
+            name,
+            snapshot_id,
+        });
+    }
+    tables.sort_by(|a, b| {
+        (&a.catalog, &a.database, &a.name).cmp(&(&b.catalog, &b.database, &b.name))
+    });
+    views.sort_by(|a, b| {
+        (&a.catalog, &a.database, &a.name).cmp(&(&b.catalog, &b.database, &b.name))
+    });
+
+    let bundle = QueryReplayBundle {
+        version: QueryReplayBundle::VERSION,
+        query_id: ctx.get_id(),
+        sql: sql.to_string(),
+        catalog: ctx.get_current_catalog(),
+        database: ctx.get_current_database(),
+        settings: captured_settings,
+        tables,
+        views,
+        plan: plan.format_indent(false)?,
+        captured_at: Utc::now(),
+    };
+
+    let tenant = ctx.get_tenant();
+    let op = DataOperator::instance().operator();
+    bundle.write(&op, tenant.tenant_name()).await?;
+    info!(
+        "Captured the replay bundle of query {} at {}",
+        bundle.query_id,
+        QueryReplayBundle::path(tenant.tenant_name(), &bundle.query_id)
+    );
+
+    let retention = Duration::days(settings.get_query_replay_retention_days()? as i64);
+    spawn_purge_expired(tenant.tenant_name().to_string(), retention);
+    Ok(())
+}
+
+fn spawn_purge_expired(tenant: String, retention: Duration) {
+    {
+        let mut last_purges = LAST_PURGES.lock();
+        let now = Instant::now();
+        if let Some(last_purge) = last_purges.get(&tenant) {
+            if now.duration_since(*last_purge) < PURGE_INTERVAL {
+                return;
+            }
+        }
+        last_purges.insert(tenant.clone(), now);
+    }
+
+    GlobalIORuntime::instance().spawn(async move {
+        let op = DataOperator::instance().operator();
+        if let Err(error) = QueryReplayBundle::purge_expired(&op, &tenant, retention).await {
+            warn!("Failed to purge the expired replay bundles: {:?}", error);
+        }
+    });
+}
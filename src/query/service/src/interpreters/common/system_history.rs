@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_storages_system::ClusteringHistoryQueue;
+use databend_common_storages_system::ColumnLineageQueue;
+use databend_common_storages_system::MaintenanceHistoryQueue;
+use databend_common_storages_system::ProfilesLogQueue;
+use databend_common_storages_system::QueryLogQueue;
+use databend_common_storages_system::ShareUsageQueue;
+use databend_common_storages_system::UsageQueue;
+use log::error;
+use log::info;
+
+const PURGE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Purges the expired and oversize entries of the in-memory system history tables
+/// (`system.query_log`, `system.queries_profiling`, `system.usage`, ...) in background.
+pub struct SystemHistoryPurger {
+    retention: Option<Duration>,
+    max_bytes: Option<usize>,
+}
+
+impl SystemHistoryPurger {
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        let retention_days = cfg.query.system_history_retention_days;
+        let max_bytes = cfg.query.system_history_max_bytes;
+        let purger = Arc::new(SystemHistoryPurger {
+            retention: (retention_days != 0)
+                .then(|| Duration::from_secs(retention_days * 24 * 60 * 60)),
+            max_bytes: (max_bytes != 0).then_some(max_bytes as usize),
+        });
+        GlobalInstance::set(purger.clone());
+
+        if purger.retention.is_none() && purger.max_bytes.is_none() {
+            return Ok(());
+        }
+
+        let purger = Arc::downgrade(&purger);
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                tokio::time::sleep(PURGE_INTERVAL).await;
+                let Some(purger) = purger.upgrade() else {
+                    break;
+                };
+
+                match purger.purge(None) {
+                    Ok(0) => {}
+                    Ok(purged) => info!("system_history.purge: {} rows purged", purged),
+                    Err(cause) => error!("system_history.purge.error: {:?}", cause),
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<SystemHistoryPurger> {
+        GlobalInstance::get()
+    }
+
+    /// Purge the system history with the configured retention, or with `retention` if given,
+    /// the size limit applies to each history table separately.
+    ///
+    /// Returns the number of the purged rows.
+    pub fn purge(&self, retention: Option<Duration>) -> Result<usize> {
+        let retention = retention.or(self.retention);
+        let max_bytes = self.max_bytes;
+
+        Ok(QueryLogQueue::instance()?.purge(retention, max_bytes)
+            + ProfilesLogQueue::instance()?.purge(retention, max_bytes)
+            + ClusteringHistoryQueue::instance()?.purge(retention, max_bytes)
+            + MaintenanceHistoryQueue::instance()?.purge(retention, max_bytes)
+            + ColumnLineageQueue::instance()?.purge(retention, max_bytes)
+            + UsageQueue::instance()?.purge(retention, max_bytes)
+            + ShareUsageQueue::instance()?.purge(retention, max_bytes))
+    }
+}
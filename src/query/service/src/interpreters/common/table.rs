@@ -14,12 +14,14 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataSchemaRef;
 use databend_common_sql::parse_computed_expr;
+use databend_common_storages_system::collect_object_dependencies;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +57,43 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// Returns error if other objects of the default catalog depend on `database.name`, used by
+/// `DROP TABLE` and `DROP VIEW` unless `CASCADE` is given.
+pub async fn check_no_dependents(
+    ctx: Arc<dyn TableContext>,
+    catalog: &str,
+    database: &str,
+    name: &str,
+) -> Result<()> {
+    if catalog != CATALOG_DEFAULT {
+        return Ok(());
+    }
+
+    let tenant = ctx.get_tenant();
+    let default_catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+    let mut database_and_tables = vec![];
+    for db in default_catalog.list_databases(&tenant).await? {
+        let tables = db.list_tables().await?;
+        database_and_tables.push((db.name().to_string(), tables));
+    }
+
+    let dependents = collect_object_dependencies(&ctx, &database_and_tables)
+        .await?
+        .into_iter()
+        .filter(|d| d.referenced_database == database && d.referenced_name == name)
+        .map(|d| match d.referencing_database {
+            Some(db) => format!("{} {}.{}", d.referencing_domain, db, d.referencing_name),
+            None => format!("{} {}", d.referencing_domain, d.referencing_name),
+        })
+        .collect::<Vec<_>>();
+    if !dependents.is_empty() {
+        return Err(ErrorCode::ObjectHasDependents(format!(
+            "cannot drop {}.{} because other objects depend on it: {}, use DROP ... CASCADE to drop it anyway",
+            database,
+            name,
+            dependents.join(", ")
+        )));
+    }
+    Ok(())
+}
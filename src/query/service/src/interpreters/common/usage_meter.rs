@@ -0,0 +1,199 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::mem;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_config::GlobalConfig;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_sharing::ShareUsageMeter;
+use databend_common_storages_system::ShareUsageLogElement;
+use databend_common_storages_system::ShareUsageQueue;
+use databend_common_storages_system::UsageLogElement;
+use databend_common_storages_system::UsageQueue;
+use log::error;
+use parking_lot::Mutex;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+const USAGE_FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct UsageKey {
+    tenant_id: String,
+    cluster_id: String,
+    sql_user: String,
+}
+
+#[derive(Default)]
+struct UsageCounters {
+    query_count: u64,
+    scan_bytes: u64,
+    written_bytes: u64,
+    cpu_time_ns: u64,
+    spilled_bytes: u64,
+}
+
+struct UsageWindow {
+    start_time: SystemTime,
+    usages: HashMap<UsageKey, UsageCounters>,
+}
+
+impl UsageWindow {
+    fn new() -> UsageWindow {
+        UsageWindow {
+            start_time: SystemTime::now(),
+            usages: HashMap::new(),
+        }
+    }
+}
+
+/// Aggregates the resource usage of finished queries per tenant, warehouse and user,
+/// and flushes it into `system.usage` every minute for chargeback, together with the
+/// reads of the shared tables into `system.share_usage`.
+pub struct UsageMeter {
+    node_id: String,
+    window: Mutex<UsageWindow>,
+}
+
+impl UsageMeter {
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        let meter = Arc::new(UsageMeter::create(cfg.query.node_id.clone()));
+        GlobalInstance::set(meter.clone());
+
+        let meter = Arc::downgrade(&meter);
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                tokio::time::sleep(USAGE_FLUSH_INTERVAL).await;
+                let Some(meter) = meter.upgrade() else {
+                    break;
+                };
+
+                if let Err(cause) = meter.flush().await {
+                    error!("usage.flush.error: {:?}", cause);
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    pub fn create(node_id: String) -> UsageMeter {
+        UsageMeter {
+            node_id,
+            window: Mutex::new(UsageWindow::new()),
+        }
+    }
+
+    pub fn instance() -> Arc<UsageMeter> {
+        GlobalInstance::get()
+    }
+
+    /// Add the usage of a finished query to the current window.
+    pub fn record(&self, ctx: &QueryContext) -> Result<()> {
+        let key = UsageKey {
+            tenant_id: ctx.get_tenant().tenant_name().to_string(),
+            cluster_id: GlobalConfig::instance().query.cluster_id.clone(),
+            sql_user: ctx.get_current_user()?.name,
+        };
+
+        let cpu_time_ns = ctx
+            .get_query_profiles()
+            .iter()
+            .map(|profile| profile.statistics[ProfileStatisticsName::CpuTime as usize] as u64)
+            .sum::<u64>();
+        let spilled_bytes = ctx.get_join_spill_progress_value().bytes
+            + ctx.get_aggregate_spill_progress_value().bytes
+            + ctx.get_group_by_spill_progress_value().bytes;
+
+        let mut window = self.window.lock();
+        let counters = window.usages.entry(key).or_default();
+        counters.query_count += 1;
+        counters.scan_bytes += ctx.get_scan_progress_value().bytes as u64;
+        counters.written_bytes += ctx.get_write_progress_value().bytes as u64;
+        counters.cpu_time_ns += cpu_time_ns;
+        counters.spilled_bytes += spilled_bytes as u64;
+        Ok(())
+    }
+
+    /// Write the usage of the current window into `system.usage` and start a new window.
+    /// The reads of the shared tables are written into `system.share_usage` of this tenant,
+    /// and added to the usages seen by the tenants providing the shares.
+    #[async_backtrace::framed]
+    pub async fn flush(&self) -> Result<()> {
+        let window = mem::replace(&mut *self.window.lock(), UsageWindow::new());
+        let start_time = to_timestamp(window.start_time);
+        let end_time = to_timestamp(SystemTime::now());
+
+        let tenant_id = GlobalConfig::instance()
+            .query
+            .tenant_id
+            .tenant_name()
+            .to_string();
+        let share_usages = ShareUsageMeter::instance().take();
+        let share_queue = ShareUsageQueue::instance()?;
+        for (key, usage) in share_usages.iter() {
+            share_queue.append_data(ShareUsageLogElement {
+                start_time,
+                end_time,
+                tenant_id: tenant_id.clone(),
+                node_id: self.node_id.clone(),
+                share_tenant: key.share_tenant.clone(),
+                share_name: key.share_name.clone(),
+                table_id: key.table_id,
+                read_requests: usage.read_requests,
+                read_bytes: usage.read_bytes,
+            })?;
+        }
+
+        let queue = UsageQueue::instance()?;
+        for (key, counters) in window.usages {
+            queue.append_data(UsageLogElement {
+                start_time,
+                end_time,
+                tenant_id: key.tenant_id,
+                cluster_id: key.cluster_id,
+                node_id: self.node_id.clone(),
+                sql_user: key.sql_user,
+                query_count: counters.query_count,
+                scan_bytes: counters.scan_bytes,
+                written_bytes: counters.written_bytes,
+                cpu_seconds: counters.cpu_time_ns as f64 / 1_000_000_000.0,
+                spilled_bytes: counters.spilled_bytes,
+            })?;
+        }
+
+        if !share_usages.is_empty() {
+            ShareUsageMeter::report_to_providers(&tenant_id, &share_usages).await?;
+        }
+        Ok(())
+    }
+}
+
+fn to_timestamp(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_micros() as i64
+}
@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_COLUMN_BACKFILLS;
+use log::info;
+use log::warn;
+
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+#[derive(Clone)]
+pub struct BackfillDesc {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+/// Backfills the rest of the table in the background, batch by batch. Each batch runs in a new
+/// query context of a background session, since the context of the statement adding the column
+/// ends with the statement. The backfill interrupted by a failure or a restart is resumed by
+/// `OPTIMIZE TABLE`.
+pub fn spawn_column_backfill(ctx: Arc<QueryContext>, desc: BackfillDesc) {
+    let tenant = ctx.get_tenant();
+    let user = ctx.get_current_user();
+    GlobalIORuntime::instance().spawn(async move {
+        let res = match user {
+            Ok(user) => run_column_backfill(tenant, user, &desc).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = res {
+            warn!(
+                "column backfill of table {}.{} failed, it's resumed by OPTIMIZE TABLE: {}",
+                desc.database, desc.table, e
+            );
+        }
+    });
+}
+
+async fn run_column_backfill(tenant: Tenant, user: UserInfo, desc: &BackfillDesc) -> Result<()> {
+    let session_manager = SessionManager::instance();
+    let mut session = session_manager.create_session(SessionType::Dummy).await?;
+    session.set_current_tenant(tenant);
+    let session = session_manager.register_session(session)?;
+    session.set_authed_user(user, None).await?;
+    finish_column_backfills(&session, desc).await
+}
+
+/// Backfills the rest of the table in the session until it's done, each batch in a new query
+/// context of the session.
+///
+/// The statements rewriting the blocks of the table, e.g. UPDATE and compaction, finish the
+/// backfills first, since they would write the blocks with the insert defaults of the columns
+/// instead.
+pub async fn finish_column_backfills(session: &Arc<Session>, desc: &BackfillDesc) -> Result<()> {
+    loop {
+        let ctx = session.create_query_context().await?;
+        match backfill_columns(ctx, desc).await {
+            Ok(0) => return Ok(()),
+            Ok(_) => {}
+            // The table is backfilled concurrently, e.g. by the background backfill and a
+            // mutation, the next batch is read from the newer snapshot.
+            Err(e)
+                if e.code() == ErrorCode::UNRESOLVABLE_CONFLICT
+                    || e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Rewrites a batch of the blocks without the backfilled columns, the backfills are removed
+/// from the table once all the blocks are rewritten.
+///
+/// Returns the number of the blocks left to backfill.
+pub async fn backfill_columns(ctx: Arc<QueryContext>, desc: &BackfillDesc) -> Result<usize> {
+    let table = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?;
+    let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+        return Ok(0);
+    };
+    if fuse_table.column_backfills()?.is_empty() {
+        return Ok(0);
+    }
+
+    ctx.evict_table_from_cache(&desc.catalog, &desc.database, &desc.table)?;
+    let table = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let backfills = fuse_table.column_backfills()?;
+    if backfills.is_empty() {
+        return Ok(0);
+    }
+
+    let batch_blocks = ctx.get_settings().get_column_backfill_batch_blocks()?;
+    let (rewritten, remaining) = fuse_table
+        .backfill_columns(ctx.clone(), batch_blocks)
+        .await?;
+    if remaining > 0 {
+        return Ok(remaining);
+    }
+
+    let table = if rewritten > 0 {
+        ctx.evict_table_from_cache(&desc.catalog, &desc.database, &desc.table)?;
+        ctx.get_table(&desc.catalog, &desc.database, &desc.table)
+            .await?
+    } else {
+        table
+    };
+    // The columns added meanwhile are kept to backfill in the next round.
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    if fuse_table.column_backfills()? != backfills {
+        return Ok(0);
+    }
+    let req = UpsertTableOptionReq {
+        table_id: table.get_id(),
+        seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+        options: HashMap::from([(OPT_KEY_COLUMN_BACKFILLS.to_string(), None)]),
+    };
+    let catalog = ctx.get_catalog(&desc.catalog).await?;
+    catalog
+        .upsert_table_option(&ctx.get_tenant(), &desc.database, req)
+        .await?;
+    ctx.evict_table_from_cache(&desc.catalog, &desc.database, &desc.table)?;
+    info!(
+        "column backfill of table {}.{} is done",
+        desc.database, desc.table
+    );
+    Ok(0)
+}
@@ -25,6 +25,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_common_sql::plans::OptimizeTableAction;
 use databend_common_sql::plans::OptimizeTablePlan;
+use databend_common_storages_fuse::FuseTable;
 use log::info;
 
 use crate::interpreters::common::metrics_inc_compact_hook_compact_time_ms;
@@ -80,37 +81,59 @@ async fn do_hook_compact(
                 let compaction_num_block_hint = ctx.get_compaction_num_block_hint();
                 info!("hint number of blocks need to be compacted {}", compaction_num_block_hint);
                 if compaction_num_block_hint == 0 {
-                    return Ok(());
-                }
-                CompactionLimits {
-                    segment_limit: None,
-                    block_limit: Some(compaction_num_block_hint as usize),
+                    None
+                } else {
+                    Some(CompactionLimits {
+                        segment_limit: None,
+                        block_limit: Some(compaction_num_block_hint as usize),
+                    })
                 }
             }
             _ =>
             // for mutations other than Insertions, we use an empirical value of 3 segments as the
             // limit for compaction. to be refined later.
                 {
-                    CompactionLimits {
+                    Some(CompactionLimits {
                         segment_limit: Some(3),
                         block_limit: None,
-                    }
+                    })
                 }
         };
 
+        // for insertions, the segments are compacted only if the commit found them fragmented,
+        // which saves reloading the table after each insertion.
+        let need_compact_segments = match compact_target.mutation_kind {
+            MutationKind::Insert => {
+                let compaction_num_segment_hint = ctx.get_compaction_num_segment_hint();
+                info!("hint number of segments need to be compacted {}", compaction_num_segment_hint);
+                compaction_num_segment_hint > 0
+            }
+            _ => true,
+        };
+
         let op_name = &trace_ctx.operation_name;
         metrics_inc_compact_hook_main_operation_time_ms(op_name, trace_ctx.start.elapsed().as_millis() as u64);
 
         let compact_start_at = Instant::now();
         if info.res.is_ok() {
-            info!("execute {op_name} finished successfully. running table optimization job.");
-            match GlobalIORuntime::instance().block_on({
-                compact_table(ctx, compact_target, compaction_limits, lock_opt)
-            }) {
-                Ok(_) => {
-                    info!("execute {op_name} finished successfully. table optimization job finished.");
+            if let Some(compaction_limits) = compaction_limits {
+                info!("execute {op_name} finished successfully. running table optimization job.");
+                match GlobalIORuntime::instance().block_on({
+                    compact_table(ctx.clone(), &compact_target, compaction_limits, lock_opt.clone())
+                }) {
+                    Ok(_) => {
+                        info!("execute {op_name} finished successfully. table optimization job finished.");
+                    }
+                    Err(e) => { info!("execute {op_name} finished successfully. table optimization job failed. {:?}", e) }
+                }
+            }
+
+            if need_compact_segments {
+                if let Err(e) = GlobalIORuntime::instance().block_on({
+                    compact_segments(ctx, &compact_target, lock_opt)
+                }) {
+                    info!("execute {op_name} finished successfully. segment compaction job failed. {:?}", e)
                 }
-                Err(e) => { info!("execute {op_name} finished successfully. table optimization job failed. {:?}", e) }
             }
         }
         metrics_inc_compact_hook_compact_time_ms(&trace_ctx.operation_name, compact_start_at.elapsed().as_millis() as u64);
@@ -126,7 +149,7 @@ async fn do_hook_compact(
 ///  - re-cluster if the cluster keys are defined
 async fn compact_table(
     ctx: Arc<QueryContext>,
-    compact_target: CompactTargetTableDescription,
+    compact_target: &CompactTargetTableDescription,
     compaction_limits: CompactionLimits,
     lock_opt: LockTableOption,
 ) -> Result<()> {
@@ -140,15 +163,17 @@ async fn compact_table(
     // build the optimize table pipeline with compact action.
     let optimize_interpreter =
         OptimizeTableInterpreter::try_create(ctx.clone(), OptimizeTablePlan {
-            catalog: compact_target.catalog,
-            database: compact_target.database,
-            table: compact_target.table,
+            catalog: compact_target.catalog.clone(),
+            database: compact_target.database.clone(),
+            table: compact_target.table.clone(),
             action: OptimizeTableAction::CompactBlocks(compaction_limits.block_limit),
             limit: compaction_limits.segment_limit,
             lock_opt,
         })?;
 
-    let mut build_res = optimize_interpreter.execute2().await?;
+    let mut build_res = optimize_interpreter
+        .build_compact_pipeline(compaction_limits.block_limit)
+        .await?;
 
     if build_res.main_pipeline.is_empty() {
         return Ok(());
@@ -178,3 +203,64 @@ async fn compact_table(
     }
     Ok(())
 }
+
+/// compact the segments of the target table if they are fragmented, i.e. the snapshot has more
+/// segments than its blocks need by `auto_compaction_segments_threshold`, which is usually
+/// caused by the trickle loads. only the metadata of the segments is rewritten, the blocks
+/// are referenced by the new segments as they are.
+async fn compact_segments(
+    ctx: Arc<QueryContext>,
+    compact_target: &CompactTargetTableDescription,
+    lock_opt: LockTableOption,
+) -> Result<()> {
+    let threshold = ctx
+        .get_settings()
+        .get_auto_compaction_segments_threshold()?;
+    if threshold == 0 {
+        return Ok(());
+    }
+
+    ctx.evict_table_from_cache(
+        &compact_target.catalog,
+        &compact_target.database,
+        &compact_target.table,
+    )?;
+    let table = ctx
+        .get_table(
+            &compact_target.catalog,
+            &compact_target.database,
+            &compact_target.table,
+        )
+        .await?;
+    let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+        return Ok(());
+    };
+    let Some(snapshot) = fuse_table.read_table_snapshot().await? else {
+        return Ok(());
+    };
+
+    let num_segments = snapshot.segments.len() as u64;
+    let block_per_seg = fuse_table.get_write_settings().block_per_seg as u64;
+    let needed_segments = snapshot.summary.block_count.div_ceil(block_per_seg.max(1));
+    if num_segments <= needed_segments + threshold {
+        return Ok(());
+    }
+
+    info!(
+        "table {}.{} has {} segments, {} are needed by its blocks, running segment compaction job.",
+        compact_target.database, compact_target.table, num_segments, needed_segments
+    );
+    let lock_guard = ctx
+        .clone()
+        .acquire_table_lock(
+            &compact_target.catalog,
+            &compact_target.database,
+            &compact_target.table,
+            &lock_opt,
+        )
+        .await?;
+    table.check_mutable()?;
+    table.compact_segments(ctx.clone(), None).await?;
+    drop(lock_guard);
+    Ok(())
+}
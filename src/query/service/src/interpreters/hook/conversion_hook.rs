@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_sql::plans::ModifyTableColumnPlan;
+use log::info;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterMaintenanceHistory;
+use crate::interpreters::ModifyTableColumnInterpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// Converts the data of the modified columns in the background. The job runs as a query of a
+/// background session, its progress is shown in `system.processes` and it's aborted by
+/// `KILL QUERY <session id>`. The table lock taken by the statement is held until the job is
+/// done, and the new schema is committed together with the converted data.
+///
+/// The outcome of the job is recorded in `system.maintenance_history` as the action
+/// `modify_column`, with the error message if the conversion failed.
+pub fn spawn_column_conversion(ctx: Arc<QueryContext>, plan: ModifyTableColumnPlan) {
+    let tenant = ctx.get_tenant();
+    let user = ctx.get_current_user();
+    GlobalIORuntime::instance().spawn(async move {
+        let res = match user {
+            Ok(user) => run_column_conversion(tenant, user, &plan).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = res {
+            warn!(
+                "column conversion of table {}.{} failed, the table is kept unchanged: {}",
+                plan.database, plan.table, e
+            );
+        }
+    });
+}
+
+async fn run_column_conversion(
+    tenant: Tenant,
+    user: UserInfo,
+    plan: &ModifyTableColumnPlan,
+) -> Result<()> {
+    let session_manager = SessionManager::instance();
+    let mut session = session_manager.create_session(SessionType::Dummy).await?;
+    session.set_current_tenant(tenant);
+    let session = session_manager.register_session(session)?;
+    session.set_authed_user(user, None).await?;
+
+    let ctx = session.create_query_context().await?;
+    let start = SystemTime::now();
+    let res = convert_columns(ctx.clone(), plan, session.get_id()).await;
+    InterpreterMaintenanceHistory::write_log(
+        &ctx,
+        start,
+        &plan.database,
+        &plan.table,
+        "modify_column",
+        res.as_ref().err(),
+    )?;
+    res
+}
+
+async fn convert_columns(
+    ctx: Arc<QueryContext>,
+    plan: &ModifyTableColumnPlan,
+    session_id: String,
+) -> Result<()> {
+    ctx.evict_table_from_cache(&plan.catalog, &plan.database, &plan.table)?;
+    ctx.set_status_info(&format!(
+        "modify column: converting the data of table {}.{}",
+        plan.database, plan.table
+    ));
+    info!(
+        "column conversion of table {}.{} is started in session {}",
+        plan.database, plan.table, session_id
+    );
+
+    let interpreter =
+        ModifyTableColumnInterpreter::try_create_background(ctx.clone(), plan.clone())?;
+    let mut build_res = interpreter.execute2().await?;
+    if build_res.main_pipeline.is_empty() {
+        return Ok(());
+    }
+
+    let settings = ctx.get_settings();
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let executor_settings = ExecutorSettings::try_create(ctx.clone())?;
+    let mut pipelines = build_res.sources_pipelines;
+    pipelines.push(build_res.main_pipeline);
+    let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+    ctx.set_executor(complete_executor.get_inner())?;
+    complete_executor.execute()?;
+    drop(complete_executor);
+
+    info!(
+        "column conversion of table {}.{} is done",
+        plan.database, plan.table
+    );
+    Ok(())
+}
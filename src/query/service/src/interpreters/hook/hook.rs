@@ -27,6 +27,8 @@ use crate::interpreters::hook::compact_hook::CompactHookTraceCtx;
 use crate::interpreters::hook::compact_hook::CompactTargetTableDescription;
 use crate::interpreters::hook::refresh_hook::hook_refresh;
 use crate::interpreters::hook::refresh_hook::RefreshDesc;
+use crate::interpreters::hook::tiering_hook::hook_tiering;
+use crate::interpreters::hook::tiering_hook::TieringDesc;
 use crate::sessions::QueryContext;
 
 /// Hook operator.
@@ -63,11 +65,14 @@ impl HookOperator {
     /// 1. Compact if needed.
     /// 2. Refresh aggregating index if needed.
     /// 3. Refresh virtual columns if needed.
+    /// 4. Migrate the old segments to the cold storage in the background if the table has a
+    ///    storage policy.
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn execute(&self, pipeline: &mut Pipeline) {
         self.execute_compact(pipeline).await;
         self.execute_refresh(pipeline).await;
+        self.execute_tiering(pipeline).await;
     }
 
     /// Execute the compact hook operator.
@@ -125,4 +130,17 @@ impl HookOperator {
 
         hook_refresh(self.ctx.clone(), pipeline, refresh_desc).await;
     }
+
+    /// Execute the tiering hook operator.
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    pub async fn execute_tiering(&self, pipeline: &mut Pipeline) {
+        let tiering_desc = TieringDesc {
+            catalog: self.catalog.to_owned(),
+            database: self.database.to_owned(),
+            table: self.table.to_owned(),
+        };
+
+        hook_tiering(self.ctx.clone(), pipeline, tiering_desc).await;
+    }
 }
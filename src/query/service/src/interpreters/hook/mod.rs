@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub(crate) mod backfill_hook;
 pub(crate) mod compact_hook;
+pub(crate) mod conversion_hook;
 pub(crate) mod refresh_hook;
+pub(crate) mod tiering_hook;
 pub(crate) mod vacuum_hook;
 
 #[allow(clippy::module_inception)]
@@ -0,0 +1,195 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
+use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_pipeline_core::ExecutionInfo;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_POLICY;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+// The tables being migrated on this node, a table is migrated by one job at a time.
+static TIERING_TABLES: LazyLock<Mutex<HashSet<String>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+pub struct TieringDesc {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+/// Hook the migration of the old segments to the cold storage with a on-finished callback,
+/// for the tables with a storage policy. The migration is spawned as a background job, so the
+/// write doesn't wait for it.
+/// errors (if any) are ignored.
+pub async fn hook_tiering(ctx: Arc<QueryContext>, pipeline: &mut Pipeline, desc: TieringDesc) {
+    if pipeline.is_empty() {
+        return;
+    }
+
+    pipeline.set_on_finished(move |info: &ExecutionInfo| {
+        if info.res.is_ok() {
+            spawn_tiering(ctx.get_current_session(), desc);
+        }
+        Ok(())
+    });
+}
+
+/// Migrates the old segments of the table in the background, in a new query context of the
+/// session and with the table lock, the job is skipped if the table is being migrated.
+pub fn spawn_tiering(session: Arc<Session>, desc: TieringDesc) {
+    GlobalIORuntime::instance().spawn(async move {
+        run_tiering(session, &desc).await;
+    });
+}
+
+async fn run_tiering(session: Arc<Session>, desc: &TieringDesc) {
+    let key = format!("{}.{}.{}", desc.catalog, desc.database, desc.table);
+    if !TIERING_TABLES.lock().insert(key.clone()) {
+        return;
+    }
+
+    let res = match session.create_query_context().await {
+        Ok(ctx) => migrate_table_cold_segments(ctx, desc, LockTableOption::LockNoRetry).await,
+        Err(e) => Err(e),
+    };
+    match res {
+        Ok(0) => {}
+        Ok(migrated) => {
+            info!(
+                "migrated {} segments of table {}.{} to the cold storage.",
+                migrated, desc.database, desc.table
+            );
+        }
+        Err(e) => {
+            info!("execute tiering job failed. {:?}", e);
+        }
+    }
+    TIERING_TABLES.lock().remove(&key);
+}
+
+/// Migrates the old segments of the table to the cold storage with the table lock, the
+/// segments are rewritten against the latest snapshot once the lock is held.
+///
+/// Returns the number of the migrated segments.
+pub async fn migrate_table_cold_segments(
+    ctx: Arc<QueryContext>,
+    desc: &TieringDesc,
+    lock_opt: LockTableOption,
+) -> Result<usize> {
+    let table = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?;
+    let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+        return Ok(0);
+    };
+    if fuse_table.storage_policy()?.is_none() {
+        return Ok(0);
+    }
+
+    let lock_guard = ctx
+        .clone()
+        .acquire_table_lock(&desc.catalog, &desc.database, &desc.table, &lock_opt)
+        .await?;
+    ctx.evict_table_from_cache(&desc.catalog, &desc.database, &desc.table)?;
+    let table = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?;
+    table.check_mutable()?;
+    let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+        return Ok(0);
+    };
+    let migrated = fuse_table.migrate_cold_segments(ctx.clone()).await?;
+    drop(lock_guard);
+    Ok(migrated)
+}
+
+/// Migrates the old segments of the tables with a storage policy every `tiering_interval_secs`
+/// of the node config, so that the segments of the tables not written anymore get cold too.
+pub struct TieringMover;
+
+impl TieringMover {
+    pub fn init(cfg: &InnerConfig) -> Result<()> {
+        let interval = cfg.query.tiering_interval_secs;
+        if interval == 0 {
+            return Ok(());
+        }
+
+        let tenant = cfg.query.tenant_id.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_secs(interval)).await;
+                if let Err(e) = TieringMover::migrate_tables(tenant.clone()).await {
+                    warn!("periodic tiering failed: {:?}", e);
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Migrates the tables of the default catalog one by one, in a background session with
+    /// the global privileges.
+    async fn migrate_tables(tenant: Tenant) -> Result<()> {
+        let session_manager = SessionManager::instance();
+        let mut session = session_manager.create_session(SessionType::Dummy).await?;
+        session.set_current_tenant(tenant.clone());
+        let session = session_manager.register_session(session)?;
+        let mut user = UserInfo::new_no_auth("root", "%");
+        user.grants.grant_privileges(
+            &GrantObject::Global,
+            UserPrivilegeSet::available_privileges_on_global(),
+        );
+        session.set_authed_user(user, None).await?;
+
+        let ctx = session.create_query_context().await?;
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        for database in catalog.list_databases(&tenant).await? {
+            for table in database.list_tables().await? {
+                if !table.options().contains_key(OPT_KEY_STORAGE_POLICY) {
+                    continue;
+                }
+                let desc = TieringDesc {
+                    catalog: CATALOG_DEFAULT.to_string(),
+                    database: database.name().to_string(),
+                    table: table.name().to_string(),
+                };
+                run_tiering(session.clone(), &desc).await;
+            }
+        }
+        Ok(())
+    }
+}
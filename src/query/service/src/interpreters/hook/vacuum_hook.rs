@@ -16,6 +16,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_license::license::Feature::Vacuum;
@@ -23,8 +24,10 @@ use databend_common_license::license_manager::get_license_manager;
 use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_storage::DataOperator;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
+use log::warn;
 use opendal::Buffer;
 
+use crate::servers::http::v1::result_spill_prefix;
 use crate::sessions::QueryContext;
 
 pub fn hook_vacuum_temp_files(query_ctx: &Arc<QueryContext>) -> Result<()> {
@@ -41,28 +44,44 @@ pub fn hook_vacuum_temp_files(query_ctx: &Arc<QueryContext>) -> Result<()> {
             .check_enterprise_enabled(query_ctx.get_license_key(), Vacuum)
             .is_ok()
     {
-        let handler = get_vacuum_handler();
+        let _ = GlobalIORuntime::instance()
+            .block_on(vacuum_temp_files(spill_prefix, vacuum_limit as usize));
+    }
+
+    Ok(())
+}
+
+/// Removes the result blocks spilled by the queries of the session for the slow clients, the
+/// ones read by the clients are removed already. Unlike the spilled data of the queries, they
+/// are removed without the enterprise vacuum, since nothing else cleans them up.
+pub fn hook_vacuum_session_result_files(tenant: &str, session_id: &str) {
+    let spill_prefix = format!("{}/", result_spill_prefix(tenant, session_id));
+    let operator = DataOperator::instance().operator();
 
-        let _ = GlobalIORuntime::instance().block_on(async move {
-            let removed_files = handler
-                .do_vacuum_temporary_files(
-                    spill_prefix.clone(),
-                    Some(Duration::from_secs(0)),
-                    vacuum_limit as usize,
-                )
-                .await;
+    // The session is dropped, don't wait for the removal.
+    GlobalIORuntime::instance().spawn(async move {
+        if let Err(e) = operator.remove_all(&spill_prefix).await {
+            warn!("failed to remove spilled results {}: {}", spill_prefix, e);
+        }
+    });
+}
 
-            if vacuum_limit != 0 && matches!(removed_files, Ok(res) if res == vacuum_limit as usize)
-            {
-                // Have not been removed files
-                let op = DataOperator::instance().operator();
-                op.create_dir(&format!("{}/", spill_prefix)).await?;
-                op.write(&format!("{}/finished", spill_prefix), Buffer::new())
-                    .await?;
-            }
+async fn vacuum_temp_files(spill_prefix: String, vacuum_limit: usize) -> Result<()> {
+    let handler = get_vacuum_handler();
+    let removed_files = handler
+        .do_vacuum_temporary_files(
+            spill_prefix.clone(),
+            Some(Duration::from_secs(0)),
+            vacuum_limit,
+        )
+        .await;
 
-            Ok(())
-        });
+    if matches!(removed_files, Ok(res) if res == vacuum_limit) {
+        // Have not been removed files
+        let op = DataOperator::instance().operator();
+        op.create_dir(&format!("{}/", spill_prefix)).await?;
+        op.write(&format!("{}/finished", spill_prefix), Buffer::new())
+            .await?;
     }
 
     Ok(())
@@ -43,10 +43,12 @@ use log::info;
 use md5::Digest;
 use md5::Md5;
 
+use crate::interpreters::common::capture_query_replay;
 use crate::interpreters::hook::vacuum_hook::hook_vacuum_temp_files;
 use crate::interpreters::interpreter_txn_commit::CommitInterpreter;
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::UsageMeter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
@@ -125,15 +127,21 @@ pub trait Interpreter: Sync + Send {
                     #[derive(serde::Serialize)]
                     struct QueryProfiles {
                         query_id: String,
+                        query_tag: String,
+                        application_name: String,
                         profiles: Vec<PlanProfile>,
                         statistics_desc: Arc<BTreeMap<ProfileStatisticsName, ProfileDesc>>,
                     }
 
+                    let settings = query_ctx.get_settings();
+
                     info!(
                         target: "databend::log::profile",
                         "{}",
                         serde_json::to_string(&QueryProfiles {
                             query_id: query_ctx.get_id(),
+                            query_tag: settings.get_query_tag()?,
+                            application_name: settings.get_application_name()?,
                             profiles: query_profiles.clone(),
                             statistics_desc: get_statistics_desc(),
                         })?
@@ -224,6 +232,7 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
     let session = ctx.get_current_session();
 
     session.get_status().write().query_finish();
+    ctx.update_last_query_warnings();
     if session.get_type().is_user_session() {
         SessionManager::instance().status.write().query_finish(now)
     }
@@ -231,6 +240,12 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error, has_profiles) {
         error!("interpreter.finish.error: {:?}", error)
     }
+
+    if session.get_type().is_user_session() {
+        if let Err(error) = UsageMeter::instance().record(ctx) {
+            error!("interpreter.usage.error: {:?}", error)
+        }
+    }
 }
 
 /// There are two steps to execute a query:
@@ -254,6 +269,12 @@ pub async fn interpreter_plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<(
 
     attach_query_hash(&ctx, &mut stmt, &short_sql);
 
+    if let Ok((plan, extras)) = &result {
+        if let Err(error) = capture_query_replay(&ctx, sql, plan, extras).await {
+            error!("interpreter.replay_capture.error: {:?}", error)
+        }
+    }
+
     result
 }
 
@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use databend_common_exception::Result;
+use databend_common_sql::ColumnLineage;
+use databend_common_storages_system::ColumnLineageLogElement;
+use databend_common_storages_system::ColumnLineageQueue;
+use log::info;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct InterpreterColumnLineage;
+
+impl InterpreterColumnLineage {
+    /// Records the lineage of the output columns of a query, which are written into
+    /// `output_table`, given as (catalog, database, table), if any.
+    ///
+    /// The lineage is kept in `system.column_lineage`, and also written into the query log
+    /// in JSON format like the query log entries, which is persisted by the query log file or
+    /// OpenTelemetry endpoint if they are configured.
+    pub fn write_log(
+        ctx: &QueryContext,
+        output_table: Option<(&str, &str, &str)>,
+        lineage: &[ColumnLineage],
+    ) -> Result<()> {
+        let event_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64;
+        let query_id = ctx.get_id();
+        let (output_catalog, output_database, output_table) = output_table.unwrap_or_default();
+
+        let queue = ColumnLineageQueue::instance()?;
+        for column in lineage {
+            for source in column.sources.iter() {
+                let element = ColumnLineageLogElement {
+                    event_time,
+                    query_id: query_id.clone(),
+                    output_catalog: output_catalog.to_string(),
+                    output_database: output_database.to_string(),
+                    output_table: output_table.to_string(),
+                    output_position: column.output_position as u64,
+                    output_column: column.output_column.clone(),
+                    source_catalog: source.catalog.clone(),
+                    source_database: source.database.clone(),
+                    source_table: source.table.clone(),
+                    source_column: source.column.clone(),
+                };
+                info!(target: "databend::log::query", "{}", serde_json::to_string(&element)?);
+                queue.append_data(element)?;
+            }
+        }
+        Ok(())
+    }
+}
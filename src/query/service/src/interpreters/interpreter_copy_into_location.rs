@@ -28,6 +28,7 @@ use log::debug;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_query_estimate;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
@@ -112,6 +113,8 @@ impl CopyIntoLocationInterpreter {
             },
         }));
 
+        check_query_estimate(&self.ctx, &physical_plan).await?;
+
         let mut next_plan_id = 0;
         physical_plan.adjust_plan_id(&mut next_plan_id);
         Ok((
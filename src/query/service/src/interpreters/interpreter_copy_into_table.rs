@@ -38,6 +38,7 @@ use log::debug;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_query_estimate;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
@@ -351,6 +352,7 @@ impl Interpreter for CopyIntoTableInterpreter {
         }
 
         let (physical_plan, update_stream_meta) = self.build_physical_plan(&self.plan).await?;
+        check_query_estimate(&self.ctx, &physical_plan).await?;
         let mut build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
 
@@ -0,0 +1,207 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_ast::ast::quote::display_ident;
+use databend_common_ast::ast::quote::QuotedString;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::ExportDatabasePlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_stage::StageTable;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use log::info;
+use uuid::Uuid;
+
+use crate::interpreters::common::database_bundle_path;
+use crate::interpreters::common::execute_sql_in_new_context;
+use crate::interpreters::common::sort_database_bundle_views;
+use crate::interpreters::common::DatabaseBundleManifest;
+use crate::interpreters::common::DatabaseBundleTable;
+use crate::interpreters::common::DatabaseBundleView;
+use crate::interpreters::common::DATABASE_BUNDLE_DIALECT;
+use crate::interpreters::common::DATABASE_BUNDLE_MANIFEST;
+use crate::interpreters::Interpreter;
+use crate::interpreters::ShowCreateQuerySettings;
+use crate::interpreters::ShowCreateTableInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Writes the DDL of the tables and views of a database, and the data of the fuse tables
+/// read at their current snapshots, to a stage location, see [`DatabaseBundleManifest`].
+pub struct ExportDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExportDatabasePlan,
+}
+
+impl ExportDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ExportDatabasePlan) -> Result<Self> {
+        Ok(ExportDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExportDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "ExportDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let database = catalog
+            .get_database(&tenant, &self.plan.database)
+            .await?
+            .name()
+            .to_string();
+
+        let op = StageTable::get_op(&self.plan.stage)?;
+        let manifest_path = database_bundle_path(&self.plan.path, DATABASE_BUNDLE_MANIFEST);
+        if op.is_exist(&manifest_path).await? {
+            return Err(ErrorCode::BadArguments(format!(
+                "a database bundle already exists at @{}",
+                self.plan.location
+            )));
+        }
+
+        let settings = ShowCreateQuerySettings {
+            sql_dialect: DATABASE_BUNDLE_DIALECT,
+            quoted_ident_case_sensitive: true,
+            hide_options_in_show_create_table: false,
+            hide_snapshot_location: true,
+        };
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let quoted_catalog = display_ident(&self.plan.catalog, true, sql_dialect);
+        let quoted_database = display_ident(&database, true, sql_dialect);
+        let location = self.plan.location.trim_end_matches('/');
+        // The data of each export goes to its own dir, so the files left by a failed export
+        // at the same location are never mixed into the bundle.
+        let export_id = Uuid::new_v4().simple().to_string();
+
+        let mut tables = catalog.list_tables(&tenant, &database).await?;
+        tables.sort_by(|a, b| a.name().cmp(b.name()));
+
+        let mut manifest = DatabaseBundleManifest {
+            version: DatabaseBundleManifest::VERSION,
+            database: database.clone(),
+            exported_at: Some(Utc::now()),
+            tables: vec![],
+            views: vec![],
+        };
+        for table in tables {
+            let name = table.name().to_string();
+            if table.engine() == VIEW_ENGINE {
+                let ddl = ShowCreateTableInterpreter::show_create_query(
+                    catalog.as_ref(),
+                    &database,
+                    table.as_ref(),
+                    &settings,
+                )
+                .await?;
+                manifest.views.push(DatabaseBundleView { name, ddl });
+                continue;
+            }
+
+            let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                self.ctx.push_warning(format!(
+                    "table {}.{} of engine {} is not exported",
+                    database,
+                    name,
+                    table.engine()
+                ));
+                continue;
+            };
+
+            let ddl =
+                ShowCreateTableInterpreter::show_create_table_query(table.as_ref(), &settings)?;
+            // The table id instead of the name, which may not be a valid path.
+            let data_dir = format!("data/{}/{}", export_id, table.get_id());
+            let snapshot = fuse_table.read_table_snapshot().await?;
+            let (snapshot_id, snapshot_timestamp, rows) = match &snapshot {
+                Some(snapshot) => {
+                    // Pin the snapshot, so that the data is consistent with the manifest
+                    // even if the table is written during the export.
+                    let snapshot_id = snapshot.snapshot_id.simple().to_string();
+                    let sql = format!(
+                        "COPY INTO {} FROM (SELECT * FROM {}.{}.{} AT (SNAPSHOT => '{}')) FILE_FORMAT = (TYPE = PARQUET)",
+                        QuotedString(format!("@{}/{}/", location, data_dir), '\''),
+                        quoted_catalog,
+                        quoted_database,
+                        display_ident(&name, true, sql_dialect),
+                        snapshot_id
+                    );
+                    info!("Export table {}.{} via sql {:?}", database, name, sql);
+                    execute_sql_in_new_context(&self.ctx, &sql).await?;
+                    (
+                        Some(snapshot_id),
+                        snapshot.timestamp,
+                        snapshot.summary.row_count,
+                    )
+                }
+                None => (None, None, 0),
+            };
+
+            manifest.tables.push(DatabaseBundleTable {
+                name,
+                ddl,
+                snapshot_id,
+                snapshot_timestamp,
+                rows,
+                data_dir,
+            });
+        }
+
+        manifest.views = sort_database_bundle_views(&database, manifest.views)?;
+
+        // The manifest is written last, a bundle without it is incomplete.
+        op.write(&manifest_path, serde_json::to_vec_pretty(&manifest)?)
+            .await?;
+
+        let mut names = Vec::with_capacity(manifest.tables.len() + manifest.views.len());
+        let mut kinds = Vec::with_capacity(names.capacity());
+        let mut snapshot_ids = Vec::with_capacity(names.capacity());
+        let mut rows = Vec::with_capacity(names.capacity());
+        for table in manifest.tables {
+            names.push(table.name);
+            kinds.push("TABLE".to_string());
+            snapshot_ids.push(table.snapshot_id.unwrap_or_default());
+            rows.push(table.rows);
+        }
+        for view in manifest.views {
+            names.push(view.name);
+            kinds.push("VIEW".to_string());
+            snapshot_ids.push(String::new());
+            rows.push(0);
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(kinds),
+            StringType::from_data(snapshot_ids),
+            UInt64Type::from_data(rows),
+        ])])
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::quote::display_ident;
+use databend_common_ast::ast::quote::QuotedString;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::ScalarRef;
+use databend_common_sql::plans::ImportDatabasePlan;
+use databend_common_storages_stage::StageTable;
+use log::info;
+use log::warn;
+
+use crate::interpreters::common::database_bundle_path;
+use crate::interpreters::common::execute_sql_in_new_context;
+use crate::interpreters::common::read_database_bundle_manifest;
+use crate::interpreters::common::rewrite_database_bundle_ddl;
+use crate::interpreters::common::sort_database_bundle_views;
+use crate::interpreters::common::DatabaseBundleManifest;
+use crate::interpreters::common::DATABASE_BUNDLE_MANIFEST;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Creates a database from a bundle written by `EXPORT DATABASE`: the tables are created
+/// and loaded first, then the views.
+///
+/// The database must not exist, and is dropped again if the import fails.
+pub struct ImportDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ImportDatabasePlan,
+}
+
+impl ImportDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ImportDatabasePlan) -> Result<Self> {
+        Ok(ImportDatabaseInterpreter { ctx, plan })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_manifest(&self) -> Result<DatabaseBundleManifest> {
+        let op = StageTable::get_op(&self.plan.stage)?;
+        let manifest_path = database_bundle_path(&self.plan.path, DATABASE_BUNDLE_MANIFEST);
+        if !op.is_exist(&manifest_path).await? {
+            return Err(ErrorCode::BadArguments(format!(
+                "no database bundle found at @{}",
+                self.plan.location
+            )));
+        }
+
+        read_database_bundle_manifest(&op, &manifest_path).await
+    }
+
+    #[async_backtrace::framed]
+    async fn import(
+        &self,
+        manifest: &DatabaseBundleManifest,
+        sql_dialect: Dialect,
+    ) -> Result<Vec<u64>> {
+        let quoted_catalog = display_ident(&self.plan.catalog, true, sql_dialect);
+        let quoted_database = display_ident(&self.plan.database, true, sql_dialect);
+        let location = self.plan.location.trim_end_matches('/');
+
+        for table in &manifest.tables {
+            table.check_data_dir()?;
+        }
+
+        let mut rows = Vec::with_capacity(manifest.tables.len());
+        for table in &manifest.tables {
+            let sql = rewrite_database_bundle_ddl(
+                &table.ddl,
+                &self.plan.catalog,
+                &self.plan.database,
+                sql_dialect,
+            )?;
+            execute_sql_in_new_context(&self.ctx, &sql).await?;
+
+            let mut loaded = 0;
+            if table.snapshot_id.is_some() {
+                let sql = format!(
+                    "COPY INTO {}.{}.{} FROM {} FILE_FORMAT = (TYPE = PARQUET)",
+                    quoted_catalog,
+                    quoted_database,
+                    display_ident(&table.name, true, sql_dialect),
+                    QuotedString(format!("@{}/{}/", location, table.data_dir), '\''),
+                );
+                info!(
+                    "Import table {}.{} via sql {:?}",
+                    self.plan.database, table.name, sql
+                );
+                let blocks = execute_sql_in_new_context(&self.ctx, &sql).await?;
+                loaded = rows_loaded(&blocks);
+            }
+            if loaded != table.rows {
+                self.ctx.push_warning(format!(
+                    "table {}.{} is exported with {} rows, but {} rows are imported",
+                    self.plan.database, table.name, table.rows, loaded
+                ));
+            }
+            rows.push(loaded);
+        }
+
+        // The bundles exported before the views were sorted may list a view before its dependencies.
+        let views = sort_database_bundle_views(&manifest.database, manifest.views.clone())?;
+        for view in &views {
+            let sql = rewrite_database_bundle_ddl(
+                &view.ddl,
+                &self.plan.catalog,
+                &self.plan.database,
+                sql_dialect,
+            )?;
+            execute_sql_in_new_context(&self.ctx, &sql).await?;
+        }
+        Ok(rows)
+    }
+}
+
+/// Sums the `Rows_loaded` column of the result of `COPY INTO <table>`.
+fn rows_loaded(blocks: &[DataBlock]) -> u64 {
+    let mut rows = 0;
+    for block in blocks {
+        let value = &block.get_by_offset(1).value;
+        for row in 0..block.num_rows() {
+            if let Some(ScalarRef::Number(NumberScalar::Int32(n))) = value.index(row) {
+                rows += n as u64;
+            }
+        }
+    }
+    rows
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ImportDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "ImportDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let manifest = self.read_manifest().await?;
+
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let quoted_catalog = display_ident(&self.plan.catalog, true, sql_dialect);
+        let quoted_database = display_ident(&self.plan.database, true, sql_dialect);
+        execute_sql_in_new_context(
+            &self.ctx,
+            &format!("CREATE DATABASE {}.{}", quoted_catalog, quoted_database),
+        )
+        .await?;
+
+        let rows = match self.import(&manifest, sql_dialect).await {
+            Ok(rows) => rows,
+            Err(cause) => {
+                let sql = format!(
+                    "DROP DATABASE IF EXISTS {}.{}",
+                    quoted_catalog, quoted_database
+                );
+                if let Err(e) = execute_sql_in_new_context(&self.ctx, &sql).await {
+                    warn!(
+                        "Failed to drop database {} after a failed import: {}",
+                        self.plan.database, e
+                    );
+                }
+                return Err(cause);
+            }
+        };
+
+        let mut names = Vec::with_capacity(manifest.tables.len() + manifest.views.len());
+        let mut kinds = Vec::with_capacity(names.capacity());
+        for table in manifest.tables {
+            names.push(table.name);
+            kinds.push("TABLE".to_string());
+        }
+        for view in manifest.views {
+            names.push(view.name);
+            kinds.push("VIEW".to_string());
+        }
+        let mut all_rows = rows;
+        all_rows.resize(names.len(), 0);
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(kinds),
+            UInt64Type::from_data(all_rows),
+        ])])
+    }
+}
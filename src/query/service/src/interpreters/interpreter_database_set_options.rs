@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
+use databend_common_meta_app::schema::UpdateDatabaseOptionsReq;
+use databend_common_sql::plans::SetDatabaseOptionsPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct SetDatabaseOptionsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetDatabaseOptionsPlan,
+}
+
+impl SetDatabaseOptionsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetDatabaseOptionsPlan) -> Result<Self> {
+        Ok(SetDatabaseOptionsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetDatabaseOptionsInterpreter {
+    fn name(&self) -> &str {
+        "SetDatabaseOptionsInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let req = UpdateDatabaseOptionsReq {
+            name_ident: DatabaseNameIdent::new(&self.plan.tenant, &self.plan.database),
+            options: self
+                .plan
+                .set_options
+                .iter()
+                .map(|(k, v)| (k.clone(), Some(v.clone())))
+                .collect(),
+        };
+        match catalog.update_database_options(req).await {
+            Err(e) if self.plan.if_exists && e.code() == ErrorCode::UNKNOWN_DATABASE => {}
+            res => res?,
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
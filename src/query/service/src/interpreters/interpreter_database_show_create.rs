@@ -15,6 +15,7 @@
 use std::fmt::Write;
 use std::sync::Arc;
 
+use databend_common_catalog::database::Database;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::BlockEntry;
@@ -54,6 +55,24 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
         let db = catalog.get_database(&tenant, &self.plan.database).await?;
+        let name = db.name();
+        let info = Self::show_create_database_query(db.as_ref());
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new(
+            vec![
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(Scalar::String(name.to_string())),
+                ),
+                BlockEntry::new(DataType::String, Value::Scalar(Scalar::String(info))),
+            ],
+            1,
+        )])
+    }
+}
+
+impl ShowCreateDatabaseInterpreter {
+    pub fn show_create_database_query(db: &dyn Database) -> String {
         let name = db.name();
         let mut info = format!("CREATE DATABASE `{}`", name);
         if !db.engine().is_empty() {
@@ -71,19 +90,6 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
                 info.push_str(&engine);
             }
         }
-
-        PipelineBuildResult::from_blocks(vec![DataBlock::new(
-            vec![
-                BlockEntry::new(
-                    DataType::String,
-                    Value::Scalar(Scalar::String(name.to_string())),
-                ),
-                BlockEntry::new(
-                    DataType::String,
-                    Value::Scalar(Scalar::String(info.clone())),
-                ),
-            ],
-            1,
-        )])
+        info
     }
 }
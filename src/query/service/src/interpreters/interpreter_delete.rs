@@ -61,6 +61,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use futures_util::TryStreamExt;
 use log::debug;
 
+use crate::interpreters::common::check_query_estimate;
 use crate::interpreters::common::create_push_down_filters;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
@@ -267,6 +268,7 @@ impl Interpreter for DeleteInterpreter {
             is_distributed,
             query_row_id_col,
         )?;
+        check_query_estimate(&self.ctx, &physical_plan).await?;
 
         build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
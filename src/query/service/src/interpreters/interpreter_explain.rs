@@ -29,6 +29,7 @@ use databend_common_expression::FromData;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_sql::binder::ExplainConfig;
+use databend_common_sql::optimizer::logical_plan_to_json;
 use databend_common_sql::optimizer::ColumnSet;
 use databend_common_sql::plans::FunctionCall;
 use databend_common_sql::plans::MergeInto;
@@ -42,6 +43,7 @@ use databend_common_users::UserApiProvider;
 use super::InsertMultiTableInterpreter;
 use super::InterpreterFactory;
 use super::UpdateInterpreter;
+use crate::interpreters::common::get_current_role_with_limits;
 use crate::interpreters::interpreter_merge_into::MergeIntoInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::executor::ExecutorSettings;
@@ -54,6 +56,7 @@ use crate::schedulers::QueryFragmentsActions;
 use crate::sessions::QueryContext;
 use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PhysicalPlanBuilder;
+use crate::sql::executor::QueryEstimate;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::BoundColumnRef;
 use crate::sql::plans::DeletePlan;
@@ -89,6 +92,17 @@ impl Interpreter for ExplainInterpreter {
         let blocks = match &self.kind {
             ExplainKind::Raw | ExplainKind::Optimized => self.explain_plan(&self.plan)?,
             ExplainKind::Plan if self.config.logical => self.explain_plan(&self.plan)?,
+            ExplainKind::Plan if self.config.json => match &self.plan {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                    ..
+                } => self.explain_json(s_expr, metadata, bind_context).await?,
+                _ => Err(ErrorCode::Unimplemented(
+                    "Unsupported EXPLAIN FORMAT = JSON statement",
+                ))?,
+            },
             ExplainKind::Plan => match &self.plan {
                 Plan::Query {
                     s_expr,
@@ -243,6 +257,18 @@ impl Interpreter for ExplainInterpreter {
                 ));
             }
 
+            ExplainKind::Verify => match &self.plan {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                    ..
+                } => self.explain_verify(s_expr, metadata, bind_context).await?,
+                _ => Err(ErrorCode::Unimplemented(
+                    "Unsupported EXPLAIN VERIFY statement",
+                ))?,
+            },
+
             ExplainKind::Ast(display_string)
             | ExplainKind::Syntax(display_string)
             | ExplainKind::Memo(display_string) => {
@@ -472,6 +498,64 @@ impl ExplainInterpreter {
             .await
     }
 
+    /// Estimates the resources of the query without running it, and whether it would be
+    /// refused by the estimate limits.
+    #[async_backtrace::framed]
+    async fn explain_verify(
+        &self,
+        s_expr: &SExpr,
+        metadata: &MetadataRef,
+        bind_context: &BindContext,
+    ) -> Result<Vec<DataBlock>> {
+        let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
+        let plan = builder.build(s_expr, bind_context.column_set()).await?;
+        let estimate = QueryEstimate::estimate(&plan);
+
+        let verdict = match get_current_role_with_limits(&self.ctx).await? {
+            Some(role) => estimate.check_limits(&role),
+            None => Ok(()),
+        };
+        let verdict = match verdict {
+            Ok(_) => "verdict: allowed".to_string(),
+            Err(cause) => format!("verdict: refused, {}", cause.message()),
+        };
+
+        let estimate = estimate.to_string();
+        let lines = estimate
+            .lines()
+            .chain(std::iter::once(verdict.as_str()))
+            .collect::<Vec<_>>();
+        Ok(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(lines),
+        ])])
+    }
+
+    #[async_backtrace::framed]
+    async fn explain_json(
+        &self,
+        s_expr: &SExpr,
+        metadata: &MetadataRef,
+        bind_context: &BindContext,
+    ) -> Result<Vec<DataBlock>> {
+        let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), true);
+        let plan = builder.build(s_expr, bind_context.column_set()).await?;
+
+        let logical = logical_plan_to_json(self.ctx.clone(), s_expr)?;
+        let physical = plan.format_json()?;
+        let distributed = plan.is_distributed_plan();
+
+        // Bump the version when the layout of the document changes incompatibly.
+        let document = serde_json::json!({
+            "version": 1,
+            "distributed": distributed,
+            "logical": logical,
+            "physical": physical,
+        });
+
+        let formatted_plan = StringType::from_data(vec![serde_json::to_string(&document)?]);
+        Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
+    }
+
     #[async_backtrace::framed]
     async fn explain_delete(&self, delete: &DeletePlan) -> Result<Vec<DataBlock>> {
         let table_index = delete
@@ -542,6 +626,7 @@ impl ExplainInterpreter {
                 columns: delete.bind_context.column_set(),
                 push_down_predicates: None,
                 limit: None,
+                filter_limit: None,
                 order_by: None,
                 prewhere: None,
                 agg_index: None,
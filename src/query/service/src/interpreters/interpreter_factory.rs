@@ -50,6 +50,7 @@ use crate::interpreters::interpreter_notification_drop::DropNotificationInterpre
 use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
 use crate::interpreters::interpreter_set_priority::SetPriorityInterpreter;
+use crate::interpreters::interpreter_show_warnings::ShowWarningsInterpreter;
 use crate::interpreters::interpreter_system_action::SystemActionInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
@@ -179,6 +180,18 @@ impl InterpreterFactory {
                 RenameDatabaseInterpreter::try_create(ctx, *rename_database.clone())?,
             )),
 
+            Plan::SetDatabaseOptions(set_options) => Ok(Arc::new(
+                SetDatabaseOptionsInterpreter::try_create(ctx, *set_options.clone())?,
+            )),
+
+            Plan::ExportDatabase(export_database) => Ok(Arc::new(
+                ExportDatabaseInterpreter::try_create(ctx, *export_database.clone())?,
+            )),
+
+            Plan::ImportDatabase(import_database) => Ok(Arc::new(
+                ImportDatabaseInterpreter::try_create(ctx, *import_database.clone())?,
+            )),
+
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(Arc::new(
                 ShowCreateTableInterpreter::try_create(ctx, *show_create_table.clone())?,
@@ -202,6 +215,10 @@ impl InterpreterFactory {
                 ctx,
                 *rename_table.clone(),
             )?)),
+            Plan::SwapTable(swap_table) => Ok(Arc::new(SwapTableInterpreter::try_create(
+                ctx,
+                *swap_table.clone(),
+            )?)),
             Plan::SetOptions(set_options) => Ok(Arc::new(SetOptionsInterpreter::try_create(
                 ctx,
                 *set_options.clone(),
@@ -209,6 +226,13 @@ impl InterpreterFactory {
             Plan::ModifyTableComment(new_comment) => Ok(Arc::new(
                 ModifyTableCommentInterpreter::try_create(ctx, *new_comment.clone())?,
             )),
+            Plan::AlterTableTags(p) => Ok(Arc::new(AlterTableTagsInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterTableStoragePolicy(p) => Ok(Arc::new(
+                AlterTableStoragePolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
                 RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
             )),
@@ -246,6 +270,20 @@ impl InterpreterFactory {
             Plan::VacuumTemporaryFiles(vacuum_temporary_files) => Ok(Arc::new(
                 VacuumTemporaryFilesInterpreter::try_create(ctx, *vacuum_temporary_files.clone())?,
             )),
+            Plan::VacuumSystemHistory(vacuum_system_history) => Ok(Arc::new(
+                VacuumSystemHistoryInterpreter::try_create(ctx, *vacuum_system_history.clone())?,
+            )),
+            Plan::VerifyTable(verify_table) => Ok(Arc::new(VerifyTableInterpreter::try_create(
+                ctx,
+                *verify_table.clone(),
+            )?)),
+            Plan::RefreshTableStatistics(refresh) => Ok(Arc::new(
+                RefreshTableStatisticsInterpreter::try_create(ctx, *refresh.clone())?,
+            )),
+            Plan::RestoreTable(restore_table) => Ok(Arc::new(RestoreTableInterpreter::try_create(
+                ctx,
+                *restore_table.clone(),
+            )?)),
             Plan::AnalyzeTable(analyze_table) => Ok(Arc::new(AnalyzeTableInterpreter::try_create(
                 ctx,
                 *analyze_table.clone(),
@@ -363,6 +401,10 @@ impl InterpreterFactory {
                 ctx,
                 *drop_role.clone(),
             )?)),
+            Plan::AlterRole(alter_role) => Ok(Arc::new(AlterRoleInterpreter::try_create(
+                ctx,
+                *alter_role.clone(),
+            )?)),
             Plan::SetRole(set_role) => Ok(Arc::new(SetRoleInterpreter::try_create(
                 ctx,
                 *set_role.clone(),
@@ -443,6 +485,7 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::KillWhere(p) => Ok(Arc::new(KillWhereInterpreter::try_create(ctx, *p.clone())?)),
 
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
@@ -530,6 +573,9 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateTag(p) => Ok(Arc::new(CreateTagInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::AlterTag(p) => Ok(Arc::new(AlterTagInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropTag(p) => Ok(Arc::new(DropTagInterpreter::try_create(ctx, *p.clone())?)),
 
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
@@ -602,6 +648,7 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::ShowWarnings => Ok(Arc::new(ShowWarningsInterpreter::try_create(ctx)?)),
         }
     }
 }
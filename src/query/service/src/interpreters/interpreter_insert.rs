@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use databend_common_catalog::lock::LockTableOption;
@@ -21,22 +22,31 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_pipeline_sources::BlocksSource;
+use databend_common_sql::collect_column_lineage;
 use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::optimizer::SExpr;
 use databend_common_sql::plans::insert::InsertValue;
 use databend_common_sql::plans::Insert;
 use databend_common_sql::plans::InsertInputSource;
 use databend_common_sql::plans::Plan;
+use databend_common_sql::ColumnBinding;
+use databend_common_sql::MetadataRef;
 use databend_common_sql::NameResolutionContext;
 use log::info;
+use parking_lot::Mutex;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_query_estimate;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterColumnLineage;
 use crate::interpreters::InterpreterPtr;
+use crate::pipelines::processors::TransformCastSchema;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
 use crate::pipelines::RawValueSource;
@@ -72,6 +82,26 @@ impl InsertInterpreter {
         let cast_needed = select_schema.as_ref() != &DataSchema::from(output_schema.as_ref());
         Ok(cast_needed)
     }
+
+    /// Records the lineage of the inserted columns, which are named after the columns of the
+    /// table instead of the select list, this also covers `CREATE TABLE ... AS SELECT`.
+    fn write_column_lineage(
+        &self,
+        s_expr: &SExpr,
+        metadata: &MetadataRef,
+        output_columns: &[ColumnBinding],
+    ) -> Result<()> {
+        let mut lineage = collect_column_lineage(s_expr, metadata, output_columns);
+        for (column, field) in lineage.iter_mut().zip(self.plan.schema.fields()) {
+            column.output_column = field.name().clone();
+        }
+        let output_table = (
+            self.plan.catalog.as_str(),
+            self.plan.database.as_str(),
+            self.plan.table.as_str(),
+        );
+        InterpreterColumnLineage::write_log(&self.ctx, Some(output_table), &lineage)
+    }
 }
 
 #[async_trait::async_trait]
@@ -119,6 +149,25 @@ impl Interpreter for InsertInterpreter {
                     1,
                 )?;
             }
+            InsertInputSource::Blocks { schema, blocks } => {
+                let data_blocks = Arc::new(Mutex::new(VecDeque::from(blocks.clone())));
+                build_res.main_pipeline.add_source(
+                    |output| BlocksSource::create(self.ctx.clone(), output, data_blocks.clone()),
+                    1,
+                )?;
+
+                let dest_schema = self.plan.dest_schema();
+                if schema != &dest_schema {
+                    let func_ctx = self.ctx.get_function_context()?;
+                    build_res.main_pipeline.try_add_transformer(|| {
+                        TransformCastSchema::try_new(
+                            schema.clone(),
+                            dest_schema.clone(),
+                            func_ctx.clone(),
+                        )
+                    })?;
+                }
+            }
             InsertInputSource::Values(InsertValue::RawValues { data, start }) => {
                 build_res.main_pipeline.add_source(
                     |output| {
@@ -147,6 +196,9 @@ impl Interpreter for InsertInterpreter {
                         bind_context,
                         ..
                     } => {
+                        if self.ctx.get_settings().get_enable_column_lineage()? {
+                            self.write_column_lineage(s_expr, metadata, &bind_context.columns)?;
+                        }
                         let mut builder1 =
                             PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
                         (
@@ -157,6 +209,7 @@ impl Interpreter for InsertInterpreter {
                     }
                     _ => unreachable!(),
                 };
+                check_query_estimate(&self.ctx, &select_plan).await?;
 
                 let explain_plan = select_plan
                     .format(metadata.clone(), Default::default())?
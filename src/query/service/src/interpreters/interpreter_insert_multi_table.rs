@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::UInt64Type;
@@ -45,7 +46,9 @@ use databend_common_sql::plans::Into;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::MetadataRef;
 use databend_common_sql::ScalarExpr;
+use databend_common_storages_fuse::FuseTable;
 
+use crate::interpreters::common::check_query_estimate;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -87,6 +90,7 @@ impl Interpreter for InsertMultiTableInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let physical_plan = self.build_physical_plan().await?;
+        check_query_estimate(&self.ctx, &physical_plan).await?;
         let build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
         Ok(build_res)
@@ -275,6 +279,20 @@ impl InsertMultiTableInterpreter {
                 .then(a.1.table.cmp(&b.1.table))
         });
 
+        // All the target tables are committed in a single meta transaction of the catalog,
+        // which is only possible for fuse tables of the same catalog.
+        if let Some((_, first)) = condition_intos.first() {
+            if let Some((_, other)) = condition_intos
+                .iter()
+                .find(|(_, into)| into.catalog != first.catalog)
+            {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "multi-table insert into tables of different catalogs ({} and {}) is not supported",
+                    first.catalog, other.catalog
+                )));
+            }
+        }
+
         for (condition, into) in condition_intos {
             let Into {
                 catalog,
@@ -283,7 +301,17 @@ impl InsertMultiTableInterpreter {
                 casted_schema,
                 source_scalar_exprs,
             } = into;
-            let table = self.ctx.get_table(catalog, database, table).await?;
+            let table_name = table;
+            let table = self.ctx.get_table(catalog, database, table_name).await?;
+            if FuseTable::try_from_table(table.as_ref()).is_err() {
+                return Err(ErrorCode::StorageUnsupported(format!(
+                    "multi-table insert only supports fuse tables, but table {}.{} is of engine {}",
+                    database,
+                    table_name,
+                    table.engine()
+                )));
+            }
+            table.check_mutable()?;
             branches.push(
                 table,
                 condition,
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ScalarRef;
+use databend_common_sql::plans::KillPlan;
+use databend_common_sql::plans::KillWherePlan;
+use databend_common_sql::Planner;
+use futures::TryStreamExt;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::KillInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct KillWhereInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: KillWherePlan,
+}
+
+impl KillWhereInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: KillWherePlan) -> Result<Self> {
+        Ok(KillWhereInterpreter { ctx, plan })
+    }
+
+    #[async_backtrace::framed]
+    async fn matched_session_ids(&self) -> Result<Vec<String>> {
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(&self.plan.subquery).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+
+        let mut session_ids = Vec::new();
+        for block in blocks {
+            let column = block.get_by_offset(0).to_column(block.num_rows());
+            for row in 0..block.num_rows() {
+                if let Some(ScalarRef::String(id)) = column.index(row) {
+                    session_ids.push(id.to_string());
+                }
+            }
+        }
+        Ok(session_ids)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for KillWhereInterpreter {
+    fn name(&self) -> &str {
+        "KillWhereInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let session_ids = self.matched_session_ids().await?;
+        info!(
+            "Kill {} sessions matched by {:?}",
+            session_ids.len(),
+            self.plan.subquery
+        );
+
+        for id in session_ids {
+            let plan = KillPlan {
+                id,
+                kill_connection: self.plan.kill_connection,
+            };
+            let interpreter = KillInterpreter::try_create(self.ctx.clone(), plan)?;
+            match interpreter.execute2().await {
+                // The query may have finished since it was listed.
+                Err(cause) if cause.code() == ErrorCode::UNKNOWN_SESSION => {}
+                res => {
+                    res?;
+                }
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
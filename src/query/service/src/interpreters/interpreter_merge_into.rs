@@ -34,6 +34,7 @@ use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 
+use crate::interpreters::common::check_query_estimate;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
@@ -79,6 +80,7 @@ impl Interpreter for MergeIntoInterpreter {
 
         // Build physical plan.
         let physical_plan = self.build_physical_plan(&merge_into).await?;
+        check_query_estimate(&self.ctx, &physical_plan).await?;
 
         // Build pipeline.
         let mut build_res =
@@ -134,6 +136,7 @@ impl MergeIntoInterpreter {
                 table.get_table_info().engine(),
             ))
         })?;
+        fuse_table.check_column_backfills_done()?;
 
         // Prepare MergeIntoBuildInfo for PhysicalPlanBuilder to build MergeInto physical plan.
         let table_info = fuse_table.get_table_info();
@@ -43,24 +43,11 @@ impl PresignInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: PresignPlan) -> Result<Self> {
         Ok(PresignInterpreter { ctx, plan })
     }
-}
 
-#[async_trait::async_trait]
-impl Interpreter for PresignInterpreter {
-    fn name(&self) -> &str {
-        "PresignInterpreter"
-    }
-
-    fn is_ddl(&self) -> bool {
-        true
-    }
-
-    #[minitrace::trace]
+    /// Presign the path of the plan, returns a block of the method, headers and url.
     #[async_backtrace::framed]
-    async fn execute2(&self) -> Result<PipelineBuildResult> {
-        debug!("ctx.id" = self.ctx.get_id().as_str(); "presign_interpreter_execute");
-
-        let op = StageTable::get_op(&self.plan.stage)?;
+    pub async fn presign(ctx: &dyn TableContext, plan: &PresignPlan) -> Result<DataBlock> {
+        let op = StageTable::get_op(&plan.stage)?;
         if !op.info().full_capability().presign {
             return Err(ErrorCode::StorageUnsupported(
                 "storage doesn't support presign operation",
@@ -68,19 +55,19 @@ impl Interpreter for PresignInterpreter {
         }
 
         let start_time = std::time::Instant::now();
-        let presigned_req = match self.plan.action {
-            PresignAction::Download => op.presign_read(&self.plan.path, self.plan.expire).await?,
+        let presigned_req = match plan.action {
+            PresignAction::Download => op.presign_read(&plan.path, plan.expire).await?,
             PresignAction::Upload => {
-                let mut fut = op.presign_write_with(&self.plan.path, self.plan.expire);
-                if let Some(content_type) = &self.plan.content_type {
+                let mut fut = op.presign_write_with(&plan.path, plan.expire);
+                if let Some(content_type) = &plan.content_type {
                     fut = fut.content_type(content_type);
                 }
                 fut.await?
             }
         };
         info!(
-            "query_id" = self.ctx.get_id();
-            "presign {:?} {} success in {}ms", self.plan.action, self.plan.path, start_time.elapsed().as_millis()
+            "query_id" = ctx.get_id();
+            "presign {:?} {} success in {}ms", plan.action, plan.path, start_time.elapsed().as_millis()
         );
 
         let header = JsonbValue::Object(
@@ -119,6 +106,26 @@ impl Interpreter for PresignInterpreter {
             1,
         );
 
+        Ok(block)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for PresignInterpreter {
+    fn name(&self) -> &str {
+        "PresignInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "presign_interpreter_execute");
+
+        let block = Self::presign(self.ctx.as_ref(), &self.plan).await?;
         PipelineBuildResult::from_blocks(vec![block])
     }
 }
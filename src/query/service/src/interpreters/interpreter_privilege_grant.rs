@@ -101,7 +101,7 @@ impl GrantPrivilegeInterpreter {
             GrantObject::UDF(name) => Ok(OwnershipObject::UDF {
                 name: name.to_string(),
             }),
-            GrantObject::Global => Err(ErrorCode::IllegalGrant(
+            GrantObject::Global | GrantObject::Warehouse(_) => Err(ErrorCode::IllegalGrant(
                 "Illegal GRANT/REVOKE command; please consult the manual to see which privileges can be used",
             )),
         }
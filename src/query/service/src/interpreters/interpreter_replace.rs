@@ -49,6 +49,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use parking_lot::RwLock;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_query_estimate;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
 use crate::interpreters::HookOperator;
@@ -91,6 +92,7 @@ impl Interpreter for ReplaceInterpreter {
 
         // replace
         let (physical_plan, purge_info) = self.build_physical_plan().await?;
+        check_query_estimate(&self.ctx, &physical_plan).await?;
         let mut pipeline =
             build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
         pipeline
@@ -162,6 +164,7 @@ impl ReplaceInterpreter {
                 table.get_table_info().engine(),
             ))
         })?;
+        fuse_table.check_column_backfills_done()?;
 
         let table_info = fuse_table.get_table_info();
         let base_snapshot = fuse_table.read_table_snapshot().await?.unwrap_or_else(|| {
@@ -394,6 +397,9 @@ impl ReplaceInterpreter {
             InsertInputSource::SelectPlan(plan) => {
                 self.connect_query_plan_source(ctx.clone(), plan).await
             }
+            InsertInputSource::Blocks { .. } => Err(ErrorCode::Unimplemented(
+                "REPLACE INTO does not support the bulk insert payload",
+            )),
             InsertInputSource::Stage(plan) => match *plan.clone() {
                 Plan::CopyIntoTable(copy_plan) => {
                     let interpreter =
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::AlterRolePlan;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use databend_common_users::BUILTIN_ROLE_PUBLIC;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterRoleInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterRolePlan,
+}
+
+impl AlterRoleInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterRolePlan) -> Result<Self> {
+        Ok(AlterRoleInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterRoleInterpreter {
+    fn name(&self) -> &str {
+        "AlterRoleInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_role_execute");
+
+        let plan = self.plan.clone();
+        let role_name = plan.role_name;
+        if role_name.to_lowercase() == BUILTIN_ROLE_ACCOUNT_ADMIN
+            || role_name.to_lowercase() == BUILTIN_ROLE_PUBLIC
+        {
+            return Err(ErrorCode::IllegalRole(
+                "Illegal Alter Role command. Can not alter built-in role [ account_admin | public ]",
+            ));
+        }
+
+        let tenant = self.ctx.get_tenant();
+        UserApiProvider::instance()
+            .update_role_estimate_limits(
+                &tenant,
+                &role_name,
+                plan.max_estimated_scan_bytes,
+                plan.max_estimated_memory_bytes,
+            )
+            .await?;
+        RoleCacheManager::instance().force_reload(&tenant).await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -32,6 +32,7 @@ use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformDummy;
+use databend_common_sql::collect_column_lineage;
 use databend_common_sql::executor::physical_plans::FragmentKind;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::parse_result_scan_args;
@@ -44,8 +45,10 @@ use databend_common_users::UserApiProvider;
 use log::error;
 use log::info;
 
+use crate::interpreters::common::check_query_estimate;
 use crate::interpreters::common::query_build_update_stream_req;
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterColumnLineage;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline;
 use crate::sessions::QueryContext;
@@ -280,11 +283,20 @@ impl Interpreter for SelectInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         self.attach_tables_to_ctx();
 
+        if self.ctx.get_settings().get_enable_column_lineage()? {
+            let lineage =
+                collect_column_lineage(&self.s_expr, &self.metadata, &self.bind_context.columns);
+            InterpreterColumnLineage::write_log(&self.ctx, None, &lineage)?;
+        }
+
         self.ctx.set_status_info("preparing plan");
 
         // 0. Need to build physical plan first to get the partitions.
         let physical_plan = self.build_physical_plan().await?;
 
+        // Refuse the query before it runs if it is estimated to exceed the limits.
+        check_query_estimate(&self.ctx, &physical_plan).await?;
+
         let query_plan = physical_plan
             .format(self.metadata.clone(), Default::default())?
             .format_pretty()?;
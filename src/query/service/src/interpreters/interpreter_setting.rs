@@ -101,6 +101,16 @@ impl Interpreter for SettingInterpreter {
                     self.set_setting_by_var(&var, var.value.clone()).await?;
                     true
                 }
+                "maintenance_mode" | "reserved_warehouses" => {
+                    if !var.is_global {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "{} can only be set with SET GLOBAL",
+                            var.variable.to_lowercase()
+                        )));
+                    }
+                    self.set_setting_by_var(&var, var.value.clone()).await?;
+                    true
+                }
                 _ => {
                     self.set_setting_by_var(&var, var.value.clone()).await?;
                     true
@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt16Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// Returns the warnings of the last query of the session, e.g. the ones of the query
+/// advisor, which are kept so that `SHOW WARNINGS` can be repeated.
+pub struct ShowWarningsInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl ShowWarningsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(ShowWarningsInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowWarningsInterpreter {
+    fn name(&self) -> &str {
+        "ShowWarningsInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        self.ctx.keep_last_warnings();
+        let warnings = self.ctx.get_current_session().get_last_query_warnings();
+        if warnings.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let levels = vec!["Warning".to_string(); warnings.len()];
+        let codes = vec![0u16; warnings.len()];
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(levels),
+            UInt16Type::from_data(codes),
+            StringType::from_data(warnings),
+        ])])
+    }
+}
@@ -16,16 +16,21 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
 use databend_common_exception::set_backtrace;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::SystemAction;
 use databend_common_sql::plans::SystemPlan;
+use log::info;
 
+use crate::clusters::ClusterDiscovery;
 use crate::clusters::ClusterHelper;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::servers::flight::v1::actions::SYSTEM_ACTION;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
 
 pub struct SystemActionInterpreter {
     ctx: Arc<QueryContext>,
@@ -64,12 +69,49 @@ impl Interpreter for SystemActionInterpreter {
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if let SystemAction::CancelAllQueries { warehouse } = &self.plan.action {
+            let cluster_id = &GlobalConfig::instance().query.cluster_id;
+            if warehouse != cluster_id {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Unknown warehouse {}, the current warehouse is {}",
+                    warehouse, cluster_id
+                )));
+            }
+        }
+
+        let mut plan = self.plan.clone();
         if self.proxy_to_cluster {
-            let cluster = self.ctx.get_cluster();
+            let cluster = match &mut plan.action {
+                SystemAction::SuspendNode { node_id } | SystemAction::ResumeNode { node_id } => {
+                    // The suspended nodes are not in the cluster of the queries, but they
+                    // have to be told when they are resumed.
+                    let cluster = ClusterDiscovery::instance()
+                        .discover_with_suspended(&GlobalConfig::instance())
+                        .await?;
+                    let node_id = node_id.get_or_insert_with(|| cluster.local_id.clone());
+                    if !cluster.nodes.iter().any(|node| &node.id == node_id) {
+                        return Err(ErrorCode::ClusterUnknownNode(format!(
+                            "Unknown node {}, it is not in the warehouse {}",
+                            node_id,
+                            GlobalConfig::instance().query.cluster_id
+                        )));
+                    }
+
+                    // The mark is persisted once, the other nodes are told to refresh it.
+                    let discovery = ClusterDiscovery::instance();
+                    match &self.plan.action {
+                        SystemAction::SuspendNode { .. } => discovery.suspend_node(node_id).await?,
+                        _ => discovery.resume_node(node_id).await?,
+                    }
+                    cluster
+                }
+                _ => self.ctx.get_cluster(),
+            };
+
             let mut message = HashMap::with_capacity(cluster.nodes.len());
             for node_info in &cluster.nodes {
                 if node_info.id != cluster.local_id {
-                    message.insert(node_info.id.clone(), self.plan.clone());
+                    message.insert(node_info.id.clone(), plan.clone());
                 }
             }
 
@@ -80,10 +122,28 @@ impl Interpreter for SystemActionInterpreter {
                 .await?;
         }
 
-        match self.plan.action {
+        match plan.action {
             SystemAction::Backtrace(switch) => {
                 set_backtrace(switch);
             }
+            SystemAction::CancelAllQueries { ref warehouse } => {
+                let session_id = self.ctx.get_current_session_id();
+                let killed = SessionManager::instance().kill_all_queries(&session_id);
+                info!(
+                    "Cancelled {} queries on warehouse {} of node {}",
+                    killed,
+                    warehouse,
+                    self.ctx.get_cluster().local_id
+                );
+            }
+            SystemAction::SuspendNode { node_id } => {
+                let node_id = node_id.unwrap_or_else(|| self.ctx.get_cluster().local_id.clone());
+                ClusterDiscovery::instance().set_suspended(&node_id, true);
+            }
+            SystemAction::ResumeNode { node_id } => {
+                let node_id = node_id.unwrap_or_else(|| self.ctx.get_cluster().local_id.clone());
+                ClusterDiscovery::instance().set_suspended(&node_id, false);
+            }
         }
         Ok(PipelineBuildResult::create())
     }
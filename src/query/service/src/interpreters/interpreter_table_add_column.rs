@@ -27,15 +27,21 @@ use databend_common_meta_types::MatchSeq;
 use databend_common_sql::field_default_value;
 use databend_common_sql::plans::AddColumnOption;
 use databend_common_sql::plans::AddTableColumnPlan;
+use databend_common_storages_fuse::operations::ColumnBackfill;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_share::update_share_table_info;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::OPT_KEY_COLUMN_BACKFILLS;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use log::info;
+use log::warn;
 
+use crate::interpreters::hook::backfill_hook::backfill_columns;
+use crate::interpreters::hook::backfill_hook::spawn_column_backfill;
+use crate::interpreters::hook::backfill_hook::BackfillDesc;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -117,6 +123,14 @@ impl Interpreter for AddTableColumnInterpreter {
             };
             new_table_meta.add_column(&field, &self.plan.comment, index)?;
 
+            let backfill = match &self.plan.backfill_expr {
+                Some(expr) => {
+                    self.record_backfill(table.as_ref(), &mut new_table_meta, expr)
+                        .await?
+                }
+                None => false,
+            };
+
             let table_id = table_info.ident.table_id;
             let table_version = table_info.ident.seq;
 
@@ -142,12 +156,68 @@ impl Interpreter for AddTableColumnInterpreter {
                     .await?;
                 }
             }
+
+            if backfill {
+                let desc = BackfillDesc {
+                    catalog: self.plan.catalog.clone(),
+                    database: self.plan.database.clone(),
+                    table: self.plan.table.clone(),
+                };
+                // The first batch is backfilled in place, the rest in the background.
+                match backfill_columns(self.ctx.clone(), &desc).await {
+                    Ok(0) => {}
+                    Ok(_) => spawn_column_backfill(self.ctx.clone(), desc),
+                    Err(e) => warn!(
+                        "column backfill of table {}.{} failed, it's resumed by OPTIMIZE TABLE: {}",
+                        db_name, tbl_name, e
+                    ),
+                }
+            }
         };
 
         Ok(PipelineBuildResult::create())
     }
 }
 
+impl AddTableColumnInterpreter {
+    /// Records the backfill of the added column in the table options, returns false if the
+    /// table has no rows to backfill.
+    async fn record_backfill(
+        &self,
+        table: &dyn Table,
+        new_table_meta: &mut TableMeta,
+        expr: &str,
+    ) -> Result<bool> {
+        let fuse_table = FuseTable::try_from_table(table).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support the column default `{}`, which is not a constant",
+                &self.plan.database, &self.plan.table, expr
+            ))
+        })?;
+        let has_rows = fuse_table
+            .read_table_snapshot()
+            .await?
+            .is_some_and(|snapshot| snapshot.summary.row_count > 0);
+        if !has_rows {
+            return Ok(false);
+        }
+
+        let mut backfills = fuse_table.column_backfills()?;
+        let field = new_table_meta
+            .schema
+            .field_with_name(self.plan.field.name())?;
+        backfills.push(ColumnBackfill {
+            column_id: field.column_id(),
+            expr: expr.to_string(),
+        });
+        new_table_meta.options.insert(
+            OPT_KEY_COLUMN_BACKFILLS.to_string(),
+            serde_json::to_string(&backfills)?,
+        );
+        Ok(true)
+    }
+}
+
 pub(crate) async fn generate_new_snapshot(
     table: &dyn Table,
     new_table_meta: &mut TableMeta,
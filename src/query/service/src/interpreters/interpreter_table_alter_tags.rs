@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AlterTableTagsPlan;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_data_mask_feature::get_datamask_handler;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AlterTableTagsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTableTagsPlan,
+}
+
+impl AlterTableTagsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTableTagsPlan) -> Result<Self> {
+        Ok(AlterTableTagsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTableTagsInterpreter {
+    fn name(&self) -> &str {
+        "AlterTableTagsInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let table = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let engine = table.engine();
+        if matches!(engine, VIEW_ENGINE | STREAM_ENGINE) {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support alter",
+                db_name, tbl_name, engine
+            )));
+        }
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support alter",
+                db_name, tbl_name
+            )));
+        }
+
+        let schema = table.schema();
+        let column_field = match &self.plan.column {
+            Some(column) => match schema.column_with_name(column) {
+                Some((_, field)) => Some(field),
+                None => {
+                    return Err(ErrorCode::UnknownColumn(format!(
+                        "Cannot find column {}",
+                        column
+                    )));
+                }
+            },
+            None => None,
+        };
+
+        // the tags must be created before they are attached.
+        let user_mgr = UserApiProvider::instance();
+        for tag_name in self.plan.set_tags.keys() {
+            let tag = user_mgr.get_tag(&self.plan.tenant, tag_name).await?;
+            // the masking policy of the tag must apply to the column.
+            if let (Some(field), Some(mask_name)) = (column_field, tag.masking_policy) {
+                let policy = get_datamask_handler()
+                    .get_data_mask(
+                        user_mgr.get_meta_store_client(),
+                        &self.plan.tenant,
+                        mask_name.clone(),
+                    )
+                    .await?;
+                let policy_data_type = policy.args[0].1.to_lowercase();
+                let data_type = field.data_type().to_string().to_lowercase();
+                if data_type != policy_data_type {
+                    return Err(ErrorCode::UnmatchColumnDataType(format!(
+                        "Column '{}' data type {} does not match to the type {} of the mask policy {} of tag {}",
+                        field.name(),
+                        data_type,
+                        policy_data_type,
+                        mask_name,
+                        tag_name,
+                    )));
+                }
+            }
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        let tags = match &self.plan.column {
+            Some(column) => new_table_meta
+                .column_tags
+                .entry(column.clone())
+                .or_default(),
+            None => &mut new_table_meta.tags,
+        };
+        for (tag_name, tag_value) in &self.plan.set_tags {
+            tags.insert(tag_name.clone(), tag_value.clone());
+        }
+        for tag_name in &self.plan.unset_tags {
+            tags.remove(tag_name);
+        }
+        new_table_meta
+            .column_tags
+            .retain(|_, tags| !tags.is_empty());
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+        };
+        catalog.update_single_table_meta(req, table_info).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
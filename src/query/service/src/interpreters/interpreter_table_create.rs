@@ -14,6 +14,8 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
@@ -23,6 +25,10 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::is_internal_column;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::SendableDataBlockStream;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
@@ -51,6 +57,7 @@ use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use databend_common_storages_fuse::FUSE_OPT_KEY_SEGMENT_FORMAT_VERSION;
 use databend_common_storages_share::remove_share_table_info;
 use databend_common_storages_share::save_share_spec;
 use databend_common_users::RoleCacheManager;
@@ -58,6 +65,7 @@ use databend_common_users::UserApiProvider;
 use databend_enterprise_attach_table::get_attach_table_handler;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
@@ -68,6 +76,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
+use databend_storages_common_table_meta::table::OPT_KEY_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -84,16 +93,23 @@ use crate::sql::plans::insert::Insert;
 use crate::sql::plans::insert::InsertInputSource;
 use crate::sql::plans::Plan;
 use crate::storages::StorageDescription;
+use crate::stream::DataBlockStream;
 
 #[derive(Clone, Debug)]
 pub struct CreateTableInterpreter {
     ctx: Arc<QueryContext>,
     plan: CreateTablePlan,
+    /// The rows inserted by `CREATE TABLE AS SELECT`, set once the table is committed.
+    rows_inserted: Arc<AtomicU64>,
 }
 
 impl CreateTableInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTablePlan) -> Result<Self> {
-        Ok(CreateTableInterpreter { ctx, plan })
+        Ok(CreateTableInterpreter {
+            ctx,
+            plan,
+            rows_inserted: Arc::new(AtomicU64::new(0)),
+        })
     }
 }
 
@@ -172,6 +188,17 @@ impl Interpreter for CreateTableInterpreter {
             None => self.create_table().await,
         }
     }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        let blocks = match self.plan.as_select {
+            None => vec![],
+            Some(_) => vec![DataBlock::new_from_columns(vec![UInt64Type::from_data(
+                vec![self.rows_inserted.load(Ordering::Acquire)],
+            )])],
+        };
+
+        Ok(Box::pin(DataBlockStream::create(None, blocks)))
+    }
 }
 
 impl CreateTableInterpreter {
@@ -277,6 +304,8 @@ impl CreateTableInterpreter {
 
         let db_name = self.plan.database.clone();
         let table_name = self.plan.table.clone();
+        let ctx = self.ctx.clone();
+        let rows_inserted = self.rows_inserted.clone();
 
         // Add a callback to restore table visibility upon successful insert pipeline completion.
         // As there might be previous on_finish callbacks(e.g. refresh/compact/re-cluster hooks) which
@@ -314,6 +343,9 @@ impl CreateTableInterpreter {
                         info!("create {} as select failed. {:?}", qualified_table_name, e);
                         e
                     })?;
+
+                    let rows = ctx.get_write_progress_value().rows as u64;
+                    rows_inserted.store(rows, Ordering::Release);
                 }
 
                 Ok(())
@@ -438,11 +470,14 @@ impl CreateTableInterpreter {
             statistics: statistics.unwrap_or_default(),
             comment: comment.unwrap_or_default(),
             indexes: self.plan.inverted_indexes.clone().unwrap_or_default(),
+            constraints: self.plan.constraints.clone().unwrap_or_default(),
             ..Default::default()
         };
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
+        is_valid_block_size_threshold(&table_meta.options)?;
+        is_valid_segment_format_version(&table_meta.options)?;
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
@@ -498,6 +533,7 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_SEGMENT_FORMAT_VERSION);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
@@ -505,6 +541,7 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_READ_ONLY);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -548,12 +585,42 @@ pub fn is_valid_block_per_segment(options: &BTreeMap<String, String>) -> Result<
 }
 
 pub fn is_valid_row_per_block(options: &BTreeMap<String, String>) -> Result<()> {
-    // check row_per_block can not be over 1000000.
+    // check row_per_block can not be zero or over 1000000.
     if let Some(value) = options.get(FUSE_OPT_KEY_ROW_PER_BLOCK) {
         let row_per_block = value.parse::<u64>()?;
-        let error_str = "invalid row_per_block option, can't be over 1000000";
+        let error_str = "invalid row_per_block option, must be in the range of 1 to 1000000";
+
+        if row_per_block == 0 || row_per_block > DEFAULT_BLOCK_MAX_ROWS as u64 {
+            error!("{}", error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_block_size_threshold(options: &BTreeMap<String, String>) -> Result<()> {
+    // check block_size_threshold is a positive number of bytes, an invalid value would
+    // otherwise be ignored by the write paths, which fall back to the default.
+    if let Some(value) = options.get(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD) {
+        let block_size_threshold = value.parse::<u64>()?;
+        if block_size_threshold == 0 {
+            let error_str = "invalid block_size_threshold option, can't be zero";
+            error!("{}", error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
 
-        if row_per_block > DEFAULT_BLOCK_MAX_ROWS as u64 {
+pub fn is_valid_segment_format_version(options: &BTreeMap<String, String>) -> Result<()> {
+    // check segment_format_version is one of the versions the segments can be written in.
+    if let Some(value) = options.get(FUSE_OPT_KEY_SEGMENT_FORMAT_VERSION) {
+        let version = value.parse::<u64>()?;
+        if !SegmentInfo::WRITABLE_VERSIONS.contains(&version) {
+            let error_str = format!(
+                "invalid segment_format_version option, must be one of {:?}",
+                SegmentInfo::WRITABLE_VERSIONS
+            );
             error!("{}", error_str);
             return Err(ErrorCode::TableOptionInvalid(error_str));
         }
@@ -30,6 +30,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 
+use crate::interpreters::common::check_no_dependents;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -89,6 +90,10 @@ impl Interpreter for DropTableInterpreter {
                 &self.plan.table
             )));
         }
+        if self.plan.restrict {
+            check_no_dependents(self.ctx.clone(), catalog_name, db_name, tbl_name).await?;
+        }
+
         let catalog = self.ctx.get_catalog(catalog_name).await?;
 
         // Although even if data is in READ_ONLY mode,
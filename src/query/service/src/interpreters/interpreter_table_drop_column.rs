@@ -113,16 +113,21 @@ impl Interpreter for DropTableColumnInterpreter {
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
+        // Constraints referencing the column are dropped together with it.
+        new_table_meta
+            .constraints
+            .retain(|_, constraint| !constraint.column_ids.contains(&field.column_id));
+        new_table_meta.column_tags.remove(&self.plan.column);
 
         // update table options
         let opts = &mut new_table_meta.options;
         if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
             let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
             if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                if cols.iter().any(|x| x.name == self.plan.column) {
                     // remove from the bloom index columns.
-                    cols.remove(pos);
-                    *value = cols.join(",");
+                    cols.retain(|x| x.name != self.plan.column);
+                    *value = BloomIndexColumns::format_columns(&cols);
                 }
             }
         }
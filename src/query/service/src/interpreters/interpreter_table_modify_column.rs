@@ -52,6 +52,7 @@ use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::hook::conversion_hook::spawn_column_conversion;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
@@ -61,11 +62,30 @@ use crate::sessions::TableContext;
 pub struct ModifyTableColumnInterpreter {
     ctx: Arc<QueryContext>,
     plan: ModifyTableColumnPlan,
+    // True if it's the job converting the data in the background.
+    background: bool,
 }
 
 impl ModifyTableColumnInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: ModifyTableColumnPlan) -> Result<Self> {
-        Ok(ModifyTableColumnInterpreter { ctx, plan })
+        Ok(ModifyTableColumnInterpreter {
+            ctx,
+            plan,
+            background: false,
+        })
+    }
+
+    /// Creates the interpreter of the job spawned by `spawn_column_conversion`, which converts
+    /// the data in place of the statement.
+    pub fn try_create_background(
+        ctx: Arc<QueryContext>,
+        plan: ModifyTableColumnPlan,
+    ) -> Result<Self> {
+        Ok(ModifyTableColumnInterpreter {
+            ctx,
+            plan,
+            background: true,
+        })
     }
 
     // Set data mask policy to a column is a ee feature.
@@ -161,6 +181,7 @@ impl ModifyTableColumnInterpreter {
         let catalog = self.ctx.get_catalog(catalog_name).await?;
 
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        fuse_table.check_column_backfills_done()?;
         let prev_snapshot_id = fuse_table
             .read_table_snapshot()
             .await
@@ -192,7 +213,9 @@ impl ModifyTableColumnInterpreter {
 
                     // If the column is defined in bloom index columns,
                     // check whether the data type is supported for bloom index.
-                    if bloom_index_cols.iter().any(|v| v.as_str() == column)
+                    if bloom_index_cols
+                        .iter()
+                        .any(|v| v.name == *column && v.path.is_empty())
                         && !BloomIndex::supported_type(data_type)
                     {
                         return Err(ErrorCode::TableOptionInvalid(format!(
@@ -263,42 +286,60 @@ impl ModifyTableColumnInterpreter {
             return Ok(PipelineBuildResult::create());
         }
 
-        // if alter column from string to binary, we don't need to rebuild table
-        let is_alter_column_string_to_binary =
+        // if the stored data is compatible with the new data type, we don't need to rebuild table,
+        // e.g. alter column from string to binary, or rename the fields of tuple.
+        let is_alter_column_compatible =
             schema
                 .fields()
                 .iter()
                 .zip(new_schema.fields())
                 .all(|(old_field, new_field)| {
-                    fn is_string_to_binary(old_ty: &TableDataType, new_ty: &TableDataType) -> bool {
+                    // The inner fields of tuple are read by the leaf columns in DFS order,
+                    // so renaming the fields doesn't change the stored data.
+                    fn is_compatible_type(
+                        old_ty: &TableDataType,
+                        new_ty: &TableDataType,
+                        allow_rename: bool,
+                    ) -> bool {
                         match (old_ty, new_ty) {
                             (TableDataType::String, TableDataType::Binary) => true,
+                            // Widening the precision of decimal keeps the stored values, if they
+                            // are stored in the same physical type of parquet, i.e. int32 for the
+                            // precision up to 9 and int64 for the others up to 18.
+                            (TableDataType::Decimal(old_ty), TableDataType::Decimal(new_ty)) => {
+                                let (old_size, new_size) = (old_ty.size(), new_ty.size());
+                                let is_int32 = |precision: u8| precision <= 9;
+                                old_size.scale == new_size.scale
+                                    && old_size.precision <= new_size.precision
+                                    && new_size.precision <= 18
+                                    && is_int32(old_size.precision) == is_int32(new_size.precision)
+                            }
                             (TableDataType::Nullable(old_ty), TableDataType::Nullable(new_ty)) => {
-                                is_string_to_binary(old_ty, new_ty)
+                                is_compatible_type(old_ty, new_ty, allow_rename)
                             }
                             (TableDataType::Map(old_ty), TableDataType::Map(new_ty)) => {
-                                is_string_to_binary(old_ty, new_ty)
+                                is_compatible_type(old_ty, new_ty, allow_rename)
                             }
                             (TableDataType::Array(old_ty), TableDataType::Array(new_ty)) => {
-                                is_string_to_binary(old_ty, new_ty)
+                                is_compatible_type(old_ty, new_ty, allow_rename)
                             }
                             (
                                 TableDataType::Tuple {
+                                    fields_name: old_names,
                                     fields_type: old_tys,
-                                    ..
                                 },
                                 TableDataType::Tuple {
+                                    fields_name: new_names,
                                     fields_type: new_tys,
-                                    ..
                                 },
                             ) => {
-                                old_tys.len() == new_tys.len()
-                                    && old_tys
-                                        .iter()
-                                        .zip(new_tys)
-                                        .all(|(old_ty, new_ty)| is_string_to_binary(old_ty, new_ty))
+                                (allow_rename || old_names == new_names)
+                                    && old_tys.len() == new_tys.len()
+                                    && old_tys.iter().zip(new_tys).all(|(old_ty, new_ty)| {
+                                        is_compatible_type(old_ty, new_ty, allow_rename)
+                                    })
                             }
-                            _ => false,
+                            (old_ty, new_ty) => old_ty == new_ty,
                         }
                     }
 
@@ -316,15 +357,18 @@ impl ModifyTableColumnInterpreter {
                         column_id: new_column_id,
                         computed_expr: new_computed_expr,
                     } = new_field;
+                    // The bloom index of inner fields is defined by the field names.
+                    let allow_rename = !bloom_index_cols
+                        .iter()
+                        .any(|v| v.name == *old_name && !v.path.is_empty());
                     old_name == new_name
                         && old_default_expr == new_default_expr
                         && old_column_id == new_column_id
                         && old_computed_expr == new_computed_expr
-                        && (old_data_type == new_data_type
-                            || is_string_to_binary(&old_field.data_type, &new_field.data_type))
+                        && is_compatible_type(old_data_type, new_data_type, allow_rename)
                 });
 
-        if is_alter_column_string_to_binary {
+        if is_alter_column_compatible {
             table_info.meta.schema = new_schema.into();
 
             let table_id = table_info.ident.table_id;
@@ -355,6 +399,19 @@ impl ModifyTableColumnInterpreter {
             return Ok(PipelineBuildResult::create());
         }
 
+        // The data is converted by a job in the background if `enable_background_column_conversion`
+        // is set. The new schema is committed with the converted data, so the table is read with
+        // the old schema until the job is done.
+        if !self.background
+            && self
+                .ctx
+                .get_settings()
+                .get_enable_background_column_conversion()?
+        {
+            spawn_column_conversion(self.ctx.clone(), self.plan.clone());
+            return Ok(PipelineBuildResult::create());
+        }
+
         // 1. construct sql for selecting data from old table
         let mut sql = "select".to_string();
         schema
@@ -559,6 +616,11 @@ impl Interpreter for ModifyTableColumnInterpreter {
         let db_name = self.plan.database.as_str();
         let tbl_name = self.plan.table.as_str();
 
+        // The blocks without the added columns would be rewritten with their insert defaults.
+        self.ctx
+            .finish_column_backfills(catalog_name, db_name, tbl_name)
+            .await?;
+
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let table = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
 
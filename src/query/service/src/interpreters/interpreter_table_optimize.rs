@@ -15,17 +15,18 @@
 use std::sync::Arc;
 use std::time::SystemTime;
 
-use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::match_join_handle;
+use databend_common_base::runtime::spawn_blocking;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::plan::PartInfoType;
 use databend_common_catalog::plan::Partitions;
-use databend_common_catalog::table::CompactTarget;
 use databend_common_catalog::table::CompactionLimits;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_pipeline_core::ExecutionInfo;
-use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::executor::physical_plans::CommitSink;
 use databend_common_sql::executor::physical_plans::CompactSource;
 use databend_common_sql::executor::physical_plans::Exchange;
@@ -38,9 +39,12 @@ use databend_common_storages_factory::NavigationPoint;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 
+use crate::interpreters::hook::backfill_hook::backfill_columns;
+use crate::interpreters::hook::backfill_hook::BackfillDesc;
 use crate::interpreters::interpreter_table_recluster::build_recluster_physical_plan;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterClusteringHistory;
+use crate::interpreters::InterpreterMaintenanceHistory;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::PipelineBuildResult;
@@ -59,6 +63,31 @@ impl OptimizeTableInterpreter {
     }
 }
 
+/// A step of `OPTIMIZE TABLE`, see [`OptimizeTableInterpreter::plan_maintenance`].
+#[derive(Clone, Debug)]
+enum MaintenanceStep {
+    // Finishes the backfills of the added columns interrupted by a failure or a restart.
+    BackfillColumns,
+    CompactSegments,
+    // Optionally, specify the limit on the number of blocks to be compacted.
+    CompactBlocks(Option<usize>),
+    Recluster,
+    Purge(Option<NavigationPoint>),
+}
+
+impl MaintenanceStep {
+    /// The name of the step in `system.maintenance_history`.
+    fn name(&self) -> &'static str {
+        match self {
+            MaintenanceStep::BackfillColumns => "backfill_columns",
+            MaintenanceStep::CompactSegments => "compact_segments",
+            MaintenanceStep::CompactBlocks(_) => "compact_blocks",
+            MaintenanceStep::Recluster => "recluster",
+            MaintenanceStep::Purge(_) => "purge",
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl Interpreter for OptimizeTableInterpreter {
     fn name(&self) -> &str {
@@ -72,28 +101,55 @@ impl Interpreter for OptimizeTableInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let ctx = self.ctx.clone();
-        let plan = self.plan.clone();
-
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(&ctx.get_tenant(), &self.plan.database, &self.plan.table)
+            .await?;
+        let is_clustered = !table.cluster_keys(ctx.clone()).is_empty();
+        if matches!(self.plan.action, OptimizeTableAction::Recluster) && !is_clustered {
+            return Err(ErrorCode::UnclusteredTable(format!(
+                "Unclustered table {}",
+                table.get_table_info().desc
+            )));
+        }
 
-        match self.plan.action.clone() {
-            OptimizeTableAction::CompactBlocks(limit) => {
-                self.build_pipeline(catalog, CompactTarget::Blocks(limit), false)
-                    .await
-            }
-            OptimizeTableAction::CompactSegments => {
-                self.build_pipeline(catalog, CompactTarget::Segments, false)
-                    .await
-            }
-            OptimizeTableAction::Purge(point) => {
-                purge(ctx, catalog, plan, point).await?;
-                Ok(PipelineBuildResult::create())
-            }
-            OptimizeTableAction::All => {
-                self.build_pipeline(catalog, CompactTarget::Blocks(None), true)
-                    .await
-            }
+        let has_backfills = match FuseTable::try_from_table(table.as_ref()) {
+            Ok(fuse_table) => !fuse_table.column_backfills()?.is_empty(),
+            Err(_) => false,
+        };
+        let steps = Self::plan_maintenance(&self.plan.action, is_clustered, has_backfills);
+        let num_steps = steps.len();
+        for (idx, step) in steps.into_iter().enumerate() {
+            ctx.check_aborting()?;
+            ctx.set_status_info(&format!(
+                "optimize: begin to run {} ({}/{})",
+                step.name(),
+                idx + 1,
+                num_steps
+            ));
+
+            let start = SystemTime::now();
+            let res = self.execute_step(catalog.clone(), &step).await;
+            InterpreterMaintenanceHistory::write_log(
+                &ctx,
+                start,
+                &self.plan.database,
+                &self.plan.table,
+                step.name(),
+                res.as_ref().err(),
+            )?;
+            res?;
+
+            ctx.set_status_info(&format!(
+                "optimize: end to run {} ({}/{}), cost:{:?}",
+                step.name(),
+                idx + 1,
+                num_steps,
+                start.elapsed().unwrap_or_default()
+            ));
         }
+
+        Ok(PipelineBuildResult::create())
     }
 }
 
@@ -135,13 +191,60 @@ impl OptimizeTableInterpreter {
         })))
     }
 
-    async fn build_pipeline(
-        &self,
-        catalog: Arc<dyn Catalog>,
-        target: CompactTarget,
-        need_purge: bool,
-    ) -> Result<PipelineBuildResult> {
-        let tenant = self.ctx.get_tenant();
+    /// Plans the steps of an action in the order they run: the blocks are compacted
+    /// before reclustering, so that fewer blocks are reclustered, and the purge runs last
+    /// to remove the files made obsolete by the former steps.
+    fn plan_maintenance(
+        action: &OptimizeTableAction,
+        is_clustered: bool,
+        has_backfills: bool,
+    ) -> Vec<MaintenanceStep> {
+        let mut steps = vec![];
+        match action {
+            OptimizeTableAction::CompactSegments => steps.push(MaintenanceStep::CompactSegments),
+            OptimizeTableAction::CompactBlocks(limit) => {
+                if has_backfills {
+                    steps.push(MaintenanceStep::BackfillColumns);
+                }
+                steps.push(MaintenanceStep::CompactBlocks(*limit));
+                if is_clustered {
+                    steps.push(MaintenanceStep::Recluster);
+                }
+            }
+            OptimizeTableAction::Recluster => steps.push(MaintenanceStep::Recluster),
+            OptimizeTableAction::Purge(point) => {
+                steps.push(MaintenanceStep::Purge(point.clone()));
+            }
+            OptimizeTableAction::All => {
+                if has_backfills {
+                    steps.push(MaintenanceStep::BackfillColumns);
+                }
+                steps.push(MaintenanceStep::CompactBlocks(None));
+                if is_clustered {
+                    steps.push(MaintenanceStep::Recluster);
+                }
+                steps.push(MaintenanceStep::Purge(None));
+            }
+        }
+        steps
+    }
+
+    async fn execute_step(&self, catalog: Arc<dyn Catalog>, step: &MaintenanceStep) -> Result<()> {
+        if let MaintenanceStep::Purge(point) = step {
+            return purge(self.ctx.clone(), catalog, self.plan.clone(), point.clone()).await;
+        }
+        if let MaintenanceStep::BackfillColumns = step {
+            let desc = BackfillDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            };
+            while backfill_columns(self.ctx.clone(), &desc).await? > 0 {
+                self.ctx.check_aborting()?;
+            }
+            return Ok(());
+        }
+
         let lock_guard = self
             .ctx
             .clone()
@@ -152,128 +255,175 @@ impl OptimizeTableInterpreter {
                 &self.plan.lock_opt,
             )
             .await?;
-
-        let mut table = catalog
-            .get_table(&tenant, &self.plan.database, &self.plan.table)
+        let table = catalog
+            .get_table(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                &self.plan.table,
+            )
             .await?;
         // check mutability
         table.check_mutable()?;
 
-        let compaction_limits = match target {
-            CompactTarget::Segments => {
+        let build_res = match step {
+            MaintenanceStep::CompactSegments => {
                 table
                     .compact_segments(self.ctx.clone(), self.plan.limit)
                     .await?;
-                return Ok(PipelineBuildResult::create());
+                None
             }
-            CompactTarget::Blocks(num_block_limit) => {
-                let segment_limit = self.plan.limit;
-                CompactionLimits::limits(segment_limit, num_block_limit)
+            MaintenanceStep::CompactBlocks(num_block_limit) => {
+                self.build_compact_blocks_pipeline(table.as_ref(), *num_block_limit)
+                    .await?
             }
+            MaintenanceStep::Recluster => self.build_recluster_pipeline(table.as_ref()).await?,
+            MaintenanceStep::Purge(_) | MaintenanceStep::BackfillColumns => unreachable!(),
         };
 
-        let res = table
+        if let Some(build_res) = build_res {
+            self.execute_pipeline(build_res).await?;
+        }
+        // make sure the lock guard is dropped after the pipeline is executed.
+        drop(lock_guard);
+        Ok(())
+    }
+
+    /// Executes the pipeline in a blocking thread, so the runtime of the interpreter isn't
+    /// blocked while waiting for it.
+    async fn execute_pipeline(&self, mut build_res: PipelineBuildResult) -> Result<()> {
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+        build_res.set_max_threads(max_threads);
+
+        let executor_settings = ExecutorSettings::try_create(self.ctx.clone())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+
+        let complete_executor =
+            PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        self.ctx.set_executor(complete_executor.get_inner())?;
+        // the executor is moved into the blocking thread, so it's dropped before the next step.
+        match_join_handle(spawn_blocking(move || complete_executor.execute())).await
+    }
+
+    async fn build_compact_blocks_pipeline(
+        &self,
+        table: &dyn Table,
+        num_block_limit: Option<usize>,
+    ) -> Result<Option<PipelineBuildResult>> {
+        let compaction_limits = CompactionLimits::limits(self.plan.limit, num_block_limit);
+        let Some((parts, snapshot)) = table
             .compact_blocks(self.ctx.clone(), compaction_limits)
-            .await?;
+            .await?
+        else {
+            return Ok(None);
+        };
 
         let compact_is_distributed = (!self.ctx.get_cluster().is_empty())
             && self.ctx.get_settings().get_enable_distributed_compact()?;
+        let physical_plan = Self::build_physical_plan(
+            parts,
+            table.get_table_info().clone(),
+            snapshot,
+            compact_is_distributed,
+        )?;
+        let build_res =
+            build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
+        Ok(Some(build_res))
+    }
 
-        // build the compact pipeline.
-        let mut compact_pipeline = if let Some((parts, snapshot)) = res {
-            let physical_plan = Self::build_physical_plan(
-                parts,
-                table.get_table_info().clone(),
-                snapshot,
-                compact_is_distributed,
-            )?;
-
-            let build_res =
-                build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
-            build_res.main_pipeline
-        } else {
-            Pipeline::create()
+    async fn build_recluster_pipeline(
+        &self,
+        table: &dyn Table,
+    ) -> Result<Option<PipelineBuildResult>> {
+        let fuse_table = FuseTable::try_from_table(table)?;
+        let Some(mutator) = fuse_table
+            .build_recluster_mutator(self.ctx.clone(), None, None, self.plan.limit)
+            .await?
+        else {
+            return Ok(None);
         };
+        if mutator.tasks.is_empty() {
+            return Ok(None);
+        }
 
-        // build the recluster pipeline.
-        let mut build_res = PipelineBuildResult::create();
-        let settings = self.ctx.get_settings();
-        // check if the table need recluster, defined by cluster keys.
-        let need_recluster = !table.cluster_keys(self.ctx.clone()).is_empty();
-        if need_recluster {
-            if !compact_pipeline.is_empty() {
-                compact_pipeline.set_max_threads(settings.get_max_threads()? as usize);
+        let is_distributed = mutator.is_distributed();
+        let reclustered_block_count = mutator.recluster_blocks_count;
+        let physical_plan = build_recluster_physical_plan(
+            mutator.tasks,
+            table.get_table_info().clone(),
+            mutator.snapshot,
+            is_distributed,
+        )?;
+
+        let mut build_res =
+            build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
+
+        let ctx = self.ctx.clone();
+        let plan = self.plan.clone();
+        let start = SystemTime::now();
+        build_res
+            .main_pipeline
+            .set_on_finished(move |info: &ExecutionInfo| match &info.res {
+                Ok(_) => InterpreterClusteringHistory::write_log(
+                    &ctx,
+                    start,
+                    &plan.database,
+                    &plan.table,
+                    reclustered_block_count,
+                ),
+                Err(error_code) => Err(error_code.clone()),
+            });
+        Ok(Some(build_res))
+    }
+
+    /// Builds the pipeline to compact the blocks of the table, which reclusters the table
+    /// after the compaction if the cluster keys are defined.
+    ///
+    /// Unlike [`Interpreter::execute2`], the pipeline is returned to the caller to run, which
+    /// is used by the compact hook of the write statements.
+    pub async fn build_compact_pipeline(
+        &self,
+        num_block_limit: Option<usize>,
+    ) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let lock_guard = self
+            .ctx
+            .clone()
+            .acquire_table_lock(
+                &self.plan.catalog,
+                &self.plan.database,
+                &self.plan.table,
+                &self.plan.lock_opt,
+            )
+            .await?;
 
-                let executor_settings = ExecutorSettings::try_create(self.ctx.clone())?;
-                let executor =
-                    PipelineCompleteExecutor::try_create(compact_pipeline, executor_settings)?;
+        let mut table = catalog
+            .get_table(&tenant, &self.plan.database, &self.plan.table)
+            .await?;
+        // check mutability
+        table.check_mutable()?;
 
-                self.ctx.set_executor(executor.get_inner())?;
-                executor.execute()?;
-                // Make sure the executor is dropped before recluster.
-                drop(executor);
+        let compact_res = self
+            .build_compact_blocks_pipeline(table.as_ref(), num_block_limit)
+            .await?;
+
+        // check if the table need recluster, defined by cluster keys.
+        let need_recluster = !table.cluster_keys(self.ctx.clone()).is_empty();
+        let mut build_res = if need_recluster {
+            if let Some(compact_res) = compact_res {
+                self.execute_pipeline(compact_res).await?;
 
                 // refresh table.
                 table = catalog
                     .get_table(&tenant, &self.plan.database, &self.plan.table)
                     .await?;
             }
-
-            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
-            if let Some(mutator) = fuse_table
-                .build_recluster_mutator(self.ctx.clone(), None, self.plan.limit)
-                .await?
-            {
-                if !mutator.tasks.is_empty() {
-                    let is_distributed = mutator.is_distributed();
-                    let reclustered_block_count = mutator.recluster_blocks_count;
-                    let physical_plan = build_recluster_physical_plan(
-                        mutator.tasks,
-                        table.get_table_info().clone(),
-                        mutator.snapshot,
-                        is_distributed,
-                    )?;
-
-                    build_res =
-                        build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan)
-                            .await?;
-
-                    let ctx = self.ctx.clone();
-                    let plan = self.plan.clone();
-                    let start = SystemTime::now();
-                    build_res.main_pipeline.set_on_finished(
-                        move |info: &ExecutionInfo| match &info.res {
-                            Ok(_) => InterpreterClusteringHistory::write_log(
-                                &ctx,
-                                start,
-                                &plan.database,
-                                &plan.table,
-                                reclustered_block_count,
-                            ),
-                            Err(error_code) => Err(error_code.clone()),
-                        },
-                    );
-                }
-            }
+            self.build_recluster_pipeline(table.as_ref()).await?
         } else {
-            build_res.main_pipeline = compact_pipeline;
-        }
-
-        let ctx = self.ctx.clone();
-        let plan = self.plan.clone();
-        if need_purge {
-            if build_res.main_pipeline.is_empty() {
-                purge(ctx, catalog, plan, None).await?;
-            } else {
-                build_res
-                    .main_pipeline
-                    .set_on_finished(move |info: &ExecutionInfo| match &info.res {
-                        Ok(_) => GlobalIORuntime::instance()
-                            .block_on(async move { purge(ctx, catalog, plan, None).await }),
-                        Err(error_code) => Err(error_code.clone()),
-                    });
-            }
+            compact_res
         }
+        .unwrap_or_else(PipelineBuildResult::create);
 
         build_res.main_pipeline.add_lock_guard(lock_guard);
         Ok(build_res)
@@ -101,6 +101,10 @@ impl Interpreter for ReclusterTableInterpreter {
             None
         };
 
+        // The blocks without the added columns would be reclustered with their insert defaults.
+        ctx.finish_column_backfills(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+
         let mut times = 0;
         let mut block_count = 0;
         let start = SystemTime::now();
@@ -208,7 +212,12 @@ impl ReclusterTableInterpreter {
 
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         let mutator = fuse_table
-            .build_recluster_mutator(self.ctx.clone(), extras.clone(), self.plan.limit)
+            .build_recluster_mutator(
+                self.ctx.clone(),
+                extras.clone(),
+                self.plan.last_segments,
+                self.plan.limit,
+            )
             .await?;
         if mutator.is_none() {
             return Ok(true);
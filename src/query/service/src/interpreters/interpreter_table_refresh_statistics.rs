@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::RefreshTableStatisticsPlan;
+use databend_common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Re-derives the statistics of the segments and the snapshot of a fuse table from its
+/// blocks, to recover the pruning of a table whose statistics went stale.
+pub struct RefreshTableStatisticsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshTableStatisticsPlan,
+}
+
+impl RefreshTableStatisticsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshTableStatisticsPlan) -> Result<Self> {
+        Ok(RefreshTableStatisticsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshTableStatisticsInterpreter {
+    fn name(&self) -> &str {
+        "RefreshTableStatisticsInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        // The lock is released once the statistics are refreshed.
+        let _lock_guard = self
+            .ctx
+            .clone()
+            .acquire_table_lock(
+                &plan.catalog,
+                &plan.database,
+                &plan.table,
+                &LockTableOption::LockWithRetry,
+            )
+            .await?;
+
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::StorageUnsupported(format!(
+                "table {}.{} is not a fuse table, only the statistics of fuse tables can be refreshed",
+                plan.database, plan.table
+            ))
+        })?;
+        table.check_mutable()?;
+
+        let (segment_count, block_count, row_count) =
+            fuse_table.refresh_statistics(self.ctx.clone()).await?;
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(vec![segment_count]),
+            UInt64Type::from_data(vec![block_count]),
+            UInt64Type::from_data(vec![row_count]),
+        ])])
+    }
+}
@@ -107,16 +107,26 @@ impl Interpreter for RenameTableColumnInterpreter {
             }
 
             new_table_meta.schema = Arc::new(self.plan.schema.clone());
+            // Column tags are keyed by column name, move them to the new name.
+            if let Some(tags) = new_table_meta.column_tags.remove(&self.plan.old_column) {
+                new_table_meta
+                    .column_tags
+                    .insert(self.plan.new_column.clone(), tags);
+            }
 
             // update table options
             let opts = &mut new_table_meta.options;
             if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
                 let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
                 if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
+                    if cols.iter().any(|x| x.name == self.plan.old_column) {
                         // replace the bloom index columns with new column name.
-                        cols[pos] = self.plan.new_column.clone();
-                        *value = cols.join(",");
+                        for col in cols.iter_mut() {
+                            if col.name == self.plan.old_column {
+                                col.name = self.plan.new_column.clone();
+                            }
+                        }
+                        *value = BloomIndexColumns::format_columns(&cols);
                     }
                 }
             }
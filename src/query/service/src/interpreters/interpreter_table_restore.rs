@@ -0,0 +1,240 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_ast::ast::quote::display_ident;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::RestoreTablePlan;
+use databend_common_storage::StageFilesInfo;
+use databend_common_storages_stage::StageTable;
+use log::info;
+use log::warn;
+
+use crate::interpreters::common::database_bundle_path;
+use crate::interpreters::common::execute_sql_in_new_context;
+use crate::interpreters::common::read_database_bundle_manifest;
+use crate::interpreters::common::rewrite_database_bundle_ddl;
+use crate::interpreters::common::DatabaseBundleTable;
+use crate::interpreters::common::DATABASE_BUNDLE_MANIFEST;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Restores a table from the bundles written by `EXPORT DATABASE` under a stage location,
+/// which do not depend on the snapshots of the table, so that a table can be restored to a
+/// point in time whose snapshots have been purged.
+///
+/// The bundle of the table that is the newest as of the given point is restored, the data
+/// of the table is overwritten if it exists, otherwise the table is created and dropped
+/// again if the data can't be restored.
+pub struct RestoreTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RestoreTablePlan,
+}
+
+/// A table of a bundle under the location of the plan.
+struct BundledTable {
+    /// The path of the bundle relative to the stage.
+    bundle_dir: String,
+    table: DatabaseBundleTable,
+    as_of: Option<DateTime<Utc>>,
+}
+
+impl RestoreTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RestoreTablePlan) -> Result<Self> {
+        Ok(RestoreTableInterpreter { ctx, plan })
+    }
+
+    #[async_backtrace::framed]
+    async fn list_bundled_tables(&self) -> Result<Vec<BundledTable>> {
+        let op = StageTable::get_op(&self.plan.stage)?;
+        let thread_num = self.ctx.get_settings().get_max_threads()? as usize;
+        let files_info = StageFilesInfo {
+            path: self.plan.path.clone(),
+            files: None,
+            pattern: None,
+        };
+
+        let mut bundled_tables = vec![];
+        for file in files_info.list(&op, thread_num, None).await? {
+            let Some(bundle_dir) = file.path.strip_suffix(DATABASE_BUNDLE_MANIFEST) else {
+                continue;
+            };
+            if !bundle_dir.is_empty() && !bundle_dir.ends_with('/') {
+                continue;
+            }
+
+            let manifest = read_database_bundle_manifest(&op, &file.path).await?;
+            if manifest.database != self.plan.database {
+                continue;
+            }
+            if let Some(table) = manifest
+                .tables
+                .iter()
+                .find(|table| table.name == self.plan.table)
+            {
+                bundled_tables.push(BundledTable {
+                    bundle_dir: bundle_dir.trim_end_matches('/').to_string(),
+                    table: table.clone(),
+                    as_of: table.as_of(&manifest),
+                });
+            }
+        }
+        Ok(bundled_tables)
+    }
+
+    fn choose_bundled_table(&self, bundled_tables: Vec<BundledTable>) -> Result<BundledTable> {
+        let chosen = match &self.plan.point {
+            None => bundled_tables.into_iter().max_by_key(|t| t.as_of),
+            Some(NavigationPoint::TimePoint(point)) => bundled_tables
+                .into_iter()
+                .filter(|t| t.as_of.is_some_and(|as_of| as_of <= *point))
+                .max_by_key(|t| t.as_of),
+            Some(NavigationPoint::SnapshotID(snapshot_id)) => bundled_tables
+                .into_iter()
+                .find(|t| t.table.snapshot_id.as_deref() == Some(snapshot_id.as_str())),
+            Some(NavigationPoint::StreamInfo(_)) => {
+                return Err(ErrorCode::BadArguments(
+                    "RESTORE TABLE does not support the STREAM point",
+                ));
+            }
+        };
+
+        chosen.ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "no bundle of table {}.{} found at @{} for the given point",
+                self.plan.database, self.plan.table, self.plan.location
+            ))
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RestoreTableInterpreter {
+    fn name(&self) -> &str {
+        "RestoreTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let bundled_tables = self.list_bundled_tables().await?;
+        let BundledTable {
+            bundle_dir,
+            table: bundled,
+            ..
+        } = self.choose_bundled_table(bundled_tables)?;
+
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let quoted_table = [&self.plan.catalog, &self.plan.database, &self.plan.table]
+            .iter()
+            .map(|ident| display_ident(ident, true, sql_dialect))
+            .collect::<Vec<_>>()
+            .join(".");
+        let stage_name = self.plan.location.split('/').next().unwrap_or_default();
+        let data_location = format!(
+            "@{}/{}/",
+            stage_name,
+            database_bundle_path(&bundle_dir, &bundled.data_dir)
+        );
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                &self.ctx.get_tenant(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await;
+        let (table, created) = match table {
+            Ok(table) => (table, false),
+            Err(cause) if cause.code() == ErrorCode::UNKNOWN_TABLE => {
+                let sql = rewrite_database_bundle_ddl(
+                    &bundled.ddl,
+                    &self.plan.catalog,
+                    &self.plan.database,
+                    sql_dialect,
+                )?;
+                execute_sql_in_new_context(&self.ctx, &sql).await?;
+                let table = catalog
+                    .get_table(
+                        &self.ctx.get_tenant(),
+                        &self.plan.database,
+                        &self.plan.table,
+                    )
+                    .await?;
+                (table, true)
+            }
+            Err(cause) => return Err(cause),
+        };
+
+        // The table is always overwritten in a single snapshot, so that it's either restored
+        // as a whole or left untouched, and restoring it again gives the same result.
+        let sql = match bundled.snapshot_id.is_some() {
+            true => {
+                // The computed columns are not in the bundle, they are computed again.
+                let columns = table
+                    .schema()
+                    .fields()
+                    .iter()
+                    .filter(|f| f.computed_expr().is_none())
+                    .map(|f| display_ident(f.name(), true, sql_dialect))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "INSERT OVERWRITE {} ({}) SELECT {} FROM {} (FILE_FORMAT => 'parquet')",
+                    quoted_table, columns, columns, data_location
+                )
+            }
+            false => format!("TRUNCATE TABLE {}", quoted_table),
+        };
+
+        info!(
+            "Restore table {}.{} from bundle {:?} via sql {:?}",
+            self.plan.database, self.plan.table, bundle_dir, sql
+        );
+        if let Err(cause) = execute_sql_in_new_context(&self.ctx, &sql).await {
+            // Don't leave the table created by the restore half done.
+            if created {
+                let sql = format!("DROP TABLE IF EXISTS {}", quoted_table);
+                if let Err(e) = execute_sql_in_new_context(&self.ctx, &sql).await {
+                    warn!(
+                        "Failed to drop table {}.{} after a failed restore: {}",
+                        self.plan.database, self.plan.table, e
+                    );
+                }
+            }
+            return Err(cause);
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![bundle_dir]),
+            StringType::from_data(vec![bundled.snapshot_id.unwrap_or_default()]),
+            UInt64Type::from_data(vec![bundled.rows]),
+        ])])
+    }
+}
@@ -26,13 +26,16 @@ use databend_common_storages_share::update_share_table_info;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use log::error;
 
 use super::interpreter_table_create::is_valid_block_per_segment;
+use super::interpreter_table_create::is_valid_block_size_threshold;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_create_opt;
 use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_segment_format_version;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -66,6 +69,10 @@ impl Interpreter for SetOptionsInterpreter {
         is_valid_block_per_segment(&self.plan.set_options)?;
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
+        // check block_size_threshold
+        is_valid_block_size_threshold(&self.plan.set_options)?;
+        // check segment_format_version
+        is_valid_segment_format_version(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -82,6 +89,14 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_DATABASE_ID
             )));
         }
+        if let Some(value) = self.plan.set_options.get(OPT_KEY_READ_ONLY) {
+            if value.to_lowercase().parse::<bool>().is_err() {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "table option {} must be true or false, but got {}",
+                    OPT_KEY_READ_ONLY, value
+                )));
+            }
+        }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
             if !is_valid_create_opt(&key) {
@@ -0,0 +1,139 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AlterTableStoragePolicyPlan;
+use databend_common_storages_fuse::operations::TableStoragePolicy;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_POLICY;
+
+use crate::interpreters::migrate_table_cold_segments;
+use crate::interpreters::Interpreter;
+use crate::interpreters::TieringDesc;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AlterTableStoragePolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTableStoragePolicyPlan,
+}
+
+impl AlterTableStoragePolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTableStoragePolicyPlan) -> Result<Self> {
+        Ok(AlterTableStoragePolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTableStoragePolicyInterpreter {
+    fn name(&self) -> &str {
+        "AlterTableStoragePolicyInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let table = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not a fuse table, only fuse tables support storage policies",
+                db_name, tbl_name
+            ))
+        })?;
+        if table_info.db_type != DatabaseType::NormalDB || table_info.meta.storage_params.is_some()
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not stored in the storage of the tenant, which doesn't support storage policies",
+                db_name, tbl_name
+            )));
+        }
+
+        let policy = self.plan.policy.as_ref().map(|desc| TableStoragePolicy {
+            cold_after_days: desc.cold_after_days,
+            cold_location: desc.cold_location.clone(),
+            cold_storage: desc.cold_storage.clone(),
+        });
+
+        // The blocks already in the cold storage are read from the cold storage of the
+        // policy, which can not be removed or replaced by another storage.
+        let old_policy = fuse_table.storage_policy()?;
+        let cold_storage_changed = match (&old_policy, &policy) {
+            (Some(old), Some(new)) => old.cold_storage != new.cold_storage,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if cold_storage_changed && fuse_table.has_cold_blocks(self.ctx.clone()).await? {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "{}.{} has blocks in the cold storage, its cold storage can not be unset or changed",
+                db_name, tbl_name
+            )));
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        match &policy {
+            Some(policy) => {
+                new_table_meta.options.insert(
+                    OPT_KEY_STORAGE_POLICY.to_string(),
+                    serde_json::to_string(policy)?,
+                );
+            }
+            None => {
+                new_table_meta.options.remove(OPT_KEY_STORAGE_POLICY);
+            }
+        }
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+        };
+        catalog.update_single_table_meta(req, table_info).await?;
+
+        // The segments already due are moved now, the later ones are moved after the writes
+        // and periodically by the background mover.
+        if policy.is_some() {
+            let desc = TieringDesc {
+                catalog: catalog_name.to_string(),
+                database: db_name.to_string(),
+                table: tbl_name.to_string(),
+            };
+            self.ctx
+                .evict_table_from_cache(catalog_name, db_name, tbl_name)?;
+            migrate_table_cold_segments(self.ctx.clone(), &desc, LockTableOption::LockWithRetry)
+                .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_ast::ast::quote::display_ident;
+use databend_common_ast::ast::quote::QuotedString;
 use databend_common_ast::parser::Dialect;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::table::Table;
@@ -25,7 +27,11 @@ use databend_common_expression::BlockEntry;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
 use databend_common_expression::Value;
+use databend_common_meta_app::schema::IndexType;
+use databend_common_meta_app::schema::ListIndexesByIdReq;
+use databend_common_meta_app::tenant::Tenant;
 use databend_common_sql::plans::ShowCreateTablePlan;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
@@ -33,6 +39,7 @@ use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_internal_opt_key;
 use databend_storages_common_table_meta::table::StreamMode;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 
@@ -50,6 +57,9 @@ pub struct ShowCreateQuerySettings {
     pub sql_dialect: Dialect,
     pub quoted_ident_case_sensitive: bool,
     pub hide_options_in_show_create_table: bool,
+    /// Hides the `SNAPSHOT_LOCATION` option, so that the table created by the DDL is empty
+    /// instead of sharing the data of the table.
+    pub hide_snapshot_location: bool,
 }
 
 impl ShowCreateTableInterpreter {
@@ -85,6 +95,7 @@ impl Interpreter for ShowCreateTableInterpreter {
             hide_options_in_show_create_table: settings
                 .get_hide_options_in_show_create_table()
                 .unwrap_or(false),
+            hide_snapshot_location: false,
         };
 
         let create_query = Self::show_create_query(
@@ -130,7 +141,7 @@ impl ShowCreateTableInterpreter {
         }
     }
 
-    fn show_create_table_query(
+    pub(crate) fn show_create_table_query(
         table: &dyn Table,
         settings: &ShowCreateQuerySettings,
     ) -> Result<String> {
@@ -185,17 +196,18 @@ impl ShowCreateTableInterpreter {
                 {
                     // make the display more readable.
                     // can not use debug print, will add double quote
-                    format!(
-                        " COMMENT '{}'",
-                        &field_comments[idx].as_str().replace('\'', "\\'")
-                    )
+                    format!(" COMMENT {}", QuotedString(&field_comments[idx], '\''))
                 } else {
                     "".to_string()
                 };
                 let column_str = format!(
                     "  {} {}{}{}{}{}",
                     display_ident(field.name(), quoted_ident_case_sensitive, sql_dialect),
-                    field.data_type().remove_recursive_nullable().sql_name(),
+                    column_type_sql(
+                        &field.data_type().remove_nullable(),
+                        quoted_ident_case_sensitive,
+                        sql_dialect
+                    ),
                     nullable,
                     default_expr,
                     computed_expr,
@@ -214,12 +226,16 @@ impl ShowCreateTableInterpreter {
                 let mut column_names = Vec::with_capacity(index_field.column_ids.len());
                 for column_id in index_field.column_ids.iter() {
                     let field = schema.field_of_column_id(*column_id)?;
-                    column_names.push(field.name().to_string());
+                    column_names.push(display_ident(
+                        field.name(),
+                        quoted_ident_case_sensitive,
+                        sql_dialect,
+                    ));
                 }
                 let column_names_str = column_names.join(", ").to_string();
                 let mut options = Vec::with_capacity(index_field.options.len());
                 for (key, value) in index_field.options.iter() {
-                    let option = format!("{} = '{}'", key, value);
+                    let option = format!("{} = {}", key, QuotedString(value, '\''));
                     options.push(option);
                 }
                 let mut index_str = format!(
@@ -236,6 +252,46 @@ impl ShowCreateTableInterpreter {
                 create_defs.push(index_str);
             }
 
+            for constraint in table_info.meta.constraints.values() {
+                let mut column_names = Vec::with_capacity(constraint.column_ids.len());
+                for column_id in constraint.column_ids.iter() {
+                    let field = schema.field_of_column_id(*column_id)?;
+                    column_names.push(display_ident(
+                        field.name(),
+                        quoted_ident_case_sensitive,
+                        sql_dialect,
+                    ));
+                }
+                let mut constraint_str = format!(
+                    "  CONSTRAINT {} {} ({})",
+                    display_ident(&constraint.name, quoted_ident_case_sensitive, sql_dialect),
+                    constraint.constraint_type,
+                    column_names.join(", ")
+                );
+                if let (Some(ref_database), Some(ref_table)) =
+                    (&constraint.ref_database, &constraint.ref_table)
+                {
+                    let ref_columns = constraint
+                        .ref_columns
+                        .iter()
+                        .map(|column| {
+                            display_ident(column, quoted_ident_case_sensitive, sql_dialect)
+                        })
+                        .collect::<Vec<_>>();
+                    constraint_str.push_str(&format!(
+                        " REFERENCES {}.{} ({})",
+                        display_ident(ref_database, quoted_ident_case_sensitive, sql_dialect),
+                        display_ident(ref_table, quoted_ident_case_sensitive, sql_dialect),
+                        ref_columns.join(", ")
+                    ));
+                }
+                constraint_str.push_str(" NOT ENFORCED");
+                if constraint.rely {
+                    constraint_str.push_str(" RELY");
+                }
+                create_defs.push(constraint_str);
+            }
+
             // Format is:
             //  (
             //      x,
@@ -257,7 +313,10 @@ impl ShowCreateTableInterpreter {
                 opts.sort_by_key(|(k, _)| *k);
                 opts.iter()
                     .filter(|(k, _)| !is_internal_opt_key(k))
-                    .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
+                    .filter(|(k, _)| {
+                        !settings.hide_snapshot_location || k.as_str() != OPT_KEY_SNAPSHOT_LOCATION
+                    })
+                    .map(|(k, v)| format!(" {}={}", k.to_uppercase(), QuotedString(v, '\'')))
                     .collect::<Vec<_>>()
                     .join("")
                     .as_str()
@@ -271,11 +330,96 @@ impl ShowCreateTableInterpreter {
         }
 
         if !table_info.meta.comment.is_empty() {
-            table_create_sql.push_str(format!(" COMMENT = '{}'", table_info.meta.comment).as_str());
+            table_create_sql.push_str(
+                format!(
+                    " COMMENT = {}",
+                    QuotedString(&table_info.meta.comment, '\'')
+                )
+                .as_str(),
+            );
         }
         Ok(table_create_sql)
     }
 
+    /// Returns the statements of what can't be declared in `CREATE TABLE`, i.e. the tags, the
+    /// masking policies and the aggregating indexes of the table, to run after creating it.
+    /// They are not part of `SHOW CREATE TABLE`, which returns a single statement, but of
+    /// `GET_DDL`.
+    pub async fn show_table_dependents_query(
+        catalog: &dyn Catalog,
+        tenant: &Tenant,
+        table: &dyn Table,
+        settings: &ShowCreateQuerySettings,
+    ) -> Result<Vec<String>> {
+        if matches!(table.engine(), STREAM_ENGINE | VIEW_ENGINE)
+            || table.options().contains_key(OPT_KEY_STORAGE_PREFIX)
+        {
+            return Ok(vec![]);
+        }
+
+        let sql_dialect = settings.sql_dialect;
+        let quoted_ident_case_sensitive = settings.quoted_ident_case_sensitive;
+        let name = display_ident(table.name(), quoted_ident_case_sensitive, sql_dialect);
+        let meta = &table.get_table_info().meta;
+        let tags_sql = |tags: &BTreeMap<String, String>| {
+            tags.iter()
+                .map(|(k, v)| format!("{} = {}", k, QuotedString(v, '\'')))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut queries = vec![];
+        if !meta.tags.is_empty() {
+            queries.push(format!(
+                "ALTER TABLE {} SET TAG {}",
+                name,
+                tags_sql(&meta.tags)
+            ));
+        }
+        for (column, tags) in meta.column_tags.iter() {
+            if !tags.is_empty() {
+                queries.push(format!(
+                    "ALTER TABLE {} MODIFY COLUMN {} SET TAG {}",
+                    name,
+                    display_ident(column, quoted_ident_case_sensitive, sql_dialect),
+                    tags_sql(tags)
+                ));
+            }
+        }
+        if let Some(column_mask_policy) = &meta.column_mask_policy {
+            for (column, policy) in column_mask_policy.iter() {
+                queries.push(format!(
+                    "ALTER TABLE {} MODIFY COLUMN {} SET MASKING POLICY {}",
+                    name,
+                    display_ident(column, quoted_ident_case_sensitive, sql_dialect),
+                    display_ident(policy, quoted_ident_case_sensitive, sql_dialect)
+                ));
+            }
+        }
+        if table.support_index() {
+            let mut indexes = catalog
+                .list_indexes_by_table_id(ListIndexesByIdReq::new(tenant, table.get_id()))
+                .await?;
+            indexes.sort_by(|a, b| a.1.cmp(&b.1));
+            for (_, index_name, index_meta) in indexes {
+                if index_meta.index_type != IndexType::AGGREGATING {
+                    continue;
+                }
+                queries.push(format!(
+                    "CREATE {}AGGREGATING INDEX {} AS {}",
+                    if index_meta.sync_creation {
+                        ""
+                    } else {
+                        "ASYNC "
+                    },
+                    display_ident(&index_name, quoted_ident_case_sensitive, sql_dialect),
+                    index_meta.original_query
+                ));
+            }
+        }
+        Ok(queries)
+    }
+
     fn show_create_view_query(table: &dyn Table, database: &str) -> Result<String> {
         let name = table.name();
         let view_create_sql = if let Some(query) = table.options().get(QUERY) {
@@ -310,7 +454,7 @@ impl ShowCreateTableInterpreter {
 
         let comment = stream_table.get_table_info().meta.comment.clone();
         if !comment.is_empty() {
-            create_sql.push_str(format!(" COMMENT = '{}'", comment).as_str());
+            create_sql.push_str(format!(" COMMENT = {}", QuotedString(&comment, '\'')).as_str());
         }
         Ok(create_sql)
     }
@@ -331,3 +475,63 @@ impl ShowCreateTableInterpreter {
         )
     }
 }
+
+/// Renders the type of a column for DDL. The nullability of the nested types is always
+/// explicit, the unannotated ones are resolved by the setting `ddl_column_type_nullable`.
+fn column_type_sql(
+    data_type: &TableDataType,
+    quoted_ident_case_sensitive: bool,
+    sql_dialect: Dialect,
+) -> String {
+    let nested_type_sql = |data_type: &TableDataType| {
+        let sql = column_type_sql(data_type, quoted_ident_case_sensitive, sql_dialect);
+        if data_type.is_nullable() {
+            sql
+        } else {
+            format!("{} NOT NULL", sql)
+        }
+    };
+    match data_type {
+        TableDataType::Nullable(inner_ty) => format!(
+            "{} NULL",
+            column_type_sql(inner_ty, quoted_ident_case_sensitive, sql_dialect)
+        ),
+        TableDataType::Array(inner_ty) => format!("ARRAY({})", nested_type_sql(inner_ty)),
+        // The keys of map can't be nullable.
+        TableDataType::Map(inner_ty) => match inner_ty.as_ref() {
+            TableDataType::Tuple { fields_type, .. } => format!(
+                "MAP({}, {})",
+                column_type_sql(&fields_type[0], quoted_ident_case_sensitive, sql_dialect),
+                nested_type_sql(&fields_type[1])
+            ),
+            _ => data_type.sql_name(),
+        },
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => {
+            // The fields of unnamed tuple are named by their positions.
+            let unnamed = fields_name
+                .iter()
+                .enumerate()
+                .all(|(i, name)| *name == (i + 1).to_string());
+            let fields = fields_name
+                .iter()
+                .zip(fields_type)
+                .map(|(name, ty)| {
+                    if unnamed {
+                        nested_type_sql(ty)
+                    } else {
+                        format!(
+                            "{} {}",
+                            display_ident(name, quoted_ident_case_sensitive, sql_dialect),
+                            nested_type_sql(ty)
+                        )
+                    }
+                })
+                .collect::<Vec<_>>();
+            format!("TUPLE({})", fields.join(", "))
+        }
+        _ => data_type.sql_name(),
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::VerifyTablePlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_QUARANTINED_BLOCKS;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Verifies the blocks of a fuse table and reports the corrupted ones, which are quarantined
+/// so that the scans of the table skip them instead of failing.
+pub struct VerifyTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: VerifyTablePlan,
+}
+
+impl VerifyTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: VerifyTablePlan) -> Result<Self> {
+        Ok(VerifyTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for VerifyTableInterpreter {
+    fn name(&self) -> &str {
+        "VerifyTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::StorageUnsupported(format!(
+                "table {}.{} is not a fuse table, only fuse tables can be verified",
+                plan.database, plan.table
+            ))
+        })?;
+
+        let corrupted_blocks = fuse_table.verify_blocks(self.ctx.clone()).await?;
+
+        // The quarantine always reflects the latest verification, so the blocks which are
+        // readable again are released.
+        let quarantined_blocks = corrupted_blocks
+            .iter()
+            .map(|block| block.location.clone())
+            .collect::<BTreeSet<_>>();
+        if quarantined_blocks != fuse_table.quarantined_blocks()? {
+            for block in &corrupted_blocks {
+                warn!(
+                    "quarantine corrupted block {} of table {}.{}: {}",
+                    block.location, plan.database, plan.table, block.reason
+                );
+            }
+            let value = if quarantined_blocks.is_empty() {
+                None
+            } else {
+                Some(serde_json::to_string(&quarantined_blocks)?)
+            };
+            let req = UpsertTableOptionReq {
+                table_id: table.get_id(),
+                seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+                options: HashMap::from([(OPT_KEY_QUARANTINED_BLOCKS.to_string(), value)]),
+            };
+            let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+            catalog
+                .upsert_table_option(&self.ctx.get_tenant(), &plan.database, req)
+                .await?;
+        }
+
+        if corrupted_blocks.is_empty() {
+            return Ok(PipelineBuildResult::create());
+        }
+        let (locations, reasons): (Vec<_>, Vec<_>) = corrupted_blocks
+            .into_iter()
+            .map(|block| (block.location, block.reason))
+            .unzip();
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(locations),
+            StringType::from_data(reasons),
+        ])])
+    }
+}
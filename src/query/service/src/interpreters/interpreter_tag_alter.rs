@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_license::license::Feature::DataMask;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_sql::plans::AlterTagPlan;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_data_mask_feature::get_datamask_handler;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterTagInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTagPlan,
+}
+
+impl AlterTagInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTagPlan) -> Result<Self> {
+        Ok(AlterTagInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTagInterpreter {
+    fn name(&self) -> &str {
+        "AlterTagInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_tag_execute");
+
+        let plan = self.plan.clone();
+        let user_mgr = UserApiProvider::instance();
+
+        if let Some(mask_name) = &plan.masking_policy {
+            let license_manager = get_license_manager();
+            license_manager
+                .manager
+                .check_enterprise_enabled(self.ctx.get_license_key(), DataMask)?;
+
+            // make sure the masking policy exists.
+            let handler = get_datamask_handler();
+            handler
+                .get_data_mask(
+                    user_mgr.get_meta_store_client(),
+                    &plan.tenant,
+                    mask_name.clone(),
+                )
+                .await?;
+        }
+
+        user_mgr
+            .update_tag_masking_policy(
+                &plan.tenant,
+                &plan.name,
+                plan.masking_policy,
+                plan.if_exists,
+            )
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
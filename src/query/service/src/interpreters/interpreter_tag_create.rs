@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Tag;
+use databend_common_sql::plans::CreateTagPlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateTagInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTagPlan,
+}
+
+impl CreateTagInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTagPlan) -> Result<Self> {
+        Ok(CreateTagInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTagInterpreter {
+    fn name(&self) -> &str {
+        "CreateTagInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_tag_execute");
+
+        let plan = self.plan.clone();
+        let tag = Tag {
+            name: plan.name,
+            comment: plan.comment,
+            masking_policy: None,
+            create_on: Utc::now(),
+            update_on: None,
+        };
+
+        let user_mgr = UserApiProvider::instance();
+        user_mgr
+            .add_tag(&plan.tenant, tag, &plan.create_option)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -46,6 +46,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use log::debug;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_query_estimate;
 use crate::interpreters::common::create_push_down_filters;
 use crate::interpreters::interpreter_delete::subquery_filter;
 use crate::interpreters::HookOperator;
@@ -99,6 +100,7 @@ impl Interpreter for UpdateInterpreter {
         // build pipeline.
         let mut build_res = PipelineBuildResult::create();
         if let Some(physical_plan) = physical_plan {
+            check_query_estimate(&self.ctx, &physical_plan).await?;
             build_res =
                 build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
             {
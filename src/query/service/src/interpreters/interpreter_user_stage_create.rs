@@ -23,6 +23,7 @@ use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::CreateStagePlan;
+use databend_common_storage::remove_all;
 use databend_common_storages_stage::StageTable;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
@@ -105,7 +106,7 @@ impl Interpreter for CreateUserStageInterpreter {
         if let Some(stage) = old_stage {
             if stage.stage_type != StageType::External {
                 let op = StageTable::get_op(&stage)?;
-                op.remove_all("/").await?;
+                remove_all(&op, "/").await?;
                 info!(
                     "create or replace stage {:?} with all objects removed in stage",
                     user_stage.stage_name
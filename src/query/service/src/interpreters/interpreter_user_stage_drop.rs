@@ -19,6 +19,7 @@ use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::principal::StageType;
 use databend_common_sql::plans::DropStagePlan;
+use databend_common_storage::remove_all;
 use databend_common_storages_stage::StageTable;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
@@ -79,7 +80,7 @@ impl Interpreter for DropUserStageInterpreter {
 
             if !matches!(&stage.stage_type, StageType::External) {
                 let op = StageTable::get_op(&stage)?;
-                op.remove_all("/").await?;
+                remove_all(&op, "/").await?;
                 info!(
                     "drop stage {:?} with all objects removed in stage",
                     stage.stage_name
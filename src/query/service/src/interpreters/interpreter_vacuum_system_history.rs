@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::VacuumSystemHistoryPlan;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::SystemHistoryPurger;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// Purges the system history of the current node, with the configured retention or the
+/// retention of the statement.
+pub struct VacuumSystemHistoryInterpreter {
+    plan: VacuumSystemHistoryPlan,
+}
+
+impl VacuumSystemHistoryInterpreter {
+    pub fn try_create(_ctx: Arc<QueryContext>, plan: VacuumSystemHistoryPlan) -> Result<Self> {
+        Ok(VacuumSystemHistoryInterpreter { plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for VacuumSystemHistoryInterpreter {
+    fn name(&self) -> &str {
+        "VacuumSystemHistory"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let purged_rows = SystemHistoryPurger::instance().purge(self.plan.retain)?;
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(vec![purged_rows as u64]),
+        ])])
+    }
+}
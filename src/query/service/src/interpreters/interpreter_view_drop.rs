@@ -21,6 +21,7 @@ use databend_common_sql::plans::DropViewPlan;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 
+use crate::interpreters::common::check_no_dependents;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -82,6 +83,10 @@ impl Interpreter for DropViewInterpreter {
                 )));
             }
 
+            if self.plan.restrict {
+                check_no_dependents(self.ctx.clone(), &catalog_name, &db_name, &view_name).await?;
+            }
+
             let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
             let db = catalog
                 .get_database(&self.plan.tenant, &self.plan.database)
@@ -22,6 +22,7 @@ mod interpreter_catalog_show_create;
 mod interpreter_cluster_key_alter;
 mod interpreter_cluster_key_drop;
 mod interpreter_clustering_history;
+mod interpreter_column_lineage;
 mod interpreter_connection_create;
 mod interpreter_connection_desc;
 mod interpreter_connection_drop;
@@ -33,7 +34,10 @@ mod interpreter_data_mask_desc;
 mod interpreter_data_mask_drop;
 mod interpreter_database_create;
 mod interpreter_database_drop;
+mod interpreter_database_export;
+mod interpreter_database_import;
 mod interpreter_database_rename;
+mod interpreter_database_set_options;
 mod interpreter_database_show_create;
 mod interpreter_database_undrop;
 mod interpreter_delete;
@@ -49,6 +53,8 @@ mod interpreter_index_refresh;
 mod interpreter_insert;
 mod interpreter_insert_multi_table;
 mod interpreter_kill;
+mod interpreter_kill_where;
+mod interpreter_maintenance_history;
 mod interpreter_merge_into;
 mod interpreter_metrics;
 mod interpreter_network_policies_show;
@@ -68,6 +74,7 @@ mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
 mod interpreter_replace;
+mod interpreter_role_alter;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
@@ -92,10 +99,12 @@ mod interpreter_share_revoke_object;
 mod interpreter_share_show;
 mod interpreter_share_show_grant_tenants;
 mod interpreter_show_object_grant_privileges;
+mod interpreter_show_warnings;
 mod interpreter_stream_create;
 mod interpreter_stream_drop;
 mod interpreter_system_action;
 mod interpreter_table_add_column;
+mod interpreter_table_alter_tags;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_describe;
@@ -109,14 +118,22 @@ mod interpreter_table_modify_column;
 mod interpreter_table_modify_comment;
 mod interpreter_table_optimize;
 mod interpreter_table_recluster;
+mod interpreter_table_refresh_statistics;
 mod interpreter_table_rename;
 mod interpreter_table_rename_column;
+mod interpreter_table_restore;
 mod interpreter_table_revert;
 mod interpreter_table_set_options;
+mod interpreter_table_set_storage_policy;
 mod interpreter_table_show_create;
+mod interpreter_table_swap;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
 mod interpreter_table_vacuum;
+mod interpreter_table_verify;
+mod interpreter_tag_alter;
+mod interpreter_tag_create;
+mod interpreter_tag_drop;
 mod interpreter_task_alter;
 mod interpreter_task_create;
 mod interpreter_task_describe;
@@ -139,6 +156,7 @@ mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
 mod interpreter_user_udf_drop;
 mod interpreter_vacuum_drop_tables;
+mod interpreter_vacuum_system_history;
 mod interpreter_vacuum_temporary_files;
 mod interpreter_view_alter;
 mod interpreter_view_create;
@@ -152,6 +170,14 @@ mod util;
 
 pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
+pub use common::SystemHistoryPurger;
+pub use common::UsageMeter;
+pub(crate) use hook::backfill_hook::finish_column_backfills;
+pub(crate) use hook::backfill_hook::BackfillDesc;
+pub(crate) use hook::tiering_hook::migrate_table_cold_segments;
+pub(crate) use hook::tiering_hook::TieringDesc;
+pub(crate) use hook::tiering_hook::TieringMover;
+pub(crate) use hook::vacuum_hook::hook_vacuum_session_result_files;
 pub use hook::HookOperator;
 pub use interpreter::interpreter_plan_sql;
 pub use interpreter::Interpreter;
@@ -159,12 +185,16 @@ pub use interpreter::InterpreterPtr;
 pub use interpreter_cluster_key_alter::AlterTableClusterKeyInterpreter;
 pub use interpreter_cluster_key_drop::DropTableClusterKeyInterpreter;
 pub use interpreter_clustering_history::InterpreterClusteringHistory;
+pub use interpreter_column_lineage::InterpreterColumnLineage;
 pub use interpreter_data_mask_create::CreateDataMaskInterpreter;
 pub use interpreter_data_mask_desc::DescDataMaskInterpreter;
 pub use interpreter_data_mask_drop::DropDataMaskInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
+pub use interpreter_database_export::ExportDatabaseInterpreter;
+pub use interpreter_database_import::ImportDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
+pub use interpreter_database_set_options::SetDatabaseOptionsInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UndropDatabaseInterpreter;
 pub use interpreter_delete::DeleteInterpreter;
@@ -175,6 +205,8 @@ pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_insert_multi_table::InsertMultiTableInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_kill_where::KillWhereInterpreter;
+pub use interpreter_maintenance_history::InterpreterMaintenanceHistory;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_network_policies_show::ShowNetworkPoliciesInterpreter;
 pub use interpreter_network_policy_alter::AlterNetworkPolicyInterpreter;
@@ -185,9 +217,11 @@ pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
 pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
 pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
 pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
+pub use interpreter_presign::PresignInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
+pub use interpreter_role_alter::AlterRoleInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
@@ -210,10 +244,12 @@ pub use interpreter_share_revoke_object::RevokeShareObjectInterpreter;
 pub use interpreter_share_show::ShowSharesInterpreter;
 pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
+pub use interpreter_show_warnings::ShowWarningsInterpreter;
 pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_system_action::SystemActionInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_alter_tags::AlterTableTagsInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
@@ -227,13 +263,21 @@ pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_modify_comment::ModifyTableCommentInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
+pub use interpreter_table_refresh_statistics::RefreshTableStatisticsInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
+pub use interpreter_table_restore::RestoreTableInterpreter;
+pub use interpreter_table_set_storage_policy::AlterTableStoragePolicyInterpreter;
 pub use interpreter_table_show_create::ShowCreateQuerySettings;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
+pub use interpreter_table_swap::SwapTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_table_verify::VerifyTableInterpreter;
+pub use interpreter_tag_alter::AlterTagInterpreter;
+pub use interpreter_tag_create::CreateTagInterpreter;
+pub use interpreter_tag_drop::DropTagInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
@@ -247,6 +291,7 @@ pub use interpreter_user_udf_alter::AlterUserUDFScript;
 pub use interpreter_user_udf_create::CreateUserUDFScript;
 pub use interpreter_user_udf_drop::DropUserUDFScript;
 pub use interpreter_vacuum_drop_tables::VacuumDropTablesInterpreter;
+pub use interpreter_vacuum_system_history::VacuumSystemHistoryInterpreter;
 pub use interpreter_vacuum_temporary_files::VacuumTemporaryFilesInterpreter;
 pub use interpreter_view_alter::AlterViewInterpreter;
 pub use interpreter_view_create::CreateViewInterpreter;
@@ -193,9 +193,10 @@ impl LockManager {
                     catalog
                         .delete_lock_revision(delete_table_lock_req.clone())
                         .await?;
-                    Err(ErrorCode::TableAlreadyLocked(
-                        "table is locked by other session, please retry later".to_string(),
-                    ))
+                    Err(ErrorCode::TableLockWaitTimeout(format!(
+                        "table is locked by other session, waited for the lock more than {} seconds (acquire_lock_timeout)",
+                        acquire_lock_timeout
+                    )))
                 }
             }?;
         }
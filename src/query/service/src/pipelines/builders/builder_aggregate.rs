@@ -262,7 +262,12 @@ impl PipelineBuilder {
 
                     self.build_pipeline(&aggregate.input)?;
                     self.exchange_injector = old_inject;
-                    build_partition_bucket::<_, ()>(v, &mut self.main_pipeline, params.clone())
+                    build_partition_bucket::<_, ()>(
+                        self.ctx.clone(),
+                        v,
+                        &mut self.main_pipeline,
+                        params.clone(),
+                    )
                 }
             }),
             false => with_hash_method!(|T| match method {
@@ -277,7 +282,12 @@ impl PipelineBuilder {
                     }
                     self.build_pipeline(&aggregate.input)?;
                     self.exchange_injector = old_inject;
-                    build_partition_bucket::<_, usize>(v, &mut self.main_pipeline, params.clone())
+                    build_partition_bucket::<_, usize>(
+                        self.ctx.clone(),
+                        v,
+                        &mut self.main_pipeline,
+                        params.clone(),
+                    )
                 }
             }),
         }
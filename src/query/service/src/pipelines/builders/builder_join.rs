@@ -16,8 +16,11 @@ use std::sync::Arc;
 
 use databend_common_base::base::tokio::sync::Barrier;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sinks::Sinker;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::executor::physical_plans::HashJoin;
 use databend_common_sql::executor::physical_plans::MaterializedCte;
 use databend_common_sql::executor::physical_plans::RangeJoin;
@@ -29,12 +32,19 @@ use crate::pipelines::processors::transforms::range_join::RangeJoinState;
 use crate::pipelines::processors::transforms::range_join::TransformRangeJoinLeft;
 use crate::pipelines::processors::transforms::range_join::TransformRangeJoinRight;
 use crate::pipelines::processors::transforms::BuildSpillState;
+use crate::pipelines::processors::transforms::CacheSourceState;
+use crate::pipelines::processors::transforms::HashJoinBuildCache;
+use crate::pipelines::processors::transforms::HashJoinBuildCacheCollector;
+use crate::pipelines::processors::transforms::HashJoinBuildCacheKey;
+use crate::pipelines::processors::transforms::HashJoinBuildCacheState;
 use crate::pipelines::processors::transforms::HashJoinBuildState;
 use crate::pipelines::processors::transforms::HashJoinProbeState;
 use crate::pipelines::processors::transforms::MaterializedCteSink;
 use crate::pipelines::processors::transforms::MaterializedCteState;
 use crate::pipelines::processors::transforms::ProbeSpillState;
+use crate::pipelines::processors::transforms::TransformCacheScan;
 use crate::pipelines::processors::transforms::TransformHashJoinBuild;
+use crate::pipelines::processors::transforms::TransformHashJoinBuildCache;
 use crate::pipelines::processors::transforms::TransformHashJoinProbe;
 use crate::pipelines::processors::HashJoinDesc;
 use crate::pipelines::processors::HashJoinState;
@@ -110,7 +120,34 @@ impl PipelineBuilder {
             self.hash_join_states
                 .insert(build_cache_index, state.clone());
         }
-        self.expand_build_side_pipeline(&join.build, join, state.clone())?;
+        // The fragments of a distributed query run without the user on the other nodes, whose
+        // build sides are not cached since they can't be qualified by the user and roles.
+        let build_cache = match (
+            self.ctx.get_hash_join_build_cache_scope()?,
+            self.ctx.query_result_qualifier().ok(),
+        ) {
+            (Some(scope), Some(qualifier)) => {
+                HashJoinBuildCacheKey::try_create(join, scope, qualifier)
+                    .map(|key| (HashJoinBuildCache::instance(), key))
+            }
+            _ => None,
+        };
+        match build_cache {
+            Some((cache, key)) => match cache.get(&key) {
+                Some(blocks) => {
+                    self.expand_cached_build_side_pipeline(blocks, join, state.clone())?
+                }
+                None => {
+                    self.expand_build_side_pipeline(
+                        &join.build,
+                        join,
+                        state.clone(),
+                        Some((cache, key)),
+                    )?;
+                }
+            },
+            None => self.expand_build_side_pipeline(&join.build, join, state.clone(), None)?,
+        }
         self.build_join_probe(join, state)
     }
 
@@ -137,6 +174,7 @@ impl PipelineBuilder {
         build: &PhysicalPlan,
         hash_join_plan: &HashJoin,
         join_state: Arc<HashJoinState>,
+        build_cache: Option<(Arc<HashJoinBuildCache>, HashJoinBuildCacheKey)>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
         let mut build_side_builder = PipelineBuilder::create(
@@ -150,7 +188,59 @@ impl PipelineBuilder {
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
-        let output_len = build_res.main_pipeline.output_len();
+        // Keep the output of the build side for the following queries.
+        if let Some((cache, key)) = build_cache {
+            let collector = HashJoinBuildCacheCollector::create(
+                cache,
+                key,
+                self.settings.get_hash_join_build_cache_max_bytes()?,
+                build_res.main_pipeline.output_len(),
+            );
+            build_res.main_pipeline.add_transform(|input, output| {
+                Ok(TransformHashJoinBuildCache::create(
+                    input,
+                    output,
+                    collector.clone(),
+                ))
+            })?;
+        }
+        self.add_build_side_sink(&mut build_res.main_pipeline, hash_join_plan, join_state)?;
+
+        self.pipelines.push(build_res.main_pipeline.finalize());
+        self.pipelines.extend(build_res.sources_pipelines);
+        Ok(())
+    }
+
+    // Build the hash table from the build side cached by the previous queries.
+    fn expand_cached_build_side_pipeline(
+        &mut self,
+        blocks: Arc<Vec<DataBlock>>,
+        hash_join_plan: &HashJoin,
+        join_state: Arc<HashJoinState>,
+    ) -> Result<()> {
+        let max_threads = self.settings.get_max_threads()? as usize;
+        let cache_source_state =
+            CacheSourceState::HashJoinBuildCacheState(HashJoinBuildCacheState::new(blocks));
+        let mut build_pipeline = Pipeline::with_scopes(self.main_pipeline.get_scopes());
+        build_pipeline.add_source(
+            |output| {
+                TransformCacheScan::create(self.ctx.clone(), output, cache_source_state.clone())
+            },
+            max_threads,
+        )?;
+        self.add_build_side_sink(&mut build_pipeline, hash_join_plan, join_state)?;
+
+        self.pipelines.push(build_pipeline.finalize());
+        Ok(())
+    }
+
+    fn add_build_side_sink(
+        &mut self,
+        build_pipeline: &mut Pipeline,
+        hash_join_plan: &HashJoin,
+        join_state: Arc<HashJoinState>,
+    ) -> Result<()> {
+        let output_len = build_pipeline.output_len();
         let build_state = HashJoinBuildState::try_create(
             self.ctx.clone(),
             self.func_ctx.clone(),
@@ -180,11 +270,7 @@ impl PipelineBuilder {
         if hash_join_plan.need_hold_hash_table {
             self.join_state = Some(build_state.clone())
         }
-        build_res.main_pipeline.add_sink(create_sink_processor)?;
-
-        self.pipelines.push(build_res.main_pipeline.finalize());
-        self.pipelines.extend(build_res.sources_pipelines);
-        Ok(())
+        build_pipeline.add_sink(create_sink_processor)
     }
 
     fn build_join_probe(&mut self, join: &HashJoin, state: Arc<HashJoinState>) -> Result<()> {
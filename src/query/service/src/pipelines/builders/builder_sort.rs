@@ -28,6 +28,7 @@ use databend_common_pipeline_transforms::processors::TransformSortPartial;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::executor::physical_plans::Sort;
+use databend_common_sql::executor::physical_plans::SortInputOrder;
 use databend_common_storage::DataOperator;
 use databend_common_storages_fuse::TableContext;
 
@@ -70,6 +71,11 @@ impl PipelineBuilder {
             }
         }
 
+        if sort.input_order == SortInputOrder::Sorted {
+            // The input is a single sorted stream, keep it as it is.
+            return self.main_pipeline.try_resize(1);
+        }
+
         let plan_schema = sort.output_schema()?;
 
         let sort_desc = sort
@@ -87,7 +93,13 @@ impl PipelineBuilder {
             .collect::<Result<Vec<_>>>()?;
 
         if sort.window_partition.is_empty() {
-            self.build_sort_pipeline(plan_schema, sort_desc, sort.limit, sort.after_exchange)
+            self.build_sort_pipeline(
+                plan_schema,
+                sort_desc,
+                sort.limit,
+                sort.after_exchange,
+                sort.input_order == SortInputOrder::BlockSorted,
+            )
         } else {
             self.build_window_sort_pipeline(plan_schema, sort_desc, sort.limit, sort.after_exchange)
         }
@@ -99,6 +111,7 @@ impl PipelineBuilder {
         sort_desc: Vec<SortColumnDescription>,
         limit: Option<usize>,
         after_exchange: Option<bool>,
+        block_sorted: bool,
     ) -> Result<()> {
         let block_size = self.settings.get_max_block_size()? as usize;
         let max_threads = self.settings.get_max_threads()? as usize;
@@ -113,7 +126,8 @@ impl PipelineBuilder {
             SortPipelineBuilder::create(self.ctx.clone(), plan_schema.clone(), sort_desc.clone())
                 .with_partial_block_size(block_size)
                 .with_final_block_size(block_size)
-                .with_limit(limit);
+                .with_limit(limit)
+                .with_block_sorted(block_sorted);
 
         match after_exchange {
             Some(true) => {
@@ -217,6 +231,7 @@ pub struct SortPipelineBuilder {
     partial_block_size: usize,
     final_block_size: usize,
     remove_order_col_at_last: bool,
+    block_sorted: bool,
 }
 
 impl SortPipelineBuilder {
@@ -233,6 +248,7 @@ impl SortPipelineBuilder {
             partial_block_size: 0,
             final_block_size: 0,
             remove_order_col_at_last: false,
+            block_sorted: false,
         }
     }
 
@@ -251,6 +267,12 @@ impl SortPipelineBuilder {
         self
     }
 
+    /// The rows of each input block are already sorted, so the partial sort can be skipped.
+    pub fn with_block_sorted(mut self, block_sorted: bool) -> Self {
+        self.block_sorted = block_sorted;
+        self
+    }
+
     pub fn remove_order_col_at_last(mut self) -> Self {
         self.remove_order_col_at_last = true;
         self
@@ -262,7 +284,10 @@ impl SortPipelineBuilder {
         is_window_sort_and_shuffled: bool,
     ) -> Result<()> {
         // Partial sort
-        pipeline.add_transformer(|| TransformSortPartial::new(self.limit, self.sort_desc.clone()));
+        if !self.block_sorted {
+            pipeline
+                .add_transformer(|| TransformSortPartial::new(self.limit, self.sort_desc.clone()));
+        }
 
         self.build_merge_sort_pipeline(pipeline, false, is_window_sort_and_shuffled)
     }
@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::Result;
+use parking_lot::Condvar;
+use parking_lot::Mutex;
+
+/// The worker threads of the batch pool for the default pipeline executor.
+///
+/// The default executor spawns the worker threads of each query, so the batch pool is a
+/// budget of threads shared by the running batch queries: a batch query runs with at most
+/// all of the threads of the pool, and waits for the threads released by the other batch
+/// queries before it starts. The interactive queries are not limited.
+///
+/// The threads are taken once per query, the other executors of the query, e.g. the
+/// executors of its fragments or of the recursive CTEs, share the threads of the query
+/// instead of waiting for the threads held by the query itself.
+pub struct BatchExecutorThreads {
+    threads: usize,
+    state: Mutex<BatchThreadsState>,
+    released: Condvar,
+}
+
+struct BatchThreadsState {
+    available: usize,
+    // query id -> (the threads taken by the query, the executors sharing them)
+    queries: HashMap<String, (usize, usize)>,
+}
+
+impl BatchExecutorThreads {
+    pub fn init(threads: usize) -> Result<()> {
+        GlobalInstance::set(Arc::new(Self::create(threads)));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<BatchExecutorThreads> {
+        GlobalInstance::get()
+    }
+
+    pub fn create(threads: usize) -> BatchExecutorThreads {
+        BatchExecutorThreads {
+            threads,
+            state: Mutex::new(BatchThreadsState {
+                available: threads,
+                queries: HashMap::new(),
+            }),
+            released: Condvar::new(),
+        }
+    }
+
+    /// The worker threads of a batch query which asks for `threads`, no threads being
+    /// reserved for the batch queries means no limit.
+    pub fn limit(&self, threads: usize) -> usize {
+        match self.threads {
+            0 => threads,
+            pool_threads => threads.min(pool_threads),
+        }
+    }
+
+    /// Waits until `threads` are released by the other batch queries, or `is_aborted`
+    /// returns true, in which case `None` is returned. If the query already holds threads
+    /// of the pool, the executor shares them without waiting. The threads are given back
+    /// to the pool when the guards of all the executors of the query are dropped.
+    pub fn acquire(
+        self: &Arc<Self>,
+        query_id: &str,
+        threads: usize,
+        is_aborted: impl Fn() -> bool,
+    ) -> Option<BatchExecutorThreadsGuard> {
+        let threads = self.limit(threads);
+        if self.threads == 0 {
+            return Some(BatchExecutorThreadsGuard {
+                pool: self.clone(),
+                query_id: None,
+            });
+        }
+
+        let mut state = self.state.lock();
+        if let Some((_, executors)) = state.queries.get_mut(query_id) {
+            *executors += 1;
+            return Some(BatchExecutorThreadsGuard {
+                pool: self.clone(),
+                query_id: Some(query_id.to_string()),
+            });
+        }
+
+        while state.available < threads {
+            if is_aborted() {
+                return None;
+            }
+            self.released
+                .wait_for(&mut state, Duration::from_millis(100));
+        }
+
+        state.available -= threads;
+        state.queries.insert(query_id.to_string(), (threads, 1));
+        Some(BatchExecutorThreadsGuard {
+            pool: self.clone(),
+            query_id: Some(query_id.to_string()),
+        })
+    }
+
+    pub fn available(&self) -> usize {
+        self.state.lock().available
+    }
+
+    fn release(&self, query_id: &str) {
+        let mut state = self.state.lock();
+        let Some((threads, executors)) = state.queries.get_mut(query_id) else {
+            return;
+        };
+
+        *executors -= 1;
+        if *executors == 0 {
+            let threads = *threads;
+            state.queries.remove(query_id);
+            state.available += threads;
+            self.released.notify_all();
+        }
+    }
+}
+
+pub struct BatchExecutorThreadsGuard {
+    pool: Arc<BatchExecutorThreads>,
+    query_id: Option<String>,
+}
+
+impl Drop for BatchExecutorThreadsGuard {
+    fn drop(&mut self) {
+        if let Some(query_id) = &self.query_id {
+            self.pool.release(query_id);
+        }
+    }
+}
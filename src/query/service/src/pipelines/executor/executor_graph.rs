@@ -34,6 +34,9 @@ use databend_common_base::runtime::TrackingPayload;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_metrics::executor::PipelineProcessorsGauges;
+use databend_common_metrics::executor::PROCESSOR_STATE_BLOCKED;
+use databend_common_metrics::executor::PROCESSOR_STATE_READY;
 use databend_common_pipeline_core::processors::EventCause;
 use databend_common_pipeline_core::processors::PlanScope;
 use databend_common_pipeline_core::Pipeline;
@@ -75,6 +78,17 @@ enum State {
     Finished,
 }
 
+impl State {
+    /// The state of the processor in the `pipeline_processors` metric.
+    fn metrics_state(&self) -> Option<&'static str> {
+        match self {
+            State::Idle => Some(PROCESSOR_STATE_BLOCKED),
+            State::Processing => Some(PROCESSOR_STATE_READY),
+            State::Finished => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct EdgeInfo {
     input_index: usize,
@@ -86,6 +100,7 @@ pub(crate) struct Node {
     pub(crate) processor: ProcessorPtr,
 
     pub(crate) tracking_payload: TrackingPayload,
+    processors_gauges: PipelineProcessorsGauges,
     updated_list: Arc<UpdateList>,
     inputs_port: Vec<Arc<InputPort>>,
     outputs_port: Vec<Arc<OutputPort>>,
@@ -137,9 +152,12 @@ impl Node {
             tracking_payload
         };
 
+        let processors_gauges = PipelineProcessorsGauges::create();
+        processors_gauges.inc(PROCESSOR_STATE_BLOCKED, 1);
         Arc::new(Node {
             state: std::sync::Mutex::new(State::Idle),
             processor: processor.clone(),
+            processors_gauges,
             updated_list: UpdateList::create(),
             inputs_port: inputs_port.to_vec(),
             outputs_port: outputs_port.to_vec(),
@@ -437,7 +455,17 @@ impl ExecutingGraph {
                 };
 
                 node.trigger(&mut need_schedule_edges);
-                *state_guard_cache.unwrap() = processor_state;
+                let mut state_guard = state_guard_cache.unwrap();
+                let (from, to) = (state_guard.metrics_state(), processor_state.metrics_state());
+                if from != to {
+                    if let Some(from) = from {
+                        node.processors_gauges.dec(from, 1);
+                    }
+                    if let Some(to) = to {
+                        node.processors_gauges.inc(to, 1);
+                    }
+                }
+                *state_guard = processor_state;
             }
         }
 
@@ -984,6 +1012,15 @@ impl RunningGraph {
     }
 }
 
+impl Drop for Node {
+    fn drop(&mut self) {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(state) = state.metrics_state() {
+            self.processors_gauges.dec(state, 1);
+        }
+    }
+}
+
 impl Debug for Node {
     fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         unsafe { write!(f, "{}", self.processor.name()) }
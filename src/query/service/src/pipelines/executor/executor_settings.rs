@@ -12,19 +12,66 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::SystemTime;
 
+use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
+/// The worker pool a query runs in, the batch pool is a pool of the queries executor, or a
+/// budget of the worker threads of the default executor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExecutorPool {
+    Interactive,
+    Batch,
+}
+
+impl ExecutorPool {
+    /// Resolves the `executor_pool` setting, `auto` runs the queries that load or unload
+    /// data in the batch pool.
+    pub fn resolve(setting: &str, kind: QueryKind) -> Result<ExecutorPool> {
+        match setting.to_lowercase().as_str() {
+            "interactive" => Ok(ExecutorPool::Interactive),
+            "batch" => Ok(ExecutorPool::Batch),
+            "auto" => match kind {
+                QueryKind::CopyIntoTable
+                | QueryKind::CopyIntoLocation
+                | QueryKind::Insert
+                | QueryKind::Update => Ok(ExecutorPool::Batch),
+                _ => Ok(ExecutorPool::Interactive),
+            },
+            other => Err(ErrorCode::BadArguments(format!(
+                "unknown executor pool {}, must be one of ( auto | interactive | batch )",
+                other
+            ))),
+        }
+    }
+}
+
+impl Display for ExecutorPool {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ExecutorPool::Interactive => write!(f, "interactive"),
+            ExecutorPool::Batch => write!(f, "batch"),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ExecutorSettings {
     pub query_id: Arc<String>,
     pub max_threads: u64,
     pub enable_queries_executor: bool,
     pub max_execute_time_in_seconds: Duration,
+    // The remaining time of the statement timeout when the executor is created.
+    pub statement_timeout: Duration,
     pub executor_node_id: String,
+    pub pool: ExecutorPool,
 }
 
 impl ExecutorSettings {
@@ -33,13 +80,53 @@ impl ExecutorSettings {
         let settings = ctx.get_settings();
         let max_threads = settings.get_max_threads()?;
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
+        let pool = ExecutorPool::resolve(&settings.get_executor_pool()?, ctx.get_query_kind())?;
+
+        // The statement timeout counts from the creation of the query, so the time spent on
+        // planning and waiting for locks is deducted from it.
+        let statement_timeout = match settings.get_statement_timeout_in_seconds()? {
+            0 => Duration::ZERO,
+            secs => {
+                let elapsed = SystemTime::now()
+                    .duration_since(ctx.get_created_time())
+                    .unwrap_or_default();
+                Duration::from_secs(secs)
+                    .saturating_sub(elapsed)
+                    .max(Duration::from_millis(1))
+            }
+        };
 
         Ok(ExecutorSettings {
             enable_queries_executor: settings.get_enable_experimental_queries_executor()?,
             query_id: Arc::new(query_id),
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
+            statement_timeout,
             max_threads,
             executor_node_id: ctx.get_cluster().local_id.clone(),
+            pool,
         })
     }
+
+    /// The time the executor is allowed to run, zero means no limit.
+    pub fn execute_timeout(&self) -> Duration {
+        [self.statement_timeout, self.max_execute_time_in_seconds]
+            .into_iter()
+            .filter(|timeout| !timeout.is_zero())
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// The error to abort the executor with when the execute timeout expires.
+    pub fn execute_timeout_error(&self) -> ErrorCode {
+        let statement_timeout = self.statement_timeout;
+        if !statement_timeout.is_zero() && self.execute_timeout() == statement_timeout {
+            return ErrorCode::StatementTimeout(
+                "Aborted query, because the statement exceeds the statement_timeout_in_seconds limit",
+            );
+        }
+
+        ErrorCode::AbortedQuery(
+            "Aborted query, because the execution time exceeds the maximum execution time limit",
+        )
+    }
 }
@@ -19,23 +19,62 @@ use databend_common_base::runtime::Thread;
 use databend_common_exception::Result;
 use log::info;
 
+use crate::pipelines::executor::ExecutorPool;
 use crate::pipelines::executor::QueriesPipelineExecutor;
 
-pub struct GlobalQueriesExecutor(pub QueriesPipelineExecutor);
+/// The worker pools of the queries executor on the node.
+///
+/// If some threads are reserved for the batch queries, they run in their own pool, so that a
+/// large ETL pipeline can not take the threads of the short interactive queries.
+pub struct GlobalQueriesExecutor {
+    interactive: Arc<QueriesPipelineExecutor>,
+    batch: Option<Arc<QueriesPipelineExecutor>>,
+}
 
 impl GlobalQueriesExecutor {
-    pub fn init() -> Result<()> {
+    pub fn init(batch_threads: usize) -> Result<()> {
         let num_cpus = num_cpus::get();
-        GlobalInstance::set(QueriesPipelineExecutor::create(num_cpus)?);
-        Thread::spawn(|| {
-            if let Err(e) = Self::instance().execute() {
-                info!("Executor finished with error: {:?}", e);
+        // At least one thread is left for the interactive queries.
+        let batch_threads = batch_threads.min(num_cpus.saturating_sub(1));
+
+        let interactive = QueriesPipelineExecutor::create(num_cpus - batch_threads)?;
+        Self::spawn_pool(ExecutorPool::Interactive, interactive.clone());
+        let batch = match batch_threads {
+            0 => None,
+            threads => {
+                let batch = QueriesPipelineExecutor::create(threads)?;
+                Self::spawn_pool(ExecutorPool::Batch, batch.clone());
+                Some(batch)
             }
-        });
+        };
+
+        info!(
+            "Queries executor is started with {} interactive threads and {} batch threads",
+            num_cpus - batch_threads,
+            batch_threads
+        );
+        GlobalInstance::set(Arc::new(GlobalQueriesExecutor { interactive, batch }));
         Ok(())
     }
 
-    pub fn instance() -> Arc<QueriesPipelineExecutor> {
+    pub fn instance() -> Arc<GlobalQueriesExecutor> {
         GlobalInstance::get()
     }
+
+    /// The executor of the pool, the batch queries share the interactive pool if no
+    /// threads are reserved for them.
+    pub fn executor(&self, pool: ExecutorPool) -> Arc<QueriesPipelineExecutor> {
+        match (pool, &self.batch) {
+            (ExecutorPool::Batch, Some(batch)) => batch.clone(),
+            _ => self.interactive.clone(),
+        }
+    }
+
+    fn spawn_pool(pool: ExecutorPool, executor: Arc<QueriesPipelineExecutor>) {
+        Thread::spawn(move || {
+            if let Err(e) = executor.execute() {
+                info!("Executor of {} pool finished with error: {:?}", pool, e);
+            }
+        });
+    }
 }
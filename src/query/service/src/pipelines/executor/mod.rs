@@ -14,6 +14,7 @@
 
 mod query_pipeline_executor;
 
+mod batch_executor_threads;
 mod executor_condvar;
 mod executor_graph;
 mod executor_settings;
@@ -28,10 +29,13 @@ mod queries_executor_tasks;
 mod queries_pipeline_executor;
 mod query_executor_tasks;
 
+pub use batch_executor_threads::BatchExecutorThreads;
+pub use batch_executor_threads::BatchExecutorThreadsGuard;
 pub use databend_common_base::base::WatchNotify;
 pub use executor_condvar::WorkersCondvar;
 pub use executor_condvar::WorkersWaitingStatus;
 pub use executor_graph::RunningGraph;
+pub use executor_settings::ExecutorPool;
 pub use executor_settings::ExecutorSettings;
 pub use executor_worker_context::CompletedAsyncTask;
 pub use executor_worker_context::ExecutorTask;
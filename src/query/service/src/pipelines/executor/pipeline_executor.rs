@@ -14,7 +14,6 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Duration;
 use std::time::Instant;
 
 use databend_common_base::base::WatchNotify;
@@ -182,11 +181,10 @@ impl PipelineExecutor {
                     &query_wrapper.on_init_callback,
                     &query_wrapper.settings.query_id,
                 )?;
-                GlobalQueriesExecutor::instance().send_graph(query_wrapper.graph.clone())?;
-                Self::start_executor_daemon(
-                    query_wrapper,
-                    query_wrapper.settings.max_execute_time_in_seconds,
-                )?;
+                GlobalQueriesExecutor::instance()
+                    .executor(query_wrapper.settings.pool)
+                    .send_graph(query_wrapper.graph.clone())?;
+                Self::start_executor_daemon(query_wrapper)?;
                 let (lock, cvar) = &*query_wrapper.finish_condvar_wait;
                 let mut finished = lock.lock();
                 if !*finished {
@@ -213,21 +211,20 @@ impl PipelineExecutor {
         }
     }
 
-    fn start_executor_daemon(
-        query_wrapper: &QueryWrapper,
-        max_execute_time_in_seconds: Duration,
-    ) -> Result<()> {
-        if !max_execute_time_in_seconds.is_zero() {
+    fn start_executor_daemon(query_wrapper: &QueryWrapper) -> Result<()> {
+        let execute_timeout = query_wrapper.settings.execute_timeout();
+        if !execute_timeout.is_zero() {
             let this_graph = Arc::downgrade(&query_wrapper.graph);
             let finished_notify = query_wrapper.finished_notify.clone();
+            let cause = query_wrapper.settings.execute_timeout_error();
             GlobalIORuntime::instance().spawn(async move {
                 let finished_future = Box::pin(finished_notify.notified());
-                let max_execute_future = Box::pin(tokio::time::sleep(max_execute_time_in_seconds));
+                let max_execute_future = Box::pin(tokio::time::sleep(execute_timeout));
                 if let Either::Left(_) = select(max_execute_future, finished_future).await {
                     if let Some(graph) = this_graph.upgrade() {
-                        graph.should_finish(Err(ErrorCode::AbortedQuery(
-                            "Aborted query, because the execution time exceeds the maximum execution time limit",
-                        ))).expect("exceed max execute time, but cannot send error message");
+                        graph
+                            .should_finish(Err(cause))
+                            .expect("exceed max execute time, but cannot send error message");
                     }
                 }
             });
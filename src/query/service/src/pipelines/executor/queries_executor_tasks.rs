@@ -18,6 +18,8 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use databend_common_metrics::executor::metrics_dec_pipeline_scheduler_queue_depth;
+use databend_common_metrics::executor::metrics_inc_pipeline_scheduler_queue_depth;
 use parking_lot::Mutex;
 
 use crate::pipelines::executor::executor_graph::ProcessorWrapper;
@@ -209,6 +211,7 @@ impl QueriesExecutorTasksQueue {
         let mut workers_tasks = self.workers_tasks.lock();
         let mut worker_id = task.worker_id;
         workers_tasks.current_tasks.tasks_size += 1;
+        metrics_inc_pipeline_scheduler_queue_depth(1);
         workers_tasks.current_tasks.workers_completed_async_tasks[worker_id].push_back(task);
 
         condvar.dec_active_async_worker();
@@ -265,6 +268,12 @@ struct ExecutorTasks {
 
 unsafe impl Send for ExecutorTasks {}
 
+impl Drop for ExecutorTasks {
+    fn drop(&mut self) {
+        metrics_dec_pipeline_scheduler_queue_depth(self.tasks_size as i64);
+    }
+}
+
 impl ExecutorTasks {
     pub fn create(workers_size: usize) -> ExecutorTasks {
         let mut workers_sync_tasks = Vec::with_capacity(workers_size);
@@ -340,6 +349,7 @@ impl ExecutorTasks {
                 }
                 other => {
                     self.tasks_size -= 1;
+                    metrics_dec_pipeline_scheduler_queue_depth(1);
                     return other;
                 }
             }
@@ -350,6 +360,7 @@ impl ExecutorTasks {
 
     pub fn push_task(&mut self, worker_id: usize, task: ExecutorTask) {
         self.tasks_size += 1;
+        metrics_inc_pipeline_scheduler_queue_depth(1);
         debug_assert!(
             worker_id < self.workers_sync_tasks.len(),
             "out of index, {}, {}",
@@ -17,6 +17,8 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use databend_common_metrics::executor::metrics_dec_pipeline_scheduler_queue_depth;
+use databend_common_metrics::executor::metrics_inc_pipeline_scheduler_queue_depth;
 use parking_lot::Mutex;
 
 use crate::pipelines::executor::executor_graph::ProcessorWrapper;
@@ -163,6 +165,7 @@ impl QueryExecutorTasksQueue {
 
         let mut worker_id = task.worker_id;
         workers_tasks.tasks_size += 1;
+        metrics_inc_pipeline_scheduler_queue_depth(1);
         workers_tasks.workers_completed_async_tasks[worker_id].push_back(task);
 
         condvar.dec_active_async_worker();
@@ -201,6 +204,12 @@ struct ExecutorTasks {
 
 unsafe impl Send for ExecutorTasks {}
 
+impl Drop for ExecutorTasks {
+    fn drop(&mut self) {
+        metrics_dec_pipeline_scheduler_queue_depth(self.tasks_size as i64);
+    }
+}
+
 impl ExecutorTasks {
     pub fn create(workers_size: usize) -> ExecutorTasks {
         let mut workers_sync_tasks = Vec::with_capacity(workers_size);
@@ -267,6 +276,7 @@ impl ExecutorTasks {
                 }
                 other => {
                     self.tasks_size -= 1;
+                    metrics_dec_pipeline_scheduler_queue_depth(1);
                     return other;
                 }
             }
@@ -277,6 +287,7 @@ impl ExecutorTasks {
 
     pub fn push_task(&mut self, worker_id: usize, task: ExecutorTask) {
         self.tasks_size += 1;
+        metrics_inc_pipeline_scheduler_queue_depth(1);
         debug_assert!(
             worker_id < self.workers_sync_tasks.len(),
             "out of index, {}, {}",
@@ -45,6 +45,8 @@ use parking_lot::Mutex;
 use petgraph::matrix_graph::Zero;
 
 use crate::pipelines::executor::executor_graph::ScheduleQueue;
+use crate::pipelines::executor::BatchExecutorThreads;
+use crate::pipelines::executor::ExecutorPool;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::ExecutorWorkerContext;
 use crate::pipelines::executor::QueryExecutorTasksQueue;
@@ -174,6 +176,10 @@ impl QueryPipelineExecutor {
         settings: ExecutorSettings,
         lock_guards: Vec<Arc<LockGuard>>,
     ) -> Result<Arc<QueryPipelineExecutor>> {
+        let threads_num = match settings.pool {
+            ExecutorPool::Batch => BatchExecutorThreads::instance().limit(threads_num),
+            ExecutorPool::Interactive => threads_num,
+        };
         let workers_condvar = WorkersCondvar::create(threads_num);
         let global_tasks_queue = QueryExecutorTasksQueue::create(threads_num);
 
@@ -233,6 +239,18 @@ impl QueryPipelineExecutor {
 
         self.start_executor_daemon()?;
 
+        // The batch queries wait for the threads of the batch pool, the nested executors of
+        // the query share the threads taken by it. If the query is aborted while waiting,
+        // the threads are still spawned and exit at once.
+        let _batch_threads = match self.settings.pool {
+            ExecutorPool::Batch => BatchExecutorThreads::instance().acquire(
+                &self.settings.query_id,
+                self.threads_num,
+                || self.is_finished(),
+            ),
+            ExecutorPool::Interactive => None,
+        };
+
         let mut thread_join_handles = self.execute_threads(self.threads_num);
 
         while let Some(join_handle) = thread_join_handles.pop() {
@@ -330,19 +348,18 @@ impl QueryPipelineExecutor {
     }
 
     fn start_executor_daemon(self: &Arc<Self>) -> Result<()> {
-        if !self.settings.max_execute_time_in_seconds.is_zero() {
+        let execute_timeout = self.settings.execute_timeout();
+        if !execute_timeout.is_zero() {
             // NOTE(wake ref): When runtime scheduling is blocked, holding executor strong ref may cause the executor can not stop.
             let this = Arc::downgrade(self);
-            let max_execute_time_in_seconds = self.settings.max_execute_time_in_seconds;
             let finished_notify = self.finished_notify.clone();
             self.async_runtime.spawn(async move {
                 let finished_future = Box::pin(finished_notify.notified());
-                let max_execute_future = Box::pin(tokio::time::sleep(max_execute_time_in_seconds));
+                let max_execute_future = Box::pin(tokio::time::sleep(execute_timeout));
                 if let Either::Left(_) = select(max_execute_future, finished_future).await {
                     if let Some(executor) = this.upgrade() {
-                        executor.finish(Some(ErrorCode::AbortedQuery(
-                            "Aborted query, because the execution time exceeds the maximum execution time limit",
-                        )));
+                        let cause = executor.settings.execute_timeout_error();
+                        executor.finish(Some(cause));
                     }
                 }
             });
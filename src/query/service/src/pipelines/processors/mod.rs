@@ -15,6 +15,9 @@
 pub use databend_common_pipeline_core::processors::*;
 pub(crate) mod transforms;
 
+pub use transforms::HashJoinBuildCache;
+pub use transforms::HashJoinBuildCacheCollector;
+pub use transforms::HashJoinBuildCacheKey;
 pub use transforms::HashJoinBuildState;
 pub use transforms::HashJoinDesc;
 pub use transforms::HashJoinState;
@@ -114,6 +114,8 @@ pub struct BucketSpilledPayload {
     pub data_range: Range<u64>,
     pub columns_layout: Vec<u64>,
     pub max_partition_count: usize,
+    /// Whether the data is encrypted by the spill encryption of the query on this node.
+    pub encrypted: bool,
 }
 
 pub struct AggregatePayload {
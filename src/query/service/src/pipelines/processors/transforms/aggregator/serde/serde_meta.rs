@@ -31,6 +31,8 @@ pub struct AggregateSerdeMeta {
     pub columns_layout: Vec<usize>,
     // use for new agg hashtable
     pub max_partition_count: usize,
+    // Whether the spilled data is encrypted by the spill encryption shared by the query.
+    pub encrypted: bool,
 }
 
 impl AggregateSerdeMeta {
@@ -42,6 +44,7 @@ impl AggregateSerdeMeta {
             data_range: None,
             columns_layout: vec![],
             max_partition_count: 0,
+            encrypted: false,
         })
     }
 
@@ -53,6 +56,7 @@ impl AggregateSerdeMeta {
             data_range: None,
             columns_layout: vec![],
             max_partition_count,
+            encrypted: false,
         })
     }
 
@@ -69,6 +73,7 @@ impl AggregateSerdeMeta {
             location: Some(location),
             data_range: Some(data_range),
             max_partition_count: 0,
+            encrypted: false,
         })
     }
 
@@ -78,6 +83,7 @@ impl AggregateSerdeMeta {
         data_range: Range<u64>,
         columns_layout: Vec<usize>,
         max_partition_count: usize,
+        encrypted: bool,
     ) -> BlockMetaInfoPtr {
         Box::new(AggregateSerdeMeta {
             typ: SPILLED_TYPE,
@@ -86,6 +92,7 @@ impl AggregateSerdeMeta {
             location: Some(location),
             data_range: Some(data_range),
             max_partition_count,
+            encrypted,
         })
     }
 }
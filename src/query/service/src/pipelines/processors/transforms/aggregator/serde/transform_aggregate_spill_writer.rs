@@ -28,6 +28,7 @@ use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
 use databend_common_expression::PartitionedPayload;
 use databend_common_hashtable::HashtableLike;
+use databend_common_metrics::executor::metrics_inc_spill_write_bytes;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
@@ -210,6 +211,7 @@ pub fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
     let location = format!("{}/{}", location_prefix, unique_name);
+    let encryption = ctx.get_spill_encryption()?;
 
     let mut write_size = 0;
     let partition_count = partitioned_payload.partition_count();
@@ -237,12 +239,15 @@ pub fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
             columns_data.push(column_data);
         }
 
+        let columns_data = encryption.encrypt_columns(columns_data)?;
+        write_size = begin + columns_data.iter().map(Vec::len).sum::<usize>() as u64;
         write_data.push(columns_data);
         spilled_buckets_payloads.push(BucketSpilledPayload {
             bucket: bucket as isize,
             location: location.clone(),
             data_range: begin..write_size,
             columns_layout,
+            encrypted: encryption.is_enabled(),
             max_partition_count: partition_count,
         });
     }
@@ -271,6 +276,7 @@ pub fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
         {
             Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, 1);
             Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, write_bytes);
+            metrics_inc_spill_write_bytes("Aggregate", write_bytes as u64);
             Profile::record_usize_profile(
                 ProfileStatisticsName::SpillWriteTime,
                 instant.elapsed().as_millis() as usize,
@@ -307,6 +313,7 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
     let location = format!("{}/{}", location_prefix, unique_name);
+    let encryption = ctx.get_spill_encryption()?;
 
     let mut write_size = 0;
     let mut write_data = Vec::with_capacity(256);
@@ -334,12 +341,15 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
             columns_data.push(column_data);
         }
 
+        let columns_data = encryption.encrypt_columns(columns_data)?;
+        write_size = begin + columns_data.iter().map(Vec::len).sum::<usize>() as u64;
         write_data.push(columns_data);
         spilled_buckets_payloads.push(BucketSpilledPayload {
             bucket: bucket as isize,
             location: location.clone(),
             data_range: begin..write_size,
             columns_layout,
+            encrypted: encryption.is_enabled(),
             max_partition_count: 0,
         });
     }
@@ -368,6 +378,7 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
         {
             Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, 1);
             Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, write_bytes);
+            metrics_inc_spill_write_bytes("Aggregate", write_bytes as u64);
             Profile::record_usize_profile(
                 ProfileStatisticsName::SpillWriteTime,
                 instant.elapsed().as_millis() as usize,
@@ -165,6 +165,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformDeserializer<M
                                             ..columns_layout.offsets[index + 1] as usize]
                                             .to_vec(),
                                         max_partition_count: meta.max_partition_count,
+                                        encrypted: meta.encrypted,
                                     });
                                 }
                             }
@@ -38,6 +38,7 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FromData;
 use databend_common_expression::PartitionedPayload;
 use databend_common_hashtable::HashtableLike;
+use databend_common_metrics::executor::metrics_inc_spill_write_bytes;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
@@ -252,6 +253,8 @@ fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
     let location = format!("{}/{}", location_prefix, unique_name);
+    let encryption = ctx.get_spill_encryption()?;
+    let encrypted = encryption.is_enabled();
 
     let partition_count = partitioned_payload.partition_count();
     let mut write_size = 0;
@@ -284,6 +287,8 @@ fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
             columns_data.push(column_data);
         }
 
+        let columns_data = encryption.encrypt_columns(columns_data)?;
+        write_size = old_write_size + columns_data.iter().map(Vec::len).sum::<usize>() as u64;
         write_data.push(columns_data);
         buckets_column_data.push(bucket as i64);
         data_range_end_column_data.push(write_size);
@@ -313,6 +318,7 @@ fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
             {
                 Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, 1);
                 Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, write_bytes);
+                metrics_inc_spill_write_bytes("Aggregate", write_bytes as u64);
                 Profile::record_usize_profile(
                     ProfileStatisticsName::SpillWriteTime,
                     instant.elapsed().as_millis() as usize,
@@ -353,6 +359,7 @@ fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
                 0..0,
                 vec![],
                 partition_count,
+                encrypted,
             )))?;
 
             let ipc_fields = exchange_defines::spilled_ipc_fields();
@@ -374,6 +381,8 @@ fn spilling_aggregate_payload<Method: HashMethodBounds>(
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
     let location = format!("{}/{}", location_prefix, unique_name);
+    let encryption = ctx.get_spill_encryption()?;
+    let encrypted = encryption.is_enabled();
 
     let mut write_size = 0;
     let mut write_data = Vec::with_capacity(256);
@@ -405,6 +414,8 @@ fn spilling_aggregate_payload<Method: HashMethodBounds>(
             columns_data.push(column_data);
         }
 
+        let columns_data = encryption.encrypt_columns(columns_data)?;
+        write_size = old_write_size + columns_data.iter().map(Vec::len).sum::<usize>() as u64;
         write_data.push(columns_data);
         buckets_column_data.push(bucket as i64);
         data_range_end_column_data.push(write_size);
@@ -434,6 +445,7 @@ fn spilling_aggregate_payload<Method: HashMethodBounds>(
             {
                 Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, 1);
                 Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, write_bytes);
+                metrics_inc_spill_write_bytes("Aggregate", write_bytes as u64);
                 Profile::record_usize_profile(
                     ProfileStatisticsName::SpillWriteTime,
                     instant.elapsed().as_millis() as usize,
@@ -473,6 +485,7 @@ fn spilling_aggregate_payload<Method: HashMethodBounds>(
                 location.clone(),
                 0..0,
                 vec![],
+                encrypted,
             )))?;
 
             let ipc_fields = exchange_defines::spilled_ipc_fields();
@@ -43,6 +43,7 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FromData;
 use databend_common_expression::PartitionedPayload;
 use databend_common_hashtable::HashtableLike;
+use databend_common_metrics::executor::metrics_inc_spill_write_bytes;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
@@ -305,6 +306,8 @@ fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
     let location = format!("{}/{}", location_prefix, unique_name);
+    let encryption = ctx.get_spill_encryption()?;
+    let encrypted = encryption.is_enabled();
 
     let mut write_size = 0;
     let partition_count = partitioned_payload.partition_count();
@@ -338,6 +341,8 @@ fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
             columns_data.push(column_data);
         }
 
+        let columns_data = encryption.encrypt_columns(columns_data)?;
+        write_size = old_write_size + columns_data.iter().map(Vec::len).sum::<usize>() as u64;
         write_data.push(columns_data);
         buckets_column_data.push(bucket as i64);
         data_range_end_column_data.push(write_size);
@@ -367,6 +372,7 @@ fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
             {
                 Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, 1);
                 Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, write_bytes);
+                metrics_inc_spill_write_bytes("GroupBy", write_bytes as u64);
                 Profile::record_usize_profile(
                     ProfileStatisticsName::SpillWriteTime,
                     instant.elapsed().as_millis() as usize,
@@ -405,6 +411,7 @@ fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
                 0..0,
                 vec![],
                 partition_count,
+                encrypted,
             )))?;
 
             let ipc_fields = exchange_defines::spilled_ipc_fields();
@@ -425,6 +432,8 @@ fn spilling_group_by_payload<Method: HashMethodBounds>(
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
     let location = format!("{}/{}", location_prefix, unique_name);
+    let encryption = ctx.get_spill_encryption()?;
+    let encrypted = encryption.is_enabled();
 
     let mut write_size = 0;
     let mut write_data = Vec::with_capacity(256);
@@ -457,6 +466,8 @@ fn spilling_group_by_payload<Method: HashMethodBounds>(
             columns_data.push(column_data);
         }
 
+        let columns_data = encryption.encrypt_columns(columns_data)?;
+        write_size = old_write_size + columns_data.iter().map(Vec::len).sum::<usize>() as u64;
         write_data.push(columns_data);
         buckets_column_data.push(bucket as i64);
         data_range_end_column_data.push(write_size);
@@ -486,6 +497,7 @@ fn spilling_group_by_payload<Method: HashMethodBounds>(
             {
                 Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, 1);
                 Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, write_bytes);
+                metrics_inc_spill_write_bytes("GroupBy", write_bytes as u64);
                 Profile::record_usize_profile(
                     ProfileStatisticsName::SpillWriteTime,
                     instant.elapsed().as_millis() as usize,
@@ -523,6 +535,7 @@ fn spilling_group_by_payload<Method: HashMethodBounds>(
                 location.clone(),
                 0..0,
                 vec![],
+                encrypted,
             )))?;
 
             let ipc_fields = exchange_defines::spilled_ipc_fields();
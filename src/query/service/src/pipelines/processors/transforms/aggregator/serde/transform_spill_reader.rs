@@ -39,6 +39,7 @@ use crate::pipelines::processors::transforms::aggregator::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::BucketSpilledPayload;
 use crate::pipelines::processors::transforms::aggregator::SerializedPayload;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
+use crate::spillers::SpillEncryption;
 
 type DeserializingMeta<Method, V> = (AggregateMeta<Method, V>, VecDeque<Vec<u8>>);
 
@@ -47,6 +48,7 @@ pub struct TransformSpillReader<Method: HashMethodBounds, V: Send + Sync + 'stat
     output: Arc<OutputPort>,
 
     operator: Operator,
+    encryption: Arc<SpillEncryption>,
     deserialized_meta: Option<BlockMetaInfoPtr>,
     reading_meta: Option<AggregateMeta<Method, V>>,
     deserializing_meta: Option<DeserializingMeta<Method, V>>,
@@ -144,7 +146,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                     debug_assert!(read_data.len() == 1);
                     let data = read_data.pop_front().unwrap();
 
-                    self.deserialized_meta = Some(Box::new(Self::deserialize(payload, data)));
+                    self.deserialized_meta = Some(Box::new(self.deserialize(payload, data)?));
                 }
                 AggregateMeta::Partitioned { bucket, data } => {
                     let mut new_data = Vec::with_capacity(data.len());
@@ -153,7 +155,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                         if matches!(&meta, AggregateMeta::BucketSpilled(_)) {
                             if let AggregateMeta::BucketSpilled(payload) = meta {
                                 let data = read_data.pop_front().unwrap();
-                                new_data.push(Self::deserialize(payload, data));
+                                new_data.push(self.deserialize(payload, data)?);
                             }
 
                             continue;
@@ -282,6 +284,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformSpillReader<Me
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         operator: Operator,
+        encryption: Arc<SpillEncryption>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(TransformSpillReader::<
             Method,
@@ -290,13 +293,23 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformSpillReader<Me
             input,
             output,
             operator,
+            encryption,
             deserialized_meta: None,
             reading_meta: None,
             deserializing_meta: None,
         })))
     }
 
-    fn deserialize(payload: BucketSpilledPayload, data: Vec<u8>) -> AggregateMeta<Method, V> {
+    fn deserialize(
+        &self,
+        payload: BucketSpilledPayload,
+        data: Vec<u8>,
+    ) -> Result<AggregateMeta<Method, V>> {
+        let data = match payload.encrypted {
+            true => self.encryption.decrypt(data)?,
+            false => data,
+        };
+
         let mut begin = 0;
         let mut columns = Vec::with_capacity(payload.columns_layout.len());
 
@@ -305,11 +318,11 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformSpillReader<Me
             begin += column_layout as usize;
         }
 
-        AggregateMeta::<Method, V>::Serialized(SerializedPayload {
+        Ok(AggregateMeta::<Method, V>::Serialized(SerializedPayload {
             bucket: payload.bucket,
             data_block: DataBlock::new_from_columns(columns),
             max_partition_count: payload.max_partition_count,
-        })
+        }))
     }
 }
 
@@ -68,6 +68,15 @@ struct AggregateSettings {
     convert_threshold: usize,
     max_memory_usage: usize,
     spilling_bytes_threshold_per_proc: usize,
+    memory_pressure_ratio: u64,
+}
+
+impl AggregateSettings {
+    // Spill if the memory usage reaches the spilling threshold, or the node is under memory pressure.
+    fn exceeds_memory_usage(&self) -> bool {
+        GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.max_memory_usage
+            || GLOBAL_MEM_STAT.is_memory_pressure(self.memory_pressure_ratio)
+    }
 }
 
 impl TryFrom<Arc<QueryContext>> for AggregateSettings {
@@ -100,6 +109,7 @@ impl TryFrom<Arc<QueryContext>> for AggregateSettings {
                 0 => max_memory_usage / max_threads,
                 spilling_bytes_threshold_per_proc => spilling_bytes_threshold_per_proc,
             },
+            memory_pressure_ratio: settings.get_memory_pressure_ratio()?,
         })
     }
 }
@@ -339,7 +349,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
                 && (matches!(&self.hash_table, HashTable::HashTable(cell)
                     if cell.len() >= self.settings.convert_threshold ||
                         cell.allocated_bytes() >= self.settings.spilling_bytes_threshold_per_proc ||
-                        GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.settings.max_memory_usage))
+                        self.settings.exceeds_memory_usage()))
             {
                 if let HashTable::HashTable(cell) = std::mem::take(&mut self.hash_table) {
                     self.hash_table = HashTable::PartitionedHashTable(
@@ -350,8 +360,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
 
             if !is_new_agg
                 && (matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc)
-                    || GLOBAL_MEM_STAT.get_memory_usage() as usize
-                        >= self.settings.max_memory_usage)
+                    || self.settings.exceeds_memory_usage())
             {
                 if let HashTable::PartitionedHashTable(v) = std::mem::take(&mut self.hash_table) {
                     let _dropper = v._dropper.clone();
@@ -375,7 +384,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
 
         if is_new_agg
             && (matches!(&self.hash_table, HashTable::AggregateHashTable(cell) if cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc
-            || GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.settings.max_memory_usage))
+            || self.settings.exceeds_memory_usage()))
         {
             if let HashTable::AggregateHashTable(v) = std::mem::take(&mut self.hash_table) {
                 let group_types = v.payload.group_types.clone();
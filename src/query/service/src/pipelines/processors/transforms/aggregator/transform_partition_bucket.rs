@@ -48,6 +48,7 @@ use crate::pipelines::processors::transforms::aggregator::TransformGroupBySpillR
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::group_by::KeysColumnIter;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
+use crate::sessions::QueryContext;
 
 static SINGLE_LEVEL_BUCKET_NUM: isize = -1;
 
@@ -439,6 +440,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> Processor
 }
 
 pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync + 'static>(
+    ctx: Arc<QueryContext>,
     method: Method,
     pipeline: &mut Pipeline,
     params: Arc<AggregatorParams>,
@@ -460,11 +462,17 @@ pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync +
         pipeline.try_resize(input_nums)?;
 
         let operator = DataOperator::instance().operator();
+        let encryption = ctx.get_spill_encryption()?;
         pipeline.add_transform(|input, output| {
             let operator = operator.clone();
+            let encryption = encryption.clone();
             match params.aggregate_functions.is_empty() {
-                true => TransformGroupBySpillReader::<Method>::create(input, output, operator),
-                false => TransformAggregateSpillReader::<Method>::create(input, output, operator),
+                true => TransformGroupBySpillReader::<Method>::create(
+                    input, output, operator, encryption,
+                ),
+                false => TransformAggregateSpillReader::<Method>::create(
+                    input, output, operator, encryption,
+                ),
             }
         })?;
 
@@ -502,11 +510,17 @@ pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync +
         pipeline.try_resize(input_nums)?;
 
         let operator = DataOperator::instance().operator();
+        let encryption = ctx.get_spill_encryption()?;
         pipeline.add_transform(|input, output| {
             let operator = operator.clone();
+            let encryption = encryption.clone();
             match params.aggregate_functions.is_empty() {
-                true => TransformGroupBySpillReader::<Method>::create(input, output, operator),
-                false => TransformAggregateSpillReader::<Method>::create(input, output, operator),
+                true => TransformGroupBySpillReader::<Method>::create(
+                    input, output, operator, encryption,
+                ),
+                false => TransformAggregateSpillReader::<Method>::create(
+                    input, output, operator, encryption,
+                ),
             }
         })?;
 
@@ -0,0 +1,364 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_catalog::plan::DataSourceInfo;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::executor::physical_plans::HashJoin;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::Event;
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::processors::ProcessorPtr;
+
+/// Identifies a cached build side of hash join.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashJoinBuildCacheKey {
+    /// The session id if it's cached for the session, empty if it's shared by the warehouse.
+    pub scope: String,
+    /// The fingerprint of the build side plan, qualified by the user, the roles and the
+    /// function context settings of the query.
+    pub name: String,
+    /// The snapshots of the tables read by the build side.
+    pub version: String,
+}
+
+impl HashJoinBuildCacheKey {
+    /// Create the key if the build side only reads fuse tables with deterministic filters
+    /// and expressions, so the output only changes with the table snapshots. The build side
+    /// is only shared by the queries with the same `qualifier`, see
+    /// `QueryContext::query_result_qualifier`.
+    pub fn try_create(join: &HashJoin, scope: String, qualifier: String) -> Option<Self> {
+        if join.need_hold_hash_table || join.build_side_cache_info.is_some() {
+            return None;
+        }
+        let mut name = format!("/* {} */ ", qualifier);
+        let mut version = String::new();
+        Self::collect(&join.build, &mut name, &mut version).then_some(HashJoinBuildCacheKey {
+            scope,
+            name,
+            version,
+        })
+    }
+
+    fn collect(plan: &PhysicalPlan, name: &mut String, version: &mut String) -> bool {
+        match plan {
+            PhysicalPlan::TableScan(scan) => {
+                let DataSourceInfo::TableSource(table_info) = &scan.source.source_info else {
+                    return false;
+                };
+                if !table_info.engine().eq_ignore_ascii_case("FUSE")
+                    || scan.source.tbl_args.is_some()
+                    || scan.source.base_block_ids.is_some()
+                    || scan.source.update_stream_columns
+                    || scan
+                        .source
+                        .push_downs
+                        .as_ref()
+                        .is_some_and(|push_downs| !push_downs.is_deterministic)
+                {
+                    return false;
+                }
+                name.push_str(&format!(
+                    "TableScan({}, {}, {:?}, {:?}, {:?}, {:?})",
+                    table_info.catalog(),
+                    table_info.ident.table_id,
+                    scan.name_mapping,
+                    scan.internal_column,
+                    scan.source.push_downs,
+                    scan.source.data_mask_policy,
+                ));
+                version.push_str(&format!(
+                    "{}:{}:{};",
+                    table_info.ident.table_id,
+                    table_info.ident.seq,
+                    table_info
+                        .options()
+                        .get(OPT_KEY_SNAPSHOT_LOCATION)
+                        .map(String::as_str)
+                        .unwrap_or_default(),
+                ));
+                true
+            }
+            PhysicalPlan::Filter(filter) => {
+                if !filter.predicates.iter().all(|predicate| {
+                    predicate
+                        .as_expr(&BUILTIN_FUNCTIONS)
+                        .is_deterministic(&BUILTIN_FUNCTIONS)
+                }) {
+                    return false;
+                }
+                let mut projections = filter.projections.iter().collect::<Vec<_>>();
+                projections.sort();
+                name.push_str(&format!(
+                    "Filter({:?}, {:?}, ",
+                    filter.predicates, projections
+                ));
+                let res = Self::collect(&filter.input, name, version);
+                name.push(')');
+                res
+            }
+            PhysicalPlan::EvalScalar(eval_scalar) => {
+                if !eval_scalar.exprs.iter().all(|(expr, _)| {
+                    expr.as_expr(&BUILTIN_FUNCTIONS)
+                        .is_deterministic(&BUILTIN_FUNCTIONS)
+                }) {
+                    return false;
+                }
+                let mut projections = eval_scalar.projections.iter().collect::<Vec<_>>();
+                projections.sort();
+                name.push_str(&format!(
+                    "EvalScalar({:?}, {:?}, ",
+                    eval_scalar.exprs, projections
+                ));
+                let res = Self::collect(&eval_scalar.input, name, version);
+                name.push(')');
+                res
+            }
+            _ => false,
+        }
+    }
+}
+
+struct CachedBuildSide {
+    key: HashJoinBuildCacheKey,
+    blocks: Arc<Vec<DataBlock>>,
+    bytes: usize,
+}
+
+/// Caches the build sides of hash joins across queries. It's shared by all the sessions of
+/// the node and holds at most `capacity` bytes, the build sides cached for a session are
+/// only visible to the session and are removed once the session ends.
+pub struct HashJoinBuildCache {
+    capacity: usize,
+    // Ordered from the least recently used.
+    entries: Mutex<VecDeque<CachedBuildSide>>,
+}
+
+impl HashJoinBuildCache {
+    pub fn init(capacity: usize) -> Result<()> {
+        GlobalInstance::set(Arc::new(HashJoinBuildCache::create(capacity)));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<HashJoinBuildCache> {
+        GlobalInstance::get()
+    }
+
+    pub fn create(capacity: usize) -> HashJoinBuildCache {
+        HashJoinBuildCache {
+            capacity,
+            entries: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the cached build side, the stale one built on other snapshots is dropped.
+    pub fn get(&self, key: &HashJoinBuildCacheKey) -> Option<Arc<Vec<DataBlock>>> {
+        let mut entries = self.entries.lock();
+        let position = entries
+            .iter()
+            .position(|entry| entry.key.scope == key.scope && entry.key.name == key.name)?;
+        let entry = entries.remove(position)?;
+        if entry.key.version != key.version {
+            return None;
+        }
+        let blocks = entry.blocks.clone();
+        entries.push_back(entry);
+        Some(blocks)
+    }
+
+    /// Put the complete build side, the least recently used ones are evicted to keep the
+    /// cache within the capacity.
+    pub fn put(&self, key: HashJoinBuildCacheKey, blocks: Vec<DataBlock>) {
+        let bytes = blocks
+            .iter()
+            .map(|block| block.memory_size())
+            .sum::<usize>();
+        if bytes > self.capacity {
+            return;
+        }
+        let mut entries = self.entries.lock();
+        entries.retain(|entry| entry.key.scope != key.scope || entry.key.name != key.name);
+        let mut total_bytes = entries.iter().map(|entry| entry.bytes).sum::<usize>();
+        while total_bytes + bytes > self.capacity {
+            match entries.pop_front() {
+                Some(entry) => total_bytes -= entry.bytes,
+                None => break,
+            }
+        }
+        entries.push_back(CachedBuildSide {
+            key,
+            blocks: Arc::new(blocks),
+            bytes,
+        });
+    }
+
+    /// Remove the build sides cached for the session.
+    pub fn remove_scope(&self, scope: &str) {
+        if scope.is_empty() {
+            return;
+        }
+        self.entries.lock().retain(|entry| entry.key.scope != scope);
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.entries.lock().iter().map(|entry| entry.bytes).sum()
+    }
+}
+
+/// Collects the output of the build side from all the pipes, and puts it into the cache
+/// once all of them have read their input to the end. Nothing is cached if any pipe is
+/// finished early or the build side is larger than `max_bytes`.
+pub struct HashJoinBuildCacheCollector {
+    cache: Arc<HashJoinBuildCache>,
+    key: HashJoinBuildCacheKey,
+    max_bytes: usize,
+    blocks: Mutex<Vec<DataBlock>>,
+    bytes: AtomicUsize,
+    discarded: AtomicBool,
+    running_pipes: AtomicUsize,
+}
+
+impl HashJoinBuildCacheCollector {
+    pub fn create(
+        cache: Arc<HashJoinBuildCache>,
+        key: HashJoinBuildCacheKey,
+        max_bytes: usize,
+        pipes: usize,
+    ) -> Arc<Self> {
+        let max_bytes = max_bytes.min(cache.capacity());
+        Arc::new(HashJoinBuildCacheCollector {
+            cache,
+            key,
+            max_bytes,
+            blocks: Mutex::new(vec![]),
+            bytes: AtomicUsize::new(0),
+            discarded: AtomicBool::new(false),
+            running_pipes: AtomicUsize::new(pipes),
+        })
+    }
+
+    pub fn collect(&self, data_block: &DataBlock) {
+        if self.discarded.load(Ordering::Relaxed) {
+            return;
+        }
+        let bytes = self
+            .bytes
+            .fetch_add(data_block.memory_size(), Ordering::Relaxed)
+            + data_block.memory_size();
+        if bytes > self.max_bytes {
+            // The build side is too large to be cached.
+            self.discard();
+            return;
+        }
+        self.blocks.lock().push(data_block.clone());
+    }
+
+    /// The pipe has read its input to the end.
+    pub fn finish(&self) {
+        if self.running_pipes.fetch_sub(1, Ordering::AcqRel) != 1
+            || self.discarded.load(Ordering::Relaxed)
+        {
+            return;
+        }
+        let blocks = std::mem::take(&mut *self.blocks.lock());
+        self.cache.put(self.key.clone(), blocks);
+    }
+
+    /// The pipe is finished before reading its input to the end, so the collected blocks
+    /// are only a part of the build side.
+    pub fn discard(&self) {
+        self.discarded.store(true, Ordering::Relaxed);
+        self.blocks.lock().clear();
+    }
+}
+
+/// Passes the blocks of the build side through to the hash join build sink and collects
+/// them for the cache. Unlike the `Transform`, it tells the end of the input from the
+/// output being finished early.
+pub struct TransformHashJoinBuildCache {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    collector: Arc<HashJoinBuildCacheCollector>,
+}
+
+impl TransformHashJoinBuildCache {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        collector: Arc<HashJoinBuildCacheCollector>,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Box::new(TransformHashJoinBuildCache {
+            input,
+            output,
+            collector,
+        }))
+    }
+}
+
+impl Processor for TransformHashJoinBuildCache {
+    fn name(&self) -> String {
+        "TransformHashJoinBuildCache".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.collector.discard();
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.has_data() {
+            let data_block = self.input.pull_data().unwrap()?;
+            self.collector.collect(&data_block);
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.is_finished() {
+            self.collector.finish();
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+}
@@ -112,6 +112,15 @@ impl BuildSpillState {
             );
             return Ok(true);
         }
+
+        // Check if the node is under memory pressure.
+        if GLOBAL_MEM_STAT.is_memory_pressure(self.build_state.memory_pressure_ratio) {
+            info!(
+                "need to spill due to memory pressure, global memory usage {:?}",
+                total_gb
+            );
+            return Ok(true);
+        }
         Ok(false)
     }
 }
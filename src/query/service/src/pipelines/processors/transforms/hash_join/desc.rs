@@ -33,6 +33,7 @@ pub struct MarkJoinDesc {
 }
 
 pub struct HashJoinDesc {
+    pub(crate) plan_id: u32,
     pub(crate) build_keys: Vec<Expr>,
     pub(crate) probe_keys: Vec<Expr>,
     pub(crate) is_null_equal: Vec<bool>,
@@ -78,6 +79,7 @@ impl HashJoinDesc {
             .collect();
 
         Ok(HashJoinDesc {
+            plan_id: join.plan_id,
             join_type: join.join_type.clone(),
             build_keys,
             probe_keys,
@@ -31,6 +31,7 @@ use databend_common_expression::arrow::and_validities;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDomain;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::Column;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::ColumnVec;
@@ -65,14 +66,18 @@ use xorf::BinaryFuse16;
 
 use crate::pipelines::processors::transforms::hash_join::common::wrap_true_validity;
 use crate::pipelines::processors::transforms::hash_join::desc::MARKER_KIND_FALSE;
+use crate::pipelines::processors::transforms::hash_join::semi_join_reduction::merge_semi_join_reduction_keys;
 use crate::pipelines::processors::transforms::hash_join::util::dedup_build_key_column;
 use crate::pipelines::processors::transforms::hash_join::util::hash_by_method;
 use crate::pipelines::processors::transforms::hash_join::util::inlist_filter;
 use crate::pipelines::processors::transforms::hash_join::util::min_max_filter;
 use crate::pipelines::processors::transforms::hash_join::FixedKeyHashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::HashJoinHashTable;
+use crate::pipelines::processors::transforms::hash_join::SemiJoinReductionMeta;
 use crate::pipelines::processors::transforms::hash_join::SerializerHashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::SingleBinaryHashJoinHashTable;
+use crate::pipelines::processors::transforms::hash_join::SkewedJoinKeys;
+use crate::pipelines::processors::transforms::hash_join::SkewedJoinKeysMeta;
 use crate::pipelines::processors::HashJoinState;
 use crate::sessions::QueryContext;
 
@@ -118,6 +123,8 @@ pub struct HashJoinBuildState {
     pub(crate) max_memory_usage: usize,
     // Spilling threshold for each processor
     pub(crate) spilling_threshold_per_proc: usize,
+    // Spill once the node is under memory pressure, see `memory_pressure_ratio`
+    pub(crate) memory_pressure_ratio: u64,
     /// Spilled partition set, it contains all spilled_partition_sets from all processors
     pub(crate) spilled_partition_set: RwLock<HashSet<u8>>,
 
@@ -126,6 +133,11 @@ pub struct HashJoinBuildState {
     pub(crate) enable_min_max_runtime_filter: bool,
     /// Need to open runtime filter setting.
     pub(crate) enable_bloom_runtime_filter: bool,
+
+    /// Hot keys reported by the build side senders of a skewed shuffle join.
+    pub(crate) skewed_join_keys: Arc<SkewedJoinKeys>,
+    /// Build keys reported by all the build side senders of a shuffle join.
+    pub(crate) semi_join_reduction_reports: Mutex<Vec<SemiJoinReductionMeta>>,
 }
 
 impl HashJoinBuildState {
@@ -162,9 +174,11 @@ impl HashJoinBuildState {
                     hash_join_state.hash_join_desc.enable_bloom_runtime_filter;
             }
         }
+        let skewed_join_keys = ctx.get_skewed_join_keys(hash_join_state.hash_join_desc.plan_id);
         let chunk_size_limit = ctx.get_settings().get_max_block_size()? as usize * 16;
         let (max_memory_usage, spilling_threshold_per_proc) =
             Self::max_memory_usage(ctx.clone(), num_threads)?;
+        let memory_pressure_ratio = ctx.get_settings().get_memory_pressure_ratio()?;
         Ok(Arc::new(Self {
             ctx: ctx.clone(),
             func_ctx,
@@ -186,7 +200,10 @@ impl HashJoinBuildState {
             enable_inlist_runtime_filter,
             enable_min_max_runtime_filter,
             spilling_threshold_per_proc,
+            memory_pressure_ratio,
             spilled_partition_set: Default::default(),
+            skewed_join_keys,
+            semi_join_reduction_reports: Default::default(),
         }))
     }
 
@@ -215,6 +232,25 @@ impl HashJoinBuildState {
         Ok((max_memory_usage, spilling_threshold_per_proc))
     }
 
+    /// Consume the report of a shuffle sender, return false if `block` is not one.
+    pub(crate) fn try_add_exchange_report(&self, block: &DataBlock) -> bool {
+        let Some(meta) = block.get_meta() else {
+            return false;
+        };
+
+        if let Some(meta) = SkewedJoinKeysMeta::downcast_ref_from(meta) {
+            self.skewed_join_keys.add_report(meta);
+            return true;
+        }
+
+        if let Some(meta) = SemiJoinReductionMeta::downcast_ref_from(meta) {
+            self.semi_join_reduction_reports.lock().push(meta.clone());
+            return true;
+        }
+
+        false
+    }
+
     /// Add input `DataBlock` to `hash_join_state.row_space`.
     pub fn build(&self, input: DataBlock) -> Result<()> {
         let mut buffer = self.hash_join_state.row_space.buffer.write();
@@ -300,6 +336,8 @@ impl HashJoinBuildState {
     pub(crate) fn row_space_build_done(&self) -> Result<()> {
         let old_count = self.row_space_builders.fetch_sub(1, Ordering::Relaxed);
         if old_count == 1 {
+            self.skewed_join_keys.finalize();
+            self.add_semi_join_reduction_filter()?;
             {
                 let mut buffer = self.hash_join_state.row_space.buffer.write();
                 if !buffer.is_empty() {
@@ -661,6 +699,27 @@ impl HashJoinBuildState {
         }
         *chunk = DataBlock::new(block_entries, chunk.num_rows());
 
+        // The NULL build keys of the mark and null aware anti joins are recorded before the
+        // chunk is skipped for having only NULL keys, e.g. `x NOT IN (SELECT NULL)`.
+        if matches!(
+            self.hash_join_state.hash_join_desc.join_type,
+            JoinType::RightMark | JoinType::LeftAntiNullAware
+        ) && !_has_null
+            && !keys_columns.is_empty()
+        {
+            let (is_all_null, validity) = keys_columns[0].validity();
+            if is_all_null || validity.is_some_and(|validity| validity.unset_bits() > 0) {
+                _has_null = true;
+                let mut has_null_ref = self
+                    .hash_join_state
+                    .hash_join_desc
+                    .marker_join_desc
+                    .has_null
+                    .write();
+                *has_null_ref = true;
+            }
+        }
+
         let is_null_equal = &self.hash_join_state.hash_join_desc.is_null_equal;
         let may_null = build_keys.iter().any(|expr| {
             let ty = expr.data_type();
@@ -696,33 +755,11 @@ impl HashJoinBuildState {
             }
         };
 
-        match self.hash_join_state.hash_join_desc.join_type {
-            JoinType::LeftMark => {
-                let markers = &mut build_state.mark_scan_map[chunk_index];
-                self.hash_join_state.init_markers(
-                    (&keys_columns).into(),
-                    chunk.num_rows(),
-                    markers,
-                );
-            }
-            JoinType::RightMark => {
-                if !_has_null && !keys_columns.is_empty() {
-                    if let Some(validity) = keys_columns[0].validity().1 {
-                        if validity.unset_bits() > 0 {
-                            _has_null = true;
-                            let mut has_null_ref = self
-                                .hash_join_state
-                                .hash_join_desc
-                                .marker_join_desc
-                                .has_null
-                                .write();
-                            *has_null_ref = true;
-                        }
-                    }
-                }
-            }
-            _ => {}
-        };
+        if self.hash_join_state.hash_join_desc.join_type == JoinType::LeftMark {
+            let markers = &mut build_state.mark_scan_map[chunk_index];
+            self.hash_join_state
+                .init_markers((&keys_columns).into(), chunk.num_rows(), markers);
+        }
 
         keys_columns
             .iter_mut()
@@ -870,6 +907,42 @@ impl HashJoinBuildState {
         Ok(())
     }
 
+    /// Filter the probe side scan of this node with the build keys of all the nodes.
+    fn add_semi_join_reduction_filter(&self) -> Result<()> {
+        let reports = std::mem::take(&mut *self.semi_join_reduction_reports.lock());
+        if reports.is_empty() {
+            return Ok(());
+        }
+        let Some(keys) = merge_semi_join_reduction_keys(&reports) else {
+            return Ok(());
+        };
+
+        for ((build_key, probe_key), values) in self
+            .hash_join_state
+            .hash_join_desc
+            .build_keys
+            .iter()
+            .zip(self.hash_join_state.hash_join_desc.probe_keys_rt.iter())
+            .zip(keys.into_iter())
+        {
+            let Some((probe_key, table_index)) = probe_key else {
+                continue;
+            };
+            let mut builder = ColumnBuilder::with_capacity(build_key.data_type(), values.len());
+            for value in values.iter() {
+                builder.push(value.as_ref());
+            }
+            let build_column = Value::Scalar(Scalar::Array(builder.build()));
+            if let Some(filter) = inlist_filter(probe_key, build_column)? {
+                info!("semi join reduction filter: {:?}", filter.sql_display());
+                let mut runtime_filter = RuntimeFilterInfo::default();
+                runtime_filter.add_inlist(filter);
+                self.ctx.set_runtime_filter((*table_index, runtime_filter));
+            }
+        }
+        Ok(())
+    }
+
     fn bloom_runtime_filter(
         &self,
         data_blocks: &[DataBlock],
@@ -162,6 +162,7 @@ impl HashJoinProbeState {
             JoinType::Inner
             | JoinType::LeftSemi
             | JoinType::LeftAnti
+            | JoinType::LeftAntiNullAware
             | JoinType::RightSemi
             | JoinType::RightAnti
             | JoinType::Left
@@ -180,7 +181,7 @@ impl HashJoinProbeState {
         mut input: DataBlock,
         probe_state: &mut ProbeState,
     ) -> Result<Vec<DataBlock>> {
-        let input_num_rows = input.num_rows();
+        let mut input_num_rows = input.num_rows();
         let mut _nullable_data_block = None;
         let evaluator = if matches!(
             self.hash_join_state.hash_join_desc.join_type,
@@ -231,7 +232,7 @@ impl HashJoinProbeState {
         }
 
         let is_null_equal = &self.hash_join_state.hash_join_desc.is_null_equal;
-        let valids = if !Self::check_for_eliminate_valids(
+        let mut valids = if !Self::check_for_eliminate_valids(
             self.hash_join_state.hash_join_desc.from_correlated_subquery,
             &self.hash_join_state.hash_join_desc.join_type,
         ) && probe_keys.iter().any(|expr| {
@@ -264,7 +265,6 @@ impl HashJoinProbeState {
             .for_each(|(col, _)| {
                 *col = col.remove_nullable();
             });
-        let probe_keys = (&keys_columns).into();
 
         if self.hash_join_state.hash_join_desc.join_type != JoinType::LeftMark {
             input = input.project(&self.probe_projections);
@@ -274,7 +274,11 @@ impl HashJoinProbeState {
         if self.hash_join_state.fast_return.load(Ordering::Relaxed)
             && matches!(
                 self.hash_join_state.hash_join_desc.join_type,
-                JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAnti
+                JoinType::Left
+                    | JoinType::LeftSingle
+                    | JoinType::Full
+                    | JoinType::LeftAnti
+                    | JoinType::LeftAntiNullAware
             )
         {
             return self.left_fast_return(
@@ -284,6 +288,31 @@ impl HashJoinProbeState {
             );
         }
 
+        // `x NOT IN (subquery)` is never true once the subquery returns a NULL,
+        // and is unknown for a NULL `x` when the subquery is not empty.
+        if self.hash_join_state.hash_join_desc.join_type == JoinType::LeftAntiNullAware {
+            if *self
+                .hash_join_state
+                .hash_join_desc
+                .marker_join_desc
+                .has_null
+                .read()
+            {
+                return Ok(vec![]);
+            }
+            if let Some(probe_valids) = valids.take() {
+                if probe_valids.unset_bits() > 0 {
+                    input = input.filter_with_bitmap(&probe_valids)?;
+                    keys_columns = keys_columns
+                        .iter()
+                        .map(|col| col.filter(&probe_valids))
+                        .collect();
+                    input_num_rows = input.num_rows();
+                }
+            }
+        }
+        let probe_keys = (&keys_columns).into();
+
         // Adaptive early filtering.
         // Thanks to the **adaptive** execution strategy of early filtering, we don't experience a performance decrease
         // when all keys have matches. This allows us to achieve the same performance as before.
@@ -386,7 +415,11 @@ impl HashJoinProbeState {
     pub fn need_unmatched_selection(join_type: &JoinType, with_conjunction: bool) -> bool {
         matches!(
             join_type,
-            JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAnti
+            JoinType::Left
+                | JoinType::LeftSingle
+                | JoinType::Full
+                | JoinType::LeftAnti
+                | JoinType::LeftAntiNullAware
         ) && !with_conjunction
     }
 
@@ -151,7 +151,10 @@ impl HashJoinState {
         let (build_done_watcher, _build_done_dummy_receiver) = watch::channel(0);
         let (continue_build_watcher, _continue_build_dummy_receiver) = watch::channel(false);
         let mut enable_spill = false;
-        if ctx.get_settings().get_join_spilling_memory_ratio()? != 0 {
+        // Null aware anti join needs to see the whole build side to know if it has NULL keys.
+        if ctx.get_settings().get_join_spilling_memory_ratio()? != 0
+            && hash_join_desc.join_type != JoinType::LeftAntiNullAware
+        {
             enable_spill = true;
         }
         let column_map = if let Some((_, column_map)) = build_side_cache_info {
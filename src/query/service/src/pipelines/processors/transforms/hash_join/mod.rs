@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod build_cache;
 mod build_spill;
 mod build_state;
 mod common;
@@ -25,11 +26,17 @@ mod probe_spill;
 mod probe_state;
 mod result_blocks;
 pub(crate) mod row;
+mod semi_join_reduction;
+mod skew;
 mod spill_common;
 mod transform_hash_join_build;
 mod transform_hash_join_probe;
 mod util;
 
+pub use build_cache::HashJoinBuildCache;
+pub use build_cache::HashJoinBuildCacheCollector;
+pub use build_cache::HashJoinBuildCacheKey;
+pub use build_cache::TransformHashJoinBuildCache;
 pub use build_spill::BuildSpillState;
 pub use desc::HashJoinDesc;
 pub use hash_join_build_state::HashJoinBuildState;
@@ -37,6 +44,10 @@ pub use hash_join_probe_state::HashJoinProbeState;
 pub use hash_join_state::*;
 pub use probe_spill::ProbeSpillState;
 pub use probe_state::ProbeState;
+pub use semi_join_reduction::SemiJoinReductionKeys;
+pub use semi_join_reduction::SemiJoinReductionMeta;
+pub use skew::SkewedJoinKeys;
+pub use skew::SkewedJoinKeysMeta;
 pub use transform_hash_join_build::HashJoinBuildStep;
 pub use transform_hash_join_build::TransformHashJoinBuild;
 pub use transform_hash_join_probe::TransformHashJoinProbe;
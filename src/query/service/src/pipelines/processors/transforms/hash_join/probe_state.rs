@@ -92,7 +92,11 @@ impl ProbeState {
         };
         let probe_unmatched_indexes = if matches!(
             &join_type,
-            JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAnti
+            JoinType::Left
+                | JoinType::LeftSingle
+                | JoinType::Full
+                | JoinType::LeftAnti
+                | JoinType::LeftAntiNullAware
         ) && !with_conjunction
         {
             Some(vec![0; max_block_size])
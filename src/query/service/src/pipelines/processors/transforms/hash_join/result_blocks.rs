@@ -86,7 +86,7 @@ impl HashJoinProbeState {
                     self.left_semi_join_with_conjunct(input, keys, hash_table, probe_state)
                 }
             }
-            JoinType::LeftAnti => {
+            JoinType::LeftAnti | JoinType::LeftAntiNullAware => {
                 if no_other_predicate {
                     self.left_anti_join(input, keys, hash_table, probe_state)
                 } else {
@@ -130,7 +130,10 @@ impl HashJoinProbeState {
         is_probe_projected: bool,
         true_validity: &Bitmap,
     ) -> Result<Vec<DataBlock>> {
-        if self.hash_join_state.hash_join_desc.join_type == JoinType::LeftAnti {
+        if matches!(
+            self.hash_join_state.hash_join_desc.join_type,
+            JoinType::LeftAnti | JoinType::LeftAntiNullAware
+        ) {
             return Ok(vec![input]);
         }
         let input_num_rows = input.num_rows();
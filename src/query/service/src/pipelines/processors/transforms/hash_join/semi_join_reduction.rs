@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::BlockMetaInfoPtr;
+use databend_common_expression::Column;
+use databend_common_expression::Scalar;
+
+use crate::pipelines::processors::transforms::hash_join::hash_join_build_state::INLIST_RUNTIME_FILTER_THRESHOLD;
+
+/// Sent by every build-side sender of a shuffle hash join to all the destinations,
+/// once it has sent all its rows.
+///
+/// `keys` holds the distinct values of each build key the sender has seen,
+/// it is `None` if there were too many of them to filter the probe side with.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SemiJoinReductionMeta {
+    pub keys: Option<Vec<Vec<Scalar>>>,
+}
+
+impl SemiJoinReductionMeta {
+    pub fn create(keys: Option<Vec<Vec<Scalar>>>) -> BlockMetaInfoPtr {
+        Box::new(SemiJoinReductionMeta { keys })
+    }
+}
+
+#[typetag::serde(name = "semi_join_reduction")]
+impl BlockMetaInfo for SemiJoinReductionMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        SemiJoinReductionMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Distinct build key values collected by a build-side sender.
+pub struct SemiJoinReductionKeys {
+    keys: Option<Vec<HashSet<Scalar>>>,
+}
+
+impl SemiJoinReductionKeys {
+    pub fn create(num_keys: usize) -> SemiJoinReductionKeys {
+        SemiJoinReductionKeys {
+            keys: Some(vec![HashSet::new(); num_keys]),
+        }
+    }
+
+    /// Add the values of the build keys, NULLs never match so they are skipped.
+    pub fn add(&mut self, columns: &[Column]) {
+        let Some(keys) = &mut self.keys else {
+            return;
+        };
+
+        for (values, column) in keys.iter_mut().zip(columns.iter()) {
+            for value in column.iter() {
+                if !value.is_null() {
+                    values.insert(value.to_owned());
+                }
+            }
+        }
+
+        if keys
+            .iter()
+            .any(|values| values.len() >= INLIST_RUNTIME_FILTER_THRESHOLD)
+        {
+            self.keys = None;
+        }
+    }
+
+    pub fn take_meta(&mut self) -> BlockMetaInfoPtr {
+        SemiJoinReductionMeta::create(self.keys.take().map(|keys| {
+            keys.into_iter()
+                .map(|values| values.into_iter().collect())
+                .collect()
+        }))
+    }
+}
+
+/// Merge the keys reported by all the senders, returns `None` if any of them gave up.
+pub fn merge_semi_join_reduction_keys(
+    reports: &[SemiJoinReductionMeta],
+) -> Option<Vec<HashSet<Scalar>>> {
+    let mut merged: Option<Vec<HashSet<Scalar>>> = None;
+    for report in reports {
+        let keys = report.keys.as_ref()?;
+        let merged = merged.get_or_insert_with(|| vec![HashSet::new(); keys.len()]);
+        for (values, report_values) in merged.iter_mut().zip(keys.iter()) {
+            values.extend(report_values.iter().cloned());
+        }
+    }
+
+    merged.filter(|keys| {
+        keys.iter()
+            .all(|values| values.len() < INLIST_RUNTIME_FILTER_THRESHOLD)
+    })
+}
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::BlockMetaInfoPtr;
+use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
+
+const PRESENCE_BITS: u64 = 1 << 20;
+
+/// Sent by every build-side sender of a skewed join shuffle, once it has sent all its rows.
+///
+/// `replicated` holds the key hashes whose rows were copied to every partition,
+/// `presence` is a bitset over the hashes of all rows the sender has seen.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SkewedJoinKeysMeta {
+    pub replicated: Vec<u64>,
+    pub presence: Vec<u64>,
+}
+
+impl SkewedJoinKeysMeta {
+    pub fn create(replicated: Vec<u64>, presence: Vec<u64>) -> BlockMetaInfoPtr {
+        Box::new(SkewedJoinKeysMeta {
+            replicated,
+            presence,
+        })
+    }
+
+    pub fn set_presence(presence: &mut Vec<u64>, hash: u64) {
+        if presence.is_empty() {
+            presence.resize((PRESENCE_BITS / 64) as usize, 0);
+        }
+
+        let bit = hash % PRESENCE_BITS;
+        presence[(bit / 64) as usize] |= 1 << (bit % 64);
+    }
+
+    fn may_contain(&self, hash: u64) -> bool {
+        if self.presence.is_empty() {
+            return false;
+        }
+
+        let bit = hash % PRESENCE_BITS;
+        self.presence[(bit / 64) as usize] & (1 << (bit % 64)) != 0
+    }
+}
+
+#[typetag::serde(name = "skewed_join_keys")]
+impl BlockMetaInfo for SkewedJoinKeysMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        SkewedJoinKeysMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Node-local agreement on which join keys the probe side may spread across partitions.
+///
+/// A key hash is hot only if every build-side sender either replicated all of its rows
+/// or never saw it, so each partition holds every build row of the key.
+#[derive(Default)]
+pub struct SkewedJoinKeys {
+    reports: Mutex<Vec<SkewedJoinKeysMeta>>,
+    hot_keys: OnceCell<HashSet<u64>>,
+}
+
+impl SkewedJoinKeys {
+    pub fn add_report(&self, meta: &SkewedJoinKeysMeta) {
+        self.reports.lock().push(meta.clone());
+    }
+
+    /// Called once the build side of this node has received all of its input.
+    pub fn finalize(&self) {
+        self.hot_keys.get_or_init(|| {
+            let reports = self.reports.lock();
+            let replicated = reports
+                .iter()
+                .map(|report| report.replicated.iter().copied().collect::<HashSet<_>>())
+                .collect::<Vec<_>>();
+
+            replicated
+                .iter()
+                .flatten()
+                .copied()
+                .filter(|hash| {
+                    reports
+                        .iter()
+                        .zip(replicated.iter())
+                        .all(|(report, keys)| keys.contains(hash) || !report.may_contain(*hash))
+                })
+                .collect()
+        });
+    }
+
+    pub fn hot_keys(&self) -> Option<&HashSet<u64>> {
+        self.hot_keys.get().filter(|keys| !keys.is_empty())
+    }
+}
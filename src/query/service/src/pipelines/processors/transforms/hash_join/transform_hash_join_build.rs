@@ -149,7 +149,12 @@ impl Processor for TransformHashJoinBuild {
 
                 match self.input_port.has_data() {
                     true => {
-                        self.input_data = Some(self.input_port.pull_data().unwrap()?);
+                        let data_block = self.input_port.pull_data().unwrap()?;
+                        if self.build_state.try_add_exchange_report(&data_block) {
+                            self.input_port.set_need_data();
+                            return Ok(Event::NeedData);
+                        }
+                        self.input_data = Some(data_block);
                         if self.spill_handler.check_need_spill(&mut self.input_data)? {
                             self.step = HashJoinBuildStep::Spill;
                             self.step_logs.push(HashJoinBuildStep::Spill);
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::collections::VecDeque;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
@@ -29,16 +31,39 @@ use crate::sessions::QueryContext;
 #[derive(Clone)]
 pub enum CacheSourceState {
     HashJoinCacheState(HashJoinCacheState),
+    HashJoinBuildCacheState(HashJoinBuildCacheState),
 }
 
 impl CacheSourceState {
     fn next_data_block(&mut self) -> Option<DataBlock> {
         match self {
             CacheSourceState::HashJoinCacheState(state) => state.next_data_block(),
+            CacheSourceState::HashJoinBuildCacheState(state) => state.next_data_block(),
         }
     }
 }
 
+/// Reads the build side of hash join cached by the previous queries.
+#[derive(Clone)]
+pub struct HashJoinBuildCacheState {
+    blocks: Arc<Vec<DataBlock>>,
+    next_block_index: Arc<AtomicUsize>,
+}
+
+impl HashJoinBuildCacheState {
+    pub fn new(blocks: Arc<Vec<DataBlock>>) -> Self {
+        Self {
+            blocks,
+            next_block_index: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn next_data_block(&mut self) -> Option<DataBlock> {
+        let index = self.next_block_index.fetch_add(1, Ordering::Relaxed);
+        self.blocks.get(index).cloned()
+    }
+}
+
 #[derive(Clone)]
 pub struct HashJoinCacheState {
     initilized: bool,
@@ -208,6 +208,7 @@ async fn drop_tables(ctx: Arc<QueryContext>, table_names: Vec<String>) -> Result
             database: ctx.get_current_database(),
             table: table_name.to_string(),
             all: true,
+            restrict: false,
         };
         let drop_table_interpreter =
             DropTableInterpreter::try_create(ctx.clone(), drop_table_plan)?;
@@ -307,6 +308,7 @@ async fn create_memory_table_for_cte_scan(
                 cluster_key: None,
                 as_select: None,
                 inverted_indexes: None,
+                constraints: None,
             };
             let create_table_interpreter =
                 CreateTableInterpreter::try_create(ctx.clone(), create_table_plan)?;
@@ -17,7 +17,7 @@ use std::sync::Arc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
-use databend_common_expression::udf_client::UDFFlightClient;
+use databend_common_expression::udf_server_pool::UDFServerPool;
 use databend_common_expression::variant_transform::contains_variant;
 use databend_common_expression::variant_transform::transform_variant;
 use databend_common_expression::BlockEntry;
@@ -55,8 +55,10 @@ impl TransformUdfServer {
 }
 
 impl AsyncRetry for TransformUdfServer {
-    fn retry_on(&self, _err: &databend_common_exception::ErrorCode) -> bool {
-        true
+    fn retry_on(&self, err: &databend_common_exception::ErrorCode) -> bool {
+        // The failures of the servers are failed over by the pool, no address is available
+        // if it gives up.
+        err.code() != ErrorCode::UDF_SERVER_UNAVAILABLE
     }
 
     fn retry_strategy(&self) -> RetryStrategy {
@@ -108,18 +110,27 @@ impl AsyncTransform for TransformUdfServer {
                 .to_record_batch_with_dataschema(&data_schema)
                 .map_err(|err| ErrorCode::from_string(format!("{err}")))?;
 
-            let mut client = UDFFlightClient::connect(
-                server_addr,
-                connect_timeout,
-                request_timeout,
-                request_bacth_rows,
-            )
-            .await?
-            .with_tenant(self.ctx.get_tenant().tenant_name())?
-            .with_func_name(&func.func_name)?
-            .with_query_id(&self.ctx.get_id())?;
-
-            let result_batch = client.do_exchange(&func.func_name, input_batch).await?;
+            let tenant = self.ctx.get_tenant();
+            let query_id = self.ctx.get_id();
+            let result_batch = UDFServerPool::instance()
+                .with_client(
+                    server_addr,
+                    connect_timeout,
+                    request_timeout,
+                    request_bacth_rows,
+                    |client| {
+                        let input_batch = input_batch.clone();
+                        let (tenant, query_id) = (&tenant, &query_id);
+                        async move {
+                            let mut client = client
+                                .with_tenant(tenant.tenant_name())?
+                                .with_func_name(&func.func_name)?
+                                .with_query_id(query_id)?;
+                            client.do_exchange(&func.func_name, input_batch).await
+                        }
+                    },
+                )
+                .await?;
             let schema = DataSchema::try_from(&(*result_batch.schema()))?;
             let (result_block, result_schema) =
                 DataBlock::from_record_batch(&schema, &result_batch).map_err(|err| {
@@ -31,6 +31,7 @@ use databend_common_sql::executor::physical_plans::ReplaceInto;
 use databend_common_sql::executor::physical_plans::TableScan;
 use databend_common_sql::executor::physical_plans::UnionAll;
 use databend_common_sql::executor::PhysicalPlanReplacer;
+use databend_common_sql::plans::JoinType;
 
 use crate::clusters::ClusterHelper;
 use crate::schedulers::fragments::plan_fragment::FragmentType;
@@ -39,6 +40,8 @@ use crate::servers::flight::v1::exchange::BroadcastExchange;
 use crate::servers::flight::v1::exchange::DataExchange;
 use crate::servers::flight::v1::exchange::MergeExchange;
 use crate::servers::flight::v1::exchange::ShuffleDataExchange;
+use crate::servers::flight::v1::exchange::SkewedJoinExchange;
+use crate::servers::flight::v1::exchange::SkewedJoinRole;
 use crate::sessions::QueryContext;
 use crate::sql::executor::physical_plans::MergeInto;
 use crate::sql::executor::PhysicalPlan;
@@ -147,6 +150,91 @@ impl Fragmenter {
     }
 }
 
+impl Fragmenter {
+    /// Hot keys can be replicated to every build partition and spread across probe
+    /// partitions only if no build row needs to be matched or emitted exactly once.
+    fn can_handle_skew(
+        &self,
+        plan: &HashJoin,
+        build_input: &PhysicalPlan,
+        probe_input: &PhysicalPlan,
+    ) -> Result<bool> {
+        if !self.ctx.get_settings().get_enable_join_skew_handling()? {
+            return Ok(false);
+        }
+
+        let supported_join_type = matches!(
+            plan.join_type,
+            JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+        );
+
+        Ok(supported_join_type
+            && !plan.need_hold_hash_table
+            && plan.single_to_inner.is_none()
+            && !plan
+                .is_null_equal
+                .iter()
+                .any(|is_null_equal| *is_null_equal)
+            && Self::is_shuffle_source(&self.fragments, build_input)
+            && Self::is_shuffle_source(&self.fragments, probe_input))
+    }
+
+    fn is_shuffle_source(fragments: &[PlanFragment], plan: &PhysicalPlan) -> bool {
+        let PhysicalPlan::ExchangeSource(source) = plan else {
+            return false;
+        };
+
+        fragments.iter().any(|fragment| {
+            fragment.fragment_id == source.source_fragment_id
+                && matches!(
+                    fragment.exchange,
+                    Some(DataExchange::ShuffleDataExchange(_))
+                )
+        })
+    }
+
+    fn mark_semi_join_reduction(&mut self, plan: &HashJoin, build_input: &PhysicalPlan) {
+        if !plan.enable_semi_join_reduction
+            || plan
+                .probe_keys_rt
+                .iter()
+                .all(|probe_key| probe_key.is_none())
+        {
+            return;
+        }
+
+        let PhysicalPlan::ExchangeSource(source) = build_input else {
+            return;
+        };
+
+        for fragment in self.fragments.iter_mut() {
+            if fragment.fragment_id != source.source_fragment_id {
+                continue;
+            }
+
+            if let Some(DataExchange::ShuffleDataExchange(exchange)) = &mut fragment.exchange {
+                exchange.semi_join_reduction = Some(plan.build_keys.clone());
+            }
+        }
+    }
+
+    fn mark_skewed_shuffle(&mut self, plan: &PhysicalPlan, join_id: u32, role: SkewedJoinRole) {
+        let PhysicalPlan::ExchangeSource(source) = plan else {
+            return;
+        };
+
+        for fragment in self.fragments.iter_mut() {
+            if fragment.fragment_id != source.source_fragment_id {
+                continue;
+            }
+
+            if let Some(DataExchange::ShuffleDataExchange(exchange)) = &mut fragment.exchange {
+                exchange.skew = Some(SkewedJoinExchange { join_id, role });
+            }
+        }
+    }
+}
+
 impl PhysicalPlanReplacer for Fragmenter {
     fn replace_table_scan(&mut self, plan: &TableScan) -> Result<PhysicalPlan> {
         self.state = State::SelectLeaf;
@@ -232,6 +320,12 @@ impl PhysicalPlanReplacer for Fragmenter {
         fragments.append(&mut self.fragments);
         self.fragments = fragments;
 
+        if self.can_handle_skew(plan, &build_input, &probe_input)? {
+            self.mark_skewed_shuffle(&build_input, plan.plan_id, SkewedJoinRole::Build);
+            self.mark_skewed_shuffle(&probe_input, plan.plan_id, SkewedJoinRole::Probe);
+        }
+        self.mark_semi_join_reduction(plan, &build_input);
+
         Ok(PhysicalPlan::HashJoin(HashJoin {
             plan_id: plan.plan_id,
             projections: plan.projections.clone(),
@@ -252,6 +346,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             stat_info: plan.stat_info.clone(),
             probe_keys_rt: plan.probe_keys_rt.clone(),
             enable_bloom_runtime_filter: plan.enable_bloom_runtime_filter,
+            enable_semi_join_reduction: plan.enable_semi_join_reduction,
             broadcast: plan.broadcast,
             single_to_inner: plan.single_to_inner.clone(),
             build_side_cache_info: plan.build_side_cache_info.clone(),
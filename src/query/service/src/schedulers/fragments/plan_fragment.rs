@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockEntry;
@@ -166,7 +167,8 @@ impl PlanFragment {
 
         let data_sources = self.collect_data_sources()?;
 
-        let executors = Fragmenter::get_executors(ctx);
+        let executors = Fragmenter::get_executors(ctx.clone());
+        let cache_affinity = ctx.get_settings().get_enable_cache_affinity_scheduling()?;
 
         let mut executor_partitions: HashMap<String, HashMap<u32, DataSource>> = HashMap::new();
 
@@ -174,8 +176,16 @@ impl PlanFragment {
             match data_source {
                 DataSource::Table(data_source_plan) => {
                     // Redistribute partitions of ReadDataSourcePlan.
-                    let partitions = &data_source_plan.parts;
-                    let partition_reshuffle = partitions.reshuffle(executors.clone())?;
+                    let partition_reshuffle = match data_source_plan.parts.kind {
+                        // The partitions are hashed by their locations, which are also the keys
+                        // of the local disk cache.
+                        PartitionsShuffleKind::Mod if cache_affinity => {
+                            let mut partitions = data_source_plan.parts.clone();
+                            partitions.kind = PartitionsShuffleKind::ConsistentHash;
+                            partitions.reshuffle(executors.clone())?
+                        }
+                        _ => data_source_plan.parts.reshuffle(executors.clone())?,
+                    };
                     for (executor, parts) in partition_reshuffle {
                         let mut source = data_source_plan.clone();
                         source.parts = parts;
@@ -192,6 +192,8 @@ impl QueryFragmentsActions {
                 .ctx
                 .get_settings()
                 .get_create_query_flight_client_with_current_rt()?,
+            function_now: self.ctx.get_function_now(),
+            spill_encryption_key: self.ctx.get_spill_encryption()?.key(),
         })
     }
 
@@ -73,6 +73,10 @@ impl AdminService {
                 "/v1/cluster/list",
                 get(super::v1::cluster::cluster_list_handler),
             )
+            .at(
+                "/v1/scaling/recommendation",
+                get(super::v1::scaling::scaling_recommendation_handler),
+            )
             .at(
                 "v1/queries/:query_id/profiling",
                 get(super::v1::query_profiling::query_profiling_handler),
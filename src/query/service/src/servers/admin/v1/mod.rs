@@ -18,6 +18,7 @@ pub mod config;
 pub mod instance_status;
 pub mod processes;
 pub mod query_profiling;
+pub mod scaling;
 pub mod settings;
 pub mod stream_status;
 pub mod system;
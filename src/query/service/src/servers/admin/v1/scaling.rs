@@ -0,0 +1,26 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use poem::web::Json;
+use poem::IntoResponse;
+
+use crate::clusters::ScalingAdvisorService;
+
+// the recommendation of the scaling advisor from the current load of the node, which can be
+// polled by the external operators to autoscale the warehouse
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn scaling_recommendation_handler() -> poem::Result<impl IntoResponse> {
+    Ok(Json(ScalingAdvisorService::instance().report()))
+}
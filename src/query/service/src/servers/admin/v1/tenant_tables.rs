@@ -65,6 +65,7 @@ async fn load_tenant_tables(tenant: &Tenant) -> Result<TenantTablesResponse> {
         sql_dialect: Dialect::PostgreSQL,
         quoted_ident_case_sensitive: true,
         hide_options_in_show_create_table: false,
+        hide_snapshot_location: false,
     };
 
     for database in databases {
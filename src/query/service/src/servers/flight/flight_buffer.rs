@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_base::runtime::GLOBAL_MEM_STAT;
+use databend_common_exception::Result;
+use databend_common_settings::Settings;
+use parking_lot::Mutex;
+
+/// The in-flight blocks of an exchange link before it is tuned, which is the size of
+/// the fixed buffer the links used to have.
+pub const DEFAULT_FLIGHT_BUFFER_BLOCKS: usize = 8;
+
+/// The sender waiting shorter than this for a free slot means the consumer keeps up with
+/// it, the link stalls on the size of the buffer rather than on the consumer.
+const FAST_CONSUMER_WAIT: Duration = Duration::from_millis(5);
+
+/// The sender waiting longer than this means the consumer is slow, the blocks buffered
+/// for it only hold memory.
+const SLOW_CONSUMER_WAIT: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlightBufferSettings {
+    pub autotune: bool,
+    pub min_blocks: usize,
+    pub max_blocks: usize,
+    pub memory_pressure_ratio: u64,
+}
+
+impl FlightBufferSettings {
+    pub fn fixed(blocks: usize) -> FlightBufferSettings {
+        FlightBufferSettings {
+            autotune: false,
+            min_blocks: blocks,
+            max_blocks: blocks,
+            memory_pressure_ratio: 0,
+        }
+    }
+
+    pub fn try_create(settings: &Settings) -> Result<FlightBufferSettings> {
+        let min_blocks = settings.get_exchange_buffer_min_blocks()? as usize;
+        let max_blocks = settings.get_exchange_buffer_max_blocks()? as usize;
+
+        Ok(FlightBufferSettings {
+            autotune: settings.get_enable_exchange_buffer_autotune()?,
+            min_blocks: min_blocks.min(max_blocks),
+            max_blocks,
+            memory_pressure_ratio: settings.get_memory_pressure_ratio()?,
+        })
+    }
+}
+
+impl Default for FlightBufferSettings {
+    fn default() -> Self {
+        FlightBufferSettings::fixed(DEFAULT_FLIGHT_BUFFER_BLOCKS)
+    }
+}
+
+struct FlightBufferState {
+    settings: FlightBufferSettings,
+    window: usize,
+    inflight: usize,
+    closed: bool,
+}
+
+impl FlightBufferState {
+    fn adjust(&mut self, waited: Option<Duration>) {
+        if !self.settings.autotune {
+            return;
+        }
+
+        let (min, max) = (self.settings.min_blocks, self.settings.max_blocks);
+        if GLOBAL_MEM_STAT.is_memory_pressure(self.settings.memory_pressure_ratio) {
+            self.window = (self.window / 2).max(min);
+        } else if let Some(waited) = waited {
+            if waited <= FAST_CONSUMER_WAIT {
+                self.window = (self.window + 1).min(max);
+            } else if waited >= SLOW_CONSUMER_WAIT {
+                self.window = self.window.saturating_sub(1).max(min);
+            }
+        }
+    }
+}
+
+/// Bounds the blocks in flight of an exchange link, i.e. sent by the [`FlightSender`] but not
+/// taken by the remote node yet.
+///
+/// With autotune on, the window grows while the consumer drains the link as fast as the sender
+/// fills it, and shrinks when the consumer falls behind or the node is under memory pressure.
+///
+/// [`FlightSender`]: crate::servers::flight::FlightSender
+pub struct FlightBuffer {
+    state: Mutex<FlightBufferState>,
+    notify: Notify,
+}
+
+impl FlightBuffer {
+    pub fn create(settings: FlightBufferSettings) -> Arc<FlightBuffer> {
+        Arc::new(FlightBuffer {
+            state: Mutex::new(FlightBufferState {
+                window: DEFAULT_FLIGHT_BUFFER_BLOCKS
+                    .clamp(settings.min_blocks, settings.max_blocks),
+                settings,
+                inflight: 0,
+                closed: false,
+            }),
+            notify: Notify::new(),
+        })
+    }
+
+    /// Applies the settings of the query, the links are created before they are known.
+    pub fn tune(&self, settings: FlightBufferSettings) {
+        let mut state = self.state.lock();
+        state.window = state.window.clamp(settings.min_blocks, settings.max_blocks);
+        state.settings = settings;
+        drop(state);
+
+        self.notify.notify_one();
+    }
+
+    pub fn window(&self) -> usize {
+        self.state.lock().window
+    }
+
+    pub fn inflight(&self) -> usize {
+        self.state.lock().inflight
+    }
+
+    /// Waits for a free slot of the window, returns at once if the buffer is closed.
+    #[async_backtrace::framed]
+    pub async fn acquire(&self) {
+        let mut wait_start: Option<Instant> = None;
+
+        loop {
+            {
+                let mut state = self.state.lock();
+                if state.closed {
+                    return;
+                }
+
+                state.adjust(wait_start.take().map(|start| start.elapsed()));
+                if state.inflight < state.window {
+                    state.inflight += 1;
+                    return;
+                }
+            }
+
+            wait_start = Some(Instant::now());
+            self.notify.notified().await;
+        }
+    }
+
+    pub fn release(&self) {
+        let mut state = self.state.lock();
+        state.inflight = state.inflight.saturating_sub(1);
+        drop(state);
+
+        self.notify.notify_one();
+    }
+
+    pub fn close(&self) {
+        self.state.lock().closed = true;
+        self.notify.notify_one();
+    }
+}
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::pin::Pin;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
 
 use async_channel::Receiver;
 use async_channel::Sender;
@@ -25,6 +28,11 @@ use databend_common_base::base::tokio::time::Duration;
 use databend_common_base::runtime::drop_guard;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_metrics::executor::metrics_dec_exchange_inflight_bytes;
+use databend_common_metrics::executor::metrics_inc_exchange_inflight_bytes;
+use databend_common_metrics::executor::EXCHANGE_DIRECTION_RECEIVE;
+use databend_common_metrics::executor::EXCHANGE_DIRECTION_SEND;
+use futures::Stream;
 use futures::StreamExt;
 use futures_util::future::Either;
 use minitrace::full_name;
@@ -40,6 +48,8 @@ use tonic::Status;
 use tonic::Streaming;
 
 use crate::pipelines::executor::WatchNotify;
+use crate::servers::flight::flight_buffer::FlightBuffer;
+use crate::servers::flight::flight_buffer::FlightBufferSettings;
 use crate::servers::flight::request_builder::RequestBuilder;
 use crate::servers::flight::v1::packets::DataPacket;
 
@@ -178,7 +188,16 @@ impl FlightClient {
 
                             match message {
                                 Ok(message) => {
+                                    let bytes = flight_data_bytes(&message);
+                                    metrics_inc_exchange_inflight_bytes(
+                                        EXCHANGE_DIRECTION_RECEIVE,
+                                        bytes,
+                                    );
                                     if tx.send(Ok(message)).await.is_err() {
+                                        metrics_dec_exchange_inflight_bytes(
+                                            EXCHANGE_DIRECTION_RECEIVE,
+                                            bytes,
+                                        );
                                         break;
                                     }
                                 }
@@ -237,23 +256,47 @@ impl FlightReceiver {
         match self.rx.recv().await {
             Err(_) => Ok(None),
             Ok(Err(error)) => Err(error),
-            Ok(Ok(message)) => Ok(Some(DataPacket::try_from(message)?)),
+            Ok(Ok(message)) => {
+                metrics_dec_exchange_inflight_bytes(
+                    EXCHANGE_DIRECTION_RECEIVE,
+                    flight_data_bytes(&message),
+                );
+                Ok(Some(DataPacket::try_from(message)?))
+            }
         }
     }
 
     pub fn close(&self) {
         self.rx.close();
         self.notify.notify_waiters();
+
+        // Release the inflight bytes of the data received but never consumed.
+        while let Ok(message) = self.rx.try_recv() {
+            if let Ok(message) = message {
+                metrics_dec_exchange_inflight_bytes(
+                    EXCHANGE_DIRECTION_RECEIVE,
+                    flight_data_bytes(&message),
+                );
+            }
+        }
     }
 }
 
 pub struct FlightSender {
     tx: Sender<Result<FlightData, Status>>,
+    buffer: Arc<FlightBuffer>,
 }
 
 impl FlightSender {
     pub fn create(tx: Sender<Result<FlightData, Status>>) -> FlightSender {
-        FlightSender { tx }
+        FlightSender {
+            tx,
+            buffer: FlightBuffer::create(FlightBufferSettings::default()),
+        }
+    }
+
+    pub fn tune_buffer(&self, settings: FlightBufferSettings) {
+        self.buffer.tune(settings);
     }
 
     pub fn is_closed(&self) -> bool {
@@ -262,7 +305,13 @@ impl FlightSender {
 
     #[async_backtrace::framed]
     pub async fn send(&self, data: DataPacket) -> Result<()> {
-        if let Err(_cause) = self.tx.send(Ok(FlightData::try_from(data)?)).await {
+        let data = FlightData::try_from(data)?;
+        let bytes = flight_data_bytes(&data);
+        self.buffer.acquire().await;
+        metrics_inc_exchange_inflight_bytes(EXCHANGE_DIRECTION_SEND, bytes);
+        if let Err(_cause) = self.tx.send(Ok(data)).await {
+            self.buffer.release();
+            metrics_dec_exchange_inflight_bytes(EXCHANGE_DIRECTION_SEND, bytes);
             return Err(ErrorCode::AbortedQuery(
                 "Aborted query, because the remote flight channel is closed.",
             ));
@@ -276,18 +325,80 @@ impl FlightSender {
     }
 }
 
+/// The stream of the data sent by a [`FlightSender`], which is returned to the remote node
+/// by the flight service, the inflight bytes and the buffer slot of the data are released
+/// once it is taken.
+pub struct FlightSenderStream {
+    rx: Receiver<Result<FlightData, Status>>,
+    buffer: Arc<FlightBuffer>,
+}
+
+impl FlightSenderStream {
+    pub fn create(
+        rx: Receiver<Result<FlightData, Status>>,
+        buffer: Arc<FlightBuffer>,
+    ) -> FlightSenderStream {
+        FlightSenderStream { rx, buffer }
+    }
+}
+
+impl Stream for FlightSenderStream {
+    type Item = Result<FlightData, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = Pin::new(&mut self.rx).poll_next(cx);
+        match &poll {
+            Poll::Ready(Some(Ok(data))) => {
+                self.buffer.release();
+                metrics_dec_exchange_inflight_bytes(
+                    EXCHANGE_DIRECTION_SEND,
+                    flight_data_bytes(data),
+                );
+            }
+            Poll::Ready(Some(Err(_))) => self.buffer.release(),
+            _ => {}
+        }
+        poll
+    }
+}
+
+impl Drop for FlightSenderStream {
+    fn drop(&mut self) {
+        self.rx.close();
+        self.buffer.close();
+        while let Ok(data) = self.rx.try_recv() {
+            if let Ok(data) = data {
+                metrics_dec_exchange_inflight_bytes(
+                    EXCHANGE_DIRECTION_SEND,
+                    flight_data_bytes(&data),
+                );
+            }
+        }
+    }
+}
+
+fn flight_data_bytes(data: &FlightData) -> i64 {
+    (data.data_header.len() + data.data_body.len() + data.app_metadata.len()) as i64
+}
+
 pub enum FlightExchange {
     Dummy,
     Receiver {
         notify: Arc<WatchNotify>,
         receiver: Receiver<Result<FlightData>>,
     },
-    Sender(Sender<Result<FlightData, Status>>),
+    Sender {
+        sender: Sender<Result<FlightData, Status>>,
+        buffer: Arc<FlightBuffer>,
+    },
 }
 
 impl FlightExchange {
-    pub fn create_sender(sender: Sender<Result<FlightData, Status>>) -> FlightExchange {
-        FlightExchange::Sender(sender)
+    pub fn create_sender(
+        sender: Sender<Result<FlightData, Status>>,
+        buffer: Arc<FlightBuffer>,
+    ) -> FlightExchange {
+        FlightExchange::Sender { sender, buffer }
     }
 
     pub fn create_receiver(
@@ -299,7 +410,7 @@ impl FlightExchange {
 
     pub fn convert_to_sender(self) -> FlightSender {
         match self {
-            FlightExchange::Sender(tx) => FlightSender { tx },
+            FlightExchange::Sender { sender, buffer } => FlightSender { tx: sender, buffer },
             _ => unreachable!(),
         }
     }
@@ -12,13 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod flight_buffer;
 mod flight_client;
 mod flight_service;
 mod request_builder;
 pub mod v1;
 
+pub use flight_buffer::FlightBuffer;
+pub use flight_buffer::FlightBufferSettings;
 pub use flight_client::FlightClient;
 pub use flight_client::FlightExchange;
 pub use flight_client::FlightReceiver;
 pub use flight_client::FlightSender;
+pub use flight_client::FlightSenderStream;
 pub use flight_service::FlightService;
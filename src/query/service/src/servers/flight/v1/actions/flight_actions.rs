@@ -29,6 +29,7 @@ use crate::servers::flight::v1::actions::init_query_env::init_query_env;
 use crate::servers::flight::v1::actions::init_query_env::INIT_QUERY_ENV;
 use crate::servers::flight::v1::actions::init_query_fragments::init_query_fragments;
 use crate::servers::flight::v1::actions::kill_query::kill_query;
+use crate::servers::flight::v1::actions::prune_partitions::prune_partitions;
 use crate::servers::flight::v1::actions::set_priority::set_priority;
 use crate::servers::flight::v1::actions::set_priority::SET_PRIORITY;
 use crate::servers::flight::v1::actions::start_prepared_query::start_prepared_query;
@@ -38,6 +39,7 @@ use crate::servers::flight::v1::actions::truncate_table::TRUNCATE_TABLE;
 use crate::servers::flight::v1::actions::GET_PROFILE;
 use crate::servers::flight::v1::actions::INIT_QUERY_FRAGMENTS;
 use crate::servers::flight::v1::actions::KILL_QUERY;
+use crate::servers::flight::v1::actions::PRUNE_PARTITIONS;
 use crate::servers::flight::v1::actions::START_PREPARED_QUERY;
 use crate::servers::flight::v1::actions::SYSTEM_ACTION;
 
@@ -130,4 +132,5 @@ pub fn flight_actions() -> FlightActions {
         .action(SET_PRIORITY, set_priority)
         .action(SYSTEM_ACTION, system_action)
         .action(GET_PROFILE, get_profile)
+        .action(PRUNE_PARTITIONS, prune_partitions)
 }
@@ -17,6 +17,7 @@ mod get_profile;
 mod init_query_env;
 mod init_query_fragments;
 mod kill_query;
+mod prune_partitions;
 mod set_priority;
 mod start_prepared_query;
 mod system_action;
@@ -34,6 +35,8 @@ pub use init_query_env::INIT_QUERY_ENV;
 pub use init_query_fragments::init_query_fragments;
 pub use init_query_fragments::INIT_QUERY_FRAGMENTS;
 pub use kill_query::KILL_QUERY;
+pub use prune_partitions::PrunePartitionsPacket;
+pub use prune_partitions::PRUNE_PARTITIONS;
 pub use set_priority::SET_PRIORITY;
 pub use start_prepared_query::START_PREPARED_QUERY;
 pub use system_action::SYSTEM_ACTION;
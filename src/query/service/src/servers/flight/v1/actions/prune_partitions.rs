@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_settings::Settings;
+use databend_common_storages_fuse::FuseLazyPartInfo;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::SegmentLocation;
+
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+pub static PRUNE_PARTITIONS: &str = "/actions/prune_partitions";
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PrunePartitionsPacket {
+    pub settings: Arc<Settings>,
+    pub table_info: TableInfo,
+    pub push_downs: Option<PushDownInfo>,
+    pub snapshot_loc: Option<String>,
+    /// The lazy parts of the segments pruned by the node.
+    pub lazy_parts: Partitions,
+}
+
+pub async fn prune_partitions(
+    packet: PrunePartitionsPacket,
+) -> Result<(PartStatistics, Partitions)> {
+    let session_manager = SessionManager::instance();
+    let session = session_manager.create_with_settings(SessionType::FlightRPC, packet.settings)?;
+    let query_context = Arc::new(session).create_query_context().await?;
+
+    let mut segments = Vec::with_capacity(packet.lazy_parts.len());
+    for part in &packet.lazy_parts.partitions {
+        let lazy_part_info = FuseLazyPartInfo::from_part(part)?;
+        segments.push(SegmentLocation {
+            segment_idx: lazy_part_info.segment_index,
+            location: lazy_part_info.segment_location.clone(),
+            snapshot_loc: packet.snapshot_loc.clone(),
+        });
+    }
+
+    let table = FuseTable::do_create(packet.table_info)?;
+    let table_schema = table.schema_with_stream();
+    table
+        .prune_snapshot_blocks(query_context, packet.push_downs, table_schema, segments, 0)
+        .await
+}
@@ -35,6 +35,11 @@ impl DataExchange {
 pub struct ShuffleDataExchange {
     pub destination_ids: Vec<String>,
     pub shuffle_keys: Vec<RemoteExpr>,
+    /// Set when this shuffle feeds one side of a hash join that may salt hot keys.
+    pub skew: Option<SkewedJoinExchange>,
+    /// Set when this shuffle feeds the build side of a hash join, holds the build keys
+    /// that are sent to every destination to filter the probe side scan.
+    pub semi_join_reduction: Option<Vec<RemoteExpr>>,
 }
 
 impl ShuffleDataExchange {
@@ -42,10 +47,24 @@ impl ShuffleDataExchange {
         DataExchange::ShuffleDataExchange(ShuffleDataExchange {
             destination_ids,
             shuffle_keys,
+            skew: None,
+            semi_join_reduction: None,
         })
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum SkewedJoinRole {
+    Build,
+    Probe,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SkewedJoinExchange {
+    pub join_id: u32,
+    pub role: SkewedJoinRole,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MergeExchange {
     pub destination_id: String,
@@ -29,6 +29,8 @@ use crate::servers::flight::v1::exchange::ShuffleExchangeParams;
 use crate::servers::flight::v1::scatter::BroadcastFlightScatter;
 use crate::servers::flight::v1::scatter::FlightScatter;
 use crate::servers::flight::v1::scatter::HashFlightScatter;
+use crate::servers::flight::v1::scatter::SemiJoinReductionFlightScatter;
+use crate::servers::flight::v1::scatter::SkewedHashFlightScatter;
 use crate::sessions::QueryContext;
 
 pub trait ExchangeInjector: Send + Sync + 'static {
@@ -93,12 +95,35 @@ impl ExchangeInjector for DefaultExchangeInjector {
                     .iter()
                     .position(|x| x == local_id)
                     .unwrap();
-                HashFlightScatter::try_create(
-                    ctx.get_function_context()?,
-                    exchange.shuffle_keys.clone(),
-                    exchange.destination_ids.len(),
-                    local_pos,
-                )?
+
+                let scatter = match &exchange.skew {
+                    Some(skew) if !exchange.shuffle_keys.is_empty() => {
+                        SkewedHashFlightScatter::try_create(
+                            ctx.get_function_context()?,
+                            exchange.shuffle_keys.clone(),
+                            exchange.destination_ids.len(),
+                            skew.role,
+                            ctx.get_skewed_join_keys(skew.join_id),
+                            ctx.get_settings().get_join_skew_sample_rows()?,
+                        )?
+                    }
+                    _ => HashFlightScatter::try_create(
+                        ctx.get_function_context()?,
+                        exchange.shuffle_keys.clone(),
+                        exchange.destination_ids.len(),
+                        local_pos,
+                    )?,
+                };
+
+                match &exchange.semi_join_reduction {
+                    None => scatter,
+                    Some(build_keys) => SemiJoinReductionFlightScatter::try_create(
+                        scatter,
+                        ctx.get_function_context()?,
+                        build_keys,
+                        exchange.destination_ids.len(),
+                    )?,
+                }
             }
         }))
     }
@@ -15,19 +15,20 @@
 use std::cell::SyncUnsafeCell;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::ops::Deref;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
-use async_channel::Receiver;
-use databend_common_arrow::arrow_format::flight::data::FlightData;
 use databend_common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
 use databend_common_base::base::GlobalInstance;
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::Thread;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::QueryFragmentInfo;
+use databend_common_catalog::table_context::QueryFragmentState;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -41,7 +42,6 @@ use parking_lot::ReentrantMutex;
 use petgraph::prelude::EdgeRef;
 use petgraph::Direction;
 use tokio::task::JoinHandle;
-use tonic::Status;
 
 use super::exchange_params::ExchangeParams;
 use super::exchange_params::MergeExchangeParams;
@@ -57,6 +57,7 @@ use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
 use crate::schedulers::QueryFragmentActions;
 use crate::schedulers::QueryFragmentsActions;
+use crate::servers::flight::flight_buffer::DEFAULT_FLIGHT_BUFFER_BLOCKS;
 use crate::servers::flight::v1::actions::init_query_fragments;
 use crate::servers::flight::v1::actions::INIT_QUERY_FRAGMENTS;
 use crate::servers::flight::v1::actions::START_PREPARED_QUERY;
@@ -67,21 +68,29 @@ use crate::servers::flight::v1::packets::Edge;
 use crate::servers::flight::v1::packets::QueryEnv;
 use crate::servers::flight::v1::packets::QueryFragment;
 use crate::servers::flight::v1::packets::QueryFragments;
+use crate::servers::flight::FlightBuffer;
+use crate::servers::flight::FlightBufferSettings;
 use crate::servers::flight::FlightClient;
 use crate::servers::flight::FlightExchange;
 use crate::servers::flight::FlightReceiver;
 use crate::servers::flight::FlightSender;
+use crate::servers::flight::FlightSenderStream;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 
+// The number of the fragments of the finished queries kept in `system.fragments`.
+const MAX_FINISHED_FRAGMENTS: usize = 1024;
+
 pub struct DataExchangeManager {
     queries_coordinator: ReentrantMutex<SyncUnsafeCell<HashMap<String, QueryCoordinator>>>,
+    finished_fragments: Mutex<VecDeque<QueryFragmentInfo>>,
 }
 
 impl DataExchangeManager {
     pub fn init() -> Result<()> {
         GlobalInstance::set(Arc::new(DataExchangeManager {
             queries_coordinator: ReentrantMutex::new(SyncUnsafeCell::new(HashMap::new())),
+            finished_fragments: Mutex::new(VecDeque::new()),
         }));
 
         Ok(())
@@ -327,6 +336,20 @@ impl DataExchangeManager {
         }
     }
 
+    /// Get the fragments of the distributed queries executed on the current node, including
+    /// the fragments of the recently finished queries.
+    pub fn get_queries_fragments(&self) -> Vec<QueryFragmentInfo> {
+        let queries_coordinator_guard = self.queries_coordinator.lock();
+        let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
+
+        let mut fragments = queries_coordinator
+            .values()
+            .flat_map(|coordinator| coordinator.fragments_info.iter().cloned())
+            .collect::<Vec<_>>();
+        fragments.extend(self.finished_fragments.lock().iter().cloned());
+        fragments
+    }
+
     // Create a pipeline based on query plan
     #[minitrace::trace]
     pub fn init_query_fragments_plan(&self, fragments: &QueryFragments) -> Result<()> {
@@ -348,7 +371,7 @@ impl DataExchangeManager {
         &self,
         id: String,
         target: String,
-    ) -> Result<Receiver<Result<FlightData, Status>>> {
+    ) -> Result<FlightSenderStream> {
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
 
@@ -366,7 +389,7 @@ impl DataExchangeManager {
         query: String,
         target: String,
         fragment: usize,
-    ) -> Result<Receiver<Result<FlightData, Status>>> {
+    ) -> Result<FlightSenderStream> {
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
 
@@ -396,6 +419,16 @@ impl DataExchangeManager {
             // Drop mutex guard to avoid deadlock during shutdown,
             drop(queries_coordinator_guard);
 
+            let mut finished_fragments = self.finished_fragments.lock();
+            for mut fragment_info in std::mem::take(&mut query_coordinator.fragments_info) {
+                fragment_info.state = QueryFragmentState::Finished;
+                finished_fragments.push_back(fragment_info);
+            }
+            while finished_fragments.len() > MAX_FINISHED_FRAGMENTS {
+                finished_fragments.pop_front();
+            }
+            drop(finished_fragments);
+
             query_coordinator.shutdown_query();
             query_coordinator.on_finished();
         }
@@ -556,6 +589,7 @@ static FLIGHT_RECEIVER: u8 = 2;
 struct QueryCoordinator {
     info: Option<QueryInfo>,
     fragments_coordinator: HashMap<usize, Box<FragmentCoordinator>>,
+    fragments_info: Vec<QueryFragmentInfo>,
 
     statistics_exchanges: HashMap<String, FlightExchange>,
     fragment_exchanges: HashMap<(String, usize, u8), FlightExchange>,
@@ -566,21 +600,20 @@ impl QueryCoordinator {
         QueryCoordinator {
             info: None,
             fragments_coordinator: HashMap::new(),
+            fragments_info: vec![],
             fragment_exchanges: HashMap::new(),
             statistics_exchanges: HashMap::new(),
         }
     }
 
-    pub fn add_statistics_exchange(
-        &mut self,
-        target: String,
-    ) -> Result<Receiver<Result<FlightData, Status>>> {
-        let (tx, rx) = async_channel::bounded(8);
+    pub fn add_statistics_exchange(&mut self, target: String) -> Result<FlightSenderStream> {
+        let (tx, rx) = async_channel::bounded(DEFAULT_FLIGHT_BUFFER_BLOCKS);
+        let buffer = FlightBuffer::create(FlightBufferSettings::default());
         match self
             .statistics_exchanges
-            .insert(target, FlightExchange::create_sender(tx))
+            .insert(target, FlightExchange::create_sender(tx, buffer.clone()))
         {
-            None => Ok(rx),
+            None => Ok(FlightSenderStream::create(rx, buffer)),
             Some(_) => Err(ErrorCode::Internal(
                 "statistics exchanges can only have one",
             )),
@@ -606,13 +639,16 @@ impl QueryCoordinator {
         &mut self,
         target: String,
         fragment: usize,
-    ) -> Result<Receiver<Result<FlightData, Status>>> {
-        let (tx, rx) = async_channel::bounded(8);
+    ) -> Result<FlightSenderStream> {
+        // The channel is bounded by the buffer of the link, which is tuned by the settings of
+        // the query once the senders are taken by the pipeline.
+        let (tx, rx) = async_channel::unbounded();
+        let buffer = FlightBuffer::create(FlightBufferSettings::default());
         self.fragment_exchanges.insert(
             (target, fragment, FLIGHT_SENDER),
-            FlightExchange::create_sender(tx),
+            FlightExchange::create_sender(tx, buffer.clone()),
         );
-        Ok(rx)
+        Ok(FlightSenderStream::create(rx, buffer))
     }
 
     pub fn add_fragment_exchanges(
@@ -628,7 +664,12 @@ impl QueryCoordinator {
     }
 
     pub fn get_flight_senders(&mut self, params: &ExchangeParams) -> Result<Vec<FlightSender>> {
-        match params {
+        let buffer_settings = match &self.info {
+            None => FlightBufferSettings::default(),
+            Some(info) => FlightBufferSettings::try_create(&info.query_ctx.get_settings())?,
+        };
+
+        let senders = match params {
             ExchangeParams::MergeExchange(params) => Ok(self
                 .fragment_exchanges
                 .extract_if(|(_, f, r), _| f == &params.fragment_id && *r == FLIGHT_SENDER)
@@ -656,7 +697,13 @@ impl QueryCoordinator {
 
                 Ok(exchanges)
             }
+        }?;
+
+        for sender in &senders {
+            sender.tune_buffer(buffer_settings.clone());
         }
+
+        Ok(senders)
     }
 
     pub fn get_flight_receiver(
@@ -706,6 +753,15 @@ impl QueryCoordinator {
                 fragment.fragment_id.to_owned(),
                 FragmentCoordinator::create(fragment),
             );
+
+            let mut plans = vec![];
+            collect_fragment_plans(&fragment.physical_plan, &mut plans);
+            self.fragments_info.push(QueryFragmentInfo {
+                query_id: query_info.query_id.clone(),
+                fragment_id: fragment.fragment_id,
+                state: QueryFragmentState::Initialized,
+                plans,
+            });
         }
 
         for fragment in &fragments.fragments {
@@ -784,6 +840,10 @@ impl QueryCoordinator {
     }
 
     pub fn execute_pipeline(&mut self) -> Result<()> {
+        for fragment_info in self.fragments_info.iter_mut() {
+            fragment_info.state = QueryFragmentState::Running;
+        }
+
         let info = self.info.as_mut().expect("Query info is None");
 
         if !info.started.swap(true, Ordering::SeqCst) {
@@ -880,6 +940,13 @@ impl QueryCoordinator {
     }
 }
 
+fn collect_fragment_plans(plan: &PhysicalPlan, plans: &mut Vec<(u32, String)>) {
+    plans.push((plan.get_id(), plan.name()));
+    for child in plan.children() {
+        collect_fragment_plans(child, plans);
+    }
+}
+
 struct FragmentCoordinator {
     initialized: bool,
     fragment_id: usize,
@@ -953,8 +1020,15 @@ impl FragmentCoordinator {
 
             let pipeline_ctx = QueryContext::create_from(ctx);
 
+            // The fragments of the nodes evaluate rand() with their own seeds
+            let mut func_ctx = pipeline_ctx.get_function_context()?;
+            let local_id = pipeline_ctx.get_cluster().local_id.clone();
+            func_ctx.random_seed = func_ctx
+                .random_seed
+                .map(|seed| seed.with_fragment(self.fragment_id, &local_id));
+
             let pipeline_builder = PipelineBuilder::create(
-                pipeline_ctx.get_function_context()?,
+                func_ctx,
                 pipeline_ctx.get_settings(),
                 pipeline_ctx,
                 vec![],
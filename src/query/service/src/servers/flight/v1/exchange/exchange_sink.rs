@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::fault_injection::FaultInjection;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
@@ -85,6 +86,7 @@ impl ExchangeSink {
                     &params.destination_id,
                     params.fragment_id,
                     &ctx.get_cluster().local_id(),
+                    FaultInjection::try_create(&ctx.get_settings())?,
                 )]));
                 Ok(())
             }
@@ -94,6 +96,7 @@ impl ExchangeSink {
                 // exchange writer sink
                 let len = pipeline.output_len();
                 let mut items = Vec::with_capacity(senders.len());
+                let fault_injection = FaultInjection::try_create(&ctx.get_settings())?;
 
                 for (destination_id, sender) in params.destination_ids.iter().zip(senders) {
                     items.push(create_writer_item(
@@ -102,6 +105,7 @@ impl ExchangeSink {
                         destination_id,
                         params.fragment_id,
                         &ctx.get_cluster().local_id(),
+                        fault_injection.clone(),
                     ));
                 }
 
@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::fault_injection::FaultInjection;
+use databend_common_catalog::fault_injection::FaultPoint;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
@@ -37,6 +39,7 @@ pub struct ExchangeWriterSink {
     source: String,
     destination: String,
     fragment: usize,
+    fault_injection: FaultInjection,
 }
 
 impl ExchangeWriterSink {
@@ -46,12 +49,14 @@ impl ExchangeWriterSink {
         source_id: &str,
         destination_id: &str,
         fragment_id: usize,
+        fault_injection: FaultInjection,
     ) -> Box<dyn Processor> {
         AsyncSinker::create(input, ExchangeWriterSink {
             flight_sender,
             source: source_id.to_string(),
             destination: destination_id.to_string(),
             fragment: fragment_id,
+            fault_injection,
         })
     }
 }
@@ -78,6 +83,10 @@ impl AsyncSink for ExchangeWriterSink {
             }),
         }?;
 
+        self.fault_injection
+            .inject(FaultPoint::ExchangeSend)
+            .await?;
+
         let mut bytes = 0;
         for packet in serialize_meta.packet {
             bytes += packet.bytes_size();
@@ -143,6 +152,7 @@ pub fn create_writer_item(
     destination_id: &str,
     fragment_id: usize,
     source_id: &str,
+    fault_injection: FaultInjection,
 ) -> PipeItem {
     let input = InputPort::create();
     PipeItem::create(
@@ -154,6 +164,7 @@ pub fn create_writer_item(
                 source_id,
                 destination_id,
                 fragment_id,
+                fault_injection,
             )),
         },
         vec![input],
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::fault_injection::FaultInjection;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::processors::create_resize_item;
@@ -54,6 +55,7 @@ impl ExchangeTransform {
                 let exchange_params = ExchangeParams::ShuffleExchange(params.clone());
                 let exchange_manager = ctx.get_exchange_manager();
                 let flight_senders = exchange_manager.get_flight_sender(&exchange_params)?;
+                let fault_injection = FaultInjection::try_create(&ctx.get_settings())?;
 
                 let senders = flight_senders.into_iter();
                 for (destination_id, sender) in params.destination_ids.iter().zip(senders) {
@@ -66,6 +68,7 @@ impl ExchangeTransform {
                             destination_id,
                             params.fragment_id,
                             &ctx.get_cluster().local_id(),
+                            fault_injection.clone(),
                         ),
                     });
                 }
@@ -18,8 +18,8 @@ use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use databend_common_pipeline_transforms::processors::Transform;
-use databend_common_pipeline_transforms::processors::Transformer;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
 
 use super::exchange_transform_shuffle::ExchangeShuffleMeta;
 use crate::servers::flight::v1::scatter::FlightScatter;
@@ -34,20 +34,36 @@ impl ScatterTransform {
         output: Arc<OutputPort>,
         scatter: Arc<Box<dyn FlightScatter>>,
     ) -> ProcessorPtr {
-        ProcessorPtr::create(Transformer::create(input, output, ScatterTransform {
-            scatter,
-        }))
+        scatter.attach();
+        ProcessorPtr::create(AccumulatingTransformer::create(
+            input,
+            output,
+            ScatterTransform { scatter },
+        ))
     }
 }
 
-impl Transform for ScatterTransform {
+impl AccumulatingTransform for ScatterTransform {
     const NAME: &'static str = "ScatterTransform";
 
-    fn transform(&mut self, data: DataBlock) -> databend_common_exception::Result<DataBlock> {
+    fn transform(&mut self, data: DataBlock) -> databend_common_exception::Result<Vec<DataBlock>> {
         let blocks = self.scatter.execute(data)?;
 
-        Ok(DataBlock::empty_with_meta(ExchangeShuffleMeta::create(
-            blocks,
-        )))
+        Ok(vec![DataBlock::empty_with_meta(
+            ExchangeShuffleMeta::create(blocks),
+        )])
+    }
+
+    fn on_finish(&mut self, output: bool) -> databend_common_exception::Result<Vec<DataBlock>> {
+        if !output {
+            return Ok(vec![]);
+        }
+
+        Ok(self
+            .scatter
+            .finish()?
+            .into_iter()
+            .map(|blocks| DataBlock::empty_with_meta(ExchangeShuffleMeta::create(blocks)))
+            .collect())
     }
 }
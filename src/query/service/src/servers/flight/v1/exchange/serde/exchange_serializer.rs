@@ -184,7 +184,7 @@ impl BlockMetaTransform<ExchangeShuffleMeta> for TransformScatterExchangeSeriali
     fn transform(&mut self, meta: ExchangeShuffleMeta) -> Result<DataBlock> {
         let mut new_blocks = Vec::with_capacity(meta.blocks.len());
         for (index, block) in meta.blocks.into_iter().enumerate() {
-            if block.is_empty() {
+            if block.is_empty() && block.get_meta().is_none() {
                 new_blocks.push(block);
                 continue;
             }
@@ -17,6 +17,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::ops::Deref;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use databend_common_catalog::cluster_info::Cluster;
 use databend_common_catalog::query_kind::QueryKind;
@@ -38,6 +39,8 @@ use crate::servers::flight::v1::actions::INIT_QUERY_ENV;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::spillers::SpillEncryption;
+use crate::spillers::SpillEncryptionKey;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Edge {
@@ -137,6 +140,8 @@ pub struct QueryEnv {
     pub dataflow_diagram: Arc<DataflowDiagram>,
     pub request_server_id: String,
     pub create_rpc_clint_with_current_rt: bool,
+    pub function_now: SystemTime,
+    pub spill_encryption_key: Option<SpillEncryptionKey>,
 }
 
 impl QueryEnv {
@@ -170,6 +175,11 @@ impl QueryEnv {
         }))?;
 
         query_ctx.set_id(self.query_id.clone());
+        query_ctx.set_function_now(self.function_now);
+        if let Some(key) = &self.spill_encryption_key {
+            let encryption = SpillEncryption::create_with_key(key.clone())?;
+            query_ctx.set_spill_encryption(Arc::new(encryption));
+        }
         query_ctx.attach_query_str(self.query_kind, "".to_string());
 
         Ok(query_ctx)
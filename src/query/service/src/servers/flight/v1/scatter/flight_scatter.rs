@@ -17,4 +17,13 @@ use databend_common_expression::DataBlock;
 
 pub trait FlightScatter: Sync + Send {
     fn execute(&self, data_block: DataBlock) -> Result<Vec<DataBlock>>;
+
+    /// Called once for each processor that scatters with this instance.
+    fn attach(&self) {}
+
+    /// Called when a processor has scattered all of its input, the returned
+    /// items are scattered blocks (one per destination) that are still pending.
+    fn finish(&self) -> Result<Vec<Vec<DataBlock>>> {
+        Ok(vec![])
+    }
 }
@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::transforms::SemiJoinReductionKeys;
+use crate::servers::flight::v1::scatter::flight_scatter::FlightScatter;
+
+/// Scatter for the build side of a shuffle hash join that also collects the
+/// distinct build keys, and sends them to every destination once all rows are sent,
+/// so each node can filter its probe side scan with the keys of the whole build side.
+pub struct SemiJoinReductionFlightScatter {
+    inner: Box<dyn FlightScatter>,
+    func_ctx: FunctionContext,
+    build_keys: Vec<Expr>,
+    scatter_size: usize,
+    state: Mutex<SemiJoinReductionState>,
+}
+
+struct SemiJoinReductionState {
+    keys: SemiJoinReductionKeys,
+    attached: usize,
+    finished: usize,
+}
+
+impl SemiJoinReductionFlightScatter {
+    pub fn try_create(
+        inner: Box<dyn FlightScatter>,
+        func_ctx: FunctionContext,
+        build_keys: &[RemoteExpr],
+        scatter_size: usize,
+    ) -> Result<Box<dyn FlightScatter>> {
+        let build_keys = build_keys
+            .iter()
+            .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(SemiJoinReductionFlightScatter {
+            inner,
+            func_ctx,
+            state: Mutex::new(SemiJoinReductionState {
+                keys: SemiJoinReductionKeys::create(build_keys.len()),
+                attached: 0,
+                finished: 0,
+            }),
+            build_keys,
+            scatter_size,
+        }))
+    }
+}
+
+impl FlightScatter for SemiJoinReductionFlightScatter {
+    fn execute(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        if !data_block.is_empty() {
+            let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let columns = self
+                .build_keys
+                .iter()
+                .map(|expr| {
+                    Ok(evaluator
+                        .run(expr)?
+                        .convert_to_full_column(expr.data_type(), data_block.num_rows()))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            self.state.lock().keys.add(&columns);
+        }
+
+        self.inner.execute(data_block)
+    }
+
+    fn attach(&self) {
+        self.state.lock().attached += 1;
+        self.inner.attach();
+    }
+
+    fn finish(&self) -> Result<Vec<Vec<DataBlock>>> {
+        let mut pending = self.inner.finish()?;
+
+        // The last processor reports the build keys to all the destinations.
+        let mut state = self.state.lock();
+        state.finished += 1;
+        if state.finished == state.attached {
+            let meta = state.keys.take_meta();
+            pending.push(
+                (0..self.scatter_size)
+                    .map(|_| DataBlock::empty_with_meta(meta.clone()))
+                    .collect(),
+            );
+        }
+
+        Ok(pending)
+    }
+}
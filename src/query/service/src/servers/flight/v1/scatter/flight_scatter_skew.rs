@@ -0,0 +1,371 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::NumberType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::transforms::SkewedJoinKeys;
+use crate::pipelines::processors::transforms::SkewedJoinKeysMeta;
+use crate::servers::flight::v1::exchange::SkewedJoinRole;
+use crate::servers::flight::v1::scatter::flight_scatter::FlightScatter;
+
+/// Hash scatter for the inputs of a shuffle hash join whose keys may be skewed.
+///
+/// Rows are routed exactly like `HashFlightScatter`, except for hot keys:
+/// the build side copies the rows of keys that are hot in its first `sample_rows`
+/// rows to every partition, and the probe side spreads the rows of keys that every
+/// build sender replicated across all partitions.
+pub struct SkewedHashFlightScatter {
+    func_ctx: FunctionContext,
+    hash_keys: Vec<Expr>,
+    scatter_size: usize,
+    role: SkewedJoinRole,
+    skewed_join_keys: Arc<SkewedJoinKeys>,
+    sample_rows: usize,
+    state: Mutex<SkewedBuildState>,
+    next_partition: AtomicUsize,
+}
+
+struct SkewedBuildState {
+    sampling: bool,
+    sampled_rows: usize,
+    key_counts: HashMap<u64, usize>,
+    buffered: Vec<(DataBlock, Vec<(u64, bool)>)>,
+    replicated: Arc<HashSet<u64>>,
+    presence: Vec<u64>,
+    attached: usize,
+    finished: usize,
+}
+
+impl SkewedHashFlightScatter {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        hash_keys: Vec<RemoteExpr>,
+        scatter_size: usize,
+        role: SkewedJoinRole,
+        skewed_join_keys: Arc<SkewedJoinKeys>,
+        sample_rows: usize,
+    ) -> Result<Box<dyn FlightScatter>> {
+        let hash_keys = hash_keys
+            .iter()
+            .map(|key| {
+                check_function(
+                    None,
+                    "siphash",
+                    &[],
+                    &[key.as_expr(&BUILTIN_FUNCTIONS)],
+                    &BUILTIN_FUNCTIONS,
+                )
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Box::new(SkewedHashFlightScatter {
+            func_ctx,
+            hash_keys,
+            scatter_size,
+            role,
+            skewed_join_keys,
+            sample_rows,
+            state: Mutex::new(SkewedBuildState {
+                sampling: true,
+                sampled_rows: 0,
+                key_counts: HashMap::new(),
+                buffered: vec![],
+                replicated: Arc::new(HashSet::new()),
+                presence: vec![],
+                attached: 0,
+                finished: 0,
+            }),
+            next_partition: AtomicUsize::new(0),
+        }))
+    }
+
+    /// Returns the hash of the keys of each row, computed the same way as `HashFlightScatter`,
+    /// and whether none of the keys is null.
+    fn key_hashes(&self, data_block: &DataBlock) -> Result<Vec<(u64, bool)>> {
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let num_rows = data_block.num_rows();
+
+        let mut valid = vec![true; num_rows];
+        let mut hash_keys = Vec::with_capacity(self.hash_keys.len());
+        for expr in &self.hash_keys {
+            hash_keys.push(get_hash_values(evaluator.run(expr)?, &mut valid));
+        }
+
+        if hash_keys.len() == 1 {
+            return Ok(hash_keys.pop().unwrap().into_iter().zip(valid).collect());
+        }
+
+        let mut hashes = vec![DefaultHasher::default(); num_rows];
+        for keys in hash_keys.iter() {
+            for (i, value) in keys.iter().enumerate() {
+                hashes[i].write_u64(*value);
+            }
+        }
+
+        Ok(hashes
+            .into_iter()
+            .map(|hash| hash.finish())
+            .zip(valid)
+            .collect())
+    }
+
+    fn scatter(&self, data_block: &DataBlock, indices: &[u64]) -> Result<Vec<DataBlock>> {
+        let block_meta = data_block.get_meta();
+        let data_blocks = DataBlock::scatter(data_block, indices, self.scatter_size)?;
+
+        let mut res = Vec::with_capacity(data_blocks.len());
+        for data_block in data_blocks {
+            res.push(data_block.add_meta(block_meta.cloned())?);
+        }
+
+        Ok(res)
+    }
+
+    fn scatter_build(
+        &self,
+        data_block: &DataBlock,
+        hashes: &[(u64, bool)],
+        replicated: &HashSet<u64>,
+    ) -> Result<Vec<DataBlock>> {
+        let m = self.scatter_size as u64;
+        if replicated.is_empty() {
+            let indices = hashes.iter().map(|(hash, _)| hash % m).collect::<Vec<_>>();
+            return self.scatter(data_block, &indices);
+        }
+
+        // Hot rows go to an extra bucket, which is then appended to every partition.
+        let indices = hashes
+            .iter()
+            .map(|(hash, valid)| match *valid && replicated.contains(hash) {
+                true => m,
+                false => hash % m,
+            })
+            .collect::<Vec<_>>();
+
+        let mut data_blocks = DataBlock::scatter(data_block, &indices, self.scatter_size + 1)?;
+        let hot_block = data_blocks.pop().unwrap();
+
+        let block_meta = data_block.get_meta();
+        let mut res = Vec::with_capacity(data_blocks.len());
+        for data_block in data_blocks {
+            let data_block = match hot_block.is_empty() {
+                true => data_block,
+                false => DataBlock::concat(&[data_block, hot_block.clone()])?,
+            };
+            res.push(data_block.add_meta(block_meta.cloned())?);
+        }
+
+        Ok(res)
+    }
+
+    fn scatter_buffered(
+        &self,
+        buffered: Vec<(DataBlock, Vec<(u64, bool)>)>,
+        replicated: &HashSet<u64>,
+    ) -> Result<Vec<DataBlock>> {
+        let mut partitions = vec![vec![]; self.scatter_size];
+        for (data_block, hashes) in buffered {
+            let data_blocks = self.scatter_build(&data_block, &hashes, replicated)?;
+            for (partition, data_block) in partitions.iter_mut().zip(data_blocks) {
+                if !data_block.is_empty() {
+                    partition.push(data_block);
+                }
+            }
+        }
+
+        partitions
+            .into_iter()
+            .map(|blocks| match blocks.is_empty() {
+                true => Ok(DataBlock::empty()),
+                false => DataBlock::concat(&blocks),
+            })
+            .collect()
+    }
+
+    fn execute_build(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        let hashes = self.key_hashes(&data_block)?;
+
+        let mut state = self.state.lock();
+        for (hash, valid) in hashes.iter() {
+            if *valid {
+                SkewedJoinKeysMeta::set_presence(&mut state.presence, *hash);
+            }
+        }
+
+        if !state.sampling {
+            let replicated = state.replicated.clone();
+            drop(state);
+            return self.scatter_build(&data_block, &hashes, &replicated);
+        }
+
+        state.sampled_rows += data_block.num_rows();
+        for (hash, valid) in hashes.iter() {
+            if *valid {
+                *state.key_counts.entry(*hash).or_default() += 1;
+            }
+        }
+        state.buffered.push((data_block, hashes));
+
+        if state.sampled_rows < self.sample_rows {
+            return Ok(vec![DataBlock::empty(); self.scatter_size]);
+        }
+
+        // A key is hot if it holds more than half of the rows a partition would get on average.
+        state.sampling = false;
+        let sampled_rows = state.sampled_rows;
+        let scatter_size = self.scatter_size;
+        if scatter_size > 1 {
+            state.replicated = Arc::new(
+                state
+                    .key_counts
+                    .iter()
+                    .filter(|(_, count)| **count * 2 * scatter_size > sampled_rows)
+                    .map(|(hash, _)| *hash)
+                    .collect(),
+            );
+        }
+        state.key_counts = HashMap::new();
+
+        let buffered = std::mem::take(&mut state.buffered);
+        let replicated = state.replicated.clone();
+        drop(state);
+        self.scatter_buffered(buffered, &replicated)
+    }
+
+    fn execute_probe(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        let hashes = self.key_hashes(&data_block)?;
+        let m = self.scatter_size as u64;
+
+        let indices = match self.skewed_join_keys.hot_keys() {
+            None => hashes.iter().map(|(hash, _)| hash % m).collect::<Vec<_>>(),
+            Some(hot_keys) => {
+                let mut next_partition = self.next_partition.fetch_add(1, Ordering::Relaxed);
+                hashes
+                    .iter()
+                    .map(|(hash, valid)| match *valid && hot_keys.contains(hash) {
+                        true => {
+                            next_partition += 1;
+                            next_partition as u64 % m
+                        }
+                        false => hash % m,
+                    })
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        self.scatter(&data_block, &indices)
+    }
+}
+
+impl FlightScatter for SkewedHashFlightScatter {
+    fn execute(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        match self.role {
+            SkewedJoinRole::Build => self.execute_build(data_block),
+            SkewedJoinRole::Probe => self.execute_probe(data_block),
+        }
+    }
+
+    fn attach(&self) {
+        self.state.lock().attached += 1;
+    }
+
+    fn finish(&self) -> Result<Vec<Vec<DataBlock>>> {
+        if self.role == SkewedJoinRole::Probe {
+            return Ok(vec![]);
+        }
+
+        let mut state = self.state.lock();
+        let mut pending = vec![];
+
+        // The input is smaller than the sample, send it without replicating any key.
+        if state.sampling {
+            state.sampling = false;
+            state.key_counts = HashMap::new();
+            let buffered = std::mem::take(&mut state.buffered);
+            pending.push(self.scatter_buffered(buffered, &HashSet::new())?);
+        }
+
+        // The last processor reports the replicated keys to all the partitions.
+        state.finished += 1;
+        if state.finished == state.attached {
+            let replicated = state.replicated.iter().copied().collect::<Vec<_>>();
+            let presence = std::mem::take(&mut state.presence);
+            pending.push(
+                (0..self.scatter_size)
+                    .map(|_| {
+                        DataBlock::empty_with_meta(SkewedJoinKeysMeta::create(
+                            replicated.clone(),
+                            presence.clone(),
+                        ))
+                    })
+                    .collect(),
+            );
+        }
+
+        Ok(pending)
+    }
+}
+
+fn get_hash_values(column: Value<AnyType>, valid: &mut [bool]) -> Vec<u64> {
+    let rows = valid.len();
+    match column {
+        Value::Scalar(Scalar::Number(NumberScalar::UInt64(x))) => vec![x; rows],
+        Value::Scalar(Scalar::Null) => {
+            valid.iter_mut().for_each(|valid| *valid = false);
+            vec![0; rows]
+        }
+        Value::Scalar(_) => unreachable!(),
+        Value::Column(c) => {
+            if let Some(column) = NumberType::<u64>::try_downcast_column(&c) {
+                column.to_vec()
+            } else if let Some(column) = NullableType::<NumberType<u64>>::try_downcast_column(&c) {
+                column
+                    .column
+                    .iter()
+                    .zip(column.validity.iter())
+                    .zip(valid.iter_mut())
+                    .map(|((x, is_valid), valid)| {
+                        *valid &= is_valid;
+                        if is_valid { *x } else { 0 }
+                    })
+                    .collect()
+            } else {
+                unreachable!()
+            }
+        }
+    }
+}
@@ -15,7 +15,11 @@
 mod flight_scatter;
 mod flight_scatter_broadcast;
 mod flight_scatter_hash;
+mod flight_scatter_reduction;
+mod flight_scatter_skew;
 
 pub use flight_scatter::FlightScatter;
 pub use flight_scatter_broadcast::BroadcastFlightScatter;
 pub use flight_scatter_hash::HashFlightScatter;
+pub use flight_scatter_reduction::SemiJoinReductionFlightScatter;
+pub use flight_scatter_skew::SkewedHashFlightScatter;
@@ -39,6 +39,7 @@ use super::v1::upload_to_stage;
 use crate::auth::AuthMgr;
 use crate::servers::http::middleware::HTTPSessionMiddleware;
 use crate::servers::http::middleware::PanicHandler;
+use crate::servers::http::v1::bulk_insert;
 use crate::servers::http::v1::clickhouse_router;
 use crate::servers::http::v1::list_suggestions;
 use crate::servers::http::v1::login_handler;
@@ -99,6 +100,7 @@ impl HttpHandler {
             .nest("/query", query_route())
             .at("/login", post(login_handler))
             .at("/upload_to_stage", put(upload_to_stage))
+            .at("/bulk_insert", post(bulk_insert))
             .at("/suggested_background_tasks", get(list_suggestions));
         let ep_v1 = self.wrap_auth(ep_v1);
 
@@ -17,9 +17,12 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_base::headers::HEADER_APPLICATION_NAME;
 use databend_common_base::headers::HEADER_DEDUPLICATE_LABEL;
+use databend_common_base::headers::HEADER_IDEMPOTENCY_KEY;
 use databend_common_base::headers::HEADER_NODE_ID;
 use databend_common_base::headers::HEADER_QUERY_ID;
+use databend_common_base::headers::HEADER_QUERY_TAG;
 use databend_common_base::headers::HEADER_TENANT;
 use databend_common_base::runtime::ThreadTracker;
 use databend_common_exception::ErrorCode;
@@ -107,6 +110,18 @@ fn extract_baggage_from_headers(headers: &HeaderMap) -> Option<Vec<(String, Stri
     Some(result)
 }
 
+fn get_header(req: &Request, name: &str) -> Result<Option<String>> {
+    match req.headers().get(name) {
+        None => Ok(None),
+        Some(value) => match value.to_str() {
+            Ok(value) => Ok(Some(value.to_string())),
+            Err(_) => Err(ErrorCode::BadArguments(format!(
+                "invalid value of header {name}, only visible ASCII characters are allowed"
+            ))),
+        },
+    }
+}
+
 fn get_credential(req: &Request, kind: HttpHandlerKind) -> Result<Credential> {
     let std_auth_headers: Vec<_> = req.headers().get_all(AUTHORIZATION).iter().collect();
     if std_auth_headers.len() > 1 {
@@ -244,8 +259,7 @@ impl<E> HTTPSessionEndpoint<E> {
 
         let mut session = session_manager.create_session(SessionType::Dummy).await?;
 
-        if let Some(tenant_id) = req.headers().get(HEADER_TENANT) {
-            let tenant_id = tenant_id.to_str().unwrap().to_string();
+        if let Some(tenant_id) = get_header(req, HEADER_TENANT)? {
             let tenant = Tenant::new_or_err(tenant_id.clone(), func_name!())?;
             session.set_current_tenant(tenant);
         }
@@ -254,25 +268,18 @@ impl<E> HTTPSessionEndpoint<E> {
 
         let session = session_manager.register_session(session)?;
 
-        let deduplicate_label = req
-            .headers()
-            .get(HEADER_DEDUPLICATE_LABEL)
-            .map(|id| id.to_str().unwrap().to_string());
-
-        let user_agent = req
-            .headers()
-            .get(USER_AGENT)
-            .map(|id| id.to_str().unwrap().to_string());
-
-        let expected_node_id = req
-            .headers()
-            .get(HEADER_NODE_ID)
-            .map(|id| id.to_str().unwrap().to_string());
-
-        let trace_parent = req
-            .headers()
-            .get(TRACE_PARENT)
-            .map(|id| id.to_str().unwrap().to_string());
+        let deduplicate_label = get_header(req, HEADER_DEDUPLICATE_LABEL)?;
+
+        let idempotency_key = get_header(req, HEADER_IDEMPOTENCY_KEY)?;
+
+        let user_agent = get_header(req, USER_AGENT)?;
+
+        let query_tag = get_header(req, HEADER_QUERY_TAG)?;
+        let application_name = get_header(req, HEADER_APPLICATION_NAME)?;
+
+        let expected_node_id = get_header(req, HEADER_NODE_ID)?;
+
+        let trace_parent = get_header(req, TRACE_PARENT)?;
         let opentelemetry_baggage = extract_baggage_from_headers(req.headers());
         let client_host = get_client_ip(req);
 
@@ -285,7 +292,10 @@ impl<E> HTTPSessionEndpoint<E> {
             node_id,
             expected_node_id,
             deduplicate_label,
+            idempotency_key,
             user_agent,
+            query_tag,
+            application_name,
             trace_parent,
             opentelemetry_baggage,
             http_method: req.method().to_string(),
@@ -304,11 +314,21 @@ impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
         let uri = req.uri().clone();
         let headers = req.headers().clone();
 
-        let query_id = req
-            .headers()
-            .get(HEADER_QUERY_ID)
-            .map(|id| id.to_str().unwrap().to_string())
-            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        let query_id = match get_header(&req, HEADER_QUERY_ID) {
+            Ok(query_id) => query_id.unwrap_or_else(|| Uuid::new_v4().to_string()),
+            Err(err) => {
+                let body = Body::from_json(serde_json::json!({
+                    "error": {
+                        "code": StatusCode::BAD_REQUEST.as_str(),
+                        "message": err.message(),
+                    }
+                }))
+                .unwrap();
+                return Ok(Response::builder()
+                    .status(StatusCode::BAD_REQUEST)
+                    .body(body));
+            }
+        };
 
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
         tracking_payload.query_id = Some(query_id.clone());
@@ -332,6 +352,17 @@ impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
                             StatusCode::UNAUTHORIZED,
                         ))
                     }
+                    ErrorCode::BAD_ARGUMENTS => {
+                        warn!(
+                            "http bad request: {method} {uri}, headers={:?}, error={}",
+                            sanitize_request_headers(&headers),
+                            err
+                        );
+                        Err(PoemError::from_string(
+                            err.message(),
+                            StatusCode::BAD_REQUEST,
+                        ))
+                    }
                     _ => {
                         error!(
                             "http request err: {method} {uri}, headers={:?}, error={}",
@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow_ipc::reader::StreamReader;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_sql::plans::insert::InsertValue;
+use databend_common_sql::plans::InsertInputSource;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use futures::StreamExt;
+use http::StatusCode;
+use poem::error::BadRequest;
+use poem::error::InternalServerError;
+use poem::error::Result as PoemResult;
+use poem::web::Json;
+use poem::Body;
+use poem::Request;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::io::AsyncReadExt;
+
+use crate::interpreters::InterpreterFactory;
+use crate::servers::http::v1::HttpQueryContext;
+use crate::sessions::QueriesQueueManager;
+use crate::sessions::QueryEntry;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+const ARROW_STREAM_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BulkInsertResponse {
+    pub id: String,
+    pub state: String,
+    pub rows: u64,
+}
+
+/// Inserts the rows of an Arrow IPC stream in the request body with the `INSERT INTO ... VALUES`
+/// statement in the `X-DATABEND-SQL` header, the rows are decoded into blocks directly instead
+/// of being parsed from a huge textual VALUES list.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn bulk_insert(
+    ctx: &HttpQueryContext,
+    req: &Request,
+    body: Body,
+) -> PoemResult<Json<BulkInsertResponse>> {
+    let sql = req
+        .headers()
+        .get("x-databend-sql")
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| {
+            poem::Error::from_string(
+                "missing header X-DATABEND-SQL, please check your arguments".to_string(),
+                StatusCode::BAD_REQUEST,
+            )
+        })?
+        .to_string();
+    let content_type = req.content_type().unwrap_or(ARROW_STREAM_CONTENT_TYPE);
+    if content_type != ARROW_STREAM_CONTENT_TYPE {
+        return Err(poem::Error::from_string(
+            format!(
+                "unsupported content type {}, only {} is supported",
+                content_type, ARROW_STREAM_CONTENT_TYPE
+            ),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        ));
+    }
+
+    let session = ctx.upgrade_session(SessionType::HTTPAPI("BulkInsert".to_string()))?;
+    let context = session
+        .create_query_context()
+        .await
+        .map_err(InternalServerError)?;
+
+    let mut planner = Planner::new(context.clone());
+    let (plan, extras) = planner
+        .plan_sql(&sql)
+        .await
+        .map_err(|err| err.display_with_sql(&sql))
+        .map_err(BadRequest)?;
+    let mut insert = match plan {
+        Plan::Insert(insert)
+            if matches!(
+                &insert.source,
+                InsertInputSource::Values(InsertValue::RawValues { data, .. })
+                    if data.trim().trim_end_matches(';').is_empty()
+            ) =>
+        {
+            insert
+        }
+        _ => {
+            return Err(poem::Error::from_string(
+                "bulk insert only supports `INSERT INTO <table> [(<columns>)] VALUES` without values"
+                    .to_string(),
+                StatusCode::BAD_REQUEST,
+            ));
+        }
+    };
+
+    let max_bytes = context
+        .get_settings()
+        .get_bulk_insert_max_bytes()
+        .map_err(InternalServerError)?;
+    let bytes = read_body(body, max_bytes).await?;
+    let (schema, blocks) = read_arrow_stream(bytes).map_err(BadRequest)?;
+    let dest_schema = insert.dest_schema();
+    if schema.num_fields() != dest_schema.num_fields() {
+        return Err(poem::Error::from_string(
+            format!(
+                "the payload has {} columns, but the insert expects {} columns",
+                schema.num_fields(),
+                dest_schema.num_fields()
+            ),
+            StatusCode::BAD_REQUEST,
+        ));
+    }
+    insert.source = InsertInputSource::Blocks { schema, blocks };
+    let plan = Plan::Insert(insert);
+
+    let entry = QueryEntry::create(&context, &plan, &extras).map_err(BadRequest)?;
+    let _guard = QueriesQueueManager::instance()
+        .acquire(entry)
+        .await
+        .map_err(BadRequest)?;
+
+    let interpreter = InterpreterFactory::get(context.clone(), &plan)
+        .await
+        .map_err(BadRequest)?;
+    let mut stream = interpreter
+        .execute(context.clone())
+        .await
+        .map_err(InternalServerError)?;
+    while let Some(block) = stream.next().await {
+        block.map_err(InternalServerError)?;
+    }
+
+    Ok(Json(BulkInsertResponse {
+        id: context.get_id(),
+        state: "SUCCESS".to_string(),
+        rows: context.get_write_progress_value().rows as u64,
+    }))
+}
+
+/// Reads the body up to `max_bytes`, the payloads are decoded as a whole, so the larger ones
+/// are rejected before they are buffered.
+async fn read_body(body: Body, max_bytes: u64) -> PoemResult<Vec<u8>> {
+    let mut bytes = vec![];
+    body.into_async_read()
+        .take(max_bytes.saturating_add(1))
+        .read_to_end(&mut bytes)
+        .await
+        .map_err(BadRequest)?;
+    if bytes.len() as u64 > max_bytes {
+        return Err(poem::Error::from_string(
+            format!(
+                "the payload is larger than {} bytes, please split it or set bulk_insert_max_bytes",
+                max_bytes
+            ),
+            StatusCode::PAYLOAD_TOO_LARGE,
+        ));
+    }
+    Ok(bytes)
+}
+
+fn read_arrow_stream(bytes: Vec<u8>) -> Result<(DataSchemaRef, Vec<DataBlock>)> {
+    let reader = StreamReader::try_new(Cursor::new(bytes), None)
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid arrow ipc stream: {}", e)))?;
+    let schema = DataSchema::try_from(reader.schema().as_ref())?;
+
+    let mut blocks = vec![];
+    for batch in reader {
+        let batch =
+            batch.map_err(|e| ErrorCode::BadBytes(format!("invalid arrow ipc stream: {}", e)))?;
+        let (block, _) = DataBlock::from_record_batch(&schema, &batch)?;
+        blocks.push(block);
+    }
+    Ok((Arc::new(schema), blocks))
+}
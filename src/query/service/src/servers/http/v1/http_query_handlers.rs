@@ -359,10 +359,19 @@ pub(crate) async fn query_handler(
         let http_query_manager = HttpQueryManager::instance();
         let sql = req.sql.clone();
 
-        let query = http_query_manager
-            .try_create_query(ctx, req.clone())
-            .await
-            .map_err(|err| err.display_with_sql(&sql));
+        let query = match http_query_manager.get_idempotent_query(ctx, &sql).await {
+            Ok(Some(query)) => {
+                // a retry of a request that has created the query, no page is consumed.
+                let resp = query.get_response_state_only().await;
+                info!("http query with idempotency key {:?} returns the existing query_id={}, state={:?}",
+                    ctx.idempotency_key, &query.id, &resp.state
+                );
+                return Ok(QueryResponse::from_internal(query.id.to_string(), resp, false).into_response());
+            }
+            Ok(None) => http_query_manager.try_create_query(ctx, req.clone()).await,
+            Err(e) => Err(e),
+        }
+        .map_err(|err| err.display_with_sql(&sql));
         match query {
             Ok(query) => {
                 query.update_expire_time(true).await;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bulk_insert;
 mod http_query_handlers;
 mod login;
 mod query;
@@ -19,6 +20,8 @@ mod stage;
 pub mod string_block;
 mod suggestions;
 
+pub use bulk_insert::bulk_insert;
+pub use bulk_insert::BulkInsertResponse;
 pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
@@ -27,6 +30,7 @@ pub use http_query_handlers::QueryError;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
 pub(crate) use login::login_handler;
+pub use query::result_spill_prefix;
 pub use query::ExecuteStateKind;
 pub use query::ExpiringMap;
 pub use query::ExpiringState;
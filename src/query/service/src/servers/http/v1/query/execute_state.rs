@@ -18,6 +18,7 @@ use std::time::SystemTime;
 use databend_common_base::base::tokio::sync::RwLock;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::CatchUnwindFuture;
+use databend_common_catalog::table_context::MaintenanceProgress;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -43,6 +44,8 @@ use crate::interpreters::InterpreterQueryLog;
 use crate::servers::http::v1::http_query_handlers::QueryResponseField;
 use crate::servers::http::v1::query::http_query::ResponseState;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelSender;
+use crate::servers::http::v1::query::ResultBlock;
+use crate::servers::http::v1::query::ResultSpiller;
 use crate::sessions::AcquireQueueGuard;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryAffect;
@@ -77,6 +80,9 @@ pub struct Progresses {
     pub write_progress: ProgressValues,
     pub result_progress: ProgressValues,
     pub total_scan: ProgressValues,
+    /// Only set by the maintenance statements, see [`MaintenanceProgress`].
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub maintenance_progress: Option<MaintenanceProgress>,
 }
 
 impl Progresses {
@@ -86,6 +92,7 @@ impl Progresses {
             write_progress: ctx.get_write_progress_value(),
             result_progress: ctx.get_result_progress_value(),
             total_scan: ctx.get_total_scan_value(),
+            maintenance_progress: ctx.get_maintenance_progress(),
         }
     }
 }
@@ -327,7 +334,8 @@ impl ExecuteState {
         sql: String,
         session: Arc<Session>,
         ctx: Arc<QueryContext>,
-        block_sender: SizedChannelSender<DataBlock>,
+        block_sender: SizedChannelSender<ResultBlock>,
+        result_spiller: Option<Arc<ResultSpiller>>,
         format_settings: Arc<parking_lot::RwLock<Option<FormatSettings>>>,
     ) -> Result<()> {
         info!("http query prepare to plan sql");
@@ -383,6 +391,7 @@ impl ExecuteState {
             plan.schema(),
             ctx_clone,
             block_sender,
+            result_spiller,
             executor_clone.clone(),
         );
         match CatchUnwindFuture::create(res).await {
@@ -405,7 +414,8 @@ async fn execute(
     interpreter: Arc<dyn Interpreter>,
     schema: DataSchemaRef,
     ctx: Arc<QueryContext>,
-    block_sender: SizedChannelSender<DataBlock>,
+    block_sender: SizedChannelSender<ResultBlock>,
+    result_spiller: Option<Arc<ResultSpiller>>,
     executor: Arc<RwLock<Executor>>,
 ) -> Result<()> {
     let data_stream_res = interpreter.execute(ctx.clone()).await;
@@ -415,14 +425,16 @@ async fn execute(
             DataType::String,
             databend_common_expression::Value::Scalar(Scalar::String(err.to_string())),
         );
-        block_sender.send(DataBlock::new(vec![data], 1), 1).await;
+        block_sender
+            .send(ResultBlock::Memory(DataBlock::new(vec![data], 1)), 1)
+            .await;
         return Err(err);
     }
     let mut data_stream = data_stream_res.unwrap();
     match data_stream.next().await {
         None => {
             let block = DataBlock::empty_with_schema(schema);
-            block_sender.send(block, 0).await;
+            block_sender.send(ResultBlock::Memory(block), 0).await;
             Executor::stop(&executor, Ok(())).await;
             block_sender.close();
         }
@@ -432,17 +444,18 @@ async fn execute(
                 DataType::String,
                 databend_common_expression::Value::Scalar(Scalar::String(err.to_string())),
             );
-            block_sender.send(DataBlock::new(vec![data], 1), 1).await;
+            block_sender
+                .send(ResultBlock::Memory(DataBlock::new(vec![data], 1)), 1)
+                .await;
             Executor::stop(&executor, Err(err)).await;
             block_sender.close();
         }
         Some(Ok(block)) => {
-            let size = block.num_rows();
-            block_sender.send(block, size).await;
+            send_result_block(&block_sender, &result_spiller, block).await?;
             while let Some(block_r) = data_stream.next().await {
                 match block_r {
                     Ok(block) => {
-                        block_sender.send(block.clone(), block.num_rows()).await;
+                        send_result_block(&block_sender, &result_spiller, block).await?;
                     }
                     Err(err) => {
                         // duplicate codes, but there is an async call
@@ -452,7 +465,9 @@ async fn execute(
                                 err.to_string(),
                             )),
                         );
-                        block_sender.send(DataBlock::new(vec![data], 1), 1).await;
+                        block_sender
+                            .send(ResultBlock::Memory(DataBlock::new(vec![data], 1)), 1)
+                            .await;
                         block_sender.close();
                         return Err(err);
                     }
@@ -464,3 +479,28 @@ async fn execute(
     }
     Ok(())
 }
+
+/// Sends the result block to the page manager, the block is spilled instead of waiting for
+/// the client if the buffer is full and the results can be spilled.
+async fn send_result_block(
+    block_sender: &SizedChannelSender<ResultBlock>,
+    result_spiller: &Option<Arc<ResultSpiller>>,
+    block: DataBlock,
+) -> Result<()> {
+    let size = block.num_rows();
+    let Some(result_spiller) = result_spiller else {
+        block_sender.send(ResultBlock::Memory(block), size).await;
+        return Ok(());
+    };
+
+    let Some(ResultBlock::Memory(block)) = block_sender.try_send(ResultBlock::Memory(block), size)
+    else {
+        return Ok(());
+    };
+    match result_spiller.spill(block).await? {
+        // The spilled blocks don't take the buffer.
+        spilled @ ResultBlock::Spilled(_) => block_sender.send(spilled, 0).await,
+        block => block_sender.send(block, size).await,
+    };
+    Ok(())
+}
@@ -57,6 +57,7 @@ use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
 use crate::servers::http::v1::query::PageManager;
 use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::ResultSpiller;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::QueryError;
@@ -409,10 +410,22 @@ impl HttpQuery {
         let result_timeout_secs = settings.get_http_handler_result_timeout_secs()?;
         let deduplicate_label = &ctx.deduplicate_label;
         let user_agent = &ctx.user_agent;
+        let query_tag = &ctx.query_tag;
+        let application_name = &ctx.application_name;
         let query_id = ctx.query_id.clone();
 
         session.set_client_host(ctx.client_host.clone());
 
+        // The client has gone away if it hasn't polled the result within the result timeout,
+        // which lets the session reaper kill the query like the other handlers do.
+        let expire_state = Arc::new(parking_lot::Mutex::new(ExpireState::Working));
+        let poll_state = expire_state.clone();
+        session.set_client_alive_probe(move || match *poll_state.lock() {
+            ExpireState::Working => true,
+            ExpireState::ExpireAt(expire_at) => Instant::now() < expire_at,
+            ExpireState::Removed(reason) => !matches!(reason, RemoveReason::Timeout),
+        });
+
         let http_ctx = ctx;
         let ctx = session.create_query_context().await?;
 
@@ -427,6 +440,16 @@ impl HttpQuery {
             ctx.set_ua(ua.clone());
         }
 
+        // The tag and the application name in the headers only apply to this query.
+        if let Some(query_tag) = query_tag {
+            ctx.get_settings()
+                .set_setting("query_tag".to_string(), query_tag.clone())?;
+        }
+        if let Some(application_name) = application_name {
+            ctx.get_settings()
+                .set_setting("application_name".to_string(), application_name.clone())?;
+        }
+
         // TODO: validate the query_id to be uuid format
         ctx.set_id(query_id.clone());
 
@@ -453,6 +476,7 @@ impl HttpQuery {
         };
 
         let (block_sender, block_receiver) = sized_spsc(request.pagination.max_rows_in_buffer);
+        let result_spiller = ResultSpiller::try_create(&ctx)?;
 
         let state = Arc::new(RwLock::new(Executor {
             query_id: query_id.clone(),
@@ -481,6 +505,7 @@ impl HttpQuery {
                     session,
                     ctx_clone.clone(),
                     block_sender,
+                    result_spiller,
                     format_settings_clone,
                 ))
                 .await
@@ -519,7 +544,7 @@ impl HttpQuery {
             state,
             page_manager: data,
             result_timeout_secs,
-            expire_state: Arc::new(parking_lot::Mutex::new(ExpireState::Working)),
+            expire_state,
             is_txn_mgr_saved: AtomicBool::new(false),
         };
 
@@ -34,7 +34,12 @@ pub struct HttpQueryContext {
     pub node_id: String,
     pub expected_node_id: Option<String>,
     pub deduplicate_label: Option<String>,
+    /// Retried requests of a query with the same key get the query created by the first one.
+    pub idempotency_key: Option<String>,
     pub user_agent: Option<String>,
+    /// Overrides the settings `query_tag` and `application_name` of the query.
+    pub query_tag: Option<String>,
+    pub application_name: Option<String>,
     pub trace_parent: Option<String>,
     pub opentelemetry_baggage: Option<Vec<(String, String)>>,
     pub http_method: String,
@@ -20,15 +20,24 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use chrono::SecondsFormat;
+use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
 use databend_common_base::base::tokio::time::sleep;
 use databend_common_base::base::GlobalInstance;
+use databend_common_base::headers::HEADER_NODE_ID;
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::UpsertKV;
+use databend_common_users::UserApiProvider;
 use databend_storages_common_txn::TxnManagerRef;
+use log::warn;
 use parking_lot::Mutex;
 use time::Instant;
 use tokio::task;
@@ -41,6 +50,23 @@ use crate::servers::http::v1::query::http_query::ServerInfo;
 use crate::servers::http::v1::query::HttpQueryRequest;
 use crate::sessions::Session;
 
+/// The idempotency keys are registered in the meta, so a retry sent to another node of the
+/// cluster knows which node has created the query.
+const IDEMPOTENCY_KEY_PREFIX: &str = "__fd_http_idempotency_keys";
+const IDEMPOTENCY_KEY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The query created by an idempotency key.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct IdempotentQuery {
+    node_id: String,
+    query_id: String,
+    sql: String,
+    /// The query is evicted from the node with its result, the retries are rejected instead
+    /// of running the query again until the key expires.
+    #[serde(default)]
+    evicted: bool,
+}
+
 #[derive(Clone, Debug, Copy)]
 pub(crate) enum RemoveReason {
     Timeout,
@@ -86,6 +112,10 @@ pub struct HttpQueryManager {
     #[allow(clippy::type_complexity)]
     pub(crate) txn_managers: Arc<Mutex<HashMap<String, (TxnManagerRef, task::JoinHandle<()>)>>>,
     pub(crate) sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
+    /// The query id and the sql of the query created by each idempotency key on this node,
+    /// the keys are scoped to the tenant and the user, and are kept as long as their queries
+    /// are.
+    pub(crate) idempotency_keys: DashMap<String, (String, String)>,
 }
 
 impl HttpQueryManager {
@@ -101,6 +131,7 @@ impl HttpQueryManager {
             sessions: Mutex::new(ExpiringMap::default()),
             removed_queries: Arc::new(parking_lot::Mutex::new(LimitedQueue::new(1000))),
             txn_managers: Arc::new(Mutex::new(HashMap::new())),
+            idempotency_keys: DashMap::new(),
         }));
 
         Ok(())
@@ -116,11 +147,209 @@ impl HttpQueryManager {
         ctx: &HttpQueryContext,
         request: HttpQueryRequest,
     ) -> Result<Arc<HttpQuery>> {
-        let query = HttpQuery::try_create(ctx, request).await?;
+        let query = match HttpQuery::try_create(ctx, request).await {
+            Ok(query) => query,
+            Err(e) => {
+                // the query is not created, a retry with the same key may create it again.
+                if let Some(key) = Self::scoped_idempotency_key(ctx)? {
+                    self.idempotency_keys
+                        .remove_if(&key, |_, (query_id, _)| query_id == &ctx.query_id);
+                    Self::unregister_idempotency_key(&key, &ctx.query_id).await;
+                }
+                return Err(e);
+            }
+        };
         self.add_query(&query.id, query.clone()).await;
         Ok(query)
     }
 
+    fn scoped_idempotency_key(ctx: &HttpQueryContext) -> Result<Option<String>> {
+        match &ctx.idempotency_key {
+            None => Ok(None),
+            Some(key) => Ok(Some(format!(
+                "{}/{}/{}",
+                ctx.session.get_current_tenant().tenant_name(),
+                ctx.session.get_current_user()?.identity().display(),
+                key
+            ))),
+        }
+    }
+
+    /// Returns the query created by a previous request with the same idempotency key, if
+    /// it is still kept, otherwise the key is taken by the query of this request, which is
+    /// to be created by [`Self::try_create_query`].
+    #[async_backtrace::framed]
+    pub(crate) async fn get_idempotent_query(
+        self: &Arc<Self>,
+        ctx: &HttpQueryContext,
+        sql: &str,
+    ) -> Result<Option<Arc<HttpQuery>>> {
+        let Some(key) = Self::scoped_idempotency_key(ctx)? else {
+            return Ok(None);
+        };
+        let query_id = match self.idempotency_keys.entry(key.clone()) {
+            Entry::Vacant(entry) => {
+                entry.insert((ctx.query_id.clone(), sql.to_string()));
+                None
+            }
+            Entry::Occupied(entry) => {
+                let (query_id, original_sql) = entry.get();
+                if original_sql != sql {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "idempotency key {:?} is already used by query {} of a different sql",
+                        ctx.idempotency_key.as_deref().unwrap_or_default(),
+                        query_id
+                    )));
+                }
+                Some(query_id.clone())
+            }
+        };
+        let Some(query_id) = query_id else {
+            // the key may be taken by a query on another node of the cluster
+            if let Err(e) = Self::register_idempotency_key(ctx, &key, sql).await {
+                self.idempotency_keys
+                    .remove_if(&key, |_, (query_id, _)| query_id == &ctx.query_id);
+                return Err(e);
+            }
+            return Ok(None);
+        };
+
+        // the query may be still being created by the previous request
+        for _ in 0..100 {
+            if let Some(query) = self.get_query(&query_id) {
+                return Ok(Some(query));
+            }
+            match self.idempotency_keys.get(&key) {
+                Some(entry) if entry.0 == query_id => {}
+                _ => break,
+            }
+            sleep(Duration::from_millis(100)).await;
+        }
+        Err(ErrorCode::Timeout(format!(
+            "query {} of idempotency key {:?} is not created in time, please retry",
+            query_id,
+            ctx.idempotency_key.as_deref().unwrap_or_default()
+        )))
+    }
+
+    fn idempotency_meta_key(key: &str) -> String {
+        format!("{}/{}", IDEMPOTENCY_KEY_PREFIX, key)
+    }
+
+    /// Take the key in the meta for the query of this request, fails if the key is taken by
+    /// a query on another node, which is where the retries should be sent.
+    #[async_backtrace::framed]
+    async fn register_idempotency_key(ctx: &HttpQueryContext, key: &str, sql: &str) -> Result<()> {
+        let meta_key = Self::idempotency_meta_key(key);
+        let value = serde_json::to_vec(&IdempotentQuery {
+            node_id: ctx.node_id.clone(),
+            query_id: ctx.query_id.clone(),
+            sql: sql.to_string(),
+            evicted: false,
+        })?;
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        let mut seq = MatchSeq::Exact(0);
+        loop {
+            let reply = meta_store
+                .upsert_kv(UpsertKV::new(
+                    &meta_key,
+                    seq,
+                    Operation::Update(value.clone()),
+                    Some(MetaSpec::new_ttl(IDEMPOTENCY_KEY_TTL)),
+                ))
+                .await?;
+            if reply.is_changed() {
+                return Ok(());
+            }
+            let Some(prev) = reply.prev else {
+                return Ok(());
+            };
+
+            let existing: IdempotentQuery = serde_json::from_slice(&prev.data)?;
+            if existing.sql != sql {
+                return Err(ErrorCode::BadArguments(format!(
+                    "idempotency key {:?} is already used by query {} of a different sql",
+                    ctx.idempotency_key.as_deref().unwrap_or_default(),
+                    existing.query_id
+                )));
+            }
+            if existing.evicted {
+                return Err(ErrorCode::BadArguments(format!(
+                    "query {} of idempotency key {:?} has expired and its result is no longer kept, please retry with a new idempotency key",
+                    existing.query_id,
+                    ctx.idempotency_key.as_deref().unwrap_or_default(),
+                )));
+            }
+            if existing.node_id != ctx.node_id {
+                return Err(ErrorCode::BadArguments(format!(
+                    "query {} of idempotency key {:?} is on server {}, please retry with header {}: {}",
+                    existing.query_id,
+                    ctx.idempotency_key.as_deref().unwrap_or_default(),
+                    existing.node_id,
+                    HEADER_NODE_ID,
+                    existing.node_id
+                )));
+            }
+            // the query is lost by this node, e.g. the node is restarted, take the key over.
+            seq = MatchSeq::Exact(prev.seq);
+        }
+    }
+
+    /// Mark the key of the evicted query in the meta, so the retries know the query has run
+    /// and don't run it again.
+    #[async_backtrace::framed]
+    async fn mark_idempotency_key_evicted(key: &str, query_id: &str) {
+        let meta_key = Self::idempotency_meta_key(key);
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        let res: Result<()> = async {
+            if let Some(seq_v) = meta_store.get_kv(&meta_key).await? {
+                let mut existing: IdempotentQuery = serde_json::from_slice(&seq_v.data)?;
+                if existing.query_id == query_id {
+                    existing.evicted = true;
+                    meta_store
+                        .upsert_kv(UpsertKV::new(
+                            &meta_key,
+                            MatchSeq::Exact(seq_v.seq),
+                            Operation::Update(serde_json::to_vec(&existing)?),
+                            Some(MetaSpec::new_ttl(IDEMPOTENCY_KEY_TTL)),
+                        ))
+                        .await?;
+                }
+            }
+            Ok(())
+        }
+        .await;
+        if let Err(e) = res {
+            warn!("failed to mark idempotency key {} evicted: {}", key, e);
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn unregister_idempotency_key(key: &str, query_id: &str) {
+        let meta_key = Self::idempotency_meta_key(key);
+        let meta_store = UserApiProvider::instance().get_meta_store_client();
+        let res: Result<()> = async {
+            if let Some(seq_v) = meta_store.get_kv(&meta_key).await? {
+                let existing: IdempotentQuery = serde_json::from_slice(&seq_v.data)?;
+                if existing.query_id == query_id {
+                    meta_store
+                        .upsert_kv(UpsertKV::new(
+                            &meta_key,
+                            MatchSeq::Exact(seq_v.seq),
+                            Operation::Delete,
+                            None,
+                        ))
+                        .await?;
+                }
+            }
+            Ok(())
+        }
+        .await;
+        if let Err(e) = res {
+            warn!("failed to remove idempotency key {}: {}", key, e);
+        }
+    }
+
     pub(crate) fn get_query(self: &Arc<Self>, query_id: &str) -> Option<Arc<HttpQuery>> {
         self.queries.get(query_id).map(|q| q.to_owned())
     }
@@ -184,15 +413,33 @@ impl HttpQueryManager {
         if let Some(q) = &query {
             if q.mark_removed(reason) {
                 q.kill(error).await;
-                let mut queue = self.removed_queries.lock();
-                if let Some(to_evict) = queue.push(q.id.to_string()) {
-                    self.queries.remove(&to_evict);
-                };
+                let to_evict = self.removed_queries.lock().push(q.id.to_string());
+                if let Some(to_evict) = to_evict {
+                    self.evict_query(&to_evict).await;
+                }
             }
         }
         query
     }
 
+    /// Drop the removed query with its result. A retry with the idempotency key of the query
+    /// fails as expired afterwards, instead of running the query again.
+    #[async_backtrace::framed]
+    pub async fn evict_query(self: &Arc<Self>, query_id: &str) {
+        self.queries.remove(query_id);
+        let mut evicted_keys = vec![];
+        self.idempotency_keys.retain(|key, (id, _)| {
+            let evicted = id == query_id;
+            if evicted {
+                evicted_keys.push(key.clone());
+            }
+            !evicted
+        });
+        for key in evicted_keys {
+            Self::mark_idempotency_key_evicted(&key, query_id).await;
+        }
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn add_txn(
         self: &Arc<Self>,
@@ -19,6 +19,7 @@ mod http_query;
 mod http_query_context;
 mod http_query_manager;
 mod page_manager;
+mod result_spill;
 pub mod sized_spsc;
 
 pub(crate) use execute_state::ExecuteState;
@@ -36,3 +37,6 @@ pub(crate) use http_query_manager::RemoveReason;
 pub use page_manager::PageManager;
 pub use page_manager::ResponseData;
 pub use page_manager::Wait;
+pub use result_spill::result_spill_prefix;
+pub use result_spill::ResultBlock;
+pub use result_spill::ResultSpiller;
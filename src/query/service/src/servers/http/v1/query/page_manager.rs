@@ -26,6 +26,7 @@ use log::info;
 use parking_lot::RwLock;
 
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
+use crate::servers::http::v1::query::ResultBlock;
 use crate::servers::http::v1::string_block::block_to_strings;
 use crate::servers::http::v1::StringBlock;
 
@@ -54,14 +55,14 @@ pub struct PageManager {
     block_end: bool,
     last_page: Option<Page>,
     row_buffer: VecDeque<Vec<String>>,
-    block_receiver: SizedChannelReceiver<DataBlock>,
+    block_receiver: SizedChannelReceiver<ResultBlock>,
     format_settings: Arc<RwLock<Option<FormatSettings>>>,
 }
 
 impl PageManager {
     pub fn new(
         max_rows_per_page: usize,
-        block_receiver: SizedChannelReceiver<DataBlock>,
+        block_receiver: SizedChannelReceiver<ResultBlock>,
         format_settings: Arc<RwLock<Option<FormatSettings>>>,
     ) -> PageManager {
         PageManager {
@@ -182,6 +183,7 @@ impl PageManager {
             match tp {
                 Wait::Async => match self.block_receiver.try_recv() {
                     Some(block) => {
+                        let block = block.into_data_block().await?;
                         self.append_block(&mut res, block, remain_rows, &mut max_size_per_page)?
                     }
                     None => break,
@@ -192,6 +194,7 @@ impl PageManager {
                     match tokio::time::timeout(d, self.block_receiver.recv()).await {
                         Ok(Some(block)) => {
                             debug!("http query got new block with {} rows", block.num_rows());
+                            let block = block.into_data_block().await?;
                             self.append_block(
                                 &mut res,
                                 block,
@@ -225,6 +228,10 @@ impl PageManager {
     #[async_backtrace::framed]
     pub async fn detach(&mut self) {
         self.block_receiver.close();
+        // Remove the spilled blocks the client will not read.
+        while let Some(block) = self.block_receiver.try_recv() {
+            block.discard().await;
+        }
         self.last_page = None;
         self.row_buffer.clear()
     }
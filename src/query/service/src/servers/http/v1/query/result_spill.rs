@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Spills the result blocks of a query the client reads slower than the query produces them,
+//! so that the query neither waits for the client nor buffers its results in memory.
+//!
+//! The spilled blocks are written under the temporary directory of the session, they are
+//! removed once read back, the ones left by the abandoned queries are removed on session end.
+//! They are encrypted by the key of the query if `enable_spill_encryption` is on.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_base::base::GlobalUniqName;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::arrow::deserialize_column;
+use databend_common_expression::arrow::serialize_column;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_storage::DataOperator;
+use log::debug;
+use log::warn;
+use opendal::Operator;
+
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::spillers::SpillEncryption;
+
+/// The temporary directory of the result blocks spilled by the queries of a session.
+pub fn result_spill_prefix(tenant: &str, session_id: &str) -> String {
+    query_spill_prefix(tenant, &format!("{}-results", session_id))
+}
+
+pub struct SpilledResultBlock {
+    spiller: Arc<ResultSpiller>,
+    location: String,
+    columns_layout: Vec<usize>,
+    num_rows: usize,
+}
+
+/// A result block sent to the page manager.
+pub enum ResultBlock {
+    Memory(DataBlock),
+    Spilled(SpilledResultBlock),
+}
+
+impl ResultBlock {
+    pub fn num_rows(&self) -> usize {
+        match self {
+            ResultBlock::Memory(block) => block.num_rows(),
+            ResultBlock::Spilled(spilled) => spilled.num_rows,
+        }
+    }
+
+    /// Reads the spilled block back and removes it.
+    #[async_backtrace::framed]
+    pub async fn into_data_block(self) -> Result<DataBlock> {
+        let spilled = match self {
+            ResultBlock::Memory(block) => return Ok(block),
+            ResultBlock::Spilled(spilled) => spilled,
+        };
+
+        let data = spilled
+            .spiller
+            .operator
+            .read(&spilled.location)
+            .await?
+            .to_vec();
+        let data = spilled.spiller.encryption.decrypt(data)?;
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(spilled.columns_layout.len());
+        for column_layout in spilled.columns_layout.iter() {
+            columns.push(deserialize_column(&data[begin..begin + column_layout])?);
+            begin += column_layout;
+        }
+        let block = if columns.is_empty() {
+            DataBlock::new(vec![], spilled.num_rows)
+        } else {
+            DataBlock::new_from_columns(columns)
+        };
+
+        spilled.spiller.remove(&spilled.location).await;
+        Ok(block)
+    }
+
+    /// Removes the spilled block the client will not read.
+    #[async_backtrace::framed]
+    pub async fn discard(self) {
+        if let ResultBlock::Spilled(spilled) = self {
+            spilled.spiller.remove(&spilled.location).await;
+        }
+    }
+}
+
+pub struct ResultSpiller {
+    operator: Operator,
+    encryption: Arc<SpillEncryption>,
+    location_prefix: String,
+    max_bytes: usize,
+    spilled_bytes: AtomicUsize,
+    // The spilled blocks are removed when the session ends, it's kept until they are read.
+    session: Arc<Session>,
+}
+
+impl ResultSpiller {
+    /// Returns None if `enable_result_spill` is off.
+    pub fn try_create(ctx: &Arc<QueryContext>) -> Result<Option<Arc<ResultSpiller>>> {
+        let settings = ctx.get_settings();
+        if !settings.get_enable_result_spill()? {
+            return Ok(None);
+        }
+
+        let session = ctx.get_current_session();
+        let tenant = ctx.get_tenant();
+        Ok(Some(Arc::new(ResultSpiller {
+            operator: DataOperator::instance().operator(),
+            encryption: ctx.get_spill_encryption()?,
+            location_prefix: result_spill_prefix(tenant.tenant_name(), &session.get_id()),
+            max_bytes: settings.get_result_spill_max_bytes()?,
+            spilled_bytes: AtomicUsize::new(0),
+            session,
+        })))
+    }
+
+    /// Writes the block to the temporary directory, returns it back if the query has spilled
+    /// `result_spill_max_bytes` already.
+    #[async_backtrace::framed]
+    pub async fn spill(self: &Arc<Self>, block: DataBlock) -> Result<ResultBlock> {
+        let num_rows = block.num_rows();
+        let mut columns_layout = Vec::with_capacity(block.num_columns());
+        let mut columns_data = Vec::with_capacity(block.num_columns());
+        for entry in block.columns() {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let column_data = serialize_column(&column);
+            columns_layout.push(column_data.len());
+            columns_data.push(column_data);
+        }
+
+        let bytes = columns_layout.iter().sum::<usize>();
+        let spilled_bytes = self.spilled_bytes.fetch_add(bytes, Ordering::SeqCst);
+        if self.max_bytes != 0 && spilled_bytes + bytes > self.max_bytes {
+            self.spilled_bytes.fetch_sub(bytes, Ordering::SeqCst);
+            return Ok(ResultBlock::Memory(block));
+        }
+
+        self.session.set_result_spilled();
+        let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+        let data = self.encryption.encrypt(columns_data.concat())?;
+        self.operator.write(&location, data).await?;
+        debug!(
+            "http query spilled result block with {} rows to {}",
+            num_rows, location
+        );
+
+        Ok(ResultBlock::Spilled(SpilledResultBlock {
+            spiller: self.clone(),
+            location,
+            columns_layout,
+            num_rows,
+        }))
+    }
+
+    async fn remove(&self, location: &str) {
+        // It's removed on session end otherwise.
+        if let Err(e) = self.operator.delete(location).await {
+            warn!("failed to remove spilled result block {}: {}", location, e);
+        }
+    }
+}
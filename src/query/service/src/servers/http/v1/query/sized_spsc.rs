@@ -203,6 +203,19 @@ impl<T> SizedChannelSender<T> {
         self.chan.send(value, size).await
     }
 
+    /// Sends the value without waiting, returns it back if the channel is full. The value is
+    /// dropped if the channel is stopped.
+    pub fn try_send(&self, value: T, size: usize) -> Option<T> {
+        match self.chan.try_send(value, size) {
+            Ok(Some(value)) => Some(value),
+            Ok(None) => {
+                self.chan.notify_on_sent.notify_one();
+                None
+            }
+            Err(_) => None,
+        }
+    }
+
     pub fn close(&self) {
         self.chan.stop_send()
     }
@@ -218,8 +218,6 @@ impl MySQLFederated {
             (Regex::new("(?i)^(UNLOCK BINLOG(.*))").unwrap(), None),
             (Regex::new("(?i)^(/\\*!40101 SET(.*) \\*/)$").unwrap(), None),
             // DBeaver.
-            (Regex::new("(?i)^(SHOW WARNINGS)").unwrap(), None),
-            (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW WARNINGS)").unwrap(), None),
             (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW PLUGINS)").unwrap(), None),
             (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW COLLATION)").unwrap(), None),
             (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW CHARSET)").unwrap(), None),
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::Instant;
 
 use databend_common_base::base::convert_byte_size;
@@ -64,6 +65,8 @@ use crate::stream::DataBlockStream;
 
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    // The time the last query of the session finished, used to detect idle transactions.
+    last_query_finished: Instant,
 }
 
 pub struct InteractiveWorker {
@@ -231,6 +234,7 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
                 write_result = Err(cause.add_message_back(suffix));
             }
             observe_mysql_process_request_duration(instant.elapsed());
+            self.base.last_query_finished = Instant::now();
 
             write_result
         })
@@ -264,6 +268,29 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
 }
 
 impl InteractiveWorkerBase {
+    // Fail the open transaction if the session stays idle in it longer than idle_transaction_timeout_secs.
+    fn check_idle_transaction(&self) -> Result<()> {
+        let txn_mgr = self.session.txn_mgr();
+        let mut txn_mgr = txn_mgr.lock();
+        if !txn_mgr.is_active() {
+            return Ok(());
+        }
+
+        let timeout = self
+            .session
+            .get_settings()
+            .get_idle_transaction_timeout_secs()?;
+        let idle = self.last_query_finished.elapsed();
+        if idle > Duration::from_secs(timeout) {
+            txn_mgr.set_fail();
+            return Err(ErrorCode::TransactionTimeout(format!(
+                "transaction timeout: session is idle in transaction for {:?}, exceeds idle_transaction_timeout_secs {}",
+                idle, timeout
+            )));
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
         let ctx = self.session.create_query_context().await?;
@@ -368,6 +395,7 @@ impl InteractiveWorkerBase {
             }
             None => {
                 info!("Normal query: {}", query);
+                self.check_idle_transaction()?;
                 let context = self.session.create_query_context().await?;
                 context.set_id(query_id);
 
@@ -472,7 +500,10 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                last_query_finished: Instant::now(),
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
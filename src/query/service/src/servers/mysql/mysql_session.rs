@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::ErrorKind;
 use std::net::Shutdown;
+use std::os::fd::AsRawFd;
 use std::sync::Arc;
 
 use databend_common_base::base::tokio::io::BufWriter;
@@ -102,9 +104,36 @@ impl MySQLConnection {
             }
         });
 
+        let probe_stream = blocking_stream.try_clone()?;
+        session.set_client_alive_probe(move || Self::is_peer_alive(&probe_stream));
+
         Ok(())
     }
 
+    // The client sends nothing while a query is running, so a readable EOF or a socket
+    // error (e.g. keepalive timeout) means the connection has gone away.
+    fn is_peer_alive(stream: &std::net::TcpStream) -> bool {
+        let mut buf = [0_u8; 1];
+        // MSG_DONTWAIT keeps the probe non-blocking without touching the shared O_NONBLOCK flag.
+        let n = unsafe {
+            libc::recv(
+                stream.as_raw_fd(),
+                buf.as_mut_ptr() as *mut libc::c_void,
+                buf.len(),
+                libc::MSG_PEEK | libc::MSG_DONTWAIT,
+            )
+        };
+
+        match n {
+            0 => false,
+            n if n > 0 => true,
+            _ => matches!(
+                std::io::Error::last_os_error().kind(),
+                ErrorKind::WouldBlock | ErrorKind::Interrupted
+            ),
+        }
+    }
+
     // TODO: move to ToBlockingStream trait
     fn convert_stream(stream: TcpStream) -> Result<std::net::TcpStream> {
         let stream = stream.into_std().map_err_to_code(
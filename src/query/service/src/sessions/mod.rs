@@ -16,6 +16,7 @@ mod query_affect;
 pub mod query_ctx;
 mod query_ctx_shared;
 mod queue_mgr;
+mod repeated_subqueries;
 mod session;
 mod session_ctx;
 mod session_info;
@@ -35,6 +36,7 @@ pub use queue_mgr::QueriesQueueManager;
 pub use queue_mgr::QueryEntry;
 pub use queue_mgr::QueueData;
 pub use queue_mgr::QueueManager;
+pub use repeated_subqueries::RepeatedSubqueries;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
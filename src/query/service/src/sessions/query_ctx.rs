@@ -28,6 +28,7 @@ use std::time::Instant;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
+use chrono::DateTime;
 use chrono::Utc;
 use chrono_tz::Tz;
 use dashmap::mapref::multiple::RefMulti;
@@ -44,15 +45,21 @@ use databend_common_catalog::plan::DataSourceInfo;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::ParquetReadOptions;
 use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::FilteredCopyFiles;
+use databend_common_catalog::table_context::MaintenanceProgress;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
+use databend_common_catalog::table_context::QueryFragmentInfo;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::table_context::SubqueryMaterialization;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -62,6 +69,7 @@ use databend_common_expression::BlockThresholds;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
+use databend_common_expression::RandomSeed;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
@@ -96,11 +104,14 @@ use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
 use databend_common_storage::StorageMetrics;
 use databend_common_storages_delta::DeltaTable;
+use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
+use databend_common_storages_hudi::HudiTable;
 use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_orc::OrcTable;
 use databend_common_storages_parquet::ParquetRSTable;
 use databend_common_storages_result_cache::ResultScan;
+use databend_common_storages_stage::FileFormatPluginRegistry;
 use databend_common_storages_stage::StageTable;
 use databend_common_users::GrantObjectVisibilityChecker;
 use databend_common_users::UserApiProvider;
@@ -108,22 +119,32 @@ use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_txn::TxnManagerRef;
 use log::debug;
 use log::info;
+use log::warn;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 use xorf::BinaryFuse16;
 
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
+use crate::clusters::ClusterHelper;
+use crate::interpreters::common::execute_sql_in_session;
+use crate::interpreters::finish_column_backfills;
+use crate::interpreters::BackfillDesc;
 use crate::locks::LockManager;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::SkewedJoinKeys;
+use crate::servers::flight::v1::actions::PrunePartitionsPacket;
+use crate::servers::flight::v1::actions::PRUNE_PARTITIONS;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContextShared;
+use crate::sessions::RepeatedSubqueries;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::spillers::SpillEncryption;
 use crate::sql::binder::get_storage_params_from_options;
 use crate::storages::Table;
 
@@ -244,6 +265,77 @@ impl QueryContext {
         self.shared.session.clone()
     }
 
+    // Get the scope of the cached hash join build sides by the `hash_join_build_cache_scope`
+    // setting, the build sides are cached by the session id or shared by the warehouse node.
+    pub fn get_hash_join_build_cache_scope(&self) -> Result<Option<String>> {
+        let settings = self.get_settings();
+        match settings.get_hash_join_build_cache_scope()?.as_str() {
+            "session" => Ok(Some(self.shared.session.get_id())),
+            "warehouse" => Ok(Some(String::new())),
+            _ => Ok(None),
+        }
+    }
+
+    // Get the runs and the materializations of the subqueries by the
+    // `repeated_subquery_tracking_scope` setting.
+    pub fn get_repeated_subqueries(&self) -> Result<Arc<RepeatedSubqueries>> {
+        let settings = self.get_settings();
+        match settings.get_repeated_subquery_tracking_scope()?.as_str() {
+            "warehouse" => Ok(RepeatedSubqueries::instance()),
+            _ => Ok(self.shared.session.session_ctx.get_repeated_subqueries()),
+        }
+    }
+
+    // The results of the subqueries depend on the privileges and the masking policies applied
+    // to the user and the roles, so they are only shared by the same user with the same roles.
+    fn qualify_subquery_fingerprint(&self, fingerprint: &str) -> Result<String> {
+        Ok(format!(
+            "{} /* {}, database: {} */",
+            fingerprint,
+            self.query_result_qualifier()?,
+            self.get_current_database(),
+        ))
+    }
+
+    /// Qualifies the results cached across the queries, which depend on the privileges and
+    /// the masking policies applied to the user and the roles, and on the settings of the
+    /// function context (timezone, datetime parsing, numeric casting, ...). They are only
+    /// shared by the queries with the same qualifier.
+    pub fn query_result_qualifier(&self) -> Result<String> {
+        let user = self.get_current_user()?;
+        let secondary_roles = match self.get_current_session().get_secondary_roles() {
+            Some(roles) => roles.join(","),
+            None => "ALL".to_string(),
+        };
+        Ok(format!(
+            "user: {}, role: {}, secondary roles: {}, function context: {}",
+            user.identity().display(),
+            self.get_current_role()
+                .map(|role| role.name)
+                .unwrap_or_default(),
+            secondary_roles,
+            self.function_context_fingerprint()?,
+        ))
+    }
+
+    /// The settings read by [`TableContext::get_function_context`] that change the results of
+    /// the functions.
+    fn function_context_fingerprint(&self) -> Result<String> {
+        let settings = self.get_settings();
+        Ok(format!(
+            "timezone={}, numeric_cast_option={}, disable_variant_check={}, geometry_output_format={}, parse_datetime_ignore_remainder={}, enable_dst_hour_fix={}, enable_strict_datetime_parser={}, strict_sql_mode={}, random_seed={:?}",
+            settings.get_timezone()?,
+            settings.get_numeric_cast_option()?,
+            settings.get_disable_variant_check()?,
+            settings.get_geometry_output_format()?,
+            settings.get_parse_datetime_ignore_remainder()?,
+            settings.get_enable_dst_hour_fix()?,
+            settings.get_enable_strict_datetime_parser()?,
+            settings.get_strict_sql_mode()?,
+            settings.get_random_seed()?,
+        ))
+    }
+
     // Get one session by session id.
     pub fn get_session_by_id(self: &Arc<Self>, id: &str) -> Option<Arc<Session>> {
         SessionManager::instance().get_session_by_id(id)
@@ -272,6 +364,25 @@ impl QueryContext {
         self.shared.pop_warnings()
     }
 
+    pub fn get_warnings(&self) -> Vec<String> {
+        self.shared.get_warnings()
+    }
+
+    /// Returns the (catalog, database, table) names and the tables read by the query.
+    pub fn get_named_tables_refs(&self) -> Vec<((String, String, String), Arc<dyn Table>)> {
+        self.shared.get_named_tables_refs()
+    }
+
+    /// Keeps the warnings of the last query of the session from being replaced by the
+    /// warnings of this query.
+    pub fn keep_last_warnings(&self) {
+        self.shared.keep_last_warnings()
+    }
+
+    pub fn update_last_query_warnings(&self) {
+        self.shared.update_last_query_warnings()
+    }
+
     pub fn get_data_metrics(&self) -> StorageMetrics {
         self.shared.get_data_metrics()
     }
@@ -309,21 +420,55 @@ impl QueryContext {
         (finish_time - query_start_time) / 1_000
     }
 
-    pub fn get_created_time(&self) -> SystemTime {
-        self.shared.created_time
-    }
-
     pub fn set_finish_time(&self, time: SystemTime) {
         *self.shared.finish_time.write() = Some(time)
     }
 
+    pub fn get_function_now(&self) -> SystemTime {
+        self.shared
+            .function_now
+            .read()
+            .unwrap_or(self.shared.created_time)
+    }
+
+    pub fn set_function_now(&self, now: SystemTime) {
+        *self.shared.function_now.write() = Some(now)
+    }
+
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         self.shared.evict_table_from_cache(catalog, database, table)
     }
 
+    pub fn get_skewed_join_keys(&self, join_id: u32) -> Arc<SkewedJoinKeys> {
+        self.shared
+            .skewed_join_keys
+            .write()
+            .entry(join_id)
+            .or_default()
+            .clone()
+    }
+
     pub fn clear_tables_cache(&self) {
         self.shared.clear_tables_cache()
     }
+
+    // Set the encryption with the key shared by the node coordinating the query.
+    pub fn set_spill_encryption(&self, spill_encryption: Arc<SpillEncryption>) {
+        *self.shared.spill_encryption.write() = Some(spill_encryption);
+    }
+
+    // Get the encryption of the data spilled by the query.
+    pub fn get_spill_encryption(&self) -> Result<Arc<SpillEncryption>> {
+        let mut spill_encryption = self.shared.spill_encryption.write();
+        if let Some(spill_encryption) = spill_encryption.as_ref() {
+            return Ok(spill_encryption.clone());
+        }
+
+        let enable = self.get_settings().get_enable_spill_encryption()?;
+        Ok(spill_encryption
+            .insert(Arc::new(SpillEncryption::create(enable)))
+            .clone())
+    }
 }
 
 #[async_trait::async_trait]
@@ -418,6 +563,14 @@ impl TableContext for QueryContext {
         *status = info.to_string();
     }
 
+    fn get_maintenance_progress(&self) -> Option<MaintenanceProgress> {
+        self.shared.maintenance_progress.read().clone()
+    }
+
+    fn set_maintenance_progress(&self, progress: MaintenanceProgress) {
+        *self.shared.maintenance_progress.write() = Some(progress);
+    }
+
     fn get_data_cache_metrics(&self) -> &DataCacheMetrics {
         self.shared.get_query_cache_metrics()
     }
@@ -462,6 +615,58 @@ impl TableContext for QueryContext {
         Ok(())
     }
 
+    #[async_backtrace::framed]
+    async fn prune_lazy_parts_in_cluster(
+        &self,
+        table_info: &TableInfo,
+        push_downs: Option<PushDownInfo>,
+        snapshot_loc: Option<String>,
+        lazy_parts: Partitions,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let cluster = self.get_cluster();
+        let settings = self.get_settings();
+        let executors = cluster.nodes.iter().map(|node| node.id.clone()).collect();
+
+        let mut message = HashMap::with_capacity(cluster.nodes.len());
+        for (executor, lazy_parts) in lazy_parts.reshuffle(executors)? {
+            if !lazy_parts.is_empty() {
+                message.insert(executor, PrunePartitionsPacket {
+                    settings: settings.clone(),
+                    table_info: table_info.clone(),
+                    push_downs: push_downs.clone(),
+                    snapshot_loc: snapshot_loc.clone(),
+                    lazy_parts,
+                });
+            }
+        }
+
+        let timeout = settings.get_flight_client_timeout()?;
+        let mut responses = cluster
+            .do_action::<_, (PartStatistics, Partitions)>(PRUNE_PARTITIONS, message, timeout)
+            .await?
+            .into_iter()
+            .collect::<Vec<_>>();
+        // Keep the surviving parts in the order of the executors.
+        responses.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut statistics = PartStatistics {
+            snapshot: snapshot_loc,
+            is_exact: true,
+            ..Default::default()
+        };
+        let mut partitions = Vec::new();
+        for (_, (executor_statistics, executor_partitions)) in responses {
+            statistics.merge(&executor_statistics);
+            statistics.is_exact &= executor_statistics.is_exact;
+            partitions.extend(executor_partitions.partitions);
+        }
+
+        Ok((
+            statistics,
+            Partitions::create(PartitionsShuffleKind::Mod, partitions),
+        ))
+    }
+
     fn partition_num(&self) -> usize {
         self.partition_queue.read().len()
     }
@@ -520,6 +725,20 @@ impl TableContext for QueryContext {
             .store(hint, Ordering::Release);
     }
 
+    // get a hint at the number of segments more than the blocks need.
+    fn get_compaction_num_segment_hint(&self) -> u64 {
+        self.shared
+            .num_fragmented_segment_hint
+            .load(Ordering::Acquire)
+    }
+
+    // set a hint at the number of segments more than the blocks need.
+    fn set_compaction_num_segment_hint(&self, hint: u64) {
+        self.shared
+            .num_fragmented_segment_hint
+            .store(hint, Ordering::Release);
+    }
+
     fn attach_query_str(&self, kind: QueryKind, query: String) {
         self.shared.attach_query_str(kind, query);
     }
@@ -567,6 +786,10 @@ impl TableContext for QueryContext {
         self.shared.init_query_id.as_ref().read().clone()
     }
 
+    fn get_created_time(&self) -> SystemTime {
+        self.shared.created_time
+    }
+
     fn get_current_catalog(&self) -> String {
         self.shared.get_current_catalog()
     }
@@ -583,6 +806,100 @@ impl TableContext for QueryContext {
         self.shared.push_warning(warn)
     }
 
+    fn record_subquery_run(&self, fingerprint: &str) -> Result<u64> {
+        let fingerprint = self.qualify_subquery_fingerprint(fingerprint)?;
+        Ok(self.get_repeated_subqueries()?.record_run(&fingerprint))
+    }
+
+    fn get_subquery_materialization(
+        &self,
+        fingerprint: &str,
+        version: &str,
+    ) -> Result<Option<Arc<Vec<DataBlock>>>> {
+        let fingerprint = self.qualify_subquery_fingerprint(fingerprint)?;
+        Ok(self.get_repeated_subqueries()?.get(&fingerprint, version))
+    }
+
+    fn add_pending_subquery_materialization(&self, materialization: SubqueryMaterialization) {
+        self.shared
+            .add_pending_subquery_materialization(materialization)
+    }
+
+    #[async_backtrace::framed]
+    async fn materialize_pending_subqueries(&self) -> Result<bool> {
+        let pending = self.shared.take_pending_subquery_materializations();
+        if pending.is_empty() {
+            return Ok(false);
+        }
+
+        let settings = self.get_settings();
+        let repeated_subqueries = self.get_repeated_subqueries()?;
+        // The cache of the warehouse is shared by all the sessions of the node, so its cap
+        // comes from the config instead of the setting of whoever materializes first.
+        let max_bytes = match settings.get_repeated_subquery_tracking_scope()?.as_str() {
+            "warehouse" => {
+                GlobalConfig::instance()
+                    .query
+                    .subquery_materialization_cache_bytes as usize
+            }
+            _ => settings.get_subquery_materialization_max_bytes()?,
+        };
+        let mut materialized = false;
+        for materialization in pending {
+            let fingerprint = self.qualify_subquery_fingerprint(&materialization.fingerprint)?;
+            if repeated_subqueries.contains(&fingerprint, &materialization.version) {
+                materialized = true;
+                continue;
+            }
+            match execute_sql_in_session(&self.get_current_session(), &materialization.sql).await {
+                Ok(blocks) => {
+                    if repeated_subqueries.put(
+                        fingerprint,
+                        materialization.version,
+                        blocks,
+                        max_bytes,
+                    ) {
+                        materialized = true;
+                    } else {
+                        info!(
+                            "the result of the subquery ({}) exceeds subquery_materialization_max_bytes, not materialized",
+                            materialization.sql
+                        );
+                    }
+                }
+                Err(e) => warn!(
+                    "failed to materialize the subquery ({}): {}",
+                    materialization.sql, e
+                ),
+            }
+        }
+        Ok(materialized)
+    }
+
+    #[async_backtrace::framed]
+    async fn finish_column_backfills(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<()> {
+        let tbl = self.get_table(catalog, database, table).await?;
+        let Ok(fuse_table) = FuseTable::try_from_table(tbl.as_ref()) else {
+            return Ok(());
+        };
+        if fuse_table.column_backfills()?.is_empty() {
+            return Ok(());
+        }
+        let desc = BackfillDesc {
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+        };
+        finish_column_backfills(&self.get_current_session(), &desc).await?;
+        // The table is read again with the backfilled snapshot.
+        self.evict_table_from_cache(catalog, database, table)
+    }
+
     fn get_current_database(&self) -> String {
         self.shared.get_current_database()
     }
@@ -664,7 +981,7 @@ impl TableContext for QueryContext {
 
         let tz = settings.get_timezone()?;
         let tz = TzFactory::instance().get_by_name(&tz)?;
-        let now = Utc::now();
+        let now = DateTime::<Utc>::from(self.get_function_now());
         let numeric_cast_option = settings.get_numeric_cast_option()?;
         let rounding_mode = numeric_cast_option.as_str() == "rounding";
         let disable_variant_check = settings.get_disable_variant_check()?;
@@ -672,6 +989,8 @@ impl TableContext for QueryContext {
         let parse_datetime_ignore_remainder = settings.get_parse_datetime_ignore_remainder()?;
         let enable_dst_hour_fix = settings.get_enable_dst_hour_fix()?;
         let enable_strict_datetime_parser = settings.get_enable_strict_datetime_parser()?;
+        let strict_mode = settings.get_strict_sql_mode()?;
+        let random_seed = settings.get_random_seed()?.map(RandomSeed::new);
         let query_config = &GlobalConfig::instance().query;
 
         Ok(FunctionContext {
@@ -694,6 +1013,8 @@ impl TableContext for QueryContext {
             parse_datetime_ignore_remainder,
             enable_dst_hour_fix,
             enable_strict_datetime_parser,
+            strict_mode,
+            random_seed,
         })
     }
 
@@ -823,6 +1144,10 @@ impl TableContext for QueryContext {
             }
         }
     }
+
+    fn check_file_format_params(&self, params: &FileFormatParams) -> Result<()> {
+        FileFormatPluginRegistry::instance().check_file_format_params(params)
+    }
     async fn get_connection(&self, name: &str) -> Result<UserDefinedConnection> {
         self.shared.get_connection(name).await
     }
@@ -857,6 +1182,12 @@ impl TableContext for QueryContext {
                 info.meta.storage_params = Some(sp);
                 DeltaTable::try_create(info.to_owned())?.into()
             }
+            "HUDI" => {
+                let sp = get_storage_params_from_options(self, table.options()).await?;
+                let mut info = table.get_table_info().to_owned();
+                info.meta.storage_params = Some(sp);
+                HudiTable::try_create(info.to_owned())?.into()
+            }
             _ => table,
         };
         Ok(table)
@@ -1031,6 +1362,10 @@ impl TableContext for QueryContext {
         SessionManager::instance().get_queries_profiles()
     }
 
+    fn get_queries_fragments(&self) -> Vec<QueryFragmentInfo> {
+        DataExchangeManager::instance().get_queries_fragments()
+    }
+
     fn set_merge_into_join(&self, join: MergeIntoJoin) {
         let mut merge_into_join = self.shared.merge_into_join.write();
         *merge_into_join = join;
@@ -1132,6 +1467,7 @@ impl TableContext for QueryContext {
                 let table = DeltaTable::load(sp).await?;
                 DeltaTable::get_meta(&table).await
             }
+            "hudi" => HudiTable::get_meta(sp).await,
             // TODO: iceberg doesn't support load from storage directly.
             _ => Err(ErrorCode::Internal("unsupported datalake type {}")),
         }
@@ -1156,6 +1492,10 @@ impl TableContext for QueryContext {
                     read_options = read_options.with_prune_row_groups(false);
                 }
 
+                if !self.get_settings().get_enable_parquet_bloom_filter()? {
+                    read_options = read_options.with_prune_bloom_filters(false);
+                }
+
                 if !self.get_settings().get_enable_parquet_prewhere()? {
                     read_options = read_options.with_do_prewhere(false);
                 }
@@ -1199,11 +1539,14 @@ impl TableContext for QueryContext {
                 };
                 StageTable::try_create(info)
             }
-            FileFormatParams::Csv(..) | FileFormatParams::Tsv(..) => {
+            FileFormatParams::Csv(..)
+            | FileFormatParams::Tsv(..)
+            | FileFormatParams::Plugin(..) => {
                 if max_column_position == 0 {
-                    let file_type = match stage_info.file_format_params {
+                    let file_type = match &stage_info.file_format_params {
                         FileFormatParams::Csv(..) => "CSV",
                         FileFormatParams::Tsv(..) => "TSV",
+                        FileFormatParams::Plugin(p) => p.format_name.as_str(),
                         _ => unreachable!(), // This branch should never be reached
                     };
 
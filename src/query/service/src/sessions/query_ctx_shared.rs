@@ -22,6 +22,8 @@ use std::sync::Weak;
 use std::time::Duration;
 use std::time::SystemTime;
 
+use chrono::DateTime;
+use chrono::Utc;
 use dashmap::DashMap;
 use databend_common_base::base::short_sql;
 use databend_common_base::base::Progress;
@@ -32,10 +34,15 @@ use databend_common_catalog::merge_into_join::MergeIntoJoin;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_catalog::table::TimeNavigation;
+use databend_common_catalog::table_context::MaintenanceProgress;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::table_context::SubqueryMaterialization;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::CheckAbort;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
@@ -52,14 +59,18 @@ use databend_common_storage::MultiTableInsertStatus;
 use databend_common_storage::StorageMetrics;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_users::UserApiProvider;
+use log::info;
+use log::warn;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 use uuid::Uuid;
 
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::SkewedJoinKeys;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
+use crate::spillers::SpillEncryption;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String, String);
@@ -82,6 +93,14 @@ pub struct QueryContextShared {
     pub(in crate::sessions) result_progress: Arc<Progress>,
     pub(in crate::sessions) error: Arc<Mutex<Option<ErrorCode>>>,
     pub(in crate::sessions) warnings: Arc<Mutex<Vec<String>>>,
+    /// All the warnings of the query, which are kept for `SHOW WARNINGS` after they are
+    /// popped from `warnings`.
+    pub(in crate::sessions) query_warnings: Arc<Mutex<Vec<String>>>,
+    /// Whether to keep the warnings of the last query of the session when the query
+    /// finishes, which is set by `SHOW WARNINGS`.
+    pub(in crate::sessions) keep_last_warnings: Arc<AtomicBool>,
+    pub(in crate::sessions) pending_subquery_materializations:
+        Arc<Mutex<Vec<SubqueryMaterialization>>>,
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
@@ -100,6 +119,9 @@ pub struct QueryContextShared {
     pub(in crate::sessions) created_time: SystemTime,
     // now it is only set in query_log::log_query_finished
     pub(in crate::sessions) finish_time: RwLock<Option<SystemTime>>,
+    // The timestamp used as `now()` by functions, it's the created time of the query
+    // on the coordinator node for the fragments of a distributed query.
+    pub(in crate::sessions) function_now: RwLock<Option<SystemTime>>,
     // DashMap<file_path, HashMap<ErrorCode::code, (ErrorCode, Number of occurrences)>>
     // We use this field to count maximum of one error found per data file.
     #[allow(clippy::type_complexity)]
@@ -114,9 +136,11 @@ pub struct QueryContextShared {
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     pub(in crate::sessions) can_scan_from_agg_index: Arc<AtomicBool>,
     pub(in crate::sessions) num_fragmented_block_hint: Arc<AtomicU64>,
+    pub(in crate::sessions) num_fragmented_segment_hint: Arc<AtomicU64>,
     pub(in crate::sessions) enable_sort_spill: Arc<AtomicBool>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
+    pub(in crate::sessions) maintenance_progress: Arc<RwLock<Option<MaintenanceProgress>>>,
 
     // Client User-Agent
     pub(in crate::sessions) user_agent: Arc<RwLock<String>>,
@@ -133,6 +157,12 @@ pub struct QueryContextShared {
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
 
     pub(in crate::sessions) query_queued_duration: Arc<RwLock<Duration>>,
+
+    /// Hot keys agreed on by the build side of each skewed hash join, keyed by join plan id.
+    pub(in crate::sessions) skewed_join_keys: Arc<RwLock<HashMap<u32, Arc<SkewedJoinKeys>>>>,
+
+    /// The key of the spilled data is generated on the first spill, and dropped with the query.
+    pub(in crate::sessions) spill_encryption: Arc<RwLock<Option<Arc<SpillEncryption>>>>,
 }
 
 impl QueryContextShared {
@@ -152,6 +182,9 @@ impl QueryContextShared {
             write_progress: Arc::new(Progress::create()),
             error: Arc::new(Mutex::new(None)),
             warnings: Arc::new(Mutex::new(vec![])),
+            query_warnings: Arc::new(Mutex::new(vec![])),
+            keep_last_warnings: Arc::new(AtomicBool::new(false)),
+            pending_subquery_materializations: Arc::new(Mutex::new(vec![])),
             runtime: Arc::new(RwLock::new(None)),
             running_query: Arc::new(RwLock::new(None)),
             running_query_kind: Arc::new(RwLock::new(None)),
@@ -164,6 +197,7 @@ impl QueryContextShared {
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
             finish_time: Default::default(),
+            function_now: Default::default(),
             on_error_map: Arc::new(RwLock::new(None)),
             on_error_mode: Arc::new(RwLock::new(None)),
             copy_status: Arc::new(Default::default()),
@@ -172,8 +206,10 @@ impl QueryContextShared {
             cacheable: Arc::new(AtomicBool::new(true)),
             can_scan_from_agg_index: Arc::new(AtomicBool::new(true)),
             num_fragmented_block_hint: Arc::new(AtomicU64::new(0)),
+            num_fragmented_segment_hint: Arc::new(AtomicU64::new(0)),
             enable_sort_spill: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
+            maintenance_progress: Arc::new(RwLock::new(None)),
             user_agent: Arc::new(RwLock::new("null".to_string())),
             materialized_cte_tables: Arc::new(Default::default()),
             join_spill_progress: Arc::new(Progress::create()),
@@ -185,6 +221,8 @@ impl QueryContextShared {
             merge_into_join: Default::default(),
             multi_table_insert_status: Default::default(),
             query_queued_duration: Arc::new(RwLock::new(Duration::from_secs(0))),
+            skewed_join_keys: Default::default(),
+            spill_encryption: Arc::new(RwLock::new(None)),
         }))
     }
 
@@ -199,10 +237,12 @@ impl QueryContextShared {
     }
 
     pub fn push_warning(&self, warn: String) {
+        self.query_warnings.lock().push(warn.clone());
         let mut guard = self.warnings.lock();
         (*guard).push(warn);
     }
 
+    /// Returns and clears the warnings pushed since the last pop.
     pub fn pop_warnings(&self) -> Vec<String> {
         let mut guard = self.warnings.lock();
         let warnings = (*guard).clone();
@@ -210,6 +250,37 @@ impl QueryContextShared {
         warnings
     }
 
+    /// Returns all the warnings of the query, including the popped ones.
+    pub fn get_warnings(&self) -> Vec<String> {
+        let guard = self.query_warnings.lock();
+        (*guard).clone()
+    }
+
+    pub fn keep_last_warnings(&self) {
+        self.keep_last_warnings.store(true, Ordering::SeqCst);
+    }
+
+    /// Records the warnings of the query as the ones of the last query of the session,
+    /// unless the query is `SHOW WARNINGS`.
+    pub fn update_last_query_warnings(&self) {
+        if !self.keep_last_warnings.load(Ordering::SeqCst) {
+            self.session
+                .session_ctx
+                .set_last_query_warnings(self.get_warnings());
+        }
+    }
+
+    pub fn add_pending_subquery_materialization(&self, materialization: SubqueryMaterialization) {
+        let mut guard = self.pending_subquery_materializations.lock();
+        if !guard.contains(&materialization) {
+            guard.push(materialization);
+        }
+    }
+
+    pub fn take_pending_subquery_materializations(&self) -> Vec<SubqueryMaterialization> {
+        std::mem::take(&mut *self.pending_subquery_materializations.lock())
+    }
+
     pub fn set_on_error_map(&self, map: Arc<DashMap<String, HashMap<u16, InputError>>>) {
         let mut guard = self.on_error_map.write();
         *guard = Some(map);
@@ -286,6 +357,16 @@ impl QueryContextShared {
         tables.values().cloned().collect()
     }
 
+    /// Get all tables that already attached in this query, with their catalog, database
+    /// and table names.
+    pub fn get_named_tables_refs(&self) -> Vec<(DatabaseAndTable, Arc<dyn Table>)> {
+        let tables = self.tables_refs.lock();
+        tables
+            .iter()
+            .map(|(name, table)| (name.clone(), table.clone()))
+            .collect()
+    }
+
     pub fn get_data_metrics(&self) -> StorageMetrics {
         let tables = self.get_tables_refs();
         let metrics: Vec<Arc<StorageMetrics>> =
@@ -360,6 +441,7 @@ impl QueryContextShared {
         let cache_table = self
             .cache_stream_source_table(cache_table, catalog_name)
             .await?;
+        let cache_table = self.navigate_to_query_start(cache_table).await?;
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -369,6 +451,53 @@ impl QueryContextShared {
         }
     }
 
+    /// Resolve the snapshot of a fuse table committed after the query started to the one at
+    /// the start, so all the tables of a SELECT are read as of the same logical point.
+    ///
+    /// Note that `updated_on` and the timestamps of the snapshots come from the clock of the
+    /// node committing them, while the start of the query comes from the local clock. With a
+    /// clock skew between the nodes, a commit made just before the query may be taken as made
+    /// after it, and the table is read as of an older snapshot within the skew, which is why
+    /// `enable_consistent_snapshot_read` is off by default.
+    #[async_backtrace::framed]
+    async fn navigate_to_query_start(&self, table: Arc<dyn Table>) -> Result<Arc<dyn Table>> {
+        if table.engine() != "FUSE"
+            || !matches!(self.get_query_kind(), QueryKind::Query | QueryKind::Explain)
+            || self.session.session_ctx.txn_mgr().lock().is_active()
+            || !self.get_settings().get_enable_consistent_snapshot_read()?
+        {
+            return Ok(table);
+        }
+
+        let query_start = DateTime::<Utc>::from(self.created_time);
+        if table.get_table_info().meta.updated_on <= query_start {
+            return Ok(table);
+        }
+
+        let navigation = TimeNavigation::TimeTravel(NavigationPoint::TimePoint(query_start));
+        let abort_checker = Arc::new(AbortingChecker(self.aborting.clone()));
+        match table.navigate_to(&navigation, abort_checker).await {
+            Ok(navigated) => {
+                info!(
+                    "table {} is committed after the query started, read it as of {}",
+                    table.get_table_info().desc,
+                    query_start
+                );
+                Ok(navigated)
+            }
+            // The table is created after the query started, or the history is purged.
+            Err(cause) if cause.code() == ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND => {
+                warn!(
+                    "table {} has no snapshot at the start of the query, read the latest one: {}",
+                    table.get_table_info().desc,
+                    cause
+                );
+                Ok(table)
+            }
+            Err(cause) => Err(cause),
+        }
+    }
+
     // Cache the source table of a stream table to ensure can get the same table metadata.
     #[async_backtrace::framed]
     async fn cache_stream_source_table(
@@ -630,3 +759,20 @@ impl Drop for QueryContextShared {
         })
     }
 }
+
+struct AbortingChecker(Arc<AtomicBool>);
+
+impl CheckAbort for AbortingChecker {
+    fn is_aborting(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    fn try_check_aborting(&self) -> Result<()> {
+        match self.is_aborting() {
+            true => Err(ErrorCode::AbortedQuery(
+                "Aborted query, because the server is shutting down or the query was killed.",
+            )),
+            false => Ok(()),
+        }
+    }
+}
@@ -50,6 +50,7 @@ use tokio::sync::OwnedSemaphorePermit;
 use tokio::sync::Semaphore;
 use tokio::time::error::Elapsed;
 
+use crate::clusters::ClusterDiscovery;
 use crate::sessions::QueryContext;
 
 pub trait QueueData: Send + Sync + 'static {
@@ -332,6 +333,13 @@ impl QueryEntry {
         plan_extras: &PlanExtras,
     ) -> Result<QueryEntry> {
         let need_add_to_queue = Self::is_heavy_action(plan);
+        // A suspended node is drained, it only accepts the light actions, e.g. to resume it.
+        if need_add_to_queue && ClusterDiscovery::instance().is_local_suspended() {
+            return Err(ErrorCode::ClusterNodeSuspended(format!(
+                "node {} is suspended, resume it with SYSTEM RESUME NODE",
+                ctx.get_cluster().local_id
+            )));
+        }
         QueryEntry::create_entry(ctx, plan_extras, need_add_to_queue)
     }
 
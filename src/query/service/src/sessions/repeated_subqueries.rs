@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use parking_lot::Mutex;
+
+/// The subqueries counted at most, the ones which only ran once are dropped first.
+const MAX_TRACKED_SUBQUERIES: usize = 4096;
+
+struct MaterializedSubquery {
+    fingerprint: String,
+    version: String,
+    blocks: Arc<Vec<DataBlock>>,
+    bytes: usize,
+}
+
+/// Counts the runs of the expensive subqueries and keeps their materialized results, it's
+/// kept by the session or shared by the whole warehouse node according to
+/// `repeated_subquery_tracking_scope`. The fingerprints are qualified by the user and the
+/// roles of the session, so the results are never read by the others.
+#[derive(Default)]
+pub struct RepeatedSubqueries {
+    runs: Mutex<HashMap<String, u64>>,
+    // Ordered from the least recently used.
+    materialized: Mutex<VecDeque<MaterializedSubquery>>,
+}
+
+impl RepeatedSubqueries {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(RepeatedSubqueries::default()));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<RepeatedSubqueries> {
+        GlobalInstance::get()
+    }
+
+    /// Counts a run of the subquery, returns the times it has run.
+    pub fn record_run(&self, fingerprint: &str) -> u64 {
+        let mut runs = self.runs.lock();
+        if runs.len() >= MAX_TRACKED_SUBQUERIES && !runs.contains_key(fingerprint) {
+            runs.retain(|_, n| *n > 1);
+            if runs.len() >= MAX_TRACKED_SUBQUERIES {
+                runs.clear();
+            }
+        }
+        let n = runs.entry(fingerprint.to_string()).or_default();
+        *n += 1;
+        *n
+    }
+
+    /// Get the materialized result, the stale one materialized on other versions is dropped.
+    pub fn get(&self, fingerprint: &str, version: &str) -> Option<Arc<Vec<DataBlock>>> {
+        let mut materialized = self.materialized.lock();
+        let position = materialized
+            .iter()
+            .position(|entry| entry.fingerprint == fingerprint)?;
+        let entry = materialized.remove(position)?;
+        if entry.version != version {
+            return None;
+        }
+        let blocks = entry.blocks.clone();
+        materialized.push_back(entry);
+        Some(blocks)
+    }
+
+    pub fn contains(&self, fingerprint: &str, version: &str) -> bool {
+        self.materialized
+            .lock()
+            .iter()
+            .any(|entry| entry.fingerprint == fingerprint && entry.version == version)
+    }
+
+    /// Returns false if the result is larger than `max_bytes`, which is not kept.
+    pub fn put(
+        &self,
+        fingerprint: String,
+        version: String,
+        blocks: Vec<DataBlock>,
+        max_bytes: usize,
+    ) -> bool {
+        let bytes = blocks
+            .iter()
+            .map(|block| block.memory_size())
+            .sum::<usize>();
+        if bytes > max_bytes {
+            return false;
+        }
+        let mut materialized = self.materialized.lock();
+        materialized.retain(|entry| entry.fingerprint != fingerprint);
+        let mut total_bytes = materialized.iter().map(|entry| entry.bytes).sum::<usize>();
+        while total_bytes + bytes > max_bytes {
+            match materialized.pop_front() {
+                Some(entry) => total_bytes -= entry.bytes,
+                None => break,
+            }
+        }
+        materialized.push_back(MaterializedSubquery {
+            fingerprint,
+            version,
+            blocks: Arc::new(blocks),
+            bytes,
+        });
+        true
+    }
+}
@@ -35,6 +35,8 @@ use log::debug;
 use parking_lot::RwLock;
 
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::hook_vacuum_session_result_files;
+use crate::pipelines::processors::transforms::HashJoinBuildCache;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session_privilege_mgr::SessionPrivilegeManager;
 use crate::sessions::session_privilege_mgr::SessionPrivilegeManagerImpl;
@@ -137,6 +139,17 @@ impl Session {
         self.kill(/* shutdown io stream */);
     }
 
+    /// Kill the session and close the client connection, even if no query is running.
+    pub fn kill_connection(&self) {
+        self.session_ctx.set_abort(true);
+        if let Some(shutdown_fun) = self.session_ctx.take_io_shutdown_tx() {
+            shutdown_fun();
+        }
+
+        let http_queries_manager = HttpQueryManager::instance();
+        http_queries_manager.kill_session(&self.id);
+    }
+
     pub fn force_kill_query(&self, cause: ErrorCode) {
         if let Some(context_shared) = self.session_ctx.get_query_context_shared() {
             context_shared.kill(cause);
@@ -185,6 +198,15 @@ impl Session {
         self.session_ctx.set_io_shutdown_tx(io_shutdown);
     }
 
+    pub fn set_client_alive_probe<F>(&self, probe: F)
+    where F: Fn() -> bool + Send + Sync + 'static {
+        self.session_ctx.set_client_alive_probe(probe);
+    }
+
+    pub fn is_client_alive(&self) -> bool {
+        self.session_ctx.is_client_alive()
+    }
+
     pub fn set_client_host(&self, host: Option<String>) {
         self.session_ctx.set_client_host(host);
     }
@@ -332,6 +354,10 @@ impl Session {
         self.session_ctx.get_query_result_cache_key(query_id)
     }
 
+    pub fn get_last_query_warnings(&self) -> Vec<String> {
+        self.session_ctx.get_last_query_warnings()
+    }
+
     pub fn update_query_ids_results(&self, query_id: String, result_cache_key: String) {
         self.session_ctx
             .update_query_ids_results(query_id, Some(result_cache_key))
@@ -344,6 +370,10 @@ impl Session {
         self.session_ctx.set_txn_mgr(txn_mgr)
     }
 
+    pub fn set_result_spilled(&self) {
+        self.session_ctx.set_result_spilled()
+    }
+
     pub fn set_query_priority(&self, priority: u8) {
         if let Some(context_shared) = self.session_ctx.get_query_context_shared() {
             context_shared.set_priority(priority);
@@ -362,6 +392,11 @@ impl Drop for Session {
     fn drop(&mut self) {
         drop_guard(move || {
             debug!("Drop session {}", self.id.clone());
+            if self.session_ctx.is_result_spilled() {
+                let tenant = self.get_current_tenant();
+                hook_vacuum_session_result_files(tenant.tenant_name(), &self.id);
+            }
+            HashJoinBuildCache::instance().remove_scope(&self.id);
             SessionManager::instance().destroy_session(&self.id.clone());
         })
     }
@@ -31,6 +31,7 @@ use parking_lot::RwLock;
 
 use super::SessionType;
 use crate::sessions::QueryContextShared;
+use crate::sessions::RepeatedSubqueries;
 
 pub struct SessionContext {
     abort: AtomicBool,
@@ -63,12 +64,21 @@ pub struct SessionContext {
     /// The client IP from the client.
     client_host: RwLock<Option<String>>,
     io_shutdown_tx: RwLock<Option<Box<dyn FnOnce() + Send + Sync + 'static>>>,
+    /// Tells whether the client connection is still open, set by the handlers that can detect it.
+    client_alive_probe: RwLock<Option<Arc<dyn Fn() -> bool + Send + Sync + 'static>>>,
     query_context_shared: RwLock<Weak<QueryContextShared>>,
     /// We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     /// query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    /// The warnings of the last query of the session, which are returned by `SHOW WARNINGS`.
+    last_query_warnings: RwLock<Vec<String>>,
     typ: SessionType,
     txn_mgr: Mutex<TxnManagerRef>,
+    /// The runs of the subqueries and the subqueries materialized for the session.
+    repeated_subqueries: Arc<RepeatedSubqueries>,
+    /// Whether the queries of the session have spilled result blocks, which are vacuumed on
+    /// session end.
+    result_spilled: AtomicBool,
 }
 
 impl SessionContext {
@@ -85,10 +95,14 @@ impl SessionContext {
             current_catalog: RwLock::new("default".to_string()),
             current_database: RwLock::new("default".to_string()),
             io_shutdown_tx: Default::default(),
+            client_alive_probe: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            last_query_warnings: Default::default(),
             typ,
             txn_mgr: Mutex::new(TxnManager::init()),
+            repeated_subqueries: Default::default(),
+            result_spilled: Default::default(),
         })
     }
 
@@ -229,6 +243,17 @@ impl SessionContext {
         lock.take()
     }
 
+    pub fn set_client_alive_probe<F: Fn() -> bool + Send + Sync + 'static>(&self, f: F) {
+        let mut lock = self.client_alive_probe.write();
+        *lock = Some(Arc::new(f));
+    }
+
+    // The client is considered alive if there is no way to probe it.
+    pub fn is_client_alive(&self) -> bool {
+        let probe = self.client_alive_probe.read().clone();
+        probe.map(|probe| probe()).unwrap_or(true)
+    }
+
     pub fn get_current_query_id(&self) -> Option<String> {
         self.query_context_shared
             .read()
@@ -271,6 +296,14 @@ impl SessionContext {
         lock.push((query_id, value))
     }
 
+    pub fn get_last_query_warnings(&self) -> Vec<String> {
+        self.last_query_warnings.read().clone()
+    }
+
+    pub fn set_last_query_warnings(&self, warnings: Vec<String>) {
+        *self.last_query_warnings.write() = warnings;
+    }
+
     pub fn get_last_query_id(&self, index: i32) -> String {
         let lock = self.query_ids_results.read();
         let query_ids_len = lock.len();
@@ -296,6 +329,18 @@ impl SessionContext {
         self.txn_mgr.lock().clone()
     }
 
+    pub fn get_repeated_subqueries(&self) -> Arc<RepeatedSubqueries> {
+        self.repeated_subqueries.clone()
+    }
+
+    pub fn set_result_spilled(&self) {
+        self.result_spilled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_result_spilled(&self) -> bool {
+        self.result_spilled.load(Ordering::Relaxed)
+    }
+
     pub fn set_txn_mgr(&self, txn_mgr: TxnManagerRef) {
         *self.txn_mgr.lock() = txn_mgr;
     }
@@ -20,11 +20,14 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 use std::time::Duration;
+use std::time::Instant;
 use std::time::SystemTime;
 
 use databend_common_base::base::tokio;
 use databend_common_base::base::GlobalInstance;
 use databend_common_base::base::SignalStream;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::table_context::ProcessInfoState;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
@@ -36,10 +39,12 @@ use databend_common_settings::Settings;
 use futures::future::Either;
 use futures::StreamExt;
 use log::info;
+use log::warn;
 use parking_lot::RwLock;
 
 use crate::sessions::session::Session;
 use crate::sessions::ProcessInfo;
+use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
@@ -56,7 +61,9 @@ pub struct SessionManager {
 
 impl SessionManager {
     pub fn init(conf: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(Self::create(conf));
+        let session_manager = Self::create(conf);
+        GlobalInstance::set(session_manager.clone());
+        session_manager.spawn_session_reaper(conf);
 
         Ok(())
     }
@@ -271,6 +278,101 @@ impl SessionManager {
             .collect::<Vec<_>>()
     }
 
+    /// Kill the running queries of all user sessions except the given one,
+    /// returns the number of killed queries.
+    pub fn kill_all_queries(&self, except_session_id: &str) -> usize {
+        let mut killed = 0;
+        for weak_ptr in self.active_sessions_snapshot() {
+            let Some(session) = weak_ptr.upgrade() else {
+                continue;
+            };
+
+            if session.get_id() == except_session_id || !session.get_type().is_user_session() {
+                continue;
+            }
+
+            if let Some(query_id) = session.get_current_query_id() {
+                if !QueriesQueueManager::instance().remove(query_id) {
+                    session.force_kill_query(ErrorCode::AbortedQuery(
+                        "Aborted query, because all queries on the warehouse were cancelled",
+                    ));
+                }
+                killed += 1;
+            }
+        }
+        killed
+    }
+
+    fn spawn_session_reaper(self: &Arc<Self>, conf: &InnerConfig) {
+        let interval = conf.query.session_reaper_interval_secs;
+        if interval == 0 {
+            return;
+        }
+
+        let idle_timeout = match conf.query.idle_session_timeout_secs {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        };
+
+        let session_manager = Arc::downgrade(self);
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_secs(interval)).await;
+                match session_manager.upgrade() {
+                    Some(session_manager) => session_manager.reap_sessions(idle_timeout),
+                    None => break,
+                }
+            }
+        });
+    }
+
+    /// Kill the running queries whose client has gone away, e.g. the connection is closed or
+    /// the result isn't polled in time, and the user sessions that have been idle for longer
+    /// than `idle_timeout`.
+    pub fn reap_sessions(&self, idle_timeout: Option<Duration>) {
+        let now = Instant::now();
+        for weak_ptr in self.active_sessions_snapshot() {
+            let Some(session) = weak_ptr.upgrade() else {
+                continue;
+            };
+
+            if !session.get_type().is_user_session() {
+                continue;
+            }
+
+            match session.get_current_query_id() {
+                Some(query_id) => {
+                    if !session.is_client_alive() {
+                        warn!(
+                            "Kill query {} of session {}, because the client has gone away",
+                            query_id,
+                            session.get_id()
+                        );
+                        session.force_kill_query(ErrorCode::AbortedQuery(
+                            "Aborted query, because the client connection is closed",
+                        ));
+                        session.kill_connection();
+                    }
+                }
+                None => {
+                    let Some(idle_timeout) = idle_timeout else {
+                        continue;
+                    };
+
+                    let last_access = session.get_status().read().last_access();
+                    if now.saturating_duration_since(last_access) > idle_timeout {
+                        info!(
+                            "Kill session {}, because it has been idle for more than {:?}",
+                            session.get_id(),
+                            idle_timeout
+                        );
+                        session.kill_connection();
+                    }
+                }
+            }
+        }
+    }
+
     fn destroy_idle_sessions(sessions: &Arc<RwLock<HashMap<String, Weak<Session>>>>) -> bool {
         // Read lock does not support reentrant
         // https://github.com/Amanieu/parking_lot::/blob/lock_api-0.4.4/lock_api/src/rwlock.rs#L422
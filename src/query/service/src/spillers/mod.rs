@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod spill_encryption;
 mod spiller;
 mod spiller_buffer;
 
+pub use spill_encryption::SpillEncryption;
+pub use spill_encryption::SpillEncryptionKey;
 pub use spiller::Spiller;
 pub use spiller::SpillerConfig;
 pub use spiller::SpillerType;
@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::AeadCore;
+use aes_gcm::aead::KeyInit;
+use aes_gcm::aead::OsRng;
+use aes_gcm::Aes256Gcm;
+use aes_gcm::Nonce;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+const NONCE_SIZE: usize = 12;
+
+/// Encrypts the data spilled by a query with AES-256-GCM if `enable_spill_encryption` is on.
+///
+/// The key is generated for the query by the node coordinating it and only kept in memory,
+/// so the spilled files can't be read once the query ends, they are still removed by the
+/// vacuum of the temporary files. In a cluster, the key is sent to the other nodes with the
+/// query env, because the data spilled by the exchange is read by the node owning the bucket.
+///
+/// Each encrypted piece of data is laid out as `<nonce><ciphertext><tag>`.
+pub struct SpillEncryption {
+    key: Option<SpillEncryptionKey>,
+    cipher: Option<Aes256Gcm>,
+}
+
+impl SpillEncryption {
+    pub fn create(enable: bool) -> Self {
+        if !enable {
+            return SpillEncryption {
+                key: None,
+                cipher: None,
+            };
+        }
+
+        let key = Aes256Gcm::generate_key(OsRng);
+        SpillEncryption {
+            key: Some(SpillEncryptionKey(key.to_vec())),
+            cipher: Some(Aes256Gcm::new(&key)),
+        }
+    }
+
+    /// Creates it with the key received from the node coordinating the query.
+    pub fn create_with_key(key: SpillEncryptionKey) -> Result<Self> {
+        let cipher = Aes256Gcm::new_from_slice(&key.0)
+            .map_err(|_| ErrorCode::Internal("Invalid spill encryption key"))?;
+        Ok(SpillEncryption {
+            key: Some(key),
+            cipher: Some(cipher),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.cipher.is_some()
+    }
+
+    pub fn key(&self) -> Option<SpillEncryptionKey> {
+        self.key.clone()
+    }
+
+    pub fn encrypt(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+        let Some(cipher) = &self.cipher else {
+            return Ok(data);
+        };
+
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, data.as_slice())
+            .map_err(|_| ErrorCode::Internal("Failed to encrypt the spilled data"))?;
+
+        let mut encrypted = Vec::with_capacity(NONCE_SIZE + ciphertext.len());
+        encrypted.extend_from_slice(&nonce);
+        encrypted.extend_from_slice(&ciphertext);
+        Ok(encrypted)
+    }
+
+    /// Encrypts the serialized columns as a whole, they are returned as is if it's disabled.
+    pub fn encrypt_columns(&self, columns_data: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>> {
+        match self.is_enabled() {
+            true => Ok(vec![self.encrypt(columns_data.concat())?]),
+            false => Ok(columns_data),
+        }
+    }
+
+    pub fn decrypt(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+        let Some(cipher) = &self.cipher else {
+            return Ok(data);
+        };
+
+        if data.len() < NONCE_SIZE {
+            return Err(ErrorCode::Internal(
+                "Failed to decrypt the spilled data, it's truncated",
+            ));
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_SIZE);
+        cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| ErrorCode::Internal("Failed to decrypt the spilled data"))
+    }
+}
+
+/// The raw key of [`SpillEncryption`], it's never printed.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct SpillEncryptionKey(Vec<u8>);
+
+impl Debug for SpillEncryptionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SpillEncryptionKey(***)")
+    }
+}
@@ -30,9 +30,12 @@ use databend_common_exception::Result;
 use databend_common_expression::arrow::deserialize_column;
 use databend_common_expression::arrow::serialize_column;
 use databend_common_expression::DataBlock;
+use databend_common_metrics::executor::metrics_inc_spill_write_bytes;
+use databend_common_storage::multipart_chunk_size;
 use opendal::Operator;
 
 use crate::sessions::QueryContext;
+use crate::spillers::spill_encryption::SpillEncryption;
 use crate::spillers::spiller_buffer::SpillerBuffer;
 
 /// Spiller type, currently only supports HashJoin
@@ -77,8 +80,9 @@ impl SpillerConfig {
 pub struct Spiller {
     ctx: Arc<QueryContext>,
     operator: Operator,
+    encryption: Arc<SpillEncryption>,
     config: SpillerConfig,
-    _spiller_type: SpillerType,
+    spiller_type: SpillerType,
     spiller_buffer: SpillerBuffer,
     pub join_spilling_partition_bits: usize,
     /// 1 partition -> N partition files
@@ -101,8 +105,9 @@ impl Spiller {
         Ok(Self {
             ctx: ctx.clone(),
             operator,
+            encryption: ctx.get_spill_encryption()?,
             config,
-            _spiller_type: spiller_type,
+            spiller_type,
             spiller_buffer: SpillerBuffer::create(ctx)?,
             join_spilling_partition_bits,
             partition_location: Default::default(),
@@ -119,8 +124,9 @@ impl Spiller {
     /// We should guarantee that the file is managed by this spiller.
     pub async fn read_spilled_file(&self, file: &str) -> Result<DataBlock> {
         debug_assert!(self.columns_layout.contains_key(file));
-        let data = self.operator.read(file).await?.to_bytes();
+        let data = self.operator.read(file).await?.to_vec();
         let bytes = data.len();
+        let data = self.encryption.decrypt(data)?;
 
         let mut begin = 0;
         let instant = Instant::now();
@@ -149,11 +155,6 @@ impl Spiller {
         let location = format!("{}/{}", self.config.location_prefix, unique_name);
         let mut write_bytes = 0;
 
-        let mut writer = self
-            .operator
-            .writer_with(&location)
-            .chunk(8 * 1024 * 1024)
-            .await?;
         let columns = data.columns().to_vec();
         let mut columns_data = Vec::with_capacity(columns.len());
         for column in columns.into_iter() {
@@ -171,13 +172,30 @@ impl Spiller {
             columns_data.push(column_data);
         }
 
-        for data in columns_data.into_iter() {
-            writer.write(data).await?;
+        let columns_data = self.encryption.encrypt_columns(columns_data)?;
+        match multipart_chunk_size(&self.operator, 8 * 1024 * 1024) {
+            Some(chunk_size) => {
+                let mut writer = self
+                    .operator
+                    .writer_with(&location)
+                    .chunk(chunk_size)
+                    .await?;
+                for data in columns_data.into_iter() {
+                    writer.write(data).await?;
+                }
+                writer.close().await?;
+            }
+            // The storage writes a file at once.
+            None => {
+                self.operator
+                    .write(&location, columns_data.concat())
+                    .await?;
+            }
         }
-        writer.close().await?;
 
         Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, 1);
         Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, write_bytes);
+        metrics_inc_spill_write_bytes(&self.spiller_type.to_string(), write_bytes as u64);
         Profile::record_usize_profile(
             ProfileStatisticsName::SpillWriteTime,
             instant.elapsed().as_millis() as usize,
@@ -0,0 +1,279 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_ast::ast::quote::display_ident;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_sql::validate_function_arg;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+
+use crate::interpreters::ShowCreateDatabaseInterpreter;
+use crate::interpreters::ShowCreateQuerySettings;
+use crate::interpreters::ShowCreateTableInterpreter;
+
+const GET_DDL: &str = "get_ddl";
+
+/// `GET_DDL('<database>')` returns the script to re-create the database, i.e. the database,
+/// the tables with their tags, masking policies and aggregating indexes, the streams and the
+/// views, in one row.
+pub struct GetDdlTable {
+    database: String,
+    table_info: TableInfo,
+}
+
+impl GetDdlTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.positioned;
+        validate_function_arg(table_func_name, args.len(), None, 1)?;
+
+        let database = match &args[0] {
+            Scalar::String(database) => database.clone(),
+            arg => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Expected String type, but got {:?}",
+                    arg
+                )));
+            }
+        };
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: GET_DDL.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(Self {
+            database,
+            table_info,
+        }))
+    }
+
+    fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![TableField::new("ddl", TableDataType::String)])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for GetDdlTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![Scalar::String(
+            self.database.clone(),
+        )]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| GetDdlSource::create(ctx.clone(), output, self.database.clone()),
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for GetDdlTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct GetDdlSource {
+    ctx: Arc<dyn TableContext>,
+    database: String,
+    finished: bool,
+}
+
+impl GetDdlSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        database: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, GetDdlSource {
+            ctx,
+            database,
+            finished: false,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for GetDdlSource {
+    const NAME: &'static str = GET_DDL;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let ddl = get_database_ddl(self.ctx.clone(), &self.database).await?;
+
+        // Mark done.
+        self.finished = true;
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![ddl]),
+        ])))
+    }
+}
+
+async fn get_database_ddl(ctx: Arc<dyn TableContext>, database: &str) -> Result<String> {
+    let tenant = ctx.get_tenant();
+    let catalog_name = ctx.get_current_catalog();
+    let catalog = ctx.get_catalog(&catalog_name).await?;
+    let db = catalog.get_database(&tenant, database).await?;
+    let db_id = db.get_db_info().ident.db_id;
+
+    let visibility_checker = ctx.get_visibility_checker().await?;
+    if !visibility_checker.check_database_visibility(&catalog_name, db.name(), db_id) {
+        return Err(ErrorCode::UnknownDatabase(format!(
+            "Unknown database '{}'",
+            database
+        )));
+    }
+
+    let settings = ctx.get_settings();
+    let settings = ShowCreateQuerySettings {
+        sql_dialect: settings.get_sql_dialect()?,
+        quoted_ident_case_sensitive: settings.get_quoted_ident_case_sensitive()?,
+        hide_options_in_show_create_table: false,
+        hide_snapshot_location: true,
+    };
+
+    let mut tables = catalog.list_tables(&tenant, db.name()).await?;
+    tables.retain(|table| {
+        visibility_checker.check_table_visibility(
+            &catalog_name,
+            db.name(),
+            table.name(),
+            db_id,
+            table.get_id(),
+        )
+    });
+    // The streams and the views depend on the tables.
+    tables.sort_by_key(|table| {
+        let order = match table.engine() {
+            STREAM_ENGINE => 1,
+            VIEW_ENGINE => 2,
+            _ => 0,
+        };
+        (order, table.name().to_string())
+    });
+
+    let mut queries = vec![
+        ShowCreateDatabaseInterpreter::show_create_database_query(db.as_ref()),
+        format!(
+            "USE {}",
+            display_ident(
+                db.name(),
+                settings.quoted_ident_case_sensitive,
+                settings.sql_dialect
+            )
+        ),
+    ];
+    for table in tables {
+        queries.push(
+            ShowCreateTableInterpreter::show_create_query(
+                catalog.as_ref(),
+                db.name(),
+                table.as_ref(),
+                &settings,
+            )
+            .await?,
+        );
+        queries.extend(
+            ShowCreateTableInterpreter::show_table_dependents_query(
+                catalog.as_ref(),
+                &tenant,
+                table.as_ref(),
+                &settings,
+            )
+            .await?,
+        );
+    }
+
+    Ok(queries
+        .into_iter()
+        .map(|query| format!("{};\n", query))
+        .collect())
+}
@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_storage::StageFileInfo;
+
+use crate::table_functions::list_stage::list_stage_table::ListStagesSource;
+use crate::table_functions::list_stage::table_args::ListStageArgsParsed;
+
+const DIRECTORY: &str = "directory";
+
+/// `SELECT * FROM DIRECTORY(@<stage>[/<path>] [, pattern => '<pattern>'])` returns the
+/// metadata of the files in the stage.
+pub struct DirectoryTable {
+    args_parsed: ListStageArgsParsed,
+    table_args: TableArgs,
+    table_info: TableInfo,
+}
+
+impl DirectoryTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args_parsed = ListStageArgsParsed::parse_directory(&table_args)?;
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: DIRECTORY.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(Self {
+            table_info,
+            args_parsed,
+            table_args,
+        }))
+    }
+
+    fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("file_name", TableDataType::String),
+            TableField::new("size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("last_modified", TableDataType::Timestamp),
+            TableField::new(
+                "etag",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "md5",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for DirectoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(self.table_args.clone())
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                ListStagesSource::create(ctx.clone(), output, self.args_parsed.clone(), make_block)
+            },
+            1,
+        )?;
+        Ok(())
+    }
+}
+
+impl TableFunction for DirectoryTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+fn make_block(files: &[StageFileInfo]) -> DataBlock {
+    let names: Vec<String> = files.iter().map(|file| file.path.to_string()).collect();
+    let sizes: Vec<u64> = files.iter().map(|file| file.size).collect();
+    let last_modifieds: Vec<i64> = files
+        .iter()
+        .map(|file| file.last_modified.timestamp_micros())
+        .collect();
+    let etags: Vec<Option<String>> = files.iter().map(|file| file.etag.clone()).collect();
+    let md5s: Vec<Option<String>> = files.iter().map(|file| file.md5.clone()).collect();
+
+    DataBlock::new_from_columns(vec![
+        StringType::from_data(names),
+        UInt64Type::from_data(sizes),
+        TimestampType::from_data(last_modifieds),
+        StringType::from_opt_data(etags),
+        StringType::from_opt_data(md5s),
+    ])
+}
@@ -137,7 +137,9 @@ impl Table for ListStageTable {
         _put_cache: bool,
     ) -> Result<()> {
         pipeline.add_source(
-            |output| ListStagesSource::create(ctx.clone(), output, self.args_parsed.clone()),
+            |output| {
+                ListStagesSource::create(ctx.clone(), output, self.args_parsed.clone(), make_block)
+            },
             1,
         )?;
         Ok(())
@@ -160,10 +162,11 @@ enum State {
     Listing(Chunks<StageFileInfoStream>),
     Finished,
 }
-struct ListStagesSource {
+pub(crate) struct ListStagesSource {
     state: State,
     ctx: Arc<dyn TableContext>,
     args_parsed: ListStageArgsParsed,
+    make_block: fn(&[StageFileInfo]) -> DataBlock,
 }
 
 impl ListStagesSource {
@@ -171,11 +174,13 @@ impl ListStagesSource {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         args_parsed: ListStageArgsParsed,
+        make_block: fn(&[StageFileInfo]) -> DataBlock,
     ) -> Result<ProcessorPtr> {
         AsyncSourcer::create(ctx.clone(), output, ListStagesSource {
             state: State::NotStarted,
             ctx,
             args_parsed,
+            make_block,
         })
     }
 
@@ -266,7 +271,7 @@ impl AsyncSource for ListStagesSource {
             match chunks.next().await {
                 Some(chunk) => {
                     let chunk: Result<Vec<StageFileInfo>> = chunk.into_iter().collect();
-                    Ok(Some(make_block(&chunk?)))
+                    Ok(Some((self.make_block)(&chunk?)))
                 }
                 None => {
                     self.state = State::Finished;
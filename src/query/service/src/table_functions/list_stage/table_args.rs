@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::Scalar;
 use databend_common_storage::StageFilesInfo;
 use databend_common_storages_fuse::table_functions::string_value;
 
@@ -27,44 +30,64 @@ pub(crate) struct ListStageArgsParsed {
 impl ListStageArgsParsed {
     pub fn parse(table_args: &TableArgs) -> Result<Self> {
         let args = table_args.expect_all_named("list_stage")?;
+        Self::parse_args("list_stage", None, &args)
+    }
 
-        let mut location = None;
+    /// Parse the args of `directory(@<stage>[/<path>] [, pattern => '<pattern>'])`.
+    pub fn parse_directory(table_args: &TableArgs) -> Result<Self> {
+        let location = match table_args.positioned.as_slice() {
+            [] => None,
+            [location] => Some(location),
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "directory only accepts the location of stage as the positioned param",
+                ));
+            }
+        };
+        Self::parse_args("directory", location, &table_args.named)
+    }
+
+    fn parse_args(
+        func_name: &str,
+        mut location_arg: Option<&Scalar>,
+        args: &HashMap<String, Scalar>,
+    ) -> Result<Self> {
         let mut files_info = StageFilesInfo {
             path: "".to_string(),
             files: None,
             pattern: None,
         };
 
-        for (k, v) in &args {
+        for (k, v) in args {
             match k.to_lowercase().as_str() {
                 "location" => {
-                    let v = string_value(v)?;
-                    if let Some(name) = v.strip_prefix('@') {
-                        location = Some(name.to_string());
-                    } else {
-                        return Err(ErrorCode::BadArguments(format!(
-                            "location must start with @, but got {}",
-                            v
-                        )));
-                    }
+                    location_arg = Some(v);
                 }
                 "pattern" => {
                     files_info.pattern = Some(string_value(v)?);
                 }
                 _ => {
                     return Err(ErrorCode::BadArguments(format!(
-                        "unknown param {} for list_stage",
-                        k
+                        "unknown param {} for {}",
+                        k, func_name
                     )));
                 }
             }
         }
 
-        let location =
-            location.ok_or_else(|| ErrorCode::BadArguments("list_stage must specify location"))?;
+        let location = location_arg.ok_or_else(|| {
+            ErrorCode::BadArguments(format!("{} must specify location", func_name))
+        })?;
+        let location = string_value(location)?;
+        let Some(location) = location.strip_prefix('@') else {
+            return Err(ErrorCode::BadArguments(format!(
+                "location must start with @, but got {}",
+                location
+            )));
+        };
 
         Ok(Self {
-            location,
+            location: location.to_string(),
             files_info,
         })
     }
@@ -14,12 +14,14 @@
 
 mod async_crash_me;
 mod cloud;
+mod get_ddl;
 mod infer_schema;
 mod inspect_parquet;
 mod list_stage;
 mod numbers;
 mod openai;
 mod others;
+mod presign;
 mod show_grants;
 mod srf;
 mod sync_crash_me;
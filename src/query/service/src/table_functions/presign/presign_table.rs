@@ -0,0 +1,219 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::StageType;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_sql::binder::resolve_stage_location;
+use databend_common_sql::plans::PresignAction;
+use databend_common_sql::plans::PresignPlan;
+
+use crate::interpreters::PresignInterpreter;
+use crate::table_functions::presign::table_args::PresignArgsParsed;
+
+const PRESIGN: &str = "presign";
+
+/// `SELECT * FROM PRESIGN(@<stage>/<path> [, action => 'download' | 'upload'] [, expire => <seconds>])`
+/// is the same as the `PRESIGN` statement, but can be used in queries.
+pub struct PresignTable {
+    args_parsed: PresignArgsParsed,
+    table_args: TableArgs,
+    table_info: TableInfo,
+}
+
+impl PresignTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args_parsed = PresignArgsParsed::parse(&table_args)?;
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: PRESIGN.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(Self {
+            table_info,
+            args_parsed,
+            table_args,
+        }))
+    }
+
+    fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("method", TableDataType::String),
+            TableField::new("headers", TableDataType::Variant),
+            TableField::new("url", TableDataType::String),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for PresignTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(self.table_args.clone())
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| PresignSource::create(ctx.clone(), output, self.args_parsed.clone()),
+            1,
+        )?;
+        Ok(())
+    }
+}
+
+impl TableFunction for PresignTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct PresignSource {
+    finished: bool,
+    ctx: Arc<dyn TableContext>,
+    args_parsed: PresignArgsParsed,
+}
+
+impl PresignSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        args_parsed: PresignArgsParsed,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, PresignSource {
+            finished: false,
+            ctx,
+            args_parsed,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for PresignSource {
+    const NAME: &'static str = PRESIGN;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+        self.finished = true;
+
+        let (stage_info, path) =
+            resolve_stage_location(self.ctx.as_ref(), &self.args_parsed.location).await?;
+        let enable_experimental_rbac_check = self
+            .ctx
+            .get_settings()
+            .get_enable_experimental_rbac_check()?;
+        if enable_experimental_rbac_check {
+            let visibility_checker = self.ctx.get_visibility_checker().await?;
+            let (granted, privilege) = match self.args_parsed.action {
+                PresignAction::Download => (
+                    visibility_checker.check_stage_read_visibility(&stage_info.stage_name),
+                    "READ",
+                ),
+                PresignAction::Upload => (
+                    visibility_checker.check_stage_write_visibility(&stage_info.stage_name),
+                    "WRITE",
+                ),
+            };
+            if !(granted
+                || stage_info.is_temporary
+                || stage_info.stage_type == StageType::User
+                    && stage_info.stage_name == self.ctx.get_current_user()?.name)
+            {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: privilege {} is required on stage {} for user {}",
+                    privilege,
+                    stage_info.stage_name.clone(),
+                    &self.ctx.get_current_user()?.identity().display(),
+                )));
+            }
+        }
+
+        let plan = PresignPlan {
+            stage: Box::new(stage_info),
+            path,
+            action: self.args_parsed.action.clone(),
+            expire: self.args_parsed.expire,
+            content_type: self.args_parsed.content_type.clone(),
+        };
+        let block = PresignInterpreter::presign(self.ctx.as_ref(), &plan).await?;
+        Ok(Some(block.convert_to_full()))
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::PresignAction;
+use databend_common_storages_fuse::table_functions::string_value;
+
+#[derive(Clone)]
+pub(crate) struct PresignArgsParsed {
+    pub(crate) location: String,
+    pub(crate) action: PresignAction,
+    pub(crate) expire: Duration,
+    pub(crate) content_type: Option<String>,
+}
+
+impl PresignArgsParsed {
+    /// Parse the args of `presign(@<stage>/<path> [, action => 'download' | 'upload']
+    /// [, expire => <seconds>] [, content_type => '<content_type>'])`.
+    pub(crate) fn parse(table_args: &TableArgs) -> Result<Self> {
+        let mut location = match table_args.positioned.as_slice() {
+            [] => None,
+            [location] => Some(string_value(location)?),
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "presign only accepts the location of stage file as the positioned param",
+                ));
+            }
+        };
+        let mut action = PresignAction::Download;
+        let mut expire = Duration::from_secs(3600);
+        let mut content_type = None;
+
+        for (k, v) in &table_args.named {
+            match k.to_lowercase().as_str() {
+                "location" => {
+                    location = Some(string_value(v)?);
+                }
+                "action" => {
+                    action = match string_value(v)?.to_lowercase().as_str() {
+                        "download" => PresignAction::Download,
+                        "upload" => PresignAction::Upload,
+                        other => {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "action of presign must be 'download' or 'upload', but got '{}'",
+                                other
+                            )));
+                        }
+                    };
+                }
+                "expire" => match v.get_i64() {
+                    Some(secs) if secs > 0 => expire = Duration::from_secs(secs as u64),
+                    _ => {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "expire of presign must be a positive number of seconds, but got {}",
+                            v
+                        )));
+                    }
+                },
+                "content_type" => {
+                    content_type = Some(string_value(v)?);
+                }
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "unknown param {} for presign",
+                        k
+                    )));
+                }
+            }
+        }
+
+        let location =
+            location.ok_or_else(|| ErrorCode::BadArguments("presign must specify location"))?;
+        let Some(location) = location.strip_prefix('@') else {
+            return Err(ErrorCode::BadArguments(format!(
+                "location must start with @, but got {}",
+                location
+            )));
+        };
+
+        Ok(Self {
+            location: location.to_string(),
+            action,
+            expire,
+            content_type,
+        })
+    }
+}
@@ -237,7 +237,7 @@ impl AsyncSource for ShowGrantsSource {
             "role" | "user" => {
                 show_account_grants(self.ctx.clone(), &self.grant_type, &self.name).await?
             }
-            "table" | "database" | "udf" | "stage" => {
+            "table" | "database" | "udf" | "stage" | "warehouse" => {
                 show_object_grant(
                     self.ctx.clone(),
                     &self.grant_type,
@@ -249,7 +249,7 @@ impl AsyncSource for ShowGrantsSource {
             }
             _ => {
                 return Err(ErrorCode::InvalidArgument(format!(
-                    "Expected 'user|role|table|database|udf|stage', but got {:?}",
+                    "Expected 'user|role|table|database|udf|stage|warehouse', but got {:?}",
                     self.grant_type
                 )));
             }
@@ -431,6 +431,12 @@ async fn show_account_grants(
                     privileges.push(get_priv_str(&grant_entry));
                     grant_list.push(format!("{} TO {}", grant_entry, identity));
                 }
+                GrantObject::Warehouse(warehouse_name) => {
+                    object_name.push(warehouse_name.to_string());
+                    object_id.push(None);
+                    privileges.push(get_priv_str(&grant_entry));
+                    grant_list.push(format!("{} TO {}", grant_entry, identity));
+                }
                 GrantObject::Global => {
                     // grant all on *.* to a
                     object_name.push("*.*".to_string());
@@ -593,11 +599,11 @@ async fn show_object_grant(
             }
             (
                 GrantObject::TableById(catalog_name.to_string(), db_id, table_id),
-                OwnershipObject::Table {
+                Some(OwnershipObject::Table {
                     catalog_name: catalog_name.to_string(),
                     db_id,
                     table_id,
-                },
+                }),
                 Some(table_id),
                 name,
             )
@@ -619,10 +625,10 @@ async fn show_object_grant(
             }
             (
                 GrantObject::DatabaseById(catalog_name.to_string(), db_id),
-                OwnershipObject::Database {
+                Some(OwnershipObject::Database {
                     catalog_name: catalog_name.to_string(),
                     db_id,
-                },
+                }),
                 Some(db_id),
                 name,
             )
@@ -636,9 +642,9 @@ async fn show_object_grant(
             }
             (
                 GrantObject::UDF(name.to_string()),
-                OwnershipObject::UDF {
+                Some(OwnershipObject::UDF {
                     name: name.to_string(),
-                },
+                }),
                 None,
                 name,
             )
@@ -652,16 +658,17 @@ async fn show_object_grant(
             }
             (
                 GrantObject::Stage(name.to_string()),
-                OwnershipObject::Stage {
+                Some(OwnershipObject::Stage {
                     name: name.to_string(),
-                },
+                }),
                 None,
                 name,
             )
         }
+        "warehouse" => (GrantObject::Warehouse(name.to_string()), None, None, name),
         _ => {
             return Err(ErrorCode::InvalidArgument(format!(
-                "Expected 'table|database|udf|stage', but got {:?}",
+                "Expected 'table|database|udf|stage|warehouse', but got {:?}",
                 grant_type
             )));
         }
@@ -681,7 +688,7 @@ async fn show_object_grant(
 
     let ownerships = user_api.role_api(&tenant).get_ownerships().await?;
     for ownership in ownerships {
-        if ownership.data.object == owner_object {
+        if Some(&ownership.data.object) == owner_object.as_ref() {
             privileges.push("OWNERSHIP".to_string());
             names.push(ownership.data.role);
         }
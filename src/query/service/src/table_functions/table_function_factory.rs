@@ -24,6 +24,7 @@ use databend_common_storages_fuse::table_functions::FuseAmendTable;
 use databend_common_storages_fuse::table_functions::FuseColumnTable;
 use databend_common_storages_fuse::table_functions::FuseEncodingTable;
 use databend_common_storages_fuse::table_functions::SetCacheCapacity;
+use databend_common_storages_fuse::table_functions::TableChanges;
 use databend_common_storages_fuse::table_functions::TableFunctionTemplate;
 use databend_common_storages_stream::stream_status_table_func::StreamStatusTable;
 use itertools::Itertools;
@@ -44,10 +45,13 @@ use crate::table_functions::async_crash_me::AsyncCrashMeTable;
 use crate::table_functions::cloud::TaskDependentsEnableTable;
 use crate::table_functions::cloud::TaskDependentsTable;
 use crate::table_functions::cloud::TaskHistoryTable;
+use crate::table_functions::get_ddl::GetDdlTable;
 use crate::table_functions::infer_schema::InferSchemaTable;
 use crate::table_functions::inspect_parquet::InspectParquetTable;
+use crate::table_functions::list_stage::DirectoryTable;
 use crate::table_functions::list_stage::ListStageTable;
 use crate::table_functions::numbers::NumbersTable;
+use crate::table_functions::presign::PresignTable;
 use crate::table_functions::show_grants::ShowGrants;
 use crate::table_functions::srf::RangeTable;
 use crate::table_functions::sync_crash_me::SyncCrashMeTable;
@@ -132,6 +136,14 @@ impl TableFunctionFactory {
             ),
         );
 
+        creators.insert(
+            "table_changes".to_string(),
+            (
+                next_id(),
+                Arc::new(TableFunctionTemplate::<TableChanges>::create),
+            ),
+        );
+
         creators.insert(
             "set_cache_capacity".to_string(),
             (
@@ -195,6 +207,16 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(ListStageTable::create)),
         );
 
+        creators.insert(
+            "directory".to_string(),
+            (next_id(), Arc::new(DirectoryTable::create)),
+        );
+
+        creators.insert(
+            "presign".to_string(),
+            (next_id(), Arc::new(PresignTable::create)),
+        );
+
         creators.insert(
             "generate_series".to_string(),
             (next_id(), Arc::new(RangeTable::create)),
@@ -255,6 +277,11 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(TaskHistoryTable::create)),
         );
 
+        creators.insert(
+            "get_ddl".to_string(),
+            (next_id(), Arc::new(GetDdlTable::create)),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
@@ -349,6 +349,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: Some("(id)".to_string()),
             inverted_indexes: None,
+            constraints: None,
         }
     }
 
@@ -374,6 +375,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: None,
         }
     }
 
@@ -410,6 +412,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: None,
         }
     }
 
@@ -446,6 +449,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: None,
         }
     }
 
@@ -491,6 +495,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            constraints: None,
         }
     }
 
@@ -16,6 +16,10 @@ use databend_common_base::base::tokio;
 use databend_common_exception::Result;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::clusters::ClusterHelper;
+use databend_query::clusters::NodeLoad;
+use databend_query::clusters::ScalingAdvisor;
+use databend_query::clusters::ScalingDirection;
+use databend_query::clusters::ThresholdScalingAdvisor;
 use databend_query::test_kits::*;
 use pretty_assertions::assert_eq;
 
@@ -65,6 +69,35 @@ async fn test_remove_invalid_nodes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_threshold_scaling_advisor() {
+    let advisor = ThresholdScalingAdvisor;
+    let idle = NodeLoad::default();
+    assert_eq!(advisor.advise(&idle).direction, ScalingDirection::ScaleDown);
+
+    let busy = NodeLoad {
+        running_queries: 2,
+        cpu_usage: 0.5,
+        ..Default::default()
+    };
+    assert_eq!(advisor.advise(&busy).direction, ScalingDirection::Keep);
+
+    let queued = NodeLoad {
+        queued_queries: 3,
+        ..busy.clone()
+    };
+    assert_eq!(advisor.advise(&queued).direction, ScalingDirection::ScaleUp);
+
+    let memory_pressure = NodeLoad {
+        memory_usage: 0.9,
+        ..busy
+    };
+    assert_eq!(
+        advisor.advise(&memory_pressure).direction,
+        ScalingDirection::ScaleUp
+    );
+}
+
 // TODO:(Winter) need kvapi::KVApi for cluster multiple nodes test
 // #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 // async fn test_multiple_cluster_discovery() -> Result<()> {
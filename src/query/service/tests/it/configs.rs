@@ -737,6 +737,13 @@ rpc_tls_query_service_domain_name = "localhost"
 table_engine_memory_enabled = true
 shutdown_wait_timeout_ms = 5000
 max_query_log_size = 10000
+system_history_retention_days = 7
+system_history_max_bytes = 0
+stage_operator_refresh_secs = 900
+batch_executor_threads = 0
+hash_join_build_cache_bytes = 1073741824
+scaling_advisor_interval_secs = 30
+scaling_advisor_webhook_url = ""
 management_mode = false
 jwt_key_file = ""
 users = []
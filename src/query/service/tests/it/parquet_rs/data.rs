@@ -361,6 +361,35 @@ pub async fn make_test_file_rg(scenario: Scenario) -> (NamedTempFile, SchemaRef)
     (output_file, schema)
 }
 
+/// Create a test parquet file with a row group of 5 rows for each batch, and the bloom
+/// filters of all the columns.
+pub async fn make_test_file_bloom(scenario: Scenario) -> (NamedTempFile, SchemaRef) {
+    let mut output_file = tempfile::Builder::new()
+        .prefix("parquet_bloom_pruning")
+        .suffix(".parquet")
+        .tempfile()
+        .expect("tempfile creation");
+
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(5)
+        .set_bloom_filter_enabled(true)
+        .build();
+
+    let batches = create_data_batch(scenario);
+
+    let schema = batches[0].schema();
+
+    let mut writer = ArrowWriter::try_new(&mut output_file, schema.clone(), Some(props)).unwrap();
+
+    for batch in batches {
+        writer.write(&batch).expect("writing batch");
+    }
+
+    writer.close().unwrap();
+
+    (output_file, schema)
+}
+
 pub async fn make_test_file_page(scenario: Scenario) -> (NamedTempFile, SchemaRef) {
     let mut output_file = tempfile::Builder::new()
         .prefix("parquet_page_pruning")
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod data;
+mod prune_bloom_filters;
 mod prune_pages;
 mod prune_row_groups;
 mod utils;
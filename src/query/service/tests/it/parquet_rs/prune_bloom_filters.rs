@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableSchema;
+use databend_common_storages_parquet::ParquetRSPruner;
+use parquet::arrow::ParquetRecordBatchStreamBuilder;
+
+use super::data::make_test_file_bloom;
+use super::data::Scenario;
+use super::utils::get_data_source_plan;
+use crate::parquet_rs::utils::create_parquet_test_fixture;
+
+/// Prune the row groups only by the bloom filters and test.
+async fn test(scenario: Scenario, predicate: &str, expected_rgs: Vec<usize>) {
+    test_impl(scenario, predicate, expected_rgs, true).await
+}
+
+async fn test_without_prune(scenario: Scenario, predicate: &str, expected_rgs: Vec<usize>) {
+    test_impl(scenario, predicate, expected_rgs, false).await
+}
+
+async fn test_impl(scenario: Scenario, predicate: &str, expected_rgs: Vec<usize>, prune: bool) {
+    let (file, arrow_schema) = make_test_file_bloom(scenario).await;
+    let file_path = file.path().to_string_lossy();
+    let sql = format!("select * from 'fs://{file_path}' where {predicate}");
+
+    let fixture = create_parquet_test_fixture().await;
+    let plan = get_data_source_plan(fixture.new_query_ctx().await.unwrap(), &sql)
+        .await
+        .unwrap();
+    let schema = TableSchema::try_from(arrow_schema.as_ref()).unwrap();
+    let leaf_fields = Arc::new(schema.leaf_fields());
+
+    let pruner = ParquetRSPruner::try_create(
+        FunctionContext::default(),
+        Arc::new(schema),
+        leaf_fields,
+        &plan.push_downs,
+        ParquetReadOptions::default()
+            .with_prune_row_groups(false)
+            .with_prune_pages(false)
+            .with_prune_bloom_filters(prune),
+        vec![],
+    )
+    .unwrap();
+
+    let reader = tokio::fs::File::open(file.path()).await.unwrap();
+    let mut builder = ParquetRecordBatchStreamBuilder::new(reader).await.unwrap();
+    let num_row_groups = builder.metadata().num_row_groups();
+    let (rgs, _) = pruner
+        .prune_row_groups_by_bloom_filters(
+            &mut builder,
+            (0..num_row_groups).collect(),
+            vec![false; num_row_groups],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        expected_rgs, rgs,
+        "Expected {:?}, got {:?}. Scenario: {:?}, predicate: {}",
+        expected_rgs, rgs, scenario, predicate
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_int32_eq() {
+    test(Scenario::Int32, "i = 2", vec![2]).await;
+    test(Scenario::Int32, "i = -4", vec![0, 1]).await;
+    test(Scenario::Int32, "100 = i", vec![]).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_int32_eq_and() {
+    test(Scenario::Int32, "i = 7 and i > 0", vec![3]).await;
+    test(Scenario::Int32, "i = 7 and i = 2", vec![]).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_int32_not_eq() {
+    // Only the equality predicates are checked against the bloom filters.
+    test(Scenario::Int32, "i > 7", vec![0, 1, 2, 3]).await;
+    test(Scenario::Int32, "i = 2 or i = 7", vec![0, 1, 2, 3]).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_disabled() {
+    test_without_prune(Scenario::Int32, "i = 2", vec![0, 1, 2, 3]).await;
+}
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_query::pipelines::processors::HashJoinBuildCache;
+use databend_query::pipelines::processors::HashJoinBuildCacheCollector;
+use databend_query::pipelines::processors::HashJoinBuildCacheKey;
+
+fn key(scope: &str, name: &str) -> HashJoinBuildCacheKey {
+    HashJoinBuildCacheKey {
+        scope: scope.to_string(),
+        name: name.to_string(),
+        version: "v1".to_string(),
+    }
+}
+
+fn block(rows: i32) -> DataBlock {
+    DataBlock::new_from_columns(vec![Int32Type::from_data((0..rows).collect::<Vec<_>>())])
+}
+
+#[test]
+fn test_build_cache_only_complete_build_side() {
+    let cache = Arc::new(HashJoinBuildCache::create(1024 * 1024));
+
+    // One of the pipes is finished before reading its input to the end.
+    let collector = HashJoinBuildCacheCollector::create(cache.clone(), key("", "t"), 4096, 2);
+    collector.collect(&block(10));
+    collector.finish();
+    collector.collect(&block(10));
+    collector.discard();
+    collector.finish();
+    assert!(cache.get(&key("", "t")).is_none());
+
+    let collector = HashJoinBuildCacheCollector::create(cache.clone(), key("", "t"), 4096, 2);
+    collector.collect(&block(10));
+    collector.finish();
+    assert!(cache.get(&key("", "t")).is_none());
+    collector.collect(&block(10));
+    collector.finish();
+    let blocks = cache.get(&key("", "t")).unwrap();
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 20);
+
+    // The stale build side is dropped.
+    let mut stale = key("", "t");
+    stale.version = "v2".to_string();
+    assert!(cache.get(&stale).is_none());
+    assert!(cache.get(&key("", "t")).is_none());
+}
+
+#[test]
+fn test_build_cache_capacity_and_scope() {
+    let bytes = block(100).memory_size();
+    let cache = Arc::new(HashJoinBuildCache::create(bytes * 2));
+
+    // The build side larger than the capacity of the cache is not cached.
+    let collector =
+        HashJoinBuildCacheCollector::create(cache.clone(), key("", "large"), usize::MAX, 1);
+    collector.collect(&block(300));
+    collector.finish();
+    assert!(cache.get(&key("", "large")).is_none());
+
+    // The build sides of all the sessions share the capacity.
+    cache.put(key("s1", "t"), vec![block(100)]);
+    cache.put(key("s2", "t"), vec![block(100)]);
+    assert!(cache.get(&key("", "t")).is_none());
+    assert!(cache.get(&key("s1", "t")).is_some());
+    cache.put(key("", "t"), vec![block(100)]);
+    assert_eq!(cache.bytes(), bytes * 2);
+    assert!(cache.get(&key("s2", "t")).is_none());
+    assert!(cache.get(&key("s1", "t")).is_some());
+
+    cache.remove_scope("s1");
+    assert!(cache.get(&key("s1", "t")).is_none());
+    assert!(cache.get(&key("", "t")).is_some());
+}
@@ -12,4 +12,5 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod hash_join_build_cache;
 mod runtime_filter;
@@ -28,6 +28,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sinks::SyncSenderSink;
 use databend_common_pipeline_sources::BlocksSource;
 use databend_common_pipeline_transforms::processors::TransformDummy;
+use databend_query::pipelines::executor::ExecutorPool;
 use databend_query::pipelines::executor::ExecutorSettings;
 use databend_query::pipelines::executor::ExecutorWorkerContext;
 use databend_query::pipelines::executor::QueryPipelineExecutor;
@@ -559,9 +560,11 @@ async fn create_executor_with_simple_pipeline(
     let settings = ExecutorSettings {
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
+        statement_timeout: Default::default(),
         enable_queries_executor: false,
         max_threads: 8,
         executor_node_id: "".to_string(),
+        pool: ExecutorPool::Interactive,
     };
     QueryPipelineExecutor::create(pipeline, settings)
 }
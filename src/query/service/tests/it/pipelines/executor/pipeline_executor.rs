@@ -20,6 +20,7 @@ use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::sync::mpsc::channel;
 use databend_common_base::base::tokio::sync::mpsc::Receiver;
 use databend_common_base::base::tokio::sync::mpsc::Sender;
+use databend_common_catalog::query_kind::QueryKind;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -32,6 +33,8 @@ use databend_common_pipeline_core::PipeItem;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sinks::SyncSenderSink;
 use databend_common_pipeline_sources::SyncReceiverSource;
+use databend_query::pipelines::executor::BatchExecutorThreads;
+use databend_query::pipelines::executor::ExecutorPool;
 use databend_query::pipelines::executor::ExecutorSettings;
 use databend_query::pipelines::executor::QueryPipelineExecutor;
 use databend_query::sessions::QueryContext;
@@ -44,9 +47,11 @@ async fn test_always_call_on_finished() -> Result<()> {
     let settings = ExecutorSettings {
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
+        statement_timeout: Default::default(),
         enable_queries_executor: false,
         max_threads: 8,
         executor_node_id: "".to_string(),
+        pool: ExecutorPool::Interactive,
     };
 
     {
@@ -94,6 +99,82 @@ async fn test_always_call_on_finished() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_resolve_executor_pool() -> Result<()> {
+    let cases = [
+        ("auto", QueryKind::Query, ExecutorPool::Interactive),
+        ("auto", QueryKind::Explain, ExecutorPool::Interactive),
+        ("auto", QueryKind::CopyIntoTable, ExecutorPool::Batch),
+        ("auto", QueryKind::CopyIntoLocation, ExecutorPool::Batch),
+        ("auto", QueryKind::Insert, ExecutorPool::Batch),
+        ("interactive", QueryKind::Insert, ExecutorPool::Interactive),
+        ("BATCH", QueryKind::Query, ExecutorPool::Batch),
+    ];
+    for (setting, kind, expected) in cases {
+        assert_eq!(ExecutorPool::resolve(setting, kind)?, expected);
+    }
+    assert!(ExecutorPool::resolve("etl", QueryKind::Query).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_batch_executor_threads() -> Result<()> {
+    // no threads reserved, the batch queries are not limited
+    let unlimited = Arc::new(BatchExecutorThreads::create(0));
+    assert_eq!(unlimited.limit(16), 16);
+    assert!(unlimited.acquire("q0", 16, || false).is_some());
+
+    let pool = Arc::new(BatchExecutorThreads::create(4));
+    assert_eq!(pool.limit(16), 4);
+    assert_eq!(pool.limit(2), 2);
+
+    let guard = pool.acquire("q1", 3, || false).unwrap();
+    assert_eq!(pool.available(), 1);
+
+    // the threads are not released, the waiting query is aborted
+    assert!(pool.acquire("q2", 2, || true).is_none());
+    assert_eq!(pool.available(), 1);
+
+    // the threads are released by the running query
+    let waiting = std::thread::spawn({
+        let pool = pool.clone();
+        move || pool.acquire("q2", 16, || false).is_some()
+    });
+    drop(guard);
+    assert!(waiting.join().unwrap());
+    assert_eq!(pool.available(), 4);
+    Ok(())
+}
+
+#[test]
+fn test_batch_executor_threads_nested_executor() -> Result<()> {
+    let pool = Arc::new(BatchExecutorThreads::create(4));
+    let query = pool.acquire("q1", 4, || false).unwrap();
+    assert_eq!(pool.available(), 0);
+
+    // the nested executor of the query, e.g. of a fragment or a recursive CTE, runs while
+    // the query holds all the threads, it shares them instead of waiting for them.
+    let nested = std::thread::spawn({
+        let pool = pool.clone();
+        move || {
+            let aborted = AtomicBool::new(false);
+            let guard = pool.acquire("q1", 4, || aborted.load(Ordering::SeqCst));
+            guard.is_some()
+        }
+    });
+    assert!(nested.join().unwrap());
+    assert_eq!(pool.available(), 0);
+
+    // the threads are held until all the executors of the query are finished
+    let nested = pool.acquire("q1", 2, || false).unwrap();
+    drop(query);
+    assert_eq!(pool.available(), 0);
+    assert!(pool.acquire("q2", 1, || true).is_none());
+    drop(nested);
+    assert_eq!(pool.available(), 4);
+    Ok(())
+}
+
 fn create_pipeline() -> (Arc<AtomicBool>, Pipeline) {
     let called_finished = Arc::new(AtomicBool::new(false));
     let mut pipeline = Pipeline::create();
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_query::servers::flight::FlightBuffer;
+use databend_query::servers::flight::FlightBufferSettings;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_fixed_flight_buffer() -> Result<()> {
+    let buffer = FlightBuffer::create(FlightBufferSettings::fixed(2));
+    buffer.acquire().await;
+    buffer.acquire().await;
+    assert_eq!(buffer.inflight(), 2);
+
+    // The window is full until a block is taken.
+    let blocked = tokio::time::timeout(Duration::from_millis(50), buffer.acquire()).await;
+    assert!(blocked.is_err());
+
+    buffer.release();
+    tokio::time::timeout(Duration::from_secs(5), buffer.acquire())
+        .await
+        .unwrap();
+    assert_eq!(buffer.inflight(), 2);
+    assert_eq!(buffer.window(), 2);
+
+    // The closed buffer never blocks the sender.
+    buffer.close();
+    tokio::time::timeout(Duration::from_secs(5), buffer.acquire())
+        .await
+        .unwrap();
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_autotune_flight_buffer() -> Result<()> {
+    let buffer = FlightBuffer::create(FlightBufferSettings {
+        autotune: true,
+        min_blocks: 1,
+        max_blocks: 16,
+        memory_pressure_ratio: 0,
+    });
+    assert_eq!(buffer.window(), 8);
+
+    // The consumer taking the blocks as soon as the window is full grows the window.
+    let consumer = {
+        let buffer = buffer.clone();
+        tokio::spawn(async move {
+            loop {
+                if buffer.inflight() >= buffer.window() {
+                    buffer.release();
+                }
+                tokio::task::yield_now().await;
+            }
+        })
+    };
+
+    let grown = tokio::time::timeout(Duration::from_secs(10), async {
+        while buffer.window() < 12 {
+            buffer.acquire().await;
+        }
+    })
+    .await;
+    consumer.abort();
+    assert!(grown.is_ok());
+    assert!(buffer.window() <= 16);
+
+    // The settings of the query clamp the window.
+    buffer.tune(FlightBufferSettings::fixed(4));
+    assert_eq!(buffer.window(), 4);
+    Ok(())
+}
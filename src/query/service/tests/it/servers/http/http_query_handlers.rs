@@ -15,8 +15,16 @@
 use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::Read;
+use std::sync::Arc;
 use std::time::Duration;
 
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::DataType as ArrowDataType;
+use arrow_schema::Field as ArrowField;
+use arrow_schema::Schema as ArrowSchema;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use databend_common_base::base::get_free_tcp_port;
@@ -26,21 +34,28 @@ use databend_common_config::UserConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::PasswordHashMethod;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::UpsertKV;
 use databend_common_users::CustomClaims;
 use databend_common_users::EnsureUser;
+use databend_common_users::UserApiProvider;
 use databend_query::auth::AuthMgr;
 use databend_query::servers::http::middleware::get_client_ip;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::bulk_insert;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::BulkInsertResponse;
 use databend_query::servers::http::v1::ExecuteStateKind;
+use databend_query::servers::http::v1::HttpQueryManager;
 use databend_query::servers::http::v1::HttpSessionConf;
 use databend_query::servers::http::v1::QueryError;
 use databend_query::servers::http::v1::QueryResponse;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::sessions::QueryAffect;
+use databend_query::sessions::SessionManager;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 use databend_storages_common_txn::TxnState;
@@ -57,6 +72,7 @@ use jwt_simple::algorithms::RSAKeyPairLike;
 use jwt_simple::claims::JWTClaims;
 use jwt_simple::claims::NoCustomClaims;
 use jwt_simple::prelude::Clock;
+use poem::post;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Request;
@@ -412,6 +428,95 @@ async fn test_client_query_id() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_idempotency_key() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let wait_time_secs = 5;
+    let sql = "select * from numbers(1)";
+    let ep = create_endpoint().await?;
+    let mut headers = HeaderMap::new();
+    headers.insert("x-databend-idempotency-key", "test-key".parse().unwrap());
+
+    let (status, first) =
+        post_sql_to_endpoint_new_session(&ep, sql, wait_time_secs, headers.clone()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", first);
+    assert!(first.error.is_none(), "{:?}", first);
+
+    // the retry gets the query created by the first request
+    let (status, retried) =
+        post_sql_to_endpoint_new_session(&ep, sql, wait_time_secs, headers.clone()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", retried);
+    assert_eq!(retried.id, first.id, "{:?}", retried);
+
+    // the key can not be reused by another sql
+    let (status, other) =
+        post_sql_to_endpoint_new_session(&ep, "select 1", wait_time_secs, headers).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", other);
+    assert!(other.error.is_some(), "{:?}", other);
+
+    // requests without the key are not deduplicated
+    let (status, result) = post_sql_to_endpoint(&ep, sql, wait_time_secs).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_ne!(result.id, first.id, "{:?}", result);
+
+    // the retry of an evicted query fails as expired instead of running the query again
+    let mut headers = HeaderMap::new();
+    headers.insert("x-databend-idempotency-key", "evicted-key".parse().unwrap());
+    let (status, evicted) =
+        post_sql_to_endpoint_new_session(&ep, sql, wait_time_secs, headers.clone()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", evicted);
+    assert!(evicted.error.is_none(), "{:?}", evicted);
+    HttpQueryManager::instance().evict_query(&evicted.id).await;
+    let (status, retried) =
+        post_sql_to_endpoint_new_session(&ep, sql, wait_time_secs, headers).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", retried);
+    let error = retried.error.unwrap();
+    assert!(error.message.contains("expired"), "{:?}", error);
+
+    // the key taken by a query on another node of the cluster
+    let value = json!({"node_id": "other-node", "query_id": "other-query", "sql": sql});
+    UserApiProvider::instance()
+        .get_meta_store_client()
+        .upsert_kv(UpsertKV::update(
+            "__fd_http_idempotency_keys/test/'root'@'%'/other-node-key",
+            &serde_json::to_vec(&value)?,
+        ))
+        .await?;
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "x-databend-idempotency-key",
+        "other-node-key".parse().unwrap(),
+    );
+    let (status, other) =
+        post_sql_to_endpoint_new_session(&ep, sql, wait_time_secs, headers).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", other);
+    let error = other.error.unwrap();
+    assert!(error.message.contains("other-node"), "{:?}", error);
+
+    // the key must be visible ASCII
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "x-databend-idempotency-key",
+        HeaderValue::from_bytes(b"\xff").unwrap(),
+    );
+    let json = serde_json::json!({ "sql": sql });
+    let mut req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .typed_header(headers::Authorization::basic("root", ""))
+        .body(serde_json::to_vec(&json)?);
+    req.headers_mut().extend(headers.into_iter());
+    let response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
 // ref: query_log not recorded correctly.
 // It could be uncommented when we remove SEE_YOU_AGAIN stmt
 
@@ -558,6 +663,34 @@ async fn test_buffer_size() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_result_spill() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let rows = 1000;
+    let sql = format!("select * from numbers({}) order by number", rows);
+
+    // The blocks beyond the buffer are spilled, and read back in order.
+    for max_bytes in ["0", "1024"] {
+        let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 1, "max_rows_in_buffer": 10, "max_rows_per_page": 100}, "session": {"settings": {"enable_result_spill": "1", "result_spill_max_bytes": max_bytes, "max_block_size": "10"}}});
+        let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+        assert_eq!(reply.last().0, StatusCode::OK, "{} {:?}", max_bytes, reply);
+        let data = reply.data();
+        assert_eq!(
+            data.len(),
+            rows,
+            "max_bytes={}, result={:?}",
+            max_bytes,
+            reply
+        );
+        for (i, row) in data.iter().enumerate() {
+            assert_eq!(row[0], i.to_string());
+        }
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_pagination() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -681,6 +814,27 @@ async fn test_result_timeout() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_client_gone_without_polling() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let json = serde_json::json!({ "sql": "select sleep(2)", "pagination": {"wait_time_secs": 0}, "session": { "settings": {"http_handler_result_timeout_secs": "1"}}});
+    let mut req = TestHttpQueryRequest::new(json);
+    let (status, result, _) = req.fetch_begin().await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+
+    let session = SessionManager::instance()
+        .get_session_by_id(result.session_id.as_ref().unwrap())
+        .unwrap();
+    assert!(session.is_client_alive());
+
+    // The client is considered gone once it hasn't polled within the result timeout.
+    sleep(Duration::from_millis(1500)).await;
+    assert!(!session.is_client_alive());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_system_tables() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -766,6 +920,126 @@ async fn test_insert() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_maintenance_progress() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let route = create_endpoint().await?;
+
+    let sqls = vec![
+        ("create table t(a int) engine=fuse", None),
+        ("insert into t(a) values (1),(2)", None),
+        ("insert into t(a) values (3),(4)", None),
+        ("optimize table t compact block", Some("compact")),
+    ];
+
+    for (sql, operation) in sqls {
+        let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 3}});
+        let (status, result) = post_json_to_endpoint(&route, &json, HeaderMap::default()).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result.error);
+        assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
+        let progress = result.stats.progresses.maintenance_progress.as_ref();
+        assert_eq!(
+            progress.map(|p| p.operation.as_str()),
+            operation,
+            "{:?}",
+            result
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_bulk_insert() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let session_middleware =
+        HTTPSessionMiddleware::create(HttpHandlerKind::Query, AuthMgr::instance());
+    let ep = Route::new()
+        .nest("/v1/query", query_route())
+        .at("/v1/bulk_insert", post(bulk_insert))
+        .with(session_middleware);
+
+    let (status, result) =
+        post_sql_to_endpoint(&ep, "create table t_bulk(a int, b string) engine=fuse", 3).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result.error);
+
+    let schema = Arc::new(ArrowSchema::new(vec![
+        ArrowField::new("a", ArrowDataType::Int64, false),
+        ArrowField::new("b", ArrowDataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![
+        Arc::new(Int64Array::from(vec![1, 2, 3])),
+        Arc::new(StringArray::from(vec!["x", "y", "z"])),
+    ])?;
+    let mut payload = vec![];
+    {
+        let mut writer = StreamWriter::try_new(&mut payload, &schema)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+    }
+
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/bulk_insert".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/vnd.apache.arrow.stream")
+        .header("X-DATABEND-SQL", "insert into t_bulk values")
+        .typed_header(basic.clone())
+        .body(payload.clone());
+    let response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    let result = serde_json::from_str::<BulkInsertResponse>(&body)?;
+    assert_eq!(result.rows, 3);
+
+    // the statement must be an INSERT without values
+    let req = Request::builder()
+        .uri("/v1/bulk_insert".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/vnd.apache.arrow.stream")
+        .header("X-DATABEND-SQL", "insert into t_bulk values (4, 'w')")
+        .typed_header(basic.clone())
+        .body(payload.clone());
+    let response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // the payload larger than bulk_insert_max_bytes is rejected
+    let (status, result) =
+        post_sql_to_endpoint(&ep, "set global bulk_insert_max_bytes = 16", 3).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    let req = Request::builder()
+        .uri("/v1/bulk_insert".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/vnd.apache.arrow.stream")
+        .header("X-DATABEND-SQL", "insert into t_bulk values")
+        .typed_header(basic)
+        .body(payload);
+    let response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let (status, result) = post_sql_to_endpoint(&ep, "unset bulk_insert_max_bytes", 3).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+
+    let (status, result) =
+        post_sql_to_endpoint(&ep, "select a, b from t_bulk order by a", 3).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.data.len(), 3, "{:?}", result);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_query_log() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
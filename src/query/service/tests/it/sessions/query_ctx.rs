@@ -65,3 +65,26 @@ async fn test_get_storage_accessor_fs() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_pop_warnings() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    ctx.push_warning("w1".to_string());
+    ctx.push_warning("w2".to_string());
+    assert_eq!(ctx.pop_warnings(), vec!["w1".to_string(), "w2".to_string()]);
+    assert!(ctx.pop_warnings().is_empty());
+
+    ctx.push_warning("w3".to_string());
+    assert_eq!(ctx.pop_warnings(), vec!["w3".to_string()]);
+
+    // the popped warnings are still shown by `SHOW WARNINGS`.
+    assert_eq!(ctx.get_warnings(), vec![
+        "w1".to_string(),
+        "w2".to_string(),
+        "w3".to_string()
+    ]);
+
+    Ok(())
+}
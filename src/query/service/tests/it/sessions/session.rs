@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use databend_common_base::base::tokio;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_meta_app::tenant::Tenant;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 
@@ -64,3 +69,42 @@ async fn test_session_in_management_mode() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_reap_sessions() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let session_manager = SessionManager::instance();
+
+    // The running query is killed once the client has gone away.
+    {
+        let session = session_manager.create_session(SessionType::MySQL).await?;
+        let session = session_manager.register_session(session)?;
+        let ctx = session.create_query_context().await?;
+
+        session_manager.reap_sessions(None);
+        assert!(ctx.check_aborting().is_ok());
+
+        session.set_client_alive_probe(|| false);
+        session_manager.reap_sessions(None);
+        assert!(ctx.check_aborting().is_err());
+        assert!(session.is_aborting());
+    }
+
+    // Idle sessions are killed only when the idle timeout is set.
+    {
+        let session = session_manager.create_session(SessionType::MySQL).await?;
+        let session = session_manager.register_session(session)?;
+
+        session_manager.reap_sessions(None);
+        assert!(!session.is_aborting());
+
+        session_manager.reap_sessions(Some(Duration::from_secs(3600)));
+        assert!(!session.is_aborting());
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        session_manager.reap_sessions(Some(Duration::from_millis(1)));
+        assert!(session.is_aborting());
+    }
+
+    Ok(())
+}
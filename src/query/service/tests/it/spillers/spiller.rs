@@ -72,3 +72,52 @@ async fn test_spill_with_partition() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_spill_encryption() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let tenant = ctx.get_tenant();
+    let spiller_config =
+        SpillerConfig::create(query_spill_prefix(tenant.tenant_name(), &ctx.get_id()));
+    let operator = DataOperator::instance().operator();
+
+    let mut spiller = Spiller::create(
+        ctx.clone(),
+        operator.clone(),
+        spiller_config,
+        SpillerType::HashJoinBuild,
+    )?;
+
+    let data =
+        DataBlock::new_from_columns(vec![Int32Type::from_data((0..100).collect::<Vec<_>>())]);
+    let location = spiller.spill_block(data).await?;
+
+    // The file is written as `<nonce><ciphertext><tag>`.
+    let written = operator.read(&location).await?.to_vec();
+    let plain = spiller.columns_layout.get(&location).unwrap()[0];
+    assert_eq!(written.len(), plain + 12 + 16);
+
+    let block = spiller.read_spilled_file(&location).await?;
+    assert_eq!(block.num_rows(), 100);
+
+    // The key of the other queries can't decrypt it.
+    let other_ctx = fixture.new_query_ctx().await?;
+    assert!(
+        other_ctx
+            .get_spill_encryption()?
+            .decrypt(written.clone())
+            .is_err()
+    );
+    assert!(ctx.get_spill_encryption()?.decrypt(written).is_ok());
+
+    // The spilled data is not encrypted if it is disabled.
+    let disabled_ctx = fixture.new_query_ctx().await?;
+    disabled_ctx
+        .get_settings()
+        .set_setting("enable_spill_encryption".to_string(), "0".to_string())?;
+    assert!(!disabled_ctx.get_spill_encryption()?.is_enabled());
+
+    Ok(())
+}
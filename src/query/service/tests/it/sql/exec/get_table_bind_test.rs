@@ -17,6 +17,7 @@ use std::collections::HashSet;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::SystemTime;
 
 use dashmap::DashMap;
 use databend_common_base::base::tokio;
@@ -29,15 +30,20 @@ use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::FilteredCopyFiles;
+use databend_common_catalog::table_context::MaintenanceProgress;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
+use databend_common_catalog::table_context::QueryFragmentInfo;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::table_context::SubqueryMaterialization;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -511,6 +517,12 @@ impl TableContext for CtxDelegation {
 
     fn set_status_info(&self, _info: &str) {}
 
+    fn get_maintenance_progress(&self) -> Option<MaintenanceProgress> {
+        todo!()
+    }
+
+    fn set_maintenance_progress(&self, _progress: MaintenanceProgress) {}
+
     fn get_partition(&self) -> Option<PartInfoPtr> {
         todo!()
     }
@@ -523,6 +535,16 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn prune_lazy_parts_in_cluster(
+        &self,
+        _table_info: &TableInfo,
+        _push_downs: Option<PushDownInfo>,
+        _snapshot_loc: Option<String>,
+        _lazy_parts: Partitions,
+    ) -> Result<(PartStatistics, Partitions)> {
+        todo!()
+    }
+
     fn add_partitions_sha(&self, _sha: String) {
         todo!()
     }
@@ -586,6 +608,10 @@ impl TableContext for CtxDelegation {
         self.ctx.get_id()
     }
 
+    fn get_created_time(&self) -> SystemTime {
+        self.ctx.get_created_time()
+    }
+
     fn get_current_catalog(&self) -> String {
         "default".to_owned()
     }
@@ -602,6 +628,35 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn record_subquery_run(&self, _fingerprint: &str) -> Result<u64> {
+        todo!()
+    }
+
+    fn get_subquery_materialization(
+        &self,
+        _fingerprint: &str,
+        _version: &str,
+    ) -> Result<Option<Arc<Vec<DataBlock>>>> {
+        todo!()
+    }
+
+    fn add_pending_subquery_materialization(&self, _materialization: SubqueryMaterialization) {
+        todo!()
+    }
+
+    async fn materialize_pending_subqueries(&self) -> Result<bool> {
+        todo!()
+    }
+
+    async fn finish_column_backfills(
+        &self,
+        _catalog: &str,
+        _database: &str,
+        _table: &str,
+    ) -> Result<()> {
+        todo!()
+    }
+
     fn get_current_database(&self) -> String {
         self.ctx.get_current_database()
     }
@@ -714,6 +769,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn check_file_format_params(&self, _params: &FileFormatParams) -> Result<()> {
+        todo!()
+    }
+
     async fn get_connection(&self, _name: &str) -> Result<UserDefinedConnection> {
         todo!()
     }
@@ -802,6 +861,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn set_compaction_num_segment_hint(&self, _hint: u64) {
+        todo!()
+    }
+
+    fn get_compaction_num_segment_hint(&self) -> u64 {
+        todo!()
+    }
+
     fn add_file_status(&self, _file_path: &str, _file_status: FileStatus) -> Result<()> {
         todo!()
     }
@@ -817,6 +884,10 @@ impl TableContext for CtxDelegation {
     fn get_queries_profile(&self) -> HashMap<String, Vec<PlanProfile>> {
         todo!()
     }
+
+    fn get_queries_fragments(&self) -> Vec<QueryFragmentInfo> {
+        todo!()
+    }
     fn add_merge_status(&self, _merge_status: MergeStatus) {
         todo!()
     }
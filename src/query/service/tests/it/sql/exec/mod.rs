@@ -12,14 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono::Duration;
 use databend_common_base::base::tokio;
 use databend_common_base::runtime::Runtime;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_sorted_eq;
+use databend_common_expression::DataBlock;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::Planner;
+use databend_common_sql::QueryReplayBundle;
+use databend_common_storage::DataOperator;
 use databend_common_storages_fuse::FuseTable;
+use databend_query::interpreters::interpreter_plan_sql;
 use databend_query::interpreters::Interpreter;
 use databend_query::interpreters::OptimizeTableInterpreter;
 use databend_query::test_kits::*;
@@ -166,3 +173,99 @@ pub async fn test_snapshot_consistency() -> Result<()> {
 }
 
 mod get_table_bind_test;
+
+#[tokio::test(flavor = "multi_thread")]
+pub async fn test_query_replay() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    fixture
+        .execute_command(&format!("create table {db}.t_replay(a int)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t_replay values (1), (2)"))
+        .await?;
+
+    // capture the query
+    let ctx = fixture.new_query_ctx().await?;
+    let settings = ctx.get_settings();
+    settings.set_setting("enable_query_replay_capture".to_string(), "1".to_string())?;
+    settings.set_setting("max_threads".to_string(), "3".to_string())?;
+    let query = format!("select count(*), max(a) from {db}.t_replay");
+    interpreter_plan_sql(ctx.clone(), &query).await?;
+    let query_id = ctx.get_id();
+
+    fixture
+        .execute_command(&format!("insert into {db}.t_replay values (3)"))
+        .await?;
+
+    // the replayed query reads the captured snapshot
+    let blocks = fixture
+        .execute_query(&format!("SYSTEM REPLAY QUERY '{query_id}'"))
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 2        | 2        |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    let res = fixture
+        .execute_command("SYSTEM REPLAY QUERY 'not_captured'")
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_QUERY);
+
+    // the replayed query reads the view as it was defined
+    fixture
+        .execute_command(&format!(
+            "create view {db}.v_replay as select a from {db}.t_replay where a > 1"
+        ))
+        .await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let settings = ctx.get_settings();
+    settings.set_setting("enable_query_replay_capture".to_string(), "1".to_string())?;
+    let query = format!("select count(*), max(v_replay.a) from {db}.v_replay");
+    interpreter_plan_sql(ctx.clone(), &query).await?;
+    let view_query_id = ctx.get_id();
+
+    fixture
+        .execute_command(&format!(
+            "create or replace view {db}.v_replay as select a from {db}.t_replay where a > 100"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t_replay values (4)"))
+        .await?;
+
+    let blocks = fixture
+        .execute_query(&format!("SYSTEM REPLAY QUERY '{view_query_id}'"))
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 2        | 3        |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    // the expired bundles are purged
+    let tenant = ctx.get_tenant();
+    let op = DataOperator::instance().operator();
+    let purged =
+        QueryReplayBundle::purge_expired(&op, tenant.tenant_name(), Duration::zero()).await?;
+    assert_eq!(purged, 2);
+    let res = fixture
+        .execute_command(&format!("SYSTEM REPLAY QUERY '{query_id}'"))
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_QUERY);
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//  Copyright 2024 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_storages_common_table_meta::meta::testing::SegmentInfoV4;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use databend_storages_common_table_meta::meta::Compression;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::Statistics;
+use databend_storages_common_table_meta::meta::Versioned;
+
+fn int_stats(v: i32) -> ColumnStatistics {
+    ColumnStatistics::new(
+        Scalar::Number(NumberScalar::Int32(v)),
+        Scalar::Number(NumberScalar::Int32(v)),
+        0,
+        4,
+        None,
+    )
+}
+
+fn test_blocks() -> Vec<Arc<BlockMeta>> {
+    (0..3)
+        .map(|i| {
+            let mut col_stats = HashMap::new();
+            col_stats.insert(0, int_stats(i));
+            // only the second block has statistics of column 1
+            if i == 1 {
+                col_stats.insert(1, int_stats(i * 10));
+            }
+            Arc::new(BlockMeta::new(
+                1,
+                4,
+                4,
+                col_stats,
+                HashMap::new(),
+                None,
+                (format!("block_{i}"), 0),
+                None,
+                0,
+                None,
+                Compression::Lz4Raw,
+                None,
+            ))
+        })
+        .collect()
+}
+
+fn test_summary() -> Statistics {
+    Statistics {
+        row_count: 3,
+        block_count: 3,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_segment_columnar_round_trip() -> databend_common_exception::Result<()> {
+    let segment = SegmentInfo::new(test_blocks(), test_summary());
+    let bytes = segment.to_bytes()?;
+
+    let decoded = SegmentInfo::from_slice(&bytes)?;
+    assert_eq!(segment, decoded);
+
+    let compact = CompactSegmentInfo::from_slice(&bytes)?;
+    assert_eq!(compact.format_version, SegmentInfo::VERSION);
+    assert_eq!(compact.block_metas()?, segment.blocks);
+    assert!(compact.raw_block_metas.columnar);
+    Ok(())
+}
+
+#[test]
+fn test_segment_columnar_projected_decode() -> databend_common_exception::Result<()> {
+    let segment = SegmentInfo::new(test_blocks(), test_summary());
+    let compact = CompactSegmentInfo::from_slice(&segment.to_bytes()?)?;
+
+    let column_ids = HashSet::from([1]);
+    let blocks = compact.block_metas_with_columns(Some(&column_ids))?;
+    assert_eq!(blocks.len(), segment.blocks.len());
+    for (block, expected) in blocks.iter().zip(&segment.blocks) {
+        assert_eq!(block.location, expected.location);
+        assert!(!block.col_stats.contains_key(&0));
+        assert_eq!(block.col_stats.get(&1), expected.col_stats.get(&1));
+    }
+
+    // the segments of v4 are decoded with the statistics of all the columns
+    let v4 = SegmentInfoV4::new(test_blocks(), test_summary());
+    let compact = CompactSegmentInfo::from_slice(&v4.to_bytes()?)?;
+    assert_eq!(
+        compact.block_metas_with_columns(Some(&column_ids))?,
+        v4.blocks
+    );
+    Ok(())
+}
+
+#[test]
+fn test_segment_v4_compatibility() -> databend_common_exception::Result<()> {
+    let v4 = SegmentInfoV4::new(test_blocks(), test_summary());
+    let bytes = v4.to_bytes()?;
+
+    let compact = CompactSegmentInfo::from_slice(&bytes)?;
+    assert_eq!(compact.format_version, SegmentInfoV4::VERSION);
+    assert_eq!(compact.block_metas()?, v4.blocks);
+    assert!(!compact.raw_block_metas.columnar);
+
+    let current: SegmentInfo = v4.into();
+    assert_eq!(current.format_version, SegmentInfoV4::VERSION);
+
+    // a segment of v4 is written back in the layout of v4, which can be read by the nodes
+    // that don't know v5 yet
+    let rewritten = current.to_bytes()?;
+    assert_eq!(rewritten, bytes);
+    let decoded = SegmentInfoV4::from_slice(&rewritten)?;
+    assert_eq!(decoded.blocks, current.blocks);
+    assert_eq!(SegmentInfo::from_slice(&rewritten)?, current);
+    Ok(())
+}
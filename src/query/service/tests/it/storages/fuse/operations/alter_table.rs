@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 use std::collections::HashSet;
+use std::time::Duration;
 
 use databend_common_base::base::tokio;
 use databend_common_exception::Result;
@@ -184,6 +185,7 @@ async fn test_fuse_table_optimize_alter_table() -> Result<()> {
         field,
         comment: "".to_string(),
         option: AddColumnOption::End,
+        backfill_expr: None,
     };
     let interpreter = AddTableColumnInterpreter::try_create(ctx.clone(), add_table_column_plan)?;
     let _ = interpreter.execute(ctx.clone()).await?;
@@ -298,3 +300,120 @@ async fn test_fuse_table_optimize_alter_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_modify_column_in_background() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    fixture
+        .execute_command(&format!("create table {db}.t(a int, b int)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(1, 10), (2, 20)"))
+        .await?;
+    fixture
+        .execute_command("set enable_background_column_conversion = 1")
+        .await?;
+    fixture
+        .execute_command(&format!("alter table {db}.t modify column b bigint"))
+        .await?;
+
+    // The new schema is committed with the converted data by the background job.
+    let mut converted = false;
+    for _ in 0..100 {
+        let table = fixture
+            .new_query_ctx()
+            .await?
+            .get_catalog("default")
+            .await?
+            .get_table(&fixture.default_tenant(), &db, "t")
+            .await?;
+        let data_type = table
+            .schema()
+            .field_with_name("b")?
+            .data_type()
+            .remove_nullable();
+        if data_type == TableDataType::Number(NumberDataType::Int64) {
+            converted = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(converted);
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 10       |",
+        "| 2        | 20       |",
+        "+----------+----------+",
+    ];
+    expects_ok(
+        "converted_in_background",
+        fixture
+            .execute_query(&format!("select a, b from {db}.t order by a"))
+            .await,
+        expected,
+    )
+    .await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_modify_column_in_background_failed() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    fixture
+        .execute_command(&format!("create table {db}.t(a int, b string)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(1, 'abc')"))
+        .await?;
+    fixture
+        .execute_command("set enable_background_column_conversion = 1")
+        .await?;
+    fixture
+        .execute_command(&format!("alter table {db}.t modify column b int"))
+        .await?;
+
+    // The failure of the background job is recorded in `system.maintenance_history`.
+    let mut failed = false;
+    for _ in 0..100 {
+        let blocks: Vec<DataBlock> = fixture
+            .execute_query(&format!(
+                "select error_message from system.maintenance_history \
+                 where database = '{db}' and table = 't' and action = 'modify_column' \
+                 and error_message <> ''"
+            ))
+            .await?
+            .try_collect()
+            .await?;
+        if blocks.iter().any(|block| block.num_rows() > 0) {
+            failed = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(failed);
+
+    // The table is kept unchanged.
+    let table = fixture
+        .new_query_ctx()
+        .await?
+        .get_catalog("default")
+        .await?
+        .get_table(&fixture.default_tenant(), &db, "t")
+        .await?;
+    let data_type = table
+        .schema()
+        .field_with_name("b")?
+        .data_type()
+        .remove_nullable();
+    assert_eq!(data_type, TableDataType::String);
+    Ok(())
+}
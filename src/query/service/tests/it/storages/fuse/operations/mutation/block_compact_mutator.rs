@@ -109,6 +109,38 @@ async fn test_compact() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_compact_build_tasks_progress() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let tbl_name = fixture.default_table_name();
+    let db_name = fixture.default_db_name();
+
+    fixture.create_default_database().await?;
+    fixture.create_normal_table().await?;
+    for i in 0..10 {
+        let qry = format!("insert into {}.{}(id) values({})", db_name, tbl_name, i);
+        fixture.execute_command(qry.as_str()).await?;
+    }
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx
+        .get_table(fixture.default_catalog_name().as_str(), &db_name, &tbl_name)
+        .await?;
+    let res = table
+        .compact_blocks(ctx.clone(), CompactionLimits::default())
+        .await?;
+    assert!(res.is_some());
+
+    // all the lazy parts are processed when the tasks are built.
+    let progress = ctx.get_maintenance_progress().unwrap();
+    assert_eq!(progress.operation, "compact");
+    assert_eq!(progress.phase, "build_tasks");
+    assert!(progress.tasks_total > 0);
+    assert_eq!(progress.tasks_done, progress.tasks_total);
+
+    Ok(())
+}
+
 async fn do_compact(ctx: Arc<QueryContext>, table: Arc<dyn Table>) -> Result<bool> {
     let settings = ctx.get_settings();
     let mut pipeline = databend_common_pipeline_core::Pipeline::create();
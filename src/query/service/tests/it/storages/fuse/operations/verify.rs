@@ -0,0 +1,132 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_storages_fuse::FuseBlockPartInfo;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::TableContext;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_verify_and_quarantine() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    // two blocks
+    fixture
+        .execute_command(&format!("insert into {db}.{tbl}(id) values(1),(2)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.{tbl}(id) values(3)"))
+        .await?;
+
+    let verify = format!("system verify table {db}.{tbl}");
+    let blocks = fixture
+        .execute_query(&verify)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+    // corrupt the chunk of column `id` of one block, the size of the file is kept.
+    let ctx = fixture.new_query_ctx().await?;
+    let table = fixture.latest_default_table().await?;
+    let (_, parts) = table.read_partitions(ctx.clone(), None, true).await?;
+    assert_eq!(parts.len(), 2);
+    let part = FuseBlockPartInfo::from_part(&parts.partitions[0])?;
+    let operator = FuseTable::try_from_table(table.as_ref())?.get_operator();
+    let mut data = operator.read(&part.location).await?.to_vec();
+    let (offset, _) = part.columns_meta.get(&0).unwrap().offset_length();
+    data[offset as usize] ^= 0xff;
+    operator.write(&part.location, data).await?;
+
+    // the corruption is detected on read if the verification is enabled
+    let res = fixture
+        .execute_command(&format!(
+            "settings (enable_block_checksum_verification = 1) select * from {db}.{tbl}"
+        ))
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::BLOCK_CORRUPTED);
+
+    let blocks = fixture
+        .execute_query(&verify)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+
+    // the corrupted block is quarantined and excluded from the scans
+    let table = fixture.latest_default_table().await?;
+    let quarantined_blocks = FuseTable::try_from_table(table.as_ref())?.quarantined_blocks()?;
+    assert_eq!(quarantined_blocks.len(), 1);
+    assert!(quarantined_blocks.contains(&part.location));
+
+    let (_, parts) = table.read_partitions(ctx.clone(), None, true).await?;
+    assert_eq!(parts.len(), 1);
+    assert_ne!(
+        FuseBlockPartInfo::from_part(&parts.partitions[0])?.location,
+        part.location
+    );
+    fixture
+        .execute_command(&format!(
+            "settings (enable_block_checksum_verification = 1) select * from {db}.{tbl}"
+        ))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_verify_native_read() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let db = fixture.default_db_name();
+    fixture.create_default_database().await?;
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t_native(id int) storage_format = 'native'"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t_native values(1),(2),(3)"))
+        .await?;
+
+    let query =
+        format!("settings (enable_block_checksum_verification = 1) select * from {db}.t_native");
+    fixture.execute_command(&query).await?;
+
+    // corrupt the first page of column `id`.
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx
+        .get_table(fixture.default_catalog_name().as_str(), &db, "t_native")
+        .await?;
+    let (_, parts) = table.read_partitions(ctx.clone(), None, true).await?;
+    let part = FuseBlockPartInfo::from_part(&parts.partitions[0])?;
+    let operator = FuseTable::try_from_table(table.as_ref())?.get_operator();
+    let mut data = operator.read(&part.location).await?.to_vec();
+    let (offset, _) = part.columns_meta.get(&0).unwrap().offset_length();
+    data[offset as usize] ^= 0xff;
+    operator.write(&part.location, data).await?;
+
+    let res = fixture.execute_command(&query).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::BLOCK_CORRUPTED);
+
+    Ok(())
+}
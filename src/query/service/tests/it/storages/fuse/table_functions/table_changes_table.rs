@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_storages_fuse::FuseTable;
+use databend_query::test_kits::*;
+
+async fn latest_snapshot_id(fixture: &TestFixture) -> Result<String> {
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    Ok(snapshot.snapshot_id.simple().to_string())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_changes_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+
+    let qry = format!("insert into {}.{} values(1, (2, 3)),(2, (4, 6))", db, tbl);
+    fixture.execute_command(qry.as_str()).await?;
+    let first = latest_snapshot_id(&fixture).await?;
+
+    let qry = format!("insert into {}.{} values(7, (8, 9))", db, tbl);
+    fixture.execute_command(qry.as_str()).await?;
+    let second = latest_snapshot_id(&fixture).await?;
+
+    let qry = format!("optimize table {}.{} compact segment", db, tbl);
+    fixture.execute_command(qry.as_str()).await?;
+    let third = latest_snapshot_id(&fixture).await?;
+
+    {
+        // the inserted segment and block
+        let expected = vec![
+            "+----------+-----------+----------+----------+",
+            "| Column 0 | Column 1  | Column 2 | Column 3 |",
+            "+----------+-----------+----------+----------+",
+            "| 'added'  | 'block'   | NULL     | 1        |",
+            "| 'added'  | 'segment' | 1        | NULL     |",
+            "+----------+-----------+----------+----------+",
+        ];
+        let qry = format!(
+            "select change, object, block_count, row_count from table_changes('{}.{}', '{}', '{}')",
+            db, tbl, first, second
+        );
+        expects_ok(
+            "inserted",
+            execute_query(ctx.clone(), qry.as_str()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    {
+        // the rows are counted once
+        let expected = vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| -1       | -1       |",
+            "+----------+----------+",
+        ];
+        let qry = format!(
+            "select sum(row_count), sum(block_count) from table_changes('{}.{}', '{}', '{}')",
+            db, tbl, second, first
+        );
+        expects_ok(
+            "reverted",
+            execute_query(ctx.clone(), qry.as_str()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    {
+        // the compaction of the segments moves the blocks, which are not changed
+        let expected = vec![
+            "+-----------+----------+----------+----------+",
+            "| Column 0  | Column 1 | Column 2 | Column 3 |",
+            "+-----------+----------+----------+----------+",
+            "| 'added'   | 1        | 2        | NULL     |",
+            "| 'removed' | 2        | -2       | NULL     |",
+            "+-----------+----------+----------+----------+",
+        ];
+        let qry = format!(
+            "select change, count(*), sum(block_count), sum(row_count) from table_changes('{}.{}', '{}', '{}') group by change",
+            db, tbl, second, third
+        );
+        expects_ok(
+            "compacted",
+            execute_query(ctx.clone(), qry.as_str()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
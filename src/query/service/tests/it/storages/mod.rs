@@ -14,5 +14,6 @@
 
 mod fuse;
 mod null;
+mod stage;
 mod statistics;
 mod system;
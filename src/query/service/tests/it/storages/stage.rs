@@ -0,0 +1,129 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::CsvFileFormatParams;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::PluginFileFormatParams;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_storage::StageOperatorPool;
+use databend_common_storages_stage::create_row_based_file_format;
+use databend_common_storages_stage::FileFormatPlugin;
+use databend_common_storages_stage::FileFormatPluginRegistry;
+use databend_common_storages_stage::RowBasedFileFormat;
+use databend_query::test_kits::expects_ok;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_stage_operator_pool() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let pool = StageOperatorPool::instance();
+    let fs = |root: &str| {
+        StorageParams::Fs(StorageFsConfig {
+            root: root.to_string(),
+        })
+    };
+
+    let pooled = pool.len();
+    pool.get(&fs("/tmp/stage_operator_pool/a"))?;
+    pool.get(&fs("/tmp/stage_operator_pool/a"))?;
+    assert_eq!(pool.len(), pooled + 1);
+
+    pool.get(&fs("/tmp/stage_operator_pool/b"))?;
+    assert_eq!(pool.len(), pooled + 2);
+
+    Ok(())
+}
+
+/// A CSV dialect delimited by `|`, which only accepts the `quote` option.
+struct PipeCsvPlugin;
+
+impl FileFormatPlugin for PipeCsvPlugin {
+    fn name(&self) -> &str {
+        "PIPE_CSV"
+    }
+
+    fn validate_options(&self, options: &BTreeMap<String, String>) -> Result<()> {
+        match options.keys().find(|key| key.as_str() != "quote") {
+            Some(key) => Err(ErrorCode::BadArguments(format!(
+                "Unknown option {key} of file format PIPE_CSV"
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn create_row_based_file_format(
+        &self,
+        params: &PluginFileFormatParams,
+    ) -> Result<Arc<dyn RowBasedFileFormat>> {
+        let mut csv = CsvFileFormatParams {
+            compression: params.compression,
+            field_delimiter: "|".to_string(),
+            ..Default::default()
+        };
+        if let Some(quote) = params.options.get("quote") {
+            csv.quote = quote.clone();
+        }
+        create_row_based_file_format(&FileFormatParams::Csv(csv))
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_file_format_plugin() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    FileFormatPluginRegistry::instance().register(Arc::new(PipeCsvPlugin))?;
+
+    // The options are validated by the plugin when the file format is created.
+    let res = fixture
+        .execute_command("create file format pipe_fmt type = pipe_csv field_delimiter = ','")
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::BAD_ARGUMENTS);
+    fixture
+        .execute_command("create file format pipe_fmt type = pipe_csv")
+        .await?;
+
+    fixture.execute_command("create stage plugin_stage").await?;
+    fixture
+        .execute_command(
+            "copy into @plugin_stage from (select number, number * 2 from numbers(3)) file_format = (type = csv field_delimiter = '|')",
+        )
+        .await?;
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| '0'      | '0'      |",
+        "| '1'      | '2'      |",
+        "| '2'      | '4'      |",
+        "+----------+----------+",
+    ];
+    expects_ok(
+        "file_format_plugin",
+        fixture
+            .execute_query(
+                "select $1, $2 from @plugin_stage (file_format => 'pipe_fmt') order by $1",
+            )
+            .await,
+        expected,
+    )
+    .await?;
+    Ok(())
+}
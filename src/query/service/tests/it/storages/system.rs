@@ -16,6 +16,7 @@ use std::io::Write;
 use std::sync::Arc;
 
 use databend_common_base::base::tokio;
+use databend_common_base::base::ProgressValues;
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_expression::block_debug::box_render;
@@ -28,6 +29,8 @@ use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::storage::StorageFsConfig;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::storage::StorageS3Config;
+use databend_common_sharing::ShareTableUsageKey;
+use databend_common_sharing::ShareUsageMeter;
 use databend_common_sql::executor::table_read_plan::ToReadDataSourcePlan;
 use databend_common_storages_system::BuildOptionsTable;
 use databend_common_storages_system::CachesTable;
@@ -44,9 +47,11 @@ use databend_common_storages_system::MetricsTable;
 use databend_common_storages_system::RolesTable;
 use databend_common_storages_system::UsersTable;
 use databend_common_users::UserApiProvider;
+use databend_query::interpreters::UsageMeter;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::TableContext;
 use databend_query::stream::ReadDataBlockStream;
+use databend_query::test_kits::expects_ok;
 use databend_query::test_kits::ClusterDescriptor;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
@@ -449,3 +454,95 @@ async fn test_caches_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_usage_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    for (scan_bytes, written_bytes) in [(200, 100), (300, 0)] {
+        let ctx = fixture.new_query_ctx().await?;
+        ctx.get_scan_progress().incr(&ProgressValues {
+            rows: 1,
+            bytes: scan_bytes,
+        });
+        ctx.get_write_progress().incr(&ProgressValues {
+            rows: 1,
+            bytes: written_bytes,
+        });
+        UsageMeter::instance().record(&ctx)?;
+    }
+    UsageMeter::instance().flush().await?;
+
+    let expected = vec![
+        "+----------+----------+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 | Column 3 | Column 4 |",
+        "+----------+----------+----------+----------+----------+",
+        "| 'root'   | 2        | 500      | 100      | 0        |",
+        "+----------+----------+----------+----------+----------+",
+    ];
+    expects_ok(
+        "usage",
+        fixture
+            .execute_query(
+                "select sql_user, sum(query_count), sum(scan_bytes), sum(written_bytes), sum(spilled_bytes) from system.usage group by sql_user",
+            )
+            .await,
+        expected,
+    )
+    .await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_share_usage_tables() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    // The reads of a share provided by the same tenant are seen on both sides.
+    let key = ShareTableUsageKey {
+        share_tenant: fixture.default_tenant().tenant_name().to_string(),
+        share_name: "s1".to_string(),
+        table_id: 10,
+    };
+    ShareUsageMeter::instance().record_read(&key, 100);
+    ShareUsageMeter::instance().record_read(&key, 50);
+    UsageMeter::instance().flush().await?;
+    ShareUsageMeter::instance().record_read(&key, 30);
+    UsageMeter::instance().flush().await?;
+
+    let expected = vec![
+        "+----------+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 | Column 3 |",
+        "+----------+----------+----------+----------+",
+        "| 's1'     | 10       | 3        | 180      |",
+        "+----------+----------+----------+----------+",
+    ];
+    expects_ok(
+        "share_usage",
+        fixture
+            .execute_query(
+                "select share_name, table_id, sum(read_requests), sum(read_bytes) from system.share_usage group by share_name, table_id",
+            )
+            .await,
+        expected,
+    )
+    .await?;
+
+    let expected = vec![
+        "+----------+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 | Column 3 |",
+        "+----------+----------+----------+----------+",
+        "| 's1'     | 10       | 3        | 180      |",
+        "+----------+----------+----------+----------+",
+    ];
+    expects_ok(
+        "share_consumer_usage",
+        fixture
+            .execute_query(
+                "select share_name, table_id, read_requests, read_bytes from system.share_consumer_usage",
+            )
+            .await,
+        expected,
+    )
+    .await?;
+    Ok(())
+}
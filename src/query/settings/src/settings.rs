@@ -31,12 +31,18 @@ use crate::settings_default::DefaultSettings;
 use crate::settings_default::SettingRange;
 use crate::SettingMode;
 
-#[derive(serde::Serialize, serde::Deserialize, Clone)]
+/// Where the effective value of a setting comes from.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq)]
 pub enum ScopeLevel {
     Default,
+    /// The `[query.settings]` section of the config file.
     Local,
+    /// Derived from other config options, e.g. `query.parquet_fast_read_bytes`.
+    Config,
     Global,
     Session,
+    /// A `SET_VAR` hint of the current query.
+    Hint,
 }
 
 impl Debug for ScopeLevel {
@@ -48,12 +54,18 @@ impl Debug for ScopeLevel {
             ScopeLevel::Local => {
                 write!(f, "LOCAL")
             }
+            ScopeLevel::Config => {
+                write!(f, "CONFIG")
+            }
             ScopeLevel::Global => {
                 write!(f, "GLOBAL")
             }
             ScopeLevel::Session => {
                 write!(f, "SESSION")
             }
+            ScopeLevel::Hint => {
+                write!(f, "HINT")
+            }
         }
     }
 }
@@ -152,6 +164,24 @@ impl Settings {
         Ok(())
     }
 
+    /// Apply the `SET_VAR` hints of a query, unknown settings are ignored.
+    pub fn set_hint_settings(&self, settings: &HashMap<String, String>) -> Result<()> {
+        for (k, v) in settings.iter() {
+            if self.has_setting(k.as_str())? {
+                self.set_setting(k.to_string(), v.to_string())?;
+                self.set_scope_level(k, ScopeLevel::Hint);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn set_scope_level(&self, key: &str, level: ScopeLevel) {
+        if let Some(mut change) = self.changes.get_mut(key) {
+            change.level = level;
+        }
+    }
+
     pub fn is_changed(&self) -> bool {
         !self.changes.is_empty()
     }
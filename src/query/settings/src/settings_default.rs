@@ -167,8 +167,8 @@ impl DefaultSettings {
                 }),
                 ("storage_io_min_bytes_for_seek", DefaultSettingValue {
                     value: UserSettingValue::UInt64(48),
-                    desc: "Sets the minimum byte size of data that must be read from storage in a single I/O operation \
-                when seeking a new location in the data file.",
+                    desc: "Sets the maximum gap in bytes between two column chunks of a data file that are coalesced \
+                into a single I/O request when reading, instead of seeking to a new location.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
@@ -178,6 +178,30 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("storage_io_max_retries", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(3),
+                    desc: "Sets the maximum number of retries for a failed read from storage when scanning tables.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
+                ("storage_io_retry_min_delay_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(100),
+                    desc: "Sets the initial delay in milliseconds before retrying a failed read from storage, the delay doubles on each retry.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
+                ("storage_io_retry_max_delay_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10 * 1000),
+                    desc: "Sets the maximum delay in milliseconds before retrying a failed read from storage.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
+                ("storage_io_hedged_read_threshold_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the latency threshold in milliseconds after which a duplicate read is issued to storage when scanning tables, the first returned result is used. 0 disables hedged reads.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("flight_client_timeout", DefaultSettingValue {
                     value: UserSettingValue::UInt64(60),
                     desc: "Sets the maximum time in seconds that a flight client request can be processed.",
@@ -332,6 +356,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("statement_timeout_in_seconds", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum time in seconds of a statement including its planning and execution, the statement fails with a statement timeout error when it expires. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("collation", DefaultSettingValue {
                     value: UserSettingValue::String("utf8".to_owned()),
                     desc: "Sets the character collation. Available values include \"utf8\".",
@@ -471,6 +501,13 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("lazy_filter_selectivity_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum estimated selectivity in percent of a filter on a single table scan to read the filter columns first, \
+                and fetch the other columns only for the rows passing the filter. Setting it to 0 disables the optimization.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
                 ("parquet_fast_read_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(16 * 1024 * 1024),
                     desc: "Parquet file with smaller size will be read as a whole file, instead of column by column. Default value: 16MB",
@@ -500,7 +537,7 @@ impl DefaultSettings {
                 }),
                 ("acquire_lock_timeout", DefaultSettingValue {
                     value: UserSettingValue::UInt64(30),
-                    desc: "Sets the maximum timeout in seconds for acquire a lock.",
+                    desc: "Sets the maximum time in seconds to wait for acquiring a table lock, the statement fails with a lock wait timeout error when it expires.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
@@ -558,6 +595,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("auto_compaction_segments_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(100),
+                    desc: "Threshold for triggering auto segment compaction, which merges the small segments without rewriting the blocks. This occurs when the number of segments in a snapshot exceeds the number needed by its blocks by this value after write operations, 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("use_parquet2", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "This setting is deprecated",
@@ -624,6 +667,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_parquet_bloom_filter", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables pruning the parquet row groups by the bloom filters of the columns in equality filters",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("external_server_connect_timeout_secs", DefaultSettingValue {
                     value: UserSettingValue::UInt64(10),
                     desc: "Connection timeout to external server",
@@ -741,7 +790,7 @@ impl DefaultSettings {
                 }),
                 ("idle_transaction_timeout_secs", DefaultSettingValue {
                     value: UserSettingValue::UInt64(4 * 60 * 60),
-                    desc: "Set the timeout in seconds for active session without any query",
+                    desc: "Sets the timeout in seconds for a session idle in an open transaction, the transaction is aborted with a transaction timeout error when it expires.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(1..=u64::MAX)),
                 }),
@@ -793,6 +842,276 @@ impl DefaultSettings {
                     desc: "Enables loser tree merge sort",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_column_lineage", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables recording column-level lineage of queries into system.column_lineage.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_join_skew_handling", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables salting the hot keys of distributed hash joins, the hot keys are detected from the build side at runtime.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("join_skew_sample_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(100000),
+                    desc: "Sets the number of build side rows sampled on each node to detect the hot keys of distributed hash joins.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
+                ("enable_semi_join_reduction", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables sending the build keys of a selective shuffle hash join to all nodes to filter the probe side scan.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("split_distinct_aggregate_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000000),
+                    desc: "Pre-aggregates distinct aggregates by the group keys and the distinct column when the estimated distinct values per group reach this threshold, 0 to disable.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("enable_auto_materialized_cte", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables materializing the identical subplans in the branches of UNION ALL and joins once and sharing them, when re-scanning is estimated to cost more.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("hash_join_build_cache_scope", DefaultSettingValue {
+                    value: UserSettingValue::String(String::from("none")),
+                    desc: "Sets where the build side of hash joins on unchanged tables is cached for the following queries. Available values include \"none\", \"session\" and \"warehouse\".",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["none".into(), "session".into(), "warehouse".into()])),
+                }),
+                ("hash_join_build_cache_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(256 * 1024 * 1024),
+                    desc: "Sets the maximum bytes of a hash join build side to be cached, the cache of the node is sized by the hash_join_build_cache_bytes config.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("enable_lazy_stage_listing", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables listing the files of stages page by page while reading them in select from stage, instead of listing all the files before the query starts.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("stage_listing_page_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the maximum number of stage files listed and kept in memory at a time when the stage files are listed lazily.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
+                ("enable_cache_affinity_scheduling", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables assigning the table scan partitions to the cluster nodes by consistent hashing, so each partition is likely read by the node which has cached it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_block_reorder_by_match_likelihood", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables reading the blocks in which all rows match the filters first for the queries with filters and limit, so the scan stops earlier.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("commit_segment_batch_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(16),
+                    desc: "Sets the number of new segments written concurrently in a batch before they are committed in one snapshot.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=1024)),
+                }),
+                ("enable_metadata_prefetch", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables loading the snapshot and all the segments of the tables into the cache in background while the query is being planned, regardless of the pruning. Disabled by default.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("bulk_insert_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(256 * 1024 * 1024),
+                    desc: "Sets the maximum bytes of the Arrow IPC payload of a bulk insert over HTTP, the larger payloads are rejected.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
+                ("metadata_prefetch_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(256 * 1024 * 1024),
+                    desc: "Sets the maximum bytes of the segments of a table loaded into the cache in background while the query is being planned.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("enable_distributed_pruning", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables pruning the segments of fuse tables on the nodes of the cluster before the query is scheduled, only the surviving blocks are distributed to the executors.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("memory_pressure_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(90),
+                    desc: "Sets the percentage of the memory limit of the node beyond which the joins and aggregators supporting spilling spill data to storage, and the table scans pause reading until memory is released. 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
+                ("memory_pressure_max_pause_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(5 * 1000),
+                    desc: "Sets the maximum time in milliseconds that a table scan pauses reading under memory pressure in total, before it continues reading anyway.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("maintenance_mode", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Rejects DML statements while still allowing reads and admin commands, it can only be set with SET GLOBAL and puts the whole cluster into maintenance.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("reserved_warehouses", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_string()),
+                    desc: "Sets the comma-separated warehouses reserved for the users and roles granted USAGE on them, it can only be set with SET GLOBAL.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("enable_adaptive_block_encoding", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables choosing narrower physical encodings for the blocks written by COPY and INSERT, by the min/max and distinct values of each column.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_block_checksum_verification", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables verifying the checksums of the column chunks read from fuse table blocks.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_query_replay_capture", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables capturing the plan, the settings and the table snapshots of queries into replay bundles, which are replayed by SYSTEM REPLAY QUERY.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("query_replay_retention_days", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(7),
+                    desc: "Sets the days the replay bundles are kept, the expired bundles are removed in background at most once an hour while queries are captured.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=365)),
+                }),
+                ("query_tag", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Tags the queries for workload attribution, e.g. by the dashboard or the job running them. The tag is recorded in the query log and the query profiles.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("application_name", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Sets the name of the client application running the queries, which is recorded in the query log and the query profiles.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("executor_pool", DefaultSettingValue {
+                    value: UserSettingValue::String("auto".to_owned()),
+                    desc: "Sets the worker pool the query runs in. Available values include \"auto\" (COPY, INSERT, UPDATE and unloading run in the batch pool), \"interactive\" and \"batch\".",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["auto".into(), "interactive".into(), "batch".into()])),
+                }),
+                ("enable_exchange_buffer_autotune", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables adjusting the number of in-flight blocks of each exchange link by its observed throughput and the memory pressure of the node.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("exchange_buffer_min_blocks", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(2),
+                    desc: "Sets the minimum number of in-flight blocks of an exchange link when the buffer is autotuned.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=1024)),
+                }),
+                ("exchange_buffer_max_blocks", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64),
+                    desc: "Sets the maximum number of in-flight blocks of an exchange link when the buffer is autotuned.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=1024)),
+                }),
+                ("enable_ctas_inherit_source_options", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables CREATE TABLE AS SELECT from a single table inheriting the cluster key and the bloom index columns of the source table, unless they are specified.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("repeated_subquery_hint_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(3),
+                    desc: "Sets the times an identical expensive subquery in FROM runs before materializing it is suggested in the warnings, 0 disables the tracking.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("repeated_subquery_tracking_scope", DefaultSettingValue {
+                    value: UserSettingValue::String(String::from("session")),
+                    desc: "Sets where the runs of the subqueries are counted for the materialization hints. Available values include \"session\" and \"warehouse\".",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["session".into(), "warehouse".into()])),
+                }),
+                ("enable_auto_subquery_materialization", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables materializing the subqueries suggested by the hints automatically, the following queries read the materialized results as long as the tables read by the subqueries are unchanged.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("subquery_materialization_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the maximum bytes of the subqueries automatically materialized for the session, the least recently used ones are evicted first. The subqueries materialized for the warehouse are capped by the subquery_materialization_cache_bytes config of the node instead.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("enable_result_spill", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables spilling the result blocks of the http queries to the temporary files when the client reads them slower than the query produces them.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("result_spill_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1024 * 1024 * 1024),
+                    desc: "Sets the maximum bytes of the result blocks spilled by a query, the query waits for the client once it is reached, 0 means unlimited.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("column_backfill_batch_blocks", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(100),
+                    desc: "Sets the maximum number of blocks rewritten in each commit of the backfill of a column added with a non-constant default.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
+                ("enable_background_column_conversion", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Converts the data of the columns modified to an incompatible type in the background, instead of in the ALTER TABLE statement.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("strict_sql_mode", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Raises errors on integer overflows, casts losing the fractional digits and invalid dates in the expressions and the COPY casts, instead of wrapping, rounding or coercing them.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("random_seed", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the seed of rand() to make the results repeatable, each value is derived from the seed, the processor, the block evaluated by the processor and the row in the block. The results of the queries with more than one thread may differ between runs when the blocks reach other processors. 0 uses a random seed.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("fault_injection", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_string()),
+                    desc: "Injects faults at the named points for testing, e.g. 'exchange_send:error@0.1,block_read:delay=200,commit:oom'. Only available in the builds with the fault-injection feature and the nodes with 'enable_fault_injection = true' in the config, the delay of commit is at most 10 seconds.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("enable_consistent_snapshot_read", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables reading the snapshots of all the tables in a SELECT as of the start of the query, ignoring the commits made while the query is being planned. The start is taken from the clock of the node, so the clocks of the nodes must be synchronized, or the commits made just before the query may be missed.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_spill_encryption", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables encrypting the data spilled by join, aggregate, sort and the http query results with a key generated for each query.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
                 })
             ]);
 
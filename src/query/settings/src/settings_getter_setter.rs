@@ -205,11 +205,31 @@ impl Settings {
         self.try_get_u64("storage_io_max_page_bytes_for_read")
     }
 
+    pub fn get_storage_io_max_retries(&self) -> Result<u64> {
+        self.try_get_u64("storage_io_max_retries")
+    }
+
+    pub fn get_storage_io_retry_min_delay_ms(&self) -> Result<u64> {
+        self.try_get_u64("storage_io_retry_min_delay_ms")
+    }
+
+    pub fn get_storage_io_retry_max_delay_ms(&self) -> Result<u64> {
+        self.try_get_u64("storage_io_retry_max_delay_ms")
+    }
+
+    pub fn get_storage_io_hedged_read_threshold_ms(&self) -> Result<u64> {
+        self.try_get_u64("storage_io_hedged_read_threshold_ms")
+    }
+
     // Get max_execute_time_in_seconds.
     pub fn get_max_execute_time_in_seconds(&self) -> Result<u64> {
         self.try_get_u64("max_execute_time_in_seconds")
     }
 
+    pub fn get_statement_timeout_in_seconds(&self) -> Result<u64> {
+        self.try_get_u64("statement_timeout_in_seconds")
+    }
+
     // Get flight client timeout.
     pub fn get_flight_client_timeout(&self) -> Result<u64> {
         self.try_get_u64("flight_client_timeout")
@@ -411,6 +431,10 @@ impl Settings {
         self.try_get_u64("lazy_read_threshold")
     }
 
+    pub fn get_lazy_filter_selectivity_threshold(&self) -> Result<u64> {
+        self.try_get_u64("lazy_filter_selectivity_threshold")
+    }
+
     pub fn set_parquet_fast_read_bytes(&self, value: u64) -> Result<()> {
         self.try_set_u64("parquet_fast_read_bytes", value)
     }
@@ -492,6 +516,10 @@ impl Settings {
         self.try_get_u64("auto_compaction_imperfect_blocks_threshold")
     }
 
+    pub fn get_auto_compaction_segments_threshold(&self) -> Result<u64> {
+        self.try_get_u64("auto_compaction_segments_threshold")
+    }
+
     pub fn set_auto_compaction_imperfect_blocks_threshold(&self, val: u64) -> Result<()> {
         self.try_set_u64("auto_compaction_imperfect_blocks_threshold", val)
     }
@@ -553,6 +581,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_parquet_rowgroup_pruning")? != 0)
     }
 
+    pub fn get_enable_parquet_bloom_filter(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_parquet_bloom_filter")? != 0)
+    }
+
     pub fn get_enable_parquet_prewhere(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_parquet_prewhere")? != 0)
     }
@@ -661,4 +693,179 @@ impl Settings {
     pub fn get_enable_loser_tree_merge_sort(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_loser_tree_merge_sort")? == 1)
     }
+
+    pub fn get_enable_column_lineage(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_column_lineage")? != 0)
+    }
+
+    pub fn get_enable_join_skew_handling(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_join_skew_handling")? != 0)
+    }
+
+    pub fn get_join_skew_sample_rows(&self) -> Result<usize> {
+        Ok(self.try_get_u64("join_skew_sample_rows")? as usize)
+    }
+
+    pub fn get_enable_semi_join_reduction(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_semi_join_reduction")? != 0)
+    }
+
+    pub fn get_split_distinct_aggregate_threshold(&self) -> Result<u64> {
+        self.try_get_u64("split_distinct_aggregate_threshold")
+    }
+
+    pub fn get_enable_auto_materialized_cte(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_auto_materialized_cte")? != 0)
+    }
+
+    pub fn get_hash_join_build_cache_scope(&self) -> Result<String> {
+        self.try_get_string("hash_join_build_cache_scope")
+    }
+
+    pub fn get_hash_join_build_cache_max_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("hash_join_build_cache_max_bytes")? as usize)
+    }
+
+    pub fn get_enable_lazy_stage_listing(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_lazy_stage_listing")? != 0)
+    }
+
+    pub fn get_stage_listing_page_size(&self) -> Result<usize> {
+        Ok(self.try_get_u64("stage_listing_page_size")? as usize)
+    }
+
+    pub fn get_enable_cache_affinity_scheduling(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_cache_affinity_scheduling")? != 0)
+    }
+
+    pub fn get_enable_block_reorder_by_match_likelihood(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_block_reorder_by_match_likelihood")? != 0)
+    }
+
+    pub fn get_commit_segment_batch_size(&self) -> Result<usize> {
+        Ok(self.try_get_u64("commit_segment_batch_size")? as usize)
+    }
+
+    pub fn get_enable_metadata_prefetch(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_metadata_prefetch")? != 0)
+    }
+
+    pub fn get_bulk_insert_max_bytes(&self) -> Result<u64> {
+        self.try_get_u64("bulk_insert_max_bytes")
+    }
+
+    pub fn get_metadata_prefetch_max_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("metadata_prefetch_max_bytes")? as usize)
+    }
+
+    pub fn get_enable_distributed_pruning(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_distributed_pruning")? != 0)
+    }
+
+    pub fn get_memory_pressure_ratio(&self) -> Result<u64> {
+        self.try_get_u64("memory_pressure_ratio")
+    }
+
+    pub fn get_memory_pressure_max_pause_ms(&self) -> Result<u64> {
+        self.try_get_u64("memory_pressure_max_pause_ms")
+    }
+
+    pub fn get_enable_adaptive_block_encoding(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_adaptive_block_encoding")? != 0)
+    }
+
+    pub fn get_enable_block_checksum_verification(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_block_checksum_verification")? != 0)
+    }
+
+    pub fn get_enable_query_replay_capture(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_query_replay_capture")? != 0)
+    }
+
+    pub fn get_query_replay_retention_days(&self) -> Result<u64> {
+        self.try_get_u64("query_replay_retention_days")
+    }
+
+    pub fn get_query_tag(&self) -> Result<String> {
+        self.try_get_string("query_tag")
+    }
+
+    pub fn get_application_name(&self) -> Result<String> {
+        self.try_get_string("application_name")
+    }
+
+    pub fn get_executor_pool(&self) -> Result<String> {
+        self.try_get_string("executor_pool")
+    }
+
+    pub fn get_enable_exchange_buffer_autotune(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_exchange_buffer_autotune")? != 0)
+    }
+
+    pub fn get_exchange_buffer_min_blocks(&self) -> Result<u64> {
+        self.try_get_u64("exchange_buffer_min_blocks")
+    }
+
+    pub fn get_exchange_buffer_max_blocks(&self) -> Result<u64> {
+        self.try_get_u64("exchange_buffer_max_blocks")
+    }
+
+    pub fn get_enable_ctas_inherit_source_options(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_ctas_inherit_source_options")? != 0)
+    }
+
+    pub fn get_repeated_subquery_hint_threshold(&self) -> Result<u64> {
+        self.try_get_u64("repeated_subquery_hint_threshold")
+    }
+
+    pub fn get_repeated_subquery_tracking_scope(&self) -> Result<String> {
+        self.try_get_string("repeated_subquery_tracking_scope")
+    }
+
+    pub fn get_enable_auto_subquery_materialization(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_auto_subquery_materialization")? != 0)
+    }
+
+    pub fn get_subquery_materialization_max_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("subquery_materialization_max_bytes")? as usize)
+    }
+
+    pub fn get_enable_result_spill(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_result_spill")? != 0)
+    }
+
+    pub fn get_result_spill_max_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("result_spill_max_bytes")? as usize)
+    }
+
+    pub fn get_column_backfill_batch_blocks(&self) -> Result<usize> {
+        Ok(self.try_get_u64("column_backfill_batch_blocks")? as usize)
+    }
+
+    pub fn get_enable_background_column_conversion(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_background_column_conversion")? != 0)
+    }
+
+    pub fn get_strict_sql_mode(&self) -> Result<bool> {
+        Ok(self.try_get_u64("strict_sql_mode")? != 0)
+    }
+
+    pub fn get_random_seed(&self) -> Result<Option<u64>> {
+        match self.try_get_u64("random_seed")? {
+            0 => Ok(None),
+            seed => Ok(Some(seed)),
+        }
+    }
+
+    pub fn get_fault_injection(&self) -> Result<String> {
+        self.try_get_string("fault_injection")
+    }
+
+    pub fn get_enable_consistent_snapshot_read(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_consistent_snapshot_read")? != 0)
+    }
+
+    pub fn get_enable_spill_encryption(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_spill_encryption")? != 0)
+    }
 }
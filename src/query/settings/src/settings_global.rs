@@ -72,16 +72,19 @@ impl Settings {
         let query_config = &GlobalConfig::instance().query;
         if let Some(parquet_fast_read_bytes) = query_config.parquet_fast_read_bytes {
             self.set_parquet_fast_read_bytes(parquet_fast_read_bytes)?;
+            self.set_scope_level("parquet_fast_read_bytes", ScopeLevel::Config);
         }
 
         if let Some(max_storage_io_requests) = query_config.max_storage_io_requests {
             self.set_max_storage_io_requests(max_storage_io_requests)?;
+            self.set_scope_level("max_storage_io_requests", ScopeLevel::Config);
         }
 
         if let Some(enterprise_license_key) = query_config.databend_enterprise_license.clone() {
             unsafe {
                 self.set_enterprise_license(enterprise_license_key)?;
             }
+            self.set_scope_level("enterprise_license", ScopeLevel::Config);
         }
         Ok(())
     }
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
 use databend_common_meta_app::tenant::Tenant;
+use databend_common_settings::ScopeLevel;
 use databend_common_settings::Settings;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -198,3 +201,28 @@ async fn test_set_data_retention_time_in_days_from_config() {
         assert_eq!(expect, format!("{}", result.unwrap_err()));
     }
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_settings_scope_level() {
+    let settings = Settings::create(Tenant::new_literal("test"));
+    let level_of = |name: &str| {
+        settings
+            .into_iter()
+            .find(|item| item.name == name)
+            .map(|item| item.level)
+            .unwrap()
+    };
+
+    assert!(level_of("max_threads") == ScopeLevel::Default);
+
+    settings.set_max_threads(2).unwrap();
+    assert!(level_of("max_threads") == ScopeLevel::Session);
+
+    let hints = HashMap::from([
+        ("max_threads".to_string(), "3".to_string()),
+        ("no_such_setting".to_string(), "1".to_string()),
+    ]);
+    settings.set_hint_settings(&hints).unwrap();
+    assert!(level_of("max_threads") == ScopeLevel::Hint);
+    assert_eq!(settings.get_max_threads().unwrap(), 3);
+}
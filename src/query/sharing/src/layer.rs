@@ -48,6 +48,8 @@ use opendal::Operator;
 use opendal::Result;
 use opendal::Scheme;
 
+use crate::ShareTableUsageKey;
+use crate::ShareUsageMeter;
 use crate::SharedSigner;
 
 pub fn create_share_table_operator(
@@ -65,10 +67,16 @@ pub fn create_share_table_operator(
         ),
         share_params.share_endpoint_credential.clone(),
     );
+    let usage_key = ShareTableUsageKey {
+        share_tenant: share_ident_raw.tenant_name().to_string(),
+        share_name: share_ident_raw.share_name().to_string(),
+        table_id,
+    };
     let client = HttpClient::new()?;
     let op = Operator::new(SharedBuilder {
         signer: Some(signer),
         client: Some(client),
+        usage_key: Some(usage_key),
     })?
     // Add retry
     .layer(RetryLayer::new().with_jitter())
@@ -86,6 +94,7 @@ pub fn create_share_table_operator(
 struct SharedBuilder {
     signer: Option<SharedSigner>,
     client: Option<HttpClient>,
+    usage_key: Option<ShareTableUsageKey>,
 }
 
 impl Builder for SharedBuilder {
@@ -101,6 +110,7 @@ impl Builder for SharedBuilder {
         Ok(SharedAccessor {
             signer: self.signer.take().expect("must be valid"),
             client: self.client.take().expect("must be valid"),
+            usage_key: self.usage_key.take().expect("must be valid"),
         })
     }
 }
@@ -109,6 +119,7 @@ impl Builder for SharedBuilder {
 struct SharedAccessor {
     signer: SharedSigner,
     client: HttpClient,
+    usage_key: ShareTableUsageKey,
 }
 
 impl Access for SharedAccessor {
@@ -153,6 +164,8 @@ impl Access for SharedAccessor {
         let resp = self.client.fetch(req).await?;
 
         let res = if resp.status().is_success() {
+            let bytes = parse_content_length(resp.headers())?.unwrap_or_default();
+            ShareUsageMeter::instance().record_read(&self.usage_key, bytes);
             resp.into_body()
         } else {
             let (part, mut body) = resp.into_parts();
@@ -27,3 +27,9 @@ pub use signer::SharedSigner;
 
 mod share_endpoint;
 pub use share_endpoint::ShareEndpointManager;
+
+mod usage;
+pub use usage::ShareConsumerUsage;
+pub use usage::ShareTableUsage;
+pub use usage::ShareTableUsageKey;
+pub use usage::ShareUsageMeter;
@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::mem;
+use std::sync::Arc;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::Result;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::Operation;
+use databend_common_users::UserApiProvider;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+
+const SHARE_USAGE_PREFIX: &str = "__fd_share_usage";
+
+/// A table of a share read by this node.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShareTableUsageKey {
+    /// The tenant providing the share.
+    pub share_tenant: String,
+    pub share_name: String,
+    pub table_id: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ShareTableUsage {
+    pub read_requests: u64,
+    pub read_bytes: u64,
+}
+
+/// The reads of a shared table by a consumer tenant in total, kept in the meta under the
+/// tenant providing the share.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ShareConsumerUsage {
+    pub share_name: String,
+    pub consumer_tenant: String,
+    pub table_id: u64,
+    pub read_requests: u64,
+    pub read_bytes: u64,
+}
+
+/// Accumulates the reads of the shared tables through the share endpoints, which is
+/// flushed periodically into `system.share_usage` for the accounting of the shares,
+/// and added up for the providers in `system.share_consumer_usage`.
+#[derive(Default)]
+pub struct ShareUsageMeter {
+    usages: Mutex<HashMap<ShareTableUsageKey, ShareTableUsage>>,
+}
+
+impl ShareUsageMeter {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(ShareUsageMeter::default()));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<ShareUsageMeter> {
+        GlobalInstance::get()
+    }
+
+    pub fn record_read(&self, key: &ShareTableUsageKey, bytes: u64) {
+        let mut usages = self.usages.lock();
+        let usage = usages.entry(key.clone()).or_default();
+        usage.read_requests += 1;
+        usage.read_bytes += bytes;
+    }
+
+    /// Takes the usages accumulated since the last call.
+    pub fn take(&self) -> HashMap<ShareTableUsageKey, ShareTableUsage> {
+        mem::take(&mut *self.usages.lock())
+    }
+}
+
+impl ShareUsageMeter {
+    /// Adds the reads of the shares by `consumer_tenant` to the usages seen by the tenants
+    /// providing the shares.
+    #[async_backtrace::framed]
+    pub async fn report_to_providers(
+        consumer_tenant: &str,
+        usages: &HashMap<ShareTableUsageKey, ShareTableUsage>,
+    ) -> Result<()> {
+        let meta = UserApiProvider::instance().get_meta_store_client();
+        for (key, usage) in usages {
+            let usage_key = format!(
+                "{}/{}/{}/{}/{}",
+                SHARE_USAGE_PREFIX,
+                escape_for_key(&key.share_tenant)?,
+                escape_for_key(&key.share_name)?,
+                escape_for_key(consumer_tenant)?,
+                key.table_id
+            );
+            // The nodes of the consumer may report the same table at the same time.
+            loop {
+                let (seq, mut total) = match meta.get_kv(&usage_key).await? {
+                    Some(seq_v) => (
+                        seq_v.seq,
+                        serde_json::from_slice::<ShareConsumerUsage>(&seq_v.data)?,
+                    ),
+                    None => (0, ShareConsumerUsage {
+                        share_name: key.share_name.clone(),
+                        consumer_tenant: consumer_tenant.to_string(),
+                        table_id: key.table_id,
+                        ..Default::default()
+                    }),
+                };
+                total.read_requests += usage.read_requests;
+                total.read_bytes += usage.read_bytes;
+                let reply = meta
+                    .upsert_kv(UpsertKVReq::new(
+                        &usage_key,
+                        MatchSeq::Exact(seq),
+                        Operation::Update(serde_json::to_vec(&total)?),
+                        None,
+                    ))
+                    .await?;
+                if reply.is_changed() {
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The reads of the shares provided by `provider_tenant`, by each consumer tenant.
+    #[async_backtrace::framed]
+    pub async fn provider_usages(provider_tenant: &str) -> Result<Vec<ShareConsumerUsage>> {
+        let meta = UserApiProvider::instance().get_meta_store_client();
+        let prefix = format!(
+            "{}/{}/",
+            SHARE_USAGE_PREFIX,
+            escape_for_key(provider_tenant)?
+        );
+        let mut usages = vec![];
+        for (_, seq_v) in meta.prefix_list_kv(&prefix).await? {
+            usages.push(serde_json::from_slice::<ShareConsumerUsage>(&seq_v.data)?);
+        }
+        Ok(usages)
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use databend_common_ast::ast::FormatTreeNode;
@@ -24,6 +25,8 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_core::processors::PlanProfile;
 use itertools::Itertools;
+use serde_json::json;
+use serde_json::Value;
 
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::AggregateExpand;
@@ -80,6 +83,12 @@ impl PhysicalPlan {
         to_format_tree(self, &metadata, &profs)
     }
 
+    /// Format the plan as a JSON document, the `id` of each node matches the
+    /// plan id used by query profiles.
+    pub fn format_json(&self) -> Result<Value> {
+        to_json(self)
+    }
+
     pub fn format_join(&self, metadata: &MetadataRef) -> Result<FormatTreeNode<String>> {
         match self {
             PhysicalPlan::TableScan(plan) => {
@@ -354,6 +363,92 @@ fn to_format_tree(
     }
 }
 
+/// Converts the plan and its children to the JSON tree of `format_json`.
+fn to_json(plan: &PhysicalPlan) -> Result<Value> {
+    let labels = plan.get_labels()?.into_iter().collect::<BTreeMap<_, _>>();
+    let pruning = match plan {
+        PhysicalPlan::TableScan(scan) => part_stats_info_to_json(&scan.source.statistics),
+        _ => Value::Null,
+    };
+    let children = plan.children().map(to_json).collect::<Result<Vec<_>>>()?;
+
+    Ok(json!({
+        "id": plan.get_id(),
+        "name": plan.name(),
+        "description": plan.get_desc()?,
+        "estimated_rows": plan_stats_info(plan).map(|info| info.estimated_rows),
+        "distribution": distribution_to_json(plan),
+        "pruning": pruning,
+        "labels": labels,
+        "children": children,
+    }))
+}
+
+fn plan_stats_info(plan: &PhysicalPlan) -> Option<&PlanStatsInfo> {
+    match plan {
+        PhysicalPlan::TableScan(v) => v.stat_info.as_ref(),
+        PhysicalPlan::Filter(v) => v.stat_info.as_ref(),
+        PhysicalPlan::EvalScalar(v) => v.stat_info.as_ref(),
+        PhysicalPlan::ProjectSet(v) => v.stat_info.as_ref(),
+        PhysicalPlan::AggregateExpand(v) => v.stat_info.as_ref(),
+        PhysicalPlan::AggregatePartial(v) => v.stat_info.as_ref(),
+        PhysicalPlan::AggregateFinal(v) => v.stat_info.as_ref(),
+        PhysicalPlan::Sort(v) => v.stat_info.as_ref(),
+        PhysicalPlan::Limit(v) => v.stat_info.as_ref(),
+        PhysicalPlan::RowFetch(v) => v.stat_info.as_ref(),
+        PhysicalPlan::HashJoin(v) => v.stat_info.as_ref(),
+        PhysicalPlan::RangeJoin(v) => v.stat_info.as_ref(),
+        PhysicalPlan::UnionAll(v) => v.stat_info.as_ref(),
+        PhysicalPlan::Udf(v) => v.stat_info.as_ref(),
+        PhysicalPlan::AsyncFunction(v) => v.stat_info.as_ref(),
+        _ => None,
+    }
+}
+
+fn distribution_to_json(plan: &PhysicalPlan) -> Value {
+    let (kind, keys) = match plan {
+        PhysicalPlan::Exchange(v) => (&v.kind, &v.keys),
+        PhysicalPlan::ExchangeSink(v) => (&v.kind, &v.keys),
+        _ => return Value::Null,
+    };
+
+    let kind = match kind {
+        FragmentKind::Init => "Init-Partition",
+        FragmentKind::Normal => "Hash",
+        FragmentKind::Expansive => "Broadcast",
+        FragmentKind::Merge => "Merge",
+    };
+    let keys = keys
+        .iter()
+        .map(|key| key.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>();
+
+    json!({
+        "kind": kind,
+        "keys": keys,
+    })
+}
+
+fn part_stats_info_to_json(info: &PartStatistics) -> Value {
+    let pruning = &info.pruning_stats;
+    json!({
+        "read_rows": info.read_rows,
+        "read_bytes": info.read_bytes,
+        "partitions_total": info.partitions_total,
+        "partitions_scanned": info.partitions_scanned,
+        "segments_range_pruning_before": pruning.segments_range_pruning_before,
+        "segments_range_pruning_after": pruning.segments_range_pruning_after,
+        "blocks_range_pruning_before": pruning.blocks_range_pruning_before,
+        "blocks_range_pruning_after": pruning.blocks_range_pruning_after,
+        "blocks_bloom_pruning_before": pruning.blocks_bloom_pruning_before,
+        "blocks_bloom_pruning_after": pruning.blocks_bloom_pruning_after,
+        "blocks_inverted_index_pruning_before": pruning.blocks_inverted_index_pruning_before,
+        "blocks_inverted_index_pruning_after": pruning.blocks_inverted_index_pruning_after,
+        "blocks_limit_pruning_before": pruning.blocks_limit_pruning_before,
+        "blocks_limit_pruning_after": pruning.blocks_limit_pruning_after,
+    })
+}
+
 /// Helper function to add profile info to the format tree.
 fn append_profile_info(
     children: &mut Vec<FormatTreeNode<String>>,
@@ -1391,6 +1486,18 @@ fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<S
         );
     }
 
+    // limit pruning status.
+    if info.pruning_stats.blocks_limit_pruning_before > 0 {
+        if !blocks_pruning_description.is_empty() {
+            blocks_pruning_description += ", ";
+        }
+        blocks_pruning_description += &format!(
+            "limit pruning: {} to {}",
+            info.pruning_stats.blocks_limit_pruning_before,
+            info.pruning_stats.blocks_limit_pruning_after
+        );
+    }
+
     // Combine segment pruning and blocks pruning descriptions if any
     if info.pruning_stats.segments_range_pruning_before > 0
         || !blocks_pruning_description.is_empty()
@@ -19,6 +19,7 @@ mod physical_plan_builder;
 mod physical_plan_display;
 mod physical_plan_visitor;
 pub mod physical_plans;
+mod query_estimate;
 mod util;
 
 pub mod table_read_plan;
@@ -27,4 +28,5 @@ pub use physical_plan::PhysicalPlan;
 pub use physical_plan_builder::MergeIntoBuildInfo;
 pub use physical_plan_builder::PhysicalPlanBuilder;
 pub use physical_plan_visitor::PhysicalPlanReplacer;
+pub use query_estimate::QueryEstimate;
 pub use util::*;
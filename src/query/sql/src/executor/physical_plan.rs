@@ -19,6 +19,7 @@ use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use enum_as_inner::EnumAsInner;
 use itertools::Itertools;
 
@@ -937,6 +938,49 @@ impl PhysicalPlan {
                 labels.insert(String::from("Total partitions"), vec![
                     v.source.statistics.partitions_total.to_string(),
                 ]);
+                labels.insert(String::from("Scanned partitions"), vec![
+                    v.source.statistics.partitions_scanned.to_string(),
+                ]);
+
+                // The snapshots of all the tables are resolved at the start of the query.
+                if let DataSourceInfo::TableSource(table_info) = &v.source.source_info {
+                    if let Some(location) = table_info.options().get(OPT_KEY_SNAPSHOT_LOCATION) {
+                        labels.insert(String::from("Snapshot"), vec![location.clone()]);
+                    }
+                }
+
+                let pruning_stats = &v.source.statistics.pruning_stats;
+                for (name, before, after) in [
+                    (
+                        "Segment range pruning",
+                        pruning_stats.segments_range_pruning_before,
+                        pruning_stats.segments_range_pruning_after,
+                    ),
+                    (
+                        "Block range pruning",
+                        pruning_stats.blocks_range_pruning_before,
+                        pruning_stats.blocks_range_pruning_after,
+                    ),
+                    (
+                        "Block bloom pruning",
+                        pruning_stats.blocks_bloom_pruning_before,
+                        pruning_stats.blocks_bloom_pruning_after,
+                    ),
+                    (
+                        "Block inverted index pruning",
+                        pruning_stats.blocks_inverted_index_pruning_before,
+                        pruning_stats.blocks_inverted_index_pruning_after,
+                    ),
+                    (
+                        "Block limit pruning",
+                        pruning_stats.blocks_limit_pruning_before,
+                        pruning_stats.blocks_limit_pruning_after,
+                    ),
+                ] {
+                    if before > 0 {
+                        labels.insert(String::from(name), vec![format!("{} to {}", before, after)]);
+                    }
+                }
             }
             PhysicalPlan::Filter(v) => {
                 labels.insert(
@@ -264,6 +264,7 @@ pub trait PhysicalPlanReplacer {
             stat_info: plan.stat_info.clone(),
             probe_keys_rt: plan.probe_keys_rt.clone(),
             enable_bloom_runtime_filter: plan.enable_bloom_runtime_filter,
+            enable_semi_join_reduction: plan.enable_semi_join_reduction,
             broadcast: plan.broadcast,
             single_to_inner: plan.single_to_inner.clone(),
             build_side_cache_info: plan.build_side_cache_info.clone(),
@@ -311,6 +312,7 @@ pub trait PhysicalPlanReplacer {
             pre_projection: plan.pre_projection.clone(),
             stat_info: plan.stat_info.clone(),
             window_partition: plan.window_partition.clone(),
+            input_order: plan.input_order,
         }))
     }
 
@@ -84,6 +84,7 @@ mod physical_row_fetch;
 pub use physical_row_fetch::RowFetch;
 mod physical_sort;
 pub use physical_sort::Sort;
+pub use physical_sort::SortInputOrder;
 mod physical_local_shuffle;
 pub use physical_local_shuffle::LocalShuffle;
 mod physical_table_scan;
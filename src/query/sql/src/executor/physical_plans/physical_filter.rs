@@ -12,19 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::ROW_ID_COLUMN_ID;
+use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use itertools::Itertools;
 
+use crate::binder::INTERNAL_COLUMN_FACTORY;
 use crate::executor::cast_expr_to_non_null_boolean;
 use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::RowFetch;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
+use crate::ColumnEntry;
 use crate::TypeCheck;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -62,10 +72,18 @@ impl PhysicalPlanBuilder {
         stat_info: PlanStatsInfo,
     ) -> Result<PhysicalPlan> {
         // 1. Prune unused Columns.
-        let column_projections = required.clone().into_iter().collect::<Vec<_>>();
-        let used = filter.predicates.iter().fold(required, |acc, v| {
-            acc.union(&v.used_columns()).cloned().collect()
-        });
+        let lazy_columns = self.analyze_lazy_filter(s_expr, filter, &required, &stat_info)?;
+        let mut column_projections = required
+            .difference(&lazy_columns)
+            .cloned()
+            .collect::<Vec<_>>();
+        if !lazy_columns.is_empty() {
+            column_projections.extend(self.metadata.read().row_id_indexes());
+        }
+        let used = filter.predicates.iter().fold(
+            column_projections.iter().cloned().collect::<ColumnSet>(),
+            |acc, v| acc.union(&v.used_columns()).cloned().collect(),
+        );
 
         // 2. Build physical plan.
         let input = Box::new(self.build(s_expr.child(0)?, used).await?);
@@ -77,7 +95,7 @@ impl PhysicalPlanBuilder {
             }
         }
 
-        Ok(PhysicalPlan::Filter(Filter {
+        let plan = PhysicalPlan::Filter(Filter {
             plan_id: 0,
             projections,
             input,
@@ -96,6 +114,150 @@ impl PhysicalPlanBuilder {
                 })
                 .collect::<Result<_>>()?,
 
+            stat_info: Some(stat_info.clone()),
+        });
+
+        if lazy_columns.is_empty() {
+            return Ok(plan);
+        }
+        self.build_lazy_filter_row_fetch(plan, lazy_columns, stat_info)
+    }
+
+    /// If the filter on a single table scan is highly selective, only the columns used by
+    /// the filter are read by the scan, and the other required columns are marked as lazy
+    /// columns, which will be fetched by the row ids of the rows passing the filter.
+    fn analyze_lazy_filter(
+        &self,
+        s_expr: &SExpr,
+        filter: &crate::plans::Filter,
+        required: &ColumnSet,
+        stat_info: &PlanStatsInfo,
+    ) -> Result<ColumnSet> {
+        let threshold = self
+            .ctx
+            .get_settings()
+            .get_lazy_filter_selectivity_threshold()?;
+        let child = s_expr.child(0)?;
+        let RelOperator::Scan(scan) = child.plan() else {
+            return Ok(ColumnSet::new());
+        };
+        if threshold == 0
+            || scan.prewhere.is_some()
+            || scan.agg_index.is_some()
+            || scan.change_type.is_some()
+            || scan.inverted_index.is_some()
+            || scan.update_stream_columns
+        {
+            return Ok(ColumnSet::new());
+        }
+
+        let cardinality = RelExpr::with_s_expr(child)
+            .derive_cardinality()?
+            .cardinality;
+        if cardinality < 1.0 || stat_info.estimated_rows * 100.0 > cardinality * threshold as f64 {
+            return Ok(ColumnSet::new());
+        }
+
+        let mut metadata = self.metadata.write();
+        if metadata.tables().len() != 1
+            || !metadata.lazy_columns().is_empty()
+            || !metadata
+                .table(scan.table_index)
+                .table()
+                .supported_internal_column(ROW_ID_COLUMN_ID)
+            || scan
+                .columns
+                .iter()
+                .any(|index| matches!(metadata.column(*index), ColumnEntry::VirtualColumn(_)))
+        {
+            return Ok(ColumnSet::new());
+        }
+
+        let filter_columns = filter
+            .predicates
+            .iter()
+            .flat_map(|predicate| predicate.used_columns())
+            .collect::<ColumnSet>();
+        let lazy_columns = required
+            .iter()
+            .filter(|index| {
+                scan.columns.contains(index)
+                    && !filter_columns.contains(index)
+                    && !metadata.non_lazy_columns().contains(index)
+                    && matches!(metadata.column(**index), ColumnEntry::BaseTableColumn(_))
+            })
+            .cloned()
+            .collect::<ColumnSet>();
+        if lazy_columns.is_empty() {
+            return Ok(lazy_columns);
+        }
+
+        if metadata
+            .row_id_index_by_table_index(scan.table_index)
+            .is_none()
+        {
+            let internal_column = INTERNAL_COLUMN_FACTORY
+                .get_internal_column(ROW_ID_COL_NAME)
+                .unwrap();
+            let index = metadata.add_internal_column(scan.table_index, internal_column);
+            metadata.set_table_row_id_index(scan.table_index, index);
+        }
+        metadata.add_lazy_columns(lazy_columns.clone());
+        Ok(lazy_columns)
+    }
+
+    fn build_lazy_filter_row_fetch(
+        &self,
+        input_plan: PhysicalPlan,
+        lazy_columns: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let metadata = self.metadata.read().clone();
+        let input_schema = input_plan.output_schema()?;
+        let row_id_col_index = metadata
+            .row_id_indexes()
+            .into_iter()
+            .find(|index| input_schema.has_field(&index.to_string()))
+            .ok_or_else(|| ErrorCode::Internal("Internal column _row_id is not found"))?;
+        let row_id_col_offset = input_schema.index_of(&row_id_col_index.to_string())?;
+
+        let lazy_columns = lazy_columns.into_iter().sorted().collect::<Vec<_>>();
+        let mut has_inner_column = false;
+        let fetched_fields = lazy_columns
+            .iter()
+            .map(|index| {
+                let col = metadata.column(*index);
+                if let ColumnEntry::BaseTableColumn(c) = col {
+                    if c.path_indices.is_some() {
+                        has_inner_column = true;
+                    }
+                }
+                DataField::new(&index.to_string(), col.data_type())
+            })
+            .collect();
+
+        let source = input_plan.try_find_single_data_source();
+        debug_assert!(source.is_some());
+        let source_info = source.cloned().unwrap();
+        let table_schema = source_info.source_info.schema();
+        let cols_to_fetch = Self::build_projection(
+            &metadata,
+            &table_schema,
+            lazy_columns.iter(),
+            has_inner_column,
+            true,
+            true,
+            false,
+        );
+
+        Ok(PhysicalPlan::RowFetch(RowFetch {
+            plan_id: 0,
+            input: Box::new(input_plan),
+            source: Box::new(source_info),
+            row_id_col_offset,
+            cols_to_fetch,
+            fetched_fields,
+            need_wrap_nullable: false,
             stat_info: Some(stat_info),
         }))
     }
@@ -83,6 +83,8 @@ pub struct HashJoin {
     pub probe_keys_rt: Vec<Option<(RemoteExpr<String>, IndexType)>>,
     // If enable bloom runtime filter
     pub enable_bloom_runtime_filter: bool,
+    // Under cluster, if the build keys of a shuffle join are sent to all nodes to filter the probe side.
+    pub enable_semi_join_reduction: bool,
     // Under cluster, mark if the join is broadcast join.
     pub broadcast: bool,
     // When left/right single join converted to inner join, record the original join type
@@ -423,10 +425,15 @@ impl PhysicalPlanBuilder {
                 probe_fields.extend(build_fields);
                 probe_fields
             }
-            JoinType::LeftSemi | JoinType::LeftAnti | JoinType::RightSemi | JoinType::RightAnti => {
-                let (result_fields, dropped_fields) = if join.join_type == JoinType::LeftSemi
-                    || join.join_type == JoinType::LeftAnti
-                {
+            JoinType::LeftSemi
+            | JoinType::LeftAnti
+            | JoinType::LeftAntiNullAware
+            | JoinType::RightSemi
+            | JoinType::RightAnti => {
+                let (result_fields, dropped_fields) = if matches!(
+                    join.join_type,
+                    JoinType::LeftSemi | JoinType::LeftAnti | JoinType::LeftAntiNullAware
+                ) {
                     (probe_fields, build_fields)
                 } else {
                     (build_fields, probe_fields)
@@ -542,6 +549,8 @@ impl PhysicalPlanBuilder {
                 s_expr,
             )
             .await?,
+            enable_semi_join_reduction: !is_broadcast
+                && adjust_semi_join_reduction(self.ctx.clone(), join, s_expr)?,
             build_side_cache_info,
         }))
     }
@@ -574,3 +583,32 @@ async fn adjust_bloom_runtime_filter(
     }
     Ok(false)
 }
+
+// Check if the build keys of the join are selective enough to filter the probe side across nodes.
+fn adjust_semi_join_reduction(
+    ctx: Arc<dyn TableContext>,
+    join: &Join,
+    s_expr: &SExpr,
+) -> Result<bool> {
+    if !ctx.get_settings().get_enable_semi_join_reduction()? {
+        return Ok(false);
+    }
+    if !matches!(
+        join.join_type,
+        JoinType::Inner | JoinType::Right | JoinType::RightSemi | JoinType::RightAnti
+    ) || join
+        .equi_conditions
+        .iter()
+        .any(|condition| condition.is_null_equal)
+    {
+        return Ok(false);
+    }
+    let join_cardinality = RelExpr::with_s_expr(s_expr)
+        .derive_cardinality()?
+        .cardinality;
+    let probe_cardinality = RelExpr::with_s_expr(s_expr.child(0)?)
+        .derive_cardinality()?
+        .cardinality;
+    // If the join keeps less than half of the probe side, the build keys are selective.
+    Ok(join_cardinality * 2.0 < probe_cardinality)
+}
@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_catalog::plan::DataSourceInfo;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
 use databend_common_pipeline_transforms::processors::sort::utils::ORDER_COL_NAME;
 use itertools::Itertools;
 
@@ -46,6 +48,21 @@ pub struct Sort {
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
     pub window_partition: Vec<IndexType>,
+    /// The order the input of the sort already has.
+    pub input_order: SortInputOrder,
+}
+
+/// The order of the input of a sort plan,
+/// derived from the cluster key of the table scanned by the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum SortInputOrder {
+    /// Nothing is known about the order of the input.
+    Unordered,
+    /// Each block of the input is sorted by the sort keys,
+    /// so the blocks only need to be merged.
+    BlockSorted,
+    /// The input is a single sorted stream, no sorting is needed.
+    Sorted,
 }
 
 impl Sort {
@@ -147,6 +164,8 @@ impl PhysicalPlanBuilder {
             input_plan
         };
 
+        let input_order = self.sort_input_order(&input_plan, sort);
+
         // 2. Build physical plan.
         Ok(PhysicalPlan::Sort(Sort {
             plan_id: 0,
@@ -166,6 +185,82 @@ impl PhysicalPlanBuilder {
             pre_projection,
             stat_info: Some(stat_info),
             window_partition,
+            input_order,
         }))
     }
+
+    /// Derive the order of the sort input from the cluster key of the scanned table.
+    ///
+    /// Fuse tables sort the rows of each block by the cluster key when writing,
+    /// so if the sort keys are a prefix of the cluster key, the blocks are already sorted,
+    /// as long as all of them were written under the current cluster key.
+    fn sort_input_order(&self, input: &PhysicalPlan, sort: &crate::plans::Sort) -> SortInputOrder {
+        if !sort.window_partition.is_empty() {
+            return SortInputOrder::Unordered;
+        }
+
+        // Filter and projection keep the order of the rows.
+        let mut input = input;
+        let scan = loop {
+            match input {
+                PhysicalPlan::Filter(plan) => input = &plan.input,
+                PhysicalPlan::EvalScalar(plan) => input = &plan.input,
+                PhysicalPlan::TableScan(plan) => break plan,
+                _ => return SortInputOrder::Unordered,
+            }
+        };
+
+        let (Some(table_index), DataSourceInfo::TableSource(table_info)) =
+            (scan.table_index, &scan.source.source_info)
+        else {
+            return SortInputOrder::Unordered;
+        };
+        let Some(cluster_key_id) = table_info.meta.default_cluster_key_id else {
+            return SortInputOrder::Unordered;
+        };
+        if scan
+            .source
+            .push_downs
+            .as_ref()
+            .is_some_and(|push_downs| push_downs.agg_index.is_some())
+        {
+            // The aggregating index is read instead of the blocks.
+            return SortInputOrder::Unordered;
+        }
+
+        let parts = &scan.source.parts.partitions;
+        if parts.is_empty()
+            || parts
+                .iter()
+                .any(|part| part.cluster_key_id() != Some(cluster_key_id))
+        {
+            return SortInputOrder::Unordered;
+        }
+
+        let table = self.metadata.read().table(table_index).table();
+        let cluster_keys = table.cluster_keys(self.ctx.clone());
+        if sort.items.len() > cluster_keys.len() {
+            return SortInputOrder::Unordered;
+        }
+        // Blocks are sorted by the cluster key in ascending order with nulls last.
+        let is_prefix = sort
+            .items
+            .iter()
+            .zip(cluster_keys.iter())
+            .all(|(item, key)| {
+                let RemoteExpr::ColumnRef { id, .. } = key else {
+                    return false;
+                };
+                item.asc && !item.nulls_first && scan.name_mapping.get(id) == Some(&item.index)
+            });
+        if !is_prefix {
+            return SortInputOrder::Unordered;
+        }
+
+        if parts.len() == 1 && sort.after_exchange.is_none() {
+            SortInputOrder::Sorted
+        } else {
+            SortInputOrder::BlockSorted
+        }
+    }
 }
@@ -471,6 +471,7 @@ impl PhysicalPlanBuilder {
             is_deterministic,
             prewhere: prewhere_info,
             limit: scan.limit,
+            filter_limit: scan.filter_limit,
             order_by: order_by.unwrap_or_default(),
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
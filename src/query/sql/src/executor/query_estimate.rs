@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_base::base::format_byte_size;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::schema::TableInfo;
+
+use crate::executor::physical_plans::CopyIntoTableSource;
+use crate::executor::PhysicalPlan;
+
+/// The resources a query is estimated to use before it runs, from the statistics of the
+/// tables after the partitions are pruned and the estimated cardinalities of the plan.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryEstimate {
+    pub scan_rows: u64,
+    pub scan_bytes: u64,
+    pub partitions_scanned: u64,
+    pub partitions_total: u64,
+    /// The memory held by the blocking operators, i.e. the hash tables of the joins and the
+    /// aggregations, and the sorts and windows without limit.
+    pub memory_bytes: u64,
+    /// Whether the scan statistics are exact rather than estimated.
+    pub is_exact: bool,
+}
+
+impl QueryEstimate {
+    pub fn estimate(plan: &PhysicalPlan) -> QueryEstimate {
+        let mut estimate = QueryEstimate {
+            is_exact: true,
+            ..Default::default()
+        };
+        estimate.collect_scans(plan);
+
+        let row_bytes = match estimate.scan_rows {
+            0 => 0.0,
+            rows => estimate.scan_bytes as f64 / rows as f64,
+        };
+        estimate.memory_bytes = (blocking_rows(plan) * row_bytes) as u64;
+        estimate
+    }
+
+    fn collect_scans(&mut self, plan: &PhysicalPlan) {
+        match plan {
+            PhysicalPlan::TableScan(scan) => {
+                let stats = &scan.source.statistics;
+                self.scan_rows += stats.read_rows as u64;
+                self.scan_bytes += stats.read_bytes as u64;
+                self.partitions_scanned += stats.partitions_scanned as u64;
+                self.partitions_total += stats.partitions_total as u64;
+                self.is_exact &= stats.is_exact;
+            }
+            // The source of COPY is not one of its children, it's either the query or the
+            // scan of the staged files.
+            PhysicalPlan::CopyIntoTable(copy) => match &copy.source {
+                CopyIntoTableSource::Query(input) | CopyIntoTableSource::Stage(input) => {
+                    self.collect_scans(input)
+                }
+            },
+            PhysicalPlan::DeleteSource(source) => {
+                self.collect_mutation_source(&source.table_info, source.parts.len())
+            }
+            PhysicalPlan::UpdateSource(source) => {
+                self.collect_mutation_source(&source.table_info, source.parts.len())
+            }
+            _ => {}
+        }
+        for child in plan.children() {
+            self.collect_scans(child);
+        }
+    }
+
+    /// The sources of DELETE and UPDATE read the pruned partitions of the table directly,
+    /// whose sizes are estimated from the statistics of the table.
+    fn collect_mutation_source(&mut self, table_info: &TableInfo, partitions: usize) {
+        let stats = &table_info.meta.statistics;
+        let total = stats.number_of_blocks.unwrap_or(partitions as u64).max(1);
+        let scanned = (partitions as u64).min(total);
+        self.scan_rows += stats.number_of_rows * scanned / total;
+        self.scan_bytes += stats.data_bytes * scanned / total;
+        self.partitions_scanned += scanned;
+        self.partitions_total += total;
+        self.is_exact = false;
+    }
+
+    /// Checks the estimate against the limits of the role.
+    pub fn check_limits(&self, role: &RoleInfo) -> Result<()> {
+        if let Some(max_scan_bytes) = role.max_estimated_scan_bytes {
+            if self.scan_bytes > max_scan_bytes {
+                return Err(ErrorCode::QueryEstimateExceedsLimit(format!(
+                    "the query is estimated to scan {}, which exceeds max_estimated_scan_bytes {} of role '{}'",
+                    format_byte_size(self.scan_bytes as usize),
+                    format_byte_size(max_scan_bytes as usize),
+                    role.name
+                )));
+            }
+        }
+        if let Some(max_memory_bytes) = role.max_estimated_memory_bytes {
+            if self.memory_bytes > max_memory_bytes {
+                return Err(ErrorCode::QueryEstimateExceedsLimit(format!(
+                    "the query is estimated to use {} of memory, which exceeds max_estimated_memory_bytes {} of role '{}'",
+                    format_byte_size(self.memory_bytes as usize),
+                    format_byte_size(max_memory_bytes as usize),
+                    role.name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for QueryEstimate {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(f, "estimated scan rows: {}", self.scan_rows)?;
+        writeln!(
+            f,
+            "estimated scan size: {}",
+            format_byte_size(self.scan_bytes as usize)
+        )?;
+        writeln!(
+            f,
+            "partitions scanned: {} of {}",
+            self.partitions_scanned, self.partitions_total
+        )?;
+        writeln!(
+            f,
+            "estimated memory: {}",
+            format_byte_size(self.memory_bytes as usize)
+        )?;
+        write!(f, "exact statistics: {}", self.is_exact)
+    }
+}
+
+/// The rows held in memory by the blocking operators of the plan.
+fn blocking_rows(plan: &PhysicalPlan) -> f64 {
+    let rows = match plan {
+        PhysicalPlan::HashJoin(join) => estimated_rows(&join.build),
+        PhysicalPlan::AggregateFinal(agg) if !agg.group_by.is_empty() => estimated_rows(plan),
+        PhysicalPlan::Sort(sort) => match sort.limit {
+            Some(limit) => estimated_rows(&sort.input).min(limit as f64),
+            None => estimated_rows(&sort.input),
+        },
+        PhysicalPlan::Window(window) => estimated_rows(&window.input),
+        PhysicalPlan::CopyIntoTable(copy) => match &copy.source {
+            CopyIntoTableSource::Query(input) | CopyIntoTableSource::Stage(input) => {
+                return blocking_rows(input);
+            }
+        },
+        _ => 0.0,
+    };
+    rows + plan.children().map(blocking_rows).sum::<f64>()
+}
+
+/// The estimated output rows of the plan, the operators without the estimate pass through
+/// the rows of their input.
+fn estimated_rows(plan: &PhysicalPlan) -> f64 {
+    let stat_info = match plan {
+        PhysicalPlan::TableScan(plan) => &plan.stat_info,
+        PhysicalPlan::Filter(plan) => &plan.stat_info,
+        PhysicalPlan::EvalScalar(plan) => &plan.stat_info,
+        PhysicalPlan::AggregateExpand(plan) => &plan.stat_info,
+        PhysicalPlan::AggregatePartial(plan) => &plan.stat_info,
+        PhysicalPlan::AggregateFinal(plan) => &plan.stat_info,
+        PhysicalPlan::Sort(plan) => &plan.stat_info,
+        PhysicalPlan::Limit(plan) => &plan.stat_info,
+        PhysicalPlan::RowFetch(plan) => &plan.stat_info,
+        PhysicalPlan::HashJoin(plan) => &plan.stat_info,
+        PhysicalPlan::RangeJoin(plan) => &plan.stat_info,
+        PhysicalPlan::UnionAll(plan) => &plan.stat_info,
+        PhysicalPlan::ProjectSet(plan) => &plan.stat_info,
+        PhysicalPlan::Udf(plan) => &plan.stat_info,
+        PhysicalPlan::AsyncFunction(plan) => &plan.stat_info,
+        _ => &None,
+    };
+    match stat_info {
+        Some(info) => info.estimated_rows,
+        None => plan.children().map(estimated_rows).sum(),
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_ast::parser::parse_expr;
@@ -28,6 +29,7 @@ use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::StreamTablePart;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::RemoteExpr;
@@ -172,12 +174,39 @@ impl ToReadDataSourcePlan for dyn Table {
             let table_meta = &table_info.meta;
             let tenant = ctx.get_tenant();
 
-            if let Some(column_mask_policy) = &table_meta.column_mask_policy {
+            let mut column_mask_policy = table_meta.column_mask_policy.clone().unwrap_or_default();
+            // The masking policy of a tag applies to the columns carrying the tag,
+            // unless the column has a masking policy set explicitly.
+            let mut tag_mask_columns = HashSet::new();
+            for (column, tags) in &table_meta.column_tags {
+                if column_mask_policy.contains_key(column) {
+                    continue;
+                }
+                for tag_name in tags.keys() {
+                    let tag = match UserApiProvider::instance().get_tag(&tenant, tag_name).await {
+                        Ok(tag) => tag,
+                        // A dropped tag doesn't mask the columns anymore.
+                        Err(e) if e.code() == ErrorCode::UNKNOWN_TAG => continue,
+                        Err(e) => return Err(e),
+                    };
+                    if let Some(mask_policy) = tag.masking_policy {
+                        column_mask_policy.insert(column.clone(), mask_policy);
+                        tag_mask_columns.insert(column.clone());
+                        break;
+                    }
+                }
+            }
+
+            if !column_mask_policy.is_empty() {
                 let license_manager = get_license_manager();
                 let ret = license_manager
                     .manager
                     .check_enterprise_enabled(ctx.get_license_key(), DataMask);
-                if ret.is_err() {
+                if let Err(e) = ret {
+                    // The columns masked by tags are never read as is.
+                    if !tag_mask_columns.is_empty() {
+                        return Err(e);
+                    }
                     None
                 } else {
                     let mut mask_policy_map = BTreeMap::new();
@@ -193,10 +222,25 @@ impl ToReadDataSourcePlan for dyn Table {
                                 "build physical plan - checking data mask policies - getting data masks, time used {:?}",
                                 start.elapsed())
                             );
-                            if let Ok(policy) = handler
+                            let policy = handler
                                 .get_data_mask(meta_api.clone(), &tenant, mask_policy.clone())
-                                .await
-                            {
+                                .await;
+                            // The columns masked by tags are never read as is, they fail
+                            // if the policy is missing or its type doesn't match the column.
+                            if tag_mask_columns.contains(field.name()) {
+                                let policy = policy.as_ref().map_err(Clone::clone)?;
+                                let policy_data_type = policy.args[0].1.to_lowercase();
+                                let data_type = field.data_type().to_string().to_lowercase();
+                                if policy_data_type != data_type {
+                                    return Err(ErrorCode::UnmatchColumnDataType(format!(
+                                        "Column '{}' data type {} does not match to the mask policy type {} of its tag",
+                                        field.name(),
+                                        data_type,
+                                        policy_data_type,
+                                    )));
+                                }
+                            }
+                            if let Ok(policy) = policy {
                                 let args = &policy.args;
                                 let mut aliases = Vec::with_capacity(args.len());
                                 for (i, (arg_name, arg_type)) in args.iter().enumerate() {
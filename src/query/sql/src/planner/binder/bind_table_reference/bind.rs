@@ -67,6 +67,11 @@ impl Binder {
                 alias,
             } => self.bind_location(bind_context, location, options, alias),
             TableReference::Join { join, .. } => self.bind_join(bind_context, join),
+            TableReference::JsonTable {
+                span,
+                json_table,
+                alias,
+            } => self.bind_json_table(bind_context, span, json_table, alias),
         }
     }
 }
@@ -0,0 +1,291 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall as ASTFunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::JsonTable;
+use databend_common_ast::ast::JsonTableBehavior;
+use databend_common_ast::ast::JsonTableColumn;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::binder::Binder;
+use crate::optimizer::SExpr;
+use crate::BindContext;
+
+impl Binder {
+    /// Bind `JSON_TABLE` as a lateral subquery, which flattens the matches of the row path,
+    /// and the matches of each `NESTED PATH` with an outer lateral flatten of the parent row.
+    pub(crate) fn bind_json_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        json_table: &JsonTable,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let subquery = json_table_query(*span, json_table)?;
+        self.bind_subquery(bind_context, true, &subquery, alias)
+    }
+}
+
+fn json_table_query(span: Span, json_table: &JsonTable) -> Result<Query> {
+    let mut select_list = vec![];
+    let mut from = vec![flatten(
+        span,
+        false,
+        json_path_query_array(span, (*json_table.input).clone(), &json_table.path),
+        0,
+    )];
+    bind_json_table_columns(span, &json_table.columns, 0, &mut select_list, &mut from)?;
+
+    let select_stmt = SelectStmt {
+        span,
+        hints: None,
+        distinct: false,
+        top_n: None,
+        select_list,
+        from,
+        selection: None,
+        group_by: None,
+        having: None,
+        window_list: None,
+        qualify: None,
+    };
+    Ok(Query {
+        span,
+        with: None,
+        body: SetExpr::Select(Box::new(select_stmt)),
+        order_by: vec![],
+        limit: vec![],
+        offset: None,
+        ignore_result: false,
+    })
+}
+
+fn bind_json_table_columns(
+    span: Span,
+    columns: &[JsonTableColumn],
+    level: usize,
+    select_list: &mut Vec<SelectTarget>,
+    from: &mut Vec<TableReference>,
+) -> Result<()> {
+    let mut has_nested = false;
+    for column in columns {
+        match column {
+            JsonTableColumn::Ordinality { name } => {
+                let expr = Expr::BinaryOp {
+                    span,
+                    op: BinaryOperator::Plus,
+                    left: Box::new(flatten_column(span, level, "index")),
+                    right: Box::new(Expr::Literal {
+                        span,
+                        value: Literal::UInt64(1),
+                    }),
+                };
+                select_list.push(SelectTarget::AliasedExpr {
+                    expr: Box::new(expr),
+                    alias: Some(name.clone()),
+                });
+            }
+            JsonTableColumn::Path {
+                name,
+                data_type,
+                path,
+                on_empty,
+                on_error,
+            } => {
+                let expr = json_table_column_expr(
+                    span,
+                    level,
+                    data_type,
+                    path,
+                    on_empty.as_ref().unwrap_or(&JsonTableBehavior::Null),
+                    on_error.as_ref().unwrap_or(&JsonTableBehavior::Null),
+                );
+                select_list.push(SelectTarget::AliasedExpr {
+                    expr: Box::new(expr),
+                    alias: Some(name.clone()),
+                });
+            }
+            JsonTableColumn::Nested { path, columns } => {
+                if has_nested {
+                    return Err(ErrorCode::SemanticError(
+                        "JSON_TABLE doesn't support sibling NESTED PATH columns yet",
+                    )
+                    .set_span(span));
+                }
+                has_nested = true;
+
+                let nested_level = from.len();
+                let input = json_path_query_array(span, flatten_column(span, level, "value"), path);
+                from.push(flatten(span, true, input, nested_level));
+                bind_json_table_columns(span, columns, nested_level, select_list, from)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// CASE WHEN <value> IS NULL THEN <on empty> ELSE <cast value with on error> END
+fn json_table_column_expr(
+    span: Span,
+    level: usize,
+    data_type: &TypeName,
+    path: &str,
+    on_empty: &JsonTableBehavior,
+    on_error: &JsonTableBehavior,
+) -> Expr {
+    let value = function_call(span, "json_path_query_first", vec![
+        flatten_column(span, level, "value"),
+        string_literal(span, path),
+    ]);
+    let try_cast = Expr::TryCast {
+        span,
+        expr: Box::new(value.clone()),
+        target_type: data_type.clone(),
+    };
+    let cast_value = match on_error {
+        JsonTableBehavior::Null => try_cast,
+        JsonTableBehavior::Error => cast(span, value.clone(), data_type.clone()),
+        JsonTableBehavior::Default(default) => Expr::Case {
+            span,
+            operand: None,
+            conditions: vec![Expr::IsNull {
+                span,
+                expr: Box::new(try_cast.clone()),
+                not: false,
+            }],
+            results: vec![cast(span, default.clone(), data_type.clone())],
+            else_result: Some(Box::new(try_cast)),
+        },
+    };
+
+    let empty_value = match on_empty {
+        // A missing value is cast to NULL unless the errors have a default.
+        JsonTableBehavior::Null if !matches!(on_error, JsonTableBehavior::Default(_)) => {
+            return cast_value;
+        }
+        JsonTableBehavior::Null => Expr::Literal {
+            span,
+            value: Literal::Null,
+        },
+        // Casting NULL to a not null type raises the error.
+        JsonTableBehavior::Error => {
+            let not_null_type = match data_type {
+                TypeName::Nullable(ty) => (**ty).clone().wrap_not_null(),
+                ty => ty.clone().wrap_not_null(),
+            };
+            cast(span, value.clone(), not_null_type)
+        }
+        JsonTableBehavior::Default(default) => cast(span, default.clone(), data_type.clone()),
+    };
+    Expr::Case {
+        span,
+        operand: None,
+        conditions: vec![Expr::IsNull {
+            span,
+            expr: Box::new(value),
+            not: false,
+        }],
+        results: vec![empty_value],
+        else_result: Some(Box::new(cast_value)),
+    }
+}
+
+fn flatten(span: Span, lateral: bool, input: Expr, level: usize) -> TableReference {
+    let mut named_params = vec![(Identifier::from_name(span, "input"), input)];
+    if lateral {
+        // Keep the parent row if the nested path matches nothing.
+        named_params.push((Identifier::from_name(span, "outer"), Expr::Literal {
+            span,
+            value: Literal::Boolean(true),
+        }));
+    }
+    TableReference::TableFunction {
+        span,
+        lateral,
+        name: Identifier::from_name(span, "flatten"),
+        params: vec![],
+        named_params,
+        alias: Some(TableAlias {
+            name: Identifier::from_name(span, flatten_name(level)),
+            columns: vec![],
+        }),
+    }
+}
+
+fn flatten_name(level: usize) -> String {
+    format!("_json_table_{level}")
+}
+
+fn flatten_column(span: Span, level: usize, column: &str) -> Expr {
+    Expr::ColumnRef {
+        span,
+        column: ColumnRef {
+            database: None,
+            table: Some(Identifier::from_name(span, flatten_name(level))),
+            column: ColumnID::Name(Identifier::from_name(span, column)),
+        },
+    }
+}
+
+fn json_path_query_array(span: Span, input: Expr, path: &str) -> Expr {
+    function_call(span, "json_path_query_array", vec![
+        input,
+        string_literal(span, path),
+    ])
+}
+
+fn function_call(span: Span, name: &str, args: Vec<Expr>) -> Expr {
+    Expr::FunctionCall {
+        span,
+        func: ASTFunctionCall {
+            distinct: false,
+            name: Identifier::from_name(span, name),
+            args,
+            params: vec![],
+            window: None,
+            lambda: None,
+        },
+    }
+}
+
+fn cast(span: Span, expr: Expr, target_type: TypeName) -> Expr {
+    Expr::Cast {
+        span,
+        expr: Box::new(expr),
+        target_type,
+        pg_style: false,
+    }
+}
+
+fn string_literal(span: Span, value: &str) -> Expr {
+    Expr::Literal {
+        span,
+        value: Literal::String(value.to_string()),
+    }
+}
@@ -41,7 +41,10 @@ impl Binder {
                     .clone()
                     .unwrap_or_else(|| Box::new(BindContext::new())),
             );
-            self.bind_query(&mut new_bind_context, subquery)?
+            let (s_expr, result_bind_context) = self.bind_query(&mut new_bind_context, subquery)?;
+            let s_expr =
+                self.track_repeated_subquery(bind_context, subquery, s_expr, &result_bind_context)?;
+            (s_expr, result_bind_context)
         };
 
         if let Some(alias) = alias {
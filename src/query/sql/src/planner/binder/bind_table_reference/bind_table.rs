@@ -236,6 +236,9 @@ impl Binder {
                 }
             }
             _ => {
+                if self.ctx.get_settings().get_enable_metadata_prefetch()? {
+                    table_meta.prefetch_metadata(self.ctx.clone());
+                }
                 let table_index = self.metadata.write().add_table(
                     catalog,
                     database.clone(),
@@ -14,6 +14,7 @@
 
 mod bind;
 mod bind_join;
+mod bind_json_table;
 mod bind_location;
 mod bind_subquery;
 mod bind_table;
@@ -20,6 +20,7 @@ use std::time::Instant;
 use chrono_tz::Tz;
 use databend_common_ast::ast::format_statement;
 use databend_common_ast::ast::Hint;
+use databend_common_ast::ast::HintItem;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::parser::parse_sql;
@@ -64,6 +65,7 @@ use crate::plans::ShowConnectionsPlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowRolesPlan;
 use crate::plans::UseDatabasePlan;
+use crate::plans::VacuumSystemHistoryPlan;
 use crate::plans::Visitor;
 use crate::BindContext;
 use crate::ColumnBinding;
@@ -190,6 +192,11 @@ impl<'a> Binder {
                 Plan::ExplainAnalyze { plan: Box::new(plan) }
             }
 
+            Statement::StatementWithSettings { settings, stmt } => {
+                self.bind_statement_settings(bind_context, settings)?;
+                self.bind_statement(bind_context, stmt).await?
+            }
+
             Statement::ShowFunctions { show_options } => {
                 self.bind_show_functions(bind_context, show_options).await?
             }
@@ -226,6 +233,7 @@ impl<'a> Binder {
             Statement::ShowSettings { show_options } => self.bind_show_settings(bind_context, show_options).await?,
             Statement::ShowIndexes { show_options } => self.bind_show_indexes(bind_context, show_options).await?,
             Statement::ShowLocks(stmt) => self.bind_show_locks(bind_context, stmt).await?,
+            Statement::ShowWarnings => Plan::ShowWarnings,
             // Catalogs
             Statement::ShowCatalogs(stmt) => self.bind_show_catalogs(bind_context, stmt).await?,
             Statement::ShowCreateCatalog(stmt) => self.bind_show_create_catalogs(stmt).await?,
@@ -239,6 +247,8 @@ impl<'a> Binder {
             Statement::DropDatabase(stmt) => self.bind_drop_database(stmt).await?,
             Statement::UndropDatabase(stmt) => self.bind_undrop_database(stmt).await?,
             Statement::AlterDatabase(stmt) => self.bind_alter_database(stmt).await?,
+            Statement::ExportDatabase(stmt) => self.bind_export_database(stmt).await?,
+            Statement::ImportDatabase(stmt) => self.bind_import_database(stmt).await?,
             Statement::UseDatabase { database } => {
                 let database = normalize_identifier(database, &self.name_resolution_ctx).name;
                 Plan::UseDatabase(Box::new(UseDatabasePlan {
@@ -268,7 +278,11 @@ impl<'a> Binder {
             Statement::VacuumTable(stmt) => self.bind_vacuum_table(bind_context, stmt).await?,
             Statement::VacuumDropTable(stmt) => self.bind_vacuum_drop_table(bind_context, stmt).await?,
             Statement::VacuumTemporaryFiles(stmt) => self.bind_vacuum_temporary_files(bind_context, stmt).await?,
+            Statement::VacuumSystemHistory(stmt) => Plan::VacuumSystemHistory(Box::new(VacuumSystemHistoryPlan { retain: stmt.retain })),
             Statement::AnalyzeTable(stmt) => self.bind_analyze_table(stmt).await?,
+            Statement::VerifyTable(stmt) => self.bind_verify_table(stmt).await?,
+            Statement::RefreshTableStatistics(stmt) => self.bind_refresh_table_statistics(stmt).await?,
+            Statement::RestoreTable(stmt) => self.bind_restore_table(bind_context, stmt).await?,
             Statement::ExistsTable(stmt) => self.bind_exists_table(stmt).await?,
             // Dictionaries
             Statement::CreateDictionary(stmt) => todo!(),
@@ -328,6 +342,7 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 role_name: role_name.to_string(),
             })),
+            Statement::AlterRole(stmt) => self.bind_alter_role(stmt)?,
 
             // Stages
             Statement::ShowStages => self.bind_rewrite_to_query(bind_context, "SELECT name, stage_type, number_of_files, creator, created_on, comment FROM system.stages ORDER BY name", RewriteKind::ShowStages).await?,
@@ -416,10 +431,15 @@ impl<'a> Binder {
                         "File format {name} is reserved"
                     )));
                 }
+                let file_format_params = FileFormatParams::try_from_reader(
+                    FileFormatOptionsReader::from_ast(file_format_options),
+                    false,
+                )?;
+                self.ctx.check_file_format_params(&file_format_params)?;
                 Plan::CreateFileFormat(Box::new(CreateFileFormatPlan {
                     create_option: create_option.clone().into(),
                     name: name.clone(),
-                    file_format_params: FileFormatParams::try_from_reader( FileFormatOptionsReader::from_ast(file_format_options), false)?,
+                    file_format_params,
                 }))
             }
             Statement::DropFileFormat {
@@ -481,6 +501,7 @@ impl<'a> Binder {
                 self.bind_kill_stmt(bind_context, kill_target, object_id.as_str())
                     .await?
             }
+            Statement::KillWhere(stmt) => self.bind_kill_where_stmt(bind_context, stmt).await?,
 
             // share statements
             Statement::CreateShareEndpoint(stmt) => {
@@ -556,6 +577,9 @@ impl<'a> Binder {
                 self.bind_desc_password_policy(stmt).await?
             }
             Statement::ShowPasswordPolicies{ show_options } => self.bind_show_password_policies(bind_context, show_options).await?,
+            Statement::CreateTag(stmt) => self.bind_create_tag(stmt).await?,
+            Statement::AlterTag(stmt) => self.bind_alter_tag(stmt).await?,
+            Statement::DropTag(stmt) => self.bind_drop_tag(stmt).await?,
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
@@ -621,7 +645,7 @@ impl<'a> Binder {
             Statement::SetPriority {priority, object_id} => {
                 self.bind_set_priority(priority, object_id).await?
             },
-            Statement::System(stmt) => self.bind_system(stmt).await?,
+            Statement::System(stmt) => self.bind_system(bind_context, stmt).await?,
         };
 
         match plan.kind() {
@@ -651,6 +675,45 @@ impl<'a> Binder {
         bind_context: &mut BindContext,
         hints: &Hint,
     ) -> Result<()> {
+        let hint_settings = self.resolve_setting_values(bind_context, &hints.hints_list)?;
+        self.ctx.get_settings().set_hint_settings(&hint_settings)
+    }
+
+    /// Apply the settings of `SETTINGS (<setting> = <value>, ...) <statement>` to the current query,
+    /// unlike the hints, the unknown settings are rejected.
+    pub(crate) fn bind_statement_settings(
+        &mut self,
+        bind_context: &mut BindContext,
+        settings: &[HintItem],
+    ) -> Result<()> {
+        let query_settings = self.resolve_setting_values(bind_context, settings)?;
+        for setting in settings {
+            let name = &setting.name.name;
+            if !self.ctx.get_settings().has_setting(name)? {
+                return Err(ErrorCode::UnknownVariable(format!(
+                    "Unknown variable: {:?}",
+                    name
+                )));
+            }
+            if !query_settings.contains_key(name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The value of setting {name} must be a constant"
+                )));
+            }
+        }
+        if query_settings.len() != settings.len() {
+            return Err(ErrorCode::SemanticError(
+                "The same setting is specified more than once".to_string(),
+            ));
+        }
+        self.ctx.get_settings().set_hint_settings(&query_settings)
+    }
+
+    fn resolve_setting_values(
+        &mut self,
+        bind_context: &mut BindContext,
+        settings: &[HintItem],
+    ) -> Result<HashMap<String, String>> {
         let mut type_checker = TypeChecker::try_create(
             bind_context,
             self.ctx.clone(),
@@ -659,10 +722,10 @@ impl<'a> Binder {
             &[],
             false,
         )?;
-        let mut hint_settings: HashMap<String, String> = HashMap::new();
-        for hint in &hints.hints_list {
-            let variable = &hint.name.name;
-            let (scalar, _) = *type_checker.resolve(&hint.expr)?;
+        let mut setting_values: HashMap<String, String> = HashMap::new();
+        for setting in settings {
+            let variable = &setting.name.name;
+            let (scalar, _) = *type_checker.resolve(&setting.expr)?;
 
             let scalar = wrap_cast(&scalar, &DataType::String);
             let expr = scalar.as_expr()?;
@@ -678,15 +741,17 @@ impl<'a> Binder {
                             ErrorCode::InvalidTimezone(format!("Invalid Timezone: {:?}", value))
                         })?;
                     }
-                    hint_settings.entry(variable.to_string()).or_insert(value);
+                    setting_values.entry(variable.to_string()).or_insert(value);
                 }
                 _ => {
-                    warn!("fold hints {:?} failed. value must be constant value", hint);
+                    warn!(
+                        "fold hints {:?} failed. value must be constant value",
+                        setting
+                    );
                 }
             }
         }
-
-        self.ctx.get_settings().set_batch_settings(&hint_settings)
+        Ok(setting_values)
     }
 
     // After the materialized cte was bound, add it to `m_cte_bound_ctx`
@@ -14,12 +14,17 @@
 
 use databend_common_ast::ast::CopyIntoLocationSource;
 use databend_common_ast::ast::CopyIntoLocationStmt;
+use databend_common_ast::ast::CopyIntoLocationTarget;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
+use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::storage::StorageHttpConfig;
+use databend_common_meta_app::storage::StorageParams;
+use url::Url;
 
 use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::Binder;
@@ -65,7 +70,12 @@ impl<'a> Binder {
             }
         }?;
 
-        let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), &stmt.dst).await?;
+        let (mut stage_info, path) = match &stmt.dst {
+            CopyIntoLocationTarget::Location(location) => {
+                resolve_file_location(self.ctx.as_ref(), location).await?
+            }
+            CopyIntoLocationTarget::Urls(urls) => resolve_urls_location(urls)?,
+        };
         self.apply_copy_into_location_options(stmt, &mut stage_info)
             .await?;
 
@@ -94,8 +104,102 @@ impl<'a> Binder {
             }
             stage.copy_options.single = stmt.single;
             stage.copy_options.detailed_output = stmt.detailed_output;
+            if let Some(template) = &stmt.file_name_template {
+                check_file_name_template(template, stmt.single)?;
+                stage.copy_options.file_name_template = Some(template.clone());
+            }
         }
 
         Ok(())
     }
 }
+
+/// The placeholders of the template are `{seq}`, `{timestamp}` and `{uuid}`,
+/// `{seq}` is required unless the data is unloaded to a single file.
+fn check_file_name_template(template: &str, single: bool) -> Result<()> {
+    let invalid = |reason: &str| {
+        Err(ErrorCode::BadArguments(format!(
+            "invalid FILE_NAME_TEMPLATE '{}': {}",
+            template, reason
+        )))
+    };
+
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            return invalid("unclosed '{'");
+        };
+        let placeholder = &rest[start..start + end + 1];
+        if !matches!(placeholder, "{seq}" | "{timestamp}" | "{uuid}") {
+            return invalid(&format!(
+                "unknown placeholder {}, expect {{seq}}, {{timestamp}} or {{uuid}}",
+                placeholder
+            ));
+        }
+        rest = &rest[start + end + 1..];
+    }
+    if template.is_empty() || template.contains('/') {
+        return invalid("the file name can't be empty or contain '/'");
+    }
+    if !single && !template.contains("{seq}") {
+        return invalid("{seq} is required to name multiple files, or set SINGLE = TRUE");
+    }
+    Ok(())
+}
+
+/// The files are uploaded to the urls in order by http storage.
+fn resolve_urls_location(urls: &[String]) -> Result<(StageInfo, String)> {
+    for url in urls {
+        let parsed = Url::parse(url)
+            .map_err(|e| ErrorCode::BadArguments(format!("invalid url {}: {}", url, e)))?;
+        match parsed.scheme() {
+            "https" => {}
+            "http" if GlobalConfig::instance().storage.allow_insecure => {}
+            "http" => {
+                return Err(ErrorCode::StorageInsecure(
+                    "copy into insecure storage is not allowed",
+                ));
+            }
+            scheme => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "only http or https urls can be copied into, but got {}",
+                    scheme
+                )));
+            }
+        }
+        if !is_unload_url_allowed(&parsed) {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Unallowed url, the host of '{}' is not in unload_url_allow_list",
+                parsed.origin().ascii_serialization()
+            )));
+        }
+    }
+    let storage_params = StorageParams::Http(StorageHttpConfig {
+        endpoint_url: "".to_string(),
+        paths: urls.to_vec(),
+    });
+    Ok((
+        StageInfo::new_external_stage(storage_params, true),
+        "/".to_string(),
+    ))
+}
+
+/// The host of the url is allowed if it's the host or a subdomain of the host of any url
+/// in `unload_url_allow_list`, nothing is allowed if the list is empty.
+fn is_unload_url_allowed(url: &Url) -> bool {
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    GlobalConfig::instance()
+        .query
+        .unload_url_allow_list
+        .iter()
+        .filter_map(|allow_url| Url::parse(allow_url).ok())
+        .filter_map(|allow_url| allow_url.host_str().map(|host| host.to_string()))
+        .any(|allowed| {
+            host == allowed
+                || host
+                    .strip_suffix(allowed.as_str())
+                    .is_some_and(|prefix| prefix.ends_with('.'))
+        })
+}
@@ -250,6 +250,7 @@ impl<'a> Binder {
                 FileFormatOptionsReader::from_map(options.clone()),
                 false,
             )?;
+            self.ctx.check_file_format_params(&params)?;
             if let FileFormatParams::Csv(ref mut fmt) = &mut params {
                 // TODO: remove this after 1. the old server is no longer supported 2. Driver add the option "EmptyFieldAs=FieldDefault"
                 // CSV attachment is mainly used in Drivers for insert.
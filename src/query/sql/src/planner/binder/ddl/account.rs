@@ -15,6 +15,7 @@
 use chrono::Utc;
 use databend_common_ast::ast::AccountMgrLevel;
 use databend_common_ast::ast::AccountMgrSource;
+use databend_common_ast::ast::AlterRoleStmt;
 use databend_common_ast::ast::AlterUserStmt;
 use databend_common_ast::ast::CreateUserStmt;
 use databend_common_ast::ast::GrantObjectName;
@@ -34,6 +35,7 @@ use databend_common_users::UserApiProvider;
 
 use crate::binder::show::get_show_options;
 use crate::binder::util::illegal_ident_name;
+use crate::plans::AlterRolePlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::GrantPrivilegePlan;
@@ -178,6 +180,7 @@ impl Binder {
             }
             AccountMgrLevel::UDF(udf) => Ok(GrantObject::UDF(udf.clone())),
             AccountMgrLevel::Stage(stage) => Ok(GrantObject::Stage(stage.clone())),
+            AccountMgrLevel::Warehouse(warehouse) => Ok(GrantObject::Warehouse(warehouse.clone())),
         }
     }
 
@@ -229,6 +232,9 @@ impl Binder {
             }
             AccountMgrLevel::UDF(udf) => Ok(vec![GrantObject::UDF(udf.clone())]),
             AccountMgrLevel::Stage(stage) => Ok(vec![GrantObject::Stage(stage.clone())]),
+            AccountMgrLevel::Warehouse(warehouse) => {
+                Ok(vec![GrantObject::Warehouse(warehouse.clone())])
+            }
         }
     }
 
@@ -357,6 +363,32 @@ impl Binder {
         Ok(Plan::AlterUser(Box::new(plan)))
     }
 
+    pub(in crate::planner::binder) fn bind_alter_role(
+        &mut self,
+        stmt: &AlterRoleStmt,
+    ) -> Result<Plan> {
+        let AlterRoleStmt { role_name, options } = stmt;
+
+        let mut plan = AlterRolePlan {
+            role_name: role_name.clone(),
+            max_estimated_scan_bytes: None,
+            max_estimated_memory_bytes: None,
+        };
+        for (name, value) in options {
+            match name.as_str() {
+                "max_estimated_scan_bytes" => plan.max_estimated_scan_bytes = Some(*value),
+                "max_estimated_memory_bytes" => plan.max_estimated_memory_bytes = Some(*value),
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Unknown role option {name}, expected max_estimated_scan_bytes or max_estimated_memory_bytes"
+                    )));
+                }
+            }
+        }
+
+        Ok(Plan::AlterRole(Box::new(plan)))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_show_account_grants(
         &mut self,
@@ -422,6 +454,9 @@ impl Binder {
             GrantObjectName::Stage(name) => {
                 format!("SELECT * FROM show_grants('stage', '{}')", name)
             }
+            GrantObjectName::Warehouse(name) => {
+                format!("SELECT * FROM show_grants('warehouse', '{}')", name)
+            }
         };
 
         let (show_limit, limit_str) = get_show_options(show_option, Some("name".to_string()));
@@ -20,6 +20,8 @@ use databend_common_ast::ast::CreateDatabaseStmt;
 use databend_common_ast::ast::DatabaseEngine;
 use databend_common_ast::ast::DatabaseRef;
 use databend_common_ast::ast::DropDatabaseStmt;
+use databend_common_ast::ast::ExportDatabaseStmt;
+use databend_common_ast::ast::ImportDatabaseStmt;
 use databend_common_ast::ast::SQLProperty;
 use databend_common_ast::ast::ShowCreateDatabaseStmt;
 use databend_common_ast::ast::ShowDatabasesStmt;
@@ -33,16 +35,21 @@ use databend_common_expression::DataSchemaRefExt;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::share::share_name_ident::ShareNameIdent;
 use databend_common_meta_app::share::share_name_ident::ShareNameIdentRaw;
+use databend_storages_common_table_meta::table::OPT_KEY_READ_ONLY;
 use log::debug;
 
+use crate::binder::resolve_stage_location;
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
+use crate::plans::ExportDatabasePlan;
+use crate::plans::ImportDatabasePlan;
 use crate::plans::Plan;
 use crate::plans::RenameDatabaseEntity;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RewriteKind;
+use crate::plans::SetDatabaseOptionsPlan;
 use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::UndropDatabasePlan;
 use crate::BindContext;
@@ -154,9 +161,75 @@ impl Binder {
                     entities: vec![entry],
                 })))
             }
+            AlterDatabaseAction::SetReadOnly { read_only } => {
+                Ok(Plan::SetDatabaseOptions(Box::new(SetDatabaseOptionsPlan {
+                    tenant,
+                    if_exists: *if_exists,
+                    catalog,
+                    database,
+                    set_options: BTreeMap::from([(
+                        OPT_KEY_READ_ONLY.to_string(),
+                        read_only.to_string(),
+                    )]),
+                })))
+            }
         }
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_export_database(
+        &self,
+        stmt: &ExportDatabaseStmt,
+    ) -> Result<Plan> {
+        let ExportDatabaseStmt {
+            catalog,
+            database,
+            location,
+        } = stmt;
+
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let (stage, path) = resolve_stage_location(self.ctx.as_ref(), location).await?;
+
+        Ok(Plan::ExportDatabase(Box::new(ExportDatabasePlan {
+            catalog,
+            database,
+            location: location.clone(),
+            stage,
+            path,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_import_database(
+        &self,
+        stmt: &ImportDatabaseStmt,
+    ) -> Result<Plan> {
+        let ImportDatabaseStmt {
+            catalog,
+            database,
+            location,
+        } = stmt;
+
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let (stage, path) = resolve_stage_location(self.ctx.as_ref(), location).await?;
+
+        Ok(Plan::ImportDatabase(Box::new(ImportDatabasePlan {
+            catalog,
+            database,
+            location: location.clone(),
+            stage,
+            path,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_database(
         &self,
@@ -132,12 +132,22 @@ impl Binder {
         bind_context: &mut BindContext,
         metadata: &MetadataRef,
     ) -> Result<()> {
-        let catalog = self.ctx.get_current_catalog();
-        let database = self.ctx.get_current_database();
         let tables = metadata.read().tables().to_vec();
 
+        let mut bound_tables = HashSet::new();
         for table_entry in tables {
             let table = table_entry.table();
+            // The same table may be scanned by several branches of `UNION ALL` or several
+            // consumers of a CTE, the indexes only need to be bound once for all of them.
+            let full_table_name = format!(
+                "{}.{}.{}",
+                table_entry.catalog(),
+                table_entry.database(),
+                table_entry.name()
+            );
+            if !bound_tables.insert(full_table_name.clone()) {
+                continue;
+            }
             // Avoid death loop
             let mut agg_indexes = vec![];
             if self.ctx.get_can_scan_from_agg_index()
@@ -158,7 +168,7 @@ impl Binder {
                     let indexes = self
                         .resolve_table_indexes(
                             &self.ctx.get_tenant(),
-                            catalog.as_str(),
+                            table_entry.catalog(),
                             table.get_id(),
                         )
                         .await?;
@@ -180,9 +190,6 @@ impl Binder {
             }
 
             if !agg_indexes.is_empty() {
-                // Should use bound table id.
-                let table_name = table.name();
-                let full_table_name = format!("{catalog}.{database}.{table_name}");
                 metadata
                     .write()
                     .add_agg_indexes(full_table_name, agg_indexes);
@@ -30,6 +30,7 @@ mod share;
 mod stage;
 mod stream;
 mod table;
+mod tag;
 mod task;
 mod view;
 mod virtual_column;
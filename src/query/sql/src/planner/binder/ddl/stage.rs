@@ -131,7 +131,9 @@ impl Binder {
         if let Some(name) = reader.options.get("format_name") {
             self.ctx.get_file_format(name).await
         } else {
-            FileFormatParams::try_from_reader(reader, false)
+            let params = FileFormatParams::try_from_reader(reader, false)?;
+            self.ctx.check_file_format_params(&params)?;
+            Ok(params)
         }
     }
 }
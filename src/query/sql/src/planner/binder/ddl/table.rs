@@ -24,9 +24,12 @@ use databend_common_ast::ast::AttachTableStmt;
 use databend_common_ast::ast::ColumnDefinition;
 use databend_common_ast::ast::ColumnExpr;
 use databend_common_ast::ast::CompactTarget;
+use databend_common_ast::ast::ConstraintDefinition;
+use databend_common_ast::ast::ConstraintType;
 use databend_common_ast::ast::CreateTableSource;
 use databend_common_ast::ast::CreateTableStmt;
 use databend_common_ast::ast::DescribeTableStmt;
+use databend_common_ast::ast::DropBehavior;
 use databend_common_ast::ast::DropTableStmt;
 use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::ExistsTableStmt;
@@ -36,7 +39,9 @@ use databend_common_ast::ast::InvertedIndexDefinition;
 use databend_common_ast::ast::ModifyColumnAction;
 use databend_common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use databend_common_ast::ast::OptimizeTableStmt;
+use databend_common_ast::ast::RefreshTableStatisticsStmt;
 use databend_common_ast::ast::RenameTableStmt;
+use databend_common_ast::ast::RestoreTableStmt;
 use databend_common_ast::ast::ShowCreateTableStmt;
 use databend_common_ast::ast::ShowDropTablesStmt;
 use databend_common_ast::ast::ShowLimit;
@@ -51,10 +56,13 @@ use databend_common_ast::ast::UriLocation;
 use databend_common_ast::ast::VacuumDropTableStmt;
 use databend_common_ast::ast::VacuumTableStmt;
 use databend_common_ast::ast::VacuumTemporaryFiles;
+use databend_common_ast::ast::VerifyTableStmt;
+use databend_common_ast::parser::parse_comma_separated_exprs;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_base::base::uuid::Uuid;
 use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table::Table;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -71,12 +79,15 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::TableConstraintType;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
+use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
@@ -88,10 +99,12 @@ use log::debug;
 
 use crate::binder::get_storage_params_from_options;
 use crate::binder::parse_storage_params_from_uri;
+use crate::binder::resolve_stage_location;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
+use crate::parse_backfill_expr_to_string;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
 use crate::planner::semantic::normalize_identifier;
@@ -100,6 +113,8 @@ use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AlterTableStoragePolicyPlan;
+use crate::plans::AlterTableTagsPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
@@ -114,12 +129,16 @@ use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
 use crate::plans::ReclusterTablePlan;
+use crate::plans::RefreshTableStatisticsPlan;
 use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
+use crate::plans::RestoreTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
 use crate::plans::SetOptionsPlan;
 use crate::plans::ShowCreateTablePlan;
+use crate::plans::StoragePolicyDesc;
+use crate::plans::SwapTablePlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
 use crate::plans::VacuumDropTableOption;
@@ -127,6 +146,7 @@ use crate::plans::VacuumDropTablePlan;
 use crate::plans::VacuumTableOption;
 use crate::plans::VacuumTablePlan;
 use crate::plans::VacuumTemporaryFilesPlan;
+use crate::plans::VerifyTablePlan;
 use crate::BindContext;
 use crate::Planner;
 use crate::SelectBuilder;
@@ -473,7 +493,7 @@ impl Binder {
         }
 
         // todo(geometry): remove this when geometry stable.
-        if let Some(CreateTableSource::Columns(cols, _)) = &source {
+        if let Some(CreateTableSource::Columns(cols, _, _)) = &source {
             if cols
                 .iter()
                 .any(|col| matches!(col.data_type, TypeName::Geometry))
@@ -558,8 +578,17 @@ impl Binder {
                         engine_options.insert(OPT_KEY_ENGINE_META.to_lowercase().to_string(), meta);
                         (Arc::new(table_schema), vec![], None)
                     }
+                    Engine::Hudi => {
+                        let sp =
+                            get_storage_params_from_options(self.ctx.as_ref(), &options).await?;
+                        let (table_schema, meta) =
+                            self.ctx.load_datalake_schema("hudi", &sp).await?;
+                        storage_params = Some(sp);
+                        engine_options.insert(OPT_KEY_ENGINE_META.to_lowercase().to_string(), meta);
+                        (Arc::new(table_schema), vec![], None)
+                    }
                     _ => Err(ErrorCode::BadArguments(
-                        "Incorrect CREATE query: required list of column descriptions or AS section or SELECT or ICEBERG/DELTA table engine",
+                        "Incorrect CREATE query: required list of column descriptions or AS section or SELECT or ICEBERG/DELTA/HUDI table engine",
                     ))?,
                 }
             }
@@ -628,7 +657,23 @@ impl Binder {
             )));
         }
 
-        let cluster_key = {
+        let constraints = match &source {
+            Some(CreateTableSource::Columns(_, _, Some(constraint_defs))) => {
+                let constraints = self
+                    .analyze_table_constraints(
+                        &catalog,
+                        &database,
+                        &table,
+                        schema.clone(),
+                        constraint_defs,
+                    )
+                    .await?;
+                Some(constraints)
+            }
+            _ => None,
+        };
+
+        let mut cluster_key = {
             let keys = self
                 .analyze_cluster_keys(cluster_by, schema.clone())
                 .await?;
@@ -639,6 +684,53 @@ impl Binder {
             }
         };
 
+        let as_select = match as_query {
+            Some(query) => {
+                let mut bind_context = BindContext::new();
+                let stmt = Statement::Query(Box::new(*query.clone()));
+                let select_plan = self.bind_statement(&mut bind_context, &stmt).await?;
+                Some(Box::new(select_plan))
+            }
+            None => None,
+        };
+
+        // `CREATE TABLE AS SELECT` from a single fuse table inherits the cluster key and the
+        // bloom index columns of it, unless they are specified by the statement.
+        if let Some(select_plan) = &as_select {
+            let inherit = self
+                .ctx
+                .get_settings()
+                .get_enable_ctas_inherit_source_options()?;
+            if let Some(source_table) = Self::ctas_source_table(select_plan) {
+                if inherit && engine == Engine::Fuse && source_table.engine() == "FUSE" {
+                    let source_meta = &source_table.get_table_info().meta;
+                    if cluster_key.is_none() {
+                        if let Some(source_cluster_key) = &source_meta.default_cluster_key {
+                            cluster_key = self
+                                .inherit_cluster_key(source_cluster_key, schema.clone())
+                                .await;
+                        }
+                    }
+
+                    if let Some(bloom_index_columns) =
+                        source_meta.options.get(OPT_KEY_BLOOM_INDEX_COLUMNS)
+                    {
+                        let all_exist = bloom_index_columns
+                            .split(',')
+                            .map(|name| name.trim())
+                            .filter(|name| !name.is_empty())
+                            .all(|name| schema.field_with_name(name).is_ok());
+                        if all_exist && !options.contains_key(OPT_KEY_BLOOM_INDEX_COLUMNS) {
+                            options.insert(
+                                OPT_KEY_BLOOM_INDEX_COLUMNS.to_string(),
+                                bloom_index_columns.clone(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
         let plan = CreateTablePlan {
             create_option: create_option.clone().into(),
             tenant: self.ctx.get_tenant(),
@@ -653,15 +745,9 @@ impl Binder {
             options,
             field_comments,
             cluster_key,
-            as_select: if let Some(query) = as_query {
-                let mut bind_context = BindContext::new();
-                let stmt = Statement::Query(Box::new(*query.clone()));
-                let select_plan = self.bind_statement(&mut bind_context, &stmt).await?;
-                Some(Box::new(select_plan))
-            } else {
-                None
-            },
+            as_select,
             inverted_indexes,
+            constraints,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -734,6 +820,7 @@ impl Binder {
             cluster_key: None,
             as_select: None,
             inverted_indexes: None,
+            constraints: None,
         })))
     }
 
@@ -748,6 +835,7 @@ impl Binder {
             database,
             table,
             all,
+            drop_behavior,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -761,6 +849,7 @@ impl Binder {
             database,
             table,
             all: *all,
+            restrict: !matches!(drop_behavior, Some(DropBehavior::Cascade)),
         })))
     }
 
@@ -827,6 +916,17 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::SwapWith { target_table } => {
+                Ok(Plan::SwapTable(Box::new(SwapTablePlan {
+                    tenant,
+                    if_exists: *if_exists,
+                    catalog,
+                    database,
+                    table,
+                    target_table: normalize_identifier(target_table, &self.name_resolution_ctx)
+                        .name,
+                })))
+            }
             AlterTableAction::ModifyTableComment { new_comment } => {
                 Ok(Plan::ModifyTableComment(Box::new(ModifyTableCommentPlan {
                     new_comment: new_comment.to_string(),
@@ -866,7 +966,8 @@ impl Binder {
                     .get_table(&catalog, &database, &table)
                     .await?
                     .schema();
-                let (field, comment) = self.analyze_add_column(column, schema).await?;
+                let (field, comment, backfill_expr) =
+                    self.analyze_add_column(column, schema, true).await?;
                 let option = match ast_option {
                     AstAddColumnOption::First => AddColumnOption::First,
                     AstAddColumnOption::After(ident) => AddColumnOption::After(
@@ -882,8 +983,88 @@ impl Binder {
                     field,
                     comment,
                     option,
+                    backfill_expr,
+                })))
+            }
+            AlterTableAction::SetTags { tags } => {
+                Ok(Plan::AlterTableTags(Box::new(AlterTableTagsPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    column: None,
+                    set_tags: tags.clone(),
+                    unset_tags: vec![],
+                })))
+            }
+            AlterTableAction::UnsetTags { tags } => {
+                Ok(Plan::AlterTableTags(Box::new(AlterTableTagsPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    column: None,
+                    set_tags: BTreeMap::new(),
+                    unset_tags: tags.clone(),
                 })))
             }
+            AlterTableAction::SetStoragePolicy {
+                cold_after_days,
+                cold_location,
+            } => {
+                let mut uri = cold_location.clone();
+                let cold_storage = parse_storage_params_from_uri(
+                    &mut uri,
+                    Some(self.ctx.as_ref()),
+                    "when set the STORAGE_POLICY of table",
+                )
+                .await?;
+                // create a temporary op to check if params is correct
+                DataOperator::try_create(&cold_storage).await?;
+
+                Ok(Plan::AlterTableStoragePolicy(Box::new(
+                    AlterTableStoragePolicyPlan {
+                        catalog,
+                        database,
+                        table,
+                        policy: Some(StoragePolicyDesc {
+                            cold_after_days: *cold_after_days,
+                            cold_location: cold_location.mask().to_string(),
+                            cold_storage,
+                        }),
+                    },
+                )))
+            }
+            AlterTableAction::UnsetStoragePolicy => Ok(Plan::AlterTableStoragePolicy(Box::new(
+                AlterTableStoragePolicyPlan {
+                    catalog,
+                    database,
+                    table,
+                    policy: None,
+                },
+            ))),
+            AlterTableAction::ModifyColumn {
+                action: ModifyColumnAction::SetTags(column, tags),
+            } => Ok(Plan::AlterTableTags(Box::new(AlterTableTagsPlan {
+                tenant,
+                catalog,
+                database,
+                table,
+                column: Some(self.normalize_object_identifier(column)),
+                set_tags: tags.clone(),
+                unset_tags: vec![],
+            }))),
+            AlterTableAction::ModifyColumn {
+                action: ModifyColumnAction::UnsetTags(column, tags),
+            } => Ok(Plan::AlterTableTags(Box::new(AlterTableTagsPlan {
+                tenant,
+                catalog,
+                database,
+                table,
+                column: Some(self.normalize_object_identifier(column)),
+                set_tags: BTreeMap::new(),
+                unset_tags: tags.clone(),
+            }))),
             AlterTableAction::ModifyColumn { action } => {
                 let mut lock_guard = None;
                 let action_in_plan = match action {
@@ -899,6 +1080,9 @@ impl Binder {
                         let column = self.normalize_object_identifier(column);
                         ModifyColumnActionInPlan::ConvertStoredComputedColumn(column)
                     }
+                    ModifyColumnAction::SetTags(..) | ModifyColumnAction::UnsetTags(..) => {
+                        unreachable!()
+                    }
                     ModifyColumnAction::SetDataType(column_def_vec) => {
                         let mut field_and_comment = Vec::with_capacity(column_def_vec.len());
                         // try add lock table.
@@ -918,8 +1102,9 @@ impl Binder {
                             .await?
                             .schema();
                         for column in column_def_vec {
-                            let (field, comment) =
-                                self.analyze_add_column(column, schema.clone()).await?;
+                            let (field, comment, _) = self
+                                .analyze_add_column(column, schema.clone(), false)
+                                .await?;
                             field_and_comment.push((field, comment));
                         }
                         ModifyColumnActionInPlan::SetDataType(field_and_comment)
@@ -971,8 +1156,14 @@ impl Binder {
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
+                last_segments,
                 limit,
             } => {
+                if *last_segments == Some(0) {
+                    return Err(ErrorCode::BadArguments(
+                        "the number of segments to recluster must be greater than 0",
+                    ));
+                }
                 let push_downs = if let Some(expr) = selection {
                     let (_, mut context) =
                         self.bind_table_reference(bind_context, table_reference)?;
@@ -1001,6 +1192,7 @@ impl Binder {
                     is_final: *is_final,
                     metadata: self.metadata.clone(),
                     push_downs,
+                    last_segments: last_segments.map(|v| v as usize),
                     limit: limit.map(|v| v as usize),
                 })))
             }
@@ -1117,6 +1309,7 @@ impl Binder {
                 CompactTarget::Block => OptimizeTableAction::CompactBlocks(None),
                 CompactTarget::Segment => OptimizeTableAction::CompactSegments,
             },
+            AstOptimizeTableAction::Recluster => OptimizeTableAction::Recluster,
         };
 
         Ok(Plan::OptimizeTable(Box::new(OptimizeTablePlan {
@@ -1225,6 +1418,83 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_verify_table(
+        &mut self,
+        stmt: &VerifyTableStmt,
+    ) -> Result<Plan> {
+        let VerifyTableStmt {
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        Ok(Plan::VerifyTable(Box::new(VerifyTablePlan {
+            catalog,
+            database,
+            table,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_table_statistics(
+        &mut self,
+        stmt: &RefreshTableStatisticsStmt,
+    ) -> Result<Plan> {
+        let RefreshTableStatisticsStmt {
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        Ok(Plan::RefreshTableStatistics(Box::new(
+            RefreshTableStatisticsPlan {
+                catalog,
+                database,
+                table,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_restore_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &RestoreTableStmt,
+    ) -> Result<Plan> {
+        let RestoreTableStmt {
+            catalog,
+            database,
+            table,
+            location,
+            point,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let (stage, path) = resolve_stage_location(self.ctx.as_ref(), location).await?;
+        let point = match point {
+            Some(point) => Some(self.resolve_data_travel_point(bind_context, point)?),
+            None => None,
+        };
+
+        Ok(Plan::RestoreTable(Box::new(RestoreTablePlan {
+            catalog,
+            database,
+            table,
+            location: location.clone(),
+            stage,
+            path,
+            point,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_exists_table(
         &mut self,
@@ -1287,17 +1557,42 @@ impl Binder {
         &self,
         column: &ColumnDefinition,
         table_schema: TableSchemaRef,
-    ) -> Result<(TableField, String)> {
+        allow_backfill: bool,
+    ) -> Result<(TableField, String, Option<String>)> {
         let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
         let not_null = self.is_column_not_null();
         let data_type = resolve_type_name(&column.data_type, not_null)?;
         let mut field = TableField::new(&name, data_type);
+        let mut backfill_expr = None;
         if let Some(expr) = &column.expr {
             match expr {
                 ColumnExpr::Default(default_expr) => {
-                    let expr =
-                        parse_default_expr_to_string(self.ctx.clone(), &field, default_expr, true)?;
-                    field = field.with_default_expr(Some(expr));
+                    match parse_default_expr_to_string(self.ctx.clone(), &field, default_expr, true)
+                    {
+                        Ok(expr) => field = field.with_default_expr(Some(expr)),
+                        Err(e) if allow_backfill && e.code() == ErrorCode::SEMANTIC_ERROR => {
+                            // The existing rows are backfilled with the non-constant default,
+                            // e.g. `uuid()` or an expression over the other columns, which is
+                            // stored apart from the insert default. The rows inserted later take
+                            // the default if it doesn't refer to the other columns, otherwise the
+                            // default value of the type.
+                            backfill_expr = Some(parse_backfill_expr_to_string(
+                                self.ctx.clone(),
+                                table_schema.clone(),
+                                &field,
+                                default_expr,
+                            )?);
+                            let expr = parse_default_expr_to_string(
+                                self.ctx.clone(),
+                                &field,
+                                default_expr,
+                                false,
+                            )
+                            .ok();
+                            field = field.with_default_expr(expr);
+                        }
+                        Err(e) => return Err(e),
+                    }
                 }
                 ColumnExpr::Virtual(virtual_expr) => {
                     let expr = parse_computed_expr_to_string(
@@ -1317,7 +1612,7 @@ impl Binder {
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
-        Ok((field, comment))
+        Ok((field, comment, backfill_expr))
     }
 
     #[async_backtrace::framed]
@@ -1435,6 +1730,124 @@ impl Binder {
         Ok(inverted_indexes)
     }
 
+    #[async_backtrace::framed]
+    async fn analyze_table_constraints(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        table_schema: TableSchemaRef,
+        constraint_defs: &[ConstraintDefinition],
+    ) -> Result<BTreeMap<String, TableConstraint>> {
+        let mut constraints = BTreeMap::new();
+        let mut has_primary_key = false;
+        for constraint_def in constraint_defs {
+            if constraint_def.enforced {
+                return Err(ErrorCode::BadArguments(
+                    "Enforced constraints are not supported, use NOT ENFORCED instead",
+                ));
+            }
+            let mut column_ids = Vec::with_capacity(constraint_def.columns.len());
+            let mut column_names = Vec::with_capacity(constraint_def.columns.len());
+            for column in &constraint_def.columns {
+                let column_name = self.normalize_object_identifier(column);
+                let field = table_schema.field_with_name(&column_name)?;
+                if matches!(constraint_def.constraint_type, ConstraintType::PrimaryKey)
+                    && field.is_nullable()
+                {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Primary key column {} must be NOT NULL",
+                        column_name
+                    )));
+                }
+                if column_ids.contains(&field.column_id) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Duplicated column {} in constraint",
+                        column_name
+                    )));
+                }
+                column_ids.push(field.column_id);
+                column_names.push(column_name);
+            }
+
+            let (constraint_type, suffix) = match &constraint_def.constraint_type {
+                ConstraintType::PrimaryKey => {
+                    if has_primary_key {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Multiple primary keys for table {} are not allowed",
+                            table
+                        )));
+                    }
+                    has_primary_key = true;
+                    (TableConstraintType::PrimaryKey, "pkey")
+                }
+                ConstraintType::Unique => (TableConstraintType::Unique, "key"),
+                ConstraintType::ForeignKey { .. } => (TableConstraintType::ForeignKey, "fkey"),
+            };
+            let (ref_database, ref_table, ref_columns) = match &constraint_def.constraint_type {
+                ConstraintType::ForeignKey {
+                    database: ref_database,
+                    table: ref_table,
+                    columns: ref_columns,
+                } => {
+                    if ref_columns.len() != column_ids.len() {
+                        return Err(ErrorCode::BadArguments(
+                            "Number of referencing and referenced columns for foreign key does not match",
+                        ));
+                    }
+                    let ref_database = ref_database
+                        .as_ref()
+                        .map(|ident| self.normalize_object_identifier(ident))
+                        .unwrap_or_else(|| database.to_string());
+                    let ref_table = self.normalize_object_identifier(ref_table);
+                    let ref_columns = ref_columns
+                        .iter()
+                        .map(|ident| self.normalize_object_identifier(ident))
+                        .collect::<Vec<_>>();
+                    // A foreign key can reference the table itself, which is not created yet.
+                    let ref_schema = if ref_database == database && ref_table == table {
+                        table_schema.clone()
+                    } else {
+                        self.ctx
+                            .get_table(catalog, &ref_database, &ref_table)
+                            .await?
+                            .schema()
+                    };
+                    for ref_column in ref_columns.iter() {
+                        ref_schema.field_with_name(ref_column)?;
+                    }
+                    (Some(ref_database), Some(ref_table), ref_columns)
+                }
+                _ => (None, None, vec![]),
+            };
+
+            let name = match &constraint_def.name {
+                Some(name) => self.normalize_object_identifier(name),
+                None if constraint_type == TableConstraintType::PrimaryKey => {
+                    format!("{}_{}", table, suffix)
+                }
+                None => format!("{}_{}_{}", table, column_names.join("_"), suffix),
+            };
+            if constraints.contains_key(&name) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Duplicated constraint name: {}",
+                    name
+                )));
+            }
+            let constraint = TableConstraint {
+                name: name.clone(),
+                constraint_type,
+                column_ids,
+                ref_database,
+                ref_table,
+                ref_columns,
+                rely: constraint_def.rely,
+            };
+            constraints.insert(name, constraint);
+        }
+        Ok(constraints)
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn analyze_create_table_schema(
         &self,
@@ -1445,7 +1858,7 @@ impl Binder {
         Option<BTreeMap<String, TableIndex>>,
     )> {
         match source {
-            CreateTableSource::Columns(columns, inverted_index_defs) => {
+            CreateTableSource::Columns(columns, inverted_index_defs, _) => {
                 let (schema, comments) =
                     self.analyze_create_table_schema_by_columns(columns).await?;
                 let inverted_indexes = if let Some(inverted_index_defs) = inverted_index_defs {
@@ -1527,6 +1940,48 @@ impl Binder {
         }
     }
 
+    /// The only table read by the query of `CREATE TABLE AS SELECT`, the query may be bound
+    /// more than once, so the table may show up several times in the metadata.
+    fn ctas_source_table(select_plan: &Plan) -> Option<Arc<dyn Table>> {
+        let Plan::Query { metadata, .. } = select_plan else {
+            return None;
+        };
+
+        let metadata = metadata.read();
+        let mut tables = metadata.tables().iter().filter(|t| !t.is_source_of_index());
+        let first = tables.next()?;
+        let table_id = first.table().get_id();
+        if first.is_source_of_view() || first.is_source_of_stage() {
+            return None;
+        }
+
+        tables
+            .all(|t| t.table().get_id() == table_id && !t.is_source_of_view())
+            .then(|| first.table())
+    }
+
+    /// Rebinds the cluster key of the source table of `CREATE TABLE AS SELECT` against the new
+    /// table, the key is not inherited if any column of it is missing.
+    #[async_backtrace::framed]
+    async fn inherit_cluster_key(
+        &mut self,
+        source_cluster_key: &str,
+        schema: TableSchemaRef,
+    ) -> Option<String> {
+        let source_cluster_key = source_cluster_key.trim();
+        let source_cluster_key = source_cluster_key
+            .strip_prefix('(')
+            .and_then(|key| key.strip_suffix(')'))
+            .unwrap_or(source_cluster_key);
+        let tokens = tokenize_sql(source_cluster_key).ok()?;
+        let exprs = parse_comma_separated_exprs(&tokens, self.dialect).ok()?;
+        let keys = self.analyze_cluster_keys(&exprs, schema).await.ok()?;
+        match keys.is_empty() {
+            true => None,
+            false => Some(format!("({})", keys.join(", "))),
+        }
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn analyze_cluster_keys(
         &mut self,
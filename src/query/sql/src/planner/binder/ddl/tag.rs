@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::*;
+use databend_common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::AlterTagPlan;
+use crate::plans::CreateTagPlan;
+use crate::plans::DropTagPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_tag(
+        &mut self,
+        stmt: &CreateTagStmt,
+    ) -> Result<Plan> {
+        let CreateTagStmt {
+            create_option,
+            name,
+            comment,
+        } = stmt;
+
+        let plan = CreateTagPlan {
+            create_option: create_option.clone().into(),
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            comment: comment.clone().unwrap_or_default(),
+        };
+        Ok(Plan::CreateTag(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_tag(
+        &mut self,
+        stmt: &AlterTagStmt,
+    ) -> Result<Plan> {
+        let AlterTagStmt {
+            if_exists,
+            name,
+            action,
+        } = stmt;
+
+        let masking_policy = match action {
+            AlterTagAction::SetMaskingPolicy(policy) => Some(policy.to_string()),
+            AlterTagAction::UnsetMaskingPolicy => None,
+        };
+
+        let plan = AlterTagPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            masking_policy,
+        };
+        Ok(Plan::AlterTag(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_tag(
+        &mut self,
+        stmt: &DropTagStmt,
+    ) -> Result<Plan> {
+        let DropTagStmt { if_exists, name } = stmt;
+
+        let plan = DropTagPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+        };
+        Ok(Plan::DropTag(Box::new(plan)))
+    }
+}
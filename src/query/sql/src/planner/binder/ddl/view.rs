@@ -15,6 +15,7 @@
 use databend_common_ast::ast::AlterViewStmt;
 use databend_common_ast::ast::CreateViewStmt;
 use databend_common_ast::ast::DescribeViewStmt;
+use databend_common_ast::ast::DropBehavior;
 use databend_common_ast::ast::DropViewStmt;
 use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowViewsStmt;
@@ -125,6 +126,7 @@ impl Binder {
             catalog,
             database,
             view,
+            drop_behavior,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -136,6 +138,7 @@ impl Binder {
             catalog,
             database,
             view_name,
+            restrict: !matches!(drop_behavior, Some(DropBehavior::Cascade)),
         };
         Ok(Plan::DropView(plan.into()))
     }
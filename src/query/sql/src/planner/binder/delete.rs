@@ -97,6 +97,13 @@ impl<'a> Binder {
             fully_table.table_name(),
         );
 
+        // Finish the backfills of the added columns first, the blocks without them would be
+        // rewritten with the insert defaults of the columns.
+        self.ctx
+            .finish_column_backfills(&catalog_name, &database_name, &table_name)
+            .await
+            .map_err(|err| fully_table.not_found_suggest_error(err))?;
+
         // Add table lock before execution.
         let lock_guard = self
             .ctx
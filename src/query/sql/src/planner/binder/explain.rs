@@ -28,12 +28,15 @@ pub struct ExplainConfig {
     pub verbose: bool,
     pub logical: bool,
     pub optimized: bool,
+    /// Output the plan as a JSON document instead of an indented tree.
+    pub json: bool,
 }
 
 struct ExplainConfigBuilder {
     verbose: bool,
     logical: bool,
     optimized: bool,
+    json: bool,
 }
 
 impl ExplainConfigBuilder {
@@ -42,6 +45,7 @@ impl ExplainConfigBuilder {
             verbose: false,
             logical: false,
             optimized: false,
+            json: false,
         }
     }
 
@@ -53,6 +57,7 @@ impl ExplainConfigBuilder {
                 self.logical = true;
                 self.optimized = true;
             }
+            ExplainOption::FormatJson => self.json = true,
         }
 
         self
@@ -63,6 +68,7 @@ impl ExplainConfigBuilder {
             verbose: self.verbose,
             logical: self.logical,
             optimized: self.optimized,
+            json: self.json,
         }
     }
 }
@@ -146,5 +152,12 @@ fn validate_explain_config(kind: &ExplainKind, config: &ExplainConfig) -> Result
         ));
     }
 
+    if config.json && (!matches!(kind, ExplainKind::Plan) || config.logical) {
+        return Err(ErrorCode::SyntaxException(
+            "FORMAT = JSON option is only supported for EXPLAIN SELECT statement without LOGICAL"
+                .to_string(),
+        ));
+    }
+
     Ok(())
 }
@@ -13,11 +13,13 @@
 // limitations under the License.
 
 use databend_common_ast::ast::KillTarget;
+use databend_common_ast::ast::KillWhereStmt;
 use databend_common_exception::Result;
 
 use crate::planner::binder::BindContext;
 use crate::planner::binder::Binder;
 use crate::plans::KillPlan;
+use crate::plans::KillWherePlan;
 use crate::plans::Plan;
 
 impl Binder {
@@ -36,4 +38,31 @@ impl Binder {
 
         Ok(Plan::Kill(plan))
     }
+
+    #[async_backtrace::framed]
+    pub(super) async fn bind_kill_where_stmt(
+        &mut self,
+        _bind_context: &BindContext,
+        stmt: &KillWhereStmt,
+    ) -> Result<Plan> {
+        let kill_connection = matches!(stmt.kill_target, KillTarget::Connection);
+        // `elapsed` is an alias of `time`, the seconds since the query or session started.
+        // Only running queries are killed by `KILL QUERY`, and never the current session.
+        let state_filter = match kill_connection {
+            true => "",
+            false => " AND command = 'Query'",
+        };
+        let subquery = format!(
+            "SELECT id FROM (SELECT *, time AS elapsed FROM system.processes) AS processes \
+             WHERE ({}){} AND id <> '{}'",
+            stmt.selection,
+            state_filter,
+            self.ctx.get_current_session_id()
+        );
+
+        Ok(Plan::KillWhere(Box::new(KillWherePlan {
+            subquery,
+            kill_connection,
+        })))
+    }
 }
@@ -24,6 +24,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_meta_app::storage::StorageAzblobConfig;
+use databend_common_meta_app::storage::StorageAzdlsConfig;
 use databend_common_meta_app::storage::StorageCosConfig;
 use databend_common_meta_app::storage::StorageFsConfig;
 use databend_common_meta_app::storage::StorageGcsConfig;
@@ -81,6 +82,32 @@ fn parse_azure_params(l: &mut UriLocation, root: String) -> Result<StorageParams
     Ok(sp)
 }
 
+fn parse_azdls_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
+    let endpoint = l.connection.get("endpoint_url").cloned().ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            anyhow!("endpoint_url is required for storage azdls"),
+        )
+    })?;
+    let sp = StorageParams::Azdls(StorageAzdlsConfig {
+        endpoint_url: secure_omission(endpoint),
+        filesystem: l.name.to_string(),
+        account_name: l
+            .connection
+            .get("account_name")
+            .cloned()
+            .unwrap_or_default(),
+        account_key: l.connection.get("account_key").cloned().unwrap_or_default(),
+        root,
+    });
+
+    l.connection
+        .check()
+        .map_err(|err| Error::new(ErrorKind::InvalidInput, err.to_string()))?;
+
+    Ok(sp)
+}
+
 fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
     let endpoint = l
         .connection
@@ -530,6 +557,7 @@ pub async fn parse_uri_location(
 
     let sp = match protocol {
         Scheme::Azblob => parse_azure_params(l, root)?,
+        Scheme::Azdls => parse_azdls_params(l, root)?,
         Scheme::Gcs => parse_gcs_params(l)?,
         #[cfg(feature = "storage-hdfs")]
         Scheme::Hdfs => parse_hdfs_params(l)?,
@@ -40,6 +40,7 @@ mod presign;
 mod project;
 mod project_set;
 mod qualify;
+mod repeated_subquery;
 mod replace;
 mod scalar;
 mod scalar_common;
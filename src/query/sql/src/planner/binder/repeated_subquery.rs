@@ -0,0 +1,263 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Query;
+use databend_common_catalog::table_context::SubqueryMaterialization;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
+use itertools::Itertools;
+use log::info;
+
+use crate::binder::Binder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::ConstantTableScan;
+use crate::plans::RelOperator;
+use crate::BindContext;
+
+/// Finds what makes the result of a subquery vary on the same tables.
+#[derive(Visitor)]
+#[visitor(FunctionCall(enter), Expr(enter))]
+struct VolatileChecker {
+    volatile: bool,
+}
+
+impl VolatileChecker {
+    fn enter_function_call(&mut self, func: &FunctionCall) {
+        if BUILTIN_FUNCTIONS
+            .get_property(&func.name.name)
+            .map(|p| p.non_deterministic)
+            .unwrap_or(false)
+        {
+            self.volatile = true;
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &Expr) {
+        // The tables read by the subqueries in expressions are not tracked in the version.
+        if matches!(
+            expr,
+            Expr::Subquery { .. } | Expr::InSubquery { .. } | Expr::Exists { .. }
+        ) {
+            self.volatile = true;
+        }
+    }
+}
+
+/// The subqueries which join, aggregate, sort or run window functions are worth materializing.
+fn is_expensive(s_expr: &SExpr) -> bool {
+    matches!(
+        s_expr.plan(),
+        RelOperator::Join(_)
+            | RelOperator::Aggregate(_)
+            | RelOperator::Sort(_)
+            | RelOperator::Window(_)
+    ) || s_expr.children().any(is_expensive)
+}
+
+impl Binder {
+    /// Tracks the runs of an uncorrelated subquery in FROM, which is suggested to be
+    /// materialized once it has run `repeated_subquery_hint_threshold` times. With
+    /// `enable_auto_subquery_materialization`, the subquery is materialized once the statement
+    /// is bound, then the statement and the following queries of the same user and roles read
+    /// the materialized result instead as long as the tables it reads are unchanged.
+    pub(crate) fn track_repeated_subquery(
+        &mut self,
+        bind_context: &BindContext,
+        subquery: &Query,
+        s_expr: SExpr,
+        result_bind_context: &BindContext,
+    ) -> Result<SExpr> {
+        let settings = self.ctx.get_settings();
+        let threshold = settings.get_repeated_subquery_hint_threshold()?;
+        // The CTEs of the outer query are not a part of the subquery text.
+        if threshold == 0 || !bind_context.cte_map_ref.is_empty() || !is_expensive(&s_expr) {
+            return Ok(s_expr);
+        }
+
+        let mut tables = vec![];
+        let mut materializable = self.collect_table_versions(&s_expr, &mut tables);
+        let mut checker = VolatileChecker { volatile: false };
+        subquery.drive(&mut checker);
+        materializable &= !checker.volatile && !self.ctx.txn_mgr().lock().is_active();
+
+        let fingerprint = format!(
+            "{} /* {} */",
+            subquery,
+            tables.iter().map(|(id, _)| id).join(", ")
+        );
+        let version = tables.iter().map(|(_, version)| version).join(";");
+        let auto_materialize =
+            materializable && settings.get_enable_auto_subquery_materialization()?;
+
+        if auto_materialize {
+            if let Some(blocks) = self
+                .ctx
+                .get_subquery_materialization(&fingerprint, &version)?
+            {
+                if let Some(s_expr) =
+                    self.materialized_subquery_scan(&blocks, result_bind_context)?
+                {
+                    info!(
+                        "read the materialized result of the subquery ({})",
+                        subquery
+                    );
+                    return Ok(s_expr);
+                }
+            }
+        }
+
+        let runs = self.ctx.record_subquery_run(&fingerprint)?;
+        if runs < threshold {
+            return Ok(s_expr);
+        }
+        if auto_materialize {
+            self.ctx.push_warning(format!(
+                "the subquery ({}) has run {} times, its result is materialized for this and the following queries until the tables it reads change",
+                subquery, runs
+            ));
+            self.ctx
+                .add_pending_subquery_materialization(SubqueryMaterialization {
+                    fingerprint,
+                    version,
+                    sql: subquery.to_string(),
+                });
+        } else if materializable {
+            self.ctx.push_warning(format!(
+                "the subquery ({}) has run {} times, consider materializing it into a table, or SET enable_auto_subquery_materialization = 1 to materialize it automatically",
+                subquery, runs
+            ));
+        } else {
+            self.ctx.push_warning(format!(
+                "the subquery ({}) has run {} times, consider materializing it into a table",
+                subquery, runs
+            ));
+        }
+        Ok(s_expr)
+    }
+
+    /// Collects the ids and the versions of the tables read by the subquery, returns false
+    /// if the result of the subquery may change while the versions don't.
+    fn collect_table_versions(&self, s_expr: &SExpr, tables: &mut Vec<(u64, String)>) -> bool {
+        let mut materializable = true;
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                let table = self.metadata.read().table(scan.table_index).table();
+                let table_info = table.get_table_info();
+                // The masking policies may be changed without changing the table version.
+                materializable = table.engine().eq_ignore_ascii_case("FUSE")
+                    && table_info
+                        .meta
+                        .column_mask_policy
+                        .as_ref()
+                        .map_or(true, |policies| policies.is_empty())
+                    && table_info.meta.column_tags.is_empty();
+                tables.push((
+                    table_info.ident.table_id,
+                    format!(
+                        "{}:{}:{}",
+                        table_info.ident.table_id,
+                        table_info.ident.seq,
+                        table_info
+                            .options()
+                            .get(OPT_KEY_SNAPSHOT_LOCATION)
+                            .map(String::as_str)
+                            .unwrap_or_default(),
+                    ),
+                ));
+            }
+            RelOperator::CteScan(_)
+            | RelOperator::MaterializedCte(_)
+            | RelOperator::ExpressionScan(_)
+            | RelOperator::CacheScan(_)
+            | RelOperator::RecursiveCteScan(_)
+            | RelOperator::Udf(_)
+            | RelOperator::AsyncFunction(_) => materializable = false,
+            _ => {}
+        }
+        for child in s_expr.children() {
+            materializable &= self.collect_table_versions(child, tables);
+        }
+        materializable
+    }
+
+    /// Builds the scan of the materialized result with the output columns of the subquery,
+    /// returns None if the result doesn't match them.
+    fn materialized_subquery_scan(
+        &self,
+        blocks: &[DataBlock],
+        result_bind_context: &BindContext,
+    ) -> Result<Option<SExpr>> {
+        let output = &result_bind_context.columns;
+        if blocks.iter().any(|block| {
+            block.num_columns() != output.len()
+                || output
+                    .iter()
+                    .enumerate()
+                    .any(|(i, column)| block.get_by_offset(i).data_type != *column.data_type)
+        }) {
+            return Ok(None);
+        }
+
+        let block = if blocks.is_empty() {
+            DataBlock::new_from_columns(
+                output
+                    .iter()
+                    .map(|column| ColumnBuilder::with_capacity(&column.data_type, 0).build())
+                    .collect(),
+            )
+        } else {
+            DataBlock::concat(blocks)?
+        };
+
+        // The same column may be output more than once.
+        let mut seen = HashSet::with_capacity(output.len());
+        let mut columns = ColumnSet::new();
+        let mut fields = Vec::with_capacity(output.len());
+        let mut values = Vec::with_capacity(output.len());
+        for (i, column) in output.iter().enumerate() {
+            if !seen.insert(column.index) {
+                continue;
+            }
+            columns.insert(column.index);
+            fields.push(DataField::new(
+                &column.index.to_string(),
+                *column.data_type.clone(),
+            ));
+            values.push(block.get_by_offset(i).to_column(block.num_rows()));
+        }
+
+        Ok(Some(SExpr::create_leaf(Arc::new(
+            ConstantTableScan {
+                values,
+                num_rows: block.num_rows(),
+                schema: DataSchemaRefExt::create(fields),
+                columns,
+            }
+            .into(),
+        ))))
+    }
+}
@@ -49,6 +49,12 @@ impl Binder {
         let (catalog_name, database_name, table_name) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        // Finish the backfills of the added columns first, the blocks without them would be
+        // rewritten with the insert defaults of the columns.
+        self.ctx
+            .finish_column_backfills(&catalog_name, &database_name, &table_name)
+            .await?;
+
         // Add table lock before execution.
         let lock_guard = self
             .ctx
@@ -12,23 +12,95 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::SystemAction as AstSystemAction;
 use databend_common_ast::ast::SystemStmt;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_storage::DataOperator;
+use log::info;
 
 use crate::planner::binder::Binder;
 use crate::plans::Plan;
 use crate::plans::SystemAction;
 use crate::plans::SystemPlan;
+use crate::BindContext;
+use crate::NameResolutionContext;
+use crate::QueryReplayBundle;
 
 impl Binder {
     #[async_backtrace::framed]
-    pub(super) async fn bind_system(&mut self, stmt: &SystemStmt) -> Result<Plan> {
+    pub(super) async fn bind_system(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &SystemStmt,
+    ) -> Result<Plan> {
         let SystemStmt { action } = stmt;
         match action {
             AstSystemAction::Backtrace(switch) => Ok(Plan::System(Box::new(SystemPlan {
                 action: SystemAction::Backtrace(*switch),
             }))),
+            AstSystemAction::CancelAllQueries { warehouse } => {
+                Ok(Plan::System(Box::new(SystemPlan {
+                    action: SystemAction::CancelAllQueries {
+                        warehouse: warehouse.clone(),
+                    },
+                })))
+            }
+            AstSystemAction::SuspendNode { node_id } => Ok(Plan::System(Box::new(SystemPlan {
+                action: SystemAction::SuspendNode {
+                    node_id: node_id.clone(),
+                },
+            }))),
+            AstSystemAction::ResumeNode { node_id } => Ok(Plan::System(Box::new(SystemPlan {
+                action: SystemAction::ResumeNode {
+                    node_id: node_id.clone(),
+                },
+            }))),
+            AstSystemAction::ReplayQuery { query_id } => {
+                self.bind_replay_query(bind_context, query_id).await
+            }
         }
     }
+
+    /// Binds the query of a replay bundle in place of `SYSTEM REPLAY QUERY`, with the
+    /// captured settings applied to the current query, the tables pinned to the captured
+    /// snapshots and the views read as they were defined.
+    #[async_backtrace::framed]
+    async fn bind_replay_query(
+        &mut self,
+        bind_context: &mut BindContext,
+        query_id: &str,
+    ) -> Result<Plan> {
+        let tenant = self.ctx.get_tenant();
+        let op = DataOperator::instance().operator();
+        let bundle = QueryReplayBundle::read(&op, tenant.tenant_name(), query_id).await?;
+
+        let settings = self.ctx.get_settings();
+        settings.set_hint_settings(&bundle.settings.clone().into_iter().collect())?;
+        let sql_dialect = settings.get_sql_dialect()?;
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+
+        let tokens = tokenize_sql(&bundle.sql)?;
+        let (mut stmt, _) = parse_sql(&tokens, sql_dialect)?;
+        let is_query = match &stmt {
+            Statement::StatementWithSettings { stmt, .. } => {
+                matches!(stmt.as_ref(), Statement::Query(_))
+            }
+            stmt => matches!(stmt, Statement::Query(_)),
+        };
+        if !is_query {
+            return Err(ErrorCode::BadArguments(format!(
+                "query {} is not a SELECT query, only the SELECT queries can be replayed",
+                query_id
+            )));
+        }
+
+        bundle.pin_snapshots(&mut stmt, &name_resolution_ctx, sql_dialect)?;
+        info!("Replay query {} as {}", query_id, stmt);
+        self.bind_statement(bind_context, &stmt).await
+    }
 }
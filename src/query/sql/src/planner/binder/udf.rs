@@ -22,7 +22,7 @@ use databend_common_ast::ast::UDFDefinition;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
-use databend_common_expression::udf_client::UDFFlightClient;
+use databend_common_expression::udf_server_pool::UDFServerPool;
 use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition as PlanUDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
@@ -88,21 +88,22 @@ impl Binder {
                 }
                 let return_type = DataType::from(&resolve_type_name(return_type, true)?);
 
-                let mut client = UDFFlightClient::connect(
-                    address,
-                    self.ctx
-                        .get_settings()
-                        .get_external_server_connect_timeout_secs()?,
-                    self.ctx
-                        .get_settings()
-                        .get_external_server_request_timeout_secs()?,
-                    self.ctx
-                        .get_settings()
-                        .get_external_server_request_batch_rows()?,
-                )
-                .await?;
-                client
-                    .check_schema(handler, &arg_datatypes, &return_type)
+                let settings = self.ctx.get_settings();
+                UDFServerPool::instance()
+                    .with_client(
+                        address,
+                        settings.get_external_server_connect_timeout_secs()?,
+                        settings.get_external_server_request_timeout_secs()?,
+                        settings.get_external_server_request_batch_rows()?,
+                        |mut client| {
+                            let (arg_datatypes, return_type) = (&arg_datatypes, &return_type);
+                            async move {
+                                client
+                                    .check_schema(handler, arg_datatypes, return_type)
+                                    .await
+                            }
+                        },
+                    )
                     .await?;
 
                 Ok(UserDefinedFunction {
@@ -13,9 +13,16 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::str::FromStr;
 
-use databend_common_ast::parser::parse_comma_separated_idents;
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::MapAccessor;
+use databend_common_ast::parser::parse_comma_separated_exprs;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_exception::ErrorCode;
@@ -28,6 +35,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_settings::Settings;
+use itertools::Itertools;
 
 use crate::normalize_identifier;
 use crate::planner::semantic::NameResolutionContext;
@@ -37,11 +45,113 @@ pub enum BloomIndexColumns {
     /// Default, all columns that support bloom index.
     All,
     /// Specify with column names.
-    Specify(Vec<String>),
+    Specify(Vec<BloomIndexColumn>),
     /// The column of bloom index is empty.
     None,
 }
 
+/// A column of the bloom index, which is defined like `c`, `m['key']`, `v['a']['b']`
+/// or `v:a:b`, with an optional false positive rate like `c = 0.0001`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BloomIndexColumn {
+    pub name: String,
+    /// The keys of a map column or the path of a variant column,
+    /// empty if the filter is built on the whole column.
+    pub path: Vec<String>,
+    /// The target false positive rate, use the default rate of the filter if not set.
+    pub fpr: Option<f64>,
+}
+
+impl BloomIndexColumn {
+    fn try_from_expr(expr: &Expr, name_resolution_ctx: &NameResolutionContext) -> Result<Self> {
+        match expr {
+            Expr::BinaryOp {
+                op: BinaryOperator::Eq,
+                left,
+                right: box Expr::Literal { value, .. },
+                ..
+            } => {
+                let mut column = Self::try_from_expr(left, name_resolution_ctx)?;
+                if column.fpr.is_some() {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "Invalid bloom index column '{expr}'"
+                    )));
+                }
+                let fpr = match value {
+                    Literal::UInt64(_) | Literal::Float64(_) | Literal::Decimal256 { .. } => {
+                        value.to_string().parse::<f64>().ok()
+                    }
+                    _ => None,
+                };
+                match fpr {
+                    Some(fpr) if fpr > 0.0 && fpr < 1.0 => column.fpr = Some(fpr),
+                    _ => {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "The false positive rate of bloom index column must be between 0 and 1, but got '{value}'"
+                        )));
+                    }
+                }
+                Ok(column)
+            }
+            Expr::ColumnRef { column, .. } if column.database.is_none() => match &column.column {
+                ColumnID::Name(ident) if column.table.is_none() => Ok(Self {
+                    name: normalize_identifier(ident, name_resolution_ctx).name,
+                    path: vec![],
+                    fpr: None,
+                }),
+                _ => Err(ErrorCode::TableOptionInvalid(format!(
+                    "Invalid bloom index column '{expr}'"
+                ))),
+            },
+            Expr::MapAccess {
+                expr: inner,
+                accessor,
+                ..
+            } => {
+                let mut column = Self::try_from_expr(inner, name_resolution_ctx)?;
+                let key = match accessor {
+                    MapAccessor::Bracket {
+                        key:
+                            box Expr::Literal {
+                                value: Literal::String(key),
+                                ..
+                            },
+                    } => key.clone(),
+                    MapAccessor::Colon { key } => key.name.clone(),
+                    _ => {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "Invalid bloom index column '{expr}', only string keys are supported"
+                        )));
+                    }
+                };
+                if column.fpr.is_some() {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "Invalid bloom index column '{expr}'"
+                    )));
+                }
+                column.path.push(key);
+                Ok(column)
+            }
+            _ => Err(ErrorCode::TableOptionInvalid(format!(
+                "Invalid bloom index column '{expr}'"
+            ))),
+        }
+    }
+}
+
+impl Display for BloomIndexColumn {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        for key in &self.path {
+            write!(f, "['{}']", key.replace('\\', "\\\\").replace('\'', "\\'"))?;
+        }
+        if let Some(fpr) = self.fpr {
+            write!(f, " = {fpr}")?;
+        }
+        Ok(())
+    }
+}
+
 impl FromStr for BloomIndexColumns {
     type Err = ErrorCode;
 
@@ -50,23 +160,31 @@ impl FromStr for BloomIndexColumns {
             return Ok(BloomIndexColumns::None);
         }
 
+        let cols = Self::parse_columns(s)?;
+        Ok(BloomIndexColumns::Specify(cols))
+    }
+}
+
+impl BloomIndexColumns {
+    fn parse_columns(definition: &str) -> Result<Vec<BloomIndexColumn>> {
         let sql_dialect = Dialect::default();
-        let tokens = tokenize_sql(s)?;
-        let idents = parse_comma_separated_idents(&tokens, sql_dialect)?;
+        let tokens = tokenize_sql(definition)?;
+        let exprs = parse_comma_separated_exprs(&tokens, sql_dialect)?;
 
         let settings = Settings::create(Tenant::new_literal("dummy"));
         let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
 
-        let mut cols = Vec::with_capacity(idents.len());
-        idents
-            .into_iter()
-            .for_each(|ident| cols.push(normalize_identifier(&ident, &name_resolution_ctx).name));
+        exprs
+            .iter()
+            .map(|expr| BloomIndexColumn::try_from_expr(expr, &name_resolution_ctx))
+            .collect()
+    }
 
-        Ok(BloomIndexColumns::Specify(cols))
+    /// Format the columns back to the definition of the table option.
+    pub fn format_columns(cols: &[BloomIndexColumn]) -> String {
+        cols.iter().map(|col| col.to_string()).join(",")
     }
-}
 
-impl BloomIndexColumns {
     /// Verify the definition based on schema.
     pub fn verify_definition<F>(
         definition: &str,
@@ -80,14 +198,8 @@ impl BloomIndexColumns {
             return Ok(());
         }
 
-        let settings = Settings::create(Tenant::new_literal("dummy"));
-        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-
-        let sql_dialect = Dialect::default();
-        let tokens = tokenize_sql(definition)?;
-        let idents = parse_comma_separated_idents(&tokens, sql_dialect)?;
-        for ident in idents.iter() {
-            let name = &normalize_identifier(ident, &name_resolution_ctx).name;
+        for col in Self::parse_columns(definition)? {
+            let name = &col.name;
             let field = schema.field_with_name(name)?;
 
             if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
@@ -98,7 +210,7 @@ impl BloomIndexColumns {
             }
 
             let data_type = field.data_type();
-            if !verify_type(data_type) {
+            if !Self::verify_column_type(&col, data_type, &verify_type) {
                 return Err(ErrorCode::TableOptionInvalid(format!(
                     "Unsupported data type '{}' for bloom index",
                     data_type
@@ -108,6 +220,29 @@ impl BloomIndexColumns {
         Ok(())
     }
 
+    // Filters on keys are only supported for maps with string keys and variants.
+    fn verify_column_type<F>(
+        col: &BloomIndexColumn,
+        data_type: &TableDataType,
+        verify_type: F,
+    ) -> bool
+    where
+        F: Fn(&TableDataType) -> bool,
+    {
+        if col.path.is_empty() {
+            return verify_type(data_type);
+        }
+        match data_type.remove_nullable() {
+            TableDataType::Variant => true,
+            TableDataType::Map(box TableDataType::Tuple { fields_type, .. }) => {
+                fields_type[0].remove_nullable() == TableDataType::String
+                    && (col.path.len() == 1
+                        || fields_type[1].remove_nullable() == TableDataType::Variant)
+            }
+            _ => false,
+        }
+    }
+
     /// Get table field based on the BloomIndexColumns and schema.
     pub fn bloom_index_fields<F>(
         &self,
@@ -134,10 +269,10 @@ impl BloomIndexColumns {
             }
             BloomIndexColumns::Specify(cols) => {
                 for col in cols {
-                    let field_index = source_schema.index_of(col)?;
+                    let field_index = source_schema.index_of(&col.name)?;
                     let field = source_schema.fields[field_index].clone();
                     let data_type = field.data_type();
-                    if !verify_type(data_type) {
+                    if !Self::verify_column_type(col, data_type, &verify_type) {
                         return Err(ErrorCode::BadArguments(format!(
                             "Unsupported data type for bloom index: {:?}",
                             data_type
@@ -150,4 +285,24 @@ impl BloomIndexColumns {
         }
         Ok(fields_map)
     }
+
+    /// Get the bloom index columns grouped by the field index, the fields that
+    /// are not included use the default filter on the whole column.
+    pub fn bloom_index_columns(
+        &self,
+        schema: TableSchemaRef,
+    ) -> Result<BTreeMap<FieldIndex, Vec<BloomIndexColumn>>> {
+        let mut columns_map: BTreeMap<FieldIndex, Vec<BloomIndexColumn>> = BTreeMap::new();
+        if let BloomIndexColumns::Specify(cols) = self {
+            let source_schema = schema.remove_virtual_computed_fields();
+            for col in cols {
+                let field_index = source_schema.index_of(&col.name)?;
+                columns_map
+                    .entry(field_index)
+                    .or_default()
+                    .push(col.clone());
+            }
+        }
+        Ok(columns_map)
+    }
 }
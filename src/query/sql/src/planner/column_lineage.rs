@@ -0,0 +1,185 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::BaseTableColumn;
+use crate::ColumnBinding;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::DerivedColumn;
+use crate::IndexType;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::VirtualColumn;
+
+/// A base table column that feeds an output column of a query.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineageSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub column: String,
+}
+
+/// The lineage of a single output column, `sources` is empty for
+/// columns that don't read any table, e.g. `SELECT 1`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnLineage {
+    pub output_position: usize,
+    pub output_column: String,
+    pub sources: Vec<LineageSource>,
+}
+
+/// Collect the lineage of `output_columns` by walking the plan and following
+/// the derivations of every column back to the base table columns.
+pub fn collect_column_lineage(
+    s_expr: &SExpr,
+    metadata: &MetadataRef,
+    output_columns: &[ColumnBinding],
+) -> Vec<ColumnLineage> {
+    let mut derivations = HashMap::new();
+    collect_derivations(s_expr, &mut derivations);
+
+    let metadata = metadata.read();
+    output_columns
+        .iter()
+        .enumerate()
+        .map(|(output_position, column)| {
+            let mut sources = BTreeSet::new();
+            let mut visited = ColumnSet::new();
+            resolve_sources(
+                column.index,
+                &derivations,
+                &metadata,
+                &mut visited,
+                &mut sources,
+            );
+            ColumnLineage {
+                output_position,
+                output_column: column.column_name.clone(),
+                sources: sources.into_iter().collect(),
+            }
+        })
+        .collect()
+}
+
+fn collect_derivations(s_expr: &SExpr, derivations: &mut HashMap<IndexType, ColumnSet>) {
+    match s_expr.plan() {
+        RelOperator::EvalScalar(eval_scalar) => add_items(&eval_scalar.items, derivations),
+        RelOperator::Aggregate(aggregate) => {
+            add_items(&aggregate.group_items, derivations);
+            add_items(&aggregate.aggregate_functions, derivations);
+        }
+        RelOperator::Window(window) => {
+            // Partition and order keys only shape the window, the data comes from the arguments.
+            let mut used_columns = ColumnSet::new();
+            for argument in window.arguments.iter() {
+                used_columns.insert(argument.index);
+            }
+            add_derivation(window.index, used_columns, derivations);
+            add_items(&window.arguments, derivations);
+        }
+        RelOperator::ProjectSet(project_set) => {
+            for srf in project_set.srfs.iter() {
+                add_derivation(srf.index, srf.scalar.used_columns(), derivations);
+            }
+        }
+        RelOperator::Udf(udf) => add_items(&udf.items, derivations),
+        RelOperator::UnionAll(union_all) => {
+            // The output of union reuses the column index of the left side.
+            for ((left, _), (right, _)) in union_all
+                .left_outputs
+                .iter()
+                .zip(union_all.right_outputs.iter())
+            {
+                add_derivation(*left, ColumnSet::from([*right]), derivations);
+            }
+        }
+        _ => {}
+    }
+
+    for child in s_expr.children() {
+        collect_derivations(child, derivations);
+    }
+}
+
+fn add_items(items: &[ScalarItem], derivations: &mut HashMap<IndexType, ColumnSet>) {
+    for item in items.iter() {
+        add_derivation(item.index, item.scalar.used_columns(), derivations);
+    }
+}
+
+fn add_derivation(
+    index: IndexType,
+    used_columns: ColumnSet,
+    derivations: &mut HashMap<IndexType, ColumnSet>,
+) {
+    derivations.entry(index).or_default().extend(used_columns);
+}
+
+fn resolve_sources(
+    index: IndexType,
+    derivations: &HashMap<IndexType, ColumnSet>,
+    metadata: &Metadata,
+    visited: &mut ColumnSet,
+    sources: &mut BTreeSet<LineageSource>,
+) {
+    if !visited.insert(index) || index >= metadata.columns().len() {
+        return;
+    }
+
+    let mut inputs = derivations.get(&index).cloned().unwrap_or_default();
+    match metadata.column(index) {
+        ColumnEntry::BaseTableColumn(BaseTableColumn {
+            table_index,
+            column_name,
+            ..
+        }) => {
+            sources.insert(lineage_source(metadata, *table_index, column_name));
+            return;
+        }
+        ColumnEntry::VirtualColumn(VirtualColumn {
+            table_index,
+            source_column_name,
+            ..
+        }) => {
+            sources.insert(lineage_source(metadata, *table_index, source_column_name));
+            return;
+        }
+        ColumnEntry::DerivedColumn(DerivedColumn {
+            scalar_expr: Some(scalar_expr),
+            ..
+        }) => inputs.extend(scalar_expr.used_columns()),
+        _ => {}
+    }
+
+    for input in inputs {
+        resolve_sources(input, derivations, metadata, visited, sources);
+    }
+}
+
+fn lineage_source(metadata: &Metadata, table_index: IndexType, column: &str) -> LineageSource {
+    let table = metadata.table(table_index);
+    LineageSource {
+        catalog: table.catalog().to_string(),
+        database: table.database().to_string(),
+        table: table.name().to_string(),
+        column: column.to_string(),
+    }
+}
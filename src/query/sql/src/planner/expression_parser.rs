@@ -281,6 +281,62 @@ pub fn parse_default_expr_to_string(
     Ok(expr.sql_display())
 }
 
+/// Parses the default expression of an added column, which is not a constant, e.g. `uuid()`
+/// or an expression over the other columns of the table. The existing rows are backfilled with
+/// it in the background.
+pub fn parse_backfill_expr_to_string(
+    ctx: Arc<dyn TableContext>,
+    table_schema: TableSchemaRef,
+    field: &TableField,
+    ast: &AExpr,
+) -> Result<String> {
+    let settings = Settings::create(Tenant::new_literal("dummy"));
+    let mut bind_context = BindContext::new();
+    let mut metadata = Metadata::default();
+    for (index, field) in table_schema.fields().iter().enumerate() {
+        bind_context.add_column_binding(
+            ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(field.data_type().into()),
+                Visibility::Visible,
+            )
+            .build(),
+        );
+        metadata.add_base_table_column(
+            field.name().clone(),
+            field.data_type().clone(),
+            0,
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut type_checker = TypeChecker::try_create(
+        &mut bind_context,
+        ctx,
+        &name_resolution_ctx,
+        Arc::new(RwLock::new(metadata)),
+        &[],
+        false,
+    )?;
+
+    let (scalar, _) = *type_checker.resolve(ast)?;
+    let schema_data_type = DataType::from(field.data_type());
+    // Checks the values can be cast to the column.
+    wrap_cast(&scalar, &schema_data_type).as_expr()?;
+
+    let mut ast = ast.clone();
+    let mut normalizer = IdentifierNormalizer {
+        ctx: &name_resolution_ctx,
+    };
+    ast.drive_mut(&mut normalizer);
+    Ok(format!("{:#}", ast))
+}
+
 pub fn parse_computed_expr_to_string(
     ctx: Arc<dyn TableContext>,
     table_schema: TableSchemaRef,
@@ -70,6 +70,9 @@ impl Plan {
             Plan::DropDatabase(_) => Ok("DropDatabase".to_string()),
             Plan::UndropDatabase(_) => Ok("UndropDatabase".to_string()),
             Plan::RenameDatabase(_) => Ok("RenameDatabase".to_string()),
+            Plan::SetDatabaseOptions(_) => Ok("SetDatabaseOptions".to_string()),
+            Plan::ExportDatabase(_) => Ok("ExportDatabase".to_string()),
+            Plan::ImportDatabase(_) => Ok("ImportDatabase".to_string()),
 
             // Tables
             Plan::CreateTable(create_table) => format_create_table(create_table),
@@ -78,8 +81,11 @@ impl Plan {
             Plan::UndropTable(_) => Ok("UndropTable".to_string()),
             Plan::DescribeTable(_) => Ok("DescribeTable".to_string()),
             Plan::RenameTable(_) => Ok("RenameTable".to_string()),
+            Plan::SwapTable(_) => Ok("SwapTable".to_string()),
             Plan::ModifyTableComment(_) => Ok("ModifyTableComment".to_string()),
             Plan::SetOptions(_) => Ok("SetOptions".to_string()),
+            Plan::AlterTableTags(_) => Ok("AlterTableTags".to_string()),
+            Plan::AlterTableStoragePolicy(_) => Ok("AlterTableStoragePolicy".to_string()),
             Plan::RenameTableColumn(_) => Ok("RenameTableColumn".to_string()),
             Plan::AddTableColumn(_) => Ok("AddTableColumn".to_string()),
             Plan::ModifyTableColumn(_) => Ok("ModifyTableColumn".to_string()),
@@ -92,7 +98,11 @@ impl Plan {
             Plan::VacuumTable(_) => Ok("VacuumTable".to_string()),
             Plan::VacuumDropTable(_) => Ok("VacuumDropTable".to_string()),
             Plan::VacuumTemporaryFiles(_) => Ok("VacuumTemporaryFiles".to_string()),
+            Plan::VacuumSystemHistory(_) => Ok("VacuumSystemHistory".to_string()),
             Plan::AnalyzeTable(_) => Ok("AnalyzeTable".to_string()),
+            Plan::VerifyTable(_) => Ok("VerifyTable".to_string()),
+            Plan::RefreshTableStatistics(_) => Ok("RefreshTableStatistics".to_string()),
+            Plan::RestoreTable(_) => Ok("RestoreTable".to_string()),
             Plan::ExistsTable(_) => Ok("ExistsTable".to_string()),
 
             // Views
@@ -153,6 +163,7 @@ impl Plan {
             Plan::AlterUser(_) => Ok("AlterUser".to_string()),
             Plan::CreateRole(_) => Ok("CreateRole".to_string()),
             Plan::DropRole(_) => Ok("DropRole".to_string()),
+            Plan::AlterRole(_) => Ok("AlterRole".to_string()),
             Plan::Presign(_) => Ok("Presign".to_string()),
 
             Plan::SetVariable(_) => Ok("SetVariable".to_string()),
@@ -161,6 +172,7 @@ impl Plan {
             Plan::SetSecondaryRoles(_) => Ok("SetSecondaryRoles".to_string()),
             Plan::UseDatabase(_) => Ok("UseDatabase".to_string()),
             Plan::Kill(_) => Ok("Kill".to_string()),
+            Plan::KillWhere(_) => Ok("KillWhere".to_string()),
 
             Plan::CreateShareEndpoint(_) => Ok("CreateShareEndpoint".to_string()),
             Plan::ShowShareEndpoint(_) => Ok("ShowShareEndpoint".to_string()),
@@ -195,6 +207,11 @@ impl Plan {
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
 
+            // tag
+            Plan::CreateTag(_) => Ok("CreateTag".to_string()),
+            Plan::AlterTag(_) => Ok("AlterTag".to_string()),
+            Plan::DropTag(_) => Ok("DropTag".to_string()),
+
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
             Plan::DropTask(_) => Ok("DropTask".to_string()),
@@ -227,6 +244,7 @@ impl Plan {
 
             Plan::SetPriority(_) => Ok("SetPriority".to_string()),
             Plan::System(_) => Ok("System".to_string()),
+            Plan::ShowWarnings => Ok("ShowWarnings".to_string()),
         }
     }
 }
@@ -269,6 +287,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             columns: Default::default(),
             push_down_predicates: None,
             limit: None,
+            filter_limit: None,
             order_by: None,
             prewhere: None,
             agg_index: None,
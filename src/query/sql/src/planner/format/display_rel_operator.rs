@@ -205,6 +205,7 @@ fn format_join(op: &Join) -> String {
         JoinType::RightSemi => "RightSemi".to_string(),
         JoinType::LeftAnti => "LeftAnti".to_string(),
         JoinType::RightAnti => "RightAnti".to_string(),
+        JoinType::LeftAntiNullAware => "LeftAntiNullAware".to_string(),
         JoinType::LeftMark => "LeftMark".to_string(),
         JoinType::RightMark => "RightMark".to_string(),
         JoinType::LeftSingle => "LeftSingle".to_string(),
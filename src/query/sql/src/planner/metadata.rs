@@ -180,6 +180,45 @@ impl Metadata {
             .collect()
     }
 
+    /// Returns the columns of the primary and unique keys of the table which are declared
+    /// with `RELY`, the optimizer can assume these columns are unique. Keys with nullable
+    /// columns are skipped, since they can contain duplicated NULLs, the columns of a primary
+    /// key created before they were required to be NOT NULL may still be nullable.
+    pub fn unique_keys_by_table_index(&self, index: IndexType) -> Vec<Vec<IndexType>> {
+        let table = self.table(index).table();
+        let schema = table.schema();
+        let columns = self.columns_by_table_index(index);
+        table
+            .get_table_info()
+            .meta
+            .constraints
+            .values()
+            .filter(|constraint| constraint.rely && constraint.is_unique_key())
+            .filter_map(|constraint| {
+                constraint
+                    .column_ids
+                    .iter()
+                    .map(|column_id| {
+                        let field = schema.field_of_column_id(*column_id).ok()?;
+                        if field.is_nullable() {
+                            return None;
+                        }
+                        columns.iter().find_map(|column| match column {
+                            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                                column_index,
+                                column_name,
+                                path_indices: None,
+                                virtual_computed_expr: None,
+                                ..
+                            }) if column_name == field.name() => Some(*column_index),
+                            _ => None,
+                        })
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect()
+    }
+
     pub fn virtual_columns_by_table_index(&self, index: IndexType) -> Vec<ColumnEntry> {
         self.columns
             .iter()
@@ -296,6 +335,10 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn has_agg_indexes(&self) -> bool {
+        !self.agg_indexes.is_empty()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
@@ -326,20 +369,23 @@ impl Metadata {
         };
         self.tables.push(table_entry);
         let table_schema = table_meta.schema_with_stream();
+        let nested_projection = table_meta.support_nested_projection();
         let mut index = 0;
         let mut fields = VecDeque::with_capacity(table_schema.fields().len());
         for field in table_schema.fields().iter() {
             if let Some(ComputedExpr::Virtual(_)) = field.computed_expr() {
-                fields.push_back((vec![], field.clone()));
+                fields.push_back((vec![], field.clone(), false));
             } else {
-                fields.push_back((vec![index], field.clone()));
+                fields.push_back((vec![index], field.clone(), false));
                 index += 1;
             }
         }
 
         // build leaf index in DFS order for primitive columns.
+        // The inner fields of tuples nested in arrays don't have leaf index,
+        // as they are part of the leaf columns of the array.
         let mut leaf_index = 0;
-        while let Some((indices, field)) = fields.pop_front() {
+        while let Some((indices, field, in_array)) = fields.pop_front() {
             if indices.is_empty() {
                 self.add_base_table_column(
                     field.name().clone(),
@@ -358,7 +404,6 @@ impl Metadata {
                 None
             };
 
-            // TODO handle Tuple inside Array.
             if let TableDataType::Tuple {
                 fields_name,
                 fields_type,
@@ -388,7 +433,7 @@ impl Metadata {
                         display_tuple_field_name(inner_field_name)
                     );
                     let inner_field = TableField::new(&inner_name, inner_field_type.clone());
-                    fields.push_front((inner_indices, inner_field));
+                    fields.push_front((inner_indices, inner_field, in_array));
                 }
             } else {
                 self.add_base_table_column(
@@ -396,11 +441,36 @@ impl Metadata {
                     field.data_type().clone(),
                     table_index,
                     path_indices,
-                    Some(leaf_index),
+                    if in_array { None } else { Some(leaf_index) },
                     Some(indices[0] + 1),
                     None,
                 );
-                leaf_index += 1;
+                if !in_array {
+                    leaf_index += 1;
+                }
+
+                // create inner fields of tuple nested in array, like `a:b` of `Array(Tuple(b, c))`.
+                if let Some((fields_name, fields_type)) = field
+                    .data_type()
+                    .array_tuple_fields()
+                    .filter(|_| nested_projection)
+                {
+                    let mut i = fields_type.len();
+                    for (inner_field_name, inner_field_type) in
+                        fields_name.iter().zip(fields_type.into_iter()).rev()
+                    {
+                        i -= 1;
+                        let mut inner_indices = indices.clone();
+                        inner_indices.push(i);
+                        let inner_name = format!(
+                            "{}:{}",
+                            field.name(),
+                            display_tuple_field_name(inner_field_name)
+                        );
+                        let inner_field = TableField::new(&inner_name, inner_field_type);
+                        fields.push_front((inner_indices, inner_field, true));
+                    }
+                }
             }
         }
 
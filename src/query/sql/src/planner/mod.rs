@@ -13,10 +13,12 @@
 // limitations under the License.
 
 mod bloom_index;
+mod column_lineage;
 mod format;
 mod metadata;
 #[allow(clippy::module_inception)]
 mod planner;
+mod query_replay;
 mod semantic;
 
 pub mod binder;
@@ -35,7 +37,11 @@ pub use binder::ColumnBindingBuilder;
 pub use binder::ScalarBinder;
 pub use binder::SelectBuilder;
 pub use binder::Visibility;
+pub use bloom_index::BloomIndexColumn;
 pub use bloom_index::BloomIndexColumns;
+pub use column_lineage::collect_column_lineage;
+pub use column_lineage::ColumnLineage;
+pub use column_lineage::LineageSource;
 pub use expression_parser::*;
 pub use format::format_scalar;
 pub use metadata::*;
@@ -47,5 +53,9 @@ pub use plans::ScalarExpr;
 pub use plans::DELETE_NAME;
 pub use plans::INSERT_NAME;
 pub use plans::UPDATE_NAME;
+pub use query_replay::QueryReplayBundle;
+pub use query_replay::QueryReplayTable;
+pub use query_replay::QueryReplayView;
+pub use query_replay::QUERY_REPLAY_PREFIX;
 pub use semantic::*;
 pub use stream_column::*;
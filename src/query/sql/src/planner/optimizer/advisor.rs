@@ -0,0 +1,269 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_expression::RemoteExpr;
+
+use super::SExpr;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::BindContext;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// The number of output columns above which a query is considered to select too many columns.
+pub const ADVISOR_MAX_OUTPUT_COLUMNS: usize = 500;
+
+const COMPARISON_FUNCTIONS: [&str; 6] = ["eq", "noteq", "gt", "gte", "lt", "lte"];
+
+/// The functions that keep the order of their argument, the min/max statistics of a column
+/// wrapped in them are still used to prune the blocks.
+const MONOTONIC_FUNCTIONS: &[&str] = &[
+    "plus",
+    "minus",
+    "multiply",
+    "divide",
+    "floor",
+    "ceil",
+    "to_date",
+    "to_timestamp",
+    "to_unix_timestamp",
+    "to_year",
+    "to_yyyymm",
+    "to_yyyymmdd",
+    "to_yyyymmddhh",
+    "to_yyyymmddhhmmss",
+    "to_start_of_year",
+    "to_start_of_iso_year",
+    "to_start_of_quarter",
+    "to_start_of_month",
+    "to_start_of_week",
+    "to_monday",
+    "to_start_of_day",
+    "to_start_of_hour",
+    "to_start_of_fifteen_minutes",
+    "to_start_of_ten_minutes",
+    "to_start_of_five_minutes",
+    "to_start_of_minute",
+    "to_start_of_second",
+    "add_years",
+    "add_quarters",
+    "add_months",
+    "add_days",
+    "add_hours",
+    "add_minutes",
+    "add_seconds",
+    "subtract_years",
+    "subtract_quarters",
+    "subtract_months",
+    "subtract_days",
+    "subtract_hours",
+    "subtract_minutes",
+    "subtract_seconds",
+];
+
+/// Looks for the known anti-patterns in an optimized query, and pushes a warning for each
+/// of them to the query context, which are returned with the result of the query and by
+/// `SHOW WARNINGS`.
+pub fn advise_query(
+    ctx: &Arc<dyn TableContext>,
+    metadata: &MetadataRef,
+    s_expr: &SExpr,
+    bind_context: &BindContext,
+) {
+    let mut advisor = QueryAdvisor {
+        ctx: ctx.clone(),
+        metadata: metadata.clone(),
+        warnings: vec![],
+    };
+
+    let output_columns = bind_context.columns.len();
+    if output_columns > ADVISOR_MAX_OUTPUT_COLUMNS {
+        advisor.warn(format!(
+            "the query returns {} columns, which is more than {}, consider selecting only the needed columns instead of SELECT *",
+            output_columns, ADVISOR_MAX_OUTPUT_COLUMNS
+        ));
+    }
+    advisor.visit(s_expr, false);
+
+    for warning in advisor.warnings {
+        ctx.push_warning(warning);
+    }
+}
+
+struct QueryAdvisor {
+    ctx: Arc<dyn TableContext>,
+    metadata: MetadataRef,
+    warnings: Vec<String>,
+}
+
+impl QueryAdvisor {
+    fn warn(&mut self, warning: String) {
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    fn visit(&mut self, s_expr: &SExpr, under_join: bool) {
+        let mut under_join = under_join;
+        match s_expr.plan() {
+            RelOperator::Join(join) => {
+                if join.join_type == JoinType::Cross {
+                    let mut tables = vec![];
+                    self.collect_scanned_tables(s_expr, &mut tables);
+                    tables.sort();
+                    tables.dedup();
+                    self.warn(format!(
+                        "cross join detected on tables {}, which produces the cartesian product of its inputs, consider adding a join condition",
+                        tables.join(", ")
+                    ));
+                }
+                under_join = true;
+            }
+            RelOperator::Scan(scan) => {
+                self.check_cluster_key_predicates(scan);
+                if under_join {
+                    self.check_missing_stats(scan);
+                }
+            }
+            _ => {}
+        }
+
+        for child in s_expr.children() {
+            self.visit(child, under_join);
+        }
+    }
+
+    fn collect_scanned_tables(&self, s_expr: &SExpr, tables: &mut Vec<String>) {
+        if let RelOperator::Scan(scan) = s_expr.plan() {
+            let metadata = self.metadata.read();
+            let table = metadata.table(scan.table_index);
+            tables.push(format!("{}.{}", table.database(), table.name()));
+        }
+        for child in s_expr.children() {
+            self.collect_scanned_tables(child, tables);
+        }
+    }
+
+    /// A cluster key column wrapped in a non-monotonic function in a comparison can not be
+    /// used to prune the blocks by their min/max statistics.
+    fn check_cluster_key_predicates(&mut self, scan: &Scan) {
+        let Some(predicates) = &scan.push_down_predicates else {
+            return;
+        };
+
+        let (table_name, cluster_keys) = {
+            let metadata = self.metadata.read();
+            let entry = metadata.table(scan.table_index);
+            let cluster_keys = entry
+                .table()
+                .cluster_keys(self.ctx.clone())
+                .into_iter()
+                .filter_map(|key| match key {
+                    RemoteExpr::ColumnRef { id, .. } => Some(id),
+                    _ => None,
+                })
+                .collect::<HashSet<_>>();
+            (
+                format!("{}.{}", entry.database(), entry.name()),
+                cluster_keys,
+            )
+        };
+        if cluster_keys.is_empty() {
+            return;
+        }
+
+        for predicate in predicates {
+            let mut columns = vec![];
+            collect_wrapped_columns(predicate, &mut columns);
+            for column in columns {
+                let metadata = self.metadata.read();
+                let entry = metadata.column(column);
+                let name = entry.name();
+                if entry.table_index() == Some(scan.table_index) && cluster_keys.contains(&name) {
+                    drop(metadata);
+                    self.warn(format!(
+                        "the cluster key column {} of table {} is wrapped in a non-monotonic function in a filter, which prevents the blocks from being pruned by the cluster key",
+                        name, table_name
+                    ));
+                }
+            }
+        }
+    }
+
+    /// The join order and the join sides are chosen by the statistics of the columns, which
+    /// are missing for the columns without min/max values in the snapshot.
+    fn check_missing_stats(&mut self, scan: &Scan) {
+        let has_rows = scan
+            .statistics
+            .table_stats
+            .and_then(|stats| stats.num_rows)
+            .is_some_and(|rows| rows > 0);
+        if !has_rows {
+            return;
+        }
+
+        let missing = scan.statistics.column_stats.values().any(Option::is_none);
+        if missing {
+            let table_name = {
+                let metadata = self.metadata.read();
+                let entry = metadata.table(scan.table_index);
+                format!("{}.{}", entry.database(), entry.name())
+            };
+            self.warn(format!(
+                "statistics are missing for some columns of table {}, the join may be planned poorly, consider running ANALYZE TABLE {}",
+                table_name, table_name
+            ));
+        }
+    }
+}
+
+/// Collects the columns that are wrapped in a non-monotonic function which is an operand of
+/// a comparison.
+fn collect_wrapped_columns(scalar: &ScalarExpr, columns: &mut Vec<usize>) {
+    match scalar {
+        ScalarExpr::FunctionCall(func)
+            if COMPARISON_FUNCTIONS.contains(&func.func_name.as_str()) =>
+        {
+            for arg in &func.arguments {
+                collect_non_monotonic_columns(arg, columns);
+            }
+        }
+        ScalarExpr::FunctionCall(func) => {
+            for arg in &func.arguments {
+                collect_wrapped_columns(arg, columns);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_non_monotonic_columns(scalar: &ScalarExpr, columns: &mut Vec<usize>) {
+    match scalar {
+        ScalarExpr::FunctionCall(func)
+            if MONOTONIC_FUNCTIONS.contains(&func.func_name.as_str()) =>
+        {
+            for arg in &func.arguments {
+                collect_non_monotonic_columns(arg, columns);
+            }
+        }
+        ScalarExpr::CastExpr(cast) => collect_non_monotonic_columns(&cast.argument, columns),
+        ScalarExpr::FunctionCall(_) => columns.extend(scalar.used_columns()),
+        _ => {}
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::unique_keys;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateMode;
+use crate::plans::EvalScalar;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::ColumnSet;
+use crate::MetadataRef;
+
+/// Remove the aggregates without aggregate functions whose group keys contain a unique key
+/// of the input, since every group only has one row:
+///
+/// Input:  Aggregate(group by pk, a)
+///           \
+///            Scan(t, primary key (pk) rely)
+///
+/// Output: EvalScalar(pk, a)
+///           \
+///            Scan(t, primary key (pk) rely)
+///
+/// The unique keys come from the table constraints declared with `RELY`.
+pub struct EliminateDistinctOptimizer {
+    metadata: MetadataRef,
+}
+
+impl EliminateDistinctOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        EliminateDistinctOptimizer { metadata }
+    }
+
+    pub fn run(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let child = self.run(child)?;
+            children.push(Arc::new(child));
+        }
+        let s_expr = s_expr.replace_children(children);
+        if let RelOperator::Aggregate(_) = s_expr.plan.as_ref() {
+            self.eliminate_distinct(&s_expr)
+        } else {
+            Ok(s_expr)
+        }
+    }
+
+    fn eliminate_distinct(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let aggregate: Aggregate = s_expr.plan().clone().try_into()?;
+        if aggregate.mode != AggregateMode::Initial
+            || aggregate.grouping_sets.is_some()
+            || !aggregate.aggregate_functions.is_empty()
+        {
+            return Ok(s_expr.clone());
+        }
+
+        let group_columns = aggregate
+            .group_items
+            .iter()
+            .filter_map(|item| match &item.scalar {
+                ScalarExpr::BoundColumnRef(column) => Some(column.column.index),
+                _ => None,
+            })
+            .collect::<ColumnSet>();
+        let child = s_expr.child(0)?;
+        if !unique_keys(child, &self.metadata)
+            .iter()
+            .any(|key| key.iter().all(|index| group_columns.contains(index)))
+        {
+            return Ok(s_expr.clone());
+        }
+
+        let eval_scalar = EvalScalar {
+            items: aggregate.group_items,
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(eval_scalar.into()),
+            Arc::new(child.clone()),
+        ))
+    }
+}
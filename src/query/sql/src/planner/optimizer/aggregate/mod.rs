@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod eliminate_distinct;
 mod normalize_aggregate;
+mod split_distinct_aggregate;
 
+pub use eliminate_distinct::EliminateDistinctOptimizer;
 pub use normalize_aggregate::RuleNormalizeAggregateOptimizer;
+pub use split_distinct_aggregate::SplitDistinctAggregateOptimizer;
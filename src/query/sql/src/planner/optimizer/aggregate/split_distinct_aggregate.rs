@@ -0,0 +1,195 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateMode;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::IndexType;
+
+/// Rewrite distinct aggregates on a single column into a pre-aggregation on the
+/// group keys and the distinct column, followed by the non-distinct aggregates:
+///
+/// Input:  Aggregate(group by k, count_distinct(v))
+///           \
+///            *
+///
+/// Output: Aggregate(group by k, count(v))
+///           \
+///          Aggregate(group by k, v)
+///             \
+///              *
+///
+/// The distinct values are then deduplicated by a regular aggregate, which is shuffled by
+/// `(k, v)` in cluster mode, instead of being kept in a hash set for each group.
+/// It's only chosen if the estimated number of distinct values per group reaches `threshold`.
+pub struct SplitDistinctAggregateOptimizer {
+    threshold: u64,
+}
+
+impl SplitDistinctAggregateOptimizer {
+    pub fn new(threshold: u64) -> Self {
+        SplitDistinctAggregateOptimizer { threshold }
+    }
+
+    pub fn run(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let child = self.run(child)?;
+            children.push(Arc::new(child));
+        }
+        let s_expr = s_expr.replace_children(children);
+        if self.threshold == 0 {
+            return Ok(s_expr);
+        }
+        if let RelOperator::Aggregate(_) = s_expr.plan.as_ref() {
+            self.split_distinct_aggregate(&s_expr)
+        } else {
+            Ok(s_expr)
+        }
+    }
+
+    fn split_distinct_aggregate(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let aggregate: Aggregate = s_expr.plan().clone().try_into()?;
+        if aggregate.mode != AggregateMode::Initial
+            || aggregate.grouping_sets.is_some()
+            || aggregate.aggregate_functions.is_empty()
+            || !aggregate
+                .group_items
+                .iter()
+                .all(|item| matches!(item.scalar, ScalarExpr::BoundColumnRef(_)))
+        {
+            return Ok(s_expr.clone());
+        }
+
+        // All the aggregate functions must be distinct on the same column.
+        let mut distinct_column: Option<&ScalarExpr> = None;
+        for item in aggregate.aggregate_functions.iter() {
+            let ScalarExpr::AggregateFunction(function) = &item.scalar else {
+                return Ok(s_expr.clone());
+            };
+            if Self::non_distinct_name(&function.func_name).is_none()
+                || function.args.len() != 1
+                || !matches!(function.args[0], ScalarExpr::BoundColumnRef(_))
+            {
+                return Ok(s_expr.clone());
+            }
+            match distinct_column {
+                Some(column) if column != &function.args[0] => return Ok(s_expr.clone()),
+                _ => distinct_column = Some(&function.args[0]),
+            }
+        }
+        let Some(ScalarExpr::BoundColumnRef(distinct_column)) = distinct_column else {
+            return Ok(s_expr.clone());
+        };
+        let distinct_index = distinct_column.column.index;
+        if aggregate
+            .group_items
+            .iter()
+            .any(|item| item.index == distinct_index)
+        {
+            return Ok(s_expr.clone());
+        }
+
+        if self.distinct_values_per_group(s_expr, &aggregate, distinct_index)?
+            < self.threshold as f64
+        {
+            return Ok(s_expr.clone());
+        }
+
+        let mut group_items = aggregate.group_items.clone();
+        group_items.push(ScalarItem {
+            scalar: ScalarExpr::BoundColumnRef(distinct_column.clone()),
+            index: distinct_index,
+        });
+        let pre_aggregate = Aggregate {
+            mode: AggregateMode::Initial,
+            group_items,
+            aggregate_functions: vec![],
+            from_distinct: false,
+            limit: None,
+            grouping_sets: None,
+        };
+
+        let aggregate_functions = aggregate
+            .aggregate_functions
+            .iter()
+            .map(|item| {
+                let mut scalar = item.scalar.clone();
+                if let ScalarExpr::AggregateFunction(function) = &mut scalar {
+                    if let Some(func_name) = Self::non_distinct_name(&function.func_name) {
+                        function.func_name = func_name.to_string();
+                    }
+                }
+                ScalarItem {
+                    scalar,
+                    index: item.index,
+                }
+            })
+            .collect();
+        let aggregate = Aggregate {
+            aggregate_functions,
+            ..aggregate
+        };
+
+        Ok(SExpr::create_unary(
+            Arc::new(aggregate.into()),
+            Arc::new(SExpr::create_unary(
+                Arc::new(pre_aggregate.into()),
+                Arc::new(s_expr.child(0)?.clone()),
+            )),
+        ))
+    }
+
+    /// `xxx(distinct ...)` is bound as `xxx_distinct(...)`, returns the name of `xxx`.
+    fn non_distinct_name(func_name: &str) -> Option<&str> {
+        func_name
+            .strip_suffix("_distinct")
+            .filter(|name| AggregateFunctionFactory::instance().contains(name))
+    }
+
+    /// Estimate the number of distinct values of the distinct column in each group.
+    fn distinct_values_per_group(
+        &self,
+        s_expr: &SExpr,
+        aggregate: &Aggregate,
+        distinct_index: IndexType,
+    ) -> Result<f64> {
+        let rel_expr = RelExpr::with_s_expr(s_expr);
+        let stat_info = rel_expr.derive_cardinality_child(0)?;
+        let cardinality = stat_info.cardinality;
+        let ndv = |index: &IndexType| {
+            stat_info
+                .statistics
+                .column_stats
+                .get(index)
+                .map_or(cardinality, |stat| stat.ndv)
+        };
+
+        let groups = aggregate
+            .group_items
+            .iter()
+            .fold(1.0, |groups, item| groups * ndv(&item.index))
+            .clamp(1.0, cardinality.max(1.0));
+        Ok(ndv(&distinct_index).min(cardinality / groups))
+    }
+}
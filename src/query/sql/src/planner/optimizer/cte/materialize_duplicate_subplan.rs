@@ -0,0 +1,693 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use parking_lot::RwLock;
+
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::aggregate::RuleNormalizeAggregateOptimizer;
+use crate::optimizer::OptimizerContext;
+use crate::optimizer::RecursiveOptimizer;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::optimizer::StatInfo;
+use crate::optimizer::DEFAULT_REWRITE_RULES;
+use crate::plans::walk_expr_mut;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::CteScan;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::MaterializedCte;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::plans::VisitorMut;
+use crate::ColumnBinding;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::Visibility;
+
+/// Materialize the identical subplans found in different branches of `UNION ALL` or of joins
+/// once, and let every branch read the materialized rows instead of scanning the tables again:
+///
+/// Input:  UnionAll
+///          /     \
+///     Agg(a)     Agg(b)
+///        |         |
+///     Filter     Filter
+///        |         |
+///     Scan(t)    Scan(t)
+///
+/// Output: MaterializedCte
+///          /        \
+///      Filter      UnionAll
+///        |         /     \
+///      Scan(t)  Agg(a)   Agg(b)
+///                 |        |
+///              CteScan  EvalScalar
+///                          |
+///                       CteScan
+///
+/// The inputs of the nested `UNION ALL`s and joins are all branches of the same query, e.g. the
+/// two sides of a self join on the same aggregated subquery share it as well.
+///
+/// Two subplans are identical if they have the same operators on the same table with the
+/// same expressions, regardless of the column indexes. The copies except the first one
+/// rename the columns of the CTE back to their own column indexes.
+///
+/// It's only applied when reading the materialized rows is estimated to be cheaper than
+/// computing the subplan again in every branch. The subplans whose scans would be rewritten to
+/// read an aggregating index are never shared, reading the index in each branch is usually
+/// cheaper.
+pub struct MaterializeDuplicateSubplanOptimizer {
+    table_ctx: Arc<dyn TableContext>,
+    metadata: MetadataRef,
+    next_cte_idx: IndexType,
+    ctes: Vec<(MaterializedCte, SExpr)>,
+    /// The table indexes of the scans in the current branches that read an aggregating index.
+    index_scans: HashSet<IndexType>,
+}
+
+struct Candidate {
+    branch: usize,
+    path: Vec<usize>,
+    s_expr: SExpr,
+    size: usize,
+}
+
+impl MaterializeDuplicateSubplanOptimizer {
+    pub fn new(table_ctx: Arc<dyn TableContext>, metadata: MetadataRef) -> Self {
+        let next_cte_idx = table_ctx
+            .get_materialized_ctes()
+            .read()
+            .keys()
+            .map(|(cte_idx, _)| cte_idx + 1)
+            .max()
+            .unwrap_or(0);
+        MaterializeDuplicateSubplanOptimizer {
+            table_ctx,
+            metadata,
+            next_cte_idx,
+            ctes: vec![],
+            index_scans: HashSet::new(),
+        }
+    }
+
+    pub fn run(mut self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut s_expr = self.rewrite(s_expr)?;
+        for (cte, cte_s_expr) in self.ctes.into_iter().rev() {
+            s_expr = SExpr::create_binary(
+                Arc::new(RelOperator::MaterializedCte(cte)),
+                Arc::new(cte_s_expr),
+                Arc::new(s_expr),
+            );
+        }
+        Ok(s_expr)
+    }
+
+    fn rewrite(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        match s_expr.plan.as_ref() {
+            plan if Self::is_branching(plan) => {
+                let mut branches = vec![];
+                Self::collect_branches(s_expr, &mut branches);
+                let branches = self.reuse_duplicates(s_expr, branches)?;
+                let mut new_branches = Vec::with_capacity(branches.len());
+                for branch in branches.iter() {
+                    new_branches.push(self.rewrite(branch)?);
+                }
+                Ok(Self::replace_branches(
+                    s_expr,
+                    &mut new_branches.into_iter(),
+                ))
+            }
+            _ => {
+                let mut children = Vec::with_capacity(s_expr.arity());
+                for child in s_expr.children() {
+                    children.push(Arc::new(self.rewrite(child)?));
+                }
+                Ok(s_expr.replace_children(children))
+            }
+        }
+    }
+
+    /// The inputs of `UNION ALL` and joins are computed independently of each other,
+    /// the recursive `UNION ALL` of a CTE reads its own results.
+    fn is_branching(plan: &RelOperator) -> bool {
+        match plan {
+            RelOperator::UnionAll(union) => union.cte_scan_names.is_empty(),
+            RelOperator::Join(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Collect the inputs of the nested `UNION ALL`s and joins.
+    fn collect_branches(s_expr: &SExpr, branches: &mut Vec<SExpr>) {
+        match s_expr.plan.as_ref() {
+            plan if Self::is_branching(plan) => {
+                for child in s_expr.children() {
+                    Self::collect_branches(child, branches);
+                }
+            }
+            _ => branches.push(s_expr.clone()),
+        }
+    }
+
+    fn replace_branches(s_expr: &SExpr, branches: &mut impl Iterator<Item = SExpr>) -> SExpr {
+        match s_expr.plan.as_ref() {
+            plan if Self::is_branching(plan) => {
+                let children = s_expr
+                    .children()
+                    .map(|child| Arc::new(Self::replace_branches(child, branches)))
+                    .collect::<Vec<_>>();
+                s_expr.replace_children(children)
+            }
+            _ => branches.next().unwrap_or_else(|| s_expr.clone()),
+        }
+    }
+
+    fn reuse_duplicates(&mut self, root: &SExpr, mut branches: Vec<SExpr>) -> Result<Vec<SExpr>> {
+        self.index_scans = self.collect_index_scans(&branches)?;
+        let mut candidates = vec![];
+        for (branch, s_expr) in branches.iter().enumerate() {
+            self.collect_candidates(s_expr, branch, &mut vec![], &mut candidates);
+        }
+        // Try the largest subplans first, the sort is stable so the candidates
+        // of the same size keep the order of the branches.
+        candidates.sort_by_key(|candidate| Reverse(candidate.size));
+
+        let mut replaced: Vec<(usize, Vec<usize>)> = vec![];
+        for (i, candidate) in candidates.iter().enumerate() {
+            if Self::overlaps(&replaced, candidate) {
+                continue;
+            }
+            let mut group = vec![(candidate, HashMap::new())];
+            for other in candidates[i + 1..].iter() {
+                if other.size != candidate.size {
+                    break;
+                }
+                if group
+                    .iter()
+                    .any(|(member, _)| member.branch == other.branch)
+                    || Self::overlaps(&replaced, other)
+                {
+                    continue;
+                }
+                let mut mapping = HashMap::new();
+                if self.match_subplan(&candidate.s_expr, &other.s_expr, &mut mapping)? {
+                    group.push((other, mapping));
+                }
+            }
+            if group.len() < 2 || !self.worth_materializing(&candidate.s_expr, group.len())? {
+                continue;
+            }
+
+            // Only materialize the columns used outside of the subplans.
+            let mut placeholders = branches.clone();
+            for (member, _) in group.iter() {
+                placeholders[member.branch] = Self::replace_at(
+                    &placeholders[member.branch],
+                    &member.path,
+                    Self::cte_scan((0, 0), &[], Arc::new(StatInfo::default())),
+                );
+            }
+            let used_columns =
+                RelExpr::with_s_expr(&Self::replace_branches(root, &mut placeholders.into_iter()))
+                    .derive_relational_prop()?
+                    .used_columns
+                    .clone();
+            let mut renames = Vec::with_capacity(group.len());
+            let mut cte_columns = ColumnSet::new();
+            for (member, mapping) in group.iter() {
+                let output_columns = RelExpr::with_s_expr(&member.s_expr)
+                    .derive_relational_prop()?
+                    .output_columns
+                    .clone();
+                let mut rename = vec![];
+                for index in output_columns.intersection(&used_columns) {
+                    let cte_column = mapping.get(index).cloned().unwrap_or(*index);
+                    cte_columns.insert(cte_column);
+                    rename.push((*index, cte_column));
+                }
+                renames.push(rename);
+            }
+            if cte_columns.is_empty() {
+                continue;
+            }
+
+            let left_output_columns = self.column_bindings(cte_columns);
+            let stat = RelExpr::with_s_expr(&candidate.s_expr).derive_cardinality()?;
+            let cte_idx = self.next_cte_idx;
+            self.next_cte_idx += 1;
+            for (used_count, ((member, _), rename)) in group.iter().zip(renames).enumerate() {
+                let cte_idx = (cte_idx, used_count + 1);
+                self.table_ctx
+                    .set_materialized_cte(cte_idx, Arc::new(RwLock::new(vec![])))?;
+                let mut cte_scan = Self::cte_scan(cte_idx, &left_output_columns, stat.clone());
+                if used_count > 0 {
+                    let items = rename
+                        .into_iter()
+                        .map(|(index, cte_column)| ScalarItem {
+                            scalar: BoundColumnRef {
+                                span: None,
+                                column: left_output_columns
+                                    .iter()
+                                    .find(|column| column.index == cte_column)
+                                    .unwrap()
+                                    .clone(),
+                            }
+                            .into(),
+                            index,
+                        })
+                        .collect();
+                    cte_scan = SExpr::create_unary(
+                        Arc::new(EvalScalar { items }.into()),
+                        Arc::new(cte_scan),
+                    );
+                }
+                branches[member.branch] =
+                    Self::replace_at(&branches[member.branch], &member.path, cte_scan);
+                replaced.push((member.branch, member.path.clone()));
+            }
+            self.ctes.push((
+                MaterializedCte {
+                    left_output_columns,
+                    cte_idx,
+                },
+                candidate.s_expr.clone(),
+            ));
+        }
+        Ok(branches)
+    }
+
+    /// Collect the subplans which can be materialized in pre-order, return the size of
+    /// the subplan if it can be materialized.
+    fn collect_candidates(
+        &self,
+        s_expr: &SExpr,
+        branch: usize,
+        path: &mut Vec<usize>,
+        candidates: &mut Vec<Candidate>,
+    ) -> Option<usize> {
+        let position = candidates.len();
+        let mut size = 1;
+        let mut reusable = self.is_reusable(s_expr.plan.as_ref());
+        for (i, child) in s_expr.children().enumerate() {
+            path.push(i);
+            match self.collect_candidates(child, branch, path, candidates) {
+                Some(child_size) => size += child_size,
+                None => reusable = false,
+            }
+            path.pop();
+        }
+        if !reusable {
+            return None;
+        }
+        candidates.insert(position, Candidate {
+            branch,
+            path: path.clone(),
+            s_expr: s_expr.clone(),
+            size,
+        });
+        Some(size)
+    }
+
+    fn is_reusable(&self, plan: &RelOperator) -> bool {
+        match plan {
+            RelOperator::Scan(scan) => {
+                if scan.push_down_predicates.is_some()
+                    || scan.limit.is_some()
+                    || scan.order_by.is_some()
+                    || scan.prewhere.is_some()
+                    || scan.agg_index.is_some()
+                    || scan.change_type.is_some()
+                    || scan.update_stream_columns
+                    || scan.inverted_index.is_some()
+                {
+                    return false;
+                }
+                let metadata = self.metadata.read();
+                let table = metadata.table(scan.table_index);
+                !table.is_consume()
+                    && table.table().table_args().is_none()
+                    && !self.index_scans.contains(&scan.table_index)
+            }
+            RelOperator::Filter(_) | RelOperator::EvalScalar(_) | RelOperator::Limit(_) => true,
+            RelOperator::Aggregate(aggregate) => {
+                aggregate.mode == AggregateMode::Initial && aggregate.grouping_sets.is_none()
+            }
+            RelOperator::Sort(sort) => {
+                sort.after_exchange.is_none()
+                    && sort.pre_projection.is_none()
+                    && sort.window_partition.is_empty()
+            }
+            _ => false,
+        }
+    }
+
+    /// Collect the scans that are rewritten to read an aggregating index if the branches are
+    /// optimized on their own, which are kept in every branch so each of them can read its
+    /// own index.
+    fn collect_index_scans(&self, branches: &[SExpr]) -> Result<HashSet<IndexType>> {
+        let mut index_scans = HashSet::new();
+        if !self.metadata.read().has_agg_indexes() {
+            return Ok(index_scans);
+        }
+
+        let opt_ctx = OptimizerContext::new(self.table_ctx.clone(), self.metadata.clone());
+        for branch in branches.iter() {
+            let s_expr = RuleNormalizeAggregateOptimizer::new().run(branch)?;
+            let s_expr = RecursiveOptimizer::new(&DEFAULT_REWRITE_RULES, &opt_ctx).run(&s_expr)?;
+            Self::collect_agg_index_scans(&s_expr, &mut index_scans);
+        }
+        Ok(index_scans)
+    }
+
+    fn collect_agg_index_scans(s_expr: &SExpr, index_scans: &mut HashSet<IndexType>) {
+        if let RelOperator::Scan(scan) = s_expr.plan.as_ref() {
+            if scan.agg_index.is_some() {
+                index_scans.insert(scan.table_index);
+            }
+        }
+        for child in s_expr.children() {
+            Self::collect_agg_index_scans(child, index_scans);
+        }
+    }
+
+    fn overlaps(replaced: &[(usize, Vec<usize>)], candidate: &Candidate) -> bool {
+        replaced.iter().any(|(branch, path)| {
+            *branch == candidate.branch
+                && (path.starts_with(&candidate.path) || candidate.path.starts_with(path))
+        })
+    }
+
+    fn replace_at(s_expr: &SExpr, path: &[usize], new_expr: SExpr) -> SExpr {
+        match path.split_first() {
+            None => new_expr,
+            Some((position, rest)) => {
+                let children = s_expr
+                    .children()
+                    .enumerate()
+                    .map(|(i, child)| {
+                        if i == *position {
+                            Arc::new(Self::replace_at(child, rest, new_expr.clone()))
+                        } else {
+                            Arc::new(child.clone())
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                s_expr.replace_children(children)
+            }
+        }
+    }
+
+    /// Check if the subplan `right` is identical to `left`, the mapping from the
+    /// column indexes of `right` to the ones of `left` is collected in `mapping`.
+    fn match_subplan(
+        &self,
+        left: &SExpr,
+        right: &SExpr,
+        mapping: &mut HashMap<IndexType, IndexType>,
+    ) -> Result<bool> {
+        if left.arity() != right.arity() {
+            return Ok(false);
+        }
+        for (left_child, right_child) in left.children().zip(right.children()) {
+            if !self.match_subplan(left_child, right_child, mapping)? {
+                return Ok(false);
+            }
+        }
+        match (left.plan.as_ref(), right.plan.as_ref()) {
+            (RelOperator::Scan(left), RelOperator::Scan(right)) => {
+                Ok(self.match_scan(left, right, mapping))
+            }
+            (RelOperator::Filter(left), RelOperator::Filter(right)) => {
+                Self::match_scalars(&left.predicates, &right.predicates, mapping)
+            }
+            (RelOperator::EvalScalar(left), RelOperator::EvalScalar(right)) => {
+                Self::match_items(&left.items, &right.items, mapping)
+            }
+            (RelOperator::Aggregate(left), RelOperator::Aggregate(right)) => Ok(left.mode
+                == right.mode
+                && left.from_distinct == right.from_distinct
+                && left.limit == right.limit
+                && Self::match_items(&left.group_items, &right.group_items, mapping)?
+                && Self::match_items(
+                    &left.aggregate_functions,
+                    &right.aggregate_functions,
+                    mapping,
+                )?),
+            (RelOperator::Sort(left), RelOperator::Sort(right)) => Ok(left.limit == right.limit
+                && left.items.len() == right.items.len()
+                && left.items.iter().zip(right.items.iter()).all(|(l, r)| {
+                    mapping.get(&r.index) == Some(&l.index)
+                        && l.asc == r.asc
+                        && l.nulls_first == r.nulls_first
+                })),
+            (RelOperator::Limit(left), RelOperator::Limit(right)) => Ok(left.limit == right.limit
+                && left.offset == right.offset
+                && left.before_exchange == right.before_exchange),
+            _ => Ok(false),
+        }
+    }
+
+    fn match_scan(
+        &self,
+        left: &Scan,
+        right: &Scan,
+        mapping: &mut HashMap<IndexType, IndexType>,
+    ) -> bool {
+        let metadata = self.metadata.read();
+        let left_table = metadata.table(left.table_index);
+        let right_table = metadata.table(right.table_index);
+        if left_table.catalog() != right_table.catalog()
+            || left_table.table().get_table_info() != right_table.table().get_table_info()
+            || left.columns.len() != right.columns.len()
+        {
+            return false;
+        }
+        for right_column in right.columns.iter() {
+            let right_entry = metadata.column(*right_column);
+            let Some(left_column) = left.columns.iter().find(|left_column| {
+                match (metadata.column(**left_column), right_entry) {
+                    (ColumnEntry::BaseTableColumn(l), ColumnEntry::BaseTableColumn(r)) => {
+                        l.column_name == r.column_name && l.path_indices == r.path_indices
+                    }
+                    (ColumnEntry::InternalColumn(l), ColumnEntry::InternalColumn(r)) => {
+                        l.internal_column.column_name == r.internal_column.column_name
+                    }
+                    (ColumnEntry::VirtualColumn(l), ColumnEntry::VirtualColumn(r)) => {
+                        l.source_column_name == r.source_column_name
+                            && l.column_name == r.column_name
+                    }
+                    _ => false,
+                }
+            }) else {
+                return false;
+            };
+            mapping.insert(*right_column, *left_column);
+        }
+        true
+    }
+
+    fn match_items(
+        left: &[ScalarItem],
+        right: &[ScalarItem],
+        mapping: &mut HashMap<IndexType, IndexType>,
+    ) -> Result<bool> {
+        if left.len() != right.len() {
+            return Ok(false);
+        }
+        for (l, r) in left.iter().zip(right.iter()) {
+            if !Self::match_scalar(&l.scalar, &r.scalar, mapping)? {
+                return Ok(false);
+            }
+            mapping.insert(r.index, l.index);
+        }
+        Ok(true)
+    }
+
+    fn match_scalars(
+        left: &[ScalarExpr],
+        right: &[ScalarExpr],
+        mapping: &HashMap<IndexType, IndexType>,
+    ) -> Result<bool> {
+        if left.len() != right.len() {
+            return Ok(false);
+        }
+        for (l, r) in left.iter().zip(right.iter()) {
+            if !Self::match_scalar(l, r, mapping)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn match_scalar(
+        left: &ScalarExpr,
+        right: &ScalarExpr,
+        mapping: &HashMap<IndexType, IndexType>,
+    ) -> Result<bool> {
+        let left = normalize_scalar(left, &HashMap::new())?;
+        let right = normalize_scalar(right, mapping)?;
+        Ok(matches!((left, right), (Some(l), Some(r)) if l == r))
+    }
+
+    /// The subplan is materialized if writing and reading the rows of the subplan
+    /// is cheaper than scanning the tables again in the other branches.
+    fn worth_materializing(&self, s_expr: &SExpr, used_count: usize) -> Result<bool> {
+        let Some(scan_rows) = Self::scan_rows(s_expr) else {
+            return Ok(false);
+        };
+        let cte_rows = RelExpr::with_s_expr(s_expr)
+            .derive_cardinality()?
+            .cardinality;
+        Ok((used_count + 1) as f64 * cte_rows < (used_count - 1) as f64 * scan_rows as f64)
+    }
+
+    fn scan_rows(s_expr: &SExpr) -> Option<u64> {
+        match s_expr.plan.as_ref() {
+            RelOperator::Scan(scan) => scan.statistics.table_stats.and_then(|s| s.num_rows),
+            _ => s_expr
+                .children()
+                .map(Self::scan_rows)
+                .try_fold(0, |acc, rows| rows.map(|rows| acc + rows)),
+        }
+    }
+
+    fn column_bindings(&self, columns: ColumnSet) -> Vec<ColumnBinding> {
+        let metadata = self.metadata.read();
+        let mut columns = columns.into_iter().collect::<Vec<_>>();
+        columns.sort();
+        columns
+            .into_iter()
+            .map(|index| {
+                let column = metadata.column(index);
+                ColumnBindingBuilder::new(
+                    column.name(),
+                    index,
+                    Box::new(column.data_type()),
+                    Visibility::Visible,
+                )
+                .build()
+            })
+            .collect()
+    }
+
+    fn cte_scan(
+        cte_idx: (IndexType, usize),
+        columns: &[ColumnBinding],
+        stat: Arc<StatInfo>,
+    ) -> SExpr {
+        let fields = columns
+            .iter()
+            .map(|column| DataField::new(&column.index.to_string(), *column.data_type.clone()))
+            .collect();
+        SExpr::create_leaf(Arc::new(
+            CteScan {
+                cte_idx,
+                fields,
+                offsets: (0..columns.len()).collect(),
+                stat,
+            }
+            .into(),
+        ))
+    }
+}
+
+/// Rewrite the column references with the mapped column indexes and drop the names,
+/// return `None` if the scalar can't be computed only once.
+fn normalize_scalar(
+    scalar: &ScalarExpr,
+    mapping: &HashMap<IndexType, IndexType>,
+) -> Result<Option<ScalarExpr>> {
+    struct NormalizeVisitor<'a> {
+        mapping: &'a HashMap<IndexType, IndexType>,
+        deterministic: bool,
+    }
+
+    impl<'a> VisitorMut<'a> for NormalizeVisitor<'_> {
+        fn visit(&mut self, expr: &'a mut ScalarExpr) -> Result<()> {
+            match expr {
+                ScalarExpr::WindowFunction(_)
+                | ScalarExpr::SubqueryExpr(_)
+                | ScalarExpr::UDFCall(_)
+                | ScalarExpr::UDFLambdaCall(_)
+                | ScalarExpr::AsyncFunctionCall(_) => {
+                    self.deterministic = false;
+                    Ok(())
+                }
+                _ => walk_expr_mut(self, expr),
+            }
+        }
+
+        fn visit_bound_column_ref(&mut self, col: &'a mut BoundColumnRef) -> Result<()> {
+            let index = self
+                .mapping
+                .get(&col.column.index)
+                .cloned()
+                .unwrap_or(col.column.index);
+            col.column = ColumnBindingBuilder::new(
+                String::new(),
+                index,
+                col.column.data_type.clone(),
+                Visibility::Visible,
+            )
+            .build();
+            Ok(())
+        }
+
+        fn visit_aggregate_function(&mut self, aggregate: &'a mut AggregateFunction) -> Result<()> {
+            aggregate.display_name = String::new();
+            for expr in &mut aggregate.args {
+                self.visit(expr)?;
+            }
+            Ok(())
+        }
+
+        fn visit_function_call(&mut self, func: &'a mut FunctionCall) -> Result<()> {
+            if BUILTIN_FUNCTIONS
+                .get_property(&func.func_name)
+                .map(|p| p.non_deterministic)
+                .unwrap_or(false)
+            {
+                self.deterministic = false;
+            }
+            for expr in &mut func.arguments {
+                self.visit(expr)?;
+            }
+            Ok(())
+        }
+    }
+
+    let mut scalar = scalar.clone();
+    let mut visitor = NormalizeVisitor {
+        mapping,
+        deterministic: true,
+    };
+    visitor.visit(&mut scalar)?;
+    Ok(visitor.deterministic.then_some(scalar))
+}
@@ -102,6 +102,15 @@ impl SubqueryRewriter {
             RelOperator::Filter(mut plan) => {
                 let mut input = self.rewrite(s_expr.child(0)?)?;
                 for pred in plan.predicates.iter_mut() {
+                    if let Some(join) = self.try_rewrite_not_in_subquery(pred, &input)? {
+                        input = join;
+                        *pred = ScalarExpr::ConstantExpr(ConstantExpr {
+                            span: None,
+                            value: Scalar::Boolean(true),
+                        });
+                        continue;
+                    }
+
                     let res = self.try_rewrite_subquery(pred, &input, true)?;
                     input = res.1;
                     *pred = res.0;
@@ -413,6 +422,93 @@ impl SubqueryRewriter {
         }
     }
 
+    /// Try to rewrite an uncorrelated `expr NOT IN (subquery)` predicate into an anti join.
+    /// The anti join is null aware if either side of the comparison is nullable, so the
+    /// predicate keeps its three-valued semantics without building a mark join.
+    fn try_rewrite_not_in_subquery(
+        &mut self,
+        predicate: &ScalarExpr,
+        left: &SExpr,
+    ) -> Result<Option<SExpr>> {
+        let ScalarExpr::FunctionCall(func) = predicate else {
+            return Ok(None);
+        };
+        if func.func_name != "not" || func.arguments.len() != 1 {
+            return Ok(None);
+        }
+        let ScalarExpr::SubqueryExpr(subquery) = &func.arguments[0] else {
+            return Ok(None);
+        };
+        if subquery.typ != SubqueryType::Any || subquery.compare_op != Some(ComparisonOp::Equal) {
+            return Ok(None);
+        }
+        let child_expr = subquery.child_expr.as_ref().unwrap();
+        if !matches!(
+            child_expr.as_ref(),
+            ScalarExpr::BoundColumnRef(_) | ScalarExpr::CastExpr(_)
+        ) {
+            return Ok(None);
+        }
+        let (probe_condition, is_non_equi_condition) =
+            check_child_expr_in_subquery(child_expr, &ComparisonOp::Equal)?;
+        if is_non_equi_condition {
+            return Ok(None);
+        }
+
+        let subquery_expr = self.rewrite(&subquery.subquery)?;
+        let prop = RelExpr::with_s_expr(&subquery_expr).derive_relational_prop()?;
+        if !prop.outer_columns.is_empty() {
+            return Ok(None);
+        }
+
+        let output_column = subquery.output_column.clone();
+        let column_name = format!("subquery_{}", output_column.index);
+        let build_condition = wrap_cast(
+            &ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: subquery.span,
+                column: ColumnBindingBuilder::new(
+                    column_name,
+                    output_column.index,
+                    output_column.data_type,
+                    Visibility::Visible,
+                )
+                .table_index(output_column.table_index)
+                .build(),
+            }),
+            &subquery.data_type,
+        );
+
+        let join_type = if probe_condition.data_type()?.is_nullable_or_null()
+            || build_condition.data_type()?.is_nullable_or_null()
+        {
+            JoinType::LeftAntiNullAware
+        } else {
+            JoinType::LeftAnti
+        };
+        let anti_join = Join {
+            equi_conditions: JoinEquiCondition::new_conditions(
+                vec![probe_condition],
+                vec![build_condition],
+                vec![],
+            ),
+            non_equi_conditions: vec![],
+            join_type,
+            marker_index: None,
+            from_correlated_subquery: false,
+            need_hold_hash_table: false,
+            is_lateral: false,
+            single_to_inner: None,
+            build_side_cache_info: None,
+        }
+        .into();
+
+        Ok(Some(SExpr::create_binary(
+            Arc::new(anti_join),
+            Arc::new(left.clone()),
+            Arc::new(subquery_expr),
+        )))
+    }
+
     fn try_rewrite_uncorrelated_subquery(
         &mut self,
         left: &SExpr,
@@ -88,9 +88,11 @@ impl PullUpFilterOptimizer {
     fn pull_up_join(&mut self, s_expr: &SExpr, join: &Join) -> Result<SExpr> {
         let (left_need_pull_up, right_need_pull_up) = match join.join_type {
             JoinType::Inner | JoinType::Cross => (true, true),
-            JoinType::Left | JoinType::LeftSingle | JoinType::LeftSemi | JoinType::LeftAnti => {
-                (true, false)
-            }
+            JoinType::Left
+            | JoinType::LeftSingle
+            | JoinType::LeftSemi
+            | JoinType::LeftAnti
+            | JoinType::LeftAntiNullAware => (true, false),
             JoinType::Right | JoinType::RightSingle | JoinType::RightSemi | JoinType::RightAnti => {
                 (false, true)
             }
@@ -12,16 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_ast::ast::FormatTreeNode;
 use databend_common_base::base::format_byte_size;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use itertools::Itertools;
+use serde_json::json;
+use serde_json::Value;
 
+use crate::optimizer::cost::CostModel;
+use crate::optimizer::cost::DefaultCostModel;
 use crate::optimizer::group::Group;
 use crate::optimizer::MExpr;
 use crate::optimizer::Memo;
+use crate::optimizer::SExpr;
 use crate::plans::Exchange;
 use crate::plans::RelOperator;
+use crate::IndexType;
 
 pub fn display_memo(memo: &Memo) -> Result<String> {
     let mem_size = format_byte_size(memo.mem_size());
@@ -37,6 +47,54 @@ pub fn display_memo(memo: &Memo) -> Result<String> {
     Ok(root.format_pretty()?)
 }
 
+/// Format the logical plan as a JSON document, with the estimated cardinality
+/// and cost of each operator.
+pub fn logical_plan_to_json(ctx: Arc<dyn TableContext>, s_expr: &SExpr) -> Result<Value> {
+    let cost_model = DefaultCostModel::new(ctx)?;
+    let mut memo = Memo::create();
+    memo.init(s_expr.clone())?;
+    let root = memo
+        .root
+        .ok_or_else(|| ErrorCode::Internal("Root group cannot be None after initialization"))?;
+
+    Ok(group_to_json(&memo, &cost_model, root)?.0)
+}
+
+// Returns the JSON of the group and its accumulated cost.
+fn group_to_json(
+    memo: &Memo,
+    cost_model: &DefaultCostModel,
+    group_index: IndexType,
+) -> Result<(Value, f64)> {
+    let group = memo.group(group_index)?;
+    let m_expr = group.m_expr(0)?;
+
+    let mut total_cost = 0.0;
+    let mut children = Vec::with_capacity(m_expr.children.len());
+    for child in m_expr.children.iter() {
+        let (child, child_cost) = group_to_json(memo, cost_model, *child)?;
+        children.push(child);
+        total_cost += child_cost;
+    }
+
+    // Some operators (e.g. `MergeInto`) have no cost, leave it as null.
+    let cost = cost_model
+        .compute_cost(memo, m_expr)
+        .ok()
+        .map(|cost| cost.0);
+    total_cost += cost.unwrap_or(0.0);
+
+    let value = json!({
+        "group": group.group_index,
+        "operator": display_rel_op(&m_expr.plan),
+        "cardinality": group.stat_info.cardinality,
+        "cost": cost,
+        "total_cost": total_cost,
+        "children": children,
+    });
+    Ok((value, total_cost))
+}
+
 pub fn display_rel_op(rel_op: &RelOperator) -> String {
     match rel_op {
         RelOperator::Scan(_) => "Scan".to_string(),
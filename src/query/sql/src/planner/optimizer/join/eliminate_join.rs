@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::unique_keys;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::ColumnSet;
+use crate::MetadataRef;
+
+/// Remove the left outer joins whose right side is joined on a unique key and none of
+/// the columns of the right side are used after the join, since every row of the left
+/// side matches at most one row of the right side:
+///
+/// Input:  Join(left, t1.fk = t2.pk)
+///           /    \
+///          t1     t2(primary key (pk) rely)
+///
+/// Output: t1
+///
+/// The unique keys come from the table constraints declared with `RELY`.
+pub struct EliminateJoinOptimizer {
+    metadata: MetadataRef,
+}
+
+impl EliminateJoinOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        EliminateJoinOptimizer { metadata }
+    }
+
+    /// `required` is the columns used by the parent of the plan, which are the output
+    /// columns of the query at the root.
+    pub fn run(&self, s_expr: &SExpr, required: &ColumnSet) -> Result<SExpr> {
+        if let RelOperator::Join(_) = s_expr.plan.as_ref() {
+            if self.can_eliminate(s_expr, required)? {
+                return self.run(s_expr.child(0)?, required);
+            }
+        }
+
+        // Only go through the operators whose used columns are known.
+        let used_columns = match s_expr.plan.as_ref() {
+            RelOperator::Filter(filter) => filter.used_columns()?,
+            RelOperator::EvalScalar(eval_scalar) => eval_scalar.used_columns()?,
+            RelOperator::Aggregate(aggregate) => aggregate.used_columns()?,
+            RelOperator::Window(window) => window.used_columns()?,
+            RelOperator::Join(join) => join.used_columns()?,
+            RelOperator::Sort(sort) => sort.items.iter().map(|item| item.index).collect(),
+            RelOperator::Limit(_) => ColumnSet::new(),
+            _ => return Ok(s_expr.clone()),
+        };
+        let required = required.union(&used_columns).cloned().collect();
+
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let child = self.run(child, &required)?;
+            children.push(Arc::new(child));
+        }
+        Ok(s_expr.replace_children(children))
+    }
+
+    fn can_eliminate(&self, s_expr: &SExpr, required: &ColumnSet) -> Result<bool> {
+        let RelOperator::Join(join) = s_expr.plan.as_ref() else {
+            return Ok(false);
+        };
+        if join.join_type != JoinType::Left
+            || join.single_to_inner.is_some()
+            || join.is_lateral
+            || join.equi_conditions.is_empty()
+        {
+            return Ok(false);
+        }
+
+        let right = s_expr.child(1)?;
+        let right_columns = RelExpr::with_s_expr(right)
+            .derive_relational_prop()?
+            .output_columns
+            .clone();
+        if !right_columns.is_disjoint(required) {
+            return Ok(false);
+        }
+
+        let join_keys = join
+            .equi_conditions
+            .iter()
+            .filter_map(|condition| match &condition.right {
+                ScalarExpr::BoundColumnRef(column) => Some(column.column.index),
+                _ => None,
+            })
+            .collect::<ColumnSet>();
+        Ok(unique_keys(right, &self.metadata)
+            .iter()
+            .any(|key| key.iter().all(|index| join_keys.contains(index))))
+    }
+}
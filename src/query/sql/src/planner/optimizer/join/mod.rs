@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod eliminate_join;
 mod single_to_inner;
 
+pub use eliminate_join::EliminateJoinOptimizer;
 pub use single_to_inner::SingleToInnerOptimizer;
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod advisor;
 mod aggregate;
 mod cascades;
 mod cost;
+mod cte;
 mod decorrelate;
 mod distributed;
 mod extract;
@@ -33,10 +35,13 @@ pub mod s_expr;
 mod statistics;
 mod util;
 
+pub use advisor::advise_query;
+pub use advisor::ADVISOR_MAX_OUTPUT_COLUMNS;
 pub use cascades::CascadesOptimizer;
 pub use decorrelate::FlattenInfo;
 pub use decorrelate::SubqueryRewriter;
 pub use extract::PatternExtractor;
+pub use format::logical_plan_to_json;
 pub use hyper_dp::DPhpy;
 pub use m_expr::MExpr;
 pub use memo::Memo;
@@ -54,3 +59,4 @@ pub use rule::DEFAULT_REWRITE_RULES;
 pub use s_expr::get_udf_names;
 pub use s_expr::SExpr;
 pub use util::contains_local_table_scan;
+pub use util::unique_keys;
@@ -29,16 +29,22 @@ use super::format::display_memo;
 use super::Memo;
 use crate::binder::target_table_position;
 use crate::binder::MergeIntoType;
+use crate::optimizer::advisor::advise_query;
+use crate::optimizer::aggregate::EliminateDistinctOptimizer;
 use crate::optimizer::aggregate::RuleNormalizeAggregateOptimizer;
+use crate::optimizer::aggregate::SplitDistinctAggregateOptimizer;
 use crate::optimizer::cascades::CascadesOptimizer;
+use crate::optimizer::cte::MaterializeDuplicateSubplanOptimizer;
 use crate::optimizer::decorrelate::decorrelate_subquery;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::distributed::SortAndLimitPushDownOptimizer;
 use crate::optimizer::filter::DeduplicateJoinConditionOptimizer;
 use crate::optimizer::filter::PullUpFilterOptimizer;
 use crate::optimizer::hyper_dp::DPhpy;
+use crate::optimizer::join::EliminateJoinOptimizer;
 use crate::optimizer::join::SingleToInnerOptimizer;
 use crate::optimizer::rule::TransformResult;
+use crate::optimizer::s_expr::find_subquery;
 use crate::optimizer::statistics::CollectStatisticsOptimizer;
 use crate::optimizer::util::contains_local_table_scan;
 use crate::optimizer::RuleFactory;
@@ -51,6 +57,7 @@ use crate::plans::JoinType;
 use crate::plans::MergeInto;
 use crate::plans::Plan;
 use crate::plans::RelOperator;
+use crate::ColumnSet;
 use crate::InsertInputSource;
 use crate::MetadataRef;
 
@@ -121,6 +128,12 @@ impl<'a> RecursiveOptimizer<'a> {
         self.optimize_expression(s_expr)
     }
 
+    /// Run the optimizer on the root of the given expression only, the expression
+    /// substituted by the rules is optimized recursively.
+    pub fn run_root(&self, s_expr: &SExpr) -> Result<SExpr> {
+        self.apply_transform_rules(s_expr, self.rules)
+    }
+
     #[recursive::recursive]
     fn optimize_expression(&self, s_expr: &SExpr) -> Result<SExpr> {
         let mut optimized_children = Vec::with_capacity(s_expr.arity());
@@ -170,14 +183,32 @@ pub async fn optimize(opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan> {
             rewrite_kind,
             formatted_ast,
             ignore_result,
-        } => Ok(Plan::Query {
-            s_expr: Box::new(optimize_query(opt_ctx, *s_expr).await?),
-            bind_context,
-            metadata,
-            rewrite_kind,
-            formatted_ast,
-            ignore_result,
-        }),
+        } => {
+            // Remove the joins only used to check the existence of unique keys, the
+            // columns not in the output of the query are not required.
+            let required = bind_context
+                .columns
+                .iter()
+                .map(|column| column.index)
+                .collect::<ColumnSet>();
+            let s_expr =
+                EliminateJoinOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr, &required)?;
+            let s_expr = optimize_query(opt_ctx.clone(), s_expr).await?;
+            advise_query(
+                &opt_ctx.table_ctx,
+                &opt_ctx.metadata,
+                &s_expr,
+                &bind_context,
+            );
+            Ok(Plan::Query {
+                s_expr: Box::new(s_expr),
+                bind_context,
+                metadata,
+                rewrite_kind,
+                formatted_ast,
+                ignore_result,
+            })
+        }
         Plan::Explain { kind, config, plan } => match kind {
             ExplainKind::Ast(_) | ExplainKind::Syntax(_) => {
                 Ok(Plan::Explain { config, kind, plan })
@@ -293,13 +324,16 @@ pub async fn optimize(opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan> {
     }
 }
 
-pub async fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<SExpr> {
-    let mut enable_distributed_query = opt_ctx.enable_distributed_optimization;
-    if contains_local_table_scan(&s_expr, &opt_ctx.metadata) {
-        enable_distributed_query = false;
-        info!("Disable distributed optimization due to local table scan.");
-    }
+pub async fn optimize_query(opt_ctx: OptimizerContext, s_expr: SExpr) -> Result<SExpr> {
+    let (s_expr, dphyp_optimized) = optimize_logical_query(&opt_ctx, s_expr).await?;
+    optimize_physical_query(&opt_ctx, s_expr, dphyp_optimized)
+}
 
+// The logical optimizations, return whether the join order is decided by dphyp.
+async fn optimize_logical_query(
+    opt_ctx: &OptimizerContext,
+    mut s_expr: SExpr,
+) -> Result<(SExpr, bool)> {
     // Decorrelate subqueries, after this step, there should be no subquery in the expression.
     if s_expr.contain_subquery() {
         s_expr = decorrelate_subquery(
@@ -314,14 +348,37 @@ pub async fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
         .run(&s_expr)
         .await?;
 
+    // Materialize the identical subplans in the branches of union all and joins once.
+    if opt_ctx
+        .table_ctx
+        .get_settings()
+        .get_enable_auto_materialized_cte()?
+    {
+        s_expr = MaterializeDuplicateSubplanOptimizer::new(
+            opt_ctx.table_ctx.clone(),
+            opt_ctx.metadata.clone(),
+        )
+        .run(&s_expr)?;
+    }
+
     // Normalize aggregate, it should be executed before RuleSplitAggregate.
     s_expr = RuleNormalizeAggregateOptimizer::new().run(&s_expr)?;
 
+    // Remove distinct on unique keys declared by table constraints.
+    s_expr = EliminateDistinctOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
+
+    // Pre-aggregate distinct aggregates with many distinct values per group.
+    let split_distinct_threshold = opt_ctx
+        .table_ctx
+        .get_settings()
+        .get_split_distinct_aggregate_threshold()?;
+    s_expr = SplitDistinctAggregateOptimizer::new(split_distinct_threshold).run(&s_expr)?;
+
     // Pull up and infer filter.
     s_expr = PullUpFilterOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
 
     // Run default rewrite rules
-    s_expr = RecursiveOptimizer::new(&DEFAULT_REWRITE_RULES, &opt_ctx).run(&s_expr)?;
+    s_expr = RecursiveOptimizer::new(&DEFAULT_REWRITE_RULES, opt_ctx).run(&s_expr)?;
 
     // Cost based optimization
     let mut dphyp_optimized = false;
@@ -340,6 +397,21 @@ pub async fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
     // Deduplicate join conditions.
     s_expr = DeduplicateJoinConditionOptimizer::new().run(&s_expr)?;
 
+    Ok((s_expr, dphyp_optimized))
+}
+
+// The cascades optimization, which decides the build sides of the joins and the distribution.
+fn optimize_physical_query(
+    opt_ctx: &OptimizerContext,
+    mut s_expr: SExpr,
+    dphyp_optimized: bool,
+) -> Result<SExpr> {
+    let mut enable_distributed_query = opt_ctx.enable_distributed_optimization;
+    if contains_local_table_scan(&s_expr, &opt_ctx.metadata) {
+        enable_distributed_query = false;
+        info!("Disable distributed optimization due to local table scan.");
+    }
+
     let mut cascades = CascadesOptimizer::new(
         opt_ctx.table_ctx.clone(),
         opt_ctx.metadata.clone(),
@@ -348,8 +420,7 @@ pub async fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
     )?;
 
     if opt_ctx.enable_join_reorder {
-        s_expr =
-            RecursiveOptimizer::new([RuleID::CommuteJoin].as_slice(), &opt_ctx).run(&s_expr)?;
+        s_expr = RecursiveOptimizer::new([RuleID::CommuteJoin].as_slice(), opt_ctx).run(&s_expr)?;
     }
 
     // Cascades optimizer may fail due to timeout, fallback to heuristic optimizer in this case.
@@ -378,7 +449,7 @@ pub async fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
     };
 
     s_expr =
-        RecursiveOptimizer::new([RuleID::EliminateEvalScalar].as_slice(), &opt_ctx).run(&s_expr)?;
+        RecursiveOptimizer::new([RuleID::EliminateEvalScalar].as_slice(), opt_ctx).run(&s_expr)?;
 
     Ok(s_expr)
 }
@@ -429,6 +500,43 @@ async fn get_optimized_memo(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
     Ok(cascades.memo)
 }
 
+/// The source of MERGE INTO can be an arbitrary query, the joins and aggregates in it are
+/// optimized on their own through the logical optimizations before the source is joined
+/// with the target table, as they would be in a standalone query. The join with the target
+/// table is only rewritten at the top, and the whole plan then goes through the cascades
+/// optimizer once, e.g. the aggregates are eagerly pushed down below the joins of the source.
+async fn optimize_merge_into_join(
+    opt_ctx: &OptimizerContext,
+    join_s_expr: &SExpr,
+    target_table_position: usize,
+) -> Result<SExpr> {
+    fn is_complex_source(s_expr: &SExpr) -> bool {
+        matches!(
+            s_expr.plan(),
+            RelOperator::Join(_) | RelOperator::Aggregate(_) | RelOperator::MaterializedCte(_)
+        ) || s_expr.children().any(is_complex_source)
+    }
+
+    let source_position = 1 - target_table_position;
+    if find_subquery(join_s_expr.plan()) || !is_complex_source(join_s_expr.child(source_position)?)
+    {
+        return optimize_query(opt_ctx.clone(), join_s_expr.clone()).await;
+    }
+
+    let mut children = Vec::with_capacity(join_s_expr.arity());
+    let mut dphyp_optimized = false;
+    for (position, child) in join_s_expr.children().enumerate() {
+        let (child, optimized) = optimize_logical_query(opt_ctx, child.clone()).await?;
+        if position == source_position {
+            dphyp_optimized = optimized;
+        }
+        children.push(Arc::new(child));
+    }
+    let s_expr = RecursiveOptimizer::new(&DEFAULT_REWRITE_RULES, opt_ctx)
+        .run_root(&join_s_expr.replace_children(children))?;
+    optimize_physical_query(opt_ctx, s_expr, dphyp_optimized)
+}
+
 async fn optimize_merge_into(mut opt_ctx: OptimizerContext, s_expr: SExpr) -> Result<Plan> {
     let mut plan: MergeInto = s_expr.plan().clone().try_into()?;
     let enable_distributed_merge_into = opt_ctx
@@ -440,7 +548,9 @@ async fn optimize_merge_into(mut opt_ctx: OptimizerContext, s_expr: SExpr) -> Re
     }
     let original_target_table_position =
         target_table_position(s_expr.child(0)?, plan.target_table_index)?;
-    let mut join_s_expr = optimize_query(opt_ctx.clone(), s_expr.child(0)?.clone()).await?;
+    let mut join_s_expr =
+        optimize_merge_into_join(&opt_ctx, s_expr.child(0)?, original_target_table_position)
+            .await?;
     if let &RelOperator::Exchange(_) = join_s_expr.plan() {
         join_s_expr = join_s_expr.child(0)?.clone();
     }
@@ -84,6 +84,57 @@ pub struct RelationalProperty {
     pub partition_orderings: Option<(Vec<ScalarItem>, Vec<SortItem>)>,
 }
 
+impl RelationalProperty {
+    /// Check if the output is already sorted by `items`. The leading items that are
+    /// `partition` columns only need to keep the rows of a partition together, so
+    /// their sequence and direction are free, this is how window sorts are satisfied.
+    pub fn satisfies_ordering(&self, items: &[SortItem], partition: &[ScalarItem]) -> bool {
+        let required = dedup_sort_items(items);
+        let partition_len = required
+            .iter()
+            .take_while(|item| partition.iter().any(|p| p.index == item.index))
+            .count();
+
+        if !partition.is_empty() {
+            if let Some((provided_partition, provided)) = &self.partition_orderings {
+                if provided_partition.len() == partition_len
+                    && provided_partition
+                        .iter()
+                        .all(|p| partition.iter().any(|item| item.index == p.index))
+                    && ordering_satisfies(provided, &required, partition_len)
+                {
+                    return true;
+                }
+            }
+        }
+
+        ordering_satisfies(&self.orderings, &required, partition_len)
+    }
+}
+
+/// Remove the items that sort by an already sorted column, they never change the order.
+fn dedup_sort_items(items: &[SortItem]) -> Vec<SortItem> {
+    let mut columns = ColumnSet::new();
+    items
+        .iter()
+        .filter(|item| columns.insert(item.index))
+        .cloned()
+        .collect()
+}
+
+fn ordering_satisfies(provided: &[SortItem], required: &[SortItem], partition_len: usize) -> bool {
+    let provided = dedup_sort_items(provided);
+    if required.is_empty() || provided.len() < required.len() {
+        return false;
+    }
+
+    required[..partition_len].iter().all(|item| {
+        provided[..partition_len]
+            .iter()
+            .any(|p| p.index == item.index)
+    }) && provided[partition_len..required.len()] == required[partition_len..]
+}
+
 #[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct PhysicalProperty {
     pub distribution: Distribution,
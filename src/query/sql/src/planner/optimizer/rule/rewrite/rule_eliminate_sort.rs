@@ -55,18 +55,9 @@ impl Rule for RuleEliminateSort {
         let rel_expr = RelExpr::with_s_expr(input);
         let prop = rel_expr.derive_relational_prop()?;
 
-        if !sort.window_partition.is_empty() {
-            if let Some((partition, ordering)) = &prop.partition_orderings {
-                if partition == &sort.window_partition && ordering == &sort.items {
-                    state.add_result(input.clone());
-                    return Ok(());
-                }
-            }
-        }
-
-        if prop.orderings == sort.items {
-            // If the derived ordering is completely equal to
-            // the sort operator, we can eliminate the sort.
+        if prop.satisfies_ordering(&sort.items, &sort.window_partition) {
+            // If the input is already sorted by the sort keys, or grouped by the window
+            // partition and sorted within it, the window can consume it as a stream.
             state.add_result(input.clone());
         }
         Ok(())
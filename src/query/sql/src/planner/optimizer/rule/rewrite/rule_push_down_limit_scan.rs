@@ -23,6 +23,7 @@ use crate::optimizer::rule::TransformResult;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
 use crate::plans::Limit;
+use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
@@ -35,6 +36,19 @@ use crate::plans::Scan;
 ///         Limit
 ///           \
 ///           Scan(padding limit)
+///
+/// Input:  Limit
+///           \
+///          Filter
+///             \
+///             Scan
+///
+/// Output:
+///         Limit
+///           \
+///          Filter
+///             \
+///             Scan(padding filter limit)
 pub struct RulePushDownLimitScan {
     id: RuleID,
     matchers: Vec<Matcher>,
@@ -44,13 +58,25 @@ impl RulePushDownLimitScan {
     pub fn new() -> Self {
         Self {
             id: RuleID::PushDownLimitScan,
-            matchers: vec![Matcher::MatchOp {
-                op_type: RelOp::Limit,
-                children: vec![Matcher::MatchOp {
-                    op_type: RelOp::Scan,
-                    children: vec![],
-                }],
-            }],
+            matchers: vec![
+                Matcher::MatchOp {
+                    op_type: RelOp::Limit,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::Scan,
+                        children: vec![],
+                    }],
+                },
+                Matcher::MatchOp {
+                    op_type: RelOp::Limit,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::Filter,
+                        children: vec![Matcher::MatchOp {
+                            op_type: RelOp::Scan,
+                            children: vec![],
+                        }],
+                    }],
+                },
+            ],
         }
     }
 }
@@ -64,12 +90,22 @@ impl Rule for RulePushDownLimitScan {
         let limit: Limit = s_expr.plan().clone().try_into()?;
         if let Some(mut count) = limit.limit {
             let child = s_expr.child(0)?;
-            let mut get: Scan = child.plan().clone().try_into()?;
             count += limit.offset;
-            get.limit = Some(get.limit.map_or(count, |c| cmp::max(c, count)));
-            let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
+            let mut result = if child.plan().rel_op() == RelOp::Filter {
+                // The limit can't skip read under the filter, but the scan can read
+                // the blocks most likely to match the filter first.
+                let mut get: Scan = child.child(0)?.plan().clone().try_into()?;
+                get.filter_limit = Some(get.filter_limit.map_or(count, |c| cmp::max(c, count)));
+                let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
+                let filter = child.replace_children(vec![Arc::new(get)]);
+                s_expr.replace_children(vec![Arc::new(filter)])
+            } else {
+                let mut get: Scan = child.plan().clone().try_into()?;
+                get.limit = Some(get.limit.map_or(count, |c| cmp::max(c, count)));
+                let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
+                s_expr.replace_children(vec![Arc::new(get)])
+            };
 
-            let mut result = s_expr.replace_children(vec![Arc::new(get)]);
             result.set_applied_rule(&self.id);
             state.add_result(result);
         }
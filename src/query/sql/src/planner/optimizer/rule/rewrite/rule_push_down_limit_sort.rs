@@ -36,14 +36,16 @@ use crate::plans::Sort;
 ///          Sort(padding limit)
 ///             \
 ///               *
+///
+/// The limit is always padded, so every sort pipeline and every remote
+/// fragment before the exchange only keeps the top `limit` rows.
 pub struct RulePushDownLimitSort {
     id: RuleID,
     matchers: Vec<Matcher>,
-    max_limit: usize,
 }
 
 impl RulePushDownLimitSort {
-    pub fn new(max_limit: usize) -> Self {
+    pub fn new() -> Self {
         Self {
             id: RuleID::PushDownLimitSort,
             matchers: vec![Matcher::MatchOp {
@@ -53,7 +55,6 @@ impl RulePushDownLimitSort {
                     children: vec![Matcher::Leaf],
                 }],
             }],
-            max_limit,
         }
     }
 }
@@ -75,17 +76,15 @@ impl Rule for RulePushDownLimitSort {
             let mut sort_limit: Sort = sort.plan().clone().try_into()?;
             let limit = sort_limit.limit.map_or(count, |c| cmp::max(c, count));
 
-            if limit <= self.max_limit {
-                sort_limit.limit = Some(limit);
-                let sort = SExpr::create_unary(
-                    Arc::new(RelOperator::Sort(sort_limit)),
-                    Arc::new(sort.child(0)?.clone()),
-                );
+            sort_limit.limit = Some(limit);
+            let sort = SExpr::create_unary(
+                Arc::new(RelOperator::Sort(sort_limit)),
+                Arc::new(sort.child(0)?.clone()),
+            );
 
-                let mut result = s_expr.replace_children(vec![Arc::new(sort)]);
-                result.set_applied_rule(&self.id);
-                state.add_result(result);
-            }
+            let mut result = s_expr.replace_children(vec![Arc::new(sort)]);
+            result.set_applied_rule(&self.id);
+            state.add_result(result);
         }
         Ok(())
     }
@@ -409,7 +409,7 @@ impl SExpr {
     }
 }
 
-fn find_subquery(rel_op: &RelOperator) -> bool {
+pub(crate) fn find_subquery(rel_op: &RelOperator) -> bool {
     match rel_op {
         RelOperator::Scan(_)
         | RelOperator::Limit(_)
@@ -86,6 +86,21 @@ impl CollectStatisticsOptimizer {
                     }
                 }
 
+                // Each row has a distinct value in a single column unique key.
+                if let Some(num_rows) = table_stats.as_ref().and_then(|stats| stats.num_rows) {
+                    let unique_keys = self
+                        .metadata
+                        .read()
+                        .unique_keys_by_table_index(scan.table_index);
+                    for unique_key in unique_keys.iter() {
+                        if let [column_index] = unique_key.as_slice() {
+                            if let Some(Some(col_stat)) = column_stats.get_mut(column_index) {
+                                col_stat.ndv = Some(num_rows.saturating_sub(col_stat.null_count));
+                            }
+                        }
+                    }
+                }
+
                 let mut scan = scan.clone();
                 scan.statistics = Arc::new(Statistics {
                     table_stats,
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use super::SExpr;
+use crate::plans::AggregateMode;
 use crate::plans::RelOperator;
+use crate::IndexType;
 use crate::MetadataRef;
 
 /// Check if a query will read data from local tables(e.g. system tables).
@@ -28,3 +30,36 @@ pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool
         }
         || matches!(s_expr.plan(), RelOperator::RecursiveCteScan { .. })
 }
+
+/// Collect the unique keys of the output rows of a plan, only operators which never
+/// duplicate rows are considered. The unique keys of the tables come from the constraints
+/// declared with `RELY`.
+pub fn unique_keys(s_expr: &SExpr, metadata: &MetadataRef) -> Vec<Vec<IndexType>> {
+    match s_expr.plan.as_ref() {
+        RelOperator::Scan(scan) if scan.change_type.is_none() => metadata
+            .read()
+            .unique_keys_by_table_index(scan.table_index)
+            .into_iter()
+            .filter(|key| key.iter().all(|index| scan.columns.contains(index)))
+            .collect(),
+        RelOperator::Aggregate(aggregate)
+            if aggregate.mode == AggregateMode::Initial && aggregate.grouping_sets.is_none() =>
+        {
+            vec![
+                aggregate
+                    .group_items
+                    .iter()
+                    .map(|item| item.index)
+                    .collect(),
+            ]
+        }
+        RelOperator::Filter(_)
+        | RelOperator::EvalScalar(_)
+        | RelOperator::Sort(_)
+        | RelOperator::Limit(_) => match s_expr.child(0) {
+            Ok(child) => unique_keys(child, metadata),
+            Err(_) => vec![],
+        },
+        _ => vec![],
+    }
+}
@@ -26,6 +26,8 @@ use databend_common_ast::parser::token::TokenKind;
 use databend_common_ast::parser::token::Tokenizer;
 use databend_common_ast::parser::Dialect;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::fault_injection::inject_fault;
+use databend_common_catalog::fault_injection::FaultPoint;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -150,20 +152,34 @@ impl Planner {
 
                 self.replace_stmt(&mut stmt)?;
 
+                inject_fault(&settings, FaultPoint::Plan).await?;
+
                 // Step 3: Bind AST with catalog, and generate a pure logical SExpr
                 let metadata = Arc::new(RwLock::new(Metadata::default()));
                 let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
                 let binder = Binder::new(
                     self.ctx.clone(),
                     CatalogManager::instance(),
-                    name_resolution_ctx,
+                    name_resolution_ctx.clone(),
                     metadata.clone(),
                 );
 
                 // Indicate binder there is no need to collect column statistics for the binding table.
                 self.ctx
                     .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
-                let plan = binder.bind(&stmt).await?;
+                let mut plan = binder.bind(&stmt).await?;
+                // The repeated subqueries picked by the binder are materialized first, then the
+                // statement is bound again to read their results, so they don't run twice.
+                if self.ctx.materialize_pending_subqueries().await? {
+                    *metadata.write() = Metadata::default();
+                    let binder = Binder::new(
+                        self.ctx.clone(),
+                        CatalogManager::instance(),
+                        name_resolution_ctx,
+                        metadata.clone(),
+                    );
+                    plan = binder.bind(&stmt).await?;
+                }
                 // attach again to avoid the query kind is overwritten by the subquery
                 self.ctx
                     .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
@@ -233,6 +249,10 @@ impl Planner {
             return;
         }
 
+        if let Statement::StatementWithSettings { stmt, .. } = statement {
+            return self.add_max_rows_limit(stmt);
+        }
+
         if let Statement::Query(query) = statement {
             if query.limit.is_empty() {
                 query.limit = vec![Expr::Literal {
@@ -267,6 +287,7 @@ pub fn get_query_kind(stmt: &Statement) -> QueryKind {
         Statement::CopyIntoTable(_) => QueryKind::CopyIntoTable,
         Statement::CopyIntoLocation(_) => QueryKind::CopyIntoLocation,
         Statement::Explain { .. } => QueryKind::Explain,
+        Statement::StatementWithSettings { stmt, .. } => get_query_kind(stmt),
         Statement::Insert(_) => QueryKind::Insert,
         Statement::Replace(_)
         | Statement::Delete(_)
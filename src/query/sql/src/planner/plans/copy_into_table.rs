@@ -36,6 +36,8 @@ use databend_common_meta_app::principal::COPY_MAX_FILES_PER_COMMIT;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_metrics::storage::*;
 use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFileInfoStream;
+use futures::StreamExt;
 use log::info;
 
 use crate::plans::Plan;
@@ -141,6 +143,9 @@ pub struct CopyIntoTablePlan {
 }
 
 impl CopyIntoTablePlan {
+    /// Lists the files of the stage page by page, the files already copied are filtered
+    /// out of each page as it's listed, so only the files to copy are kept in memory, and
+    /// the listing stops once `max_files` files to copy are found.
     pub async fn collect_files(&mut self, ctx: &dyn TableContext) -> Result<()> {
         ctx.set_status_info("begin to list files");
         let start = Instant::now();
@@ -154,81 +159,103 @@ impl CopyIntoTablePlan {
         };
 
         let thread_num = ctx.get_settings().get_max_threads()? as usize;
+        let page_size = ctx.get_settings().get_stage_listing_page_size()?;
         let operator = init_stage_operator(&stage_table_info.stage_info)?;
-        let all_source_file_infos = if operator.info().native_capability().blocking {
-            if self.force {
-                stage_table_info
-                    .files_info
-                    .blocking_list(&operator, max_files)
-            } else {
-                stage_table_info.files_info.blocking_list(&operator, None)
-            }
-        } else if self.force {
-            stage_table_info
+        let list_max_files = if self.force { max_files } else { None };
+        let mut stream: StageFileInfoStream = if operator.info().native_capability().blocking {
+            let files = stage_table_info
                 .files_info
-                .list(&operator, thread_num, max_files)
-                .await
+                .blocking_list(&operator, list_max_files)?;
+            Box::pin(futures::stream::iter(files.into_iter().map(Ok)))
         } else {
             stage_table_info
                 .files_info
-                .list(&operator, thread_num, None)
-                .await
-        }?;
+                .list_stream(&operator, thread_num, list_max_files)
+                .await?
+        };
 
-        let num_all_files = all_source_file_infos.len();
+        if !self.force {
+            ctx.set_status_info("begin filtering out copied files");
+        }
+        let mut num_all_files = 0;
+        let mut need_copy_file_infos = vec![];
+        let mut duplicated = vec![];
+        let mut page = vec![];
+        let mut finished = false;
+        while !finished {
+            match stream.next().await {
+                Some(file) => {
+                    page.push(file?);
+                    if page.len() < page_size {
+                        continue;
+                    }
+                }
+                None => finished = true,
+            }
+            if page.is_empty() {
+                break;
+            }
+            num_all_files += page.len();
+
+            if self.force {
+                need_copy_file_infos.append(&mut page);
+                if !self.stage_table_info.stage_info.copy_options.purge
+                    && need_copy_file_infos.len() > COPY_MAX_FILES_PER_COMMIT
+                {
+                    return Err(ErrorCode::Internal(COPY_MAX_FILES_COMMIT_MSG));
+                }
+            } else {
+                let filter_start = Instant::now();
+                let remaining = max_files.map(|max| max - need_copy_file_infos.len());
+                let FilteredCopyFiles {
+                    mut files_to_copy,
+                    mut duplicated_files,
+                } = ctx
+                    .filter_out_copied_files(
+                        self.catalog_info.catalog_name(),
+                        &self.database_name,
+                        &self.table_name,
+                        &page,
+                        remaining,
+                    )
+                    .await?;
+                metrics_inc_copy_filter_out_copied_files_entire_milliseconds(
+                    filter_start.elapsed().as_millis() as u64,
+                );
+                page.clear();
+
+                need_copy_file_infos.append(&mut files_to_copy);
+                duplicated.append(&mut duplicated_files);
+                if need_copy_file_infos.len() > COPY_MAX_FILES_PER_COMMIT {
+                    return Err(ErrorCode::Internal(COPY_MAX_FILES_COMMIT_MSG));
+                }
+                if max_files.is_some_and(|max| need_copy_file_infos.len() >= max) {
+                    break;
+                }
+            }
 
-        let end_get_all_source = Instant::now();
-        let cost_get_all_files = end_get_all_source.duration_since(start).as_millis();
-        metrics_inc_copy_collect_files_get_all_source_files_milliseconds(cost_get_all_files as u64);
+            ctx.set_status_info(&format!(
+                "listing files: listed {} files, {} files to copy, time used {:?}",
+                num_all_files,
+                need_copy_file_infos.len(),
+                start.elapsed()
+            ));
+        }
 
+        metrics_inc_copy_collect_files_get_all_source_files_milliseconds(
+            start.elapsed().as_millis() as u64,
+        );
         ctx.set_status_info(&format!(
-            "end list files: got {} files, time used {:?}",
+            "end list files: listed {} files, time used {:?}",
             num_all_files,
             start.elapsed()
         ));
-
-        let (need_copy_file_infos, duplicated) = if self.force {
-            if !self.stage_table_info.stage_info.copy_options.purge
-                && all_source_file_infos.len() > COPY_MAX_FILES_PER_COMMIT
-            {
-                return Err(ErrorCode::Internal(COPY_MAX_FILES_COMMIT_MSG));
-            }
+        if self.force {
             info!(
                 "force mode, ignore file filtering. ({}.{})",
                 &self.database_name, &self.table_name
             );
-            (all_source_file_infos, vec![])
-        } else {
-            // Status.
-            ctx.set_status_info("begin filtering out copied files");
-
-            let filter_start = Instant::now();
-            let FilteredCopyFiles {
-                files_to_copy,
-                duplicated_files,
-            } = ctx
-                .filter_out_copied_files(
-                    self.catalog_info.catalog_name(),
-                    &self.database_name,
-                    &self.table_name,
-                    &all_source_file_infos,
-                    max_files,
-                )
-                .await?;
-            ctx.set_status_info(&format!(
-                "end filtering out copied files: {}, time used {:?}",
-                num_all_files,
-                filter_start.elapsed()
-            ));
-
-            let end_filter_out = Instant::now();
-            let cost_filter_out = end_filter_out
-                .duration_since(end_get_all_source)
-                .as_millis();
-            metrics_inc_copy_filter_out_copied_files_entire_milliseconds(cost_filter_out as u64);
-
-            (files_to_copy, duplicated_files)
-        };
+        }
 
         let num_copied_files = need_copy_file_infos.len();
         let copied_bytes: u64 = need_copy_file_infos.iter().map(|i| i.size).sum();
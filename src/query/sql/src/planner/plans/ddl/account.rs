@@ -65,6 +65,14 @@ pub struct DropRolePlan {
     pub role_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterRolePlan {
+    pub role_name: String,
+    // None means no change to make, zero means no limit
+    pub max_estimated_scan_bytes: Option<u64>,
+    pub max_estimated_memory_bytes: Option<u64>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GrantRolePlan {
     pub principal: PrincipalIdentity,
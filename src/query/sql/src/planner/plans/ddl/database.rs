@@ -12,7 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::CreateDatabaseReq;
 use databend_common_meta_app::schema::CreateOption;
@@ -93,6 +100,16 @@ pub struct RenameDatabaseEntity {
     pub new_database: String,
 }
 
+/// Set options.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetDatabaseOptionsPlan {
+    pub tenant: Tenant,
+    pub if_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub set_options: BTreeMap<String, String>,
+}
+
 /// Undrop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UndropDatabasePlan {
@@ -115,6 +132,49 @@ pub struct UseDatabasePlan {
     pub database: String,
 }
 
+/// Export.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportDatabasePlan {
+    pub catalog: String,
+    pub database: String,
+    /// The location as written in the statement, without the leading `@`.
+    pub location: String,
+    pub stage: StageInfo,
+    pub path: String,
+}
+
+impl ExportDatabasePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("kind", DataType::String),
+            DataField::new("snapshot_id", DataType::String),
+            DataField::new("rows", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+}
+
+/// Import.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportDatabasePlan {
+    pub catalog: String,
+    pub database: String,
+    /// The location as written in the statement, without the leading `@`.
+    pub location: String,
+    pub stage: StageInfo,
+    pub path: String,
+}
+
+impl ImportDatabasePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("kind", DataType::String),
+            DataField::new("rows", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+}
+
 /// Show.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ShowCreateDatabasePlan {
@@ -25,6 +25,7 @@ mod sequence;
 mod stage;
 mod stream;
 mod table;
+mod tag;
 mod task;
 mod udf;
 mod view;
@@ -43,6 +44,7 @@ pub use sequence::*;
 pub use stage::*;
 pub use stream::*;
 pub use table::*;
+pub use tag::*;
 pub use task::*;
 pub use udf::*;
 pub use view::*;
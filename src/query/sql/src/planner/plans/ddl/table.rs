@@ -28,7 +28,9 @@ use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::UndropTableReq;
@@ -58,11 +60,18 @@ pub struct CreateTablePlan {
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
     pub inverted_indexes: Option<BTreeMap<String, TableIndex>>,
+    pub constraints: Option<BTreeMap<String, TableConstraint>>,
 }
 
 impl CreateTablePlan {
     pub fn schema(&self) -> DataSchemaRef {
-        DataSchemaRefExt::create(vec![])
+        match self.as_select {
+            None => DataSchemaRefExt::create(vec![]),
+            Some(_) => DataSchemaRefExt::create(vec![DataField::new(
+                "number of rows inserted",
+                DataType::Number(NumberDataType::UInt64),
+            )]),
+        }
     }
 }
 
@@ -93,6 +102,9 @@ pub struct DropTablePlan {
     /// The table name
     pub table: String,
     pub all: bool,
+    /// Refuse to drop the table if other objects depend on it, which is the default unless
+    /// `CASCADE` is given.
+    pub restrict: bool,
 }
 
 impl DropTablePlan {
@@ -186,6 +198,20 @@ impl crate::plans::VacuumTemporaryFilesPlan {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct VacuumSystemHistoryPlan {
+    pub retain: Option<Duration>,
+}
+
+impl VacuumSystemHistoryPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::new(vec![DataField::new(
+            "Rows",
+            DataType::Number(NumberDataType::UInt64),
+        )]))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct VacuumDropTableOption {
     // Some(true) means dry run with summary option
@@ -222,6 +248,7 @@ pub enum OptimizeTableAction {
     // Optionally, specify the limit on the number of blocks to be compacted.
     CompactBlocks(Option<usize>),
     CompactSegments,
+    Recluster,
 }
 
 #[derive(Clone, Debug)]
@@ -237,6 +264,62 @@ impl AnalyzeTablePlan {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct VerifyTablePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl VerifyTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("block_location", DataType::String),
+            DataField::new("reason", DataType::String),
+        ])
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RefreshTableStatisticsPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl RefreshTableStatisticsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("segment_count", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("block_count", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("row_count", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RestoreTablePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// The location as written in the statement, without the leading `@`.
+    pub location: String,
+    pub stage: StageInfo,
+    pub path: String,
+    /// The newest bundle of the table is restored if not specified.
+    pub point: Option<NavigationPoint>,
+}
+
+impl RestoreTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("bundle", DataType::String),
+            DataField::new("snapshot_id", DataType::String),
+            DataField::new("rows", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+}
+
 /// Rename.
 #[derive(Clone, Debug)]
 pub struct RenameTablePlan {
@@ -255,6 +338,23 @@ impl RenameTablePlan {
     }
 }
 
+/// Swap the names of two tables.
+#[derive(Clone, Debug)]
+pub struct SwapTablePlan {
+    pub tenant: Tenant,
+    pub if_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub target_table: String,
+}
+
+impl SwapTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Modify table comment.
 #[derive(Clone, Debug)]
 pub struct ModifyTableCommentPlan {
@@ -270,6 +370,49 @@ impl ModifyTableCommentPlan {
     }
 }
 
+/// Set or unset the tags of a table or one of its columns.
+#[derive(Clone, Debug)]
+pub struct AlterTableTagsPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    // None means the tags are attached to the table itself.
+    pub column: Option<String>,
+    pub set_tags: BTreeMap<String, String>,
+    pub unset_tags: Vec<String>,
+}
+
+impl AlterTableTagsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Set or unset the storage policy of a table.
+#[derive(Clone, Debug)]
+pub struct AlterTableStoragePolicyPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    // None means the storage policy is unset.
+    pub policy: Option<StoragePolicyDesc>,
+}
+
+#[derive(Clone, Debug)]
+pub struct StoragePolicyDesc {
+    pub cold_after_days: u64,
+    // The cold location with the credentials masked.
+    pub cold_location: String,
+    pub cold_storage: StorageParams,
+}
+
+impl AlterTableStoragePolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// SetOptions
 #[derive(Clone, Debug)]
 pub struct SetOptionsPlan {
@@ -295,6 +438,8 @@ pub struct AddTableColumnPlan {
     pub field: TableField,
     pub comment: String,
     pub option: AddColumnOption,
+    /// The default expression, which is not a constant, to backfill the existing rows with.
+    pub backfill_expr: Option<String>,
 }
 
 impl AddTableColumnPlan {
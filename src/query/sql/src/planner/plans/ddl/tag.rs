@@ -0,0 +1,40 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTagPlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub name: String,
+    pub comment: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterTagPlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+    // None means unset the masking policy of the tag.
+    pub masking_policy: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTagPlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+}
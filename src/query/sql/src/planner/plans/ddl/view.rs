@@ -44,6 +44,8 @@ pub struct DropViewPlan {
     pub catalog: String,
     pub database: String,
     pub view_name: String,
+    /// Refuse to drop the view if other objects depend on it.
+    pub restrict: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -35,6 +35,11 @@ pub enum InsertInputSource {
     Values(InsertValue),
     // From stage
     Stage(Box<Plan>),
+    // Data blocks decoded from the binary payload of the HTTP bulk insert
+    Blocks {
+        schema: DataSchemaRef,
+        blocks: Vec<DataBlock>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -154,6 +159,11 @@ pub(crate) fn format_insert_source(
             )
             .format_pretty()?),
         },
+        InsertInputSource::Blocks { .. } => Ok(FormatTreeNode::with_children(
+            format!("{plan_name} (blocks):"),
+            children,
+        )
+        .format_pretty()?),
         InsertInputSource::Stage(plan) => match *plan.clone() {
             Plan::CopyIntoTable(copy_plan) => {
                 let CopyIntoTablePlan {
@@ -54,6 +54,10 @@ pub enum JoinType {
     RightSemi,
     LeftAnti,
     RightAnti,
+    /// Null Aware Anti Join is used to process uncorrelated `NOT IN` subquery, it differs from
+    /// Left Anti Join when there are NULLs: a probe row with NULL key is never returned,
+    /// and no row is returned if the build side contains NULL.
+    LeftAntiNullAware,
     /// Mark Join is a special case of join that is used to process Any subquery and correlated Exists subquery.
     /// Left Mark Join use subquery as probe side, it's blocked at `mark_join_blocks`
     LeftMark,
@@ -124,6 +128,9 @@ impl Display for JoinType {
             JoinType::RightAnti => {
                 write!(f, "RIGHT ANTI")
             }
+            JoinType::LeftAntiNullAware => {
+                write!(f, "LEFT ANTI NULL AWARE")
+            }
             JoinType::Cross => {
                 write!(f, "CROSS")
             }
@@ -534,7 +541,10 @@ impl Operator for Join {
             }
             JoinType::LeftSemi => f64::min(left_cardinality, inner_join_cardinality),
             JoinType::RightSemi => f64::min(right_cardinality, inner_join_cardinality),
-            JoinType::LeftSingle | JoinType::RightMark | JoinType::LeftAnti => left_cardinality,
+            JoinType::LeftSingle
+            | JoinType::RightMark
+            | JoinType::LeftAnti
+            | JoinType::LeftAntiNullAware => left_cardinality,
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
@@ -608,6 +618,16 @@ impl Operator for Join {
             }
         }
 
+        // A NULL on the build side must be seen by every probe row, so never shuffle it.
+        if self.join_type == JoinType::LeftAntiNullAware {
+            if child_index == 1 {
+                required.distribution = Distribution::Broadcast;
+            } else {
+                required.distribution = Distribution::Any;
+            }
+            return Ok(required);
+        }
+
         // Otherwise, use hash shuffle
         if child_index == 0 {
             let left_conditions = self
@@ -636,7 +656,11 @@ impl Operator for Join {
     ) -> Result<Vec<Vec<RequiredProperty>>> {
         let mut children_required = vec![];
 
-        if self.join_type != JoinType::Cross && !ctx.get_settings().get_enforce_broadcast_join()? {
+        if !matches!(
+            self.join_type,
+            JoinType::Cross | JoinType::LeftAntiNullAware
+        ) && !ctx.get_settings().get_enforce_broadcast_join()?
+        {
             // (Hash, Hash)
             children_required.extend(self.equi_conditions.iter().map(|condition| {
                 vec![
@@ -17,3 +17,10 @@ pub struct KillPlan {
     pub id: String,
     pub kill_connection: bool,
 }
+
+/// Kill the sessions found by `subquery`, which selects their ids from `system.processes`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KillWherePlan {
+    pub subquery: String,
+    pub kill_connection: bool,
+}
@@ -78,6 +78,7 @@ pub use insert::InsertInputSource;
 pub use insert_multi_table::*;
 pub use join::*;
 pub use kill::KillPlan;
+pub use kill::KillWherePlan;
 pub use limit::*;
 pub use materialized_cte::MaterializedCte;
 pub use merge_into::MatchedEvaluator;
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
@@ -33,8 +34,12 @@ use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterRolePlan;
 use crate::plans::AlterShareTenantsPlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AlterTableStoragePolicyPlan;
+use crate::plans::AlterTableTagsPlan;
+use crate::plans::AlterTagPlan;
 use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
@@ -61,6 +66,7 @@ use crate::plans::CreateStagePlan;
 use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTableIndexPlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTagPlan;
 use crate::plans::CreateTaskPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
@@ -95,6 +101,7 @@ use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTableIndexPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTagPlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
@@ -103,12 +110,15 @@ use crate::plans::DropVirtualColumnPlan;
 use crate::plans::ExecuteImmediatePlan;
 use crate::plans::ExecuteTaskPlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::ExportDatabasePlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::GrantShareObjectPlan;
+use crate::plans::ImportDatabasePlan;
 use crate::plans::Insert;
 use crate::plans::InsertMultiTable;
 use crate::plans::KillPlan;
+use crate::plans::KillWherePlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::ModifyTableCommentPlan;
 use crate::plans::OptimizeTablePlan;
@@ -116,16 +126,19 @@ use crate::plans::PresignPlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RefreshIndexPlan;
 use crate::plans::RefreshTableIndexPlan;
+use crate::plans::RefreshTableStatisticsPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::Replace;
+use crate::plans::RestoreTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
 use crate::plans::RevokeShareObjectPlan;
+use crate::plans::SetDatabaseOptionsPlan;
 use crate::plans::SetOptionsPlan;
 use crate::plans::SetPriorityPlan;
 use crate::plans::SetRolePlan;
@@ -143,6 +156,7 @@ use crate::plans::ShowRolesPlan;
 use crate::plans::ShowShareEndpointPlan;
 use crate::plans::ShowSharesPlan;
 use crate::plans::ShowTasksPlan;
+use crate::plans::SwapTablePlan;
 use crate::plans::SystemPlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UnSettingPlan;
@@ -151,8 +165,10 @@ use crate::plans::UndropTablePlan;
 use crate::plans::UpdatePlan;
 use crate::plans::UseDatabasePlan;
 use crate::plans::VacuumDropTablePlan;
+use crate::plans::VacuumSystemHistoryPlan;
 use crate::plans::VacuumTablePlan;
 use crate::plans::VacuumTemporaryFilesPlan;
+use crate::plans::VerifyTablePlan;
 use crate::BindContext;
 use crate::MetadataRef;
 
@@ -198,7 +214,10 @@ pub enum Plan {
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
+    SetDatabaseOptions(Box<SetDatabaseOptionsPlan>),
     UseDatabase(Box<UseDatabasePlan>),
+    ExportDatabase(Box<ExportDatabasePlan>),
+    ImportDatabase(Box<ImportDatabasePlan>),
 
     // Tables
     ShowCreateTable(Box<ShowCreateTablePlan>),
@@ -207,6 +226,7 @@ pub enum Plan {
     DropTable(Box<DropTablePlan>),
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
+    SwapTable(Box<SwapTablePlan>),
     ModifyTableComment(Box<ModifyTableCommentPlan>),
     RenameTableColumn(Box<RenameTableColumnPlan>),
     AddTableColumn(Box<AddTableColumnPlan>),
@@ -221,9 +241,15 @@ pub enum Plan {
     VacuumTable(Box<VacuumTablePlan>),
     VacuumDropTable(Box<VacuumDropTablePlan>),
     VacuumTemporaryFiles(Box<VacuumTemporaryFilesPlan>),
+    VacuumSystemHistory(Box<VacuumSystemHistoryPlan>),
     AnalyzeTable(Box<AnalyzeTablePlan>),
+    VerifyTable(Box<VerifyTablePlan>),
+    RefreshTableStatistics(Box<RefreshTableStatisticsPlan>),
+    RestoreTable(Box<RestoreTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
     SetOptions(Box<SetOptionsPlan>),
+    AlterTableTags(Box<AlterTableTagsPlan>),
+    AlterTableStoragePolicy(Box<AlterTableStoragePolicyPlan>),
 
     // Insert
     Insert(Box<Insert>),
@@ -278,6 +304,7 @@ pub enum Plan {
     ShowRoles(Box<ShowRolesPlan>),
     CreateRole(Box<CreateRolePlan>),
     DropRole(Box<DropRolePlan>),
+    AlterRole(Box<AlterRolePlan>),
     GrantRole(Box<GrantRolePlan>),
     GrantPriv(Box<GrantPrivilegePlan>),
     RevokePriv(Box<RevokePrivilegePlan>),
@@ -308,8 +335,10 @@ pub enum Plan {
     SetVariable(Box<SettingPlan>),
     UnSetVariable(Box<UnSettingPlan>),
     Kill(Box<KillPlan>),
+    KillWhere(Box<KillWherePlan>),
     SetPriority(Box<SetPriorityPlan>),
     System(Box<SystemPlan>),
+    ShowWarnings,
 
     // Share
     CreateShareEndpoint(Box<CreateShareEndpointPlan>),
@@ -343,6 +372,11 @@ pub enum Plan {
     DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
     DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
 
+    // Tag
+    CreateTag(Box<CreateTagPlan>),
+    AlterTag(Box<AlterTagPlan>),
+    DropTag(Box<DropTagPlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -453,11 +487,18 @@ impl Plan {
             Plan::MergeInto { schema, .. } => schema.clone(),
             Plan::ShowCreateCatalog(plan) => plan.schema(),
             Plan::ShowCreateDatabase(plan) => plan.schema(),
+            Plan::ExportDatabase(plan) => plan.schema(),
+            Plan::ImportDatabase(plan) => plan.schema(),
             Plan::ShowCreateTable(plan) => plan.schema(),
+            Plan::CreateTable(plan) => plan.schema(),
             Plan::DescribeTable(plan) => plan.schema(),
             Plan::VacuumTable(plan) => plan.schema(),
             Plan::VacuumDropTable(plan) => plan.schema(),
             Plan::VacuumTemporaryFiles(plan) => plan.schema(),
+            Plan::VacuumSystemHistory(plan) => plan.schema(),
+            Plan::VerifyTable(plan) => plan.schema(),
+            Plan::RefreshTableStatistics(plan) => plan.schema(),
+            Plan::RestoreTable(plan) => plan.schema(),
             Plan::ExistsTable(plan) => plan.schema(),
             Plan::DescribeView(plan) => plan.schema(),
             Plan::ShowRoles(plan) => plan.schema(),
@@ -485,6 +526,11 @@ impl Plan {
             Plan::ShowConnections(plan) => plan.schema(),
             Plan::ExecuteImmediate(plan) => plan.schema(),
             Plan::InsertMultiTable(plan) => plan.schema(),
+            Plan::ShowWarnings => DataSchemaRefExt::create(vec![
+                DataField::new("level", DataType::String),
+                DataField::new("code", DataType::Number(NumberDataType::UInt16)),
+                DataField::new("message", DataType::String),
+            ]),
 
             _ => Arc::new(DataSchema::empty()),
         }
@@ -26,5 +26,7 @@ pub struct ReclusterTablePlan {
     pub is_final: bool,
     pub metadata: MetadataRef,
     pub push_downs: Option<ScalarExpr>,
+    /// Only the newest N segments of the table are considered for reclustering.
+    pub last_segments: Option<usize>,
     pub limit: Option<usize>,
 }
@@ -94,6 +94,8 @@ pub struct Scan {
     pub columns: ColumnSet,
     pub push_down_predicates: Option<Vec<ScalarExpr>>,
     pub limit: Option<usize>,
+    // The limit of the rows matching the push down predicates.
+    pub filter_limit: Option<usize>,
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
@@ -120,6 +122,7 @@ impl Scan {
             columns,
             push_down_predicates: self.push_down_predicates.clone(),
             limit: self.limit,
+            filter_limit: self.filter_limit,
             order_by: self.order_by.clone(),
             statistics: Arc::new(Statistics {
                 table_stats: self.statistics.table_stats,
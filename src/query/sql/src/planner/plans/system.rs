@@ -23,4 +23,7 @@ pub struct SystemPlan {
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum SystemAction {
     Backtrace(bool),
+    CancelAllQueries { warehouse: String },
+    SuspendNode { node_id: Option<String> },
+    ResumeNode { node_id: Option<String> },
 }
@@ -0,0 +1,286 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TemporalClause;
+use databend_common_ast::ast::TimeTravelPoint;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+use futures::TryStreamExt;
+use log::info;
+use opendal::Metakey;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::normalize_identifier;
+use crate::NameResolutionContext;
+
+/// The prefix of the replay bundles in the storage of the tenant.
+pub const QUERY_REPLAY_PREFIX: &str = "_query_replay";
+
+/// What is needed to re-execute a query exactly as it was executed: the settings that were
+/// changed from their defaults, the snapshots of the tables the query read, and the
+/// definitions of the views it read.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QueryReplayBundle {
+    pub version: u64,
+    pub query_id: String,
+    pub sql: String,
+    pub catalog: String,
+    pub database: String,
+    pub settings: BTreeMap<String, String>,
+    pub tables: Vec<QueryReplayTable>,
+    /// The bundles of version 1 have no views, their views are read as currently defined.
+    #[serde(default)]
+    pub views: Vec<QueryReplayView>,
+    /// The plan of the query when it was captured, which is kept for the investigation
+    /// rather than replayed, the query is planned again against the same snapshots.
+    pub plan: String,
+    pub captured_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QueryReplayTable {
+    pub catalog: String,
+    pub database: String,
+    pub name: String,
+    /// None if the table is not a fuse table or had no snapshot.
+    pub snapshot_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QueryReplayView {
+    pub catalog: String,
+    pub database: String,
+    pub name: String,
+    /// The query of the view when the query was captured.
+    pub query: String,
+}
+
+impl QueryReplayBundle {
+    pub const VERSION: u64 = 2;
+
+    pub fn path(tenant: &str, query_id: &str) -> String {
+        format!("{}/{}.json", Self::prefix(tenant), query_id)
+    }
+
+    fn prefix(tenant: &str) -> String {
+        format!("{}/{}", QUERY_REPLAY_PREFIX, tenant)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write(&self, op: &Operator, tenant: &str) -> Result<()> {
+        let path = Self::path(tenant, &self.query_id);
+        op.write(&path, serde_json::to_vec_pretty(self)?).await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read(op: &Operator, tenant: &str, query_id: &str) -> Result<Self> {
+        let path = Self::path(tenant, query_id);
+        let bytes = match op.read(&path).await {
+            Ok(bytes) => bytes.to_vec(),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => {
+                return Err(ErrorCode::UnknownQuery(format!(
+                    "no replay bundle of query {} is captured",
+                    query_id
+                )));
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let bundle: QueryReplayBundle = serde_json::from_slice(&bytes)?;
+        if bundle.version > Self::VERSION {
+            return Err(ErrorCode::BadArguments(format!(
+                "unsupported replay bundle version {} of query {}, the latest supported is {}",
+                bundle.version,
+                query_id,
+                Self::VERSION
+            )));
+        }
+        Ok(bundle)
+    }
+
+    /// Removes the bundles of the tenant which were captured more than `retention` ago,
+    /// returns the number of the removed bundles.
+    #[async_backtrace::framed]
+    pub async fn purge_expired(op: &Operator, tenant: &str, retention: Duration) -> Result<usize> {
+        let prefix = format!("{}/", Self::prefix(tenant));
+        let expire_at = Utc::now() - retention;
+        let mut lister = op
+            .lister_with(&prefix)
+            .metakey(Metakey::Mode | Metakey::LastModified)
+            .await?;
+
+        let mut expired = vec![];
+        while let Some(entry) = lister.try_next().await? {
+            let meta = entry.metadata();
+            if meta.is_file() && meta.last_modified().is_some_and(|t| t < expire_at) {
+                expired.push(entry.path().to_string());
+            }
+        }
+
+        let num_expired = expired.len();
+        if num_expired > 0 {
+            op.remove(expired).await?;
+            info!(
+                "Purged {} replay bundles captured before {} at {}",
+                num_expired, expire_at, prefix
+            );
+        }
+        Ok(num_expired)
+    }
+
+    /// Qualifies the tables of the statement with the catalog and the database the query
+    /// was executed in, pins the tables the query read to their captured snapshots, and
+    /// replaces the views it read with their captured queries, whose tables are pinned too.
+    ///
+    /// The tables that are already read at a point in time are left as they are.
+    pub fn pin_snapshots(
+        &self,
+        stmt: &mut Statement,
+        name_resolution_ctx: &NameResolutionContext,
+        dialect: Dialect,
+    ) -> Result<()> {
+        let mut pinner = SnapshotPinner {
+            bundle: self,
+            name_resolution_ctx,
+            dialect,
+            error: None,
+        };
+        stmt.drive_mut(&mut pinner);
+        match pinner.error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn snapshot_id(&self, catalog: &str, database: &str, name: &str) -> Option<&str> {
+        self.tables
+            .iter()
+            .find(|t| t.catalog == catalog && t.database == database && t.name == name)
+            .and_then(|t| t.snapshot_id.as_deref())
+    }
+
+    fn view(&self, catalog: &str, database: &str, name: &str) -> Option<&QueryReplayView> {
+        self.views
+            .iter()
+            .find(|v| v.catalog == catalog && v.database == database && v.name == name)
+    }
+}
+
+#[derive(VisitorMut)]
+#[visitor(TableReference(enter))]
+struct SnapshotPinner<'a> {
+    bundle: &'a QueryReplayBundle,
+    name_resolution_ctx: &'a NameResolutionContext,
+    dialect: Dialect,
+    error: Option<ErrorCode>,
+}
+
+impl SnapshotPinner<'_> {
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        if self.error.is_some() {
+            return;
+        }
+        let TableReference::Table {
+            span,
+            catalog,
+            database,
+            table,
+            alias,
+            temporal,
+            ..
+        } = table_ref
+        else {
+            return;
+        };
+        if temporal.is_some() {
+            return;
+        }
+
+        let normalize = |ident: &Option<Identifier>, default: &str| match ident {
+            Some(ident) => normalize_identifier(ident, self.name_resolution_ctx).name,
+            None => default.to_string(),
+        };
+        let catalog_name = normalize(catalog, &self.bundle.catalog);
+        let database_name = normalize(database, &self.bundle.database);
+        let table_name = normalize_identifier(table, self.name_resolution_ctx).name;
+
+        // The view is read as the subquery it was defined by, whose table references are
+        // visited after this one.
+        if let Some(view) = self.bundle.view(&catalog_name, &database_name, &table_name) {
+            let subquery = match Self::parse_view_query(&view.query, self.dialect) {
+                Ok(subquery) => subquery,
+                Err(error) => {
+                    self.error = Some(error.add_message_back(format!(
+                        " (while replaying the view {}.{})",
+                        database_name, table_name
+                    )));
+                    return;
+                }
+            };
+            let alias = alias.clone().unwrap_or_else(|| TableAlias {
+                name: table.clone(),
+                columns: vec![],
+            });
+            *table_ref = TableReference::Subquery {
+                span: *span,
+                lateral: false,
+                subquery: Box::new(subquery),
+                alias: Some(alias),
+            };
+            return;
+        }
+
+        let Some(snapshot_id) = self
+            .bundle
+            .snapshot_id(&catalog_name, &database_name, &table_name)
+        else {
+            return;
+        };
+
+        let quote = Some(self.dialect.default_ident_quote());
+        catalog.get_or_insert_with(|| Identifier::from_name_with_quoted(None, catalog_name, quote));
+        database
+            .get_or_insert_with(|| Identifier::from_name_with_quoted(None, database_name, quote));
+        *temporal = Some(TemporalClause::TimeTravel(TimeTravelPoint::Snapshot(
+            snapshot_id.to_string(),
+        )));
+    }
+
+    fn parse_view_query(query: &str, dialect: Dialect) -> Result<Query> {
+        let tokens = tokenize_sql(query)?;
+        let (stmt, _) = parse_sql(&tokens, dialect)?;
+        match stmt {
+            Statement::Query(query) => Ok(*query),
+            _ => Err(ErrorCode::Internal("Invalid VIEW object")),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::parser::Dialect;
+
+/// (alias, number of arguments, builtin function), `None` matches any number of arguments.
+type FunctionAlias = (&'static str, Option<usize>, &'static str);
+
+const MYSQL_FUNCTION_ALIASES: &[FunctionAlias] = &[
+    ("curdate", None, "today"),
+    ("from_unixtime", None, "to_timestamp"),
+    ("unix_timestamp", None, "to_unix_timestamp"),
+];
+
+// `btrim(s, t)` of PostgreSQL trims any of the characters in `t`, rather than `t` as a whole
+// string like `trim_both`.
+const POSTGRESQL_FUNCTION_ALIASES: &[FunctionAlias] = &[
+    ("btrim", Some(1), "trim"),
+    ("btrim", Some(2), "trim_chars"),
+    ("to_char", None, "to_string"),
+];
+
+const HIVE_FUNCTION_ALIASES: &[FunctionAlias] = &[
+    ("from_unixtime", None, "to_timestamp"),
+    ("unix_timestamp", None, "to_unix_timestamp"),
+];
+
+/// Resolve a function name of the given `sql_dialect` called with `num_args` arguments
+/// to the builtin Databend function.
+///
+/// Aliases are consulted after builtin functions and UDFs, so an alias never
+/// shadows a function that already exists.
+pub fn dialect_function_alias(
+    dialect: Dialect,
+    func_name: &str,
+    num_args: usize,
+) -> Option<&'static str> {
+    let aliases = match dialect {
+        Dialect::MySQL => MYSQL_FUNCTION_ALIASES,
+        Dialect::PostgreSQL => POSTGRESQL_FUNCTION_ALIASES,
+        Dialect::Hive => HIVE_FUNCTION_ALIASES,
+        Dialect::PRQL | Dialect::Experimental => &[],
+    };
+    aliases
+        .iter()
+        .find(|(alias, arity, _)| {
+            alias.eq_ignore_ascii_case(func_name) && (arity.is_none() || *arity == Some(num_args))
+        })
+        .map(|(_, _, name)| *name)
+}
@@ -16,10 +16,12 @@ mod aggregate_rewriter;
 mod aggregating_index_visitor;
 mod async_function_rewriter;
 mod count_set_ops;
+mod dialect_function_alias;
 mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
+mod table_reference_collector;
 mod type_check;
 mod udf_rewriter;
 mod view_rewriter;
@@ -32,6 +34,7 @@ pub use aggregating_index_visitor::AggregatingIndexRewriter;
 pub use aggregating_index_visitor::RefreshAggregatingIndexRewriter;
 pub use async_function_rewriter::AsyncFunctionRewriter;
 pub use count_set_ops::CountSetOps;
+pub use dialect_function_alias::dialect_function_alias;
 pub use distinct_to_groupby::DistinctToGroupBy;
 pub use grouping_check::GroupingChecker;
 pub use lowering::*;
@@ -40,6 +43,7 @@ pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
 pub use name_resolution::NameResolutionSuggest;
+pub use table_reference_collector::TableReferenceCollector;
 pub use type_check::resolve_type_name;
 pub use type_check::resolve_type_name_by_str;
 pub use type_check::validate_function_arg;
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::Result;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
+
+use crate::normalize_identifier;
+use crate::NameResolutionContext;
+
+/// Collects the `(database, table)` names referenced by a statement, the tables without
+/// database are resolved in `current_database`.
+#[derive(Debug, Clone, Default, Visitor)]
+#[visitor(TableReference(enter))]
+pub struct TableReferenceCollector {
+    pub current_database: String,
+    pub tables: BTreeSet<(String, String)>,
+    name_resolution_ctx: NameResolutionContext,
+}
+
+impl TableReferenceCollector {
+    pub fn new(current_database: String) -> Self {
+        Self {
+            current_database,
+            ..Default::default()
+        }
+    }
+
+    /// Parses `sql` and returns the tables referenced by it.
+    pub fn collect(
+        current_database: String,
+        sql: &str,
+        dialect: Dialect,
+    ) -> Result<BTreeSet<(String, String)>> {
+        let tokens = tokenize_sql(sql)?;
+        let (stmt, _) = parse_sql(&tokens, dialect)?;
+        let mut collector = Self::new(current_database);
+        stmt.drive(&mut collector);
+        Ok(collector.tables)
+    }
+
+    fn enter_table_reference(&mut self, table_ref: &TableReference) {
+        if let TableReference::Table {
+            database, table, ..
+        } = table_ref
+        {
+            let database = database
+                .as_ref()
+                .map(|database| normalize_identifier(database, &self.name_resolution_ctx).name)
+                .unwrap_or_else(|| self.current_database.clone());
+            let table = normalize_identifier(table, &self.name_resolution_ctx).name;
+            self.tables.insert((database, table));
+        }
+    }
+}
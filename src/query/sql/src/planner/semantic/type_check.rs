@@ -117,6 +117,7 @@ use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::parse_lambda_expr;
 use crate::planner::metadata::optimize_remove_count_args;
+use crate::planner::semantic::dialect_function_alias;
 use crate::planner::semantic::lowering::TypeCheck;
 use crate::planner::udf_validator::UDFValidator;
 use crate::plans::Aggregate;
@@ -735,12 +736,16 @@ impl<'a> TypeChecker<'a> {
                     },
             } => {
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
-                let func_name = func_name.as_str();
+                let mut func_name = func_name.as_str();
                 if !is_builtin_function(func_name)
                     && !Self::all_sugar_functions().contains(&func_name)
                 {
                     if let Some(udf) = self.resolve_udf(*span, func_name, args)? {
                         return Ok(udf);
+                    } else if let Some(alias) =
+                        dialect_function_alias(self.dialect, func_name, args.len())
+                    {
+                        func_name = alias;
                     } else {
                         // Function not found, try to find and suggest similar function name.
                         let all_funcs = BUILTIN_FUNCTIONS
@@ -2582,6 +2587,28 @@ impl<'a> TypeChecker<'a> {
                 let (positive, _) = *self.resolve_binary_op(span, &positive_op, left, right)?;
                 self.resolve_scalar_function_call(span, "not", vec![], vec![positive])
             }
+            BinaryOperator::ILike | BinaryOperator::NotILike => {
+                // rewrite "expr1 ILIKE expr2" to "LOWER(expr1) LIKE LOWER(expr2)"
+                let box (left, _) = self.resolve(left)?;
+                let box (right, _) = self.resolve(right)?;
+
+                let (left, _) =
+                    *self.resolve_scalar_function_call(span, "lower", vec![], vec![left])?;
+                let (right, _) =
+                    *self.resolve_scalar_function_call(span, "lower", vec![], vec![right])?;
+
+                let (like, data_type) = *self.resolve_scalar_function_call(
+                    span,
+                    &BinaryOperator::Like.to_func_name(),
+                    vec![],
+                    vec![left, right],
+                )?;
+                if matches!(op, BinaryOperator::NotILike) {
+                    self.resolve_scalar_function_call(span, "not", vec![], vec![like])
+                } else {
+                    Ok(Box::new((like, data_type)))
+                }
+            }
             BinaryOperator::SoundsLike => {
                 // rewrite "expr1 SOUNDS LIKE expr2" to "SOUNDEX(expr1) = SOUNDEX(expr2)"
                 let box (left, _) = self.resolve(left)?;
@@ -3719,6 +3746,21 @@ impl<'a> TypeChecker<'a> {
                 }
             }
         }
+        // If it is a column of tuples nested in array, convert it to the internal column
+        // of the field values if the table supports nested projection.
+        if let ScalarExpr::BoundColumnRef(BoundColumnRef { ref column, .. }) = scalar {
+            if let Some((inner_scalar, inner_table_data_type)) = self
+                .resolve_array_tuple_map_access_pushdown(
+                    expr.span(),
+                    column.clone(),
+                    &table_data_type,
+                    &mut paths,
+                )
+            {
+                scalar = inner_scalar;
+                table_data_type = inner_table_data_type;
+            }
+        }
 
         // Otherwise, desugar it into a `get` function.
         while let Some((span, path_lit)) = paths.pop_front() {
@@ -3878,6 +3920,51 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Resolve field names of `Array(Tuple)` column to the inner column, like `a:b` of
+    /// `a Array(Tuple(b Int32, c String))`, which is read as `Array(Int32)`.
+    /// Returns `None` if the inner column does not exist, the paths are not consumed then.
+    fn resolve_array_tuple_map_access_pushdown(
+        &mut self,
+        span: Span,
+        column: ColumnBinding,
+        table_data_type: &TableDataType,
+        paths: &mut VecDeque<(Span, Literal)>,
+    ) -> Option<(ScalarExpr, TableDataType)> {
+        let mut names = vec![column.column_name.clone()];
+        let mut inner_type = table_data_type.clone();
+        let mut consumed = 0;
+        for (_, path) in paths.iter() {
+            let Literal::String(name) = path else {
+                break;
+            };
+            let Some((fields_name, fields_type)) = inner_type.array_tuple_fields() else {
+                break;
+            };
+            let Some(idx) = fields_name.iter().position(|k| k == name) else {
+                break;
+            };
+            names.push(display_tuple_field_name(&fields_name[idx]));
+            inner_type = fields_type[idx].clone();
+            consumed += 1;
+        }
+        if consumed == 0 {
+            return None;
+        }
+
+        let inner_column_ident = Identifier::from_name(span, names.join(":"));
+        let Ok(NameResolutionResult::Column(column)) = self.bind_context.resolve_name(
+            column.database_name.as_deref(),
+            column.table_name.as_deref(),
+            &inner_column_ident,
+            self.aliases,
+            self.name_resolution_ctx,
+        ) else {
+            return None;
+        };
+        paths.drain(..consumed);
+        Some((BoundColumnRef { span, column }.into(), inner_type))
+    }
+
     fn convert_inlist_to_subquery(
         &mut self,
         expr: &Expr,
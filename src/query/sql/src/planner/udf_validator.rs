@@ -22,6 +22,7 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ToErrorCode;
+use databend_common_expression::udf_server_pool::udf_server_addresses;
 use databend_common_functions::is_builtin_function;
 use derive_visitor::Drive;
 use derive_visitor::Visitor;
@@ -92,6 +93,7 @@ impl UDFValidator {
         )))
     }
 
+    /// Checks each of the comma separated addresses of a UDF server.
     pub fn is_udf_server_allowed(address: &str) -> Result<()> {
         if !GlobalConfig::instance().query.enable_udf_server {
             return Err(ErrorCode::Unimplemented(
@@ -99,6 +101,18 @@ impl UDFValidator {
             ));
         }
 
+        let addresses = udf_server_addresses(address);
+        if addresses.is_empty() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "udf server address '{address}' is invalid, please check the address"
+            )));
+        }
+        addresses
+            .into_iter()
+            .try_for_each(Self::is_udf_server_address_allowed)
+    }
+
+    fn is_udf_server_address_allowed(address: &str) -> Result<()> {
         let udf_server_allow_list = &GlobalConfig::instance().query.udf_server_allow_list;
         let url_addr = url::Url::parse(address)
             .map_err_to_code(ErrorCode::InvalidArgument, || {
@@ -24,6 +24,7 @@ use databend_common_base::base::tokio;
 use databend_common_base::base::GlobalInstance;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
+use databend_common_meta_app::storage::StorageAzdlsConfig;
 use databend_common_meta_app::storage::StorageFsConfig;
 // use databend_common_storage::StorageFtpConfig;
 use databend_common_meta_app::storage::StorageGcsConfig;
@@ -414,6 +415,33 @@ async fn test_parse_uri_location() -> Result<()> {
                 "/".to_string(),
             ),
         ),
+        (
+            "azdls",
+            UriLocation::new(
+                "azdls".to_string(),
+                "data".to_string(),
+                "/path/to/dir/".to_string(),
+                "".to_string(),
+                vec![
+                    ("endpoint_url", "databend.dfs.core.windows.net"),
+                    ("account_name", "databend"),
+                    ("account_key", "key"),
+                ]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<_, _>>(),
+            ),
+            (
+                StorageParams::Azdls(StorageAzdlsConfig {
+                    endpoint_url: "https://databend.dfs.core.windows.net".to_string(),
+                    filesystem: "data".to_string(),
+                    account_name: "databend".to_string(),
+                    account_key: "key".to_string(),
+                    root: "/path/to/dir/".to_string(),
+                }),
+                "/".to_string(),
+            ),
+        ),
     ];
 
     for (name, mut input, expected) in cases {
@@ -16,3 +16,5 @@
 
 mod parquet_rs;
 pub use parquet_rs::blocks_to_parquet;
+pub use parquet_rs::blocks_to_parquet_with_encodings;
+pub use parquet_rs::ColumnEncodingHint;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
@@ -24,6 +25,18 @@ use parquet::basic::Encoding;
 use parquet::file::properties::EnabledStatistics;
 use parquet::file::properties::WriterProperties;
 use parquet::format::FileMetaData;
+use parquet::schema::types::ColumnPath;
+
+/// The physical encoding picked for a column after analyzing the data of a block,
+/// the logical type of the column is not changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnEncodingHint {
+    /// The 64-bit integer values fit in 32 bits, bit-pack the deltas instead of
+    /// writing full width values.
+    NarrowInteger,
+    /// The column has few distinct values, dictionary encode it.
+    Dictionary,
+}
 
 /// Serialize data blocks to parquet format.
 pub fn blocks_to_parquet(
@@ -31,17 +44,44 @@ pub fn blocks_to_parquet(
     blocks: Vec<DataBlock>,
     write_buffer: &mut Vec<u8>,
     compression: TableCompression,
+) -> Result<FileMetaData> {
+    blocks_to_parquet_with_encodings(
+        table_schema,
+        blocks,
+        write_buffer,
+        compression,
+        &HashMap::new(),
+    )
+}
+
+/// Serialize data blocks to parquet format, the top level columns in `column_encodings`
+/// are written with the hinted encoding instead of the default plain encoding.
+pub fn blocks_to_parquet_with_encodings(
+    table_schema: &TableSchema,
+    blocks: Vec<DataBlock>,
+    write_buffer: &mut Vec<u8>,
+    compression: TableCompression,
+    column_encodings: &HashMap<String, ColumnEncodingHint>,
 ) -> Result<FileMetaData> {
     assert!(!blocks.is_empty());
-    let props = WriterProperties::builder()
+    let mut builder = WriterProperties::builder()
         .set_compression(compression.into())
         // use `usize::MAX` to effectively limit the number of row groups to 1
         .set_max_row_group_size(usize::MAX)
         .set_encoding(Encoding::PLAIN)
         .set_dictionary_enabled(false)
         .set_statistics_enabled(EnabledStatistics::None)
-        .set_bloom_filter_enabled(false)
-        .build();
+        .set_bloom_filter_enabled(false);
+    for (name, hint) in column_encodings {
+        let path = ColumnPath::from(name.as_str());
+        builder = match hint {
+            ColumnEncodingHint::NarrowInteger => {
+                builder.set_column_encoding(path, Encoding::DELTA_BINARY_PACKED)
+            }
+            ColumnEncodingHint::Dictionary => builder.set_column_dictionary_enabled(path, true),
+        };
+    }
+    let props = builder.build();
     let batches = blocks
         .into_iter()
         .map(|block| block.to_record_batch(table_schema))
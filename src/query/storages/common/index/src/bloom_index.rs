@@ -54,6 +54,7 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_storages_common_table_meta::meta::SingleColumnMeta;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use databend_storages_common_table_meta::meta::Versioned;
+use jsonb::get_by_name;
 use parquet::format::FileMetaData;
 
 use crate::filters::BlockBloomFilterIndexVersion;
@@ -111,6 +112,23 @@ impl TryFrom<FileMetaData> for BloomIndexMeta {
     }
 }
 
+/// The max number of filters built for a target, each filter has a false positive rate of about 1/256.
+const MAX_FILTERS_PER_TARGET: usize = 4;
+
+/// BloomIndexTarget represents a filter to be built on a column.
+///
+/// By default the filter is built on the whole column. If `path` is not empty, the filter is built
+/// on the values of the map keys or the variant path, e.g. `m['k']` or `v['a']['b']`.
+/// If `fpr` is set, multiple filters may be built to reach the target false positive rate.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BloomIndexTarget {
+    pub path: Vec<String>,
+    pub fpr: Option<f64>,
+}
+
+/// The filters to be built on the columns, the columns not included use the default filter.
+pub type BloomIndexTargets = BTreeMap<FieldIndex, Vec<BloomIndexTarget>>;
+
 /// BlockFilter represents multiple per-column filters(bloom filter or xor filter etc) for data block.
 ///
 /// By default we create a filter per column for a parquet data file. For columns whose data_type
@@ -185,6 +203,23 @@ impl BloomIndex {
         version: u64,
         block: &DataBlock,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        Self::try_create_with_targets(
+            func_ctx,
+            version,
+            block,
+            bloom_columns_map,
+            &BloomIndexTargets::new(),
+        )
+    }
+
+    /// Create a filter block from source data, with the filters specified by `bloom_index_targets`.
+    pub fn try_create_with_targets(
+        func_ctx: FunctionContext,
+        version: u64,
+        block: &DataBlock,
+        bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        bloom_index_targets: &BloomIndexTargets,
     ) -> Result<Option<Self>> {
         // TODO refactor :
         // if only current version is allowed, just use the current version
@@ -209,94 +244,46 @@ impl BloomIndex {
             };
 
             let field_type = &block.get_by_offset(index).data_type;
-            if !Xor8Filter::supported_type(field_type) {
-                continue;
-            }
-
-            let (column, data_type) = match field_type.remove_nullable() {
-                DataType::Map(box inner_ty) => {
-                    // Add bloom filter for the value of map type
-                    let map_column = if field_type.is_nullable() {
-                        let nullable_column =
-                            NullableType::<MapType<AnyType, AnyType>>::try_downcast_column(&column)
-                                .unwrap();
-                        nullable_column.column
-                    } else {
-                        MapType::<AnyType, AnyType>::try_downcast_column(&column).unwrap()
-                    };
-                    let column = map_column.values.values;
-
-                    let val_type = match inner_ty {
-                        DataType::Tuple(kv_tys) => kv_tys[1].clone(),
-                        _ => unreachable!(),
-                    };
-                    // Extract JSON value of string type to create bloom index,
-                    // other types of JSON value will be ignored.
-                    if val_type.remove_nullable() == DataType::Variant {
-                        let mut builder = ColumnBuilder::with_capacity(
-                            &DataType::Nullable(Box::new(DataType::String)),
-                            column.len(),
-                        );
-                        for val in column.iter() {
-                            if let ScalarRef::Variant(v) = val {
-                                if let Ok(str_val) = jsonb::to_str(v) {
-                                    builder.push(ScalarRef::String(str_val.as_str()));
-                                    continue;
-                                }
-                            }
-                            builder.push_default();
-                        }
-                        let str_column = builder.build();
-                        if Self::check_large_string(&str_column) {
-                            continue;
-                        }
-                        let str_type = DataType::Nullable(Box::new(DataType::String));
-                        (str_column, str_type)
-                    } else {
-                        if Self::check_large_string(&column) {
-                            continue;
-                        }
-                        (column, val_type)
-                    }
-                }
-                _ => {
-                    if Self::check_large_string(&column) {
+            let default_targets = vec![BloomIndexTarget::default()];
+            let targets = bloom_index_targets.get(&index).unwrap_or(&default_targets);
+            for target in targets {
+                let target_column = if target.path.is_empty() {
+                    if !Xor8Filter::supported_type(field_type) {
                         continue;
                     }
-                    (column, field_type.clone())
-                }
-            };
+                    Self::filter_column(&column, field_type)
+                } else {
+                    Self::key_path_column(&column, field_type, &target.path)
+                };
+                let Some((target_column, data_type)) = target_column else {
+                    continue;
+                };
 
-            let (column, validity) =
-                Self::calculate_nullable_column_digest(&func_ctx, &column, &data_type)?;
-
-            // create filter per column
-            let mut filter_builder = Xor8Builder::create();
-            if validity.as_ref().map(|v| v.unset_bits()).unwrap_or(0) > 0 {
-                let validity = validity.unwrap();
-                let it = column.deref().iter().zip(validity.iter()).map(
-                    |(v, b)| {
-                        if !b { &0 } else { v }
-                    },
-                );
-                filter_builder.add_digests(it);
-            } else {
-                filter_builder.add_digests(column.deref());
-            }
-            let filter = filter_builder.build()?;
+                let (digests, validity) =
+                    Self::calculate_nullable_column_digest(&func_ctx, &target_column, &data_type)?;
 
-            if let Some(len) = filter.len() {
-                match field.data_type() {
-                    TableDataType::Map(_) => {}
-                    _ => {
-                        column_distinct_count.insert(index, len);
+                let filter_name = Self::build_key_filter_column_name(
+                    &Self::build_filter_column_name(version, &field)?,
+                    &target.path,
+                );
+                // create filters per target, the extra filters are built on the rehashed digests
+                // to reduce the false positive rate.
+                for seed in 0..Self::num_filters(target.fpr) as u64 {
+                    let filter = Self::build_filter(&digests, &validity, seed)?;
+                    if seed == 0
+                        && target.path.is_empty()
+                        && !matches!(field.data_type(), TableDataType::Map(_))
+                    {
+                        if let Some(len) = filter.len() {
+                            column_distinct_count.insert(index, len);
+                        }
                     }
+
+                    let name = Self::build_extra_filter_column_name(&filter_name, seed);
+                    filter_fields.push(TableField::new(&name, TableDataType::Binary));
+                    filters.push(Arc::new(filter));
                 }
             }
-
-            let filter_name = Self::build_filter_column_name(version, &field)?;
-            filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
-            filters.push(Arc::new(filter));
         }
 
         if filter_fields.is_empty() {
@@ -314,6 +301,176 @@ impl BloomIndex {
         }))
     }
 
+    /// Get the column to build the filter on the whole column,
+    /// returns None if the column is not applicable for a filter.
+    fn filter_column(column: &Column, field_type: &DataType) -> Option<(Column, DataType)> {
+        match field_type.remove_nullable() {
+            DataType::Map(box inner_ty) => {
+                // Add bloom filter for the value of map type
+                let map_column = if field_type.is_nullable() {
+                    let nullable_column =
+                        NullableType::<MapType<AnyType, AnyType>>::try_downcast_column(column)
+                            .unwrap();
+                    nullable_column.column
+                } else {
+                    MapType::<AnyType, AnyType>::try_downcast_column(column).unwrap()
+                };
+                let column = map_column.values.values;
+
+                let val_type = match inner_ty {
+                    DataType::Tuple(kv_tys) => kv_tys[1].clone(),
+                    _ => unreachable!(),
+                };
+                // Extract JSON value of string type to create bloom index,
+                // other types of JSON value will be ignored.
+                if val_type.remove_nullable() == DataType::Variant {
+                    let mut builder = ColumnBuilder::with_capacity(
+                        &DataType::Nullable(Box::new(DataType::String)),
+                        column.len(),
+                    );
+                    for val in column.iter() {
+                        if let ScalarRef::Variant(v) = val {
+                            if let Ok(str_val) = jsonb::to_str(v) {
+                                builder.push(ScalarRef::String(str_val.as_str()));
+                                continue;
+                            }
+                        }
+                        builder.push_default();
+                    }
+                    let str_column = builder.build();
+                    if Self::check_large_string(&str_column) {
+                        return None;
+                    }
+                    let str_type = DataType::Nullable(Box::new(DataType::String));
+                    Some((str_column, str_type))
+                } else {
+                    if Self::check_large_string(&column) {
+                        return None;
+                    }
+                    Some((column, val_type))
+                }
+            }
+            _ => {
+                if Self::check_large_string(column) {
+                    return None;
+                }
+                Some((column.clone(), field_type.clone()))
+            }
+        }
+    }
+
+    /// Get the column of values under the map key or the variant path to build the filter,
+    /// only JSON value of string type are kept for variant.
+    fn key_path_column(
+        column: &Column,
+        field_type: &DataType,
+        path: &[String],
+    ) -> Option<(Column, DataType)> {
+        let (key, sub_path) = path.split_first()?;
+        let str_type = DataType::Nullable(Box::new(DataType::String));
+        let (column, data_type) = match field_type.remove_nullable() {
+            DataType::Map(box DataType::Tuple(kv_tys)) => {
+                let map_column = if field_type.is_nullable() {
+                    let nullable_column =
+                        NullableType::<MapType<AnyType, AnyType>>::try_downcast_column(column)
+                            .unwrap();
+                    nullable_column.column
+                } else {
+                    MapType::<AnyType, AnyType>::try_downcast_column(column).unwrap()
+                };
+                let is_variant = kv_tys[1].remove_nullable() == DataType::Variant;
+                if !is_variant && !sub_path.is_empty() {
+                    return None;
+                }
+                let data_type = if is_variant {
+                    str_type
+                } else {
+                    kv_tys[1].wrap_nullable()
+                };
+
+                let mut builder = ColumnBuilder::with_capacity(&data_type, map_column.len());
+                for kv_column in map_column.iter() {
+                    let val = kv_column
+                        .iter()
+                        .find(|(k, _)| matches!(k, ScalarRef::String(k) if *k == key.as_str()))
+                        .map(|(_, v)| v);
+                    match val {
+                        Some(ScalarRef::Variant(v)) => {
+                            match Self::variant_path_to_str(v, sub_path) {
+                                Some(str_val) => builder.push(ScalarRef::String(str_val.as_str())),
+                                None => builder.push_default(),
+                            }
+                        }
+                        Some(v) if !is_variant => builder.push(v),
+                        _ => builder.push_default(),
+                    }
+                }
+                (builder.build(), data_type)
+            }
+            DataType::Variant => {
+                let mut builder = ColumnBuilder::with_capacity(&str_type, column.len());
+                for val in column.iter() {
+                    match val {
+                        ScalarRef::Variant(v) => match Self::variant_path_to_str(v, path) {
+                            Some(str_val) => builder.push(ScalarRef::String(str_val.as_str())),
+                            None => builder.push_default(),
+                        },
+                        _ => builder.push_default(),
+                    }
+                }
+                (builder.build(), str_type)
+            }
+            _ => return None,
+        };
+        if Self::check_large_string(&column) {
+            return None;
+        }
+        Some((column, data_type))
+    }
+
+    fn variant_path_to_str(value: &[u8], path: &[String]) -> Option<String> {
+        let mut value = value.to_vec();
+        for name in path {
+            value = get_by_name(&value, name, false)?;
+        }
+        jsonb::to_str(&value).ok()
+    }
+
+    /// Build a filter on the digests, the digests are rehashed with the seed if it is not zero.
+    fn build_filter(
+        digests: &Buffer<u64>,
+        validity: &Option<Bitmap>,
+        seed: u64,
+    ) -> Result<Xor8Filter> {
+        let mut filter_builder = Xor8Builder::create();
+        let digests: Vec<u64> = match validity {
+            Some(validity) if validity.unset_bits() > 0 => digests
+                .iter()
+                .zip(validity.iter())
+                .map(|(v, b)| if !b { 0 } else { *v })
+                .map(|v| rehash_digest(v, seed))
+                .collect(),
+            _ if seed == 0 => {
+                filter_builder.add_digests(digests.deref());
+                return Ok(filter_builder.build()?);
+            }
+            _ => digests.iter().map(|v| rehash_digest(*v, seed)).collect(),
+        };
+        filter_builder.add_digests(digests.iter());
+        Ok(filter_builder.build()?)
+    }
+
+    /// The number of filters to build for the target false positive rate.
+    fn num_filters(fpr: Option<f64>) -> usize {
+        match fpr {
+            Some(fpr) if fpr > 0.0 && fpr < 1.0 => {
+                let n = (fpr.ln() / (1.0f64 / 256.0).ln()).ceil() as usize;
+                n.clamp(1, MAX_FILTERS_PER_TARGET)
+            }
+            _ => 1,
+        }
+    }
+
     pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
         let fields = self.filter_schema.fields();
         let mut filter_columns = Vec::with_capacity(fields.len());
@@ -343,15 +500,26 @@ impl BloomIndex {
 
         visit_expr_column_eq_constant(
             &mut expr,
-            &mut |span, col_name, scalar, ty, return_type| {
-                let filter_column = &Self::build_filter_column_name(
-                    self.version,
-                    data_schema.field_with_name(col_name)?,
-                )?;
+            &mut |span, col_name, path, scalar, ty, return_type| {
+                let field = data_schema.field_with_name(col_name)?;
+                let mut filter_column = Self::build_filter_column_name(self.version, field)?;
+                if !path.is_empty() {
+                    // Prefer the filter built on the map key or variant path, the filter of
+                    // the whole map column can be used for the value of a single map key.
+                    let key_filter_column =
+                        Self::build_key_filter_column_name(&filter_column, path);
+                    if self.filter_schema.has_field(&key_filter_column) {
+                        filter_column = key_filter_column;
+                    } else if path.len() > 1
+                        || !matches!(field.data_type().remove_nullable(), TableDataType::Map(_))
+                    {
+                        return Ok(None);
+                    }
+                }
 
                 // If the column doesn't contain the constant,
                 // we rewrite the expression to a new column with `false` domain.
-                if self.find(filter_column, scalar, ty, scalar_map)? == FilterEvalResult::MustFalse
+                if self.find(&filter_column, scalar, ty, scalar_map)? == FilterEvalResult::MustFalse
                 {
                     let new_col_name = format!("__bloom_column_{}_{}", col_name, new_col_id);
                     new_col_id += 1;
@@ -476,7 +644,7 @@ impl BloomIndex {
         fields: Vec<TableField>,
     ) -> Result<Vec<(TableField, Scalar, DataType)>> {
         let mut cols = Vec::new();
-        visit_expr_column_eq_constant(&mut expr.clone(), &mut |_, col_name, scalar, ty, _| {
+        visit_expr_column_eq_constant(&mut expr.clone(), &mut |_, col_name, _, scalar, ty, _| {
             if let Some(v) = fields.iter().find(|f: &&TableField| f.name() == col_name) {
                 if Xor8Filter::supported_type(ty) && !scalar.is_null() {
                     cols.push((v.clone(), scalar.clone(), ty.clone()));
@@ -502,6 +670,34 @@ impl BloomIndex {
         }
     }
 
+    /// The filter of a map key or a variant path will be stored with field name
+    /// like 'Bloom(column_id)['k1']['k2']'
+    pub fn build_key_filter_column_name(filter_column: &str, path: &[String]) -> String {
+        let mut name = filter_column.to_string();
+        for key in path {
+            name.push_str(&format!("['{}']", key));
+        }
+        name
+    }
+
+    /// The extra filters to reduce the false positive rate will be stored with field name
+    /// like 'Bloom(column_id)#1'
+    pub fn build_extra_filter_column_name(filter_column: &str, seed: u64) -> String {
+        if seed == 0 {
+            filter_column.to_string()
+        } else {
+            format!("{}#{}", filter_column, seed)
+        }
+    }
+
+    /// The names of all the filters that may be built for the filter column, including the
+    /// extra filters to reduce the false positive rate.
+    pub fn all_filter_column_names(filter_column: &str) -> Vec<String> {
+        (0..MAX_FILTERS_PER_TARGET as u64)
+            .map(|seed| Self::build_extra_filter_column_name(filter_column, seed))
+            .collect()
+    }
+
     fn find(
         &self,
         filter_column: &str,
@@ -524,9 +720,16 @@ impl BloomIndex {
             let data_value = scalar_to_datavalue(target);
             filter.contains(&data_value)
         } else {
-            scalar_map
-                .get(target)
-                .map_or(true, |digest| filter.contains_digest(*digest))
+            scalar_map.get(target).map_or(true, |digest| {
+                // The value exists only if all the filters of the column contain it.
+                (0..MAX_FILTERS_PER_TARGET as u64).all(|seed| {
+                    let name = Self::build_extra_filter_column_name(filter_column, seed);
+                    match self.filter_schema.index_of(&name) {
+                        Ok(idx) => self.filters[idx].contains_digest(rehash_digest(*digest, seed)),
+                        Err(_) => true,
+                    }
+                })
+            })
         };
 
         if contains {
@@ -554,12 +757,32 @@ impl BloomIndex {
     }
 }
 
+/// Rehash the digest with the seed, so that the filters built with different seeds
+/// are independent. The digest is unchanged if the seed is zero.
+fn rehash_digest(digest: u64, seed: u64) -> u64 {
+    if seed == 0 {
+        return digest;
+    }
+    let mut z = digest ^ seed.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 fn visit_expr_column_eq_constant(
     expr: &mut Expr<String>,
-    visitor: &mut impl FnMut(Span, &str, &Scalar, &DataType, &DataType) -> Result<Option<Expr<String>>>,
+    visitor: &mut impl FnMut(
+        Span,
+        &str,
+        &[String],
+        &Scalar,
+        &DataType,
+        &DataType,
+    ) -> Result<Option<Expr<String>>>,
 ) -> Result<()> {
     // Find patterns like `Column = <constant>`, `<constant> = Column`,
-    // or `MapColumn[<key>] = <constant>`, `<constant> = MapColumn[<key>]`
+    // or `MapColumn[<key>] = <constant>`, `<constant> = MapColumn[<key>]`,
+    // or `VariantColumn[<key>]::String = <constant>`
     match expr {
         Expr::FunctionCall {
             span,
@@ -596,7 +819,9 @@ fn visit_expr_column_eq_constant(
                 // debug_assert_eq!(scalar_type, column_type);
                 // If the visitor returns a new expression, then replace with the current expression.
                 if scalar_type == column_type {
-                    if let Some(new_expr) = visitor(*span, id, scalar, column_type, return_type)? {
+                    if let Some(new_expr) =
+                        visitor(*span, id, &[], scalar, column_type, return_type)?
+                    {
                         *expr = new_expr;
 
                         return Ok(());
@@ -706,31 +931,75 @@ fn visit_map_column(
     scalar: &Scalar,
     scalar_type: &DataType,
     return_type: &DataType,
-    visitor: &mut impl FnMut(Span, &str, &Scalar, &DataType, &DataType) -> Result<Option<Expr<String>>>,
+    visitor: &mut impl FnMut(
+        Span,
+        &str,
+        &[String],
+        &Scalar,
+        &DataType,
+        &DataType,
+    ) -> Result<Option<Expr<String>>>,
 ) -> Result<Option<Expr<String>>> {
-    match &args[0] {
-        Expr::ColumnRef { id, data_type, .. }
-        | Expr::Cast {
-            expr: box Expr::ColumnRef { id, data_type, .. },
-            ..
-        } => {
-            if let DataType::Map(box inner_ty) = data_type.remove_nullable() {
-                let val_type = match inner_ty {
-                    DataType::Tuple(kv_tys) => kv_tys[1].clone(),
-                    _ => unreachable!(),
-                };
-                // Only JSON value of string type have bloom index.
-                if val_type.remove_nullable() == DataType::Variant {
-                    if scalar_type.remove_nullable() != DataType::String {
-                        return Ok(None);
-                    }
-                } else if val_type.remove_nullable() != scalar_type.remove_nullable() {
+    let mut keys = Vec::new();
+    let Some((id, data_type)) = visit_key_path(args, &mut keys) else {
+        return Ok(None);
+    };
+    // The keys of the filters are strings, other keys can only use the filter of the whole map.
+    let path = keys
+        .iter()
+        .map(|key| match key {
+            Scalar::String(key) => Some(key.clone()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default();
+    match data_type.remove_nullable() {
+        DataType::Map(box inner_ty) => {
+            let val_type = match inner_ty {
+                DataType::Tuple(kv_tys) => kv_tys[1].clone(),
+                _ => unreachable!(),
+            };
+            // Only JSON value of string type have bloom index.
+            if val_type.remove_nullable() == DataType::Variant {
+                if scalar_type.remove_nullable() != DataType::String {
                     return Ok(None);
                 }
-                return visitor(span, id, scalar, scalar_type, return_type);
+            } else if keys.len() > 1 || val_type.remove_nullable() != scalar_type.remove_nullable()
+            {
+                return Ok(None);
+            }
+            if keys.len() > 1 && path.is_empty() {
+                return Ok(None);
             }
+            visitor(span, id, &path, scalar, scalar_type, return_type)
         }
-        _ => {}
+        DataType::Variant => {
+            if path.is_empty() || scalar_type.remove_nullable() != DataType::String {
+                return Ok(None);
+            }
+            visitor(span, id, &path, scalar, scalar_type, return_type)
+        }
+        _ => Ok(None),
     }
-    Ok(None)
+}
+
+/// Collect the constant keys of the nested `get` functions, returns the column being accessed.
+fn visit_key_path<'a>(
+    args: &'a [Expr<String>],
+    keys: &mut Vec<Scalar>,
+) -> Option<(&'a str, &'a DataType)> {
+    let [inner, Expr::Constant { scalar: key, .. }] = args else {
+        return None;
+    };
+    let column = match inner {
+        Expr::ColumnRef { id, data_type, .. }
+        | Expr::Cast {
+            expr: box Expr::ColumnRef { id, data_type, .. },
+            ..
+        } => Some((id.as_str(), data_type)),
+        Expr::FunctionCall { id, args, .. } if id.name() == "get" => visit_key_path(args, keys),
+        _ => None,
+    }?;
+    keys.push(key.clone());
+    Some(column)
 }
@@ -24,6 +24,8 @@ mod range_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
+pub use bloom_index::BloomIndexTarget;
+pub use bloom_index::BloomIndexTargets;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
 pub use inverted_index::build_tantivy_footer;
@@ -47,6 +47,8 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_storages_common_index::filters::BlockFilter as LatestBloom;
 use databend_storages_common_index::filters::Xor8Filter;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::BloomIndexTarget;
+use databend_storages_common_index::BloomIndexTargets;
 use databend_storages_common_index::FilterEvalResult;
 use databend_storages_common_index::Index;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
@@ -344,6 +346,93 @@ fn test_specify_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_key_path_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "0",
+        TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, TableDataType::String],
+        })),
+    )]));
+    let map_ty = DataType::Map(Box::new(DataType::Tuple(vec![
+        DataType::String,
+        DataType::String,
+    ])));
+
+    let block = DataBlock::new_from_columns(vec![Column::Map(Box::new(
+        ArrayColumn::<KvPair<AnyType, AnyType>> {
+            values: KvColumn {
+                keys: StringType::from_data(vec!["k1", "k2", "k1"]),
+                values: StringType::from_data(vec!["a", "b", "c"]),
+            },
+            offsets: Buffer::<u64>::from(vec![0, 2, 3]),
+        }
+        .upcast(),
+    ))]);
+
+    let bloom_columns = bloom_columns_map(schema.clone(), vec![0]);
+    let mut bloom_index_targets = BloomIndexTargets::new();
+    bloom_index_targets.insert(0, vec![BloomIndexTarget {
+        path: vec!["k1".to_string()],
+        fpr: Some(0.0001),
+    }]);
+    let index = BloomIndex::try_create_with_targets(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &block,
+        bloom_columns,
+        &bloom_index_targets,
+    )?
+    .unwrap();
+
+    // Two filters are built on the key `k1` to reach the false positive rate.
+    let filter_column =
+        BloomIndex::build_filter_column_name(LatestBloom::VERSION, schema.field(0))?;
+    let key_filter_column =
+        BloomIndex::build_key_filter_column_name(&filter_column, &["k1".to_string()]);
+    assert_eq!(index.filter_schema.num_fields(), 2);
+    assert!(index.filter_schema.has_field(&key_filter_column));
+    assert!(!index.filter_schema.has_field(&filter_column));
+
+    for (val, expected) in [
+        ("a", FilterEvalResult::Uncertain),
+        ("c", FilterEvalResult::Uncertain),
+        ("b", FilterEvalResult::MustFalse),
+    ] {
+        assert_eq!(
+            expected,
+            eval_map_index(
+                &index,
+                0,
+                schema.clone(),
+                map_ty.clone(),
+                Scalar::String("k1".to_string()),
+                DataType::String,
+                Scalar::String(val.to_string()),
+                DataType::String
+            )
+        );
+    }
+
+    // There is no filter on the key `k2`, the value is uncertain.
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_map_index(
+            &index,
+            0,
+            schema.clone(),
+            map_ty,
+            Scalar::String("k2".to_string()),
+            DataType::String,
+            Scalar::String("x".to_string()),
+            DataType::String
+        )
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_string_bloom_filter() -> Result<()> {
     let schema = Arc::new(TableSchema::new(vec![
@@ -19,6 +19,10 @@ use std::sync::Arc;
 pub trait Limiter {
     fn exceeded(&self) -> bool;
     fn within_limit(&self, n: u64) -> bool;
+    /// Returns false if there is no limit to prune with.
+    fn is_limited(&self) -> bool {
+        true
+    }
 }
 
 pub struct Unlimited;
@@ -31,6 +35,10 @@ impl Limiter for Unlimited {
     fn within_limit(&self, _: u64) -> bool {
         true
     }
+
+    fn is_limited(&self) -> bool {
+        false
+    }
 }
 
 struct U64Limiter {
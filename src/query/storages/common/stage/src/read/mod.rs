@@ -14,6 +14,8 @@
 
 mod columnar;
 mod single_file_partition;
+mod stage_listing_partition;
 
 pub use columnar::*;
 pub use single_file_partition::SingleFilePartition;
+pub use stage_listing_partition::StageListingPartition;
@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartInfoPtr;
+
+/// The only partition of a stage whose files are listed lazily while reading,
+/// the reader that gets it lists and reads all the files.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Eq, PartialEq)]
+pub struct StageListingPartition {}
+
+#[typetag::serde(name = "stage_listing_part")]
+impl PartInfo for StageListingPartition {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<StageListingPartition>()
+            .is_some()
+    }
+
+    fn hash(&self) -> u64 {
+        0
+    }
+}
+
+impl StageListingPartition {
+    pub fn is_listing_part(info: &PartInfoPtr) -> bool {
+        info.as_any()
+            .downcast_ref::<StageListingPartition>()
+            .is_some()
+    }
+}
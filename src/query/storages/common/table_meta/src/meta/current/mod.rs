@@ -20,11 +20,12 @@ pub use v2::ColumnStatistics;
 pub use v2::MetaHLL;
 pub use v2::Statistics;
 pub use v2::TableSnapshotStatistics;
-pub use v4::CompactSegmentInfo;
-pub use v4::SegmentInfo;
 pub use v4::TableSnapshot;
 pub use v4::TableSnapshotLite;
+pub use v5::CompactSegmentInfo;
+pub use v5::SegmentInfo;
 
 use super::v0;
 use super::v2;
 use super::v4;
+use super::v5;
@@ -24,6 +24,7 @@ mod v1;
 mod v2;
 mod v3;
 mod v4;
+mod v5;
 mod versions;
 
 pub use compression::Compression;
@@ -55,4 +56,5 @@ pub mod testing {
     pub use super::v2::TableSnapshot as TableSnapshotV2;
     pub use super::v3::SegmentInfo as SegmentInfoV3;
     pub use super::v3::TableSnapshot as TableSnapshotV3;
+    pub use super::v4::SegmentInfo as SegmentInfoV4;
 }
@@ -82,6 +82,10 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// crc32 checksums of the column chunks in the block file, by column id
+    #[serde(default)]
+    pub column_checksums: Option<HashMap<ColumnId, u32>>,
 }
 
 impl BlockMeta {
@@ -113,6 +117,7 @@ impl BlockMeta {
             inverted_index_size,
             compression,
             create_on,
+            column_checksums: None,
         }
     }
 
@@ -260,6 +265,7 @@ impl BlockMeta {
             compression: Compression::Lz4,
             inverted_index_size: None,
             create_on: None,
+            column_checksums: None,
         }
     }
 
@@ -293,6 +299,7 @@ impl BlockMeta {
             compression: s.compression,
             inverted_index_size: None,
             create_on: None,
+            column_checksums: None,
         }
     }
 }
@@ -0,0 +1,396 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::io::Cursor;
+use std::io::Read;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
+use databend_common_io::prelude::BinaryRead;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::super::v4;
+use crate::meta::format::compress;
+use crate::meta::format::decode_segment_header;
+use crate::meta::format::encode;
+use crate::meta::format::read_and_deserialize;
+use crate::meta::format::MetaCompression;
+use crate::meta::format::SegmentHeader;
+use crate::meta::format::MAX_SEGMENT_BLOCK_NUMBER;
+use crate::meta::v2::BlockMeta;
+use crate::meta::v2::ColumnStatistics;
+use crate::meta::FormatVersion;
+use crate::meta::MetaEncoding;
+use crate::meta::Statistics;
+use crate::meta::Versioned;
+
+/// A segment comprises one or more blocks
+/// The structure of the segment is the same as that of v4, but the statistics of columns of the
+/// blocks are stored column-wise, and each column is compressed separately, so that the statistics
+/// of a column can be decoded without decoding the others.
+///
+/// The layout of the block metas:
+///
+/// ```text
+/// | size of block metas without column statistics: u64 | block metas without column statistics |
+/// | number of columns: u64 |
+/// | column id: u32 | size of column statistics: u64 | column statistics of all blocks | ...
+/// ```
+///
+/// The layout is chosen by `format_version`, a segment whose `format_version` is before v5 is
+/// encoded in the layout of v4. The v5 layout is only written for the tables created with the
+/// table option `segment_format_version = 5`, so that the older nodes of a cluster being
+/// upgraded can still read the segments of the other tables.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct SegmentInfo {
+    /// format version of SegmentInfo table meta data
+    ///
+    /// Note that:
+    ///
+    /// - A instance of v5::SegmentInfo may have a value of v4/v3/v2/v1::SegmentInfo::VERSION for this field.
+    ///
+    ///   That indicates this instance is converted from a v4/v3/v2/v1::SegmentInfo.
+    ///
+    /// - The meta writers are responsible for only writing down one of the
+    /// [`SegmentInfo::WRITABLE_VERSIONS`], which is chosen by the table option.
+    pub format_version: FormatVersion,
+    /// blocks belong to this segment
+    pub blocks: Vec<Arc<BlockMeta>>,
+    /// summary statistics
+    pub summary: Statistics,
+}
+
+impl SegmentInfo {
+    /// The format version the segments are written in unless v5 is enabled for the table.
+    pub const DEFAULT_WRITE_VERSION: FormatVersion = v4::SegmentInfo::VERSION;
+    pub const WRITABLE_VERSIONS: [FormatVersion; 2] =
+        [Self::DEFAULT_WRITE_VERSION, SegmentInfo::VERSION];
+
+    pub fn new(blocks: Vec<Arc<BlockMeta>>, summary: Statistics) -> Self {
+        assert!(
+            blocks.len() <= MAX_SEGMENT_BLOCK_NUMBER,
+            "number of block overflow: {},  max number allowed {}",
+            blocks.len(),
+            MAX_SEGMENT_BLOCK_NUMBER,
+        );
+
+        Self {
+            format_version: SegmentInfo::VERSION,
+            blocks,
+            summary,
+        }
+    }
+
+    // Total block bytes of this segment.
+    pub fn total_bytes(&self) -> u64 {
+        self.summary.uncompressed_byte_size
+    }
+
+    // Encode self.blocks as RawBlockMeta.
+    fn block_raw_bytes(&self) -> Result<RawBlockMeta> {
+        let encoding = MetaEncoding::MessagePack;
+        let compression = MetaCompression::default();
+        let bytes = encode_block_metas(&self.blocks, self.format_version, &encoding, &compression)?;
+
+        Ok(RawBlockMeta {
+            bytes,
+            encoding,
+            compression,
+            columnar: self.format_version >= SegmentInfo::VERSION,
+        })
+    }
+}
+
+// use the chain of converters, for versions before v5
+impl<T> From<T> for SegmentInfo
+where T: Into<v4::SegmentInfo>
+{
+    fn from(value: T) -> Self {
+        Self::from_v4(value.into())
+    }
+}
+
+impl SegmentInfo {
+    pub fn from_v4(s: v4::SegmentInfo) -> Self {
+        // NOTE: it is important to let the format_version return from here
+        // carries the format_version of segment info being converted.
+        Self {
+            format_version: s.format_version,
+            blocks: s.blocks,
+            summary: s.summary,
+        }
+    }
+
+    /// Serializes the Segment struct to a byte vector.
+    ///
+    /// The byte vector contains the format version, encoding, compression, the block data and
+    /// compressed summary data. The block data is laid out column-wise since v5, each part of
+    /// it is compressed separately.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.to_bytes_with_encoding(MetaEncoding::MessagePack)
+    }
+
+    fn to_bytes_with_encoding(&self, encoding: MetaEncoding) -> Result<Vec<u8>> {
+        let compression = MetaCompression::default();
+
+        let blocks =
+            encode_block_metas(&self.blocks, self.format_version, &encoding, &compression)?;
+
+        let summary = encode(&encoding, &self.summary)?;
+        let summary_compress = compress(&compression, summary)?;
+
+        let data_size = self.format_version.to_le_bytes().len()
+            + 2
+            + blocks.len().to_le_bytes().len()
+            + blocks.len()
+            + summary_compress.len().to_le_bytes().len()
+            + summary_compress.len();
+        let mut buf = Vec::with_capacity(data_size);
+
+        buf.extend_from_slice(&self.format_version.to_le_bytes());
+        buf.push(encoding as u8);
+        buf.push(compression as u8);
+        buf.extend_from_slice(&blocks.len().to_le_bytes());
+        buf.extend_from_slice(&summary_compress.len().to_le_bytes());
+
+        buf.extend(blocks);
+        buf.extend(summary_compress);
+
+        Ok(buf)
+    }
+
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        let compact = CompactSegmentInfo::from_slice(bytes)?;
+        let mut segment = Self::new(compact.block_metas()?, compact.summary);
+
+        // bytes may represent an encoded v[n]::SegmentInfo, where n <= self::SegmentInfo::VERSION
+        // please see PR https://github.com/datafuselabs/databend/pull/11211 for the adjustment of
+        // format_version`'s "semantic"
+        segment.format_version = compact.format_version;
+        Ok(segment)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct RawBlockMeta {
+    pub bytes: Vec<u8>,
+    pub encoding: MetaEncoding,
+    pub compression: MetaCompression,
+    /// Whether the statistics of columns are stored column-wise, which is true since v5.
+    pub columnar: bool,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct CompactSegmentInfo {
+    pub format_version: FormatVersion,
+    pub summary: Statistics,
+    pub raw_block_metas: RawBlockMeta,
+}
+
+impl CompactSegmentInfo {
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        Self::from_reader(Cursor::new(bytes))
+    }
+
+    /// Reads the segment of v4 or v5, which share the same header.
+    pub fn from_reader(mut r: impl Read) -> Result<Self> {
+        let SegmentHeader {
+            version,
+            encoding,
+            compression,
+            blocks_size,
+            summary_size,
+        } = decode_segment_header(&mut r)?;
+
+        let mut block_metas_raw_bytes = vec![0; blocks_size as usize];
+        r.read_exact(&mut block_metas_raw_bytes)?;
+
+        let summary: Statistics =
+            read_and_deserialize(&mut r, summary_size, &encoding, &compression)?;
+
+        let segment = CompactSegmentInfo {
+            format_version: version,
+            summary,
+            raw_block_metas: RawBlockMeta {
+                bytes: block_metas_raw_bytes,
+                encoding,
+                compression,
+                columnar: version >= SegmentInfo::VERSION,
+            },
+        };
+        Ok(segment)
+    }
+
+    pub fn block_metas(&self) -> Result<Vec<Arc<BlockMeta>>> {
+        self.block_metas_with_columns(None)
+    }
+
+    /// Decodes the block metas with the statistics of only `column_ids`, or of all the columns
+    /// if it's `None`. The statistics of the other columns are skipped without being
+    /// decompressed in the columnar layout of v5, the segments of the older versions are
+    /// decoded with the statistics of all the columns.
+    pub fn block_metas_with_columns(
+        &self,
+        column_ids: Option<&HashSet<ColumnId>>,
+    ) -> Result<Vec<Arc<BlockMeta>>> {
+        let raw = &self.raw_block_metas;
+        if !raw.columnar {
+            let mut reader = Cursor::new(&raw.bytes);
+            return read_and_deserialize(
+                &mut reader,
+                raw.bytes.len() as u64,
+                &raw.encoding,
+                &raw.compression,
+            );
+        }
+
+        let mut reader = Cursor::new(&raw.bytes);
+        let size = reader.read_scalar::<u64>()?;
+        let mut blocks: Vec<BlockMeta> =
+            read_and_deserialize(&mut reader, size, &raw.encoding, &raw.compression)?;
+        let num_columns = reader.read_scalar::<u64>()?;
+        for _ in 0..num_columns {
+            let column_id = reader.read_scalar::<u32>()?;
+            let size = reader.read_scalar::<u64>()?;
+            if column_ids.is_some_and(|ids| !ids.contains(&column_id)) {
+                reader.set_position(reader.position() + size);
+                continue;
+            }
+            let col_stats: Vec<Option<ColumnStatistics>> =
+                read_and_deserialize(&mut reader, size, &raw.encoding, &raw.compression)?;
+            if col_stats.len() != blocks.len() {
+                return Err(ErrorCode::StorageOther(format!(
+                    "invalid segment, the statistics of column {} has {} blocks, but the segment has {} blocks",
+                    column_id,
+                    col_stats.len(),
+                    blocks.len()
+                )));
+            }
+            for (block, stats) in blocks.iter_mut().zip(col_stats) {
+                if let Some(stats) = stats {
+                    block.col_stats.insert(column_id, stats);
+                }
+            }
+        }
+        Ok(blocks.into_iter().map(Arc::new).collect())
+    }
+}
+
+/// Encodes the block metas in the layout of the format version, the versions before v5 are
+/// encoded in the layout of v4::SegmentInfo.
+fn encode_block_metas(
+    blocks: &[Arc<BlockMeta>],
+    format_version: FormatVersion,
+    encoding: &MetaEncoding,
+    compression: &MetaCompression,
+) -> Result<Vec<u8>> {
+    if format_version >= SegmentInfo::VERSION {
+        encode_columnar_block_metas(blocks, encoding, compression)
+    } else {
+        compress(compression, encode(encoding, &blocks)?)
+    }
+}
+
+/// Encodes the block metas in the columnar layout of v5::SegmentInfo.
+fn encode_columnar_block_metas(
+    blocks: &[Arc<BlockMeta>],
+    encoding: &MetaEncoding,
+    compression: &MetaCompression,
+) -> Result<Vec<u8>> {
+    let column_ids = blocks
+        .iter()
+        .flat_map(|block| block.col_stats.keys().copied())
+        .collect::<BTreeSet<_>>();
+
+    let blocks_without_stats = blocks
+        .iter()
+        .map(|block| BlockMeta {
+            col_stats: HashMap::new(),
+            ..block.as_ref().clone()
+        })
+        .collect::<Vec<_>>();
+    let blocks_bytes = compress(compression, encode(encoding, &blocks_without_stats)?)?;
+
+    let mut buf = Vec::with_capacity(blocks_bytes.len() + 16);
+    buf.extend_from_slice(&(blocks_bytes.len() as u64).to_le_bytes());
+    buf.extend(blocks_bytes);
+    buf.extend_from_slice(&(column_ids.len() as u64).to_le_bytes());
+    for column_id in column_ids {
+        let col_stats = blocks
+            .iter()
+            .map(|block| block.col_stats.get(&column_id))
+            .collect::<Vec<_>>();
+        let bytes = compress(compression, encode(encoding, &col_stats)?)?;
+        buf.extend_from_slice(&column_id.to_le_bytes());
+        buf.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        buf.extend(bytes);
+    }
+    Ok(buf)
+}
+
+impl TryFrom<Arc<CompactSegmentInfo>> for SegmentInfo {
+    type Error = ErrorCode;
+    fn try_from(value: Arc<CompactSegmentInfo>) -> Result<Self, Self::Error> {
+        let blocks = value.block_metas()?;
+        Ok(SegmentInfo {
+            format_version: value.format_version,
+            blocks,
+            summary: value.summary.clone(),
+        })
+    }
+}
+
+impl TryFrom<&CompactSegmentInfo> for SegmentInfo {
+    type Error = ErrorCode;
+    fn try_from(value: &CompactSegmentInfo) -> Result<Self, Self::Error> {
+        let blocks = value.block_metas()?;
+        Ok(SegmentInfo {
+            format_version: value.format_version,
+            blocks,
+            summary: value.summary.clone(),
+        })
+    }
+}
+
+impl TryFrom<&SegmentInfo> for CompactSegmentInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: &SegmentInfo) -> Result<Self, Self::Error> {
+        let bytes = value.block_raw_bytes()?;
+        Ok(Self {
+            format_version: value.format_version,
+            summary: value.summary.clone(),
+            raw_block_metas: bytes,
+        })
+    }
+}
+
+impl TryFrom<SegmentInfo> for CompactSegmentInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: SegmentInfo) -> Result<Self, Self::Error> {
+        let bytes = value.block_raw_bytes()?;
+        Ok(Self {
+            format_version: value.format_version,
+            summary: value.summary,
+            raw_block_metas: bytes,
+        })
+    }
+}
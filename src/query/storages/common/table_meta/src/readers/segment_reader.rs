@@ -30,7 +30,9 @@ impl VersionedReader<CompactSegmentInfo> for (SegmentInfoVersion, TableSchemaRef
     where R: Read + Unpin + Send {
         let schema = &self.1;
         match &self.0 {
-            SegmentInfoVersion::V4(_) => CompactSegmentInfo::from_reader(reader),
+            SegmentInfoVersion::V5(_) | SegmentInfoVersion::V4(_) => {
+                CompactSegmentInfo::from_reader(reader)
+            }
             SegmentInfoVersion::V3(_) => {
                 let current: SegmentInfo = SegmentInfoV3::from_reader(reader)?.into();
                 current.try_into()
@@ -24,6 +24,18 @@ pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+// DML on a table, or on the tables of a database, with `read_only = true` is rejected, reads and
+// admin commands are still allowed.
+pub const OPT_KEY_READ_ONLY: &str = "read_only";
+// The locations of the corrupted blocks found by `SYSTEM VERIFY TABLE`, as a JSON array,
+// these blocks are excluded from the scans of the table.
+pub const OPT_KEY_QUARANTINED_BLOCKS: &str = "quarantined_blocks";
+// The storage policy set by `ALTER TABLE ... SET STORAGE_POLICY`, as a JSON, which contains
+// the storage params of the cold tier.
+pub const OPT_KEY_STORAGE_POLICY: &str = "storage_policy";
+// The columns added with the non-constant defaults by `ALTER TABLE ... ADD COLUMN`, as a JSON
+// array, the blocks written before are rewritten with the computed values in the background.
+pub const OPT_KEY_COLUMN_BACKFILLS: &str = "column_backfills";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -54,6 +66,9 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_QUARANTINED_BLOCKS);
+    r.insert(OPT_KEY_STORAGE_POLICY);
+    r.insert(OPT_KEY_COLUMN_BACKFILLS);
     r
 });
 
@@ -64,6 +79,9 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
+    r.insert(OPT_KEY_QUARANTINED_BLOCKS);
+    r.insert(OPT_KEY_STORAGE_POLICY);
+    r.insert(OPT_KEY_COLUMN_BACKFILLS);
     r
 });
 
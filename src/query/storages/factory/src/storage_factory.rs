@@ -21,6 +21,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_storages_delta::DeltaTable;
+use databend_common_storages_hudi::HudiTable;
 use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_memory::MemoryTable;
 use databend_common_storages_null::NullTable;
@@ -161,6 +162,13 @@ impl StorageFactory {
             table_info_refresher: None,
         });
 
+        // Register HUDI table engine
+        creators.insert("HUDI".to_string(), Storage {
+            creator: Arc::new(HudiTable::try_create),
+            descriptor: Arc::new(HudiTable::description),
+            table_info_refresher: None,
+        });
+
         StorageFactory {
             storages: creators,
             schema_refreshing_timeout: DEFAULT_SCHEMA_REFRESHING_TIMEOUT_MS,
@@ -17,6 +17,9 @@ pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+// The format version of the segments written, 4 by default, 5 stores the statistics of the
+// columns column-wise, which can't be read by the nodes before v5 is introduced.
+pub const FUSE_OPT_KEY_SEGMENT_FORMAT_VERSION: &str = "segment_format_version";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
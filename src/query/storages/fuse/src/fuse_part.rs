@@ -48,6 +48,10 @@ pub struct FuseBlockPartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// The id of the cluster key the rows of the block are sorted by.
+    pub cluster_key_id: Option<u32>,
+    /// The crc32 checksums of the column chunks, verified on read if enabled.
+    pub column_checksums: Option<HashMap<ColumnId, u32>>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -71,6 +75,10 @@ impl PartInfo for FuseBlockPartInfo {
     fn part_type(&self) -> PartInfoType {
         PartInfoType::BlockLevel
     }
+
+    fn cluster_key_id(&self) -> Option<u32> {
+        self.cluster_key_id
+    }
 }
 
 impl FuseBlockPartInfo {
@@ -84,6 +92,8 @@ impl FuseBlockPartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        cluster_key_id: Option<u32>,
+        column_checksums: Option<HashMap<ColumnId, u32>>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FuseBlockPartInfo {
             location,
@@ -94,6 +104,8 @@ impl FuseBlockPartInfo {
             sort_min_max,
             block_meta_index,
             columns_stat,
+            cluster_key_id,
+            column_checksums,
         }))
     }
 
@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -54,11 +55,14 @@ use databend_common_sql::binder::STREAM_COLUMN_FACTORY;
 use databend_common_sql::parse_cluster_keys;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storage::init_operator;
+use databend_common_storage::tiered_operator;
 use databend_common_storage::DataOperator;
+use databend_common_storage::StageOperatorPool;
 use databend_common_storage::StorageMetrics;
 use databend_common_storage::StorageMetricsLayer;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::ClusterKey;
+use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::Statistics as FuseStatistics;
 use databend_storages_common_table_meta::meta::TableSnapshot;
@@ -71,6 +75,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_QUARANTINED_BLOCKS;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -100,6 +105,7 @@ use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
+use crate::FUSE_OPT_KEY_SEGMENT_FORMAT_VERSION;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 
 #[derive(Clone)]
@@ -189,6 +195,12 @@ impl FuseTable {
                 }
             }
         };
+        if let Some(policy) = Self::parse_storage_policy(&table_info)? {
+            // The table is created on each access, the cold operator is pooled by its params
+            // as the operators of the stages.
+            let cold_operator = StageOperatorPool::instance().get(&policy.cold_storage)?;
+            operator = tiered_operator(operator, cold_operator);
+        }
 
         let data_metrics = Arc::new(StorageMetrics::default());
         operator = operator.layer(StorageMetricsLayer::new(data_metrics.clone()));
@@ -211,10 +223,18 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        let segment_version = table_info
+            .options()
+            .get(FUSE_OPT_KEY_SEGMENT_FORMAT_VERSION)
+            .and_then(|s| s.parse::<u64>().ok())
+            .filter(|v| SegmentInfo::WRITABLE_VERSIONS.contains(v))
+            .unwrap_or(SegmentInfo::DEFAULT_WRITE_VERSION);
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
-        let meta_location_generator =
-            TableMetaLocationGenerator::with_prefix(storage_prefix).with_part_prefix(part_prefix);
+        let meta_location_generator = TableMetaLocationGenerator::with_prefix(storage_prefix)
+            .with_part_prefix(part_prefix)
+            .with_segment_version(segment_version);
 
         Ok(Box::new(FuseTable {
             table_info,
@@ -261,6 +281,7 @@ impl FuseTable {
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            adaptive_encoding: false,
         }
     }
 
@@ -446,6 +467,19 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    /// The locations of the blocks quarantined by `SYSTEM VERIFY TABLE`.
+    pub fn quarantined_blocks(&self) -> Result<BTreeSet<String>> {
+        match self.table_info.options().get(OPT_KEY_QUARANTINED_BLOCKS) {
+            Some(value) => serde_json::from_str(value).map_err(|e| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid table option {}: {}",
+                    OPT_KEY_QUARANTINED_BLOCKS, e
+                ))
+            }),
+            None => Ok(BTreeSet::new()),
+        }
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -926,6 +960,10 @@ impl Table for FuseTable {
         true
     }
 
+    fn prefetch_metadata(&self, ctx: Arc<dyn TableContext>) {
+        self.do_prefetch_metadata(ctx)
+    }
+
     fn is_read_only(&self) -> bool {
         self.table_type.is_readonly()
     }
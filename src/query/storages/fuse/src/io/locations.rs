@@ -16,6 +16,7 @@ use std::marker::PhantomData;
 
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_storages_common_table_meta::meta::FormatVersion;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotVersion;
@@ -50,6 +51,7 @@ static SNAPSHOT_STATISTICS_V2: TableSnapshotStatisticsVersion =
 pub struct TableMetaLocationGenerator {
     prefix: String,
     part_prefix: String,
+    segment_version: FormatVersion,
 }
 
 impl TableMetaLocationGenerator {
@@ -57,6 +59,7 @@ impl TableMetaLocationGenerator {
         Self {
             prefix,
             part_prefix: "".to_string(),
+            segment_version: SegmentInfo::DEFAULT_WRITE_VERSION,
         }
     }
 
@@ -65,6 +68,13 @@ impl TableMetaLocationGenerator {
         self
     }
 
+    /// Sets the format version of the segments written, which is one of
+    /// [`SegmentInfo::WRITABLE_VERSIONS`].
+    pub fn with_segment_version(mut self, segment_version: FormatVersion) -> Self {
+        self.segment_version = segment_version;
+        self
+    }
+
     pub fn prefix(&self) -> &str {
         &self.prefix
     }
@@ -73,6 +83,10 @@ impl TableMetaLocationGenerator {
         &self.part_prefix
     }
 
+    pub fn segment_info_version(&self) -> FormatVersion {
+        self.segment_version
+    }
+
     pub fn gen_block_location(&self) -> (Location, Uuid) {
         let part_uuid = Uuid::new_v4();
         let location_path = format!(
@@ -104,10 +118,7 @@ impl TableMetaLocationGenerator {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
             "{}/{}/{}_v{}.mpk",
-            &self.prefix,
-            FUSE_TBL_SEGMENT_PREFIX,
-            segment_uuid,
-            SegmentInfo::VERSION,
+            &self.prefix, FUSE_TBL_SEGMENT_PREFIX, segment_uuid, self.segment_version,
         )
     }
 
@@ -37,10 +37,14 @@ pub use segments::SegmentsIO;
 pub use segments::SerializedSegment;
 pub use snapshots::SnapshotLiteExtended;
 pub use snapshots::SnapshotsIO;
+pub(crate) use write::bloom_index_targets;
+pub use write::choose_column_encodings;
+pub use write::column_checksums;
 pub(crate) use write::create_index_schema;
 pub(crate) use write::create_inverted_index_builders;
 pub(crate) use write::create_tokenizer_manager;
 pub use write::serialize_block;
+pub use write::serialize_block_with_encodings;
 pub use write::write_data;
 pub use write::BlockBuilder;
 pub use write::BlockSerialization;
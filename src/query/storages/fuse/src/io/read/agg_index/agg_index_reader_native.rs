@@ -63,6 +63,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    None,
                 );
                 let res = self
                     .reader
@@ -117,6 +119,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    None,
                 );
                 let res = self
                     .reader
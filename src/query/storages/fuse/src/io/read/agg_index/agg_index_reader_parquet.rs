@@ -55,6 +55,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    None,
                 );
                 let res = self
                     .reader
@@ -106,6 +108,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    None,
                 );
                 Some((part, res))
             }
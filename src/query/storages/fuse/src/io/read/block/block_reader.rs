@@ -18,6 +18,7 @@ use std::sync::Arc;
 use databend_common_arrow::arrow::datatypes::Field;
 use databend_common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use databend_common_arrow::parquet::metadata::SchemaDescriptor;
+use databend_common_catalog::fault_injection::FaultInjection;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -53,6 +54,7 @@ pub struct BlockReader {
     pub put_cache: bool,
 
     pub original_schema: TableSchemaRef,
+    pub(crate) fault_injection: FaultInjection,
 }
 
 fn inner_project_field_default_values(default_vals: &[Scalar], paths: &[usize]) -> Result<Scalar> {
@@ -137,6 +139,7 @@ impl BlockReader {
             .map(|c| (*c).clone())
             .collect();
         let project_indices = Self::build_projection_indices(&project_column_nodes);
+        let fault_injection = FaultInjection::try_create(&ctx.get_settings())?;
 
         Ok(Arc::new(BlockReader {
             ctx,
@@ -151,6 +154,7 @@ impl BlockReader {
             update_stream_columns,
             put_cache,
             original_schema: schema,
+            fault_injection,
         }))
     }
 
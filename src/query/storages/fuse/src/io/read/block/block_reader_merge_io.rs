@@ -126,6 +126,23 @@ impl MergeIOReadResult {
         Ok(res)
     }
 
+    /// Verify the raw column chunks against the checksums recorded in the block meta,
+    /// the columns read from the column array cache are skipped.
+    pub fn verify_checksums(&self, checksums: &HashMap<ColumnId, u32>) -> Result<()> {
+        for (column_id, data) in self.column_buffers()? {
+            if let Some(expected) = checksums.get(&column_id) {
+                let actual = crc32fast::hash(&data);
+                if actual != *expected {
+                    return Err(ErrorCode::BlockCorrupted(format!(
+                        "checksum mismatch of column {} in block {}, expected {}, actual {}",
+                        column_id, self.block_path, expected, actual
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn get_chunk(&self, index: usize, path: &str) -> Result<Bytes> {
         self.owner_memory.get_chunk(index, path)
     }
@@ -14,11 +14,18 @@
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::future::Future;
 use std::ops::Range;
+use std::time::Duration;
 use std::time::Instant;
 
+use backoff::backoff::Backoff;
+use databend_common_base::base::tokio;
 use databend_common_base::rangemap::RangeMerger;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_base::runtime::UnlimitedFuture;
+use databend_common_catalog::fault_injection::FaultPoint;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ColumnId;
@@ -27,12 +34,18 @@ use databend_storages_common_cache::CacheAccessorExt;
 use databend_storages_common_cache::TableDataCacheKey;
 use databend_storages_common_cache_manager::CacheManager;
 use databend_storages_common_table_meta::meta::ColumnMeta;
+use futures::future::select;
 use futures::future::try_join_all;
+use futures::future::Either;
+use log::warn;
+use opendal::Buffer;
+use opendal::ErrorKind;
 use opendal::Operator;
 
 use crate::io::read::block::block_reader_merge_io::OwnerMemory;
 use crate::io::read::ReadSettings;
 use crate::io::BlockReader;
+use crate::operations::set_backoff;
 use crate::MergeIOReadResult;
 
 impl BlockReader {
@@ -88,6 +101,7 @@ impl BlockReader {
             }
 
             read_handlers.push(UnlimitedFuture::create(Self::read_range(
+                read_settings,
                 op.clone(),
                 location,
                 idx,
@@ -142,6 +156,8 @@ impl BlockReader {
             metrics_inc_remote_io_read_parts(1);
         }
 
+        self.fault_injection.inject(FaultPoint::BlockRead).await?;
+
         let mut ranges = vec![];
         // for async read, try using table data cache (if enabled in settings)
         let column_data_cache = CacheManager::instance().get_table_data_cache();
@@ -202,16 +218,372 @@ impl BlockReader {
         Ok(merge_io_read_res)
     }
 
-    #[inline]
+    /// Read the range, and retry with exponential backoff if the storage returns
+    /// a transient error, like `503 Slow Down` of S3.
     #[async_backtrace::framed]
     async fn read_range(
+        read_settings: &ReadSettings,
         op: Operator,
         path: &str,
         index: usize,
         start: u64,
         end: u64,
     ) -> Result<(usize, Vec<u8>)> {
-        let chunk = op.read_with(path).range(start..end).await?;
+        let op = &op;
+        let chunk = retry_transient_read(read_settings, path, start..end, || {
+            hedged_read(
+                read_settings.storage_io_hedged_read_threshold,
+                move || async move { op.read_with(path).range(start..end).await },
+            )
+        })
+        .await?;
         Ok((index, chunk.to_vec()))
     }
 }
+
+/// Returns true if the error is expected to go away by retrying, the others, e.g. not found
+/// or permission denied, are returned at once.
+///
+/// The operator's `RetryLayer` retries the temporary errors first, and marks them persistent
+/// once its retries are exhausted, so the kinds of the temporary errors are checked as well.
+fn is_transient_error(err: &opendal::Error) -> bool {
+    err.is_temporary() || matches!(err.kind(), ErrorKind::Unexpected | ErrorKind::RateLimited)
+}
+
+/// Runs the read, and retries it with exponential backoff on the transient errors, at most
+/// `storage_io_max_retries` times.
+async fn retry_transient_read<F, Fut>(
+    read_settings: &ReadSettings,
+    path: &str,
+    range: Range<u64>,
+    mut read: F,
+) -> Result<Buffer>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = opendal::Result<Buffer>>,
+{
+    let mut backoff = set_backoff(
+        Some(read_settings.storage_io_retry_min_delay),
+        Some(read_settings.storage_io_retry_max_delay),
+        None,
+    );
+    let mut retries = 0;
+    loop {
+        let err = match read().await {
+            Ok(buffer) => return Ok(buffer),
+            Err(err) => err,
+        };
+        Profile::record_usize_profile(ProfileStatisticsName::StorageReadErrors, 1);
+
+        if !is_transient_error(&err) || retries >= read_settings.storage_io_max_retries {
+            return Err(err.into());
+        }
+        let Some(delay) = backoff.next_backoff() else {
+            return Err(err.into());
+        };
+
+        retries += 1;
+        Profile::record_usize_profile(ProfileStatisticsName::StorageReadRetries, 1);
+        warn!(
+            "read range {}..{} of {} failed, retry {} in {:?}: {}",
+            range.start, range.end, path, retries, delay, err
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Issues a duplicate read if the first read doesn't finish within the hedged read
+/// threshold, and uses whichever returns first, to cut the tail latency of object storage.
+async fn hedged_read<F, Fut>(threshold: Option<Duration>, read: F) -> opendal::Result<Buffer>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = opendal::Result<Buffer>>,
+{
+    let Some(threshold) = threshold else {
+        return read().await;
+    };
+
+    let first_read = Box::pin(read());
+    let timer = Box::pin(tokio::time::sleep(threshold));
+    let first_read = match select(first_read, timer).await {
+        Either::Left((res, _)) => return res,
+        Either::Right((_, first_read)) => first_read,
+    };
+
+    Profile::record_usize_profile(ProfileStatisticsName::StorageHedgedReads, 1);
+    let hedged_read = Box::pin(read());
+    // If the first finished read fails, wait for the other one.
+    match select(first_read, hedged_read).await {
+        Either::Left((Ok(buffer), _)) | Either::Right((Ok(buffer), _)) => Ok(buffer),
+        Either::Left((Err(_), hedged_read)) => hedged_read.await,
+        Either::Right((Err(_), first_read)) => first_read.await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use opendal::layers::RetryLayer;
+    use opendal::raw::Access;
+    use opendal::raw::Layer;
+    use opendal::raw::LayeredAccess;
+    use opendal::raw::OpList;
+    use opendal::raw::OpRead;
+    use opendal::raw::OpWrite;
+    use opendal::raw::RpList;
+    use opendal::raw::RpRead;
+    use opendal::raw::RpWrite;
+    use opendal::services::Memory;
+
+    use super::*;
+
+    fn read_settings(max_retries: u64) -> ReadSettings {
+        ReadSettings {
+            storage_io_min_bytes_for_seek: 48,
+            storage_io_max_page_bytes_for_read: 512 * 1024,
+            storage_io_max_retries: max_retries,
+            storage_io_retry_min_delay: Duration::from_millis(1),
+            storage_io_retry_max_delay: Duration::from_millis(10),
+            storage_io_hedged_read_threshold: None,
+            verify_block_checksum: false,
+        }
+    }
+
+    /// Fails with the errors in order, then returns the data.
+    async fn read_after_errors(
+        max_retries: u64,
+        errors: Vec<opendal::Error>,
+    ) -> (Result<Buffer>, usize) {
+        let calls = AtomicUsize::new(0);
+        let mut errors = errors.into_iter();
+        let res = retry_transient_read(&read_settings(max_retries), "t/b.parquet", 0..3, || {
+            calls.fetch_add(1, Ordering::Relaxed);
+            let res = match errors.next() {
+                Some(err) => Err(err),
+                None => Ok(Buffer::from(vec![1u8, 2, 3])),
+            };
+            async move { res }
+        })
+        .await;
+        (res, calls.load(Ordering::Relaxed))
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_read() {
+        let slow_down =
+            || opendal::Error::new(ErrorKind::Unexpected, "503 Slow Down").set_temporary();
+        let rate_limited = || opendal::Error::new(ErrorKind::RateLimited, "429");
+
+        // The transient errors are retried.
+        let (res, calls) = read_after_errors(3, vec![slow_down(), rate_limited()]).await;
+        assert_eq!(res.unwrap().to_vec(), vec![1, 2, 3]);
+        assert_eq!(calls, 3);
+
+        // Until the retries are exhausted.
+        let (res, calls) = read_after_errors(1, vec![slow_down(), slow_down()]).await;
+        assert!(res.is_err());
+        assert_eq!(calls, 2);
+
+        // Also the ones left persistent by the retry layer.
+        let exhausted =
+            opendal::Error::new(ErrorKind::Unexpected, "503 Slow Down").set_persistent();
+        let (res, calls) = read_after_errors(3, vec![exhausted]).await;
+        assert_eq!(res.unwrap().to_vec(), vec![1, 2, 3]);
+        assert_eq!(calls, 2);
+
+        // The other errors are not retried.
+        for err in [
+            opendal::Error::new(ErrorKind::NotFound, "not found"),
+            opendal::Error::new(ErrorKind::PermissionDenied, "403"),
+        ] {
+            let (res, calls) = read_after_errors(3, vec![err]).await;
+            assert!(res.is_err());
+            assert_eq!(calls, 1);
+        }
+    }
+
+    /// Fails the first `failures` reads with a temporary error, like a throttled object storage.
+    #[derive(Debug, Clone)]
+    struct FailingReadLayer {
+        failures: Arc<AtomicUsize>,
+    }
+
+    impl<A: Access> Layer<A> for FailingReadLayer {
+        type LayeredAccess = FailingReadAccessor<A>;
+
+        fn layer(&self, inner: A) -> Self::LayeredAccess {
+            FailingReadAccessor {
+                inner,
+                failures: self.failures.clone(),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct FailingReadAccessor<A> {
+        inner: A,
+        failures: Arc<AtomicUsize>,
+    }
+
+    impl<A: Access> LayeredAccess for FailingReadAccessor<A> {
+        type Inner = A;
+        type Reader = A::Reader;
+        type BlockingReader = A::BlockingReader;
+        type Writer = A::Writer;
+        type BlockingWriter = A::BlockingWriter;
+        type Lister = A::Lister;
+        type BlockingLister = A::BlockingLister;
+
+        fn inner(&self) -> &Self::Inner {
+            &self.inner
+        }
+
+        async fn read(&self, path: &str, args: OpRead) -> opendal::Result<(RpRead, Self::Reader)> {
+            let failed = self
+                .failures
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+                .is_ok();
+            if failed {
+                return Err(
+                    opendal::Error::new(ErrorKind::Unexpected, "503 Slow Down").set_temporary()
+                );
+            }
+            self.inner.read(path, args).await
+        }
+
+        async fn write(
+            &self,
+            path: &str,
+            args: OpWrite,
+        ) -> opendal::Result<(RpWrite, Self::Writer)> {
+            self.inner.write(path, args).await
+        }
+
+        async fn list(&self, path: &str, args: OpList) -> opendal::Result<(RpList, Self::Lister)> {
+            self.inner.list(path, args).await
+        }
+
+        fn blocking_read(
+            &self,
+            path: &str,
+            args: OpRead,
+        ) -> opendal::Result<(RpRead, Self::BlockingReader)> {
+            self.inner.blocking_read(path, args)
+        }
+
+        fn blocking_write(
+            &self,
+            path: &str,
+            args: OpWrite,
+        ) -> opendal::Result<(RpWrite, Self::BlockingWriter)> {
+            self.inner.blocking_write(path, args)
+        }
+
+        fn blocking_list(
+            &self,
+            path: &str,
+            args: OpList,
+        ) -> opendal::Result<(RpList, Self::BlockingLister)> {
+            self.inner.blocking_list(path, args)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_read_through_retry_layer() -> Result<()> {
+        // The retry layer gives up after 2 attempts with a persistent error, and the 3rd
+        // failure is retried by the layer again on the 2nd attempt of the read.
+        let failures = Arc::new(AtomicUsize::new(0));
+        let op = Operator::new(Memory::default())?
+            .layer(FailingReadLayer {
+                failures: failures.clone(),
+            })
+            .layer(
+                RetryLayer::new()
+                    .with_max_times(1)
+                    .with_min_delay(Duration::from_millis(1)),
+            )
+            .finish();
+        op.write("t/b.parquet", vec![1u8, 2, 3]).await?;
+
+        failures.store(3, Ordering::Relaxed);
+        let buffer = retry_transient_read(&read_settings(3), "t/b.parquet", 0..3, || async {
+            op.read_with("t/b.parquet").range(0..3).await
+        })
+        .await?;
+        assert_eq!(buffer.to_vec(), vec![1, 2, 3]);
+        assert_eq!(failures.load(Ordering::Relaxed), 0);
+
+        // The read fails if the errors outlast its retries as well.
+        failures.store(6, Ordering::Relaxed);
+        let res = retry_transient_read(&read_settings(1), "t/b.parquet", 0..3, || async {
+            op.read_with("t/b.parquet").range(0..3).await
+        })
+        .await;
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    /// Issues the reads in order, each of them finishes after its delay and fails if it's
+    /// marked so, returns the index of the read used.
+    async fn hedged_read_with(
+        threshold: Option<Duration>,
+        reads: Vec<(Duration, bool)>,
+    ) -> (opendal::Result<Buffer>, usize) {
+        let calls = AtomicUsize::new(0);
+        let res = hedged_read(threshold, || {
+            let call = calls.fetch_add(1, Ordering::Relaxed);
+            let (delay, failed) = reads[call];
+            async move {
+                tokio::time::sleep(delay).await;
+                match failed {
+                    true => Err(opendal::Error::new(ErrorKind::Unexpected, "reset")),
+                    false => Ok(Buffer::from(vec![call as u8])),
+                }
+            }
+        })
+        .await;
+        (res, calls.load(Ordering::Relaxed))
+    }
+
+    #[tokio::test]
+    async fn test_hedged_read() {
+        let ms = Duration::from_millis;
+
+        // No duplicate read is issued if the hedged reads are disabled.
+        let (res, calls) = hedged_read_with(None, vec![(ms(50), false)]).await;
+        assert_eq!(res.unwrap().to_vec(), vec![0]);
+        assert_eq!(calls, 1);
+
+        // Or the first read finishes within the threshold.
+        let (res, calls) = hedged_read_with(Some(ms(500)), vec![(ms(1), false)]).await;
+        assert_eq!(res.unwrap().to_vec(), vec![0]);
+        assert_eq!(calls, 1);
+
+        // Otherwise the duplicate read is issued, and the faster one is used.
+        let reads = vec![(ms(2000), false), (ms(1), false)];
+        let (res, calls) = hedged_read_with(Some(ms(10)), reads).await;
+        assert_eq!(res.unwrap().to_vec(), vec![1]);
+        assert_eq!(calls, 2);
+
+        let reads = vec![(ms(50), false), (ms(2000), false)];
+        let (res, calls) = hedged_read_with(Some(ms(10)), reads).await;
+        assert_eq!(res.unwrap().to_vec(), vec![0]);
+        assert_eq!(calls, 2);
+
+        // The other read is waited for if the faster one fails.
+        let reads = vec![(ms(50), true), (ms(200), false)];
+        let (res, calls) = hedged_read_with(Some(ms(10)), reads).await;
+        assert_eq!(res.unwrap().to_vec(), vec![1]);
+        assert_eq!(calls, 2);
+
+        // And the error is returned if both of them fail.
+        let reads = vec![(ms(50), true), (ms(100), true)];
+        let (res, calls) = hedged_read_with(Some(ms(10)), reads).await;
+        assert!(res.is_err());
+        assert_eq!(calls, 2);
+    }
+}
@@ -22,6 +22,7 @@ use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ColumnId;
+use databend_common_metrics::storage::*;
 use databend_common_storage::infer_schema_with_extension;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::TableDataCacheKey;
@@ -58,6 +59,12 @@ impl BlockReader {
         // Read merged range data.
         let mut io_res = Vec::with_capacity(merged_ranges.len());
         for (idx, range) in merged_ranges.iter().enumerate() {
+            // Perf.
+            {
+                metrics_inc_remote_io_seeks_after_merged(1);
+                metrics_inc_remote_io_read_bytes_after_merged(range.end - range.start);
+            }
+
             io_res.push(Self::sync_read_range(
                 op.clone(),
                 location,
@@ -13,16 +13,18 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
-use std::io::BufReader;
 use std::ops::Range;
 use std::sync::Arc;
 
+use bytes::Bytes;
 use databend_common_arrow::arrow::array::Array;
 use databend_common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use databend_common_arrow::native::read::reader::infer_schema;
 use databend_common_arrow::native::read::reader::NativeReader;
 use databend_common_arrow::native::read::NativeReadBuf;
+use databend_common_arrow::native::ColumnMeta as NativeColumnMeta;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -33,8 +35,6 @@ use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Value;
 use databend_common_metrics::storage::*;
-use databend_storages_common_table_meta::meta::ColumnMeta;
-use opendal::Operator;
 
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::BlockReader;
@@ -73,6 +73,11 @@ impl BlockReader {
                 ignore_column_ids,
             )
             .await?;
+        if settings.verify_block_checksum {
+            if let Some(checksums) = &part.column_checksums {
+                read_res.verify_checksums(checksums)?;
+            }
+        }
 
         let column_buffers = read_res.column_buffers()?;
         let mut results = BTreeMap::new();
@@ -96,8 +101,7 @@ impl BlockReader {
                         .as_native()
                         .unwrap();
                     let data = column_buffers.get(column_id).unwrap();
-                    let reader: Reader = Box::new(std::io::Cursor::new(data.clone()));
-                    NativeReader::new(reader, native_meta.pages.clone(), vec![])
+                    Self::native_column_reader(data.clone(), native_meta)
                 })
                 .collect();
 
@@ -106,42 +110,47 @@ impl BlockReader {
         Ok(results)
     }
 
-    #[async_backtrace::framed]
-    pub async fn read_native_columns_data(
-        op: Operator,
-        path: &str,
-        index: usize,
-        metas: Vec<ColumnMeta>,
-        range: Option<&Range<usize>>,
-    ) -> Result<(usize, Vec<NativeReader<Reader>>)> {
-        let mut native_readers = Vec::with_capacity(metas.len());
-        for meta in metas {
-            let mut native_meta = meta.as_native().unwrap().clone();
-            if let Some(range) = &range {
-                native_meta = native_meta.slice(range.start, range.end);
-            }
-
-            let (offset, length) = (
-                native_meta.offset,
-                native_meta.pages.iter().map(|p| p.length).sum::<u64>(),
-            );
-
-            let reader = op.read_with(path).range(offset..offset + length).await?;
-            let reader: Reader = Box::new(std::io::Cursor::new(reader.to_bytes()));
-
-            let native_reader = NativeReader::new(reader, native_meta.pages.clone(), vec![]);
-            native_readers.push(native_reader);
-        }
-
-        Ok((index, native_readers))
-    }
-
     pub fn sync_read_native_columns_data(
         &self,
         part: &PartInfoPtr,
         ignore_column_ids: &Option<HashSet<ColumnId>>,
     ) -> Result<NativeSourceData> {
         let part = FuseBlockPartInfo::from_part(part)?;
+        let settings = ReadSettings::from_ctx(&self.ctx)?;
+
+        let mut metas = HashMap::new();
+        for column_node in self.project_column_nodes.iter() {
+            if let Some(ignore_column_ids) = ignore_column_ids {
+                if column_node.leaf_column_ids.len() == 1
+                    && ignore_column_ids.contains(&column_node.leaf_column_ids[0])
+                {
+                    continue;
+                }
+            }
+
+            for column_id in &column_node.leaf_column_ids {
+                if let Some(meta) = part.columns_meta.get(column_id) {
+                    let mut native_meta = meta.as_native().unwrap().clone();
+                    if let Some(range) = part.range() {
+                        native_meta = native_meta.slice(range.start, range.end);
+                    }
+                    metas.insert(*column_id, native_meta);
+                }
+            }
+        }
+
+        // Read all the column chunks of the block at once, so that the adjacent
+        // chunks are merged into fewer requests.
+        let ranges = Self::native_column_ranges(&metas);
+        let read_res =
+            Self::sync_merge_io_read(&settings, self.operator.clone(), &part.location, &ranges)?;
+        // The checksums cover the whole column chunks, the pages of a range are not checked.
+        if settings.verify_block_checksum && part.range().is_none() {
+            if let Some(checksums) = &part.column_checksums {
+                read_res.verify_checksums(checksums)?;
+            }
+        }
+        let column_buffers = read_res.column_buffers()?;
 
         let mut results: BTreeMap<usize, Vec<NativeReader<Reader>>> = BTreeMap::new();
         for (index, column_node) in self.project_column_nodes.iter().enumerate() {
@@ -153,51 +162,41 @@ impl BlockReader {
                 }
             }
 
-            let op = self.operator.clone();
-            let metas: Vec<ColumnMeta> = column_node
+            let readers = column_node
                 .leaf_column_ids
                 .iter()
-                .filter_map(|column_id| part.columns_meta.get(column_id))
-                .cloned()
-                .collect::<Vec<_>>();
-
-            let readers =
-                Self::sync_read_native_column(op.clone(), &part.location, metas, part.range())?;
+                .filter_map(|column_id| {
+                    let native_meta = metas.get(column_id)?;
+                    let data = column_buffers.get(column_id)?;
+                    Some(Self::native_column_reader(data.clone(), native_meta))
+                })
+                .collect();
             results.insert(index, readers);
         }
 
         Ok(results)
     }
 
-    pub fn sync_read_native_column(
-        op: Operator,
-        path: &str,
-        metas: Vec<ColumnMeta>,
-        range: Option<&Range<usize>>,
-    ) -> Result<Vec<NativeReader<Reader>>> {
-        let mut native_readers = Vec::with_capacity(metas.len());
-        for meta in metas {
-            let mut native_meta = meta.as_native().unwrap().clone();
-            if let Some(range) = &range {
-                native_meta = native_meta.slice(range.start, range.end);
-            }
-            let (offset, length) = (
-                native_meta.offset,
-                native_meta.pages.iter().map(|p| p.length).sum::<u64>(),
-            );
-            let reader = op
-                .blocking()
-                .reader_with(path)
-                .call()?
-                .into_std_read(offset..offset + length)?;
-
-            let reader: Reader = Box::new(BufReader::new(reader));
-
-            let native_reader = NativeReader::new(reader, native_meta.pages.clone(), vec![]);
-            native_readers.push(native_reader);
-        }
+    /// The ranges of the native column chunks, which are passed to the merge io.
+    pub fn native_column_ranges(
+        metas: &HashMap<ColumnId, NativeColumnMeta>,
+    ) -> Vec<(ColumnId, Range<u64>)> {
+        metas
+            .iter()
+            .map(|(column_id, native_meta)| {
+                let offset = native_meta.offset;
+                let length = native_meta.pages.iter().map(|p| p.length).sum::<u64>();
+                (*column_id, offset..offset + length)
+            })
+            .collect()
+    }
 
-        Ok(native_readers)
+    pub fn native_column_reader(
+        data: Bytes,
+        native_meta: &NativeColumnMeta,
+    ) -> NativeReader<Reader> {
+        let reader: Reader = Box::new(std::io::Cursor::new(data));
+        NativeReader::new(reader, native_meta.pages.clone(), vec![])
     }
 
     #[inline(always)]
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -21,6 +22,11 @@ use databend_common_exception::Result;
 pub struct ReadSettings {
     pub storage_io_min_bytes_for_seek: u64,
     pub storage_io_max_page_bytes_for_read: u64,
+    pub storage_io_max_retries: u64,
+    pub storage_io_retry_min_delay: Duration,
+    pub storage_io_retry_max_delay: Duration,
+    pub storage_io_hedged_read_threshold: Option<Duration>,
+    pub verify_block_checksum: bool,
 }
 
 impl ReadSettings {
@@ -32,6 +38,23 @@ impl ReadSettings {
             storage_io_max_page_bytes_for_read: ctx
                 .get_settings()
                 .get_storage_io_max_page_bytes_for_read()?,
+            storage_io_max_retries: ctx.get_settings().get_storage_io_max_retries()?,
+            storage_io_retry_min_delay: Duration::from_millis(
+                ctx.get_settings().get_storage_io_retry_min_delay_ms()?,
+            ),
+            storage_io_retry_max_delay: Duration::from_millis(
+                ctx.get_settings().get_storage_io_retry_max_delay_ms()?,
+            ),
+            storage_io_hedged_read_threshold: match ctx
+                .get_settings()
+                .get_storage_io_hedged_read_threshold_ms()?
+            {
+                0 => None,
+                threshold => Some(Duration::from_millis(threshold)),
+            },
+            verify_block_checksum: ctx
+                .get_settings()
+                .get_enable_block_checksum_verification()?,
         })
     }
 }
@@ -12,15 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 
+use databend_common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use databend_common_arrow::native::read as nread;
+use databend_common_arrow::native::ColumnMeta as NativeColumnMeta;
 use databend_common_expression::ColumnId;
-use databend_storages_common_table_meta::meta::ColumnMeta;
 
 use super::VirtualColumnReader;
 use crate::io::BlockReader;
 use crate::io::NativeSourceData;
+use crate::io::ReadSettings;
+use crate::MergeIOReadResult;
 
 impl VirtualColumnReader {
     pub fn sync_read_native_data(
@@ -50,24 +54,17 @@ impl VirtualColumnReader {
 
         let mut virtual_src_cnts = self.virtual_src_cnts.clone();
 
-        let mut results = NativeSourceData::new();
-        for (index, virtual_column) in self.virtual_column_infos.iter().enumerate() {
-            for (i, f) in schema.fields.iter().enumerate() {
-                if f.name == virtual_column.name {
-                    let metas = vec![ColumnMeta::Native(metadata[i].clone())];
-                    let readers =
-                        BlockReader::sync_read_native_column(self.dal.clone(), loc, metas, None)
-                            .ok()?;
-
-                    let virtual_index = self.source_schema.num_fields() + index;
-                    results.insert(virtual_index, readers);
-                    if let Some(cnt) = virtual_src_cnts.get_mut(&virtual_column.source_name) {
-                        *cnt -= 1;
-                    }
-                    break;
-                }
-            }
+        let (metas, virtual_indices) =
+            self.collect_native_metas(&schema, &metadata, &mut virtual_src_cnts);
+        if virtual_indices.is_empty() {
+            return None;
         }
+
+        let read_settings = ReadSettings::from_ctx(&self.ctx).ok()?;
+        let ranges = BlockReader::native_column_ranges(&metas);
+        let read_res =
+            BlockReader::sync_merge_io_read(&read_settings, self.dal.clone(), loc, &ranges).ok()?;
+        let results = Self::build_native_source_data(&read_res, &metas, virtual_indices)?;
         if !results.is_empty() {
             let ignore_column_ids = self.generate_ignore_column_ids(virtual_src_cnts);
             Some((results, ignore_column_ids))
@@ -98,22 +95,44 @@ impl VirtualColumnReader {
         );
 
         let mut virtual_src_cnts = self.virtual_src_cnts.clone();
-        let mut results = NativeSourceData::new();
+        let (metas, virtual_indices) =
+            self.collect_native_metas(&schema, &metadata, &mut virtual_src_cnts);
+        if virtual_indices.is_empty() {
+            return None;
+        }
+
+        let read_settings = ReadSettings::from_ctx(&self.ctx).ok()?;
+        let ranges = BlockReader::native_column_ranges(&metas);
+        let read_res =
+            BlockReader::merge_io_read(&read_settings, self.dal.clone(), loc, &ranges, false)
+                .await
+                .ok()?;
+        let results = Self::build_native_source_data(&read_res, &metas, virtual_indices)?;
+        if !results.is_empty() {
+            let ignore_column_ids = self.generate_ignore_column_ids(virtual_src_cnts);
+            Some((results, ignore_column_ids))
+        } else {
+            None
+        }
+    }
+
+    /// Collect the metas of the virtual columns stored in the native file,
+    /// they are read together so that the adjacent chunks can be merged.
+    fn collect_native_metas(
+        &self,
+        schema: &ArrowSchema,
+        metadata: &[NativeColumnMeta],
+        virtual_src_cnts: &mut HashMap<String, usize>,
+    ) -> (HashMap<ColumnId, NativeColumnMeta>, Vec<(usize, ColumnId)>) {
+        let mut metas = HashMap::new();
+        let mut virtual_indices = Vec::new();
         for (index, virtual_column) in self.virtual_column_infos.iter().enumerate() {
             for (i, f) in schema.fields.iter().enumerate() {
                 if f.name == virtual_column.name {
-                    let metas = vec![ColumnMeta::Native(metadata[i].clone())];
-                    let (_, readers) = BlockReader::read_native_columns_data(
-                        self.dal.clone(),
-                        loc,
-                        i,
-                        metas,
-                        None,
-                    )
-                    .await
-                    .ok()?;
+                    let column_id = i as ColumnId;
+                    metas.insert(column_id, metadata[i].clone());
                     let virtual_index = self.source_schema.num_fields() + index;
-                    results.insert(virtual_index, readers);
+                    virtual_indices.push((virtual_index, column_id));
 
                     if let Some(cnt) = virtual_src_cnts.get_mut(&virtual_column.source_name) {
                         *cnt -= 1;
@@ -122,11 +141,21 @@ impl VirtualColumnReader {
                 }
             }
         }
-        if !results.is_empty() {
-            let ignore_column_ids = self.generate_ignore_column_ids(virtual_src_cnts);
-            Some((results, ignore_column_ids))
-        } else {
-            None
+        (metas, virtual_indices)
+    }
+
+    fn build_native_source_data(
+        read_res: &MergeIOReadResult,
+        metas: &HashMap<ColumnId, NativeColumnMeta>,
+        virtual_indices: Vec<(usize, ColumnId)>,
+    ) -> Option<NativeSourceData> {
+        let column_buffers = read_res.column_buffers().ok()?;
+        let mut results = NativeSourceData::new();
+        for (virtual_index, column_id) in virtual_indices {
+            let data = column_buffers.get(&column_id)?;
+            let reader = BlockReader::native_column_reader(data.clone(), metas.get(&column_id)?);
+            results.insert(virtual_index, vec![reader]);
         }
+        Some(results)
     }
 }
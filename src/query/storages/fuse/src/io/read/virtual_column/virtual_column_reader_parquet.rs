@@ -99,6 +99,8 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
+                None,
             );
 
             let merge_io_result =
@@ -144,6 +146,8 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
+                None,
             );
 
             let merge_io_result = BlockReader::merge_io_read(
@@ -25,7 +25,6 @@ use databend_storages_common_cache_manager::CacheManager;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
-use databend_storages_common_table_meta::meta::Versioned;
 use minitrace::full_name;
 use minitrace::prelude::*;
 use opendal::Operator;
@@ -119,9 +118,8 @@ impl SegmentsIO {
 
     #[async_backtrace::framed]
     pub async fn write_segment(dal: Operator, serialized_segment: SerializedSegment) -> Result<()> {
-        assert_eq!(
-            serialized_segment.segment.format_version,
-            SegmentInfo::VERSION
+        assert!(
+            SegmentInfo::WRITABLE_VERSIONS.contains(&serialized_segment.segment.format_version)
         );
         let raw_bytes = serialized_segment.segment.to_bytes()?;
         let compact_segment_info = CompactSegmentInfo::from_slice(&raw_bytes)?;
@@ -23,11 +23,15 @@ use databend_common_arrow::native::write::NativeWriter;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FieldIndex;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
@@ -41,12 +45,17 @@ use databend_common_metrics::storage::metrics_inc_block_inverted_index_write_mil
 use databend_common_metrics::storage::metrics_inc_block_inverted_index_write_nums;
 use databend_common_metrics::storage::metrics_inc_block_write_milliseconds;
 use databend_common_metrics::storage::metrics_inc_block_write_nums;
-use databend_storages_common_blocks::blocks_to_parquet;
+use databend_common_sql::BloomIndexColumns;
+use databend_storages_common_blocks::blocks_to_parquet_with_encodings;
+use databend_storages_common_blocks::ColumnEncodingHint;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::BloomIndexTarget;
+use databend_storages_common_index::BloomIndexTargets;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::ColumnMeta;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use databend_storages_common_table_meta::table::TableCompression;
 use log::info;
 use opendal::Operator;
@@ -67,12 +76,30 @@ pub fn serialize_block(
     schema: &TableSchemaRef,
     block: DataBlock,
     buf: &mut Vec<u8>,
+) -> Result<HashMap<ColumnId, ColumnMeta>> {
+    serialize_block_with_encodings(write_settings, schema, block, buf, &HashMap::new())
+}
+
+/// Serialize the block with the encodings chosen by [`choose_column_encodings`], the
+/// encodings are recorded in the column chunks of the block file, so each block can be
+/// read back without knowing how it was written.
+pub fn serialize_block_with_encodings(
+    write_settings: &WriteSettings,
+    schema: &TableSchemaRef,
+    block: DataBlock,
+    buf: &mut Vec<u8>,
+    column_encodings: &HashMap<String, ColumnEncodingHint>,
 ) -> Result<HashMap<ColumnId, ColumnMeta>> {
     let schema = Arc::new(schema.remove_virtual_computed_fields());
     match write_settings.storage_format {
         FuseStorageFormat::Parquet => {
-            let result =
-                blocks_to_parquet(&schema, vec![block], buf, write_settings.table_compression)?;
+            let result = blocks_to_parquet_with_encodings(
+                &schema,
+                vec![block],
+                buf,
+                write_settings.table_compression,
+                column_encodings,
+            )?;
             let meta = column_parquet_metas(&result, &schema)?;
             Ok(meta)
         }
@@ -114,6 +141,62 @@ pub fn serialize_block(
     }
 }
 
+// a string column is dictionary encoded if it has at most one distinct value per 10 rows.
+const DICTIONARY_ROWS_PER_DISTINCT_VALUE: u64 = 10;
+
+/// Choose the physical encodings of the top level columns of a block by its statistics:
+/// 64-bit integers whose min and max fit in 32 bits are bit-packed, and strings with
+/// few distinct values are dictionary encoded. Other columns keep the default encoding.
+pub fn choose_column_encodings(
+    schema: &TableSchemaRef,
+    col_stats: &StatisticsOfColumns,
+    row_count: u64,
+) -> HashMap<String, ColumnEncodingHint> {
+    let fits_in_i32 = |scalar: &Scalar| match scalar {
+        Scalar::Number(NumberScalar::Int64(v)) => i32::try_from(*v).is_ok(),
+        Scalar::Number(NumberScalar::UInt64(v)) => i32::try_from(*v).is_ok(),
+        _ => false,
+    };
+
+    let mut encodings = HashMap::new();
+    for field in schema.fields() {
+        let Some(stats) = col_stats.get(&field.column_id()) else {
+            continue;
+        };
+        match field.data_type().remove_nullable() {
+            TableDataType::Number(NumberDataType::Int64 | NumberDataType::UInt64) => {
+                if fits_in_i32(&stats.min) && fits_in_i32(&stats.max) {
+                    encodings.insert(field.name().clone(), ColumnEncodingHint::NarrowInteger);
+                }
+            }
+            TableDataType::String => {
+                if let Some(distinct) = stats.distinct_of_values {
+                    if distinct > 0 && distinct * DICTIONARY_ROWS_PER_DISTINCT_VALUE <= row_count {
+                        encodings.insert(field.name().clone(), ColumnEncodingHint::Dictionary);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    encodings
+}
+
+/// Compute the crc32 checksums of the column chunks in the serialized block.
+pub fn column_checksums(
+    buf: &[u8],
+    col_metas: &HashMap<ColumnId, ColumnMeta>,
+) -> HashMap<ColumnId, u32> {
+    col_metas
+        .iter()
+        .map(|(column_id, meta)| {
+            let (offset, len) = meta.offset_length();
+            let range = offset as usize..(offset + len) as usize;
+            (*column_id, crc32fast::hash(&buf[range]))
+        })
+        .collect()
+}
+
 /// Take ownership here to avoid extra copy.
 #[async_backtrace::framed]
 pub async fn write_data(data: Vec<u8>, data_accessor: &Operator, location: &str) -> Result<()> {
@@ -135,6 +218,29 @@ pub struct BloomIndexBuilder {
     pub table_dal: Operator,
     pub storage_format: FuseStorageFormat,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub bloom_index_targets: BloomIndexTargets,
+}
+
+/// Get the filters of the bloom index columns defined on map keys, variant paths
+/// or with a false positive rate.
+pub fn bloom_index_targets(
+    bloom_index_cols: &BloomIndexColumns,
+    schema: TableSchemaRef,
+) -> Result<BloomIndexTargets> {
+    let columns = bloom_index_cols.bloom_index_columns(schema)?;
+    Ok(columns
+        .into_iter()
+        .map(|(index, cols)| {
+            let targets = cols
+                .into_iter()
+                .map(|col| BloomIndexTarget {
+                    path: col.path,
+                    fpr: col.fpr,
+                })
+                .collect();
+            (index, targets)
+        })
+        .collect())
 }
 
 impl BloomIndexBuilder {
@@ -143,11 +249,12 @@ impl BloomIndexBuilder {
         block: &DataBlock,
         bloom_location: Location,
     ) -> Result<Option<(BloomIndexState, BloomIndex)>> {
-        let maybe_bloom_index = BloomIndex::try_create(
+        let maybe_bloom_index = BloomIndex::try_create_with_targets(
             self.table_ctx.get_function_context()?,
             bloom_location.1,
             block,
             self.bloom_columns_map.clone(),
+            &self.bloom_index_targets,
         )?;
 
         match maybe_bloom_index {
@@ -220,13 +327,15 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        bloom_index_targets: &BloomIndexTargets,
     ) -> Result<Option<Self>> {
         // write index
-        let maybe_bloom_index = BloomIndex::try_create(
+        let maybe_bloom_index = BloomIndex::try_create_with_targets(
             ctx.get_function_context()?,
             location.1,
             block,
             bloom_columns_map,
+            bloom_index_targets,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             Ok(Some(Self::from_bloom_index(&bloom_index, location)?))
@@ -336,6 +445,7 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub bloom_index_targets: BloomIndexTargets,
     pub inverted_index_builders: Vec<InvertedIndexBuilder>,
 }
 
@@ -352,6 +462,7 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            &self.bloom_index_targets,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
@@ -373,14 +484,22 @@ impl BlockBuilder {
         let col_stats =
             gen_columns_statistics(&data_block, column_distinct_count, &self.source_schema)?;
 
+        let column_encodings = if self.write_settings.adaptive_encoding {
+            choose_column_encodings(&self.source_schema, &col_stats, row_count)
+        } else {
+            HashMap::new()
+        };
+
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let col_metas = serialize_block(
+        let col_metas = serialize_block_with_encodings(
             &self.write_settings,
             &self.source_schema,
             data_block,
             &mut buffer,
+            &column_encodings,
         )?;
         let file_size = buffer.len() as u64;
+        let column_checksums = column_checksums(&buffer, &col_metas);
         let inverted_index_size = if !inverted_index_states.is_empty() {
             let size = inverted_index_states.iter().map(|v| v.size).sum();
             Some(size)
@@ -403,6 +522,7 @@ impl BlockBuilder {
             compression: self.write_settings.table_compression.into(),
             inverted_index_size,
             create_on: Some(Utc::now()),
+            column_checksums: Some(column_checksums),
         };
 
         let serialized = BlockSerialization {
@@ -76,9 +76,9 @@ trait Marshal {
 
 impl Marshal for SegmentInfo {
     fn marshal(&self) -> Result<Vec<u8>> {
-        // make sure the table meta we write down to object store always has the current version
-        // can we expressed as type constraint?
-        assert_eq!(self.format_version, SegmentInfo::VERSION);
+        // make sure the table meta we write down to object store always has a writable version,
+        // which is chosen by the table option
+        assert!(SegmentInfo::WRITABLE_VERSIONS.contains(&self.format_version));
         self.to_bytes()
     }
 }
@@ -115,7 +115,7 @@ mod tests {
     #[test]
     fn test_segment_format_version_validation() {
         // old versions are not allowed (runtime panics)
-        for v in 0..SegmentInfo::VERSION {
+        for v in 0..SegmentInfo::DEFAULT_WRITE_VERSION {
             let r = catch_unwind(|| {
                 let mut segment = SegmentInfo::new(vec![], Statistics::default());
                 segment.format_version = v;
@@ -124,9 +124,12 @@ mod tests {
             assert!(r.is_err())
         }
 
-        // current version allowed
-        let segment = SegmentInfo::new(vec![], Statistics::default());
-        segment.marshal().unwrap();
+        // writable versions allowed
+        for v in SegmentInfo::WRITABLE_VERSIONS {
+            let mut segment = SegmentInfo::new(vec![], Statistics::default());
+            segment.format_version = v;
+            segment.marshal().unwrap();
+        }
     }
 
     #[test]
@@ -18,8 +18,12 @@ mod meta_writer;
 mod segment_writer;
 mod write_settings;
 
+pub(crate) use block_writer::bloom_index_targets;
+pub use block_writer::choose_column_encodings;
+pub use block_writer::column_checksums;
 pub(crate) use block_writer::create_inverted_index_builders;
 pub use block_writer::serialize_block;
+pub use block_writer::serialize_block_with_encodings;
 pub use block_writer::write_data;
 pub use block_writer::BlockBuilder;
 pub use block_writer::BlockSerialization;
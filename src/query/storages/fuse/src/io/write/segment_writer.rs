@@ -15,7 +15,6 @@
 use databend_common_exception::Result;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
-use databend_storages_common_table_meta::meta::Versioned;
 use opendal::Operator;
 
 use super::meta_writer::MetaWriter;
@@ -40,8 +39,9 @@ impl<'a> SegmentWriter<'a> {
     }
 
     #[async_backtrace::framed]
-    pub async fn write_segment(&self, segment: SegmentInfo) -> Result<Location> {
+    pub async fn write_segment(&self, mut segment: SegmentInfo) -> Result<Location> {
         let location = self.generate_location();
+        segment.format_version = location.1;
         segment
             .write_meta_through_cache(self.data_accessor, &location.0)
             .await?;
@@ -59,6 +59,6 @@ impl<'a> SegmentWriter<'a> {
 
     fn generate_location(&self) -> Location {
         let path = self.location_generator.gen_segment_info_location();
-        (path, SegmentInfo::VERSION)
+        (path, self.location_generator.segment_info_version())
     }
 }
@@ -26,6 +26,10 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+
+    // pick narrower physical encodings for the columns of each block by its statistics,
+    // current only work in parquet format
+    pub adaptive_encoding: bool,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +39,7 @@ impl Default for WriteSettings {
             table_compression: TableCompression::default(),
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            adaptive_encoding: false,
         }
     }
 }
@@ -0,0 +1,355 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::ColumnId;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::parse_computed_expr;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::Statistics;
+use databend_storages_common_table_meta::table::OPT_KEY_COLUMN_BACKFILLS;
+use log::info;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::io::bloom_index_targets;
+use crate::io::create_inverted_index_builders;
+use crate::io::BlockBuilder;
+use crate::io::BlockReader;
+use crate::io::BlockWriter;
+use crate::io::ReadSettings;
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::operations::read_block;
+use crate::statistics::merge_statistics;
+use crate::statistics::reduce_block_metas;
+use crate::statistics::ClusterStatsGenerator;
+use crate::FuseTable;
+
+/// A column added with a default computed for each row, e.g. `uuid()` or an expression over
+/// the other columns. The rows written before the column was added are filled in the
+/// background, by rewriting the blocks without the column.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnBackfill {
+    pub column_id: ColumnId,
+    /// The expression over the columns of the table, which computes the values of the column.
+    /// It's kept apart from the default of the column, which the rows inserted later take.
+    pub expr: String,
+}
+
+/// The progress of a column backfill of a table.
+pub struct ColumnBackfillProgress {
+    /// None if the column is dropped.
+    pub column: Option<String>,
+    pub expr: String,
+    pub blocks_backfilled: u64,
+    pub blocks_total: u64,
+}
+
+/// Returns true if the block is written before the column is added.
+fn lacks_column(block: &BlockMeta, column_ids: &[ColumnId]) -> bool {
+    column_ids
+        .iter()
+        .any(|column_id| !block.col_metas.contains_key(column_id))
+}
+
+impl FuseTable {
+    /// The column backfills recorded by `ALTER TABLE ... ADD COLUMN`, which are not done yet.
+    pub fn column_backfills(&self) -> Result<Vec<ColumnBackfill>> {
+        match self.table_info.options().get(OPT_KEY_COLUMN_BACKFILLS) {
+            Some(value) => serde_json::from_str(value).map_err(|e| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid table option {}: {}",
+                    OPT_KEY_COLUMN_BACKFILLS, e
+                ))
+            }),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// The mutations rewriting the blocks without the backfilled columns would fill them with
+    /// the defaults on read, so they wait for the backfills to be done.
+    pub fn check_column_backfills_done(&self) -> Result<()> {
+        let backfills = self.column_backfills()?;
+        let schema = self.schema();
+        let pending = backfills
+            .iter()
+            .filter_map(|backfill| {
+                schema
+                    .fields()
+                    .iter()
+                    .find(|field| field.column_id() == backfill.column_id)
+                    .map(|field| field.name().clone())
+            })
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        Err(ErrorCode::TableColumnBackfillPending(format!(
+            "the columns [{}] of table {} are being backfilled, retry after the backfill is done or run OPTIMIZE TABLE to finish it, the progress is shown in system.column_backfills",
+            pending.join(", "),
+            self.name()
+        )))
+    }
+
+    /// Returns the leaf column ids of the backfilled columns and their fields in the schema the
+    /// blocks are written with, the dropped columns are skipped.
+    fn backfill_columns_of(
+        &self,
+        schema: &TableSchemaRef,
+        backfills: &[ColumnBackfill],
+    ) -> Vec<(usize, Vec<ColumnId>, ColumnBackfill)> {
+        backfills
+            .iter()
+            .filter_map(|backfill| {
+                let index = schema
+                    .fields()
+                    .iter()
+                    .position(|field| field.column_id() == backfill.column_id)?;
+                Some((
+                    index,
+                    schema.field(index).leaf_column_ids(),
+                    backfill.clone(),
+                ))
+            })
+            .collect()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn column_backfill_progress(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Vec<ColumnBackfillProgress>> {
+        let backfills = self.column_backfills()?;
+        if backfills.is_empty() {
+            return Ok(vec![]);
+        }
+        let schema = self.schema();
+        let mut progress = backfills
+            .iter()
+            .map(|backfill| ColumnBackfillProgress {
+                column: schema
+                    .fields()
+                    .iter()
+                    .find(|field| field.column_id() == backfill.column_id)
+                    .map(|field| field.name().clone()),
+                expr: backfill.expr.clone(),
+                blocks_backfilled: 0,
+                blocks_total: 0,
+            })
+            .collect::<Vec<_>>();
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(progress);
+        };
+
+        let columns = backfills
+            .iter()
+            .map(|backfill| {
+                schema
+                    .fields()
+                    .iter()
+                    .find(|field| field.column_id() == backfill.column_id)
+                    .map(|field| field.leaf_column_ids())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        for segment in segments_io
+            .read_segments::<SegmentInfo>(&snapshot.segments, false)
+            .await?
+        {
+            for block in segment?.blocks.iter() {
+                for (progress, column_ids) in progress.iter_mut().zip(columns.iter()) {
+                    progress.blocks_total += 1;
+                    if !lacks_column(block, column_ids) {
+                        progress.blocks_backfilled += 1;
+                    }
+                }
+            }
+        }
+        Ok(progress)
+    }
+
+    /// Rewrites at most `max_blocks` blocks without the backfilled columns, with the columns
+    /// computed by their expressions. The rewritten segments are committed in place of the
+    /// old ones.
+    ///
+    /// Returns the number of the rewritten blocks and of the blocks left to backfill.
+    #[async_backtrace::framed]
+    pub async fn backfill_columns(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        max_blocks: usize,
+    ) -> Result<(usize, usize)> {
+        let backfills = self.column_backfills()?;
+        if backfills.is_empty() {
+            return Ok((0, 0));
+        }
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok((0, 0));
+        };
+
+        // The blocks are written with the stored columns.
+        let write_schema: TableSchemaRef = self
+            .schema_with_stream()
+            .remove_virtual_computed_fields()
+            .into();
+        let columns = self.backfill_columns_of(&write_schema, &backfills);
+        if columns.is_empty() {
+            return Ok((0, 0));
+        }
+        let column_ids = columns
+            .iter()
+            .flat_map(|(_, column_ids, _)| column_ids.clone())
+            .collect::<Vec<_>>();
+
+        let data_schema = Arc::new(DataSchema::from(write_schema.clone()));
+        let mut exprs = Vec::with_capacity(columns.len());
+        for (index, _, backfill) in columns.iter() {
+            let expr = parse_computed_expr(ctx.clone(), data_schema.clone(), &backfill.expr)?;
+            let data_type = data_schema.field(*index).data_type();
+            let expr = check_cast(None, false, expr, data_type, &BUILTIN_FUNCTIONS)?;
+            exprs.push((*index, expr));
+        }
+
+        let block_reader = BlockReader::create(
+            ctx.clone(),
+            self.operator.clone(),
+            write_schema.clone(),
+            Projection::Columns((0..write_schema.num_fields()).collect()),
+            false,
+            false,
+            false,
+        )?;
+        let block_builder = BlockBuilder {
+            ctx: ctx.clone(),
+            meta_locations: self.meta_location_generator().clone(),
+            source_schema: write_schema.clone(),
+            write_settings: self.get_write_settings(),
+            cluster_stats_gen: ClusterStatsGenerator::default(),
+            bloom_columns_map: self
+                .bloom_index_cols()
+                .bloom_index_fields(write_schema.clone(), BloomIndex::supported_type)?,
+            bloom_index_targets: bloom_index_targets(&self.bloom_index_cols, write_schema.clone())?,
+            inverted_index_builders: create_inverted_index_builders(&self.table_info.meta),
+        };
+        let read_settings = ReadSettings::from_ctx(&ctx)?;
+        let func_ctx = ctx.get_function_context()?;
+
+        let thresholds = self.get_block_thresholds();
+        let cluster_key_id = self.cluster_key_id();
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let segment_writer = SegmentWriter::new(&self.operator, &self.meta_location_generator);
+        let chunk_size = (ctx.get_settings().get_max_threads()? as usize * 4).max(1);
+
+        let mut segment_locations = snapshot.segments.clone();
+        let mut summary = Statistics::default();
+        let (mut rewritten, mut remaining) = (0, 0);
+        for (chunk_idx, chunk) in snapshot.segments.chunks(chunk_size).enumerate() {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (idx, segment) in segments.into_iter().enumerate() {
+                let segment = segment?;
+                let lacking = segment
+                    .blocks
+                    .iter()
+                    .filter(|block| lacks_column(block, &column_ids))
+                    .count();
+                if lacking == 0 || rewritten >= max_blocks {
+                    remaining += lacking;
+                    summary = merge_statistics(&summary, &segment.summary, cluster_key_id);
+                    continue;
+                }
+
+                let mut blocks = Vec::with_capacity(segment.blocks.len());
+                for block in segment.blocks.iter() {
+                    if !lacks_column(block, &column_ids) {
+                        blocks.push(block.clone());
+                        continue;
+                    }
+                    let data_block =
+                        read_block(self.storage_format, &block_reader, block, &read_settings)
+                            .await?;
+                    let data_block = Self::fill_backfill_columns(data_block, &exprs, &func_ctx)?;
+                    let cluster_stats = block.cluster_stats.clone();
+                    let serialized = block_builder.build(data_block, |data_block, _| {
+                        Ok((cluster_stats.clone(), data_block))
+                    })?;
+                    let new_block = BlockWriter::write_down(&self.operator, serialized).await?;
+                    blocks.push(Arc::new(new_block));
+                }
+                rewritten += lacking;
+
+                let segment_summary = reduce_block_metas(&blocks, thresholds, cluster_key_id);
+                summary = merge_statistics(&summary, &segment_summary, cluster_key_id);
+                let location = segment_writer
+                    .write_segment(SegmentInfo::new(blocks, segment_summary))
+                    .await?;
+                segment_locations[chunk_idx * chunk_size + idx] = location;
+            }
+        }
+
+        if rewritten == 0 {
+            return Ok((0, remaining));
+        }
+        info!(
+            "backfill {} blocks of table {}, {} blocks left",
+            rewritten,
+            self.name(),
+            remaining
+        );
+        self.commit_mutation(&ctx, snapshot.clone(), &segment_locations, summary, None)
+            .await?;
+        Ok((rewritten, remaining))
+    }
+
+    fn fill_backfill_columns(
+        data_block: DataBlock,
+        exprs: &[(usize, Expr)],
+        func_ctx: &FunctionContext,
+    ) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let evaluator = Evaluator::new(&data_block, func_ctx, &BUILTIN_FUNCTIONS);
+        let mut values = Vec::with_capacity(exprs.len());
+        for (index, expr) in exprs {
+            let value = evaluator.run(expr)?;
+            values.push((*index, expr.data_type().clone(), value));
+        }
+
+        let mut entries = data_block.columns().to_vec();
+        for (index, data_type, value) in values {
+            let column = value.convert_to_full_column(&data_type, num_rows);
+            entries[index] = BlockEntry::new(data_type, Value::Column(column));
+        }
+        Ok(DataBlock::new(entries, num_rows))
+    }
+}
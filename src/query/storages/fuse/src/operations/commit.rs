@@ -78,11 +78,15 @@ impl FuseTable {
         deduplicated_label: Option<String>,
     ) -> Result<()> {
         let block_thresholds = self.get_block_thresholds();
+        let settings = ctx.get_settings();
+        let segment_batch_size = settings.get_commit_segment_batch_size()?;
+        let io_parallelism = settings.get_max_threads()? as usize;
 
         pipeline.try_resize(1)?;
 
         pipeline.add_transform(|input, output| {
-            let proc = TransformSerializeSegment::new(input, output, self, block_thresholds);
+            let proc = TransformSerializeSegment::new(input, output, self, block_thresholds)
+                .with_segment_batch(segment_batch_size, io_parallelism);
             proc.into_processor()
         })?;
 
@@ -90,7 +94,8 @@ impl FuseTable {
             TableMutationAggregator::new(self, ctx.clone(), vec![], MutationKind::Insert)
         });
 
-        let snapshot_gen = AppendGenerator::new(ctx.clone(), overwrite);
+        let snapshot_gen = AppendGenerator::new(ctx.clone(), overwrite)
+            .with_block_per_segment(self.get_write_settings().block_per_seg);
         pipeline.add_sink(|input| {
             CommitSink::try_create(
                 self,
@@ -44,6 +44,8 @@ pub struct AppendGenerator {
     leaf_default_values: HashMap<ColumnId, Scalar>,
     overwrite: bool,
     conflict_resolve_ctx: ConflictResolveContext,
+    // Used to check if the segments are fragmented, not checked if none.
+    block_per_seg: Option<usize>,
 }
 
 impl AppendGenerator {
@@ -53,9 +55,15 @@ impl AppendGenerator {
             leaf_default_values: HashMap::new(),
             overwrite,
             conflict_resolve_ctx: ConflictResolveContext::None,
+            block_per_seg: None,
         }
     }
 
+    pub fn with_block_per_segment(mut self, block_per_seg: usize) -> Self {
+        self.block_per_seg = Some(block_per_seg);
+        self
+    }
+
     fn check_fill_default(&self, summary: &Statistics) -> Result<bool> {
         let mut fill_default_values = false;
         // check if need to fill default value in statistics
@@ -226,6 +234,28 @@ impl SnapshotGenerator for AppendGenerator {
                 .set_compaction_num_block_hint(compact_num_block_hint);
         }
 
+        // check if the segments are fragmented, i.e. there are more segments than the blocks
+        // need by `auto_compaction_segments_threshold`, so that the compact hook reloads the
+        // table to compact the segments only if needed.
+        if let Some(block_per_seg) = self.block_per_seg {
+            let threshold = self
+                .ctx
+                .get_settings()
+                .get_auto_compaction_segments_threshold()?;
+            let num_segments = new_segments.len() as u64;
+            let needed_segments = new_summary
+                .block_count
+                .div_ceil(block_per_seg.max(1) as u64);
+            if threshold > 0 && num_segments > needed_segments + threshold {
+                info!(
+                    "set compact_num_segment_hint to {}",
+                    num_segments - needed_segments
+                );
+                self.ctx
+                    .set_compaction_num_segment_hint(num_segments - needed_segments);
+            }
+        }
+
         Ok(TableSnapshot::new(
             Uuid::new_v4(),
             prev_table_seq,
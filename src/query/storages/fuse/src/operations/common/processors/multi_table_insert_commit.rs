@@ -20,6 +20,8 @@ use async_trait::async_trait;
 use async_trait::unboxed_simple;
 use backoff::backoff::Backoff;
 use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::fault_injection::FaultInjection;
+use databend_common_catalog::fault_injection::FaultPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_catalog::table_context::TableContext;
@@ -38,6 +40,7 @@ use databend_storages_common_txn::TxnManagerRef;
 use log::debug;
 use log::error;
 use log::info;
+use log::warn;
 
 use crate::operations::set_backoff;
 use crate::operations::AppendGenerator;
@@ -46,6 +49,18 @@ use crate::operations::SnapshotGenerator;
 use crate::operations::TransformMergeCommitMeta;
 use crate::FuseTable;
 
+/// Commits the appends of a multi-table insert to all the target tables atomically, in two
+/// phases:
+///
+/// - prepare: a new snapshot of each table is written, which is not visible until the table
+///   meta points to it;
+/// - commit: the metas of all the tables are updated in a single meta transaction, which
+///   succeeds only if none of the tables have been changed since their snapshots were
+///   prepared, so either all the tables see the inserted rows or none of them.
+///
+/// If the commit conflicts with other writes, the snapshots of the conflicting tables are
+/// prepared again on top of their latest snapshots. The snapshots that are known to be not
+/// committed are removed.
 pub struct CommitMultiTableInsert {
     commit_metas: HashMap<u64, CommitMeta>,
     tables: HashMap<u64, Arc<dyn Table>>,
@@ -83,28 +98,41 @@ impl AsyncSink for CommitMultiTableInsert {
 
     #[async_backtrace::framed]
     async fn on_finish(&mut self) -> Result<()> {
+        // phase 1: prepare the snapshots of all the tables
         let mut update_table_metas = Vec::with_capacity(self.commit_metas.len());
         let mut snapshot_generators = HashMap::with_capacity(self.commit_metas.len());
+        let mut prepared_snapshots = HashMap::with_capacity(self.commit_metas.len());
         for (table_id, commit_meta) in std::mem::take(&mut self.commit_metas).into_iter() {
-            // generate snapshot
             let mut snapshot_generator = AppendGenerator::new(self.ctx.clone(), self.overwrite);
             snapshot_generator.set_conflict_resolve_context(commit_meta.conflict_resolve_context);
             let table = self.tables.get(&table_id).unwrap();
-            update_table_metas.push((
-                build_update_table_meta_req(
-                    table.as_ref(),
-                    &snapshot_generator,
-                    self.ctx.txn_mgr(),
-                )
-                .await?,
-                table.get_table_info().clone(),
-            ));
+            let prepared = build_update_table_meta_req(
+                table.as_ref(),
+                &snapshot_generator,
+                self.ctx.txn_mgr(),
+            )
+            .await;
+            let (req, location) = match prepared {
+                Ok(prepared) => prepared,
+                Err(e) => {
+                    self.abort(prepared_snapshots).await;
+                    return Err(e);
+                }
+            };
+            update_table_metas.push((req, table.get_table_info().clone()));
+            prepared_snapshots.insert(table_id, location);
             snapshot_generators.insert(table_id, snapshot_generator);
         }
 
         let mut backoff = set_backoff(None, None, None);
         let mut retries = 0;
 
+        // phase 2: commit the snapshots of all the tables in one meta transaction
+        let fault_injection = FaultInjection::try_create(&self.ctx.get_settings())?;
+        if let Err(e) = fault_injection.inject(FaultPoint::Commit).await {
+            self.abort(prepared_snapshots).await;
+            return Err(e);
+        }
         loop {
             let update_multi_table_meta_req = UpdateMultiTableMetaReq {
                 update_table_metas: update_table_metas.clone(),
@@ -121,7 +149,10 @@ impl AsyncSink for CommitMultiTableInsert {
                 Ok(ret) => ret,
                 Err(e) => {
                     // other errors may occur, especially the version mismatch of streams,
-                    // let's log it here for the convenience of diagnostics
+                    // let's log it here for the convenience of diagnostics.
+                    //
+                    // the prepared snapshots are kept, since it is unknown whether the
+                    // transaction has been committed or not, e.g. on a network failure.
                     error!(
                         "Non-recoverable fault occurred during updating tables. {}",
                         e
@@ -169,21 +200,44 @@ impl AsyncSink for CommitMultiTableInsert {
                         retries,
                     );
                     databend_common_base::base::tokio::time::sleep(duration).await;
+                    // the transaction is not committed, prepare the snapshots of the
+                    // conflicting tables again, on top of their latest snapshots.
                     for (tid, seq, meta) in update_failed_tbls {
-                        let table = self.tables.get_mut(&tid).unwrap();
-                        *table = table
+                        let refreshed = self
+                            .tables
+                            .get(&tid)
+                            .unwrap()
                             .refresh_with_seq_meta(self.ctx.as_ref(), seq, meta)
-                            .await?;
-                        for (req, _) in update_table_metas.iter_mut() {
-                            if req.table_id == tid {
-                                *req = build_update_table_meta_req(
-                                    table.as_ref(),
-                                    snapshot_generators.get(&tid).unwrap(),
-                                    self.ctx.txn_mgr(),
-                                )
-                                .await?;
-                                break;
+                            .await;
+                        let table = match refreshed {
+                            Ok(table) => table,
+                            Err(e) => {
+                                self.abort(prepared_snapshots).await;
+                                return Err(e);
+                            }
+                        };
+                        self.tables.insert(tid, table.clone());
+                        let prepared = build_update_table_meta_req(
+                            table.as_ref(),
+                            snapshot_generators.get(&tid).unwrap(),
+                            self.ctx.txn_mgr(),
+                        )
+                        .await;
+                        let (new_req, location) = match prepared {
+                            Ok(prepared) => prepared,
+                            Err(e) => {
+                                self.abort(prepared_snapshots).await;
+                                return Err(e);
                             }
+                        };
+                        if let Some((req, _)) = update_table_metas
+                            .iter_mut()
+                            .find(|(req, _)| req.table_id == tid)
+                        {
+                            *req = new_req;
+                        }
+                        if let Some(superseded) = prepared_snapshots.insert(tid, location) {
+                            self.remove_snapshot(tid, &superseded).await;
                         }
                     }
                 }
@@ -197,6 +251,7 @@ impl AsyncSink for CommitMultiTableInsert {
                         update_failed_tbl_descriptions,
                     );
                     error!("{}", err_msg);
+                    self.abort(prepared_snapshots).await;
                     return Err(ErrorCode::OCCRetryFailure(err_msg));
                 }
             }
@@ -216,7 +271,7 @@ impl AsyncSink for CommitMultiTableInsert {
         match self.commit_metas.get_mut(&meta.table_id) {
             Some(m) => {
                 let table = self.tables.get(&meta.table_id).unwrap();
-                let table = FuseTable::try_from_table(table.as_ref()).unwrap();
+                let table = FuseTable::try_from_table(table.as_ref())?;
                 *m = TransformMergeCommitMeta::merge_commit_meta(
                     m.clone(),
                     meta,
@@ -231,11 +286,37 @@ impl AsyncSink for CommitMultiTableInsert {
     }
 }
 
+impl CommitMultiTableInsert {
+    /// Removes the prepared snapshots of a transaction that is known to be not committed.
+    async fn abort(&self, prepared_snapshots: HashMap<u64, String>) {
+        for (table_id, location) in prepared_snapshots {
+            self.remove_snapshot(table_id, &location).await;
+        }
+    }
+
+    async fn remove_snapshot(&self, table_id: u64, location: &str) {
+        let Some(table) = self.tables.get(&table_id) else {
+            return;
+        };
+        let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+            return;
+        };
+        // best effort, the orphan snapshot is purged by vacuum otherwise
+        if let Err(e) = fuse_table.get_operator().delete(location).await {
+            warn!(
+                "failed to remove the uncommitted snapshot {} of table {}: {}",
+                location, table_id, e
+            );
+        }
+    }
+}
+
+/// Prepares a new snapshot of the table, returns the request to commit it and its location.
 async fn build_update_table_meta_req(
     table: &dyn Table,
     snapshot_generator: &AppendGenerator,
     txn_mgr: TxnManagerRef,
-) -> Result<UpdateTableMetaReq> {
+) -> Result<(UpdateTableMetaReq, String)> {
     let fuse_table = FuseTable::try_from_table(table)?;
     let previous = fuse_table.read_table_snapshot().await?;
     let snapshot = snapshot_generator.generate_new_snapshot(
@@ -265,5 +346,5 @@ async fn build_update_table_meta_req(
         seq: MatchSeq::Exact(table_version),
         new_table_meta,
     };
-    Ok(req)
+    Ok((req, location))
 }
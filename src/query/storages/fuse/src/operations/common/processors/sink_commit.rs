@@ -19,6 +19,10 @@ use std::time::Instant;
 
 use backoff::backoff::Backoff;
 use backoff::ExponentialBackoff;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::fault_injection::FaultInjection;
+use databend_common_catalog::fault_injection::FaultPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_catalog::table_context::TableContext;
@@ -97,6 +101,7 @@ pub struct CommitSink<F: SnapshotGenerator> {
     change_tracking: bool,
     update_stream_meta: Vec<UpdateStreamMetaReq>,
     deduplicated_label: Option<String>,
+    fault_injection: FaultInjection,
 }
 
 impl<F> CommitSink<F>
@@ -115,6 +120,7 @@ where F: SnapshotGenerator + Send + 'static
         deduplicated_label: Option<String>,
     ) -> Result<ProcessorPtr> {
         let purge = Self::do_purge(table, &snapshot_gen);
+        let fault_injection = FaultInjection::try_create(&ctx.get_settings())?;
         Ok(ProcessorPtr::create(Box::new(CommitSink {
             state: State::None,
             ctx,
@@ -134,6 +140,7 @@ where F: SnapshotGenerator + Send + 'static
             change_tracking: table.change_tracking_enabled(),
             update_stream_meta,
             deduplicated_label,
+            fault_injection,
         })))
     }
 
@@ -357,6 +364,8 @@ where F: SnapshotGenerator + Send + 'static
                 snapshot,
                 table_info,
             } => {
+                self.fault_injection.inject(FaultPoint::Commit).await?;
+
                 let location = self
                     .location_gen
                     .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
@@ -426,6 +435,10 @@ where F: SnapshotGenerator + Send + 'static
                                 self.retries, elapsed_time
                             );
                             metrics_inc_commit_milliseconds(elapsed_time.as_millis());
+                            Profile::record_usize_profile(
+                                ProfileStatisticsName::CommitTime,
+                                elapsed_time.as_millis() as usize,
+                            );
                             self.ctx.set_status_info(&status);
                         }
                         if let Some(files) = &self.copied_files {
@@ -20,6 +20,7 @@ use std::time::Instant;
 
 use databend_common_base::runtime::execute_futures_in_parallel;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::MaintenanceProgress;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoPtr;
@@ -32,7 +33,6 @@ use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::Statistics;
-use databend_storages_common_table_meta::meta::Versioned;
 use itertools::Itertools;
 use log::debug;
 use log::info;
@@ -72,6 +72,9 @@ pub struct TableMutationAggregator {
     kind: MutationKind,
     start_time: Instant,
     finished_tasks: usize,
+    /// The number of the compact tasks, which is reported by the phase that built them.
+    total_tasks: usize,
+    written_bytes: u64,
     table_id: u64,
 }
 
@@ -122,6 +125,8 @@ impl TableMutationAggregator {
             removed_statistics: Statistics::default(),
             kind,
             finished_tasks: 0,
+            total_tasks: 0,
+            written_bytes: 0,
             start_time: Instant::now(),
             table_id: table.get_id(),
         }
@@ -140,11 +145,31 @@ impl TableMutationAggregator {
             );
             self.ctx.set_status_info(&status);
         }
+
+        if matches!(self.kind, MutationKind::Compact) {
+            if self.total_tasks == 0 {
+                self.total_tasks = self
+                    .ctx
+                    .get_maintenance_progress()
+                    .filter(|p| p.operation == "compact" && p.phase == "build_tasks")
+                    .map_or(0, |p| p.tasks_total as usize);
+            }
+            self.ctx.set_maintenance_progress(
+                MaintenanceProgress::new(
+                    "compact",
+                    "run_tasks",
+                    self.finished_tasks,
+                    self.total_tasks,
+                )
+                .with_bytes(self.written_bytes),
+            );
+        }
     }
 
     pub fn accumulate_log_entry(&mut self, log_entry: MutationLogEntry) {
         match log_entry {
             MutationLogEntry::ReplacedBlock { index, block_meta } => {
+                self.written_bytes += block_meta.file_size;
                 match self.mutations.entry(index.segment_idx) {
                     Entry::Occupied(mut v) => {
                         v.get_mut().push_replaced(index.block_idx, block_meta);
@@ -235,7 +260,8 @@ impl TableMutationAggregator {
                     for result in results {
                         if let Some((location, summary)) = result.new_segment_info {
                             // replace the old segment location with the new one.
-                            let new_segment_loc = (location, SegmentInfo::VERSION);
+                            let new_segment_loc =
+                                (location, self.location_gen.segment_info_version());
                             new_segment_locs.push(new_segment_loc.clone());
                             merge_statistics_mut(
                                 &mut merged_statistics,
@@ -365,7 +391,8 @@ impl TableMutationAggregator {
                     }
                 }
                 // create new segment info
-                let new_segment = SegmentInfo::new(new_blocks, new_summary.clone());
+                let mut new_segment = SegmentInfo::new(new_blocks, new_summary.clone());
+                new_segment.format_version = location_gen.segment_info_version();
 
                 // write the segment info.
                 let serialized_segment = SerializedSegment {
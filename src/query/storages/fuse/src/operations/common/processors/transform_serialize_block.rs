@@ -34,6 +34,7 @@ use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_storages_common_index::BloomIndex;
 use opendal::Operator;
 
+use crate::io::bloom_index_targets;
 use crate::io::create_inverted_index_builders;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
@@ -125,16 +126,23 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let bloom_index_targets =
+            bloom_index_targets(&table.bloom_index_cols, source_schema.clone())?;
 
         let inverted_index_builders = create_inverted_index_builders(&table.table_info.meta);
 
+        let mut write_settings = table.get_write_settings();
+        write_settings.adaptive_encoding =
+            ctx.get_settings().get_enable_adaptive_block_encoding()?;
+
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
             source_schema,
-            write_settings: table.get_write_settings(),
+            write_settings,
             cluster_stats_gen,
             bloom_columns_map,
+            bloom_index_targets,
             inverted_index_builders,
         };
         Ok(TransformSerializeBlock {
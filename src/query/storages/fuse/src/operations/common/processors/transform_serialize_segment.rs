@@ -14,8 +14,12 @@
 
 use std::any::Any;
 use std::sync::Arc;
+use std::time::Instant;
 
 use async_trait::async_trait;
+use databend_common_base::runtime::execute_futures_in_parallel;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
@@ -31,7 +35,6 @@ use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache_manager::CachedObject;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::SegmentInfo;
-use databend_storages_common_table_meta::meta::Versioned;
 use log::info;
 use opendal::Operator;
 
@@ -43,18 +46,17 @@ use crate::FuseTable;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 
+struct SerializedSegment {
+    data: Vec<u8>,
+    location: String,
+    segment: Arc<SegmentInfo>,
+}
+
 enum State {
     None,
     GenerateSegment,
-    SerializedSegment {
-        data: Vec<u8>,
-        location: String,
-        segment: Arc<SegmentInfo>,
-    },
-    PreCommitSegment {
-        location: String,
-        segment: Arc<SegmentInfo>,
-    },
+    WriteSegments,
+    PreCommitSegments(Vec<(String, Arc<SegmentInfo>)>),
     Finished,
 }
 
@@ -67,6 +69,10 @@ pub struct TransformSerializeSegment {
     output: Arc<OutputPort>,
     output_data: Option<DataBlock>,
     block_per_seg: u64,
+    // The serialized segments are written concurrently once there are `segment_batch_size` of them.
+    serialized_segments: Vec<SerializedSegment>,
+    segment_batch_size: usize,
+    io_parallelism: usize,
 
     thresholds: BlockThresholds,
     default_cluster_key_id: Option<u32>,
@@ -91,11 +97,21 @@ impl TransformSerializeSegment {
             block_per_seg: table
                 .get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT)
                 as u64,
+            serialized_segments: vec![],
+            segment_batch_size: 1,
+            io_parallelism: 1,
             thresholds,
             default_cluster_key_id,
         }
     }
 
+    /// Write the segments in batches, the segments of a batch are written concurrently.
+    pub fn with_segment_batch(mut self, segment_batch_size: usize, io_parallelism: usize) -> Self {
+        self.segment_batch_size = segment_batch_size.max(1);
+        self.io_parallelism = io_parallelism.max(1);
+        self
+    }
+
     pub fn into_processor(self) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(self)))
     }
@@ -121,12 +137,12 @@ impl Processor for TransformSerializeSegment {
     fn event(&mut self) -> Result<Event> {
         if matches!(
             &self.state,
-            State::GenerateSegment | State::PreCommitSegment { .. }
+            State::GenerateSegment | State::PreCommitSegments(_)
         ) {
             return Ok(Event::Sync);
         }
 
-        if matches!(&self.state, State::SerializedSegment { .. }) {
+        if matches!(&self.state, State::WriteSegments) {
             return Ok(Event::Async);
         }
 
@@ -148,6 +164,10 @@ impl Processor for TransformSerializeSegment {
                 self.state = State::GenerateSegment;
                 return Ok(Event::Sync);
             }
+            if !self.serialized_segments.is_empty() {
+                self.state = State::WriteSegments;
+                return Ok(Event::Async);
+            }
             self.output.finish();
             self.state = State::Finished;
             return Ok(Event::Finished);
@@ -182,30 +202,35 @@ impl Processor for TransformSerializeSegment {
                 let acc = std::mem::take(&mut self.accumulator);
                 let summary = acc.summary(self.thresholds, self.default_cluster_key_id);
 
-                let segment_info = SegmentInfo::new(acc.blocks_metas, summary);
+                let mut segment_info = SegmentInfo::new(acc.blocks_metas, summary);
+                segment_info.format_version = self.meta_locations.segment_info_version();
 
-                self.state = State::SerializedSegment {
+                self.serialized_segments.push(SerializedSegment {
                     data: segment_info.to_bytes()?,
                     location: self.meta_locations.gen_segment_info_location(),
                     segment: Arc::new(segment_info),
+                });
+                if self.serialized_segments.len() >= self.segment_batch_size {
+                    self.state = State::WriteSegments;
                 }
             }
-            State::PreCommitSegment { location, segment } => {
-                if let Some(segment_cache) = SegmentInfo::cache() {
-                    segment_cache.put(location.clone(), Arc::new(segment.as_ref().try_into()?));
-                }
-
-                let format_version = SegmentInfo::VERSION;
+            State::PreCommitSegments(segments) => {
+                let format_version = self.meta_locations.segment_info_version();
 
-                // emit log entry.
-                // for newly created segment, always use the latest version
-                let meta = MutationLogs {
-                    entries: vec![MutationLogEntry::AppendSegment {
+                // emit log entries.
+                // for newly created segment, always use the version of the table
+                let mut entries = Vec::with_capacity(segments.len());
+                for (location, segment) in segments {
+                    if let Some(segment_cache) = SegmentInfo::cache() {
+                        segment_cache.put(location.clone(), Arc::new(segment.as_ref().try_into()?));
+                    }
+                    entries.push(MutationLogEntry::AppendSegment {
                         segment_location: location,
                         format_version,
                         summary: segment.summary.clone(),
-                    }],
-                };
+                    });
+                }
+                let meta = MutationLogs { entries };
 
                 self.output_data = Some(DataBlock::empty_with_meta(Box::new(meta)));
             }
@@ -220,15 +245,47 @@ impl Processor for TransformSerializeSegment {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
-            State::SerializedSegment {
-                data,
-                location,
-                segment,
-            } => {
-                self.data_accessor.write(&location, data).await?;
-                info!("fuse append wrote down segment {} ", location);
-
-                self.state = State::PreCommitSegment { location, segment };
+            State::WriteSegments => {
+                let start = Instant::now();
+                let serialized_segments = std::mem::take(&mut self.serialized_segments);
+                let num_segments = serialized_segments.len();
+                let segments = if num_segments == 1 || self.io_parallelism == 1 {
+                    let mut segments = Vec::with_capacity(num_segments);
+                    for serialized in serialized_segments {
+                        self.data_accessor
+                            .write(&serialized.location, serialized.data)
+                            .await?;
+                        info!("fuse append wrote down segment {} ", serialized.location);
+                        segments.push((serialized.location, serialized.segment));
+                    }
+                    segments
+                } else {
+                    let tasks = serialized_segments.into_iter().map(|serialized| {
+                        let op = self.data_accessor.clone();
+                        async move {
+                            op.write(&serialized.location, serialized.data).await?;
+                            info!("fuse append wrote down segment {} ", serialized.location);
+                            Ok::<_, ErrorCode>((serialized.location, serialized.segment))
+                        }
+                    });
+                    execute_futures_in_parallel(
+                        tasks,
+                        self.io_parallelism,
+                        self.io_parallelism,
+                        "fuse-write-segments-worker".to_owned(),
+                    )
+                    .await?
+                    .into_iter()
+                    .collect::<Result<Vec<_>>>()?
+                };
+
+                Profile::record_usize_profile(ProfileStatisticsName::CommitSegments, num_segments);
+                info!(
+                    "fuse append wrote down {} segments, which took {:?}",
+                    num_segments,
+                    start.elapsed()
+                );
+                self.state = State::PreCommitSegments(segments);
             }
             _state => {
                 return Err(ErrorCode::Internal("Unknown state for fuse table sink."));
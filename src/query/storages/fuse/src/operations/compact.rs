@@ -88,6 +88,7 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         limits: CompactionLimits,
     ) -> Result<Option<(Partitions, Arc<TableSnapshot>)>> {
+        self.check_column_backfills_done()?;
         let compact_options = if let Some(v) = self
             .compact_options(limits.segment_limit, limits.block_limit)
             .await?
@@ -198,6 +198,7 @@ impl FuseTable {
         is_lazy: bool,
         is_delete: bool,
     ) -> Result<Partitions> {
+        self.check_column_backfills_done()?;
         let partitions = if is_lazy {
             let mut segments = Vec::with_capacity(snapshot.segments.len());
             for (idx, segment_location) in snapshot.segments.iter().enumerate() {
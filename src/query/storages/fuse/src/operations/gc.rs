@@ -20,6 +20,7 @@ use std::time::Instant;
 
 use databend_common_cache::CountableMeter;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::MaintenanceProgress;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -187,6 +188,12 @@ impl FuseTable {
                     counter.start.elapsed()
                 );
                 ctx.set_status_info(&status);
+                ctx.set_maintenance_progress(MaintenanceProgress::new(
+                    "purge",
+                    "read_snapshots",
+                    read_snapshot_count,
+                    snapshot_files.len(),
+                ));
             }
 
             if !snapshots_to_be_purged.is_empty() {
@@ -467,6 +474,12 @@ impl FuseTable {
                     counter.start.elapsed()
                 );
                 ctx.set_status_info(&status);
+                ctx.set_maintenance_progress(MaintenanceProgress::new(
+                    "purge",
+                    "purge_segments",
+                    count,
+                    segment_locations.len(),
+                ));
             }
 
             self.purge_block_segments(
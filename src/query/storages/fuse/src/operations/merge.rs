@@ -25,6 +25,7 @@ use databend_storages_common_table_meta::meta::Location;
 
 use super::merge_into::MatchedAggregator;
 use super::mutation::SegmentIndex;
+use crate::io::bloom_index_targets;
 use crate::io::create_inverted_index_builders;
 use crate::io::BlockBuilder;
 use crate::statistics::ClusterStatsGenerator;
@@ -90,6 +91,7 @@ impl FuseTable {
         let bloom_columns_map = self
             .bloom_index_cols()
             .bloom_index_fields(new_schema.clone(), BloomIndex::supported_type)?;
+        let bloom_index_targets = bloom_index_targets(&self.bloom_index_cols, new_schema.clone())?;
         let inverted_index_builders = create_inverted_index_builders(&self.table_info.meta);
 
         let block_builder = BlockBuilder {
@@ -99,6 +101,7 @@ impl FuseTable {
             write_settings: self.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            bloom_index_targets,
             inverted_index_builders,
         };
         let aggregator = MatchedAggregator::create(
@@ -16,6 +16,7 @@ mod agg_index_sink;
 mod analyze;
 mod append;
 mod changes;
+mod column_backfill;
 mod commit;
 mod common;
 mod compact;
@@ -26,6 +27,7 @@ mod merge;
 mod merge_into;
 mod mutation;
 mod navigate;
+mod prefetch;
 mod read;
 mod read_data;
 mod read_partitions;
@@ -33,12 +35,17 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod segment_statistics;
+mod tiering;
 mod truncate;
 mod update;
 mod util;
+mod verify;
 
 pub use agg_index_sink::AggIndexSink;
 pub use changes::ChangesDesc;
+pub use column_backfill::ColumnBackfill;
+pub use column_backfill::ColumnBackfillProgress;
 pub use common::*;
 pub use compact::CompactOptions;
 pub use delete::MutationBlockPruningContext;
@@ -47,7 +54,12 @@ pub use mutation::*;
 pub use read::need_reserve_block_info;
 pub use read::row_fetch_processor;
 pub use replace_into::*;
+pub use segment_statistics::TableBlockInfo;
+pub use segment_statistics::TableSegmentInfo;
+pub use tiering::segment_tier;
+pub use tiering::TableStoragePolicy;
 pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
 pub use util::set_backoff;
+pub use verify::CorruptedBlock;
@@ -14,6 +14,8 @@
 
 use std::collections::HashSet;
 use std::collections::VecDeque;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Instant;
 use std::vec;
@@ -24,6 +26,7 @@ use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::table_context::MaintenanceProgress;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::is_stream_column_id;
@@ -166,6 +169,12 @@ impl BlockCompactMutator {
                     start.elapsed()
                 );
                 self.ctx.set_status_info(&status);
+                self.ctx.set_maintenance_progress(MaintenanceProgress::new(
+                    "compact",
+                    "read_segments",
+                    segment_idx,
+                    number_segments,
+                ));
             }
 
             if is_end {
@@ -238,6 +247,11 @@ impl BlockCompactMutator {
         let max_concurrency = std::cmp::max(max_threads * 2, 10);
         let semaphore = Arc::new(Semaphore::new(max_concurrency));
 
+        // The lazy parts are processed concurrently, the progress is the number of the
+        // processed lazy parts.
+        let total_lazy_parts = lazy_parts.len();
+        let processed_lazy_parts = Arc::new(AtomicUsize::new(0));
+
         let mut remain = lazy_parts.len() % max_threads;
         let batch_size = lazy_parts.len() / max_threads;
         let mut works = Vec::with_capacity(max_threads);
@@ -248,6 +262,8 @@ impl BlockCompactMutator {
 
             let column_ids = column_ids.clone();
             let semaphore = semaphore.clone();
+            let ctx = ctx.clone();
+            let processed_lazy_parts = processed_lazy_parts.clone();
 
             let batch = lazy_parts.drain(0..batch_size).collect::<Vec<_>>();
             works.push(async move {
@@ -263,6 +279,14 @@ impl BlockCompactMutator {
                         )
                         .await?;
                     res.extend(parts);
+
+                    let processed = processed_lazy_parts.fetch_add(1, Ordering::SeqCst) + 1;
+                    ctx.set_maintenance_progress(MaintenanceProgress::new(
+                        "compact",
+                        "build_tasks",
+                        processed,
+                        total_lazy_parts,
+                    ));
                 }
                 Ok::<_, ErrorCode>(res)
             });
@@ -283,6 +307,12 @@ impl BlockCompactMutator {
                         parts.len(),
                         elapsed_time,
                     ));
+                    ctx.set_maintenance_progress(MaintenanceProgress::new(
+                        "compact",
+                        "build_tasks",
+                        processed_lazy_parts.load(Ordering::SeqCst),
+                        total_lazy_parts,
+                    ));
                     metrics_inc_compact_block_build_task_milliseconds(
                         elapsed_time.as_millis() as u64
                     );
@@ -18,6 +18,7 @@ use std::time::Instant;
 
 use databend_common_base::runtime::execute_futures_in_parallel;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::MaintenanceProgress;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -30,7 +31,6 @@ use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::Statistics;
-use databend_storages_common_table_meta::meta::Versioned;
 use itertools::Itertools;
 use opendal::Operator;
 
@@ -59,6 +59,7 @@ pub struct ReclusterAggregator {
 
     merged_blocks: Vec<Arc<BlockMeta>>,
     new_block_count: usize,
+    new_block_bytes: u64,
 
     removed_segment_indexes: Vec<usize>,
     removed_statistics: Statistics,
@@ -73,6 +74,7 @@ impl AsyncAccumulatingTransform for ReclusterAggregator {
     async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
         // gather the input data.
         if let Some(meta) = data.get_owned_meta().and_then(BlockMeta::downcast_from) {
+            self.new_block_bytes += meta.file_size;
             self.merged_blocks.push(Arc::new(meta));
             // Refresh status
             {
@@ -84,6 +86,10 @@ impl AsyncAccumulatingTransform for ReclusterAggregator {
                     self.start_time.elapsed()
                 );
                 self.ctx.set_status_info(&status);
+                self.ctx.set_maintenance_progress(
+                    MaintenanceProgress::new("recluster", "write_blocks", self.new_block_count, 0)
+                        .with_bytes(self.new_block_bytes),
+                );
             }
         }
         // no partial output
@@ -107,7 +113,7 @@ impl AsyncAccumulatingTransform for ReclusterAggregator {
             // The remain new segments will be append.
             let appended = new_segments.split_off(removed_segments_len);
             for (location, stats) in appended.into_iter().rev() {
-                let segment_loc = (location, SegmentInfo::VERSION);
+                let segment_loc = (location, self.location_gen.segment_info_version());
                 new_segment_locs.push(segment_loc.clone());
                 appended_segments.push(segment_loc);
                 merge_statistics_mut(&mut merged_statistics, &stats, default_cluster_key);
@@ -116,7 +122,7 @@ impl AsyncAccumulatingTransform for ReclusterAggregator {
 
         for (i, (location, stats)) in new_segments.into_iter().enumerate() {
             // The old segments will be replaced with the news.
-            let segment_loc = (location, SegmentInfo::VERSION);
+            let segment_loc = (location, self.location_gen.segment_info_version());
             new_segment_locs.push(segment_loc.clone());
             replaced_segments.insert(self.removed_segment_indexes[i], segment_loc);
             merge_statistics_mut(&mut merged_statistics, &stats, default_cluster_key);
@@ -158,6 +164,7 @@ impl ReclusterAggregator {
             block_per_seg,
             merged_blocks,
             new_block_count: 0,
+            new_block_bytes: 0,
             removed_segment_indexes,
             removed_statistics,
             start_time: Instant::now(),
@@ -197,7 +204,8 @@ impl ReclusterAggregator {
                     }
                 }
                 // create new segment info
-                let new_segment = SegmentInfo::new(new_blocks, new_summary.clone());
+                let mut new_segment = SegmentInfo::new(new_blocks, new_summary.clone());
+                new_segment.format_version = location_gen.segment_info_version();
 
                 // write the segment info.
                 let serialized_segment = SerializedSegment {
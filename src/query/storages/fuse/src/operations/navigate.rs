@@ -24,6 +24,8 @@ use databend_common_exception::Result;
 use databend_common_expression::AbortChecker;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableStatistics;
+use databend_common_storage::cold_tier_location;
+use databend_common_storage::is_cold_tier_location;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
@@ -389,7 +391,11 @@ impl FuseTable {
             match meta.mode() {
                 EntryMode::FILE => {
                     let modified = meta.last_modified();
-                    let location = de.path().to_string();
+                    let mut location = de.path().to_string();
+                    // The entries of the cold tier are listed relative to the cold storage.
+                    if is_cold_tier_location(&prefix) && !is_cold_tier_location(&location) {
+                        location = cold_tier_location(&location);
+                    }
                     if let Some(modified) = modified {
                         if f(location.clone(), modified) {
                             file_list.push((location, modified));
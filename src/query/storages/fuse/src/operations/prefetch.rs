@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use databend_common_base::base::tokio::sync::Semaphore;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache_manager::CacheManager;
+use databend_storages_common_cache_manager::CompactSegmentInfoCache;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use log::info;
+use log::warn;
+
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+/// The tables prefetched at most at once on the node, the prefetching of the other tables
+/// is skipped rather than queued, since it's only a hint to the cache.
+const MAX_CONCURRENT_PREFETCHES: usize = 4;
+
+static PREFETCH_PERMITS: LazyLock<Arc<Semaphore>> =
+    LazyLock::new(|| Arc::new(Semaphore::new(MAX_CONCURRENT_PREFETCHES)));
+
+impl FuseTable {
+    /// Loads the snapshot and segments of the table into the node-local cache in background,
+    /// so that they are likely cached when the partitions of the planned scans are read.
+    ///
+    /// The cached objects are keyed by their locations. A changed table always has a new
+    /// snapshot location committed to meta, which is seen by the table info of the query,
+    /// so the cached objects never need to be invalidated.
+    ///
+    /// At most `MAX_CONCURRENT_PREFETCHES` tables are prefetched at once, each of them up to
+    /// `metadata_prefetch_max_bytes` of segments.
+    pub fn do_prefetch_metadata(&self, ctx: Arc<dyn TableContext>) {
+        let Some(segment_cache) = CacheManager::instance().get_table_segment_cache() else {
+            return;
+        };
+        let Ok(permit) = PREFETCH_PERMITS.clone().try_acquire_owned() else {
+            info!(
+                "skip prefetching metadata of table {}, {} tables are being prefetched",
+                self.get_table_info().desc,
+                MAX_CONCURRENT_PREFETCHES
+            );
+            return;
+        };
+        let table = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            let _permit = permit;
+            if let Err(e) = table.prefetch_segments(ctx, segment_cache).await {
+                warn!(
+                    "prefetch metadata of table {} failed: {}",
+                    table.get_table_info().desc,
+                    e
+                );
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn prefetch_segments(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        segment_cache: CompactSegmentInfoCache,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        let locations = snapshot
+            .segments
+            .iter()
+            .filter(|(path, _)| !segment_cache.contains_key(path))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let settings = ctx.get_settings();
+        let max_threads = settings.get_max_threads()? as usize;
+        let max_bytes = settings.get_metadata_prefetch_max_bytes()?;
+        let segments_io = SegmentsIO::create(ctx, self.get_operator(), self.schema_with_stream());
+        let mut loaded = 0;
+        let mut loaded_bytes = 0;
+        for chunk in locations.chunks(max_threads.max(1) * 4) {
+            // Stop once the cache is full, so the prefetching doesn't evict the hot segments.
+            if segment_cache.size() >= segment_cache.capacity() || loaded_bytes >= max_bytes {
+                break;
+            }
+            let segments = segments_io
+                .read_segments::<Arc<CompactSegmentInfo>>(chunk, true)
+                .await?;
+            for segment in segments.iter().flatten() {
+                loaded += 1;
+                loaded_bytes += segment.raw_block_metas.bytes.len();
+            }
+        }
+        if loaded > 0 {
+            info!(
+                "prefetched {} segments of {} bytes of table {}",
+                loaded,
+                loaded_bytes,
+                self.get_table_info().desc
+            );
+        }
+        Ok(())
+    }
+}
@@ -39,6 +39,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
 use crate::operations::read::runtime_filter_prunner::runtime_filter_pruner;
+use crate::operations::read::util::wait_for_memory_pressure;
 use crate::FuseBlockPartInfo;
 
 pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
@@ -231,6 +232,8 @@ impl Processor for ReadNativeDataSource<false> {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
+        wait_for_memory_pressure(&self.partitions).await?;
+
         let parts = self.partitions.steal(self.id, self.batch_size);
 
         if !parts.is_empty() {
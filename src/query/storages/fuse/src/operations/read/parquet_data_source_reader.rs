@@ -41,6 +41,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
 use crate::operations::read::runtime_filter_prunner::runtime_filter_pruner;
+use crate::operations::read::util::wait_for_memory_pressure;
 
 pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     func_ctx: FunctionContext,
@@ -176,11 +177,18 @@ impl SyncSource for ReadParquetDataSource<true> {
                     &None
                 };
 
+                let settings = ReadSettings::from_ctx(&self.partitions.ctx)?;
                 let source = self.block_reader.sync_read_columns_data_by_merge_io(
-                    &ReadSettings::from_ctx(&self.partitions.ctx)?,
+                    &settings,
                     &part,
                     ignore_column_ids,
                 )?;
+                if settings.verify_block_checksum {
+                    let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                    if let Some(checksums) = &fuse_part.column_checksums {
+                        source.verify_checksums(checksums)?;
+                    }
+                }
 
                 Ok(Some(DataBlock::empty_with_meta(
                     DataSourceWithMeta::create(vec![part], vec![ParquetDataSource::Normal((
@@ -229,6 +237,8 @@ impl Processor for ReadParquetDataSource<false> {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
+        wait_for_memory_pressure(&self.partitions).await?;
+
         let parts = self.partitions.steal(self.id, self.batch_size);
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -304,6 +314,11 @@ impl Processor for ReadParquetDataSource<false> {
                                 ignore_column_ids,
                             )
                             .await?;
+                        if settings.verify_block_checksum {
+                            if let Some(checksums) = &part.column_checksums {
+                                source.verify_checksums(checksums)?;
+                            }
+                        }
 
                         Ok(ParquetDataSource::Normal((source, virtual_source)))
                     })
@@ -12,16 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_catalog::merge_into_join::MergeIntoJoinType;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
 use databend_common_catalog::plan::InternalColumnMeta;
+use databend_common_catalog::plan::StealablePartitions;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoPtr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
+use log::info;
 
 use crate::operations::BlockMetaIndex;
 use crate::FuseBlockPartInfo;
@@ -37,6 +44,52 @@ pub fn need_reserve_block_info(ctx: Arc<dyn TableContext>, table_idx: usize) ->
     )
 }
 
+/// Pause reading while the node is under memory pressure, so that the operators downstream
+/// have the chance to spill and release memory. The pauses of all the readers of a scan
+/// share the budget of `memory_pressure_max_pause_ms`, the scan reads without pausing
+/// once the budget is used up.
+pub(crate) async fn wait_for_memory_pressure(partitions: &StealablePartitions) -> Result<()> {
+    let ctx = &partitions.ctx;
+    let settings = ctx.get_settings();
+    let ratio = settings.get_memory_pressure_ratio()?;
+    if !GLOBAL_MEM_STAT.is_memory_pressure(ratio) {
+        return Ok(());
+    }
+
+    let budget = settings.get_memory_pressure_max_pause_ms()?;
+    let paused_ms = &partitions.memory_pressure_paused_ms;
+    let used = paused_ms.load(Ordering::Relaxed);
+    if used >= budget {
+        return Ok(());
+    }
+
+    let max_pause = Duration::from_millis(budget - used);
+    let paused = pause_while(
+        || GLOBAL_MEM_STAT.is_memory_pressure(ratio),
+        max_pause,
+        || ctx.check_aborting(),
+    )
+    .await?;
+    paused_ms.fetch_add(paused.as_millis() as u64, Ordering::Relaxed);
+    info!("table scan paused for {:?} due to memory pressure", paused);
+    Ok(())
+}
+
+/// Sleep while `condition` holds, at most for `max_pause`, and return how long it slept.
+/// The error of `check_aborting` is returned as soon as the query is aborted.
+async fn pause_while(
+    condition: impl Fn() -> bool,
+    max_pause: Duration,
+    check_aborting: impl Fn() -> Result<()>,
+) -> Result<Duration> {
+    let start = Instant::now();
+    while condition() && start.elapsed() < max_pause {
+        check_aborting()?;
+        sleep(Duration::from_millis(10)).await;
+    }
+    Ok(start.elapsed())
+}
+
 pub(crate) fn add_data_block_meta(
     block: DataBlock,
     fuse_part: &FuseBlockPartInfo,
@@ -82,3 +135,50 @@ pub(crate) fn add_data_block_meta(
     }
     block.add_meta(meta)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use databend_common_base::base::tokio;
+    use databend_common_exception::ErrorCode;
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_pause_while() -> Result<()> {
+        let max_pause = Duration::from_secs(3600);
+
+        // Stop pausing once the condition is released.
+        let polls = AtomicUsize::new(0);
+        let paused = pause_while(
+            || polls.fetch_add(1, Ordering::Relaxed) < 3,
+            max_pause,
+            || Ok(()),
+        )
+        .await?;
+        assert!(paused < max_pause);
+        assert_eq!(polls.load(Ordering::Relaxed), 4);
+
+        // Stop pausing after the max pause.
+        let paused = pause_while(|| true, Duration::from_millis(50), || Ok(())).await?;
+        assert!(paused >= Duration::from_millis(50));
+
+        // Stop pausing as soon as the query is aborted.
+        let checks = AtomicUsize::new(0);
+        let res = pause_while(
+            || true,
+            max_pause,
+            || match checks.fetch_add(1, Ordering::Relaxed) {
+                0 | 1 => Ok(()),
+                _ => Err(ErrorCode::AbortedQuery("aborted")),
+            },
+        )
+        .await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::ABORTED_QUERY);
+        assert_eq!(checks.load(Ordering::Relaxed), 3);
+
+        Ok(())
+    }
+}
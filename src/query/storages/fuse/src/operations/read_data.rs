@@ -28,6 +28,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
+use log::info;
 
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
@@ -166,10 +167,16 @@ impl FuseTable {
                 let push_downs = push_downs.clone();
                 // let lazy_init_segments = lazy_init_segments.clone();
 
+                let segments = lazy_init_segments.len();
                 let partitions = Runtime::with_worker_threads(2, None)?.block_on(async move {
                     let (_statistics, partitions) = table
                         .prune_snapshot_blocks(ctx, push_downs, table_schema, lazy_init_segments, 0)
                         .await?;
+                    info!(
+                        "lazy pruning of {} segments done, {} partitions survived",
+                        segments,
+                        partitions.len()
+                    );
 
                     Result::<_, ErrorCode>::Ok(partitions)
                 })?;
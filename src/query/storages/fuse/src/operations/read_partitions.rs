@@ -27,6 +27,7 @@ use databend_common_catalog::plan::TopK;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_sql::field_default_value;
@@ -36,14 +37,17 @@ use databend_storages_common_cache_manager::CachedObject;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_pruner::BlockMetaIndex;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::ColumnMeta;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::table::ChangeType;
 use log::debug;
 use log::info;
+use log::warn;
 use sha2::Digest;
 use sha2::Sha256;
 
 use crate::fuse_part::FuseBlockPartInfo;
+use crate::io::bloom_index_targets;
 use crate::io::BloomIndexBuilder;
 use crate::pruning::create_segment_location_vector;
 use crate::pruning::FusePruner;
@@ -91,6 +95,27 @@ impl FuseTable {
                         segments.push(FuseLazyPartInfo::create(idx, segment_location.clone()))
                     }
 
+                    // Prune the segments on the nodes of the cluster before the query is
+                    // scheduled, each node prunes its own share of the segments and only the
+                    // surviving blocks are distributed to the executors.
+                    if !cluster.is_empty() && ctx.get_settings().get_enable_distributed_pruning()? {
+                        let (mut statistics, partitions) = ctx
+                            .prune_lazy_parts_in_cluster(
+                                &self.table_info,
+                                push_downs,
+                                Some(snapshot_loc),
+                                Partitions::create(PartitionsShuffleKind::Mod, segments),
+                            )
+                            .await?;
+                        statistics.partitions_total = snapshot.summary.block_count as usize;
+                        info!(
+                            "distributed pruning of {} segments done, {} partitions survived",
+                            snapshot.segments.len(),
+                            partitions.len()
+                        );
+                        return Ok((statistics, partitions));
+                    }
+
                     return Ok((
                         PartStatistics::new_estimated(
                             Some(snapshot_loc),
@@ -139,6 +164,7 @@ impl FuseTable {
         );
 
         let dal = self.operator.clone();
+        let quarantined_blocks = self.quarantined_blocks()?;
 
         type CacheItem = (PartStatistics, Partitions);
 
@@ -149,7 +175,10 @@ impl FuseTable {
                 .map(|push_downs| {
                     format!(
                         "{:x}",
-                        Sha256::digest(format!("{:?}_{:?}", segments_location, push_downs))
+                        Sha256::digest(format!(
+                            "{:?}_{:?}_{:?}",
+                            segments_location, push_downs, quarantined_blocks
+                        ))
                     )
                 });
 
@@ -175,6 +204,8 @@ impl FuseTable {
             let bloom_columns_map = self
                 .bloom_index_cols()
                 .bloom_index_fields(table_schema.clone(), BloomIndex::supported_type)?;
+            let bloom_index_targets =
+                bloom_index_targets(&self.bloom_index_cols, table_schema.clone())?;
 
             Some(BloomIndexBuilder {
                 table_ctx: ctx.clone(),
@@ -182,6 +213,7 @@ impl FuseTable {
                 table_dal: dal.clone(),
                 storage_format,
                 bloom_columns_map,
+                bloom_index_targets,
             })
         } else {
             None
@@ -219,10 +251,21 @@ impl FuseTable {
             start.elapsed()
         );
 
+        let num_blocks = block_metas.len();
         let block_metas = block_metas
             .into_iter()
+            .filter(|(_, block_meta)| !quarantined_blocks.contains(&block_meta.location.0))
             .map(|(block_meta_index, block_meta)| (Some(block_meta_index), block_meta))
             .collect::<Vec<_>>();
+        if block_metas.len() < num_blocks {
+            let warning = format!(
+                "{} quarantined blocks of table {} are excluded from the scan, run `SYSTEM VERIFY TABLE` to list the corrupted blocks",
+                num_blocks - block_metas.len(),
+                self.table_info.name
+            );
+            warn!("{}", warning);
+            ctx.push_warning(warning);
+        }
 
         let schema = self.schema_with_stream();
         let result = self.read_partitions_with_metas(
@@ -479,6 +522,7 @@ impl FuseTable {
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
+        let column_checksums = Self::part_column_checksums(meta, &columns_meta);
 
         let sort_min_max = top_k.as_ref().map(|(top_k, default)| {
             meta.col_stats
@@ -496,6 +540,8 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.cluster_stats.as_ref().map(|v| v.cluster_key_id),
+            column_checksums,
         )
     }
 
@@ -525,6 +571,7 @@ impl FuseTable {
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
+        let column_checksums = Self::part_column_checksums(meta, &columns_meta);
 
         let sort_min_max = top_k.map(|(top_k, default)| {
             let stat = meta.col_stats.get(&top_k.field.column_id);
@@ -544,6 +591,21 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.cluster_stats.as_ref().map(|v| v.cluster_key_id),
+            column_checksums,
         )
     }
+
+    fn part_column_checksums(
+        meta: &BlockMeta,
+        columns_meta: &HashMap<ColumnId, ColumnMeta>,
+    ) -> Option<HashMap<ColumnId, u32>> {
+        meta.column_checksums.as_ref().map(|checksums| {
+            checksums
+                .iter()
+                .filter(|(column_id, _)| columns_meta.contains_key(column_id))
+                .map(|(column_id, checksum)| (*column_id, *checksum))
+                .collect()
+        })
+    }
 }
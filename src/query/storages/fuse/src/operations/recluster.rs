@@ -18,6 +18,7 @@ use std::time::Instant;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::MaintenanceProgress;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -62,6 +63,7 @@ impl FuseTable {
         &self,
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
+        last_segments: Option<usize>,
         limit: Option<usize>,
     ) -> Result<Option<ReclusterMutator>> {
         // Status.
@@ -73,6 +75,7 @@ impl FuseTable {
         if self.cluster_key_meta.is_none() {
             return Ok(None);
         }
+        self.check_column_backfills_done()?;
 
         let snapshot_opt = self.read_table_snapshot().await?;
         let snapshot = if let Some(val) = snapshot_opt {
@@ -86,8 +89,11 @@ impl FuseTable {
 
         let mut mutator = ReclusterMutator::try_create(self, ctx.clone(), snapshot.clone())?;
 
-        let segment_locations = snapshot.segments.clone();
-        let segment_locations = create_segment_location_vector(segment_locations, None);
+        let mut segment_locations = create_segment_location_vector(snapshot.segments.clone(), None);
+        // The newest segments are at the front of the snapshot.
+        if let Some(last_segments) = last_segments {
+            segment_locations.truncate(last_segments);
+        }
 
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
         let limit = limit.unwrap_or(1000);
@@ -121,6 +127,12 @@ impl FuseTable {
                     start.elapsed()
                 );
                 ctx.set_status_info(&status);
+                ctx.set_maintenance_progress(MaintenanceProgress::new(
+                    "recluster",
+                    "read_segments",
+                    segment_idx,
+                    number_segments,
+                ));
             }
 
             if compact_segments.is_empty() {
@@ -0,0 +1,296 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::Scalar;
+use databend_common_storage::is_cold_tier_location;
+use databend_storages_common_cache_manager::CachedObject;
+use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::Statistics;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use futures_util::TryStreamExt;
+use itertools::Itertools;
+use log::info;
+use log::warn;
+
+use crate::io::MetaReaders;
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::io::SnapshotHistoryReader;
+use crate::operations::segment_tier;
+use crate::statistics::merge_statistics;
+use crate::statistics::reduce_block_metas;
+use crate::FuseTable;
+
+/// The snapshots walked back at most to find the snapshots creating the segments, the
+/// segments older than that get the oldest snapshot walked.
+const MAX_CREATION_SNAPSHOT_TRACE: usize = 1000;
+
+/// A segment of the current snapshot of a table.
+pub struct TableSegmentInfo {
+    pub location: String,
+    pub block_count: u64,
+    pub row_count: u64,
+    pub bytes_uncompressed: u64,
+    pub bytes_compressed: u64,
+    pub cluster_key_min: Option<String>,
+    pub cluster_key_max: Option<String>,
+    /// The snapshot the segment is first referenced by, None if it is not retained or
+    /// not traced.
+    pub created_snapshot_id: Option<String>,
+    pub tier: &'static str,
+}
+
+/// A block of the current snapshot of a table.
+pub struct TableBlockInfo {
+    pub segment_location: String,
+    pub block_location: String,
+    pub row_count: u64,
+    pub block_size: u64,
+    pub file_size: u64,
+    pub cluster_key_min: Option<String>,
+    pub cluster_key_max: Option<String>,
+    pub created_on: Option<DateTime<Utc>>,
+    pub tier: &'static str,
+}
+
+fn format_cluster_values(values: &[Scalar]) -> String {
+    format!("[{}]", values.iter().map(|s| format!("{}", s)).join(", "))
+}
+
+fn cluster_key_range(stats: &Option<ClusterStatistics>) -> (Option<String>, Option<String>) {
+    match stats {
+        Some(stats) => (
+            Some(format_cluster_values(stats.min())),
+            Some(format_cluster_values(stats.max())),
+        ),
+        None => (None, None),
+    }
+}
+
+impl FuseTable {
+    /// Returns the segments of the current snapshot with their statistics.
+    ///
+    /// The snapshots creating the segments are traced back through the history of the
+    /// table only if `trace_creation` is set, since it reads up to
+    /// `MAX_CREATION_SNAPSHOT_TRACE` snapshots.
+    #[async_backtrace::framed]
+    pub async fn segment_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        trace_creation: bool,
+    ) -> Result<Vec<TableSegmentInfo>> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(vec![]);
+        };
+        let mut created_snapshots = match trace_creation {
+            true => self.segment_creation_snapshots(&snapshot).await?,
+            false => HashMap::new(),
+        };
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        let segments = segments_io
+            .read_segments::<SegmentInfo>(&snapshot.segments, false)
+            .await?;
+
+        let mut infos = Vec::with_capacity(segments.len());
+        for ((location, _), segment) in snapshot.segments.iter().zip(segments) {
+            let segment = segment?;
+            let (cluster_key_min, cluster_key_max) =
+                cluster_key_range(&segment.summary.cluster_stats);
+            infos.push(TableSegmentInfo {
+                location: location.clone(),
+                block_count: segment.summary.block_count,
+                row_count: segment.summary.row_count,
+                bytes_uncompressed: segment.summary.uncompressed_byte_size,
+                bytes_compressed: segment.summary.compressed_byte_size,
+                cluster_key_min,
+                cluster_key_max,
+                created_snapshot_id: created_snapshots.remove(location),
+                tier: segment_tier(&segment),
+            });
+        }
+        Ok(infos)
+    }
+
+    /// Returns the blocks of the current snapshot with their statistics.
+    #[async_backtrace::framed]
+    pub async fn block_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Vec<TableBlockInfo>> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(vec![]);
+        };
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        let segments = segments_io
+            .read_segments::<SegmentInfo>(&snapshot.segments, false)
+            .await?;
+
+        let mut infos = vec![];
+        for ((location, _), segment) in snapshot.segments.iter().zip(segments) {
+            for block in segment?.blocks.iter() {
+                let (cluster_key_min, cluster_key_max) = cluster_key_range(&block.cluster_stats);
+                let tier = if is_cold_tier_location(&block.location.0) {
+                    "cold"
+                } else {
+                    "hot"
+                };
+                infos.push(TableBlockInfo {
+                    segment_location: location.clone(),
+                    block_location: block.location.0.clone(),
+                    row_count: block.row_count,
+                    block_size: block.block_size,
+                    file_size: block.file_size,
+                    cluster_key_min,
+                    cluster_key_max,
+                    created_on: block.create_on,
+                    tier,
+                });
+            }
+        }
+        Ok(infos)
+    }
+
+    /// Maps the segments of the snapshot to the oldest snapshot of the unbroken history
+    /// referencing them, which is the snapshot creating them.
+    #[async_backtrace::framed]
+    async fn segment_creation_snapshots(
+        &self,
+        snapshot: &TableSnapshot,
+    ) -> Result<HashMap<String, String>> {
+        let snapshot_version = self.snapshot_format_version(None).await?;
+        let snapshot_location = self
+            .meta_location_generator
+            .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot_version)?;
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut snapshot_stream = reader.snapshot_history(
+            snapshot_location,
+            snapshot_version,
+            self.meta_location_generator().clone(),
+        );
+
+        let mut created = HashMap::with_capacity(snapshot.segments.len());
+        let mut tracing = snapshot
+            .segments
+            .iter()
+            .map(|(location, _)| location.clone())
+            .collect::<HashSet<_>>();
+        let mut traced = 0;
+        while !tracing.is_empty() && traced < MAX_CREATION_SNAPSHOT_TRACE {
+            let prev = match snapshot_stream.try_next().await {
+                Ok(Some((prev, _))) => prev,
+                Ok(None) => break,
+                // The older snapshots may be purged already.
+                Err(e) => {
+                    warn!("stop tracing snapshots of table {}: {}", self.name(), e);
+                    break;
+                }
+            };
+            let prev_segments = prev
+                .segments
+                .iter()
+                .map(|(location, _)| location)
+                .collect::<HashSet<_>>();
+            let prev_id = prev.snapshot_id.simple().to_string();
+            tracing.retain(|location| {
+                if prev_segments.contains(location) {
+                    created.insert(location.clone(), prev_id.clone());
+                    true
+                } else {
+                    false
+                }
+            });
+            traced += 1;
+        }
+        Ok(created)
+    }
+
+    /// Re-derives the statistics of the segments of the current snapshot from their blocks,
+    /// and of the snapshot from its segments. The segments whose statistics are stale are
+    /// rewritten, and the cached segments are evicted so that they are read again.
+    ///
+    /// The caller is expected to hold the lock of the table, so that the segments are not
+    /// rewritten by a concurrent compaction or recluster meanwhile.
+    ///
+    /// Returns the number of the segments, blocks and rows of the table.
+    #[async_backtrace::framed]
+    pub async fn refresh_statistics(&self, ctx: Arc<dyn TableContext>) -> Result<(u64, u64, u64)> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok((0, 0, 0));
+        };
+
+        if let Some(cache) = CompactSegmentInfo::cache() {
+            for (location, _) in snapshot.segments.iter() {
+                cache.evict(location);
+            }
+        }
+
+        let thresholds = self.get_block_thresholds();
+        let cluster_key_id = self.cluster_key_id();
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let segment_writer = SegmentWriter::new(&self.operator, &self.meta_location_generator);
+        let chunk_size = (ctx.get_settings().get_max_threads()? as usize * 4).max(1);
+
+        let mut segment_locations = snapshot.segments.clone();
+        let mut summary = Statistics::default();
+        let mut rewritten = 0;
+        for (chunk_idx, chunk) in snapshot.segments.chunks(chunk_size).enumerate() {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (idx, segment) in segments.into_iter().enumerate() {
+                let segment = segment?;
+                let segment_summary =
+                    reduce_block_metas(&segment.blocks, thresholds, cluster_key_id);
+                summary = merge_statistics(&summary, &segment_summary, cluster_key_id);
+                if segment_summary == segment.summary {
+                    continue;
+                }
+
+                let location = segment_writer
+                    .write_segment(SegmentInfo::new(segment.blocks.clone(), segment_summary))
+                    .await?;
+                segment_locations[chunk_idx * chunk_size + idx] = location;
+                rewritten += 1;
+            }
+        }
+
+        let counts = (
+            segment_locations.len() as u64,
+            summary.block_count,
+            summary.row_count,
+        );
+        if rewritten == 0 && summary == snapshot.summary {
+            return Ok(counts);
+        }
+        info!(
+            "refresh statistics of table {}, {} segments rewritten",
+            self.name(),
+            rewritten
+        );
+        self.commit_mutation(&ctx, snapshot.clone(), &segment_locations, summary, None)
+            .await?;
+        Ok(counts)
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Duration;
+use chrono::Utc;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_storage::cold_tier_location;
+use databend_common_storage::is_cold_tier_location;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_POLICY;
+use log::info;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+/// The storage policy of a table, the blocks of the segments older than `cold_after_days`
+/// are moved to the cold storage, and are read from there transparently.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TableStoragePolicy {
+    pub cold_after_days: u64,
+    /// The cold location with the credentials masked, which is only displayed.
+    pub cold_location: String,
+    pub cold_storage: StorageParams,
+}
+
+/// The tier of a segment, by the tiers of its blocks.
+pub fn segment_tier(segment: &SegmentInfo) -> &'static str {
+    let cold_blocks = segment
+        .blocks
+        .iter()
+        .filter(|block| is_cold_tier_location(&block.location.0))
+        .count();
+    if cold_blocks == 0 {
+        "hot"
+    } else if cold_blocks == segment.blocks.len() {
+        "cold"
+    } else {
+        "mixed"
+    }
+}
+
+impl FuseTable {
+    pub fn parse_storage_policy(
+        table_info: &TableInfo,
+    ) -> Result<Option<TableStoragePolicy>> {
+        match table_info.options().get(OPT_KEY_STORAGE_POLICY) {
+            Some(value) => serde_json::from_str(value).map(Some).map_err(|e| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid table option {}: {}",
+                    OPT_KEY_STORAGE_POLICY, e
+                ))
+            }),
+            None => Ok(None),
+        }
+    }
+
+    pub fn storage_policy(&self) -> Result<Option<TableStoragePolicy>> {
+        Self::parse_storage_policy(&self.table_info)
+    }
+
+    /// Returns true if the current snapshot references blocks in the cold storage.
+    #[async_backtrace::framed]
+    pub async fn has_cold_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<bool> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(false);
+        };
+        let segments_io = SegmentsIO::create(ctx, self.operator.clone(), self.schema());
+        for segment in segments_io
+            .read_segments::<SegmentInfo>(&snapshot.segments, false)
+            .await?
+        {
+            if segment_tier(&segment?) != "hot" {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Moves the blocks of the segments whose newest block is older than the
+    /// `cold_after_days` of the storage policy to the cold storage.
+    ///
+    /// The blocks are copied, and the segments referencing the copies are committed in
+    /// place of the old ones, the hot blocks are removed by the purge of the old snapshots
+    /// as the other blocks no longer referenced.
+    ///
+    /// Returns the number of the migrated segments.
+    #[async_backtrace::framed]
+    pub async fn migrate_cold_segments(&self, ctx: Arc<dyn TableContext>) -> Result<usize> {
+        let Some(policy) = self.storage_policy()? else {
+            return Ok(0);
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(0);
+        };
+
+        let cold_before = Utc::now() - Duration::days(policy.cold_after_days as i64);
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let segment_writer = SegmentWriter::new(&self.operator, &self.meta_location_generator);
+        let chunk_size = (ctx.get_settings().get_max_threads()? as usize * 4).max(1);
+
+        let mut segment_locations = snapshot.segments.clone();
+        let mut migrated = 0;
+        for (chunk_idx, chunk) in snapshot.segments.chunks(chunk_size).enumerate() {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for (idx, segment) in segments.into_iter().enumerate() {
+                let segment = segment?;
+                // The blocks written before the creation time was recorded are old enough.
+                let is_due = segment
+                    .blocks
+                    .iter()
+                    .all(|block| block.create_on.map_or(true, |t| t < cold_before));
+                if !is_due || segment_tier(&segment) != "hot" {
+                    continue;
+                }
+
+                let mut blocks = Vec::with_capacity(segment.blocks.len());
+                for block in segment.blocks.iter() {
+                    let location = cold_tier_location(&block.location.0);
+                    let data = self.operator.read(&block.location.0).await?;
+                    self.operator.write(&location, data).await?;
+
+                    let mut block = block.as_ref().clone();
+                    block.location.0 = location;
+                    blocks.push(Arc::new(block));
+                }
+                let location = segment_writer
+                    .write_segment(SegmentInfo::new(blocks, segment.summary.clone()))
+                    .await?;
+                segment_locations[chunk_idx * chunk_size + idx] = location;
+                migrated += 1;
+            }
+        }
+
+        if migrated == 0 {
+            return Ok(0);
+        }
+        info!(
+            "migrate {} segments of table {} to the cold storage {}",
+            migrated,
+            self.name(),
+            policy.cold_location
+        );
+        self.commit_mutation(
+            &ctx,
+            snapshot.clone(),
+            &segment_locations,
+            snapshot.summary.clone(),
+            None,
+        )
+        .await?;
+        Ok(migrated)
+    }
+}
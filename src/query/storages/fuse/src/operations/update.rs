@@ -46,6 +46,7 @@ impl FuseTable {
         col_indices: Vec<FieldIndex>,
         query_row_id_col: bool,
     ) -> Result<Option<Arc<TableSnapshot>>> {
+        self.check_column_backfills_done()?;
         let snapshot_opt = self.read_table_snapshot().await?;
 
         // check if table is empty
@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+
+use crate::io::column_checksums;
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+/// A block of the table which fails the verification.
+#[derive(Clone, Debug)]
+pub struct CorruptedBlock {
+    pub location: String,
+    pub reason: String,
+}
+
+impl FuseTable {
+    /// Verify the blocks of the current snapshot, by reading the block files and comparing
+    /// the checksums of the column chunks with the ones recorded in the block metas.
+    ///
+    /// The blocks written before the checksums were recorded are only checked for existence
+    /// and file size.
+    #[async_backtrace::framed]
+    pub async fn verify_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<Vec<CorruptedBlock>> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(vec![]);
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let chunk_size = (ctx.get_settings().get_max_threads()? as usize * 4).max(1);
+
+        let mut corrupted_blocks = vec![];
+        for chunk in snapshot.segments.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(chunk, false)
+                .await?;
+            for segment in segments {
+                let segment = segment?;
+                for block in segment.blocks.iter() {
+                    if let Some(reason) = self.verify_block(block).await {
+                        corrupted_blocks.push(CorruptedBlock {
+                            location: block.location.0.clone(),
+                            reason,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(corrupted_blocks)
+    }
+
+    async fn verify_block(&self, block: &BlockMeta) -> Option<String> {
+        let data = match self.operator.read(&block.location.0).await {
+            Ok(data) => data.to_vec(),
+            Err(e) => return Some(format!("failed to read the block file: {}", e)),
+        };
+        if data.len() as u64 != block.file_size {
+            return Some(format!(
+                "the size of the block file is {}, but {} is expected",
+                data.len(),
+                block.file_size
+            ));
+        }
+
+        let expected = block.column_checksums.as_ref()?;
+        for (column_id, meta) in block.col_metas.iter() {
+            let (offset, len) = meta.offset_length();
+            if offset + len > block.file_size {
+                return Some(format!(
+                    "the chunk of column {} is out of the block file",
+                    column_id
+                ));
+            }
+        }
+        let actual = column_checksums(&data, &block.col_metas);
+        let mut column_ids = expected.keys().collect::<Vec<_>>();
+        column_ids.sort();
+        for column_id in column_ids {
+            if actual.get(column_id) != expected.get(column_id) {
+                return Some(format!("checksum mismatch of column {}", column_id));
+            }
+        }
+        None
+    }
+}
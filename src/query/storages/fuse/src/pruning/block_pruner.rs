@@ -26,11 +26,13 @@ use databend_common_expression::types::F32;
 use databend_common_expression::BLOCK_NAME_COL_NAME;
 use databend_common_metrics::storage::*;
 use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_pruner::LimiterPruner;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use futures_util::future;
 use log::info;
 
 use super::SegmentLocation;
+use crate::pruning::FusePruningStatistics;
 use crate::pruning::PruningContext;
 
 pub struct BlockPruner {
@@ -105,8 +107,10 @@ impl BlockPruner {
 
         let mut block_meta_indexes = block_meta_indexes.into_iter();
         let pruning_tasks = std::iter::from_fn(|| {
-            // check limit speculatively
+            // check limit speculatively, the rest blocks are all pruned by limit.
             if limit_pruner.exceeded() {
+                let pruned = block_meta_indexes.by_ref().count();
+                pruning_stats.set_blocks_limit_pruning_before(pruned as u64);
                 return None;
             }
 
@@ -172,8 +176,7 @@ impl BlockPruner {
                                     .should_keep(&index_location, index_size, &block_meta.col_stats, column_ids, &block_meta)
                                     .await;
 
-                                let keep = keep_by_bloom && limit_pruner.within_limit(row_count);
-                                if keep {
+                                if keep_by_bloom {
                                     // Perf.
                                     {
                                         metrics_inc_blocks_bloom_pruning_after(1);
@@ -184,10 +187,11 @@ impl BlockPruner {
                                         pruning_stats.set_blocks_bloom_pruning_after(1);
                                     }
                                 }
-                                keep
+                                keep_by_bloom
                             } else {
-                                limit_pruner.within_limit(row_count)
+                                true
                             };
+                            let keep = keep && Self::limit_pruning(&limit_pruner, &pruning_stats, row_count);
                             if keep {
                                 let (keep, range) =
                                     page_pruner.should_keep(&block_meta.cluster_stats);
@@ -302,7 +306,8 @@ impl BlockPruner {
 
         let mut result = Vec::with_capacity(block_meta_indexes.len());
         let block_num = block_metas.len();
-        for (block_idx, block_meta) in block_meta_indexes {
+        let mut block_meta_indexes = block_meta_indexes.into_iter();
+        while let Some((block_idx, block_meta)) = block_meta_indexes.next() {
             // Perf.
             {
                 metrics_inc_blocks_range_pruning_before(1);
@@ -311,13 +316,15 @@ impl BlockPruner {
                 pruning_stats.set_blocks_range_pruning_before(1);
             }
 
-            // check limit speculatively
+            // check limit speculatively, this block and the rest are all pruned by limit.
             if limit_pruner.exceeded() {
+                let pruned = 1 + block_meta_indexes.len();
+                pruning_stats.set_blocks_limit_pruning_before(pruned as u64);
                 break;
             }
             let row_count = block_meta.row_count;
             if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
-                && limit_pruner.within_limit(row_count)
+                && Self::limit_pruning(&limit_pruner, &pruning_stats, row_count)
             {
                 // Perf.
                 {
@@ -354,6 +361,25 @@ impl BlockPruner {
 
         Ok(result)
     }
+
+    // Returns true if the block is kept by the limit pruner, the stats are
+    // only recorded if there is a limit pushed down.
+    fn limit_pruning(
+        limit_pruner: &LimiterPruner,
+        pruning_stats: &FusePruningStatistics,
+        row_count: u64,
+    ) -> bool {
+        if !limit_pruner.is_limited() {
+            return true;
+        }
+
+        pruning_stats.set_blocks_limit_pruning_before(1);
+        let keep = limit_pruner.within_limit(row_count);
+        if keep {
+            pruning_stats.set_blocks_limit_pruning_after(1);
+        }
+        keep
+    }
 }
 
 // result of block pruning
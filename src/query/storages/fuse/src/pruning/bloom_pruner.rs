@@ -60,6 +60,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// the map keys or variant paths that have filters, grouped by column id
+    key_paths: HashMap<ColumnId, Vec<Vec<String>>>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -89,6 +92,16 @@ impl BloomPrunerCreator {
             let bloom_columns_map =
                 bloom_index_cols.bloom_index_fields(schema.clone(), BloomIndex::supported_type)?;
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
+            let mut key_paths = HashMap::<ColumnId, Vec<Vec<String>>>::new();
+            for (index, cols) in bloom_index_cols.bloom_index_columns(schema.clone())? {
+                if let Some(field) = bloom_columns_map.get(&index) {
+                    key_paths.entry(field.column_id()).or_default().extend(
+                        cols.into_iter()
+                            .filter(|col| !col.path.is_empty())
+                            .map(|col| col.path),
+                    );
+                }
+            }
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
             if !point_query_cols.is_empty() {
@@ -106,6 +119,7 @@ impl BloomPrunerCreator {
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    key_paths,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -135,7 +149,15 @@ impl BloomPrunerCreator {
             Vec::with_capacity(self.index_fields.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
-                    acc.push(BloomIndex::build_filter_column_name(version, field)?);
+                    let filter_column = BloomIndex::build_filter_column_name(version, field)?;
+                    if let Some(paths) = self.key_paths.get(&field.column_id()) {
+                        for path in paths {
+                            let key_filter_column =
+                                BloomIndex::build_key_filter_column_name(&filter_column, path);
+                            acc.extend(BloomIndex::all_filter_column_names(&key_filter_column));
+                        }
+                    }
+                    acc.extend(BloomIndex::all_filter_column_names(&filter_column));
                 }
                 Ok::<_, ErrorCode>(acc)
             },
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_base::base::tokio::sync::Semaphore;
@@ -21,6 +22,7 @@ use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::SEGMENT_NAME_COL_NAME;
@@ -192,8 +194,12 @@ pub struct FusePruner {
     pub pruning_ctx: Arc<PruningContext>,
     pub push_down: Option<PushDownInfo>,
     pub inverse_range_index: Option<RangeIndex>,
+    pub match_likelihood_index: Option<RangeIndex>,
     pub deleted_segments: Vec<DeletedSegmentInfo>,
     pub block_meta_cache: Option<BlockMetaCache>,
+    /// The columns whose statistics are decoded from the segments for read pruning, all the
+    /// columns if it's `None`.
+    pub stats_column_ids: Option<Arc<HashSet<ColumnId>>>,
 }
 
 impl FusePruner {
@@ -244,6 +250,28 @@ impl FusePruner {
 
         info!("max concurrency of pruning is set to {}", max_concurrency);
 
+        // If there are filters + limit clause and no ordering, the inverted filter is used
+        // to find the blocks in which all rows match the filters, and they are read first.
+        let match_likelihood_index = match push_down
+            .as_ref()
+            .filter(|p| p.order_by.is_empty() && p.filter_limit.is_some())
+            .and_then(|p| p.filters.as_ref())
+        {
+            Some(filters)
+                if ctx
+                    .get_settings()
+                    .get_enable_block_reorder_by_match_likelihood()? =>
+            {
+                Some(RangeIndex::try_create(
+                    ctx.get_function_context()?,
+                    &filters.inverted_filter.as_expr(&BUILTIN_FUNCTIONS),
+                    table_schema.clone(),
+                    StatisticsOfColumns::default(),
+                )?)
+            }
+            _ => None,
+        };
+
         let pruning_ctx = PruningContext::try_create(
             ctx,
             dal,
@@ -256,17 +284,60 @@ impl FusePruner {
             bloom_index_builder,
         )?;
 
+        let stats_column_ids = Self::stats_column_ids(push_down, &table_schema).map(Arc::new);
         Ok(FusePruner {
             max_concurrency,
             table_schema,
             push_down: push_down.clone(),
             pruning_ctx,
             inverse_range_index: None,
+            match_likelihood_index,
             deleted_segments: vec![],
             block_meta_cache: CacheManager::instance().get_block_meta_cache(),
+            stats_column_ids,
         })
     }
 
+    /// The columns read, filtered or sorted by the scan, whose statistics are used by the
+    /// pruners and kept in the partitions. Returns `None` if the scan has no projection or
+    /// refers to a column not found in the schema.
+    fn stats_column_ids(
+        push_down: &Option<PushDownInfo>,
+        table_schema: &TableSchemaRef,
+    ) -> Option<HashSet<ColumnId>> {
+        let push_down = push_down.as_ref()?;
+        let mut column_ids = HashSet::new();
+        let mut projections = vec![push_down.projection.as_ref()?];
+        if let Some(prewhere) = &push_down.prewhere {
+            projections.push(&prewhere.output_columns);
+            projections.push(&prewhere.prewhere_columns);
+        }
+        for projection in projections {
+            for field in projection.project_schema(table_schema).fields() {
+                column_ids.extend(field.leaf_column_ids());
+            }
+        }
+
+        let mut exprs = push_down
+            .order_by
+            .iter()
+            .map(|(expr, _, _)| expr)
+            .collect::<Vec<_>>();
+        if let Some(filters) = &push_down.filters {
+            exprs.push(&filters.filter);
+        }
+        if let Some(prewhere) = &push_down.prewhere {
+            exprs.push(&prewhere.filter);
+        }
+        for expr in exprs {
+            for name in expr.as_expr(&BUILTIN_FUNCTIONS).column_refs().keys() {
+                let field = table_schema.field_with_name(name).ok()?;
+                column_ids.extend(field.leaf_column_ids());
+            }
+        }
+        Some(column_ids)
+    }
+
     #[async_backtrace::framed]
     pub async fn read_pruning(
         &mut self,
@@ -284,7 +355,7 @@ impl FusePruner {
     }
 
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> topn pruner -> match likelihood ordering
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -311,6 +382,7 @@ impl FusePruner {
                 let block_pruner = block_pruner.clone();
                 let segment_pruner = segment_pruner.clone();
                 let pruning_ctx = self.pruning_ctx.clone();
+                let stats_column_ids = self.stats_column_ids.clone();
 
                 async move {
                     // Build pruning tasks.
@@ -349,6 +421,7 @@ impl FusePruner {
                                 &segment_location.location.0,
                                 compact_segment_info,
                                 populate_block_meta_cache,
+                                None,
                             )?;
                             res.extend(
                                 block_pruner
@@ -358,8 +431,12 @@ impl FusePruner {
                         }
                     } else {
                         for (location, info) in pruned_segments {
-                            let block_metas =
-                                Self::extract_block_metas(&location.location.0, &info, true)?;
+                            let block_metas = Self::extract_block_metas(
+                                &location.location.0,
+                                &info,
+                                true,
+                                stats_column_ids.as_deref(),
+                            )?;
                             res.extend(block_pruner.pruning(location.clone(), block_metas).await?);
                         }
                     }
@@ -386,30 +463,37 @@ impl FusePruner {
                     // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                     // will get here, we can prevent other mutations like update and so on.
                     // TopN pruner.
-                    self.topn_pruning(metas)
+                    let metas = self.topn_pruning(metas)?;
+                    Ok(self.match_likelihood_ordering(metas))
                 }
             }
         }
     }
 
+    /// Gets the block metas of the segment from the cache, or decodes them. If `column_ids` is
+    /// given and the segment is in the columnar layout, only the statistics of the columns are
+    /// decoded, and the block metas are not cached since they are incomplete.
     fn extract_block_metas(
         segment_path: &str,
         segment: &CompactSegmentInfo,
         populate_cache: bool,
+        column_ids: Option<&HashSet<ColumnId>>,
     ) -> Result<Arc<Vec<Arc<BlockMeta>>>> {
-        if let Some(cache) = CacheManager::instance().get_block_meta_cache() {
-            if let Some(metas) = cache.get(segment_path) {
-                Ok(metas)
-            } else {
-                let block_metas = Arc::new(segment.block_metas()?);
-                if populate_cache {
-                    cache.put(segment_path.to_string(), block_metas.clone());
-                }
-                Ok(block_metas)
+        let cache = CacheManager::instance().get_block_meta_cache();
+        if let Some(metas) = cache.as_ref().and_then(|cache| cache.get(segment_path)) {
+            return Ok(metas);
+        }
+        if column_ids.is_some() && segment.raw_block_metas.columnar {
+            return Ok(Arc::new(segment.block_metas_with_columns(column_ids)?));
+        }
+
+        let block_metas = Arc::new(segment.block_metas()?);
+        if let Some(cache) = cache {
+            if populate_cache {
+                cache.put(segment_path.to_string(), block_metas.clone());
             }
-        } else {
-            Ok(Arc::new(segment.block_metas()?))
         }
+        Ok(block_metas)
     }
 
     #[async_backtrace::framed]
@@ -465,7 +549,8 @@ impl FusePruner {
                 // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                 // will get here, we can prevent other mutations like update and so on.
                 // TopN pruner.
-                self.topn_pruning(metas)
+                let metas = self.topn_pruning(metas)?;
+                Ok(self.match_likelihood_ordering(metas))
             }
         }
     }
@@ -492,6 +577,20 @@ impl FusePruner {
         Ok(metas)
     }
 
+    // match likelihood ordering:
+    // if there are filters + limit clause and no ordering, the blocks in which all rows match
+    // the filters are moved to the front, so the scan is more likely to reach the limit early.
+    fn match_likelihood_ordering(
+        &self,
+        mut metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Vec<(BlockMetaIndex, Arc<BlockMeta>)> {
+        if let Some(index) = &self.match_likelihood_index {
+            // The stable sort keeps the original order of blocks with the same likelihood.
+            metas.sort_by_key(|(_, meta)| index.should_keep(&meta.col_stats, None));
+        }
+        metas
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> databend_common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
@@ -510,6 +609,9 @@ impl FusePruner {
         let blocks_inverted_index_pruning_after =
             stats.get_blocks_inverted_index_pruning_after() as usize;
 
+        let blocks_limit_pruning_before = stats.get_blocks_limit_pruning_before() as usize;
+        let blocks_limit_pruning_after = stats.get_blocks_limit_pruning_after() as usize;
+
         databend_common_catalog::plan::PruningStatistics {
             segments_range_pruning_before,
             segments_range_pruning_after,
@@ -519,6 +621,8 @@ impl FusePruner {
             blocks_bloom_pruning_after,
             blocks_inverted_index_pruning_before,
             blocks_inverted_index_pruning_after,
+            blocks_limit_pruning_before,
+            blocks_limit_pruning_after,
         }
     }
 
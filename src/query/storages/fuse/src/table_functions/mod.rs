@@ -24,6 +24,7 @@ mod fuse_segments;
 mod fuse_snapshots;
 mod fuse_statistics;
 mod table_args;
+mod table_changes;
 
 pub use cache_admin::SetCacheCapacity;
 pub use clustering_information::ClusteringInformation;
@@ -47,3 +48,4 @@ pub use fuse_snapshots::FuseSnapshot;
 pub use fuse_snapshots::FuseSnapshotTable;
 pub use fuse_statistics::FuseStatisticTable;
 pub use table_args::*;
+pub use table_changes::TableChanges;
@@ -0,0 +1,275 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use futures_util::TryStreamExt;
+
+use crate::io::MetaReaders;
+use crate::io::SegmentsIO;
+use crate::io::SnapshotHistoryReader;
+use crate::sessions::TableContext;
+use crate::table_functions::string_literal;
+use crate::table_functions::string_value;
+use crate::table_functions::SimpleTableFunc;
+use crate::table_functions::TableArgs;
+use crate::FuseTable;
+
+const TABLE_CHANGES_FUNC_NAME: &str = "table_changes";
+
+struct TableChangesArgs {
+    table: String,
+    from_snapshot: String,
+    to_snapshot: String,
+}
+
+impl From<&TableChangesArgs> for TableArgs {
+    fn from(value: &TableChangesArgs) -> Self {
+        TableArgs::new_positioned(vec![
+            string_literal(value.table.as_str()),
+            string_literal(value.from_snapshot.as_str()),
+            string_literal(value.to_snapshot.as_str()),
+        ])
+    }
+}
+
+/// `TABLE_CHANGES('<db>.<table>', '<from_snapshot_id>', '<to_snapshot_id>')` lists the
+/// segments and the blocks added and removed between two snapshots of a fuse table.
+///
+/// The deltas are signed, the removed blocks have negative row counts and sizes, so that
+/// the sum of them is the change of the table between the snapshots. The rows of the
+/// segments are counted by their blocks, the segments have the signed count of their
+/// blocks and no row count and size instead.
+pub struct TableChanges {
+    args: TableChangesArgs,
+}
+
+struct ChangeRows {
+    change: Vec<String>,
+    object: Vec<String>,
+    location: Vec<String>,
+    block_count: Vec<Option<i64>>,
+    row_count: Vec<Option<i64>>,
+    bytes: Vec<Option<i64>>,
+}
+
+impl ChangeRows {
+    fn push_segment(&mut self, added: bool, location: &str, block_count: u64) {
+        self.push(added, "segment", location);
+        self.block_count
+            .push(Some(Self::sign(added) * block_count as i64));
+        self.row_count.push(None);
+        self.bytes.push(None);
+    }
+
+    fn push_block(&mut self, added: bool, location: &str, row_count: u64, bytes: u64) {
+        self.push(added, "block", location);
+        self.block_count.push(None);
+        self.row_count
+            .push(Some(Self::sign(added) * row_count as i64));
+        self.bytes.push(Some(Self::sign(added) * bytes as i64));
+    }
+
+    fn push(&mut self, added: bool, object: &str, location: &str) {
+        self.change
+            .push(if added { "added" } else { "removed" }.to_string());
+        self.object.push(object.to_string());
+        self.location.push(location.to_string());
+    }
+
+    fn sign(added: bool) -> i64 {
+        if added { 1 } else { -1 }
+    }
+}
+
+impl TableChanges {
+    #[async_backtrace::framed]
+    async fn find_snapshot(tbl: &FuseTable, snapshot_id: &str) -> Result<Arc<TableSnapshot>> {
+        if let Some(snapshot) = tbl.read_table_snapshot().await? {
+            let snapshot_version = tbl.snapshot_format_version(None).await?;
+            let snapshot_location = tbl
+                .meta_location_generator
+                .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot_version)?;
+            let reader = MetaReaders::table_snapshot_reader(tbl.get_operator());
+            let mut snapshot_stream = reader.snapshot_history(
+                snapshot_location,
+                snapshot_version,
+                tbl.meta_location_generator().clone(),
+            );
+            while let Some((snapshot, _)) = snapshot_stream.try_next().await? {
+                if snapshot.snapshot_id.simple().to_string() == snapshot_id {
+                    return Ok(snapshot);
+                }
+            }
+        }
+
+        Err(ErrorCode::TableHistoricalDataNotFound(format!(
+            "snapshot {} is not found in the history of table {}",
+            snapshot_id,
+            tbl.name()
+        )))
+    }
+
+    #[async_backtrace::framed]
+    async fn read_segments(
+        segments_io: &SegmentsIO,
+        locations: &[Location],
+    ) -> Result<Vec<SegmentInfo>> {
+        segments_io
+            .read_segments::<SegmentInfo>(locations, false)
+            .await?
+            .into_iter()
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl SimpleTableFunc for TableChanges {
+    fn table_args(&self) -> Option<TableArgs> {
+        Some((&self.args).into())
+    }
+
+    fn schema(&self) -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("change", TableDataType::String),
+            TableField::new("object", TableDataType::String),
+            TableField::new("location", TableDataType::String),
+            TableField::new(
+                "block_count",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int64))),
+            ),
+            TableField::new(
+                "row_count",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int64))),
+            ),
+            TableField::new(
+                "bytes",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int64))),
+            ),
+        ])
+    }
+
+    #[async_backtrace::framed]
+    async fn apply(&self, ctx: &Arc<dyn TableContext>) -> Result<Option<DataBlock>> {
+        let (database, table) = match self.args.table.split_once('.') {
+            Some((database, table)) => (database.to_string(), table.to_string()),
+            None => (ctx.get_current_database(), self.args.table.clone()),
+        };
+        let tbl = ctx
+            .get_catalog(CATALOG_DEFAULT)
+            .await?
+            .get_table(&ctx.get_tenant(), &database, &table)
+            .await?;
+        let tbl = FuseTable::try_from_table(tbl.as_ref())?;
+
+        let from = Self::find_snapshot(tbl, &self.args.from_snapshot).await?;
+        let to = Self::find_snapshot(tbl, &self.args.to_snapshot).await?;
+
+        // The segments are immutable, the segments of both snapshots have the same blocks.
+        let from_segments = from.segments.iter().collect::<HashSet<_>>();
+        let to_segments = to.segments.iter().collect::<HashSet<_>>();
+        let removed = from
+            .segments
+            .iter()
+            .filter(|s| !to_segments.contains(s))
+            .cloned()
+            .collect::<Vec<_>>();
+        let added = to
+            .segments
+            .iter()
+            .filter(|s| !from_segments.contains(s))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let segments_io = SegmentsIO::create(ctx.clone(), tbl.get_operator(), tbl.schema());
+        let removed_segments = Self::read_segments(&segments_io, &removed).await?;
+        let added_segments = Self::read_segments(&segments_io, &added).await?;
+
+        let mut rows = ChangeRows {
+            change: vec![],
+            object: vec![],
+            location: vec![],
+            block_count: vec![],
+            row_count: vec![],
+            bytes: vec![],
+        };
+        for (added, locations, segments) in [
+            (false, &removed, &removed_segments),
+            (true, &added, &added_segments),
+        ] {
+            for (location, segment) in locations.iter().zip(segments.iter()) {
+                rows.push_segment(added, &location.0, segment.summary.block_count);
+            }
+        }
+
+        // The blocks may be moved between the segments, e.g. by the compaction of the
+        // segments, which are not changes of the data.
+        let block_locations = |segments: &[SegmentInfo]| {
+            segments
+                .iter()
+                .flat_map(|s| s.blocks.iter().map(|b| b.location.0.clone()))
+                .collect::<HashSet<_>>()
+        };
+        let removed_blocks = block_locations(&removed_segments);
+        let added_blocks = block_locations(&added_segments);
+        for (added, segments, others) in [
+            (false, &removed_segments, &added_blocks),
+            (true, &added_segments, &removed_blocks),
+        ] {
+            for block in segments.iter().flat_map(|s| s.blocks.iter()) {
+                if !others.contains(&block.location.0) {
+                    rows.push_block(added, &block.location.0, block.row_count, block.block_size);
+                }
+            }
+        }
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(rows.change),
+            StringType::from_data(rows.object),
+            StringType::from_data(rows.location),
+            Int64Type::from_opt_data(rows.block_count),
+            Int64Type::from_opt_data(rows.row_count),
+            Int64Type::from_opt_data(rows.bytes),
+        ])))
+    }
+
+    fn create(table_args: TableArgs) -> Result<Self>
+    where Self: Sized {
+        let args = table_args.expect_all_positioned(TABLE_CHANGES_FUNC_NAME, Some(3))?;
+        Ok(Self {
+            args: TableChangesArgs {
+                table: string_value(&args[0])?,
+                from_snapshot: string_value(&args[1])?,
+                to_snapshot: string_value(&args[2])?,
+            },
+        })
+    }
+}
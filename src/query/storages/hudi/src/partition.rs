@@ -0,0 +1,165 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_storages_common_index::RangeIndex;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use ordered_float::OrderedFloat;
+
+use crate::timeline::HudiBaseFile;
+
+pub const HUDI_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Prune the base files by the values of the partition columns in their partition paths.
+///
+/// The partition columns are kept in the base files as well, so the files whose
+/// partition path can't be parsed, e.g. written by a timestamp based key generator, are kept.
+pub struct HudiPartitionPruner {
+    range_index: RangeIndex,
+    partition_fields: Vec<TableField>,
+    hive_style_partitioning: bool,
+}
+
+impl HudiPartitionPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        filter: &Expr<String>,
+        schema: TableSchemaRef,
+        partition_fields: Vec<TableField>,
+        hive_style_partitioning: bool,
+    ) -> Result<Self> {
+        let range_index =
+            RangeIndex::try_create(func_ctx, filter, schema, StatisticsOfColumns::default())?;
+        Ok(Self {
+            range_index,
+            partition_fields,
+            hive_style_partitioning,
+        })
+    }
+
+    pub fn prune(&self, files: Vec<HudiBaseFile>) -> Result<Vec<HudiBaseFile>> {
+        // All the files of a partition share the same result.
+        let mut partitions: HashMap<String, bool> = HashMap::new();
+        let mut kept = Vec::with_capacity(files.len());
+        for file in files {
+            let keep = match partitions.get(&file.partition_path) {
+                Some(keep) => *keep,
+                None => {
+                    let keep = self.should_keep(&file.partition_path)?;
+                    partitions.insert(file.partition_path.clone(), keep);
+                    keep
+                }
+            };
+            if keep {
+                kept.push(file);
+            }
+        }
+        Ok(kept)
+    }
+
+    fn should_keep(&self, partition_path: &str) -> Result<bool> {
+        let Some(values) = partition_values(
+            partition_path,
+            &self.partition_fields,
+            self.hive_style_partitioning,
+        ) else {
+            return Ok(true);
+        };
+
+        let stats = self
+            .partition_fields
+            .iter()
+            .zip(values)
+            .map(|(field, value)| {
+                let null_count = u64::from(value.is_null());
+                let stats = ColumnStatistics::new(value.clone(), value, null_count, 0, None);
+                (field.column_id(), stats)
+            })
+            .collect();
+        self.range_index.apply(&stats, |_| false)
+    }
+}
+
+/// Parse the partition values from `value1/value2` or `field1=value1/field2=value2`.
+fn partition_values(
+    partition_path: &str,
+    fields: &[TableField],
+    hive_style_partitioning: bool,
+) -> Option<Vec<Scalar>> {
+    let segments = partition_path.split('/').collect::<Vec<_>>();
+    if segments.len() != fields.len() {
+        return None;
+    }
+
+    fields
+        .iter()
+        .zip(segments)
+        .map(|(field, segment)| {
+            let value = if hive_style_partitioning {
+                segment.strip_prefix(field.name())?.strip_prefix('=')?
+            } else {
+                segment
+            };
+            str_to_scalar(value, &field.data_type().into())
+        })
+        .collect()
+}
+
+fn str_to_scalar(value: &str, data_type: &DataType) -> Option<Scalar> {
+    match data_type {
+        DataType::Nullable(_) if value == HUDI_DEFAULT_PARTITION => Some(Scalar::Null),
+        DataType::Nullable(ty) => str_to_scalar(value, ty),
+        DataType::String => Some(Scalar::String(value.to_string())),
+        DataType::Boolean => value.parse::<bool>().ok().map(Scalar::Boolean),
+        DataType::Number(num_ty) => {
+            let num = match num_ty {
+                NumberDataType::UInt8 => NumberScalar::UInt8(value.parse().ok()?),
+                NumberDataType::UInt16 => NumberScalar::UInt16(value.parse().ok()?),
+                NumberDataType::UInt32 => NumberScalar::UInt32(value.parse().ok()?),
+                NumberDataType::UInt64 => NumberScalar::UInt64(value.parse().ok()?),
+                NumberDataType::Int8 => NumberScalar::Int8(value.parse().ok()?),
+                NumberDataType::Int16 => NumberScalar::Int16(value.parse().ok()?),
+                NumberDataType::Int32 => NumberScalar::Int32(value.parse().ok()?),
+                NumberDataType::Int64 => NumberScalar::Int64(value.parse().ok()?),
+                NumberDataType::Float32 => NumberScalar::Float32(OrderedFloat(value.parse().ok()?)),
+                NumberDataType::Float64 => NumberScalar::Float64(OrderedFloat(value.parse().ok()?)),
+            };
+            Some(Scalar::Number(num))
+        }
+        // TODO: support other data types
+        _ => None,
+    }
+}
+
+/// The partition fields in the order of the partition path, none if any of them is not a column.
+pub fn get_partition_fields(
+    schema: &TableSchemaRef,
+    partition_columns: &[String],
+) -> Option<Vec<TableField>> {
+    partition_columns
+        .iter()
+        .map(|name| schema.field_with_name(name).ok().cloned())
+        .collect()
+}
@@ -0,0 +1,323 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use databend_common_catalog::catalog::StorageDescription;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_storage::init_operator;
+use databend_common_storage::read_parquet_schema_async_rs;
+use databend_common_storages_parquet::ParquetFilesPart;
+use databend_common_storages_parquet::ParquetPart;
+use databend_common_storages_parquet::ParquetRSPruner;
+use databend_common_storages_parquet::ParquetRSReaderBuilder;
+use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use log::info;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::partition::get_partition_fields;
+use crate::partition::HudiPartitionPruner;
+use crate::table_source::HudiTableSource;
+use crate::timeline::list_base_files;
+use crate::timeline::HudiBaseFile;
+use crate::timeline::HudiTableConfig;
+use crate::timeline::HudiTimeline;
+
+pub const HUDI_ENGINE: &str = "HUDI";
+
+pub struct HudiTable {
+    info: TableInfo,
+    meta: HudiTableMeta,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct HudiTableMeta {
+    partition_columns: Vec<String>,
+    hive_style_partitioning: bool,
+}
+
+/// A copy-on-write Hudi table, read in place from its base path.
+///
+/// - snapshot: the latest base file of each file group on the completed timeline is read,
+///   the file groups replaced by clustering or insert overwrite are skipped.
+/// - pruning: the partition values are parsed from the partition paths to prune the base files,
+///   and the filter is pushed down to the parquet reader for the row groups and pages.
+/// Hudi keeps the partition columns in the base files, so they are read as the other columns.
+impl HudiTable {
+    #[async_backtrace::framed]
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        let meta_string = info
+            .meta
+            .engine_options
+            .get(OPT_KEY_ENGINE_META)
+            .ok_or_else(|| ErrorCode::Internal("missing engine option OPT_KEY_ENGINE_META"))?;
+        let meta: HudiTableMeta = serde_json::from_str(meta_string).map_err(|e| {
+            ErrorCode::Internal(format!(
+                "fail to deserialize HudiTableMeta({meta_string}): {e:?}"
+            ))
+        })?;
+        Ok(Box::new(Self { info, meta }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: HUDI_ENGINE.to_string(),
+            comment: "HUDI Storage Engine".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    fn get_storage_params(&self) -> Result<&StorageParams> {
+        self.info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Hudi table {} must have storage parameters",
+                self.info.name
+            ))
+        })
+    }
+
+    /// Infer the table schema from the latest base file of the table.
+    #[async_backtrace::framed]
+    pub async fn get_meta(sp: &StorageParams) -> Result<(TableSchema, String)> {
+        let op = init_operator(sp)?;
+        let config = HudiTableConfig::load(&op).await?;
+        let timeline = HudiTimeline::load(&op).await?;
+        let files = timeline.latest_base_files(list_base_files(&op).await?);
+        let latest = files
+            .iter()
+            .max_by(|a, b| a.instant.cmp(&b.instant))
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError(
+                    "Hudi table load failed, no base file is found on the completed timeline",
+                )
+            })?;
+
+        let arrow_schema =
+            read_parquet_schema_async_rs(&op, &latest.path, Some(latest.size)).await?;
+        let schema = TableSchema::try_from(&arrow_schema).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
+        })?;
+
+        let meta = HudiTableMeta {
+            partition_columns: config.partition_fields,
+            hive_style_partitioning: config.hive_style_partitioning,
+        };
+        let meta = serde_json::to_string(&meta).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("fail to serialize HudiTableMeta: {e:?}"))
+        })?;
+        Ok((schema, meta))
+    }
+
+    pub fn do_read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let parts_len = plan.parts.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(parts_len, max_threads);
+
+        let table_schema = self.schema();
+        let arrow_schema = table_schema.as_ref().into();
+        let leaf_fields = Arc::new(table_schema.leaf_fields());
+
+        let mut read_options = ParquetReadOptions::default();
+
+        if !ctx.get_settings().get_enable_parquet_page_index()? {
+            read_options = read_options.with_prune_pages(false);
+        }
+
+        if !ctx.get_settings().get_enable_parquet_rowgroup_pruning()? {
+            read_options = read_options.with_prune_row_groups(false);
+        }
+
+        if !ctx.get_settings().get_enable_parquet_prewhere()? {
+            read_options = read_options.with_do_prewhere(false);
+        }
+
+        let pruner = ParquetRSPruner::try_create(
+            ctx.get_function_context()?,
+            table_schema.clone(),
+            leaf_fields,
+            &plan.push_downs,
+            read_options,
+            vec![],
+        )?;
+
+        let sp = self.get_storage_params()?;
+        let op = init_operator(sp)?;
+        let mut builder =
+            ParquetRSReaderBuilder::create(ctx.clone(), op, table_schema, arrow_schema)?
+                .with_options(read_options)
+                .with_push_downs(plan.push_downs.as_ref())
+                .with_pruner(Some(pruner));
+
+        let parquet_reader = Arc::new(builder.build_full_reader()?);
+
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+        pipeline.add_source(
+            |output| {
+                HudiTableSource::create(
+                    ctx.clone(),
+                    output,
+                    output_schema.clone(),
+                    parquet_reader.clone(),
+                )
+            },
+            max_threads.max(1),
+        )
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let sp = self.get_storage_params()?;
+        let op = init_operator(sp)?;
+        let timeline = HudiTimeline::load(&op).await?;
+        let files = timeline.latest_base_files(list_base_files(&op).await?);
+        let total_files = files.len();
+
+        let files = self.prune_partitions(ctx, push_downs.as_ref(), files)?;
+        info!(
+            "hudi table {} read {} of {} base files after partition pruning",
+            self.info.name,
+            files.len(),
+            total_files
+        );
+
+        let num_records = timeline.num_records(&op, &files).await?;
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let parts = files
+            .into_iter()
+            .map(|file| {
+                read_rows += num_records.get(&file.path).copied().unwrap_or(1) as usize;
+                read_bytes += file.size as usize;
+                Arc::new(Box::new(ParquetPart::ParquetFiles(ParquetFilesPart {
+                    files: vec![(file.path, file.size)],
+                    estimated_uncompressed_size: file.size, // This field is not used here.
+                })) as Box<dyn PartInfo>)
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            PartStatistics::new_estimated(None, read_rows, read_bytes, parts.len(), total_files),
+            Partitions::create(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn prune_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<&PushDownInfo>,
+        files: Vec<HudiBaseFile>,
+    ) -> Result<Vec<HudiBaseFile>> {
+        if self.meta.partition_columns.is_empty() {
+            return Ok(files);
+        }
+        let Some(filter) = push_downs.and_then(|p| p.filters.as_ref()) else {
+            return Ok(files);
+        };
+        let schema = self.schema();
+        let Some(partition_fields) = get_partition_fields(&schema, &self.meta.partition_columns)
+        else {
+            return Ok(files);
+        };
+
+        let pruner = HudiPartitionPruner::try_create(
+            ctx.get_function_context()?,
+            &filter.filter.as_expr(&BUILTIN_FUNCTIONS),
+            schema,
+            partition_fields,
+            self.meta.hive_style_partitioning,
+        )?;
+        pruner.prune(files)
+    }
+}
+
+#[async_trait]
+impl Table for HudiTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn name(&self) -> &str {
+        &self.get_table_info().name
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        self.do_read_data(ctx, plan, pipeline)
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        None
+    }
+
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
+    fn support_prewhere(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,465 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use futures::TryStreamExt;
+use opendal::Metakey;
+use opendal::Operator;
+use serde::Deserialize;
+
+/// The folder of the timeline and the table properties, relative to the table base path.
+const HOODIE_FOLDER: &str = ".hoodie/";
+const HOODIE_PROPERTIES: &str = ".hoodie/hoodie.properties";
+const BASE_FILE_EXTENSION: &str = ".parquet";
+
+const COMMIT_ACTION: &str = "commit";
+const REPLACE_COMMIT_ACTION: &str = "replacecommit";
+const INFLIGHT_ACTION: &str = "inflight";
+const REQUESTED_SUFFIX: &str = ".requested";
+const INFLIGHT_SUFFIX: &str = ".inflight";
+
+/// The table properties kept in `.hoodie/hoodie.properties`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HudiTableConfig {
+    pub partition_fields: Vec<String>,
+    pub hive_style_partitioning: bool,
+}
+
+impl HudiTableConfig {
+    #[async_backtrace::framed]
+    pub async fn load(op: &Operator) -> Result<Self> {
+        let content = op.read(HOODIE_PROPERTIES).await.map_err(|e| {
+            ErrorCode::ReadTableDataError(format!(
+                "Hudi table load failed, cannot read {HOODIE_PROPERTIES}: {e}"
+            ))
+        })?;
+        let properties = parse_properties(&String::from_utf8_lossy(&content.to_vec()));
+
+        let table_type = properties
+            .get("hoodie.table.type")
+            .map(|v| v.to_uppercase())
+            .unwrap_or_else(|| "COPY_ON_WRITE".to_string());
+        if table_type != "COPY_ON_WRITE" {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Hudi table type {table_type} is not supported, only COPY_ON_WRITE tables can be read"
+            )));
+        }
+
+        // The custom key generator declares the fields as `field:SIMPLE` or `field:TIMESTAMP`.
+        let partition_fields = properties
+            .get("hoodie.table.partition.fields")
+            .map(|fields| {
+                fields
+                    .split(',')
+                    .map(|f| f.split(':').next().unwrap_or_default().trim().to_string())
+                    .filter(|f| !f.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let hive_style_partitioning = properties
+            .get("hoodie.datasource.write.hive_style_partitioning")
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+
+        Ok(Self {
+            partition_fields,
+            hive_style_partitioning,
+        })
+    }
+}
+
+/// Parse the java properties file, with `key=value` on each line.
+fn parse_properties(content: &str) -> HashMap<String, String> {
+    let mut properties = HashMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+
+        let mut key = String::new();
+        let mut value = String::new();
+        let mut in_value = false;
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            let c = match c {
+                '\\' => match chars.next() {
+                    Some(escaped) => escaped,
+                    None => break,
+                },
+                '=' | ':' if !in_value => {
+                    in_value = true;
+                    continue;
+                }
+                c => c,
+            };
+            if in_value {
+                value.push(c);
+            } else {
+                key.push(c);
+            }
+        }
+        properties.insert(key.trim().to_string(), value.trim().to_string());
+    }
+    properties
+}
+
+/// A base file of a file slice, named as `<file id>_<write token>_<instant time>.parquet`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HudiBaseFile {
+    pub path: String,
+    pub size: u64,
+    pub partition_path: String,
+    pub file_id: String,
+    pub instant: String,
+}
+
+impl HudiBaseFile {
+    pub fn try_create(path: &str, size: u64) -> Option<Self> {
+        let (partition_path, file_name) = match path.rfind('/') {
+            Some(pos) => (&path[..pos], &path[pos + 1..]),
+            None => ("", path),
+        };
+        let stem = file_name.strip_suffix(BASE_FILE_EXTENSION)?;
+        let mut parts = stem.rsplitn(3, '_');
+        let instant = parts.next()?;
+        let _write_token = parts.next()?;
+        let file_id = parts.next()?;
+        Some(Self {
+            path: path.to_string(),
+            size,
+            partition_path: partition_path.to_string(),
+            file_id: file_id.to_string(),
+            instant: instant.to_string(),
+        })
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct HudiCommitMetadata {
+    #[serde(default)]
+    partition_to_write_stats: Option<HashMap<String, Vec<HudiWriteStat>>>,
+    #[serde(default)]
+    partition_to_replace_file_ids: Option<HashMap<String, Vec<String>>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HudiWriteStat {
+    path: Option<String>,
+    #[serde(default)]
+    num_writes: u64,
+}
+
+/// The completed instants on the timeline of a copy-on-write table.
+///
+/// Only the 0.x layout is supported, where the completed instants are kept as
+/// `.hoodie/<instant time>.<action>` and their metadata is written in json.
+///
+/// The old completed instants are moved to `.hoodie/archived/` by the archival, which are
+/// the ones before the earliest completed instant on the active timeline. The file groups
+/// replaced by the archived replacecommits are expected to be cleaned before the archival.
+pub struct HudiTimeline {
+    // instant time -> file name of the completed instant, in time order.
+    completed: BTreeMap<String, String>,
+    // The requested and inflight instants, which are never archived.
+    pending: HashSet<String>,
+    // (partition path, file id) of the file groups replaced by clustering or insert overwrite.
+    replaced_file_groups: HashSet<(String, String)>,
+}
+
+impl HudiTimeline {
+    #[async_backtrace::framed]
+    pub async fn load(op: &Operator) -> Result<Self> {
+        let mut completed = BTreeMap::new();
+        let mut pending = HashSet::new();
+        let mut replace_commits = vec![];
+        let mut lister = op.lister(HOODIE_FOLDER).await?;
+        while let Some(entry) = lister.try_next().await? {
+            let name = entry.name();
+            // The requested and inflight instants are named as `<instant>.<action>.requested`
+            // and `<instant>.<action>.inflight`, except `<instant>.inflight` of the commits,
+            // a rolled back commit never completes.
+            let Some((instant, action)) = name.split_once('.') else {
+                continue;
+            };
+            if !instant.chars().all(|c| c.is_ascii_digit()) {
+                continue;
+            }
+            match action {
+                COMMIT_ACTION => {}
+                REPLACE_COMMIT_ACTION => replace_commits.push(name.to_string()),
+                INFLIGHT_ACTION => {
+                    pending.insert(instant.to_string());
+                    continue;
+                }
+                _ if action.ends_with(REQUESTED_SUFFIX) || action.ends_with(INFLIGHT_SUFFIX) => {
+                    pending.insert(instant.to_string());
+                    continue;
+                }
+                _ => continue,
+            }
+            completed.insert(instant.to_string(), name.to_string());
+        }
+
+        let mut replaced_file_groups = HashSet::new();
+        for name in replace_commits {
+            let metadata = read_commit_metadata(op, &name).await?;
+            for (partition_path, file_ids) in
+                metadata.partition_to_replace_file_ids.unwrap_or_default()
+            {
+                for file_id in file_ids {
+                    replaced_file_groups.insert((partition_path.clone(), file_id));
+                }
+            }
+        }
+
+        Ok(Self {
+            completed,
+            pending,
+            replaced_file_groups,
+        })
+    }
+
+    /// Returns true if the instant is completed, on the active timeline or archived.
+    fn is_completed(&self, instant: &str) -> bool {
+        if self.completed.contains_key(instant) {
+            return true;
+        }
+        if self.pending.contains(instant) {
+            return false;
+        }
+        self.completed
+            .keys()
+            .next()
+            .is_some_and(|earliest| instant < earliest.as_str())
+    }
+
+    /// Pick the base file of the latest completed file slice in each file group.
+    pub fn latest_base_files(&self, files: Vec<HudiBaseFile>) -> Vec<HudiBaseFile> {
+        let mut latest: BTreeMap<(String, String), HudiBaseFile> = BTreeMap::new();
+        for file in files {
+            if !self.is_completed(&file.instant) {
+                continue;
+            }
+            let file_group = (file.partition_path.clone(), file.file_id.clone());
+            if self.replaced_file_groups.contains(&file_group) {
+                continue;
+            }
+            match latest.get(&file_group) {
+                Some(current) if current.instant >= file.instant => {}
+                _ => {
+                    latest.insert(file_group, file);
+                }
+            }
+        }
+        latest.into_values().collect()
+    }
+
+    /// The number of records written to the base files, taken from the metadata of their commits.
+    #[async_backtrace::framed]
+    pub async fn num_records(
+        &self,
+        op: &Operator,
+        files: &[HudiBaseFile],
+    ) -> Result<HashMap<String, u64>> {
+        let instants = files
+            .iter()
+            .map(|f| f.instant.as_str())
+            .collect::<HashSet<_>>();
+        let mut num_records = HashMap::new();
+        for instant in instants {
+            let Some(name) = self.completed.get(instant) else {
+                continue;
+            };
+            let metadata = read_commit_metadata(op, name).await?;
+            for stats in metadata
+                .partition_to_write_stats
+                .unwrap_or_default()
+                .into_values()
+            {
+                for stat in stats {
+                    if let Some(path) = stat.path {
+                        num_records.insert(path, stat.num_writes);
+                    }
+                }
+            }
+        }
+        Ok(num_records)
+    }
+}
+
+async fn read_commit_metadata(op: &Operator, name: &str) -> Result<HudiCommitMetadata> {
+    let path = format!("{HOODIE_FOLDER}{name}");
+    let content = op.read(&path).await?.to_vec();
+    // An empty replacecommit is written by the insert overwrite of nothing.
+    if content.is_empty() {
+        return Ok(HudiCommitMetadata::default());
+    }
+    serde_json::from_slice(&content).map_err(|e| {
+        ErrorCode::ReadTableDataError(format!(
+            "Hudi table load failed, cannot parse the commit metadata {path}: {e}"
+        ))
+    })
+}
+
+/// List the parquet base files of all the partitions, the timeline folder is skipped.
+#[async_backtrace::framed]
+pub async fn list_base_files(op: &Operator) -> Result<Vec<HudiBaseFile>> {
+    let mut files = vec![];
+    let mut lister = op
+        .lister_with("")
+        .recursive(true)
+        .metakey(Metakey::Mode | Metakey::ContentLength)
+        .await?;
+    while let Some(entry) = lister.try_next().await? {
+        let path = entry.path();
+        if path.starts_with(HOODIE_FOLDER) || !entry.metadata().is_file() {
+            continue;
+        }
+        if let Some(file) = HudiBaseFile::try_create(path, entry.metadata().content_length()) {
+            files.push(file);
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timeline(completed: &[&str], pending: &[&str], replaced: &[(&str, &str)]) -> HudiTimeline {
+        HudiTimeline {
+            completed: completed
+                .iter()
+                .map(|i| (i.to_string(), format!("{i}.{COMMIT_ACTION}")))
+                .collect(),
+            pending: pending.iter().map(|i| i.to_string()).collect(),
+            replaced_file_groups: replaced
+                .iter()
+                .map(|(p, f)| (p.to_string(), f.to_string()))
+                .collect(),
+        }
+    }
+
+    fn base_file(path: &str) -> HudiBaseFile {
+        HudiBaseFile::try_create(path, 0).unwrap()
+    }
+
+    fn paths(mut files: Vec<HudiBaseFile>) -> Vec<String> {
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        files.into_iter().map(|f| f.path).collect()
+    }
+
+    #[test]
+    fn test_parse_properties() {
+        let properties = parse_properties(
+            "#Properties saved on 2024-01-01\n\
+             ! another comment\n\
+             \n\
+             hoodie.table.type=COPY_ON_WRITE\n\
+             hoodie.table.name : t\n\
+             hoodie.table.partition.fields = dt\\=x,region\n\
+             hoodie.table.base.path=s3\\://bucket/t\n",
+        );
+        assert_eq!(properties.len(), 4);
+        assert_eq!(properties["hoodie.table.type"], "COPY_ON_WRITE");
+        assert_eq!(properties["hoodie.table.name"], "t");
+        assert_eq!(properties["hoodie.table.partition.fields"], "dt=x,region");
+        assert_eq!(properties["hoodie.table.base.path"], "s3://bucket/t");
+    }
+
+    #[test]
+    fn test_base_file_try_create() {
+        let file = HudiBaseFile::try_create(
+            "dt=2024-01-01/region=eu/fg1-0_0-10-20_20240101000000000.parquet",
+            42,
+        )
+        .unwrap();
+        assert_eq!(file.size, 42);
+        assert_eq!(file.partition_path, "dt=2024-01-01/region=eu");
+        assert_eq!(file.file_id, "fg1-0");
+        assert_eq!(file.instant, "20240101000000000");
+
+        // The file id may contain '_', the write token and the instant never do.
+        let file = HudiBaseFile::try_create("a_b_0-1-0_20240101000000000.parquet", 1).unwrap();
+        assert_eq!(file.partition_path, "");
+        assert_eq!(file.file_id, "a_b");
+
+        assert!(HudiBaseFile::try_create("fg1_0-1-0_20240101000000000.log", 1).is_none());
+        assert!(HudiBaseFile::try_create("dt=2024-01-01/data.parquet", 1).is_none());
+    }
+
+    #[test]
+    fn test_latest_base_files() {
+        let files = vec![
+            base_file("p/fg1_0-1-0_20240101000000000.parquet"),
+            base_file("p/fg1_0-2-0_20240102000000000.parquet"),
+            base_file("p/fg1_0-3-0_20240103000000000.parquet"),
+            base_file("q/fg1_0-1-0_20240101000000000.parquet"),
+        ];
+        let timeline = timeline(&["20240101000000000", "20240102000000000"], &[], &[]);
+        assert_eq!(paths(timeline.latest_base_files(files)), vec![
+            "p/fg1_0-2-0_20240102000000000.parquet",
+            "q/fg1_0-1-0_20240101000000000.parquet",
+        ]);
+    }
+
+    #[test]
+    fn test_latest_base_files_with_archived_and_pending() {
+        let files = vec![
+            // Written by an archived commit.
+            base_file("fg1_0-1-0_20230101000000000.parquet"),
+            // Written by an inflight commit which started before the archived ones.
+            base_file("fg2_0-1-0_20230102000000000.parquet"),
+            base_file("fg3_0-2-0_20240101000000000.parquet"),
+            // Written by an inflight commit, or a rolled back one.
+            base_file("fg3_0-3-0_20240102000000000.parquet"),
+            base_file("fg4_0-3-0_20240103000000000.parquet"),
+        ];
+        let timeline = timeline(
+            &["20240101000000000"],
+            &["20230102000000000", "20240102000000000"],
+            &[],
+        );
+        assert_eq!(paths(timeline.latest_base_files(files)), vec![
+            "fg1_0-1-0_20230101000000000.parquet",
+            "fg3_0-2-0_20240101000000000.parquet",
+        ]);
+    }
+
+    #[test]
+    fn test_latest_base_files_with_replacecommit() {
+        let files = vec![
+            base_file("p/fg1_0-1-0_20240101000000000.parquet"),
+            base_file("p/fg2_0-1-0_20240101000000000.parquet"),
+            base_file("q/fg1_0-1-0_20240101000000000.parquet"),
+            // Written by the replacecommit, which replaced `p/fg1`.
+            base_file("p/fg3_0-2-0_20240102000000000.parquet"),
+        ];
+        let timeline = timeline(&["20240101000000000", "20240102000000000"], &[], &[(
+            "p", "fg1",
+        )]);
+        assert_eq!(paths(timeline.latest_base_files(files)), vec![
+            "p/fg2_0-1-0_20240101000000000.parquet",
+            "p/fg3_0-2-0_20240102000000000.parquet",
+            "q/fg1_0-1-0_20240101000000000.parquet",
+        ]);
+    }
+}
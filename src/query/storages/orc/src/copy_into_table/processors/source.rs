@@ -24,12 +24,13 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storage::init_stage_operator;
 use databend_storages_common_stage::SingleFilePartition;
-use opendal::Operator;
 use orc_rust::async_arrow_reader::StripeFactory;
 use orc_rust::ArrowReaderBuilder;
 
@@ -41,7 +42,9 @@ use crate::utils::map_orc_error;
 pub struct ORCSourceForCopy {
     table_ctx: Arc<dyn TableContext>,
     scan_progress: Arc<Progress>,
-    op: Operator,
+    // The operator is taken from the pool for each file, which refreshes the credentials
+    // of the stage when the files are read for a long time.
+    stage_info: StageInfo,
     reader: Option<(
         String,
         Box<StripeFactory<OrcChunkReader>>,
@@ -54,13 +57,13 @@ impl ORCSourceForCopy {
     pub fn try_create(
         output: Arc<OutputPort>,
         table_ctx: Arc<dyn TableContext>,
-        op: Operator,
+        stage_info: StageInfo,
     ) -> Result<ProcessorPtr> {
         let scan_progress = table_ctx.get_scan_progress();
 
         AsyncSourcer::create(table_ctx.clone(), output, ORCSourceForCopy {
             table_ctx,
-            op,
+            stage_info,
             scan_progress,
             reader: None,
         })
@@ -76,7 +79,7 @@ impl ORCSourceForCopy {
         let size = file.size;
 
         let file = OrcChunkReader {
-            operator: self.op.clone(),
+            operator: init_stage_operator(&self.stage_info)?,
             size: file.size as u64,
             path: file.path,
         };
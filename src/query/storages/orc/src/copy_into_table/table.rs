@@ -96,9 +96,9 @@ impl OrcTableForCopy {
 
         let max_threads = settings.get_max_threads()? as usize;
         let num_source = max_threads.min(plan.parts.len());
-        let operator = init_stage_operator(&stage_table_info.stage_info)?;
+        let stage_info = stage_table_info.stage_info.clone();
         pipeline.add_source(
-            |output| ORCSourceForCopy::try_create(output, ctx.clone(), operator.clone()),
+            |output| ORCSourceForCopy::try_create(output, ctx.clone(), stage_info.clone()),
             num_source,
         )?;
         pipeline.resize(max_threads, true)?;
@@ -28,11 +28,12 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Evaluator;
 use databend_common_expression::FunctionContext;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use opendal::Operator;
+use databend_common_storage::init_stage_operator;
 
 use crate::parquet_rs::copy_into_table::reader::RowGroupReaderForCopy;
 use crate::parquet_rs::parquet_reader::policy::ReadPolicyImpl;
@@ -59,7 +60,9 @@ pub struct ParquetCopySource {
 
     // Used to read parquet.
     row_group_readers: Arc<HashMap<usize, RowGroupReaderForCopy>>,
-    operator: Operator,
+    // The operator is taken from the pool for each row group, which refreshes the
+    // credentials of the stage when the files are read for a long time.
+    stage_info: StageInfo,
     schema: DataSchemaRef,
     func_ctx: FunctionContext,
 
@@ -72,18 +75,23 @@ impl ParquetCopySource {
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         row_group_readers: Arc<HashMap<usize, RowGroupReaderForCopy>>,
-        operator: Operator,
+        stage_info: StageInfo,
         schema: DataSchemaRef,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
-        let batch_size = ctx.get_settings().get_parquet_max_block_size()? as usize;
+        // The batches are not larger than the blocks of the table copied into, the
+        // smaller batches are compacted to the blocks of the table after read.
+        let batch_size = std::cmp::min(
+            ctx.get_settings().get_parquet_max_block_size()? as usize,
+            ctx.get_read_block_thresholds().max_rows_per_block,
+        );
         let func_ctx = ctx.get_function_context()?;
 
         Ok(ProcessorPtr::create(Box::new(Self {
             output,
             scan_progress,
             ctx,
-            operator,
+            stage_info,
             row_group_readers,
             func_ctx,
             batch_size,
@@ -182,7 +190,7 @@ impl Processor for ParquetCopySource {
                             let reader = builder
                                 .build_reader(
                                     part,
-                                    self.operator.clone(),
+                                    init_stage_operator(&self.stage_info)?,
                                     &ReadSettings::from_ctx(&self.ctx)?,
                                     self.batch_size,
                                 )
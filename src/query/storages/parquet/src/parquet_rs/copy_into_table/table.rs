@@ -172,7 +172,7 @@ impl ParquetTableForCopy {
                     ctx.clone(),
                     output,
                     readers.clone(),
-                    operator.clone(),
+                    stage_table_info.stage_info.clone(),
                     data_schema.clone(),
                 )
             },
@@ -104,6 +104,9 @@ impl ParquetRSFullReader {
         if let Some(pruner) = &self.pruner {
             let (selected_row_groups, omits) =
                 pruner.prune_row_groups(&file_meta, None, partition_values_map.as_ref())?;
+            let (selected_row_groups, omits) = pruner
+                .prune_row_groups_by_bloom_filters(&mut builder, selected_row_groups, omits)
+                .await?;
             all_pruned = omits.iter().all(|x| *x);
             builder = builder.with_row_groups(selected_row_groups.clone());
 
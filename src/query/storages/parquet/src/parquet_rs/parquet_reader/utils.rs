@@ -12,9 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::make_array;
+use arrow_array::Array;
+use arrow_array::ArrayRef;
 use arrow_array::BooleanArray;
+use arrow_array::LargeListArray;
+use arrow_array::ListArray;
 use arrow_array::RecordBatch;
-use arrow_array::StructArray;
+use arrow_buffer::NullBuffer;
+use arrow_schema::DataType as ArrowDataType;
 use databend_common_arrow::arrow::array::Arrow2Arrow;
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_exception::ErrorCode;
@@ -25,6 +34,7 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::TableSchema;
+use databend_common_storage::parquet_rs::parquet_list_element;
 use parquet::arrow::arrow_to_parquet_schema;
 use parquet::arrow::parquet_to_arrow_schema_by_columns;
 use parquet::arrow::ProjectionMask;
@@ -38,21 +48,77 @@ fn traverse_column(
     schema: &arrow_schema::Schema,
 ) -> Result<Column> {
     assert!(!path.is_empty());
-    let mut columns = batch.columns();
-    for idx in path.iter().take(path.len() - 1) {
-        let struct_array = columns
-            .get(*idx)
-            .ok_or_else(|| error_cannot_traverse_path(path, schema))?
-            .as_any()
-            .downcast_ref::<StructArray>()
-            .ok_or_else(|| error_cannot_traverse_path(path, schema))?;
-        columns = struct_array.columns();
-    }
-    let idx = *path.last().unwrap();
-    let array = columns
-        .get(idx)
+    let array = batch
+        .columns()
+        .get(path[0])
+        .and_then(|array| traverse_array(array, &path[1..], false))
         .ok_or_else(|| error_cannot_traverse_path(path, schema))?;
-    Column::from_arrow_rs(array.clone(), field.data_type())
+    Column::from_arrow_rs(array, field.data_type())
+}
+
+/// Traverse the inner fields of `array` by `path`.
+///
+/// Lists are transparent in the path, the inner field of the struct nested in a list
+/// is returned as a list of the field values, and it's null if the struct is null.
+fn traverse_array(array: &ArrayRef, path: &[FieldIndex], in_list: bool) -> Option<ArrayRef> {
+    if path.is_empty() {
+        return Some(array.clone());
+    }
+    match array.data_type() {
+        ArrowDataType::Struct(_) => {
+            let struct_array = array.as_struct_opt()?;
+            let child = traverse_array(struct_array.columns().get(path[0])?, &path[1..], in_list)?;
+            if in_list {
+                union_nulls(child, struct_array.nulls())
+            } else {
+                Some(child)
+            }
+        }
+        ArrowDataType::List(field) => {
+            let list_array = array.as_list_opt::<i32>()?;
+            let values = traverse_array(list_array.values(), path, true)?;
+            let field = field
+                .as_ref()
+                .clone()
+                .with_data_type(values.data_type().clone())
+                .with_nullable(true);
+            let list_array = ListArray::try_new(
+                Arc::new(field),
+                list_array.offsets().clone(),
+                values,
+                list_array.nulls().cloned(),
+            )
+            .ok()?;
+            Some(Arc::new(list_array))
+        }
+        ArrowDataType::LargeList(field) => {
+            let list_array = array.as_list_opt::<i64>()?;
+            let values = traverse_array(list_array.values(), path, true)?;
+            let field = field
+                .as_ref()
+                .clone()
+                .with_data_type(values.data_type().clone())
+                .with_nullable(true);
+            let list_array = LargeListArray::try_new(
+                Arc::new(field),
+                list_array.offsets().clone(),
+                values,
+                list_array.nulls().cloned(),
+            )
+            .ok()?;
+            Some(Arc::new(list_array))
+        }
+        _ => None,
+    }
+}
+
+fn union_nulls(array: ArrayRef, nulls: Option<&NullBuffer>) -> Option<ArrayRef> {
+    if nulls.map_or(true, |nulls| nulls.null_count() == 0) {
+        return Some(array);
+    }
+    let nulls = NullBuffer::union(nulls, array.nulls());
+    let data = array.to_data().into_builder().nulls(nulls).build().ok()?;
+    Some(make_array(data))
 }
 
 fn error_cannot_traverse_path(path: &[FieldIndex], schema: &arrow_schema::Schema) -> ErrorCode {
@@ -134,6 +200,10 @@ pub fn compute_output_field_paths(
         let mut path = Vec::with_capacity(name_path.len());
         let mut ty = parquet_schema;
         for name in name_path {
+            // The list is transparent, look up the inner fields of the nested struct.
+            while let Some(element) = parquet_list_element(ty) {
+                ty = element;
+            }
             match ty {
                 parquet::schema::types::Type::GroupType { fields, .. } => {
                     let idx = fields
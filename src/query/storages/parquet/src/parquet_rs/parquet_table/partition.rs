@@ -29,13 +29,18 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::FileStatus;
+use opendal::Operator;
+use parquet::arrow::arrow_reader::ArrowReaderMetadata;
+use parquet::arrow::arrow_reader::ArrowReaderOptions;
 use parquet::arrow::arrow_reader::RowSelector;
+use parquet::arrow::ParquetRecordBatchStreamBuilder;
 
 use super::table::ParquetRSTable;
 use crate::parquet_part::collect_small_file_parts;
 use crate::parquet_rs::partition::SerdePageLocation;
 use crate::parquet_rs::partition::SerdeRowSelector;
 use crate::parquet_rs::read_parquet_metas_batch;
+use crate::parquet_rs::ParquetFileReader;
 use crate::parquet_rs::ParquetRSRowGroupPart;
 use crate::ParquetPart;
 use crate::ParquetRSPruner;
@@ -146,6 +151,7 @@ impl ParquetRSTable {
         } else {
             prune_metas_in_parallel(
                 ctx,
+                self.operator.clone(),
                 &parquet_metas,
                 large_file_indices,
                 pruner,
@@ -241,14 +247,22 @@ impl ParquetRSTable {
             tasks.push(async move {
                 let metas = read_parquet_metas_batch(
                     file_infos,
-                    op,
+                    op.clone(),
                     expect,
                     leaf_fields,
                     schema_from,
                     max_memory_usage,
                 )
                 .await?;
-                prune_and_generate_partitions(&pruner, metas, columns_to_read, &topk, copy_status)
+                prune_and_generate_partitions(
+                    &pruner,
+                    op,
+                    metas,
+                    columns_to_read,
+                    &topk,
+                    copy_status,
+                )
+                .await
             });
         }
 
@@ -277,6 +291,7 @@ impl ParquetRSTable {
 #[async_backtrace::framed]
 async fn prune_metas_in_parallel(
     ctx: Arc<dyn TableContext>,
+    op: Operator,
     parquet_metas: &[Arc<FullParquetMeta>],
     files: Vec<usize>,
     pruner: Arc<ParquetRSPruner>,
@@ -314,9 +329,11 @@ async fn prune_metas_in_parallel(
         let columns_to_read = columns_to_read.clone();
         let copy_status = copy_status.clone();
         let topk = topk.clone();
+        let op = op.clone();
 
         tasks.push(async move {
-            prune_and_generate_partitions(&pruner, metas, columns_to_read, &topk, copy_status)
+            prune_and_generate_partitions(&pruner, op, metas, columns_to_read, &topk, copy_status)
+                .await
         });
     }
 
@@ -340,8 +357,9 @@ async fn prune_metas_in_parallel(
     Ok((stats, create_partitions(parts, &topk)))
 }
 
-fn prune_and_generate_partitions(
+async fn prune_and_generate_partitions(
     pruner: &ParquetRSPruner,
+    op: Operator,
     parquet_metas: Vec<Arc<FullParquetMeta>>,
     columns_to_read: Vec<usize>,
     topk: &Option<TopK>,
@@ -352,12 +370,25 @@ fn prune_and_generate_partitions(
     for meta in parquet_metas {
         let FullParquetMeta {
             location,
+            size,
             meta,
             row_group_level_stats,
             ..
         } = meta.as_ref();
         part_stats.partitions_total += meta.num_row_groups();
         let (rgs, omits) = pruner.prune_row_groups(meta, row_group_level_stats.as_deref(), None)?;
+        let (rgs, omits) = if pruner.has_bloom_predicates() && !rgs.is_empty() {
+            // The bloom filters are not in the footer, read them from the file.
+            let reader = ParquetFileReader::new(op.reader(location).await?, *size);
+            let arrow_meta = ArrowReaderMetadata::try_new(meta.clone(), ArrowReaderOptions::new())?;
+            let mut builder =
+                ParquetRecordBatchStreamBuilder::new_with_metadata(reader, arrow_meta);
+            pruner
+                .prune_row_groups_by_bloom_filters(&mut builder, rgs, omits)
+                .await?
+        } else {
+            (rgs, omits)
+        };
         let mut row_selections = if omits.iter().all(|x| *x) {
             None
         } else {
@@ -201,6 +201,10 @@ impl Table for ParquetRSTable {
         self.read_options.do_prewhere()
     }
 
+    fn support_nested_projection(&self) -> bool {
+        true
+    }
+
     fn has_exact_total_row_count(&self) -> bool {
         true
     }
@@ -18,6 +18,8 @@ use std::sync::Arc;
 use databend_common_catalog::plan::ParquetReadOptions;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
@@ -28,6 +30,10 @@ use databend_storages_common_pruner::RangePrunerCreator;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use parquet::arrow::arrow_reader::RowSelection;
 use parquet::arrow::arrow_reader::RowSelector;
+use parquet::arrow::async_reader::AsyncFileReader;
+use parquet::arrow::ParquetRecordBatchStreamBuilder;
+use parquet::basic::Type as PhysicalType;
+use parquet::bloom_filter::Sbbf;
 use parquet::file::metadata::ParquetMetaData;
 use parquet::format::PageLocation;
 
@@ -49,6 +55,16 @@ pub struct ParquetRSPruner {
 
     /// Leaf ids of columns contained in filter predicates.
     predicate_columns: Vec<usize>,
+    /// The equality predicates of the filter, whose values are checked against the bloom
+    /// filters of the columns.
+    bloom_predicates: Vec<BloomPredicate>,
+}
+
+#[derive(Clone)]
+struct BloomPredicate {
+    /// Leaf id of the column.
+    column: usize,
+    value: Scalar,
 }
 
 impl ParquetRSPruner {
@@ -64,6 +80,19 @@ impl ParquetRSPruner {
         let filter = push_down.as_ref().and_then(|p| p.filters.as_ref());
 
         let mut predicate_columns = vec![];
+        let mut bloom_predicates = vec![];
+        if let Some(filter) = filter
+            && options.prune_bloom_filters()
+        {
+            let filter_expr = filter.filter.as_expr(&BUILTIN_FUNCTIONS);
+            collect_bloom_predicates(&filter_expr, &leaf_fields, &mut bloom_predicates);
+            // The partition columns are not stored in the files.
+            bloom_predicates.retain(|p| {
+                !partition_columns
+                    .iter()
+                    .any(|c| c.eq_ignore_ascii_case(&leaf_fields[p.column].name))
+            });
+        }
         let range_pruner =
             if filter.is_some() && (options.prune_row_groups() || options.prune_pages()) {
                 let filter_expr = filter.as_ref().unwrap().filter.as_expr(&BUILTIN_FUNCTIONS);
@@ -106,9 +135,57 @@ impl ParquetRSPruner {
             prune_row_groups: options.prune_row_groups(),
             prune_pages: options.prune_pages(),
             predicate_columns,
+            bloom_predicates,
         })
     }
 
+    pub fn has_bloom_predicates(&self) -> bool {
+        !self.bloom_predicates.is_empty()
+    }
+
+    /// Prune row groups of a parquet file by the bloom filters of the columns in the
+    /// equality predicates, which is called after the row groups are pruned by statistics,
+    /// because the bloom filters are read from the file.
+    ///
+    /// Return the selected row groups' indices in the meta and omit filter flags.
+    pub async fn prune_row_groups_by_bloom_filters<T: AsyncFileReader + Send + 'static>(
+        &self,
+        builder: &mut ParquetRecordBatchStreamBuilder<T>,
+        row_groups: Vec<usize>,
+        omits: Vec<bool>,
+    ) -> Result<(Vec<usize>, Vec<bool>)> {
+        if self.bloom_predicates.is_empty() {
+            return Ok((row_groups, omits));
+        }
+
+        let meta = builder.metadata().clone();
+        let mut selection = Vec::with_capacity(row_groups.len());
+        let mut selected_omits = Vec::with_capacity(row_groups.len());
+        'rg: for (rg, omit) in row_groups.into_iter().zip(omits.into_iter()) {
+            // The filter is always true on the row group if it is omitted.
+            if !omit {
+                let rg_meta = meta.row_group(rg);
+                for predicate in self.bloom_predicates.iter() {
+                    if predicate.column >= rg_meta.num_columns() {
+                        continue;
+                    }
+                    let physical_type = rg_meta.column(predicate.column).column_type();
+                    if let Some(sbbf) = builder
+                        .get_row_group_column_bloom_filter(rg, predicate.column)
+                        .await?
+                    {
+                        if !bloom_filter_may_contain(&sbbf, physical_type, &predicate.value) {
+                            continue 'rg;
+                        }
+                    }
+                }
+            }
+            selection.push(rg);
+            selected_omits.push(omit);
+        }
+        Ok((selection, selected_omits))
+    }
+
     /// Prune row groups of a parquet file.
     ///
     /// Return the selected row groups' indices in the meta and omit filter flags.
@@ -246,6 +323,102 @@ impl ParquetRSPruner {
     }
 }
 
+/// Collects the `column = constant` predicates of the conjunctions of the filter.
+fn collect_bloom_predicates(
+    expr: &Expr<String>,
+    leaf_fields: &[TableField],
+    predicates: &mut Vec<BloomPredicate>,
+) {
+    let Expr::FunctionCall { function, args, .. } = expr else {
+        return;
+    };
+    match function.signature.name.as_str() {
+        "and" | "and_filters" => {
+            for arg in args {
+                collect_bloom_predicates(arg, leaf_fields, predicates);
+            }
+        }
+        "eq" if args.len() == 2 => {
+            let (column, value) = match (&args[0], &args[1]) {
+                (column, Expr::Constant { scalar, .. })
+                | (Expr::Constant { scalar, .. }, column) => (column, scalar),
+                _ => return,
+            };
+            let name = match column {
+                Expr::ColumnRef { id, .. } => id,
+                // An integer column cast to compare with the constant, which keeps its value
+                // or fails the query.
+                Expr::Cast {
+                    is_try: false,
+                    expr,
+                    dest_type,
+                    ..
+                } if expr.data_type().remove_nullable().is_integer()
+                    && dest_type.remove_nullable().is_integer() =>
+                {
+                    match expr.as_ref() {
+                        Expr::ColumnRef { id, .. } => id,
+                        _ => return,
+                    }
+                }
+                _ => return,
+            };
+            if let Some(column) = leaf_fields
+                .iter()
+                .position(|f| f.name.eq_ignore_ascii_case(name))
+            {
+                predicates.push(BloomPredicate {
+                    column,
+                    value: value.clone(),
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Checks the value as it is hashed into the bloom filter of a column of the physical type,
+/// the values of the other types are assumed to be contained.
+fn bloom_filter_may_contain(sbbf: &Sbbf, physical_type: PhysicalType, value: &Scalar) -> bool {
+    let integer = match value {
+        Scalar::Number(n) => integer_of_number(n),
+        Scalar::Date(d) => Some(*d as i128),
+        _ => None,
+    };
+    match (physical_type, value) {
+        // The unsigned integers are stored as the signed integers of the same width.
+        (PhysicalType::INT32, _) => match integer {
+            Some(v) if (i32::MIN as i128..=u32::MAX as i128).contains(&v) => {
+                sbbf.check(&(v as i32))
+            }
+            _ => true,
+        },
+        (PhysicalType::INT64, _) => match integer {
+            Some(v) if (i64::MIN as i128..=u64::MAX as i128).contains(&v) => {
+                sbbf.check(&(v as i64))
+            }
+            _ => true,
+        },
+        (PhysicalType::BYTE_ARRAY, Scalar::String(s)) => sbbf.check(&s.as_str()),
+        (PhysicalType::BYTE_ARRAY, Scalar::Binary(b)) => sbbf.check(b),
+        _ => true,
+    }
+}
+
+fn integer_of_number(n: &NumberScalar) -> Option<i128> {
+    match n {
+        NumberScalar::UInt8(v) => Some(*v as i128),
+        NumberScalar::UInt16(v) => Some(*v as i128),
+        NumberScalar::UInt32(v) => Some(*v as i128),
+        NumberScalar::UInt64(v) => Some(*v as i128),
+        NumberScalar::Int8(v) => Some(*v as i128),
+        NumberScalar::Int16(v) => Some(*v as i128),
+        NumberScalar::Int32(v) => Some(*v as i128),
+        NumberScalar::Int64(v) => Some(*v as i128),
+        NumberScalar::Float32(_) | NumberScalar::Float64(_) => None,
+    }
+}
+
 fn compute_pages_num_rows(location: &[PageLocation], num_rows: i64) -> Vec<usize> {
     let mut counts = Vec::with_capacity(location.len());
     location.windows(2).for_each(|x| {
@@ -29,6 +29,18 @@ pub(crate) fn lower_field_name(field: &ArrowField) -> ArrowField {
             let inner = lower_field_name(f);
             field.with_data_type(ArrowDataType::List(Arc::new(inner)))
         }
+        ArrowDataType::LargeList(f) => {
+            let inner = lower_field_name(f);
+            field.with_data_type(ArrowDataType::LargeList(Arc::new(inner)))
+        }
+        ArrowDataType::FixedSizeList(f, size) => {
+            let inner = lower_field_name(f);
+            field.with_data_type(ArrowDataType::FixedSizeList(Arc::new(inner), *size))
+        }
+        ArrowDataType::Map(f, sorted) => {
+            let inner = lower_field_name(f);
+            field.with_data_type(ArrowDataType::Map(Arc::new(inner), *sorted))
+        }
         ArrowDataType::Struct(fields) => {
             let typ = ArrowDataType::Struct(
                 fields
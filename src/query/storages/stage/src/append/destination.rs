@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_storage::multipart_chunk_size;
+use opendal::Operator;
+
+use crate::StageTable;
+
+const UNLOAD_WRITE_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+const UNLOAD_URL_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+const UNLOAD_URL_UPLOAD_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Where the unloaded files are written to.
+#[derive(Clone)]
+pub enum UnloadDestination {
+    Operator(Operator),
+    /// The files are uploaded to the urls in order with `PUT`, the urls are usually
+    /// presigned by the downstream systems, so no stage or credential is needed.
+    /// The hosts of the urls are checked against `unload_url_allow_list` while binding,
+    /// so the redirects, which may lead to the other hosts, are not followed.
+    Urls {
+        urls: Arc<Vec<String>>,
+        next: Arc<AtomicUsize>,
+        client: reqwest::Client,
+    },
+}
+
+impl UnloadDestination {
+    pub fn try_create(stage_info: &StageInfo) -> Result<Self> {
+        match &stage_info.stage_params.storage {
+            // Http storage can't be written by operator.
+            StorageParams::Http(cfg) => Ok(UnloadDestination::Urls {
+                urls: Arc::new(
+                    cfg.paths
+                        .iter()
+                        .map(|path| format!("{}{}", cfg.endpoint_url, path))
+                        .collect(),
+                ),
+                next: Arc::new(AtomicUsize::new(0)),
+                client: reqwest::Client::builder()
+                    .connect_timeout(UNLOAD_URL_CONNECT_TIMEOUT)
+                    .timeout(UNLOAD_URL_UPLOAD_TIMEOUT)
+                    .redirect(reqwest::redirect::Policy::none())
+                    .build()?,
+            }),
+            _ => Ok(UnloadDestination::Operator(StageTable::get_op(stage_info)?)),
+        }
+    }
+
+    /// Write the file and returns its name in the output of unload.
+    #[async_backtrace::framed]
+    pub async fn write(&self, path: &str, data: Vec<u8>) -> Result<String> {
+        match self {
+            UnloadDestination::Operator(op) => {
+                match multipart_chunk_size(op, UNLOAD_WRITE_CHUNK_SIZE) {
+                    // Upload the large files in parts, which are retried separately.
+                    Some(chunk_size) if data.len() > chunk_size => {
+                        let mut writer = op.writer_with(path).chunk(chunk_size).await?;
+                        writer.write(data).await?;
+                        writer.close().await?;
+                    }
+                    _ => op.write(path, data).await?,
+                }
+                Ok(path.to_string())
+            }
+            UnloadDestination::Urls { urls, next, client } => {
+                let Some(url) = urls.get(next.fetch_add(1, Ordering::Relaxed)) else {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "there are more unloaded files than the {} urls, try to set a larger MAX_FILE_SIZE or SINGLE = TRUE",
+                        urls.len()
+                    )));
+                };
+                let response = client.put(url).body(data).send().await?;
+                let status = response.status();
+                // Hide the signature in the query of presigned url.
+                let file_name = url.split('?').next().unwrap_or_default().to_string();
+                if !status.is_success() {
+                    let body = response.text().await.unwrap_or_default();
+                    return Err(ErrorCode::StorageOther(format!(
+                        "failed to upload unloaded file to {}: {} {}",
+                        file_name, status, body
+                    )));
+                }
+                Ok(file_name)
+            }
+        }
+    }
+}
@@ -20,7 +20,9 @@ use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 
+use super::destination::UnloadDestination;
 use super::parquet_file::append_data_to_parquet_files;
+use super::path::UnloadPath;
 use super::row_based_file::append_data_to_row_based_files;
 use crate::append::output::SumSummaryTransform;
 use crate::StageTable;
@@ -37,15 +39,15 @@ impl StageTable {
         let mem_limit = settings.get_max_memory_usage()? as usize;
         let max_threads = settings.get_max_threads()? as usize;
 
-        let op = StageTable::get_op(&self.table_info.stage_info)?;
-        let uuid = uuid::Uuid::new_v4().to_string();
+        let destination = UnloadDestination::try_create(&self.table_info.stage_info)?;
+        let unload_path = UnloadPath::create(ctx.get_created_time());
         let group_id = AtomicUsize::new(0);
         match fmt {
             FileFormatParams::Parquet(_) => append_data_to_parquet_files(
                 pipeline,
                 self.table_info.clone(),
-                op,
-                uuid,
+                destination,
+                unload_path,
                 &group_id,
                 mem_limit,
                 max_threads,
@@ -54,8 +56,8 @@ impl StageTable {
                 pipeline,
                 ctx.clone(),
                 self.table_info.clone(),
-                op,
-                uuid,
+                destination,
+                unload_path,
                 &group_id,
                 mem_limit,
                 max_threads,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod destination;
 mod do_append;
 mod output;
 mod parquet_file;
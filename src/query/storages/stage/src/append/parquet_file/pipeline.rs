@@ -15,10 +15,11 @@
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::Pipeline;
-use opendal::Operator;
 
 use super::limit_file_size_processor::LimitFileSizeProcessor;
 use super::writer_processor::ParquetFileWriter;
+use crate::append::destination::UnloadDestination;
+use crate::append::path::UnloadPath;
 
 /// - LimitFileSizeProcessor * 1: slice/group block to batches (as a block meta) to avoid files being too small when there are many threads.
 /// - ParquetFileSink * N:  serialize incoming blocks to Vec to reduce memory, and flush when they are large enough.
@@ -26,8 +27,8 @@ use super::writer_processor::ParquetFileWriter;
 pub(crate) fn append_data_to_parquet_files(
     pipeline: &mut Pipeline,
     table_info: StageTableInfo,
-    op: Operator,
-    uuid: String,
+    destination: UnloadDestination,
+    unload_path: UnloadPath,
     group_id: &std::sync::atomic::AtomicUsize,
     mem_limit: usize,
     max_threads: usize,
@@ -59,8 +60,8 @@ pub(crate) fn append_data_to_parquet_files(
             input,
             output,
             table_info.clone(),
-            op.clone(),
-            uuid.clone(),
+            destination.clone(),
+            unload_path.clone(),
             gid,
             max_file_size,
         )
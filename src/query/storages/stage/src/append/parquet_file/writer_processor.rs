@@ -31,15 +31,15 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_storages_common_table_meta::table::TableCompression;
-use opendal::Operator;
 use parquet::arrow::ArrowWriter;
 use parquet::basic::Encoding;
 use parquet::file::properties::EnabledStatistics;
 use parquet::file::properties::WriterProperties;
 
 use super::block_batch::BlockBatch;
+use crate::append::destination::UnloadDestination;
 use crate::append::output::DataSummary;
-use crate::append::path::unload_path;
+use crate::append::path::UnloadPath;
 use crate::append::UnloadOutput;
 
 pub struct ParquetFileWriter {
@@ -56,13 +56,13 @@ pub struct ParquetFileWriter {
     writer: ArrowWriter<Vec<u8>>,
 
     file_to_write: Option<(Vec<u8>, DataSummary)>,
-    data_accessor: Operator,
+    destination: UnloadDestination,
 
     // the result of statement
     unload_output: UnloadOutput,
     unload_output_blocks: Option<VecDeque<DataBlock>>,
 
-    uuid: String,
+    unload_path: UnloadPath,
     group_id: usize,
     batch_id: usize,
 
@@ -99,8 +99,8 @@ impl ParquetFileWriter {
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         table_info: StageTableInfo,
-        data_accessor: Operator,
-        uuid: String,
+        destination: UnloadDestination,
+        unload_path: UnloadPath,
         group_id: usize,
         targe_file_size: Option<usize>,
     ) -> Result<ProcessorPtr> {
@@ -121,8 +121,8 @@ impl ParquetFileWriter {
             input_data: Vec::new(),
             input_bytes: 0,
             file_to_write: None,
-            data_accessor,
-            uuid,
+            destination,
+            unload_path,
             group_id,
             batch_id: 0,
             targe_file_size,
@@ -240,16 +240,12 @@ impl Processor for ParquetFileWriter {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         assert!(self.file_to_write.is_some());
-        let path = unload_path(
-            &self.table_info,
-            &self.uuid,
-            self.group_id,
-            self.batch_id,
-            None,
-        );
+        let path = self
+            .unload_path
+            .path(&self.table_info, self.group_id, self.batch_id, None);
         let (data, summary) = mem::take(&mut self.file_to_write).unwrap();
-        self.unload_output.add_file(&path, summary);
-        self.data_accessor.write(&path, data).await?;
+        let file_name = self.destination.write(&path, data).await?;
+        self.unload_output.add_file(&file_name, summary);
         self.batch_id += 1;
         Ok(())
     }
@@ -12,34 +12,68 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_compress::CompressAlgorithm;
 
-pub fn unload_path(
-    stage_table_info: &StageTableInfo,
-    uuid: &str,
-    group_id: usize,
-    batch_id: usize,
-    compression: Option<CompressAlgorithm>,
-) -> String {
-    let format_name = format!(
-        "{:?}",
-        stage_table_info.stage_info.file_format_params.get_type()
-    )
-    .to_ascii_lowercase();
-
-    let suffix: &str = &compression
-        .map(|c| format!(".{}", c.extension()))
-        .unwrap_or_default();
-
-    let path = &stage_table_info.files_info.path;
-
-    if path.ends_with("data_") {
-        format!(
-            "{}{}_{:0>4}_{:0>8}.{}{}",
-            path, uuid, group_id, batch_id, format_name, suffix
+/// Names the files unloaded by all the writers of a `COPY INTO <location>`.
+///
+/// Without `FILE_NAME_TEMPLATE`, the files are named `data_<uuid>_<group>_<batch>`.
+/// Otherwise the placeholders of the template are replaced:
+/// - `{seq}`: the sequence number of the file, shared by the writers so the files are numbered from 0 without gaps.
+/// - `{timestamp}`: the start time of the query, formatted as `%Y%m%d%H%M%S` in UTC.
+/// - `{uuid}`: the same uuid for all the files of the statement.
+#[derive(Clone)]
+pub struct UnloadPath {
+    uuid: String,
+    timestamp: String,
+    sequence: Arc<AtomicUsize>,
+}
+
+impl UnloadPath {
+    pub fn create(query_start: SystemTime) -> Self {
+        UnloadPath {
+            uuid: uuid::Uuid::new_v4().to_string(),
+            timestamp: DateTime::<Utc>::from(query_start)
+                .format("%Y%m%d%H%M%S")
+                .to_string(),
+            sequence: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn path(
+        &self,
+        stage_table_info: &StageTableInfo,
+        group_id: usize,
+        batch_id: usize,
+        compression: Option<CompressAlgorithm>,
+    ) -> String {
+        let format_name = format!(
+            "{:?}",
+            stage_table_info.stage_info.file_format_params.get_type()
         )
-    } else {
+        .to_ascii_lowercase();
+
+        let suffix: &str = &compression
+            .map(|c| format!(".{}", c.extension()))
+            .unwrap_or_default();
+
+        let path = &stage_table_info.files_info.path;
+        let template = &stage_table_info.stage_info.copy_options.file_name_template;
+
+        if template.is_none() && path.ends_with("data_") {
+            return format!(
+                "{}{}_{:0>4}_{:0>8}.{}{}",
+                path, self.uuid, group_id, batch_id, format_name, suffix
+            );
+        }
+
         let (path, sep) = if path == "/" {
             ("", "")
         } else if path.ends_with('/') {
@@ -47,9 +81,21 @@ pub fn unload_path(
         } else {
             (path.as_str(), "/")
         };
-        format!(
-            "{}{}data_{}_{:0>4}_{:0>8}.{}{}",
-            path, sep, uuid, group_id, batch_id, format_name, suffix
-        )
+        match template {
+            None => format!(
+                "{}{}data_{}_{:0>4}_{:0>8}.{}{}",
+                path, sep, self.uuid, group_id, batch_id, format_name, suffix
+            ),
+            Some(template) => {
+                let mut name = template
+                    .replace("{timestamp}", &self.timestamp)
+                    .replace("{uuid}", &self.uuid);
+                if name.contains("{seq}") {
+                    let seq = self.sequence.fetch_add(1, Ordering::Relaxed);
+                    name = name.replace("{seq}", &format!("{:0>6}", seq));
+                }
+                format!("{}{}{}.{}{}", path, sep, name, format_name, suffix)
+            }
+        }
     }
 }
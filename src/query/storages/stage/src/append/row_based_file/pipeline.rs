@@ -21,11 +21,12 @@ use databend_common_formats::FileFormatOptionsExt;
 use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
-use opendal::Operator;
 
 use super::limit_file_size_processor::LimitFileSizeProcessor;
 use super::serialize_processor::SerializeProcessor;
 use super::writer_processor::RowBasedFileWriter;
+use crate::append::destination::UnloadDestination;
+use crate::append::path::UnloadPath;
 use crate::compression::get_compression_alg_copy;
 
 /// SerializeProcessor * N: serialize each data block to many small byte buffers.
@@ -36,8 +37,8 @@ pub(crate) fn append_data_to_row_based_files(
     pipeline: &mut Pipeline,
     ctx: Arc<dyn TableContext>,
     table_info: StageTableInfo,
-    op: Operator,
-    uuid: String,
+    destination: UnloadDestination,
+    unload_path: UnloadPath,
     group_id: &std::sync::atomic::AtomicUsize,
     mem_limit: usize,
     max_threads: usize,
@@ -99,9 +100,9 @@ pub(crate) fn append_data_to_row_based_files(
             input,
             output,
             table_info.clone(),
-            op.clone(),
+            destination.clone(),
             prefix.clone(),
-            uuid.clone(),
+            unload_path.clone(),
             gid,
             compression,
         )
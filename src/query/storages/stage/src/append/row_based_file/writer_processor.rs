@@ -29,11 +29,11 @@ use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use opendal::Operator;
 
 use super::buffers::FileOutputBuffers;
+use crate::append::destination::UnloadDestination;
 use crate::append::output::DataSummary;
-use crate::append::path::unload_path;
+use crate::append::path::UnloadPath;
 use crate::append::UnloadOutput;
 
 pub struct RowBasedFileWriter {
@@ -49,10 +49,10 @@ pub struct RowBasedFileWriter {
     unload_output: UnloadOutput,
     unload_output_blocks: Option<VecDeque<DataBlock>>,
 
-    data_accessor: Operator,
+    destination: UnloadDestination,
     prefix: Vec<u8>,
 
-    uuid: String,
+    unload_path: UnloadPath,
     group_id: usize,
     batch_id: usize,
 
@@ -64,9 +64,9 @@ impl RowBasedFileWriter {
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         table_info: StageTableInfo,
-        data_accessor: Operator,
+        destination: UnloadDestination,
         prefix: Vec<u8>,
-        uuid: String,
+        unload_path: UnloadPath,
         group_id: usize,
         compression: Option<CompressAlgorithm>,
     ) -> Result<ProcessorPtr> {
@@ -76,9 +76,9 @@ impl RowBasedFileWriter {
             table_info,
             input,
             input_data: None,
-            data_accessor,
+            destination,
             prefix,
-            uuid,
+            unload_path,
             group_id,
             batch_id: 0,
             file_to_write: None,
@@ -170,16 +170,15 @@ impl Processor for RowBasedFileWriter {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        let path = unload_path(
+        let path = self.unload_path.path(
             &self.table_info,
-            &self.uuid,
             self.group_id,
             self.batch_id,
             self.compression,
         );
         let (data, summary) = mem::take(&mut self.file_to_write).unwrap();
-        self.unload_output.add_file(&path, summary);
-        self.data_accessor.write(&path, data).await?;
+        let file_name = self.destination.write(&path, data).await?;
+        self.unload_output.add_file(&file_name, summary);
         self.batch_id += 1;
         Ok(())
     }
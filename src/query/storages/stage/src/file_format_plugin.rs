@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::PluginFileFormatParams;
+use databend_common_meta_app::principal::StageFileFormatType;
+use log::info;
+
+use crate::read::row_based::RowBasedFileFormat;
+
+/// A record format that is not built in, which is read by COPY and stage queries through
+/// the row based read pipeline, with its own separator and decoder.
+pub trait FileFormatPlugin: Send + Sync {
+    /// The name of the format, which is the `TYPE` of the `FILE_FORMAT` options.
+    fn name(&self) -> &str;
+
+    /// Validates the options of the format other than `TYPE` and `COMPRESSION`, which
+    /// is called when a file format or a stage is created with the format, and before
+    /// the files are read.
+    fn validate_options(&self, options: &BTreeMap<String, String>) -> Result<()>;
+
+    fn create_row_based_file_format(
+        &self,
+        params: &PluginFileFormatParams,
+    ) -> Result<Arc<dyn RowBasedFileFormat>>;
+}
+
+/// The file format plugins registered by name, e.g. by enterprise builds on startup.
+#[derive(Default)]
+pub struct FileFormatPluginRegistry {
+    plugins: DashMap<String, Arc<dyn FileFormatPlugin>>,
+}
+
+impl FileFormatPluginRegistry {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(FileFormatPluginRegistry::default()));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<FileFormatPluginRegistry> {
+        GlobalInstance::get()
+    }
+
+    pub fn register(&self, plugin: Arc<dyn FileFormatPlugin>) -> Result<()> {
+        let name = plugin.name().to_uppercase();
+        if StageFileFormatType::from_str(&name).is_ok() {
+            return Err(ErrorCode::BadArguments(format!(
+                "file format {name} is built in, can not be registered as a plugin"
+            )));
+        }
+        if self.plugins.contains_key(&name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "file format plugin {name} is already registered"
+            )));
+        }
+
+        info!("Register file format plugin {}", name);
+        self.plugins.insert(name, plugin);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Result<Arc<dyn FileFormatPlugin>> {
+        match self.plugins.get(&name.to_uppercase()) {
+            Some(plugin) => Ok(plugin.value().clone()),
+            None => {
+                let mut registered = self
+                    .plugins
+                    .iter()
+                    .map(|p| p.key().clone())
+                    .collect::<Vec<_>>();
+                registered.sort();
+                Err(ErrorCode::IllegalFileFormat(format!(
+                    "Unknown file format type '{name}', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC ) or of the registered plugins ( {} )",
+                    registered.join(" | ")
+                )))
+            }
+        }
+    }
+
+    /// Checks the params of a format provided by a plugin, the built in formats are
+    /// checked when they are parsed.
+    pub fn check_file_format_params(&self, params: &FileFormatParams) -> Result<()> {
+        if let FileFormatParams::Plugin(params) = params {
+            self.get(&params.format_name)?
+                .validate_options(&params.options)?;
+        }
+        Ok(())
+    }
+}
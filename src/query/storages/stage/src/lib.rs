@@ -20,7 +20,21 @@
 
 mod append;
 mod compression;
+mod file_format_plugin;
 mod read;
 mod stage_table;
 
+pub use file_format_plugin::FileFormatPlugin;
+pub use file_format_plugin::FileFormatPluginRegistry;
+pub use read::row_based::create_row_based_file_format;
+pub use read::row_based::BlockBuilderState;
+pub use read::row_based::BytesBatch;
+pub use read::row_based::CSVRowBatch;
+pub use read::row_based::Position;
+pub use read::row_based::RowBasedFileFormat;
+pub use read::row_based::RowBatch;
+pub use read::row_based::RowBatchWithPosition;
+pub use read::row_based::RowDecoder;
+pub use read::row_based::SeparatorState;
+pub use read::LoadContext;
 pub use stage_table::StageTable;
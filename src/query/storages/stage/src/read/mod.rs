@@ -15,3 +15,7 @@
 mod error_handler;
 mod load_context;
 pub mod row_based;
+mod stage_file_lister;
+
+pub use load_context::LoadContext;
+pub use stage_file_lister::StageFileLister;
@@ -27,6 +27,7 @@ use crate::read::load_context::LoadContext;
 use crate::read::row_based::formats::CsvInputFormat;
 use crate::read::row_based::formats::NdJsonInputFormat;
 use crate::read::row_based::formats::TsvInputFormat;
+use crate::FileFormatPluginRegistry;
 
 pub trait SeparatorState: Send + Sync {
     fn append(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)>;
@@ -53,11 +54,20 @@ pub trait RowBasedFileFormat: Sync + Send {
     fn try_create_decoder(&self, load_ctx: Arc<LoadContext>) -> Result<Arc<dyn RowDecoder>>;
 }
 
-pub fn create_row_based_file_format(params: &FileFormatParams) -> Arc<dyn RowBasedFileFormat> {
+pub fn create_row_based_file_format(
+    params: &FileFormatParams,
+) -> Result<Arc<dyn RowBasedFileFormat>> {
     match params {
-        FileFormatParams::Csv(p) => Arc::new(CsvInputFormat { params: p.clone() }),
-        FileFormatParams::NdJson(p) => Arc::new(NdJsonInputFormat { params: p.clone() }),
-        FileFormatParams::Tsv(p) => Arc::new(TsvInputFormat { params: p.clone() }),
+        FileFormatParams::Csv(p) => Ok(Arc::new(CsvInputFormat { params: p.clone() })),
+        FileFormatParams::NdJson(p) => Ok(Arc::new(NdJsonInputFormat { params: p.clone() })),
+        FileFormatParams::Tsv(p) => Ok(Arc::new(TsvInputFormat { params: p.clone() })),
+        FileFormatParams::Plugin(p) => {
+            let registry = FileFormatPluginRegistry::instance();
+            registry.check_file_format_params(params)?;
+            registry
+                .get(&p.format_name)?
+                .create_row_based_file_format(p)
+        }
         _ => {
             unreachable!("Unsupported row based file format")
         }
@@ -19,4 +19,14 @@ mod processors;
 mod read_pipeline;
 mod utils;
 
+pub use batch::BytesBatch;
+pub use batch::CSVRowBatch;
+pub use batch::Position;
+pub use batch::RowBatch;
+pub use batch::RowBatchWithPosition;
+pub use format::create_row_based_file_format;
+pub use format::RowBasedFileFormat;
+pub use format::RowDecoder;
+pub use format::SeparatorState;
+pub use processors::BlockBuilderState;
 pub use read_pipeline::RowBasedReadPipelineBuilder;
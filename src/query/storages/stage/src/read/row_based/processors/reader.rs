@@ -21,14 +21,16 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_pipeline_sources::PrefetchAsyncSource;
+use databend_common_storage::init_stage_operator;
 use databend_storages_common_stage::SingleFilePartition;
 use futures::AsyncRead;
 use futures::AsyncReadExt;
 use log::debug;
-use opendal::Operator;
 
 use crate::read::row_based::batch::BytesBatch;
+use crate::read::StageFileLister;
 
 struct FileState {
     file: SingleFilePartition,
@@ -38,19 +40,24 @@ struct FileState {
 
 pub struct BytesReader {
     table_ctx: Arc<dyn TableContext>,
-    op: Operator,
+    // The operator is taken from the pool for each file, which refreshes the credentials
+    // of the stage when the files are read for a long time.
+    stage_info: StageInfo,
     read_batch_size: usize,
     io_size: usize,
     file_state: Option<FileState>,
     prefetch_num: usize,
+    // Get the files from the lister instead of the partitions if the stage is listed lazily.
+    lister: Option<Arc<StageFileLister>>,
 }
 
 impl BytesReader {
     pub fn try_create(
         table_ctx: Arc<dyn TableContext>,
-        op: Operator,
+        stage_info: StageInfo,
         read_batch_size: usize,
         prefetch_num: usize,
+        lister: Option<Arc<StageFileLister>>,
     ) -> Result<Self> {
         // TODO: Use 8MiB as default IO size for now, we can extract as a new config.
         let default_io_size = 8 * 1024 * 1024;
@@ -62,11 +69,12 @@ impl BytesReader {
 
         Ok(Self {
             table_ctx,
-            op,
+            stage_info,
             read_batch_size,
             io_size,
             file_state: None,
             prefetch_num,
+            lister,
         })
     }
 
@@ -124,14 +132,18 @@ impl PrefetchAsyncSource for BytesReader {
     #[async_trait::unboxed_simple]
     async fn generate(&mut self) -> Result<Option<DataBlock>> {
         if self.file_state.is_none() {
-            let part = match self.table_ctx.get_partition() {
-                Some(part) => part,
-                None => return Ok(None),
+            let file = match &self.lister {
+                Some(lister) => match lister.next_file().await? {
+                    Some(file) => file,
+                    None => return Ok(None),
+                },
+                None => match self.table_ctx.get_partition() {
+                    Some(part) => SingleFilePartition::from_part(&part)?.clone(),
+                    None => return Ok(None),
+                },
             };
-            let file = SingleFilePartition::from_part(&part)?.clone();
 
-            let reader = self
-                .op
+            let reader = init_stage_operator(&self.stage_info)?
                 .reader_with(&file.path)
                 .chunk(self.io_size)
                 // TODO: Use 4 concurrent for test, let's extract as a new setting.
@@ -27,7 +27,7 @@ use databend_common_pipeline_sources::EmptySource;
 use databend_common_pipeline_sources::PrefetchAsyncSourcer;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_settings::Settings;
-use databend_common_storage::init_stage_operator;
+use databend_storages_common_stage::StageListingPartition;
 
 use crate::compression::get_compression_alg_copy;
 use crate::read::load_context::LoadContext;
@@ -36,6 +36,7 @@ use crate::read::row_based::processors::BlockBuilder;
 use crate::read::row_based::processors::BytesReader;
 use crate::read::row_based::processors::Decompressor;
 use crate::read::row_based::processors::Separator;
+use crate::read::StageFileLister;
 
 pub struct RowBasedReadPipelineBuilder<'a> {
     pub(crate) stage_table_info: &'a StageTableInfo,
@@ -49,12 +50,19 @@ impl RowBasedReadPipelineBuilder<'_> {
         pipeline: &mut Pipeline,
         settings: &Settings,
         num_threads: usize,
+        lister: Option<Arc<StageFileLister>>,
     ) -> Result<()> {
-        let operator = init_stage_operator(&self.stage_table_info.stage_info)?;
+        let stage_info = self.stage_table_info.stage_info.clone();
         let batch_size = settings.get_input_read_buffer_size()? as usize;
         pipeline.add_source(
             |output| {
-                let reader = BytesReader::try_create(ctx.clone(), operator.clone(), batch_size, 1)?;
+                let reader = BytesReader::try_create(
+                    ctx.clone(),
+                    stage_info.clone(),
+                    batch_size,
+                    1,
+                    lister.clone(),
+                )?;
                 PrefetchAsyncSourcer::create(ctx.clone(), output, reader)
             },
             num_threads,
@@ -91,14 +99,26 @@ impl RowBasedReadPipelineBuilder<'_> {
             None
         };
         let settings = ctx.get_settings();
-        ctx.set_partitions(plan.parts.clone())?;
-
         let max_threads = settings.get_max_threads()? as usize;
-        let num_sources = std::cmp::min(max_threads, plan.parts.len());
-        self.build_read_stage_source(ctx.clone(), pipeline, &settings, num_sources)?;
+
+        let is_lazy_listing = plan.parts.partitions.len() == 1
+            && StageListingPartition::is_listing_part(&plan.parts.partitions[0]);
+        let (num_sources, lister) = if is_lazy_listing {
+            let lister = StageFileLister::create(
+                ctx.clone(),
+                self.stage_table_info.clone(),
+                settings.get_stage_listing_page_size()?,
+                max_threads,
+            );
+            (max_threads, Some(lister))
+        } else {
+            ctx.set_partitions(plan.parts.clone())?;
+            (std::cmp::min(max_threads, plan.parts.len()), None)
+        };
+        self.build_read_stage_source(ctx.clone(), pipeline, &settings, num_sources, lister)?;
 
         let format =
-            create_row_based_file_format(&self.stage_table_info.stage_info.file_format_params);
+            create_row_based_file_format(&self.stage_table_info.stage_info.file_format_params)?;
 
         let load_ctx = Arc::new(LoadContext::try_create(
             ctx.clone(),
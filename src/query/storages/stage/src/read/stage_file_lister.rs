@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+use databend_common_base::base::tokio::sync::Mutex;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFileInfo;
+use databend_common_storage::StageFileInfoStream;
+use databend_storages_common_stage::SingleFilePartition;
+use futures::StreamExt;
+use log::info;
+
+struct ListingState {
+    // None before the listing starts.
+    stream: Option<StageFileInfoStream>,
+    page: VecDeque<StageFileInfo>,
+    listed_files: usize,
+    finished: bool,
+}
+
+/// Lists the files of a stage page by page and hands them out to the readers, so
+/// the first files are read while the rest are still being listed, and at most one
+/// page of files is kept in memory.
+pub struct StageFileLister {
+    ctx: Arc<dyn TableContext>,
+    stage_table_info: StageTableInfo,
+    page_size: usize,
+    thread_num: usize,
+    start: Instant,
+    state: Mutex<ListingState>,
+}
+
+impl StageFileLister {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        stage_table_info: StageTableInfo,
+        page_size: usize,
+        thread_num: usize,
+    ) -> Arc<Self> {
+        Arc::new(StageFileLister {
+            ctx,
+            stage_table_info,
+            page_size: page_size.max(1),
+            thread_num,
+            start: Instant::now(),
+            state: Mutex::new(ListingState {
+                stream: None,
+                page: VecDeque::new(),
+                listed_files: 0,
+                finished: false,
+            }),
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn next_file(&self) -> Result<Option<SingleFilePartition>> {
+        let mut state = self.state.lock().await;
+        if state.page.is_empty() && !state.finished {
+            self.next_page(&mut state).await?;
+        }
+        Ok(state.page.pop_front().map(|file| SingleFilePartition {
+            path: file.path,
+            size: file.size as usize,
+        }))
+    }
+
+    async fn next_page(&self, state: &mut ListingState) -> Result<()> {
+        if state.stream.is_none() {
+            self.ctx.set_status_info("begin to list stage files lazily");
+            let operator = init_stage_operator(&self.stage_table_info.stage_info)?;
+            let stream = self
+                .stage_table_info
+                .files_info
+                .list_stream(&operator, self.thread_num, None)
+                .await?;
+            state.stream = Some(stream);
+        }
+        let stream = state.stream.as_mut().unwrap();
+        while state.page.len() < self.page_size {
+            match stream.next().await {
+                Some(file) => {
+                    state.page.push_back(file?);
+                    state.listed_files += 1;
+                }
+                None => {
+                    state.finished = true;
+                    state.stream = None;
+                    break;
+                }
+            }
+        }
+
+        let status = if state.finished {
+            format!(
+                "end list stage files lazily: got {} files, time used {:?}",
+                state.listed_files,
+                self.start.elapsed()
+            )
+        } else {
+            format!(
+                "listing stage files lazily: listed {} files, time used {:?}",
+                state.listed_files,
+                self.start.elapsed()
+            )
+        };
+        info!("{}", status);
+        self.ctx.set_status_info(&status);
+        Ok(())
+    }
+}
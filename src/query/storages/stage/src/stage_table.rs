@@ -37,6 +37,7 @@ use databend_common_storage::StageFileInfo;
 use databend_common_storages_orc::OrcTableForCopy;
 use databend_common_storages_parquet::ParquetTableForCopy;
 use databend_storages_common_stage::SingleFilePartition;
+use databend_storages_common_stage::StageListingPartition;
 use opendal::Operator;
 
 use crate::read::row_based::RowBasedReadPipelineBuilder;
@@ -84,7 +85,29 @@ impl StageTable {
         ctx: Arc<dyn TableContext>,
         stage_table_info: &StageTableInfo,
     ) -> Result<(PartStatistics, Partitions)> {
-        let thread_num = ctx.get_settings().get_max_threads()? as usize;
+        let settings = ctx.get_settings();
+        let thread_num = settings.get_max_threads()? as usize;
+
+        // Select from a stage with a pattern, the files are listed while reading.
+        if stage_table_info.files_to_copy.is_none()
+            && stage_table_info.files_info.files.is_none()
+            && settings.get_enable_lazy_stage_listing()?
+        {
+            let statistics = PartStatistics {
+                snapshot: None,
+                read_rows: 0,
+                read_bytes: 0,
+                partitions_scanned: 1,
+                partitions_total: 1,
+                is_exact: false,
+                pruning_stats: Default::default(),
+            };
+            let part_info: Box<dyn PartInfo> = Box::new(StageListingPartition {});
+            return Ok((
+                statistics,
+                Partitions::create(PartitionsShuffleKind::Seq, vec![Arc::new(part_info)]),
+            ));
+        }
 
         let files = if let Some(files) = &stage_table_info.files_to_copy {
             files.clone()
@@ -154,7 +177,10 @@ impl Table for StageTable {
             FileFormatParams::Orc(_) => {
                 OrcTableForCopy::do_read_partitions(stage_table_info, ctx, _push_downs).await
             }
-            FileFormatParams::Csv(_) | FileFormatParams::NdJson(_) | FileFormatParams::Tsv(_) => {
+            FileFormatParams::Csv(_)
+            | FileFormatParams::NdJson(_)
+            | FileFormatParams::Tsv(_)
+            | FileFormatParams::Plugin(_) => {
                 self.read_partitions_simple(ctx, stage_table_info).await
             }
             _ => unreachable!(
@@ -188,7 +214,10 @@ impl Table for StageTable {
             FileFormatParams::Orc(_) => {
                 OrcTableForCopy::do_read_data(ctx, plan, pipeline, _put_cache)
             }
-            FileFormatParams::Csv(_) | FileFormatParams::NdJson(_) | FileFormatParams::Tsv(_) => {
+            FileFormatParams::Csv(_)
+            | FileFormatParams::NdJson(_)
+            | FileFormatParams::Tsv(_)
+            | FileFormatParams::Plugin(_) => {
                 let compact_threshold = ctx.get_read_block_thresholds();
                 RowBasedReadPipelineBuilder {
                     stage_table_info,
@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::TableContext;
+use log::warn;
+
+use crate::columns_table::dump_tables;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct ColumnBackfillsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ColumnBackfillsTable {
+    const NAME: &'static str = "system.column_backfills";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let mut database_names = vec![];
+        let mut table_names = vec![];
+        let mut column_names = vec![];
+        let mut expressions = vec![];
+        let mut blocks_backfilled = vec![];
+        let mut blocks_total = vec![];
+
+        let database_and_tables = dump_tables(&ctx, push_downs).await?;
+        for (database, tables) in database_and_tables {
+            for table in tables {
+                let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                    continue;
+                };
+                let backfills = match fuse_table.column_backfill_progress(ctx.clone()).await {
+                    Ok(backfills) => backfills,
+                    Err(err) => {
+                        let msg = format!(
+                            "Failed to read column backfills of table {}.{}: {}",
+                            database,
+                            table.name(),
+                            err
+                        );
+                        warn!("{}", msg);
+                        ctx.push_warning(msg);
+                        continue;
+                    }
+                };
+                for backfill in backfills {
+                    // The backfills of the dropped columns are removed once the others are done.
+                    let Some(column) = backfill.column else {
+                        continue;
+                    };
+                    database_names.push(database.clone());
+                    table_names.push(table.name().to_string());
+                    column_names.push(column);
+                    expressions.push(backfill.expr);
+                    blocks_backfilled.push(backfill.blocks_backfilled);
+                    blocks_total.push(backfill.blocks_total);
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(database_names),
+            StringType::from_data(table_names),
+            StringType::from_data(column_names),
+            StringType::from_data(expressions),
+            UInt64Type::from_data(blocks_backfilled),
+            UInt64Type::from_data(blocks_total),
+        ]))
+    }
+}
+
+impl ColumnBackfillsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("column", TableDataType::String),
+            TableField::new("expression", TableDataType::String),
+            TableField::new(
+                "blocks_backfilled",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "blocks_total",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'column_backfills'".to_string(),
+            name: "column_backfills".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemColumnBackfills".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use serde::Serialize;
+
+use crate::query_log_table::datetime_str;
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// One row per (output column, source column) pair of a query, the output table is empty
+/// unless the query writes the columns into a table, e.g. `INSERT INTO ... SELECT`.
+#[derive(Clone, Serialize)]
+pub struct ColumnLineageLogElement {
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+    pub query_id: String,
+    pub output_catalog: String,
+    pub output_database: String,
+    pub output_table: String,
+    pub output_position: u64,
+    pub output_column: String,
+    pub source_catalog: String,
+    pub source_database: String,
+    pub source_table: String,
+    pub source_column: String,
+}
+
+impl SystemLogElement for ColumnLineageLogElement {
+    const TABLE_NAME: &'static str = "column_lineage";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("output_catalog", TableDataType::String),
+            TableField::new("output_database", TableDataType::String),
+            TableField::new("output_table", TableDataType::String),
+            TableField::new(
+                "output_position",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("output_column", TableDataType::String),
+            TableField::new("source_catalog", TableDataType::String),
+            TableField::new("source_database", TableDataType::String),
+            TableField::new("source_table", TableDataType::String),
+            TableField::new("source_column", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.output_catalog.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.output_database.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.output_table.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.output_position)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.output_column.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.source_catalog.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.source_database.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.source_table.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.source_column.clone()).as_ref());
+        Ok(())
+    }
+}
+
+pub type ColumnLineageQueue = SystemLogQueue<ColumnLineageLogElement>;
+pub type ColumnLineageTable = SystemLogTable<ColumnLineageLogElement>;
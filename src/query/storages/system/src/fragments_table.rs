@@ -0,0 +1,158 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// The fragments of the running and recently finished distributed queries on each node of
+/// the cluster, the statistics of a fragment are summed up from the profiles of its plans
+/// on the node.
+pub struct FragmentsTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for FragmentsTable {
+    const NAME: &'static str = "system.fragments";
+
+    const IS_LOCAL: bool = false;
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let fragments = ctx.get_queries_fragments();
+        let queries_profiles = ctx.get_queries_profile();
+        let local_id = ctx.get_cluster().local_id.clone();
+
+        let mut node = Vec::with_capacity(fragments.len());
+        let mut query_id = Vec::with_capacity(fragments.len());
+        let mut fragment_id = Vec::with_capacity(fragments.len());
+        let mut state = Vec::with_capacity(fragments.len());
+        let mut plans = Vec::with_capacity(fragments.len());
+        let mut output_rows = Vec::with_capacity(fragments.len());
+        let mut exchange_rows = Vec::with_capacity(fragments.len());
+        let mut exchange_bytes = Vec::with_capacity(fragments.len());
+        let mut memory_usage = Vec::with_capacity(fragments.len());
+
+        for fragment in fragments {
+            let profiles = queries_profiles
+                .get(&fragment.query_id)
+                .map(|profiles| {
+                    profiles
+                        .iter()
+                        .filter_map(|profile| Some((profile.id?, profile)))
+                        .collect::<HashMap<_, _>>()
+                })
+                .unwrap_or_default();
+
+            let statistic = |name: ProfileStatisticsName| {
+                fragment
+                    .plans
+                    .iter()
+                    .filter_map(|(id, _)| profiles.get(id))
+                    .map(|profile| profile.statistics[name as usize] as u64)
+                    .sum::<u64>()
+            };
+
+            output_rows.push(statistic(ProfileStatisticsName::OutputRows));
+            exchange_rows.push(statistic(ProfileStatisticsName::ExchangeRows));
+            exchange_bytes.push(statistic(ProfileStatisticsName::ExchangeBytes));
+            memory_usage.push(statistic(ProfileStatisticsName::MemoryUsage));
+
+            let fragment_plans = fragment
+                .plans
+                .iter()
+                .map(|(id, name)| serde_json::json!({ "id": id, "name": name }))
+                .collect::<Vec<_>>();
+            plans.push(serde_json::to_vec(&fragment_plans).unwrap());
+
+            node.push(local_id.clone());
+            query_id.push(fragment.query_id);
+            fragment_id.push(fragment.fragment_id as u64);
+            state.push(fragment.state.to_string());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(node),
+            StringType::from_data(query_id),
+            UInt64Type::from_data(fragment_id),
+            StringType::from_data(state),
+            VariantType::from_data(plans),
+            UInt64Type::from_data(output_rows),
+            UInt64Type::from_data(exchange_rows),
+            UInt64Type::from_data(exchange_bytes),
+            UInt64Type::from_data(memory_usage),
+        ]))
+    }
+}
+
+impl FragmentsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("node", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("fragment_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("state", TableDataType::String),
+            TableField::new("plans", TableDataType::Variant),
+            TableField::new("output_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "exchange_rows",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "exchange_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "memory_usage",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'fragments'".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            name: "fragments".to_string(),
+            meta: TableMeta {
+                schema,
+                engine: "SystemFragments".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -28,21 +28,26 @@ mod caches_table;
 mod catalogs_table;
 mod clustering_history_table;
 mod clusters_table;
+mod column_backfills_table;
+mod column_lineage_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
 mod credits_table;
 mod databases_table;
 mod engines_table;
+mod fragments_table;
 mod functions_table;
 mod indexes_table;
 mod locks_table;
 mod log_queue;
+mod maintenance_history_table;
 mod malloc_stats_table;
 mod malloc_stats_totals_table;
 mod metrics_table;
 mod notification_history_table;
 mod notifications_table;
+mod object_dependencies_table;
 mod one_table;
 mod password_policies_table;
 mod processes_table;
@@ -51,14 +56,21 @@ mod query_cache_table;
 mod query_log_table;
 mod roles_table;
 mod settings_table;
+mod share_consumer_usage_table;
+mod share_usage_table;
 mod stages_table;
 mod streams_table;
 mod table;
+mod table_blocks_table;
 mod table_functions_table;
+mod table_segments_table;
 mod tables_table;
+mod tag_references_table;
+mod tags_table;
 mod task_history_table;
 mod tasks_table;
 mod temp_files_table;
+mod usage_table;
 mod user_functions_table;
 mod users_table;
 mod util;
@@ -74,24 +86,35 @@ pub use clustering_history_table::ClusteringHistoryLogElement;
 pub use clustering_history_table::ClusteringHistoryQueue;
 pub use clustering_history_table::ClusteringHistoryTable;
 pub use clusters_table::ClustersTable;
+pub use column_backfills_table::ColumnBackfillsTable;
+pub use column_lineage_table::ColumnLineageLogElement;
+pub use column_lineage_table::ColumnLineageQueue;
+pub use column_lineage_table::ColumnLineageTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
+pub use fragments_table::FragmentsTable;
 pub use functions_table::FunctionsTable;
 pub use indexes_table::IndexesTable;
 pub use locks_table::LocksTable;
 pub use log_queue::SystemLogElement;
 pub use log_queue::SystemLogQueue;
 pub use log_queue::SystemLogTable;
+pub use maintenance_history_table::MaintenanceHistoryLogElement;
+pub use maintenance_history_table::MaintenanceHistoryQueue;
+pub use maintenance_history_table::MaintenanceHistoryTable;
 pub use malloc_stats_table::MallocStatsTable;
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
 pub use metrics_table::MetricsTable;
 pub use notification_history_table::NotificationHistoryTable;
 pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
+pub use object_dependencies_table::collect_object_dependencies;
+pub use object_dependencies_table::ObjectDependenciesTable;
+pub use object_dependencies_table::ObjectDependency;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
 pub use processes_table::ProcessesTable;
@@ -105,22 +128,33 @@ pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
+pub use share_consumer_usage_table::ShareConsumerUsageTable;
+pub use share_usage_table::ShareUsageLogElement;
+pub use share_usage_table::ShareUsageQueue;
+pub use share_usage_table::ShareUsageTable;
 pub use stages_table::StagesTable;
 pub use streams_table::FullStreamsTable;
 pub use streams_table::TerseStreamsTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
+pub use table_blocks_table::TableBlocksTable;
 pub use table_functions_table::TableFunctionsTable;
+pub use table_segments_table::TableSegmentsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
 pub use tables_table::ViewsTableWithHistory;
 pub use tables_table::ViewsTableWithoutHistory;
+pub use tag_references_table::TagReferencesTable;
+pub use tags_table::TagsTable;
 pub use task_history_table::parse_task_runs_to_datablock;
 pub use task_history_table::TaskHistoryTable;
 pub use tasks_table::parse_tasks_to_datablock;
 pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
+pub use usage_table::UsageLogElement;
+pub use usage_table::UsageQueue;
+pub use usage_table::UsageTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
 pub use virtual_columns_table::VirtualColumnsTable;
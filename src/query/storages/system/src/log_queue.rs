@@ -17,6 +17,8 @@ use std::any::TypeId;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
 
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartStatistics;
@@ -50,11 +52,19 @@ pub trait SystemLogElement: Send + Sync + Clone {
     fn schema() -> TableSchemaRef;
 
     fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()>;
+
+    /// The approximate memory size of the event, used by the size-based retention.
+    fn memory_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
 }
 
 pub struct Data<Event: SystemLogElement> {
     index: usize,
     pub event_queue: Vec<Option<Event>>,
+    // The append time and the memory size of each event in the queue.
+    event_stats: Vec<Option<(SystemTime, usize)>>,
+    memory_size: usize,
 }
 
 impl<Event: SystemLogElement> Data<Event> {
@@ -62,8 +72,21 @@ impl<Event: SystemLogElement> Data<Event> {
         Data::<Event> {
             index: 0,
             event_queue: vec![None; size],
+            event_stats: vec![None; size],
+            memory_size: 0,
         }
     }
+
+    pub fn clear(&mut self) {
+        self.index = 0;
+        self.event_queue.iter_mut().for_each(|event| *event = None);
+        self.event_stats.iter_mut().for_each(|stats| *stats = None);
+        self.memory_size = 0;
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.memory_size
+    }
 }
 
 pub struct SystemLogQueue<Event: SystemLogElement> {
@@ -107,9 +130,15 @@ impl<Event: SystemLogElement + 'static> SystemLogQueue<Event> {
     }
 
     pub fn append_data(&self, event: Event) -> Result<()> {
+        let memory_size = event.memory_size();
         let mut write_guard = self.data.write();
         let cur_index = write_guard.index;
+        if let Some((_, replaced_size)) = write_guard.event_stats[cur_index] {
+            write_guard.memory_size -= replaced_size;
+        }
         write_guard.event_queue[cur_index] = Some(event);
+        write_guard.event_stats[cur_index] = Some((SystemTime::now(), memory_size));
+        write_guard.memory_size += memory_size;
         write_guard.index += 1;
 
         if write_guard.index == self.max_rows {
@@ -118,6 +147,39 @@ impl<Event: SystemLogElement + 'static> SystemLogQueue<Event> {
 
         Ok(())
     }
+
+    /// Remove the events appended more than `retention` ago, then the oldest events until the
+    /// memory size of the queue is within `max_bytes`.
+    ///
+    /// Returns the number of the removed events.
+    pub fn purge(&self, retention: Option<Duration>, max_bytes: Option<usize>) -> usize {
+        let now = SystemTime::now();
+        let mut write_guard = self.data.write();
+
+        let mut purged = 0;
+        // The oldest event is at the current index of the ring.
+        for offset in 0..self.max_rows {
+            let index = (write_guard.index + offset) % self.max_rows;
+            let Some((append_time, memory_size)) = write_guard.event_stats[index] else {
+                continue;
+            };
+
+            let expired = retention.is_some_and(|retention| {
+                now.duration_since(append_time).unwrap_or_default() >= retention
+            });
+            let oversize = max_bytes.is_some_and(|max_bytes| write_guard.memory_size > max_bytes);
+            if !expired && !oversize {
+                break;
+            }
+
+            write_guard.event_queue[index] = None;
+            write_guard.event_stats[index] = None;
+            write_guard.memory_size -= memory_size;
+            purged += 1;
+        }
+
+        purged
+    }
 }
 
 pub struct SystemLogTable<Event: SystemLogElement> {
@@ -218,13 +280,7 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
     #[async_backtrace::framed]
     async fn truncate(&self, _ctx: Arc<dyn TableContext>, _pipeline: &mut Pipeline) -> Result<()> {
         let log_queue = SystemLogQueue::<Event>::instance()?;
-        let mut write_guard = log_queue.data.write();
-
-        write_guard.index = 0;
-        for index in 0..write_guard.event_queue.len() {
-            write_guard.event_queue[index] = None;
-        }
-
+        log_queue.data.write().clear();
         Ok(())
     }
 }
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// An action of `OPTIMIZE TABLE` run against a table, `error_message` is empty if the
+/// action succeeded.
+#[derive(Clone)]
+pub struct MaintenanceHistoryLogElement {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub query_id: String,
+    pub database: String,
+    pub table: String,
+    pub action: String,
+    pub error_message: String,
+}
+
+impl SystemLogElement for MaintenanceHistoryLogElement {
+    const TABLE_NAME: &'static str = "maintenance_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("start_time", TableDataType::Timestamp),
+            TableField::new("end_time", TableDataType::Timestamp),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("action", TableDataType::String),
+            TableField::new("error_message", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.start_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.end_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.database.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.table.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.action.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.error_message.clone()).as_ref());
+        Ok(())
+    }
+}
+
+pub type MaintenanceHistoryQueue = SystemLogQueue<MaintenanceHistoryLogElement>;
+pub type MaintenanceHistoryTable = SystemLogTable<MaintenanceHistoryLogElement>;
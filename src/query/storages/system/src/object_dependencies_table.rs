@@ -0,0 +1,249 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::ListIndexesReq;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sql::TableReferenceCollector;
+use databend_common_storages_stream::stream_table::StreamTable;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use log::warn;
+
+use crate::columns_table::dump_tables;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::tasks_table::list_tasks;
+
+/// An object that would break if the object it references is dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectDependency {
+    /// Tasks don't belong to a database.
+    pub referencing_database: Option<String>,
+    pub referencing_name: String,
+    pub referencing_domain: String,
+    pub referenced_database: String,
+    pub referenced_name: String,
+    pub referenced_domain: String,
+}
+
+fn object_domain(table: &dyn Table) -> &'static str {
+    match table.engine() {
+        VIEW_ENGINE => "VIEW",
+        STREAM_ENGINE => "STREAM",
+        _ => "TABLE",
+    }
+}
+
+/// Collects the dependencies on the given tables of the default catalog: the views built on
+/// them, the streams and aggregating indexes created on them, and the tasks querying them.
+pub async fn collect_object_dependencies(
+    ctx: &Arc<dyn TableContext>,
+    database_and_tables: &[(String, Vec<Arc<dyn Table>>)],
+) -> Result<Vec<ObjectDependency>> {
+    let mut objects = HashMap::new();
+    let mut ids = HashMap::new();
+    for (database, tables) in database_and_tables {
+        for table in tables {
+            let name = (database.clone(), table.name().to_string());
+            objects.insert(name.clone(), object_domain(table.as_ref()));
+            ids.insert(table.get_id(), name);
+        }
+    }
+
+    let mut dependencies = vec![];
+    let mut add_dependency = |referencing: (Option<String>, String, &str),
+                              referenced: &(String, String)| {
+        if let Some(domain) = objects.get(referenced) {
+            dependencies.push(ObjectDependency {
+                referencing_database: referencing.0,
+                referencing_name: referencing.1,
+                referencing_domain: referencing.2.to_string(),
+                referenced_database: referenced.0.clone(),
+                referenced_name: referenced.1.clone(),
+                referenced_domain: domain.to_string(),
+            });
+        }
+    };
+
+    let dialect = ctx.get_settings().get_sql_dialect()?;
+    for (database, tables) in database_and_tables {
+        for table in tables {
+            match table.engine() {
+                VIEW_ENGINE => {
+                    let Some(query) = table.options().get(QUERY) else {
+                        continue;
+                    };
+                    let references =
+                        match TableReferenceCollector::collect(database.clone(), query, dialect) {
+                            Ok(references) => references,
+                            Err(e) => {
+                                warn!("failed to parse query of view {}: {}", table.name(), e);
+                                continue;
+                            }
+                        };
+                    for referenced in &references {
+                        add_dependency(
+                            (Some(database.clone()), table.name().to_string(), "VIEW"),
+                            referenced,
+                        );
+                    }
+                }
+                STREAM_ENGINE => {
+                    let source_table_id = StreamTable::try_from_table(table.as_ref())
+                        .and_then(|stream| stream.source_table_id());
+                    if let Some(referenced) = source_table_id.ok().and_then(|id| ids.get(&id)) {
+                        add_dependency(
+                            (Some(database.clone()), table.name().to_string(), "STREAM"),
+                            referenced,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+    let indexes = catalog
+        .list_indexes(ListIndexesReq::new(&tenant, None))
+        .await?;
+    for (_, name, index) in indexes {
+        if let Some(referenced) = ids.get(&index.table_id) {
+            add_dependency(
+                (Some(referenced.0.clone()), name, "AGGREGATING INDEX"),
+                referenced,
+            );
+        }
+    }
+
+    // Tasks are kept in the cloud control, the tables they query are resolved in the database
+    // of their session parameters.
+    let config = GlobalConfig::instance();
+    if config.query.cloud_control_grpc_server_address.is_some() {
+        for task in list_tasks(ctx).await? {
+            let database = task
+                .session_parameters
+                .get("database")
+                .cloned()
+                .unwrap_or_else(|| "default".to_string());
+            let Ok(references) =
+                TableReferenceCollector::collect(database, &task.query_text, dialect)
+            else {
+                continue;
+            };
+            for referenced in &references {
+                add_dependency((None, task.task_name.clone(), "TASK"), referenced);
+            }
+        }
+    }
+
+    Ok(dependencies)
+}
+
+/// The dependencies between the objects, one row for each object referenced by another object.
+pub struct ObjectDependenciesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ObjectDependenciesTable {
+    const NAME: &'static str = "system.object_dependencies";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let database_and_tables = dump_tables(&ctx, None).await?;
+        let dependencies = collect_object_dependencies(&ctx, &database_and_tables).await?;
+
+        let mut referencing_databases = Vec::with_capacity(dependencies.len());
+        let mut referencing_names = Vec::with_capacity(dependencies.len());
+        let mut referencing_domains = Vec::with_capacity(dependencies.len());
+        let mut referenced_databases = Vec::with_capacity(dependencies.len());
+        let mut referenced_names = Vec::with_capacity(dependencies.len());
+        let mut referenced_domains = Vec::with_capacity(dependencies.len());
+        for dependency in dependencies {
+            referencing_databases.push(dependency.referencing_database);
+            referencing_names.push(dependency.referencing_name);
+            referencing_domains.push(dependency.referencing_domain);
+            referenced_databases.push(dependency.referenced_database);
+            referenced_names.push(dependency.referenced_name);
+            referenced_domains.push(dependency.referenced_domain);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_opt_data(referencing_databases),
+            StringType::from_data(referencing_names),
+            StringType::from_data(referencing_domains),
+            StringType::from_data(referenced_databases),
+            StringType::from_data(referenced_names),
+            StringType::from_data(referenced_domains),
+        ]))
+    }
+}
+
+impl ObjectDependenciesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new(
+                "referencing_database",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("referencing_name", TableDataType::String),
+            TableField::new("referencing_domain", TableDataType::String),
+            TableField::new("referenced_database", TableDataType::String),
+            TableField::new("referenced_name", TableDataType::String),
+            TableField::new("referenced_domain", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'object_dependencies'".to_string(),
+            name: "object_dependencies".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemObjectDependencies".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
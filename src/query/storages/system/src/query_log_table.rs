@@ -73,7 +73,7 @@ where S: Serializer {
     s.serialize_str(t.format("%Y-%m-%d").to_string().as_str())
 }
 
-fn datetime_str<S>(dt: &i64, s: S) -> Result<S::Ok, S::Error>
+pub(crate) fn datetime_str<S>(dt: &i64, s: S) -> Result<S::Ok, S::Error>
 where S: Serializer {
     let t = DateTime::from_timestamp(
         dt / 1_000_000,
@@ -155,6 +155,8 @@ pub struct QueryLogElement {
     pub client_info: String,
     pub client_address: String,
     pub user_agent: String,
+    pub query_tag: String,
+    pub application_name: String,
 
     // Exception.
     pub exception_code: i32,
@@ -167,6 +169,7 @@ pub struct QueryLogElement {
     // Session settings
     #[serde(skip_serializing)]
     pub session_settings: String,
+    pub effective_settings: String,
 
     // Extra.
     pub extra: String,
@@ -302,6 +305,8 @@ impl SystemLogElement for QueryLogElement {
             TableField::new("client_info", TableDataType::String),
             TableField::new("client_address", TableDataType::String),
             TableField::new("user_agent", TableDataType::String),
+            TableField::new("query_tag", TableDataType::String),
+            TableField::new("application_name", TableDataType::String),
             // Exception.
             TableField::new(
                 "exception_code",
@@ -313,6 +318,7 @@ impl SystemLogElement for QueryLogElement {
             TableField::new("server_version", TableDataType::String),
             // Session settings
             TableField::new("session_settings", TableDataType::String),
+            TableField::new("effective_settings", TableDataType::String),
             // Extra.
             TableField::new("extra", TableDataType::String),
             TableField::new("has_profile", TableDataType::Boolean),
@@ -530,6 +536,14 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::String(self.user_agent.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_tag.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.application_name.clone()).as_ref());
         // Exception.
         columns
             .next()
@@ -553,6 +567,10 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::String(self.session_settings.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.effective_settings.clone()).as_ref());
         // Extra.
         columns
             .next()
@@ -564,6 +582,17 @@ impl SystemLogElement for QueryLogElement {
             .push(Scalar::Boolean(self.has_profiles).as_ref());
         Ok(())
     }
+
+    fn memory_size(&self) -> usize {
+        // Only count the fields that may be large.
+        std::mem::size_of::<Self>()
+            + self.query_text.len()
+            + self.exception_text.len()
+            + self.stack_trace.len()
+            + self.session_settings.len()
+            + self.effective_settings.len()
+            + self.extra.len()
+    }
 }
 
 pub type QueryLogQueue = SystemLogQueue<QueryLogElement>;
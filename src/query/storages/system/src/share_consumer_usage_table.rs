@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sharing::ShareUsageMeter;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The reads of the shares provided by the current tenant, added up by the consumer tenants,
+/// the consumer side of them is in `system.share_usage`.
+pub struct ShareConsumerUsageTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ShareConsumerUsageTable {
+    const NAME: &'static str = "system.share_consumer_usage";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let mut usages = ShareUsageMeter::provider_usages(tenant.tenant_name()).await?;
+        usages.sort_by(|a, b| {
+            (&a.share_name, &a.consumer_tenant, a.table_id).cmp(&(
+                &b.share_name,
+                &b.consumer_tenant,
+                b.table_id,
+            ))
+        });
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(
+                usages
+                    .iter()
+                    .map(|u| u.share_name.clone())
+                    .collect::<Vec<_>>(),
+            ),
+            StringType::from_data(
+                usages
+                    .iter()
+                    .map(|u| u.consumer_tenant.clone())
+                    .collect::<Vec<_>>(),
+            ),
+            UInt64Type::from_data(usages.iter().map(|u| u.table_id).collect::<Vec<_>>()),
+            UInt64Type::from_data(usages.iter().map(|u| u.read_requests).collect::<Vec<_>>()),
+            UInt64Type::from_data(usages.iter().map(|u| u.read_bytes).collect::<Vec<_>>()),
+        ]))
+    }
+}
+
+impl ShareConsumerUsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("share_name", TableDataType::String),
+            TableField::new("consumer_tenant", TableDataType::String),
+            TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "read_requests",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("read_bytes", TableDataType::Number(NumberDataType::UInt64)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'share_consumer_usage'".to_string(),
+            name: "share_consumer_usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemShareConsumerUsage".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(ShareConsumerUsageTable { table_info })
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// Reads of one shared table by one consumer node, aggregated over a flush window.
+#[derive(Clone)]
+pub struct ShareUsageLogElement {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub tenant_id: String,
+    pub node_id: String,
+    pub share_tenant: String,
+    pub share_name: String,
+    pub table_id: u64,
+    pub read_requests: u64,
+    pub read_bytes: u64,
+}
+
+impl SystemLogElement for ShareUsageLogElement {
+    const TABLE_NAME: &'static str = "share_usage";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("start_time", TableDataType::Timestamp),
+            TableField::new("end_time", TableDataType::Timestamp),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("share_tenant", TableDataType::String),
+            TableField::new("share_name", TableDataType::String),
+            TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "read_requests",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("read_bytes", TableDataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.start_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.end_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.tenant_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.node_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.share_tenant.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.share_name.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.table_id)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.read_requests)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.read_bytes)).as_ref());
+        Ok(())
+    }
+}
+
+pub type ShareUsageQueue = SystemLogQueue<ShareUsageLogElement>;
+pub type ShareUsageTable = SystemLogTable<ShareUsageLogElement>;
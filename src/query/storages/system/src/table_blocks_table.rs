@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::TableContext;
+use log::warn;
+
+use crate::columns_table::dump_tables;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TableBlocksTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TableBlocksTable {
+    const NAME: &'static str = "system.table_blocks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let mut database_names = vec![];
+        let mut table_names = vec![];
+        let mut segment_locations = vec![];
+        let mut block_locations = vec![];
+        let mut row_counts = vec![];
+        let mut block_sizes = vec![];
+        let mut file_sizes = vec![];
+        let mut cluster_key_mins = vec![];
+        let mut cluster_key_maxs = vec![];
+        let mut created_ons = vec![];
+        let mut tiers = vec![];
+
+        let database_and_tables = dump_tables(&ctx, push_downs).await?;
+        for (database, tables) in database_and_tables {
+            for table in tables {
+                let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                    continue;
+                };
+                let blocks = match fuse_table.block_statistics(ctx.clone()).await {
+                    Ok(blocks) => blocks,
+                    Err(err) => {
+                        let msg = format!(
+                            "Failed to read blocks of table {}.{}: {}",
+                            database,
+                            table.name(),
+                            err
+                        );
+                        warn!("{}", msg);
+                        ctx.push_warning(msg);
+                        continue;
+                    }
+                };
+                for block in blocks {
+                    database_names.push(database.clone());
+                    table_names.push(table.name().to_string());
+                    segment_locations.push(block.segment_location);
+                    block_locations.push(block.block_location);
+                    row_counts.push(block.row_count);
+                    block_sizes.push(block.block_size);
+                    file_sizes.push(block.file_size);
+                    cluster_key_mins.push(block.cluster_key_min);
+                    cluster_key_maxs.push(block.cluster_key_max);
+                    created_ons.push(block.created_on.map(|t| t.timestamp_micros()));
+                    tiers.push(block.tier.to_string());
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(database_names),
+            StringType::from_data(table_names),
+            StringType::from_data(segment_locations),
+            StringType::from_data(block_locations),
+            UInt64Type::from_data(row_counts),
+            UInt64Type::from_data(block_sizes),
+            UInt64Type::from_data(file_sizes),
+            StringType::from_opt_data(cluster_key_mins),
+            StringType::from_opt_data(cluster_key_maxs),
+            TimestampType::from_opt_data(created_ons),
+            StringType::from_data(tiers),
+        ]))
+    }
+}
+
+impl TableBlocksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("segment_location", TableDataType::String),
+            TableField::new("block_location", TableDataType::String),
+            TableField::new("row_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("block_size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("file_size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "cluster_key_min",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "cluster_key_max",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "created_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new("tier", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'table_blocks'".to_string(),
+            name: "table_blocks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTableBlocks".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
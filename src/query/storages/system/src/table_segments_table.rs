@@ -0,0 +1,198 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::TableContext;
+use log::warn;
+
+use crate::columns_table::dump_tables;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::util::find_eq_filter;
+
+pub struct TableSegmentsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TableSegmentsTable {
+    const NAME: &'static str = "system.table_segments";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let mut database_names = vec![];
+        let mut table_names = vec![];
+        let mut segment_locations = vec![];
+        let mut block_counts = vec![];
+        let mut row_counts = vec![];
+        let mut bytes_uncompressed = vec![];
+        let mut bytes_compressed = vec![];
+        let mut cluster_key_mins = vec![];
+        let mut cluster_key_maxs = vec![];
+        let mut created_snapshot_ids = vec![];
+        let mut tiers = vec![];
+
+        // Tracing the snapshots creating the segments reads the history of each table, so
+        // it's done only if the tables are filtered by their names.
+        let mut filtered_by_table = false;
+        if let Some(filter) = push_downs
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS))
+        {
+            find_eq_filter(&filter, &mut |col_name, _| {
+                filtered_by_table |= col_name == "table";
+            });
+        }
+
+        let database_and_tables = dump_tables(&ctx, push_downs).await?;
+        for (database, tables) in database_and_tables {
+            for table in tables {
+                let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                    continue;
+                };
+                let segments = match fuse_table
+                    .segment_statistics(ctx.clone(), filtered_by_table)
+                    .await
+                {
+                    Ok(segments) => segments,
+                    Err(err) => {
+                        let msg = format!(
+                            "Failed to read segments of table {}.{}: {}",
+                            database,
+                            table.name(),
+                            err
+                        );
+                        warn!("{}", msg);
+                        ctx.push_warning(msg);
+                        continue;
+                    }
+                };
+                for segment in segments {
+                    database_names.push(database.clone());
+                    table_names.push(table.name().to_string());
+                    segment_locations.push(segment.location);
+                    block_counts.push(segment.block_count);
+                    row_counts.push(segment.row_count);
+                    bytes_uncompressed.push(segment.bytes_uncompressed);
+                    bytes_compressed.push(segment.bytes_compressed);
+                    cluster_key_mins.push(segment.cluster_key_min);
+                    cluster_key_maxs.push(segment.cluster_key_max);
+                    created_snapshot_ids.push(segment.created_snapshot_id);
+                    tiers.push(segment.tier.to_string());
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(database_names),
+            StringType::from_data(table_names),
+            StringType::from_data(segment_locations),
+            UInt64Type::from_data(block_counts),
+            UInt64Type::from_data(row_counts),
+            UInt64Type::from_data(bytes_uncompressed),
+            UInt64Type::from_data(bytes_compressed),
+            StringType::from_opt_data(cluster_key_mins),
+            StringType::from_opt_data(cluster_key_maxs),
+            StringType::from_opt_data(created_snapshot_ids),
+            StringType::from_data(tiers),
+        ]))
+    }
+}
+
+impl TableSegmentsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("segment_location", TableDataType::String),
+            TableField::new("block_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("row_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "bytes_uncompressed",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "bytes_compressed",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "cluster_key_min",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "cluster_key_max",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "created_snapshot_id",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("tier", TableDataType::String),
+        ]);
+
+        // Tracing the snapshots creating the segments reads the history of each table, so
+        // `created_snapshot_id` is NULL unless the tables are filtered by their names.
+        let field_comments = schema
+            .fields()
+            .iter()
+            .map(|field| match field.name().as_str() {
+                "created_snapshot_id" => {
+                    "The snapshot creating the segment, NULL unless filtered by table".to_string()
+                }
+                _ => String::new(),
+            })
+            .collect();
+
+        let table_info = TableInfo {
+            desc: "'system'.'table_segments'".to_string(),
+            name: "table_segments".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTableSegments".to_string(),
+                field_comments,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
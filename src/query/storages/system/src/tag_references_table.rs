@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+
+use crate::columns_table::dump_tables;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The tags attached to the tables and columns, one row for each attached tag.
+pub struct TagReferencesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TagReferencesTable {
+    const NAME: &'static str = "system.tag_references";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let mut tag_names = vec![];
+        let mut tag_values = vec![];
+        let mut databases = vec![];
+        let mut tables = vec![];
+        let mut columns = vec![];
+
+        let database_and_tables = dump_tables(&ctx, push_downs).await?;
+        for (database, database_tables) in database_and_tables {
+            for table in database_tables {
+                let meta = &table.get_table_info().meta;
+                for (tag_name, tag_value) in &meta.tags {
+                    tag_names.push(tag_name.clone());
+                    tag_values.push(tag_value.clone());
+                    databases.push(database.clone());
+                    tables.push(table.name().to_string());
+                    columns.push(None);
+                }
+                for (column, tags) in &meta.column_tags {
+                    for (tag_name, tag_value) in tags {
+                        tag_names.push(tag_name.clone());
+                        tag_values.push(tag_value.clone());
+                        databases.push(database.clone());
+                        tables.push(table.name().to_string());
+                        columns.push(Some(column.clone()));
+                    }
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(tag_names),
+            StringType::from_data(tag_values),
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_opt_data(columns),
+        ]))
+    }
+}
+
+impl TagReferencesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("tag_name", TableDataType::String),
+            TableField::new("tag_value", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new(
+                "column",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tag_references'".to_string(),
+            name: "tag_references".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTagReferences".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(TagReferencesTable { table_info })
+    }
+}
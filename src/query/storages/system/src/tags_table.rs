@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TagsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TagsTable {
+    const NAME: &'static str = "system.tags";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let tags = UserApiProvider::instance().get_tags(&tenant).await?;
+
+        let mut names = Vec::with_capacity(tags.len());
+        let mut comments = Vec::with_capacity(tags.len());
+        let mut masking_policies = Vec::with_capacity(tags.len());
+        let mut created_on = Vec::with_capacity(tags.len());
+        let mut updated_on = Vec::with_capacity(tags.len());
+        for tag in tags {
+            names.push(tag.name);
+            comments.push(tag.comment);
+            masking_policies.push(tag.masking_policy);
+            created_on.push(tag.create_on.timestamp_micros());
+            updated_on.push(tag.update_on.map(|t| t.timestamp_micros()));
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(comments),
+            StringType::from_opt_data(masking_policies),
+            TimestampType::from_data(created_on),
+            TimestampType::from_opt_data(updated_on),
+        ]))
+    }
+}
+
+impl TagsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new(
+                "masking_policy",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new(
+                "updated_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tags'".to_string(),
+            name: "tags".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTags".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(TagsTable { table_info })
+    }
+}
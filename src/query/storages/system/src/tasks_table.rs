@@ -127,38 +127,42 @@ impl AsyncSystemTable for TasksTable {
             ));
         }
 
-        let tenant = ctx.get_tenant();
-        let query_id = ctx.get_id();
-        let user = ctx.get_current_user()?.identity().display().to_string();
-        let available_roles = ctx.get_available_roles().await?;
-        let req = ShowTasksRequest {
-            tenant_id: tenant.tenant_name().to_string(),
-            name_like: "".to_string(),
-            result_limit: 10000, // TODO: use plan.limit pushdown
-            owners: available_roles
-                .into_iter()
-                .map(|x| x.identity().to_string())
-                .collect(),
-            task_ids: vec![],
-        };
-
-        let cloud_api = CloudControlApiProvider::instance();
-        let task_client = cloud_api.get_task_client();
-        let cfg = build_client_config(
-            tenant.tenant_name().to_string(),
-            user,
-            query_id,
-            cloud_api.get_timeout(),
-        );
-        let req = make_request(req, cfg);
-
-        let resp = task_client.show_tasks(req).await?;
-        let tasks = resp.tasks;
-
+        let tasks = list_tasks(&ctx).await?;
         parse_tasks_to_datablock(tasks)
     }
 }
 
+/// Lists the tasks owned by the available roles of the current user.
+pub(crate) async fn list_tasks(ctx: &Arc<dyn TableContext>) -> Result<Vec<Task>> {
+    let tenant = ctx.get_tenant();
+    let query_id = ctx.get_id();
+    let user = ctx.get_current_user()?.identity().display().to_string();
+    let available_roles = ctx.get_available_roles().await?;
+    let req = ShowTasksRequest {
+        tenant_id: tenant.tenant_name().to_string(),
+        name_like: "".to_string(),
+        result_limit: 10000, // TODO: use plan.limit pushdown
+        owners: available_roles
+            .into_iter()
+            .map(|x| x.identity().to_string())
+            .collect(),
+        task_ids: vec![],
+    };
+
+    let cloud_api = CloudControlApiProvider::instance();
+    let task_client = cloud_api.get_task_client();
+    let cfg = build_client_config(
+        tenant.tenant_name().to_string(),
+        user,
+        query_id,
+        cloud_api.get_timeout(),
+    );
+    let req = make_request(req, cfg);
+
+    let resp = task_client.show_tasks(req).await?;
+    Ok(resp.tasks)
+}
+
 impl TasksTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let schema = infer_table_schema(&task_schema()).expect("failed to parse task table schema");
@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::number::F64;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// Resource usage of one user on one warehouse, aggregated over a flush window.
+#[derive(Clone)]
+pub struct UsageLogElement {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub node_id: String,
+    pub sql_user: String,
+    pub query_count: u64,
+    pub scan_bytes: u64,
+    pub written_bytes: u64,
+    pub cpu_seconds: f64,
+    pub spilled_bytes: u64,
+}
+
+impl SystemLogElement for UsageLogElement {
+    const TABLE_NAME: &'static str = "usage";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("start_time", TableDataType::Timestamp),
+            TableField::new("end_time", TableDataType::Timestamp),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("cluster_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("sql_user", TableDataType::String),
+            TableField::new("query_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("scan_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "written_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "cpu_seconds",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+            TableField::new(
+                "spilled_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.start_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.end_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.tenant_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.cluster_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.node_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.sql_user.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.query_count)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.scan_bytes)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.written_bytes)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Float64(F64::from(self.cpu_seconds))).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.spilled_bytes)).as_ref());
+        Ok(())
+    }
+}
+
+pub type UsageQueue = SystemLogQueue<UsageLogElement>;
+pub type UsageTable = SystemLogTable<UsageLogElement>;
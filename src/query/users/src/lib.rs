@@ -34,6 +34,7 @@ pub mod connection;
 pub mod file_format;
 pub mod role_cache_mgr;
 pub mod role_util;
+pub mod tag;
 
 pub use jwt::*;
 pub use password_policy::*;
@@ -225,6 +225,30 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke role privileges)"))
     }
 
+    /// Zero clears the limit, None keeps it unchanged.
+    #[async_backtrace::framed]
+    pub async fn update_role_estimate_limits(
+        &self,
+        tenant: &Tenant,
+        role: &String,
+        max_estimated_scan_bytes: Option<u64>,
+        max_estimated_memory_bytes: Option<u64>,
+    ) -> Result<Option<u64>> {
+        let client = self.role_api(tenant);
+        client
+            .update_role_with(role, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                ri.update_role_time();
+                if let Some(limit) = max_estimated_scan_bytes {
+                    ri.max_estimated_scan_bytes = (limit != 0).then_some(limit);
+                }
+                if let Some(limit) = max_estimated_memory_bytes {
+                    ri.max_estimated_memory_bytes = (limit != 0).then_some(limit);
+                }
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set role estimate limits)"))
+    }
+
     // the grant_role can not have cycle with target_role.
     #[async_backtrace::framed]
     pub async fn grant_role_to_role(
@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::crud::CrudError;
+use databend_common_meta_app::principal::Tag;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// tag operations.
+impl UserApiProvider {
+    // Add a new tag.
+    #[async_backtrace::framed]
+    pub async fn add_tag(
+        &self,
+        tenant: &Tenant,
+        tag: Tag,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let client = self.tag_api(tenant);
+        client.add(tag, create_option).await?;
+        Ok(())
+    }
+
+    // Set or unset the masking policy of a tag.
+    #[async_backtrace::framed]
+    pub async fn update_tag_masking_policy(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        masking_policy: Option<String>,
+        if_exists: bool,
+    ) -> Result<Option<u64>> {
+        let client = self.tag_api(tenant);
+        let seq_tag = match client.get(name, MatchSeq::GE(0)).await {
+            Ok(seq_tag) => seq_tag,
+            Err(e) => match e {
+                CrudError::ApiError(meta_err) => {
+                    return Err(ErrorCode::from(meta_err).add_message_back(" (while alter tag)"));
+                }
+                CrudError::Business(unknown) => {
+                    if if_exists {
+                        return Ok(None);
+                    } else {
+                        return Err(ErrorCode::from(unknown).add_message_back(" (while alter tag)"));
+                    }
+                }
+            },
+        };
+
+        let seq = seq_tag.seq;
+        let mut tag = seq_tag.data;
+        tag.masking_policy = masking_policy;
+        tag.update_on = Some(Utc::now());
+
+        match client.update(tag, MatchSeq::Exact(seq)).await {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(ErrorCode::from(e).add_message_back(" (while alter tag).")),
+        }
+    }
+
+    // Drop a tag by name.
+    #[async_backtrace::framed]
+    pub async fn drop_tag(&self, tenant: &Tenant, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.tag_api(tenant);
+        match client.remove(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => match e {
+                CrudError::ApiError(meta_err) => {
+                    Err(ErrorCode::from(meta_err).add_message_back(" (while drop tag)"))
+                }
+                CrudError::Business(unknown) => {
+                    if if_exists {
+                        Ok(())
+                    } else {
+                        Err(ErrorCode::from(unknown).add_message_back(" (while drop tag)"))
+                    }
+                }
+            },
+        }
+    }
+
+    // Get a tag by name.
+    #[async_backtrace::framed]
+    pub async fn get_tag(&self, tenant: &Tenant, name: &str) -> Result<Tag> {
+        let client = self.tag_api(tenant);
+        let tag = client.get(name, MatchSeq::GE(0)).await?.data;
+        Ok(tag)
+    }
+
+    // Get all tags by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_tags(&self, tenant: &Tenant) -> Result<Vec<Tag>> {
+        let client = self.tag_api(tenant);
+        let tags = client.list().await.map_err(|e| {
+            let e = ErrorCode::from(e);
+            e.add_message_back(" (while get tags).")
+        })?;
+        Ok(tags)
+    }
+}
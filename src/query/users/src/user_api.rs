@@ -31,6 +31,7 @@ use databend_common_management::SettingApi;
 use databend_common_management::SettingMgr;
 use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
+use databend_common_management::TagMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
 use databend_common_meta_app::principal::AuthInfo;
@@ -157,6 +158,10 @@ impl UserApiProvider {
         PasswordPolicyMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn tag_api(&self, tenant: &Tenant) -> TagMgr {
+        TagMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
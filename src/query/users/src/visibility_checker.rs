@@ -31,6 +31,7 @@ pub struct GrantObjectVisibilityChecker {
     granted_global_db_table: bool,
     granted_global_stage: bool,
     granted_global_read_stage: bool,
+    granted_global_write_stage: bool,
     granted_databases: HashSet<(String, String)>,
     granted_databases_id: HashSet<(String, u64)>,
     granted_tables: HashSet<(String, String, String)>,
@@ -52,6 +53,7 @@ impl GrantObjectVisibilityChecker {
         let mut granted_global_db_table = false;
         let mut granted_global_stage = false;
         let mut granted_global_read_stage = false;
+        let mut granted_global_write_stage = false;
         let mut granted_databases = HashSet::new();
         let mut granted_tables = HashSet::new();
         let mut granted_udfs = HashSet::new();
@@ -106,6 +108,12 @@ impl GrantObjectVisibilityChecker {
                             |privilege| privilege == UserPrivilegeType::Read,
                         );
 
+                        check_privilege(
+                            &mut granted_global_write_stage,
+                            ent.privileges().iter(),
+                            |privilege| privilege == UserPrivilegeType::Write,
+                        );
+
                         check_privilege(
                             &mut granted_global_db_table,
                             ent.privileges().iter(),
@@ -152,6 +160,7 @@ impl GrantObjectVisibilityChecker {
                             granted_read_stages.insert(stage.to_string());
                         }
                     }
+                    GrantObject::Warehouse(_) => {}
                 }
             }
         }
@@ -188,6 +197,7 @@ impl GrantObjectVisibilityChecker {
             granted_global_db_table,
             granted_global_stage,
             granted_global_read_stage,
+            granted_global_write_stage,
             granted_databases,
             granted_databases_id,
             granted_tables,
@@ -222,6 +232,17 @@ impl GrantObjectVisibilityChecker {
         false
     }
 
+    pub fn check_stage_write_visibility(&self, stage: &str) -> bool {
+        if self.granted_global_write_stage {
+            return true;
+        }
+
+        if self.granted_write_stages.contains(stage) {
+            return true;
+        }
+        false
+    }
+
     pub fn check_udf_visibility(&self, udf: &str) -> bool {
         if self.granted_global_udf {
             return true;
@@ -211,7 +211,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             };
             column_defs.push(column_def);
         }
-        CreateTableSource::Columns(column_defs, None)
+        CreateTableSource::Columns(column_defs, None, None)
     }
 }
 
@@ -613,4 +613,61 @@ query II
 select * from t2 order by c1;
 ----
 1 2
-3 4
\ No newline at end of file
+3 4
+# All the target tables are committed atomically, which is only supported for fuse tables
+statement ok
+create or replace table t3(c1 int,c2 int) engine = memory;
+
+statement error 3902
+INSERT ALL
+    INTO t1
+    INTO t3
+SELECT * from s;
+
+# A failed statement leaves all the target tables untouched
+statement ok
+create or replace table s2(c3 int,c4 string);
+
+statement ok
+insert into s2 values(1,'1'),(2,'x');
+
+statement error
+INSERT ALL
+    INTO t1 values(c3, c3)
+    INTO t2 values(c3, c4)
+SELECT * from s2;
+
+query II
+select * from t1 order by c1;
+----
+5 6
+
+query II
+select * from t2 order by c1;
+----
+1 2
+3 4
+
+# A failed commit leaves all the target tables untouched
+statement ok
+set fault_injection = 'commit:error';
+
+statement error 1130
+INSERT ALL
+    INTO t1 values(c3, c3)
+    INTO t2 values(c3, c3)
+SELECT c3 from s;
+
+statement ok
+unset fault_injection;
+
+query II
+select * from t1 order by c1;
+----
+5 6
+
+query II
+select * from t2 order by c1;
+----
+1 2
+3 4
@@ -7,7 +7,7 @@ create view v_t as select * from t;
 show full tables from db12_0002;
 show full views from db12_0002;
 
-DROP TABLE t;
+DROP TABLE t CASCADE;
 
 show tables history like 't';
 
@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists t12_0006_1" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists t12_0006_2" | $BENDSQL_CLIENT_CONNECT
+
+echo "create table t12_0006_1(a int)" | $BENDSQL_CLIENT_CONNECT
+echo "create table t12_0006_2(a int)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0006_1 values(1),(2)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0006_2 values(2),(3)" | $BENDSQL_CLIENT_CONNECT
+
+## The query is delayed while being planned, and t12_0006_2 is committed in the meantime.
+echo "the commit made after the query started is not visible, which should contain 1 row"
+(
+	echo "set enable_consistent_snapshot_read = 1; set fault_injection = 'plan:delay=5000'; select count(*) from t12_0006_1 join t12_0006_2 on t12_0006_1.a = t12_0006_2.a" | $BENDSQL_CLIENT_CONNECT
+) &
+sleep 2
+echo "insert into t12_0006_2 values(1)" | $BENDSQL_CLIENT_CONNECT
+wait
+
+echo "the commit is visible to the next query, which should contain 2 rows"
+echo "select count(*) from t12_0006_1 join t12_0006_2 on t12_0006_1.a = t12_0006_2.a" | $BENDSQL_CLIENT_CONNECT
+
+## The snapshot read by the query is the one before the concurrent commit.
+SNAPSHOT_ID=$(echo "select snapshot_id from fuse_snapshot('default','t12_0006_2') order by timestamp desc limit 1" | $BENDSQL_CLIENT_CONNECT)
+
+echo "the snapshot at the start of the query is in the profile"
+(
+	echo "set enable_consistent_snapshot_read = 1; set fault_injection = 'plan:delay=5000'; explain(format = json) select count(*) from t12_0006_1 join t12_0006_2 on t12_0006_1.a = t12_0006_2.a" | $BENDSQL_CLIENT_CONNECT | grep -q "$SNAPSHOT_ID" && echo "found"
+) &
+sleep 2
+echo "insert into t12_0006_2 values(4)" | $BENDSQL_CLIENT_CONNECT
+wait
+
+echo "drop table t12_0006_1" | $BENDSQL_CLIENT_CONNECT
+echo "drop table t12_0006_2" | $BENDSQL_CLIENT_CONNECT
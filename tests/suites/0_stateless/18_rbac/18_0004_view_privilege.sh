@@ -14,8 +14,8 @@ stmt 'create role role1'
 
 stmt 'grant role role1 to owner'
 stmt 'grant create on default.* to role role1'
-stmt 'drop table if exists t'
-stmt 'drop view if exists v_t'
+stmt 'drop table if exists t cascade'
+stmt 'drop view if exists v_t cascade'
 stmt 'drop table if exists t_owner'
 stmt 'drop view if exists v_t_owner'
 stmt 'drop view if exists v_t_union'
@@ -59,8 +59,8 @@ echo 'create view v_t1 as select * from t union select * from v_t' | $TEST_USER_
 stmt 'grant select on default.v_t1 to owner'
 echo "select * from v_t1 order by id" | $TEST_USER_CONNECT
 
-stmt 'drop table if exists t'
-stmt 'drop view if exists v_t'
+stmt 'drop table if exists t cascade'
+stmt 'drop view if exists v_t cascade'
 stmt 'drop table if exists t_owner'
 stmt 'drop view if exists v_t_owner'
 stmt 'drop view if exists v_t_union'
@@ -140,7 +140,7 @@ echo "select count(*)>=1 from fuse_block('default', 't20_0012_a')" | $TEST_USER_
 
 ## Drop table.
 echo "drop table default.t20_0012 all" | $BENDSQL_CLIENT_CONNECT
-echo "drop table default.t20_0012_a all" | $BENDSQL_CLIENT_CONNECT
+echo "drop table default.t20_0012_a all cascade" | $BENDSQL_CLIENT_CONNECT
 echo "drop table default.t20_0012_b all" | $BENDSQL_CLIENT_CONNECT
 echo "drop view default2.v_t20_0012" | $BENDSQL_CLIENT_CONNECT
 
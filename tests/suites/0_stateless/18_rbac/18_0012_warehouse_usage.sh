@@ -0,0 +1,48 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_CONNECT="bendsql --user=test_wh --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo '-- reset user and roles'
+echo "DROP USER IF EXISTS 'test_wh'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS 'wh_role1'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS 'wh_role2'" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- prepare user and roles'
+echo "CREATE USER 'test_wh' IDENTIFIED BY '123'" | $BENDSQL_CLIENT_CONNECT
+echo 'CREATE ROLE `wh_role1`' | $BENDSQL_CLIENT_CONNECT
+echo 'CREATE ROLE `wh_role2`' | $BENDSQL_CLIENT_CONNECT
+echo 'GRANT ROLE wh_role1 TO test_wh' | $BENDSQL_CLIENT_CONNECT
+echo 'GRANT ROLE wh_role2 TO test_wh' | $BENDSQL_CLIENT_CONNECT
+
+echo '-- test 1: the warehouse is open to all roles before it is reserved'
+echo 'GRANT USAGE ON WAREHOUSE test_cluster TO ROLE wh_role1' | $BENDSQL_CLIENT_CONNECT
+echo "SET ROLE wh_role2; SET SECONDARY ROLES NONE; SELECT 1;" | $TEST_USER_CONNECT
+echo "SET reserved_warehouses = 'test_cluster'" | $BENDSQL_CLIENT_CONNECT || true
+
+echo '-- test 2: only the roles with USAGE can use a reserved warehouse'
+echo "SET GLOBAL reserved_warehouses = 'test_cluster'" | $BENDSQL_CLIENT_CONNECT
+echo "SHOW GRANTS ON WAREHOUSE test_cluster" | $BENDSQL_CLIENT_CONNECT | awk -F '\t' '{print $1, $2, $4, $5}'
+echo "SHOW GRANTS FOR ROLE wh_role1" | $BENDSQL_CLIENT_CONNECT | awk -F '\t' '{print $1, $2, $6}'
+echo "SET ROLE wh_role1; SET SECONDARY ROLES NONE; SELECT 2;" | $TEST_USER_CONNECT
+echo "SET ROLE wh_role2; SET SECONDARY ROLES NONE; SELECT 3;" | $TEST_USER_CONNECT || true
+echo "SET ROLE wh_role2; SET SECONDARY ROLES ALL; SELECT 4;" | $TEST_USER_CONNECT
+
+echo "SET ROLE wh_role2; SET SECONDARY ROLES NONE; SET reserved_warehouses = ''; SELECT 5;" | $TEST_USER_CONNECT || true
+
+echo '-- test 3: the users granted USAGE directly can use a reserved warehouse'
+echo 'REVOKE USAGE ON WAREHOUSE test_cluster FROM ROLE wh_role1' | $BENDSQL_CLIENT_CONNECT
+echo "SET ROLE wh_role1; SET SECONDARY ROLES NONE; SELECT 6;" | $TEST_USER_CONNECT || true
+echo 'GRANT USAGE ON WAREHOUSE test_cluster TO test_wh' | $BENDSQL_CLIENT_CONNECT
+echo "SET ROLE wh_role2; SET SECONDARY ROLES NONE; SELECT 7;" | $TEST_USER_CONNECT
+
+echo '-- test 4: the warehouse is open again after it is released'
+echo 'REVOKE USAGE ON WAREHOUSE test_cluster FROM test_wh' | $BENDSQL_CLIENT_CONNECT
+echo "UNSET reserved_warehouses" | $BENDSQL_CLIENT_CONNECT
+echo "SET ROLE wh_role2; SET SECONDARY ROLES NONE; SELECT 8;" | $TEST_USER_CONNECT
+
+echo "DROP USER IF EXISTS 'test_wh'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS 'wh_role1'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS 'wh_role2'" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_CONNECT="bendsql --user=test_restore --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo '-- prepare the bundle'
+echo "DROP USER IF EXISTS 'test_restore'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP DATABASE IF EXISTS restore_priv" | $BENDSQL_CLIENT_CONNECT
+echo "DROP STAGE IF EXISTS restore_priv_bundles" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE DATABASE restore_priv" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE TABLE restore_priv.t(a int)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO restore_priv.t VALUES (1), (2)" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE STAGE restore_priv_bundles" | $BENDSQL_CLIENT_CONNECT
+echo "EXPORT DATABASE restore_priv TO @restore_priv_bundles/b1/" | $BENDSQL_CLIENT_CONNECT > /dev/null
+echo "DELETE FROM restore_priv.t" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE USER 'test_restore' IDENTIFIED BY '123'" | $BENDSQL_CLIENT_CONNECT
+echo "GRANT READ ON STAGE restore_priv_bundles TO 'test_restore'" | $BENDSQL_CLIENT_CONNECT
+echo "GRANT INSERT ON restore_priv.t TO 'test_restore'" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- test 1: overwriting the table requires DELETE on it'
+echo "RESTORE TABLE restore_priv.t FROM @restore_priv_bundles/" | $TEST_USER_CONNECT 2>&1 | grep -o "privilege \[[A-Za-z]*\] is required"
+
+echo '-- test 2: INSERT and DELETE on the table are enough to restore it'
+echo "GRANT DELETE ON restore_priv.t TO 'test_restore'" | $BENDSQL_CLIENT_CONNECT
+echo "RESTORE TABLE restore_priv.t FROM @restore_priv_bundles/" | $TEST_USER_CONNECT > /dev/null
+echo "RESTORE TABLE restore_priv.t FROM @restore_priv_bundles/" | $TEST_USER_CONNECT > /dev/null
+echo "SELECT count(*) FROM restore_priv.t" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- test 3: creating the table requires CREATE on the database'
+echo "DROP TABLE restore_priv.t" | $BENDSQL_CLIENT_CONNECT
+echo "RESTORE TABLE restore_priv.t FROM @restore_priv_bundles/" | $TEST_USER_CONNECT 2>&1 | grep -o "privilege \[[A-Za-z]*\] is required"
+
+echo "DROP USER IF EXISTS 'test_restore'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP DATABASE IF EXISTS restore_priv" | $BENDSQL_CLIENT_CONNECT
+echo "DROP STAGE IF EXISTS restore_priv_bundles" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+## The tag and the application name in the headers are recorded in the query log
+curl -s -u root: -XPOST "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/query" \
+  --header 'Content-Type: application/json' \
+  --header 'X-DATABEND-QUERY-TAG: nightly_etl' \
+  --header 'X-DATABEND-APPLICATION-NAME: etl_runner' \
+  -d '{"sql": "select '\''tagged_20_0020'\''", "pagination": {"wait_time_secs": 3}}' | jq -r '.data[0][0], .error'
+
+echo "select query_tag, application_name from system.query_log where query_text = 'SELECT ''tagged_20_0020''' and log_type_name = 'Finish' limit 1" | $BENDSQL_CLIENT_CONNECT
+
+## The settings of the query take the tag in the header
+curl -s -u root: -XPOST "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/query" \
+  --header 'Content-Type: application/json' \
+  --header 'X-DATABEND-QUERY-TAG: nightly_etl' \
+  -d '{"sql": "select value from system.settings where name = '\''query_tag'\''", "pagination": {"wait_time_secs": 3}}' | jq -r '.data[0][0], .error'
@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop stage if exists presign_stage" | $BENDSQL_CLIENT_CONNECT
+
+aws --endpoint-url http://127.0.0.1:9900/ s3 cp s3://testbucket/admin/data/ontime_200.csv s3://testbucket/admin/stage/internal/presign_stage/ontime_200.csv >/dev/null 2>&1
+
+echo "CREATE STAGE presign_stage;" | $BENDSQL_CLIENT_CONNECT
+
+curl -s -w "%{http_code}\n" -o /dev/null "`echo "SELECT url FROM presign(@presign_stage/ontime_200.csv, expire => 600)" | $BENDSQL_CLIENT_CONNECT`"
+
+curl -s -w "%{http_code}\n" -X PUT -o /dev/null -H Content-Type:application/octet-stream "`echo "SELECT url FROM presign(@presign_stage/hello_world.txt, action => 'upload', content_type => 'application/octet-stream')" | $BENDSQL_CLIENT_CONNECT`" -d "Hello, World!"
+
+echo "SELECT method FROM presign(@presign_stage/hello_world.txt, action => 'upload')" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT file_name, size FROM directory(@presign_stage/) ORDER BY file_name" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT * FROM presign(@presign_stage/hello_world.txt, action => 'delete')" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -o "action of presign must be 'download' or 'upload'"
+
+## Drop table.
+echo "drop stage if exists presign_stage" | $BENDSQL_CLIENT_CONNECT
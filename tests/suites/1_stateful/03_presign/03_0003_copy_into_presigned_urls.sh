@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop stage if exists presign_stage" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE STAGE presign_stage;" | $BENDSQL_CLIENT_CONNECT
+
+URL1=`echo "PRESIGN UPLOAD @presign_stage/unload/a.csv" | $BENDSQL_CLIENT_CONNECT | cut -f 3`
+URL2=`echo "PRESIGN UPLOAD @presign_stage/unload/b.csv" | $BENDSQL_CLIENT_CONNECT | cut -f 3`
+
+echo "COPY INTO ('${URL1}') FROM (SELECT number FROM numbers(10)) FILE_FORMAT = (TYPE = CSV) SINGLE = TRUE" | $BENDSQL_CLIENT_CONNECT
+
+echo "SELECT count(\$1), sum(\$1::int) FROM @presign_stage/unload/a.csv (FILE_FORMAT => 'CSV')" | $BENDSQL_CLIENT_CONNECT
+
+# The detailed output hides the signatures of urls.
+echo "COPY INTO ('${URL2}') FROM (SELECT number FROM numbers(3)) FILE_FORMAT = (TYPE = CSV) SINGLE = TRUE DETAILED_OUTPUT = TRUE" | $BENDSQL_CLIENT_CONNECT | cut -f 1 | grep -c "?"
+
+echo "SELECT \$1 FROM @presign_stage/unload/b.csv (FILE_FORMAT => 'CSV') ORDER BY \$1" | $BENDSQL_CLIENT_CONNECT
+
+# More files than urls.
+echo "COPY INTO ('${URL1}') FROM (SELECT number FROM numbers(100000)) FILE_FORMAT = (TYPE = CSV) MAX_FILE_SIZE = 1000" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -o "there are more unloaded files than the 1 urls"
+
+# The hosts not in unload_url_allow_list are rejected.
+echo "COPY INTO ('http://169.254.169.254/latest/a.csv') FROM (SELECT 1) FILE_FORMAT = (TYPE = CSV)" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -o "is not in unload_url_allow_list"
+
+echo "COPY INTO ('s3://testbucket/a.csv') FROM (SELECT 1) FILE_FORMAT = (TYPE = CSV)" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -o "only http or https urls can be copied into"
+
+## Drop table.
+echo "drop stage if exists presign_stage" | $BENDSQL_CLIENT_CONNECT
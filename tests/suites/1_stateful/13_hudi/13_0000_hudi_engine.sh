@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/hudi/simple/)
+
+stmt "drop table if exists test_hudi;"
+
+echo ">>>> create table test_hudi engine = hudi location = 'fs://\${ROOT}/';"
+echo "create table test_hudi engine = hudi location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+# fg1 is rewritten by the commit 20240102, fg2 is written by an archived commit,
+# fg3 is written by an inflight commit and fg4 is replaced by fg5.
+query "select * from test_hudi order by id;"
+stmt "drop table test_hudi;"
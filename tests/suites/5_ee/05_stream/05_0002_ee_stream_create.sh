@@ -41,5 +41,5 @@ echo "select count(*) from fuse_snapshot('db_stream', 'base')" | $BENDSQL_CLIENT
 
 echo "drop stream if exists db_stream.s2" | $BENDSQL_CLIENT_CONNECT
 echo "drop stream if exists db_stream.t2" | $BENDSQL_CLIENT_CONNECT
-echo "drop table if exists db_stream.base all" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists db_stream.base all cascade" | $BENDSQL_CLIENT_CONNECT
 echo "drop database if exists db_stream" | $BENDSQL_CLIENT_CONNECT